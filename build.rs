@@ -0,0 +1,57 @@
+use clap::CommandFactory;
+use clap_complete::Shell;
+use std::path::{Path, PathBuf};
+use std::{env, fs, io};
+
+#[allow(dead_code)]
+mod cli {
+    include!("src/cli.rs");
+}
+
+/// Generate man pages (`feedr.1` plus one per subcommand) and shell completions.
+/// They're written to `$OUT_DIR`, or to `$FEEDR_GEN_DIR` when packagers set it.
+fn main() -> io::Result<()> {
+    println!("cargo:rerun-if-changed=src/cli.rs");
+    println!("cargo:rerun-if-env-changed=FEEDR_GEN_DIR");
+
+    let out_dir = match env::var_os("FEEDR_GEN_DIR").or_else(|| env::var_os("OUT_DIR")) {
+        Some(dir) => PathBuf::from(dir),
+        None => return Ok(()),
+    };
+
+    let mut command = cli::Cli::command();
+    let man_dir = out_dir.join("man");
+    fs::create_dir_all(&man_dir)?;
+    write_man_pages(&command, "feedr", &man_dir)?;
+
+    let completions_dir = out_dir.join("completions");
+    fs::create_dir_all(&completions_dir)?;
+    for shell in [
+        Shell::Bash,
+        Shell::Zsh,
+        Shell::Fish,
+        Shell::Elvish,
+        Shell::PowerShell,
+    ] {
+        clap_complete::generate_to(shell, &mut command, "feedr", &completions_dir)?;
+    }
+    Ok(())
+}
+
+/// Render `command`'s page, then recurse into its visible subcommands. `bin_name` is
+/// how it's invoked ("feedr config set"); the page is named after it (`feedr-config-set.1`).
+fn write_man_pages(command: &clap::Command, bin_name: &str, dir: &Path) -> io::Result<()> {
+    let title = bin_name.replace(' ', "-");
+    let command = command.clone().bin_name(bin_name.to_string());
+    let mut buffer = Vec::new();
+    clap_mangen::Man::new(command.clone())
+        .title(title.clone())
+        .render(&mut buffer)?;
+    fs::write(dir.join(format!("{}.1", title)), buffer)?;
+
+    for subcommand in command.get_subcommands().filter(|s| !s.is_hide_set()) {
+        let sub_bin_name = format!("{} {}", bin_name, subcommand.get_name());
+        write_man_pages(subcommand, &sub_bin_name, dir)?;
+    }
+    Ok(())
+}
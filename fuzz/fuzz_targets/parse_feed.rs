@@ -0,0 +1,13 @@
+#![no_main]
+
+use feedr::feed::Feed;
+use libfuzzer_sys::fuzz_target;
+
+// Whatever a server sends back, parsing it must fail cleanly rather than panic
+fuzz_target!(|data: &[u8]| {
+    if let Ok(feed) = Feed::from_bytes("https://example.com/feed.xml", data) {
+        for item in &feed.items {
+            let _ = feedr::feed::fold_for_search(&item.title);
+        }
+    }
+});
@@ -0,0 +1,316 @@
+use crate::feed::{format_date, Feed, FeedItem};
+use anyhow::{anyhow, bail, Context, Result};
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+/// Accept header for the follow-up requests to an actor's outbox and its pages.
+const ACCEPT: &str =
+    "application/activity+json, application/ld+json; profile=\"https://www.w3.org/ns/activitystreams\"";
+
+// Object types shown as items; everything else (boosts, likes, follows) is skipped
+const POST_TYPES: [&str; 3] = ["Note", "Article", "Page"];
+
+/// `content` as JSON, if it's an ActivityStreams document.
+pub fn parse_document(content: &[u8]) -> Option<Value> {
+    let value: Value = serde_json::from_slice(content).ok()?;
+    let is_activity_streams = |context: &Value| {
+        context
+            .as_str()
+            .is_some_and(|c| c.contains("www.w3.org/ns/activitystreams"))
+    };
+    let found = match value.get("@context")? {
+        Value::Array(contexts) => contexts.iter().any(is_activity_streams),
+        context => is_activity_streams(context),
+    };
+    found.then_some(value)
+}
+
+/// Where the posts are when `doc` doesn't hold them itself: an actor's outbox, or the
+/// first page of a collection that doesn't embed its items.
+pub fn next_url(doc: &Value) -> Option<String> {
+    if posts(doc).is_some() {
+        return None;
+    }
+    if let Some(outbox) = doc.get("outbox").and_then(link) {
+        return Some(outbox);
+    }
+    doc.get("first").and_then(Value::as_str).map(str::to_string)
+}
+
+/// For an actor, or an outbox that only links its pages, fetch the document that
+/// holds the posts, returning its body and URL. None when `content` is neither.
+pub fn fetch_posts_page(
+    content: &[u8],
+    client: &reqwest::blocking::Client,
+    user_agent: &str,
+) -> Option<Result<(Vec<u8>, String)>> {
+    let mut next = next_url(&parse_document(content)?)?;
+    // Actor -> outbox -> first page is as far as it goes
+    for _ in 0..2 {
+        let body = match fetch_json(&next, client, user_agent) {
+            Ok(body) => body,
+            Err(e) => return Some(Err(e)),
+        };
+        let Some(doc) = parse_document(&body) else {
+            return Some(Err(anyhow!("{} isn't an ActivityPub document", next)));
+        };
+        match next_url(&doc) {
+            Some(url) => next = url,
+            None => return Some(Ok((body, next))),
+        }
+    }
+    Some(Err(anyhow!(
+        "Couldn't find the posts of this ActivityPub actor"
+    )))
+}
+
+fn fetch_json(url: &str, client: &reqwest::blocking::Client, user_agent: &str) -> Result<Vec<u8>> {
+    let body = client
+        .get(url)
+        .header("User-Agent", user_agent)
+        .header("Accept", ACCEPT)
+        .send()
+        .and_then(|r| r.error_for_status())
+        .and_then(|r| r.bytes())
+        .with_context(|| format!("Failed to fetch {}", url))?;
+    Ok(body.to_vec())
+}
+
+/// Turn an outbox, or one page of it, into a feed. Create activities are unwrapped to
+/// the Note, Article or Page they post; other activities are left out. The feed is
+/// named after the actor the posts are attributed to.
+pub fn outbox_to_feed(url: &str, doc: &Value) -> Result<Feed> {
+    let Some(activities) = posts(doc) else {
+        bail!("ActivityPub document has no posts");
+    };
+    let objects: Vec<&Value> = activities.iter().filter_map(post_object).collect();
+    let actor = objects
+        .iter()
+        .find_map(|object| object.get("attributedTo").and_then(link))
+        .or_else(|| {
+            activities
+                .iter()
+                .find_map(|activity| activity.get("actor").and_then(link))
+        });
+
+    let mut feed = Feed {
+        url: url.to_string(),
+        title: actor
+            .as_deref()
+            .map(actor_label)
+            .unwrap_or_else(|| url.to_string()),
+        items: objects.into_iter().map(post_item).collect(),
+        title_lower: String::new(),
+        description: None,
+        updated: None,
+        link: actor,
+    };
+    feed.restore_derived_fields();
+    Ok(feed)
+}
+
+// The posts a collection or page holds, including those of an embedded first page
+fn posts(doc: &Value) -> Option<&Vec<Value>> {
+    let items = |doc: &Value| {
+        doc.get("orderedItems")
+            .or_else(|| doc.get("items"))
+            .and_then(Value::as_array)
+    };
+    items(doc).or_else(|| items(doc.get("first")?))
+}
+
+fn post_object(activity: &Value) -> Option<&Value> {
+    let object = match activity.get("type")?.as_str()? {
+        "Create" => activity.get("object")?,
+        _ => activity,
+    };
+    let kind = object.get("type")?.as_str()?;
+    POST_TYPES.contains(&kind).then_some(object)
+}
+
+fn post_item(object: &Value) -> FeedItem {
+    let text = |key: &str| {
+        object
+            .get(key)
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(str::to_string)
+    };
+    let content = text("content");
+    let title = text("name").unwrap_or_else(|| {
+        content
+            .as_deref()
+            .map(note_title)
+            .filter(|title| !title.is_empty())
+            .unwrap_or_else(|| "Untitled".to_string())
+    });
+    let published = text("published")
+        .and_then(|date| DateTime::parse_from_rfc3339(&date).ok())
+        .map(|date| date.with_timezone(&Utc));
+    let image = object
+        .get("attachment")
+        .and_then(Value::as_array)
+        .and_then(|attachments| {
+            attachments.iter().find(|a| {
+                a.get("mediaType")
+                    .and_then(Value::as_str)
+                    .is_some_and(|mime| mime.starts_with("image/"))
+            })
+        })
+        .and_then(|attachment| attachment.get("url").and_then(link));
+    // Replies are a collection; its size is all that's needed
+    let replies = object
+        .get("replies")
+        .and_then(|replies| replies.get("totalItems"))
+        .and_then(Value::as_u64)
+        .and_then(|count| u32::try_from(count).ok());
+
+    FeedItem {
+        title,
+        link: object
+            .get("url")
+            .and_then(link)
+            .or_else(|| object.get("id").and_then(link)),
+        description: content,
+        summary: text("summary"),
+        pub_date: published.map(|date| date.to_rfc3339()),
+        author: None,
+        formatted_date: published.map(format_date),
+        parsed_date: None,
+        plain_text: None,
+        title_lower: String::new(),
+        plain_text_lower: None,
+        language: None,
+        image,
+        comments_url: None,
+        comment_count: replies,
+        metadata: Default::default(),
+    }
+}
+
+// A link given as a plain URL, a Link object, or a list of either (the first wins)
+fn link(value: &Value) -> Option<String> {
+    match value {
+        Value::String(url) => Some(url.clone()),
+        Value::Object(object) => object
+            .get("href")
+            .or_else(|| object.get("id"))
+            .and_then(Value::as_str)
+            .map(str::to_string),
+        Value::Array(values) => values.iter().find_map(link),
+        _ => None,
+    }
+}
+
+// Notes have no title, so use the start of their text
+fn note_title(html: &str) -> String {
+    let text = html2text::from_read(html.as_bytes(), 10_000);
+    let line = text
+        .lines()
+        .map(str::trim)
+        .find(|l| !l.is_empty())
+        .unwrap_or("");
+    if line.chars().count() > 80 {
+        format!("{}…", line.chars().take(79).collect::<String>().trim_end())
+    } else {
+        line.to_string()
+    }
+}
+
+// "alice@example.com" for https://example.com/users/alice
+fn actor_label(actor: &str) -> String {
+    match url::Url::parse(actor) {
+        Ok(url) => {
+            let name = url
+                .path_segments()
+                .and_then(|mut segments| segments.rfind(|s| !s.is_empty()))
+                .map(|name| name.trim_start_matches('@').to_string());
+            match (name, url.host_str()) {
+                (Some(name), Some(host)) => format!("{}@{}", name, host),
+                _ => actor.to_string(),
+            }
+        }
+        Err(_) => actor.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OUTBOX: &str = r#"{
+        "@context": ["https://www.w3.org/ns/activitystreams", {"Hashtag": "as:Hashtag"}],
+        "type": "OrderedCollectionPage",
+        "orderedItems": [
+            {
+                "type": "Create",
+                "actor": "https://blog.example.com/users/alice",
+                "object": {
+                    "type": "Article",
+                    "id": "https://blog.example.com/p/1",
+                    "name": "Hello Fediverse",
+                    "content": "<p>First <b>post</b></p>",
+                    "url": {"type": "Link", "href": "https://blog.example.com/hello"},
+                    "published": "2025-03-01T10:00:00Z",
+                    "attributedTo": "https://blog.example.com/users/alice",
+                    "attachment": [{"type": "Document", "mediaType": "image/png", "url": "https://blog.example.com/a.png"}]
+                }
+            },
+            {"type": "Announce", "object": "https://elsewhere.example/notes/9"},
+            {
+                "type": "Create",
+                "object": {"type": "Note", "id": "https://blog.example.com/n/2", "content": "<p>Just a quick note</p>"}
+            }
+        ]
+    }"#;
+
+    #[test]
+    fn test_outbox_to_feed_maps_posts() {
+        let doc = parse_document(OUTBOX.as_bytes()).unwrap();
+        assert_eq!(next_url(&doc), None);
+        let feed = outbox_to_feed("https://blog.example.com/users/alice/outbox", &doc).unwrap();
+        assert_eq!(feed.title, "alice@blog.example.com");
+        assert_eq!(feed.items.len(), 2);
+
+        let article = &feed.items[0];
+        assert_eq!(article.title, "Hello Fediverse");
+        assert_eq!(
+            article.link.as_deref(),
+            Some("https://blog.example.com/hello")
+        );
+        assert_eq!(
+            article.image.as_deref(),
+            Some("https://blog.example.com/a.png")
+        );
+        assert!(article.parsed_date.is_some());
+        assert!(article.plain_text.as_deref().unwrap().contains("First"));
+
+        let note = &feed.items[1];
+        assert_eq!(note.title, "Just a quick note");
+        assert_eq!(note.link.as_deref(), Some("https://blog.example.com/n/2"));
+    }
+
+    #[test]
+    fn test_next_url_follows_actors_and_paged_collections() {
+        let actor = serde_json::json!({
+            "@context": "https://www.w3.org/ns/activitystreams",
+            "type": "Person",
+            "outbox": "https://example.com/users/bob/outbox"
+        });
+        assert_eq!(
+            next_url(&actor).as_deref(),
+            Some("https://example.com/users/bob/outbox")
+        );
+        let collection = serde_json::json!({
+            "@context": "https://www.w3.org/ns/activitystreams",
+            "type": "OrderedCollection",
+            "first": "https://example.com/users/bob/outbox?page=true"
+        });
+        assert_eq!(
+            next_url(&collection).as_deref(),
+            Some("https://example.com/users/bob/outbox?page=true")
+        );
+        assert!(parse_document(br#"{"type": "Note"}"#).is_none());
+    }
+}
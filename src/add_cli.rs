@@ -0,0 +1,119 @@
+use crate::app::{AddFeedResult, App};
+use anyhow::{bail, Context, Result};
+use std::io::Read;
+use std::path::Path;
+
+/// Subscribe to a feed without launching the UI: fetch and validate it, then apply the
+/// title and category. Re-running for a feed that's already subscribed only updates
+/// the title and category, so provisioning scripts can run it unconditionally.
+pub fn run(url: &str, category: Option<&str>, title: Option<&str>) -> Result<()> {
+    let mut app = App::new();
+    let url = crate::packages::expand(url);
+    let url = url.as_str();
+
+    let (url, already_subscribed) = if app.bookmarks.iter().any(|b| b == url) {
+        (url.to_string(), true)
+    } else {
+        (subscribe(&mut app, url)?, false)
+    };
+
+    if title.is_some() {
+        app.set_feed_title(&url, title)?;
+    }
+    if let Some(name) = category {
+        let idx = app.category_index_or_create(name)?;
+        app.assign_feed_to_category(&url, idx)?;
+    }
+
+    match app.feeds.iter().find(|feed| feed.url == url) {
+        Some(feed) if !already_subscribed => {
+            println!("Added \"{}\" ({})", feed.title, url);
+            println!("  {} items", feed.items.len());
+        }
+        _ => println!("Already subscribed to {}", url),
+    }
+    if let Some(title) = app.feed_titles.get(&url) {
+        println!("  Title: {}", title);
+    }
+    if let Some(name) = category {
+        println!("  Category: {}", name.trim());
+    }
+    Ok(())
+}
+
+/// Subscribe to every feed listed in `path` (`-` for standard input), fetching them
+/// concurrently, and print how each one went. Fails if any feed couldn't be added.
+pub fn run_file(path: &Path, category: Option<&str>) -> Result<()> {
+    let text = if path == Path::new("-") {
+        let mut text = String::new();
+        std::io::stdin().read_to_string(&mut text)?;
+        text
+    } else {
+        std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?
+    };
+    let urls = App::parse_url_list(&text);
+    if urls.is_empty() {
+        bail!("No URLs found in {}", path.display());
+    }
+
+    let mut app = App::new();
+    let report = app.add_feeds_bulk(urls)?;
+    if let Some(name) = category {
+        let idx = app.category_index_or_create(name)?;
+        for (url, _) in &report.new {
+            app.assign_feed_to_category(url, idx)?;
+        }
+    }
+
+    println!("Added {} feeds:", report.new.len());
+    for (url, title) in &report.new {
+        println!("  + {} ({})", title, url);
+    }
+    if !report.duplicates.is_empty() {
+        println!(
+            "\nAlready subscribed or listed twice ({}):",
+            report.duplicates.len()
+        );
+        for url in &report.duplicates {
+            println!("  = {}", url);
+        }
+    }
+    if !report.failed.is_empty() {
+        println!("\nFailed ({}):", report.failed.len());
+        for (url, reason) in &report.failed {
+            println!("  ! {}: {}", url, reason);
+        }
+        bail!("{} feeds could not be added", report.failed.len());
+    }
+    Ok(())
+}
+
+/// Fetch and subscribe to `url`, returning the feed URL that was added. A web page
+/// that links to exactly one feed subscribes to that feed.
+fn subscribe(app: &mut App, url: &str) -> Result<String> {
+    match app.add_feed(url)? {
+        AddFeedResult::Added => Ok(url.to_string()),
+        AddFeedResult::DiscoveredFeeds { feeds, page_url } => match feeds.as_slice() {
+            [] => bail!("{} is a web page with no feed links", page_url),
+            [only] => match app.add_feed(&only.url)? {
+                AddFeedResult::Added => Ok(only.url.clone()),
+                AddFeedResult::DiscoveredFeeds { .. } => {
+                    bail!("{} links to {}, which is not a feed", page_url, only.url)
+                }
+            },
+            _ => {
+                let choices: Vec<String> = feeds
+                    .iter()
+                    .map(|feed| format!("  {} ({})", feed.url, feed.title))
+                    .collect();
+                bail!(
+                    "{} is a web page linking to {} feeds; add one of them:\n{}",
+                    page_url,
+                    feeds.len(),
+                    choices.join("\n")
+                )
+            }
+        },
+    }
+}
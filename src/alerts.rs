@@ -0,0 +1,153 @@
+use crate::config::AlertRule;
+use regex::{Regex, RegexBuilder};
+use std::process::Command;
+
+/// An alert rule with its pattern compiled.
+#[derive(Clone, Debug)]
+pub struct Alert {
+    pub name: String,
+    pub notify: bool,
+    matcher: Regex,
+    feed: Option<String>,
+    category: Option<String>,
+}
+
+impl Alert {
+    /// Whether an item from `feed_url` (in `category`, if any) with this title and
+    /// text matches the rule.
+    pub fn matches(
+        &self,
+        feed_url: &str,
+        category: Option<&str>,
+        title: &str,
+        text: Option<&str>,
+    ) -> bool {
+        if self.feed.as_deref().is_some_and(|feed| feed != feed_url) {
+            return false;
+        }
+        if let Some(wanted) = &self.category {
+            if !category.is_some_and(|c| c.eq_ignore_ascii_case(wanted)) {
+                return false;
+            }
+        }
+        self.matcher.is_match(title) || text.is_some_and(|t| self.matcher.is_match(t))
+    }
+}
+
+/// Compile alert rules, returning the usable ones and a description of each rule
+/// that was skipped.
+pub fn compile(rules: &[AlertRule]) -> (Vec<Alert>, Vec<String>) {
+    let mut alerts = Vec::new();
+    let mut warnings = Vec::new();
+    for rule in rules {
+        if rule.pattern.trim().is_empty() {
+            warnings.push("empty alert pattern".to_string());
+            continue;
+        }
+        // Keywords match case-insensitively anywhere, regexes as written
+        let pattern = if rule.regex {
+            rule.pattern.clone()
+        } else {
+            regex::escape(rule.pattern.trim())
+        };
+        match RegexBuilder::new(&pattern)
+            .case_insensitive(!rule.regex)
+            .build()
+        {
+            Ok(matcher) => alerts.push(Alert {
+                name: rule.name.clone().unwrap_or_else(|| rule.pattern.clone()),
+                notify: rule.notify,
+                matcher,
+                feed: rule.feed.clone(),
+                category: rule.category.clone(),
+            }),
+            Err(e) => warnings.push(format!("invalid regex '{}': {}", rule.pattern, e)),
+        }
+    }
+    (alerts, warnings)
+}
+
+/// Show a desktop notification using the platform's command-line notifier. Failures
+/// are ignored: a missing notifier shouldn't interrupt reading.
+pub fn notify_desktop(summary: &str, body: &str) {
+    let mut command = if cfg!(target_os = "macos") {
+        let script = format!(
+            "display notification {} with title {}",
+            applescript_string(body),
+            applescript_string(summary)
+        );
+        let mut command = Command::new("osascript");
+        command.arg("-e").arg(script);
+        command
+    } else if cfg!(windows) {
+        // Windows has no stock command-line notifier
+        return;
+    } else {
+        let mut command = Command::new("notify-send");
+        command.arg("--app-name=feedr").arg(summary).arg(body);
+        command
+    };
+    let _ = command
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn();
+}
+
+fn applescript_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(pattern: &str) -> AlertRule {
+        AlertRule {
+            name: None,
+            pattern: pattern.to_string(),
+            regex: false,
+            feed: None,
+            category: None,
+            notify: true,
+        }
+    }
+
+    #[test]
+    fn test_keyword_matches_case_insensitively() {
+        let (alerts, warnings) = compile(&[rule("Rust 2024")]);
+        assert!(warnings.is_empty());
+        let alert = &alerts[0];
+        assert_eq!(alert.name, "Rust 2024");
+        assert!(alert.matches("https://a.com/feed", None, "What's new in rust 2024", None));
+        assert!(alert.matches(
+            "https://a.com/feed",
+            None,
+            "News",
+            Some("RUST 2024 edition")
+        ));
+        assert!(!alert.matches("https://a.com/feed", None, "Rust 2021", None));
+    }
+
+    #[test]
+    fn test_scope_and_regex() {
+        let scoped = AlertRule {
+            pattern: r"CVE-\d{4}-\d+".to_string(),
+            regex: true,
+            category: Some("Security".to_string()),
+            ..rule("")
+        };
+        let (alerts, _) = compile(&[scoped]);
+        let alert = &alerts[0];
+        assert!(alert.matches("u", Some("security"), "Fix for CVE-2024-1234", None));
+        assert!(!alert.matches("u", Some("News"), "Fix for CVE-2024-1234", None));
+        assert!(!alert.matches("u", None, "Fix for CVE-2024-1234", None));
+
+        let (alerts, warnings) = compile(&[AlertRule {
+            regex: true,
+            ..rule("(")
+        }]);
+        assert!(alerts.is_empty());
+        assert_eq!(warnings.len(), 1);
+    }
+}
@@ -1,15 +1,25 @@
-use crate::config::{CompactMode, Config};
-use crate::feed::{Feed, FeedCategory, FeedItem};
+use crate::config::{CompactMode, Config, DisplayRules, NotifyLevel, OpenTarget, SectionKind};
+use crate::display_rules::StripRules;
+use crate::edit_history::{EditHistory, RemovedFeed, TreeEdit};
+use crate::feed::{fold_for_search, Feed, FeedCategory, FeedItem, RequestOptions};
+use crate::http_cache::{CacheMeta, HttpCache};
+use crate::image_art::{ImageArt, Panel};
+use crate::releases::Release;
+use crate::storage::{JsonStorage, Storage};
+#[cfg(feature = "sync")]
+use crate::sync_state::StateLog;
 use crate::ui::ColorScheme;
-use anyhow::Result;
+use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::time::Instant;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct FilterOptions {
     pub category: Option<String>,   // Filter by feed category
     pub age: Option<TimeFilter>,    // Filter by content age
@@ -17,9 +27,20 @@ pub struct FilterOptions {
     pub read_status: Option<bool>,  // Filter for read/unread items
     pub min_length: Option<usize>,  // Filter by content length
     pub starred_only: Option<bool>, // Filter for starred/unstarred items
+    #[serde(deserialize_with = "deserialize_language")]
+    pub language: Option<&'static str>, // Filter by detected language (ISO 639-3 code)
+    pub tag: Option<String>,        // Filter by confirmed or suggested tag
 }
 
-#[derive(Clone, Debug, PartialEq)]
+/// Read a saved language filter back as one of whatlang's static ISO 639-3 codes.
+fn deserialize_language<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<&'static str>, D::Error> {
+    let code: Option<String> = Option::deserialize(deserializer)?;
+    Ok(code.and_then(|code| whatlang::Lang::from_code(code).map(|lang| lang.code())))
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum TimeFilter {
     Today,
     ThisWeek,
@@ -39,6 +60,8 @@ impl FilterOptions {
             || self.read_status.is_some()
             || self.min_length.is_some()
             || self.starred_only.is_some()
+            || self.language.is_some()
+            || self.tag.is_some()
     }
 
     pub fn reset(&mut self) {
@@ -46,6 +69,53 @@ impl FilterOptions {
     }
 }
 
+/// Order of an item list: its natural newest-first order, or reversed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ItemSort {
+    #[default]
+    NewestFirst,
+    OldestFirst,
+}
+
+impl ItemSort {
+    pub fn label(&self) -> &'static str {
+        match self {
+            ItemSort::NewestFirst => "newest first",
+            ItemSort::OldestFirst => "oldest first",
+        }
+    }
+}
+
+/// Filters and sort order remembered for one feed, one category, or the whole dashboard.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ViewState {
+    #[serde(default)]
+    pub filters: FilterOptions,
+    #[serde(default)]
+    pub sort: ItemSort,
+}
+
+/// How the user has dealt with one feed's items, to show which feeds actually get read.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FeedEngagement {
+    /// Items opened in the detail view or the browser, each counted once
+    pub opened: u32,
+    /// Times an item, or a link in one, was opened in the browser
+    pub clicks: u32,
+    /// Items marked read or skipped without being opened
+    pub dismissed: u32,
+}
+
+impl FeedEngagement {
+    /// Percentage of the items dealt with that were opened; None before there are any.
+    pub fn score(&self) -> Option<u32> {
+        let total = self.opened + self.dismissed;
+        (total > 0).then(|| self.opened * 100 / total)
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum InputMode {
     Normal,
@@ -54,6 +124,10 @@ pub enum InputMode {
     FilterMode,
     CategoryNameInput,    // For creating/renaming categories
     SelectDiscoveredFeed, // For picking from auto-discovered feeds
+    PreviewFeed,          // Looking over a fetched feed before subscribing
+    ImportOpmlPath,       // Typing the path of an OPML file to import
+    ExportOpmlPath,       // Typing the path to export subscriptions to as OPML
+    ExportKeysPath,       // Typing the path to write the keybinding cheat sheet to
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -65,6 +139,112 @@ pub enum View {
     CategoryManagement,
     Summary,
     Starred,
+    Alerts,
+    Triage,
+    ReadLater,
+    Scheduler,
+    Releases,
+}
+
+/// How many steps of navigation history are kept.
+const MAX_HISTORY: usize = 100;
+
+/// A place in the UI that the back/forward history can return to: the view, what
+/// was selected in it and how far an article was scrolled.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NavState {
+    view: View,
+    selected_feed: Option<usize>,
+    selected_item: Option<usize>,
+    item: Option<(String, String)>,
+    search_query: Option<String>,
+    detail_vertical_scroll: u16,
+    detail_return: Option<(View, Option<usize>)>,
+}
+
+impl NavState {
+    /// Whether both states are the same place, ignoring where the cursor is in a list.
+    fn same_place(&self, other: &NavState) -> bool {
+        if self.view != other.view || self.search_query.is_some() != other.search_query.is_some() {
+            return false;
+        }
+        match self.view {
+            View::FeedItems => self.selected_feed == other.selected_feed,
+            View::FeedItemDetail => self.item == other.item,
+            _ => true,
+        }
+    }
+}
+
+/// A configured dashboard section with its current entries.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DashboardSectionView {
+    pub title: String,
+    pub kind: SectionKind,
+    pub items: Vec<(usize, usize)>, // (feed_idx, item_idx); for stale feeds, their newest item
+}
+
+/// New items per feed found by a refresh, shown in a popup afterwards.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RefreshDiff {
+    pub entries: Vec<RefreshDiffEntry>,
+    pub selected: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RefreshDiffEntry {
+    pub feed_url: String,
+    pub feed_title: String,
+    pub new_items: usize,
+    first_new_id: String,
+}
+
+impl RefreshDiff {
+    pub fn total(&self) -> usize {
+        self.entries.iter().map(|e| e.new_items).sum()
+    }
+}
+
+/// Where to move an entry in the read-later queue.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QueueMove {
+    Top,
+    Up,
+    Down,
+    Bottom,
+}
+
+/// A time-boxed reading session: unread items picked to fit a reading-time budget,
+/// shown in the detail view one after another.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReadingSession {
+    pub queue: Vec<(usize, usize)>,
+    pub pos: usize,
+    /// Minutes the session was asked to fit in
+    pub minutes: usize,
+}
+
+/// What to do with the item shown in triage mode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TriageAction {
+    ReadLater,
+    Star,
+    Open,
+    MarkRead,
+    Skip,
+    MuteSource,
+}
+
+/// What the detail view shows of an article.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum DetailSource {
+    /// The article as text, the usual reading view
+    #[default]
+    Rendered,
+    /// The HTML the text is rendered from
+    Html,
+    /// The entry as it appears in the feed document (XML element or JSON Feed object)
+    Entry(String),
 }
 
 #[derive(Clone, Debug)]
@@ -85,20 +265,81 @@ pub struct App {
     pub search_query: String,
     pub is_searching: bool,
     pub filtered_items: Vec<(usize, usize)>, // (feed_idx, item_idx) for search results
+    search_visited: HashSet<String>,         // Item IDs opened from search results this session
     pub dashboard_items: Vec<(usize, usize)>, // (feed_idx, item_idx) for dashboard
     pub is_loading: bool,                    // Flag to indicate loading/refreshing state
     pub loading_indicator: usize,            // For animated loading indicator
     pub filter_options: FilterOptions,
-    pub filter_mode: bool,           // Whether we're in filter selection mode
+    pub item_sort: ItemSort, // Sort order of the dashboard or open feed's items
+    pub view_states: HashMap<String, ViewState>, // Filters and sort per feed/category scope
+    filter_scope: String,    // Scope whose state is live in filter_options and item_sort
+    dashboard_scope: String, // Dashboard scope to return to after browsing a feed
+    pub filter_mode: bool,   // Whether we're in filter selection mode
     pub read_items: HashSet<String>, // Track read item IDs
     pub starred_items: HashSet<String>, // Track starred item IDs
+    pub alerts: Vec<crate::alerts::Alert>, // Compiled alert rules from config
+    pub alert_items: HashMap<String, String>, // Item ID -> name of the alert it matched
+    pub read_later: Vec<String>, // Read-later queue of item IDs, in reading order
+    pub muted_feeds: HashSet<String>, // Feed URLs whose items are hidden from the dashboard
+    pub muted_authors: HashSet<String>, // Lowercased author names hidden from the dashboard
+    pub muted_domains: HashSet<String>, // Link domains (and their subdomains) hidden likewise
+    pub mute_menu: Option<(usize, usize)>, // Item the "mute author/domain" menu is open for
+    pub webhook_menu: Option<(usize, usize)>, // Item the webhook picker is open for
+    pub context_menu: Option<ContextMenu>, // Actions offered for the selected item
+    pub paused_feeds: HashSet<String>, // Feed URLs left out of scheduled refreshes
+    pub engagement: HashMap<String, FeedEngagement>, // Feed URL -> how its items were handled
+    pub item_opens: HashMap<String, u32>, // Item ID -> times opened
+    pub item_tags: HashMap<String, Vec<String>>, // Item ID -> tags the user confirmed
+    pub reading_time: HashMap<String, u64>, // Local date (YYYY-MM-DD) -> ms spent in the detail view
+    reading_tick: Option<Instant>,          // Last tick counted toward reading_time
+    auto_theme_last: Option<crate::config::Theme>, // Theme ui.auto_theme last switched to
+    pub suggested_tags: HashMap<String, Vec<String>>, // Item ID -> tags picked from its text
+    pub feed_titles: HashMap<String, String>, // Feed URL -> title chosen by the user
+    pub archived_feeds: HashSet<String>, // Unsubscribed feeds kept, with their items, for browsing
+    pub archive_expanded: bool,          // Whether the feed list shows the archived feeds
+    pub triage_queue: Vec<(usize, usize)>, // Unread items being triaged, newest first
+    pub triage_pos: usize,               // Index of the item being triaged
+    pub reading_session: Option<ReadingSession>, // Time-boxed session being read, if any
+    pub macro_recording: Option<Vec<crossterm::event::KeyEvent>>, // Keys recorded so far, while recording
+    pub recorded_macro: Vec<crossterm::event::KeyEvent>, // Last recorded macro, replayed with @
+    pub refresh_diff: Option<RefreshDiff>, // New items from the last refresh, while the popup is open
+    refresh_baseline: Option<HashSet<String>>, // Item IDs loaded before the running refresh
+    selection_anchor: Option<(String, String)>, // (feed URL, item ID) to reselect once it's reloaded
     pub filtered_dashboard_items: Vec<(usize, usize)>, // Filtered items for dashboard
+    pub dashboard_sections: Vec<DashboardSectionView>, // Configured dashboard blocks, if any
+    pub focused_section: usize,                 // Section the dashboard cursor is in
     pub category_action: Option<CategoryAction>, // For category management
-    pub detail_vertical_scroll: u16, // Vertical scroll value for item detail view
-    pub detail_max_scroll: u16,      // Maximum scroll value for current content
+    pub detail_vertical_scroll: u16,            // Vertical scroll value for item detail view
+    pub detail_max_scroll: u16,                 // Maximum scroll value for current content
+    pub detail_no_wrap: bool, // Show the current article unwrapped, scrolling sideways
+    pub zen_mode: bool,       // Show only the article, centered at ui.reading_width
+    pub detail_horizontal_scroll: u16, // Horizontal scroll when not wrapping
+    pub detail_max_horizontal_scroll: u16, // Widest line minus the content width
+    pub detail_footnote_rows: Vec<(u16, u16)>, // (reference row, footnote row) per footnote
+    pub detail_footnote_return: Option<(u16, u16)>, // (row to return to, row jumped to)
+    pub detail_return: Option<(View, Option<usize>)>, // List and selection the detail view was opened from
+    nav_back: Vec<NavState>,                          // Places to go back to, most recent last
+    nav_forward: Vec<NavState>,                       // Places undone by going back
+    history_moved: bool, // Set by back/forward so the move itself isn't recorded
     pub last_refresh: Option<Instant>, // Track when last refresh occurred
-    pub refresh_in_progress: bool,   // Prevent concurrent refreshes
-    pub refresh_requested: bool,     // Signal to main loop to start a non-blocking refresh
+    pub refresh_started: Option<Instant>, // When the running or last refresh began, for metrics
+    pub refresh_in_progress: bool, // Prevent concurrent refreshes
+    pub refresh_requested: bool, // Signal to main loop to start a non-blocking refresh
+    pub refresh_feed_requested: Option<String>, // Signal to refresh just this feed URL
+    pub scheduled_refresh: Option<Vec<String>>, // Signal to refresh the feeds whose schedule came due
+    pub show_feed_info: bool,                   // Whether the feed info popup is visible
+    pub show_item_explanation: bool,            // Whether the "why is this here" popup is visible
+    pub show_full_title: bool,                  // Whether the full title popup is visible
+    pub tour_step: Option<usize>,               // Step of the onboarding tour being shown
+    pub first_launch: bool,                     // No saved data existed at startup
+    pub bulk_add_report: Option<ImportReport>,  // Results of adding a pasted list of feeds
+    pub failed_feeds: Vec<(String, String)>,    // (url, error) for bookmarks that failed to load
+    pub fetch_summaries: HashMap<String, String>, // url -> how its last fetch went
+    pub fetch_durations: HashMap<String, Duration>, // url -> how long its last fetch took
+    pub failed_feeds_dismissed: bool, // Hide the failed-feeds banner until the next failure
+    pub show_failed_feeds: bool,      // Whether the failed-feeds popup is open
+    pub show_quit_prompt: bool,       // Whether quitting waits on the pending-work prompt
+    pub retry_failed_requested: bool, // Signal to re-fetch just the failed feeds
     pub last_domain_fetch: HashMap<String, Instant>, // Track last fetch time per domain for rate limiting
     pub color_scheme: ColorScheme, // Cached color scheme to avoid per-frame construction
     pub last_session_time: Option<DateTime<Utc>>, // When the previous session started
@@ -108,17 +349,57 @@ pub struct App {
     pub preview_max_scroll: u16,   // Maximum scroll for preview content
     pub feed_headers: HashMap<String, HashMap<String, String>>, // Per-URL custom HTTP headers
     pub compact: bool,             // Whether compact mode is active
+    pub narrow: bool,              // Whether the terminal is too narrow for snippets and long dates
     pub discovered_feeds: Vec<crate::feed::DiscoveredFeed>, // Feeds discovered from HTML page
     pub discovered_feed_selection: usize, // Selected index in discovered feeds list
+    pub feed_preview: Option<FeedPreview>, // Feed fetched but not yet subscribed to
     pub feed_refresh_intervals: HashMap<String, u64>, // url -> per-feed refresh interval in seconds
-    pub last_feed_refresh: HashMap<String, Instant>, // url -> last refresh time
-    pub show_help_overlay: bool,   // Whether the help overlay is visible
-    pub help_overlay_scroll: u16,  // Scroll position in the help overlay
+    pub feed_max_items: HashMap<String, usize>, // url -> newest items kept from that feed
+    pub feed_date_formats: HashMap<String, Vec<String>>, // url -> formats of dates in item text
+    pub dashboard_hidden_feeds: HashSet<String>, // Feed URLs configured with hide_from_dashboard
+    pub feed_display_rules: HashMap<String, DisplayRules>, // url -> per-feed content display rules
+    pub feed_strip_rules: HashMap<String, StripRules>, // url -> that feed's compiled strip rules
+    pub feed_open_targets: HashMap<String, OpenTarget>, // url -> what Enter opens that feed's items in
+    pub feed_notify: HashMap<String, NotifyLevel>, // url -> which of that feed's new items notify
+    full_text_cache: Arc<Mutex<HashMap<String, Option<String>>>>, // link -> fetched page (None while pending)
+    display_cache: Arc<Mutex<HashMap<String, (String, String)>>>, // item id -> HTML before and after strip rules
+    image_art_cache: Arc<Mutex<HashMap<String, Option<ImageArt>>>>, // image URL -> block art (None while pending or failed)
+    pub last_feed_refresh: HashMap<String, Instant>,                // url -> last refresh time
+    pub show_help_overlay: bool,  // Whether the help overlay is visible
+    pub help_overlay_scroll: u16, // Scroll position in the help overlay
     pub extracted_links: Vec<ExtractedLink>,
     pub show_link_overlay: bool,
     pub selected_link: usize,
     pub feed_tree: Vec<TreeItem>,
     pub selected_tree_item: Option<usize>, // index into feed_tree
+    pub feed_sort: FeedSortMode,
+    pub copy_mode: Option<CopyMode>, // Some while selecting text in the detail view
+    pub show_translation: bool,      // Whether the detail view shows the article's translation
+    pub detail_source: DetailSource, // Rendered text, HTML or feed entry in the detail view
+    translations: Arc<Mutex<HashMap<String, ToolOutput>>>, // item id -> translation
+    pub show_summary_popup: bool,    // Whether the summarizer popup is visible
+    pub summary_popup_scroll: u16,   // Scroll position in the summarizer popup
+    article_summaries: Arc<Mutex<HashMap<String, ToolOutput>>>, // item id -> summary
+    #[cfg(feature = "scripting")]
+    status_strip: Arc<Mutex<Option<String>>>, // latest output of ui.status_command
+    #[cfg(feature = "scripting")]
+    status_strip_run: Option<Instant>, // when ui.status_command last started
+    pub collection_syncs: HashMap<String, i64>, // Collection OPML URL -> Unix time of its last sync
+    pub feed_meta: HashMap<String, FeedMeta>, // Feed URL -> details it published on its last refresh
+    pub feed_changes: Vec<FeedChange>,        // Metadata changes waiting to be shown, oldest first
+    #[cfg(feature = "sync")]
+    pub state_log: StateLog, // Versioned read/star changes for sync backends
+    pub recent_paths: Vec<String>,            // Paths given to the path dialogs, newest first
+    pub path_input: Option<crate::path_input::PathInput>, // Path typed into the open path dialog
+    edit_history: EditHistory,                // Category and subscription edits, for undo/redo
+    #[cfg(feature = "sync")]
+    collection_attempts: HashMap<String, Instant>, // When each collection was last fetched this session
+    #[cfg(feature = "sync")]
+    collection_results: Arc<Mutex<Vec<CollectionFetch>>>, // Fetched collection feed lists
+    pub unsaved_since: Option<Instant>, // set when read/star state changed but isn't on disk yet
+    save_generation: u64,
+    save_lock: Arc<Mutex<u64>>, // generation of the last snapshot written to disk
+    storage: Arc<dyn Storage>,  // Where saved data is loaded from and written to
     pub keybindings: crate::keybindings::KeyBindingMap,
 }
 
@@ -135,10 +416,153 @@ pub struct ExtractedLink {
     pub link_type: LinkType,
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FeedSortMode {
+    #[default]
+    Bookmarked, // order feeds were added in
+    Alphabetical,
+    MostUnread,
+    RecentlyUpdated,
+    LeastEngaged, // feeds whose items are least often opened first
+}
+
+impl FeedSortMode {
+    pub fn next(self) -> Self {
+        match self {
+            FeedSortMode::Bookmarked => FeedSortMode::Alphabetical,
+            FeedSortMode::Alphabetical => FeedSortMode::MostUnread,
+            FeedSortMode::MostUnread => FeedSortMode::RecentlyUpdated,
+            FeedSortMode::RecentlyUpdated => FeedSortMode::LeastEngaged,
+            FeedSortMode::LeastEngaged => FeedSortMode::Bookmarked,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            FeedSortMode::Bookmarked => "added",
+            FeedSortMode::Alphabetical => "A-Z",
+            FeedSortMode::MostUnread => "unread",
+            FeedSortMode::RecentlyUpdated => "recent",
+            FeedSortMode::LeastEngaged => "engagement",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SelectionKind {
+    Lines,
+    Block,
+}
+
+/// Cursor and selection state for copy mode in the detail view.
+#[derive(Clone, Debug)]
+pub struct CopyMode {
+    pub lines: Vec<String>,
+    pub line: usize,
+    pub col: usize,                                    // in chars
+    pub anchor: Option<(usize, usize, SelectionKind)>, // selection start (line, col)
+}
+
+impl CopyMode {
+    pub fn new(text: &str, first_line: usize) -> Self {
+        let lines: Vec<String> = text.lines().map(str::to_string).collect();
+        let line = first_line.min(lines.len().saturating_sub(1));
+        Self {
+            lines,
+            line,
+            col: 0,
+            anchor: None,
+        }
+    }
+
+    fn line_len(&self, line: usize) -> usize {
+        self.lines.get(line).map_or(0, |l| l.chars().count())
+    }
+
+    pub fn move_lines(&mut self, delta: isize) {
+        let last = self.lines.len().saturating_sub(1) as isize;
+        self.line = (self.line as isize + delta).clamp(0, last) as usize;
+        // Block selections may span past the end of short lines
+        if !matches!(self.anchor, Some((_, _, SelectionKind::Block))) {
+            self.col = self.col.min(self.line_len(self.line).saturating_sub(1));
+        }
+    }
+
+    pub fn move_cols(&mut self, delta: isize) {
+        let max = match self.anchor {
+            Some((_, _, SelectionKind::Block)) => isize::MAX,
+            _ => self.line_len(self.line).saturating_sub(1) as isize,
+        };
+        self.col = (self.col as isize + delta).clamp(0, max) as usize;
+    }
+
+    pub fn line_start(&mut self) {
+        self.col = 0;
+    }
+
+    pub fn line_end(&mut self) {
+        self.col = self.line_len(self.line).saturating_sub(1);
+    }
+
+    /// Start a selection of the given kind at the cursor, or clear it if one of that kind is active.
+    pub fn toggle_selection(&mut self, kind: SelectionKind) {
+        self.anchor = match self.anchor {
+            Some((_, _, active)) if active == kind => None,
+            _ => Some((self.line, self.col, kind)),
+        };
+    }
+
+    /// The selected char range `start..end` on `line`, if any of it is selected.
+    pub fn selected_cols(&self, line: usize) -> Option<(usize, usize)> {
+        let (anchor_line, anchor_col, kind) = self.anchor?;
+        if line < anchor_line.min(self.line) || line > anchor_line.max(self.line) {
+            return None;
+        }
+        match kind {
+            SelectionKind::Lines => Some((0, self.line_len(line))),
+            SelectionKind::Block => Some((anchor_col.min(self.col), anchor_col.max(self.col) + 1)),
+        }
+    }
+
+    /// Text to yank: the selection, or the cursor line when nothing is selected.
+    pub fn selected_text(&self) -> String {
+        let range = match self.anchor {
+            Some((anchor_line, _, _)) => anchor_line.min(self.line)..=anchor_line.max(self.line),
+            None => self.line..=self.line,
+        };
+        range
+            .map(|line| {
+                let text = self.lines.get(line).map_or("", String::as_str);
+                match self.selected_cols(line) {
+                    Some((start, end)) => text
+                        .chars()
+                        .skip(start)
+                        .take(end - start)
+                        .collect::<String>(),
+                    None => text.to_string(),
+                }
+                .trim_end()
+                .to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Result of piping an article through an external tool, cached per item.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ToolOutput {
+    Pending,
+    Done(String),
+    Failed(String),
+}
+
 #[derive(Clone, Debug)]
 pub enum TreeItem {
-    Category(usize),            // index into self.categories
-    Feed(usize, Option<usize>), // feed index, optional parent category index
+    Category(usize),                // index into self.categories
+    Feed(usize, Option<usize>),     // feed index, optional parent category index
+    Archive,                        // "Archived feeds" heading above the archived feeds
+    Pending(String, Option<usize>), // bookmark URL still being fetched, optional parent category
 }
 
 #[derive(Clone, Debug)]
@@ -148,6 +572,77 @@ pub enum CategoryAction {
     AddFeedToCategory(String), // Feed URL to add
 }
 
+/// What an entry of the item context menu does.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MenuCommand {
+    /// The same as pressing the action's key in the current view
+    Key(crate::keybindings::KeyAction),
+    ToggleReadLater,
+    CopyLink,
+    ToggleFeedMuted,
+}
+
+/// The context menu of a list item or article: every action that applies to it, so
+/// they can be found without knowing their keys.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ContextMenu {
+    pub feed_idx: usize,
+    pub item_idx: usize,
+    pub entries: Vec<(MenuCommand, String)>,
+    pub selected: usize,
+}
+
+// A collection's OPML URL and the feed URLs it listed, or why fetching it failed
+#[cfg(feature = "sync")]
+type CollectionFetch = (String, Result<Vec<String>, String>);
+
+/// What a feed says about itself, remembered between refreshes to notice changes.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FeedMeta {
+    pub title: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl FeedMeta {
+    fn of(feed: &Feed) -> Self {
+        Self {
+            title: feed.title.clone(),
+            link: feed.link.clone(),
+            description: feed.description.clone(),
+        }
+    }
+}
+
+/// A feed whose title, site link or description changed since an earlier refresh.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeedChange {
+    pub url: String,
+    pub old: FeedMeta,
+    pub new: FeedMeta,
+}
+
+/// Per-URL outcome of adding a list of feeds, or of checking an OPML file without
+/// changing anything.
+#[derive(Debug, Default, PartialEq)]
+pub struct ImportReport {
+    /// (URL, feed title) of valid feeds that were (or would be) added
+    pub new: Vec<(String, String)>,
+    /// URLs already subscribed, or listed more than once in the file
+    pub duplicates: Vec<String>,
+    /// (URL, reason) of entries that don't fetch or parse as a feed
+    pub failed: Vec<(String, String)>,
+}
+
+/// A fetched feed shown to the user before they subscribe to it.
+pub struct FeedPreview {
+    pub url: String,
+    pub feed: Feed,
+    pub category: Option<usize>, // Category to file the feed under when subscribing
+}
+
 pub enum AddFeedResult {
     Added,
     DiscoveredFeeds {
@@ -156,14 +651,106 @@ pub enum AddFeedResult {
     },
 }
 
-#[derive(Serialize, Deserialize)]
-struct SavedData {
+/// Scope key for the dashboard's filters and sort order when no category is picked.
+const DASHBOARD_SCOPE: &str = "all";
+
+/// How long read/star changes sit in memory before being written to disk.
+const SAVE_DEBOUNCE: Duration = Duration::from_secs(2);
+
+/// Bounds on adaptive refresh intervals, in seconds. The lower bound is
+/// `auto_refresh_interval` instead when that's set.
+const ADAPTIVE_MIN_INTERVAL: u64 = 15 * 60;
+const ADAPTIVE_MAX_INTERVAL: u64 = 24 * 60 * 60;
+/// Newest posts looked at to work out how often a feed posts.
+const ADAPTIVE_SAMPLE_POSTS: usize = 10;
+
+/// Largest block art drawn for a comic feed's image, in cells.
+const COMIC_ART_WIDTH: u16 = 120;
+const COMIC_ART_HEIGHT: u16 = 48;
+/// Extra catch-up weight for feeds the user has starred items from; every feed starts at 1
+/// and a fully engaged feed adds 4.
+const CATCH_UP_STARRED_BONUS: u64 = 4;
+/// Terminals narrower than this, in columns, drop item snippets and shorten dates.
+const NARROW_WIDTH: u16 = 80;
+
+/// Where a feed's refresh interval comes from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IntervalSource {
+    /// `refresh_interval` on the feed's `[[default_feeds]]` entry
+    Feed,
+    /// How often the feed posts (`general.adaptive_refresh`)
+    Adaptive,
+    /// `general.auto_refresh_interval`
+    Global,
+}
+
+impl IntervalSource {
+    pub fn label(self) -> &'static str {
+        match self {
+            IntervalSource::Feed => "feed",
+            IntervalSource::Adaptive => "adaptive",
+            IntervalSource::Global => "global",
+        }
+    }
+}
+
+/// When a feed is next refreshed automatically, and why, for the Scheduler view.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeedSchedule {
+    pub url: String,
+    /// Seconds between refreshes and where that comes from; None if only refreshed by hand
+    pub interval: Option<(u64, IntervalSource)>,
+    /// Time left until the next refresh (zero once it's due); None if paused or unscheduled
+    pub due_in: Option<Duration>,
+    /// How long the last fetch took
+    pub last_duration: Option<Duration>,
+    pub paused: bool,
+}
+
+/// Everything kept between runs, as handed to and from the [`Storage`] backend.
+#[derive(Default, Serialize, Deserialize)]
+pub struct SavedData {
     bookmarks: Vec<String>,
     categories: Vec<FeedCategory>,
     read_items: HashSet<String>,
     #[serde(default)]
     starred_items: HashSet<String>,
     #[serde(default)]
+    alert_items: HashMap<String, String>,
+    #[serde(default)]
+    read_later: Vec<String>,
+    #[serde(default)]
+    muted_feeds: HashSet<String>,
+    #[serde(default)]
+    muted_authors: HashSet<String>,
+    #[serde(default)]
+    muted_domains: HashSet<String>,
+    #[serde(default)]
+    feed_titles: HashMap<String, String>,
+    #[serde(default)]
+    view_states: HashMap<String, ViewState>,
+    #[serde(default)]
+    archived_feeds: Vec<Feed>,
+    #[serde(default)]
+    paused_feeds: HashSet<String>,
+    #[serde(default)]
+    engagement: HashMap<String, FeedEngagement>,
+    #[serde(default)]
+    item_opens: HashMap<String, u32>,
+    #[serde(default)]
+    item_tags: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    reading_time: HashMap<String, u64>,
+    #[serde(default)]
+    collection_syncs: HashMap<String, i64>,
+    #[serde(default)]
+    feed_meta: HashMap<String, FeedMeta>,
+    #[cfg(feature = "sync")]
+    #[serde(default)]
+    state_log: StateLog,
+    #[serde(default)]
+    recent_paths: Vec<String>,
+    #[serde(default)]
     last_session_time: Option<String>,
 }
 
@@ -181,13 +768,11 @@ impl App {
             Config::default()
         });
 
-        let saved_data = Self::load_saved_data().unwrap_or_else(|_| SavedData {
-            bookmarks: vec![],
-            categories: vec![],
-            read_items: HashSet::new(),
-            starred_items: HashSet::new(),
-            last_session_time: None,
-        });
+        let storage = Self::open_storage(&config);
+        let loaded = storage.load();
+        // Nothing saved yet, rather than unreadable data, means this is the first run
+        let first_launch = matches!(loaded, Ok(None));
+        let saved_data = loaded.ok().flatten().unwrap_or_default();
 
         // Seed bookmarks from default_feeds if no saved bookmarks exist
         let mut bookmarks = saved_data.bookmarks;
@@ -196,6 +781,15 @@ impl App {
         }
 
         let has_bookmarks = !bookmarks.is_empty();
+        // Archived feeds aren't fetched; they come back from the data file as they were
+        let mut archived = saved_data.archived_feeds;
+        archived.iter_mut().for_each(Feed::restore_derived_fields);
+        let archived_feeds: HashSet<String> = archived.iter().map(|f| f.url.clone()).collect();
+        let dashboard_state = saved_data
+            .view_states
+            .get(DASHBOARD_SCOPE)
+            .cloned()
+            .unwrap_or_default();
         let color_scheme = ColorScheme::from_theme(&config.ui.theme);
 
         // Build per-URL headers lookup from config
@@ -212,6 +806,65 @@ impl App {
             .filter_map(|f| f.refresh_interval.map(|interval| (f.url.clone(), interval)))
             .collect();
 
+        // Build per-feed item limits from config
+        let feed_max_items: HashMap<String, usize> = config
+            .default_feeds
+            .iter()
+            .filter_map(|f| f.max_items.map(|max| (f.url.clone(), max)))
+            .collect();
+
+        // Per-feed formats of the dates undated items mention in their text
+        let feed_date_formats: HashMap<String, Vec<String>> = config
+            .default_feeds
+            .iter()
+            .filter(|f| !f.date_formats.is_empty())
+            .map(|f| (f.url.clone(), f.date_formats.clone()))
+            .collect();
+
+        // Feeds configured to stay off the dashboard
+        let dashboard_hidden_feeds: HashSet<String> = config
+            .default_feeds
+            .iter()
+            .filter(|f| f.hide_from_dashboard)
+            .map(|f| f.url.clone())
+            .collect();
+
+        // Build per-feed display rules from config
+        let feed_display_rules: HashMap<String, DisplayRules> = config
+            .default_feeds
+            .iter()
+            .filter_map(|f| {
+                f.display
+                    .as_ref()
+                    .map(|rules| (f.url.clone(), rules.clone()))
+            })
+            .collect();
+        // Feeds whose items open somewhere other than general.open_target
+        let feed_open_targets: HashMap<String, OpenTarget> = config
+            .default_feeds
+            .iter()
+            .filter_map(|f| f.open.map(|open| (f.url.clone(), open)))
+            .collect();
+        // Feeds notifying about more or less than general.notify says
+        let feed_notify: HashMap<String, NotifyLevel> = config
+            .default_feeds
+            .iter()
+            .filter_map(|f| f.notify.map(|level| (f.url.clone(), level)))
+            .collect();
+        let mut feed_strip_rules = HashMap::new();
+        let mut rule_warnings = Vec::new();
+        for (url, rules) in &feed_display_rules {
+            let (strip, warnings) = crate::display_rules::compile(rules);
+            feed_strip_rules.insert(url.clone(), strip);
+            rule_warnings.extend(
+                warnings
+                    .into_iter()
+                    .map(|problem| format!("{}: {}", url, problem)),
+            );
+        }
+
+        let (alerts, alert_warnings) = crate::alerts::compile(&config.alerts);
+
         // Parse last session time from saved data
         let last_session_time = saved_data
             .last_session_time
@@ -225,7 +878,7 @@ impl App {
 
         let mut app = Self {
             config,
-            feeds: Vec::new(),
+            feeds: archived,
             bookmarks,
             categories: saved_data.categories,
             selected_category: None,
@@ -240,20 +893,81 @@ impl App {
             search_query: String::new(),
             is_searching: false,
             filtered_items: Vec::new(),
+            search_visited: HashSet::new(),
             dashboard_items: Vec::new(),
             is_loading: has_bookmarks,
             loading_indicator: 0,
-            filter_options: FilterOptions::new(),
+            filter_options: dashboard_state.filters,
+            item_sort: dashboard_state.sort,
+            view_states: saved_data.view_states,
+            filter_scope: DASHBOARD_SCOPE.to_string(),
+            dashboard_scope: DASHBOARD_SCOPE.to_string(),
             filter_mode: false,
             read_items: saved_data.read_items,
             starred_items: saved_data.starred_items,
+            alerts,
+            alert_items: saved_data.alert_items,
+            read_later: saved_data.read_later,
+            muted_feeds: saved_data.muted_feeds,
+            muted_authors: saved_data.muted_authors,
+            muted_domains: saved_data.muted_domains,
+            mute_menu: None,
+            webhook_menu: None,
+            context_menu: None,
+            paused_feeds: saved_data.paused_feeds,
+            engagement: saved_data.engagement,
+            item_opens: saved_data.item_opens,
+            item_tags: saved_data.item_tags,
+            reading_time: saved_data.reading_time,
+            reading_tick: None,
+            auto_theme_last: None,
+            suggested_tags: HashMap::new(),
+            feed_titles: saved_data.feed_titles,
+            archived_feeds,
+            archive_expanded: true,
+            triage_queue: Vec::new(),
+            triage_pos: 0,
+            reading_session: None,
+            macro_recording: None,
+            recorded_macro: Vec::new(),
+            refresh_diff: None,
+            refresh_baseline: None,
+            selection_anchor: None,
             filtered_dashboard_items: Vec::new(),
+            dashboard_sections: Vec::new(),
+            focused_section: 0,
             category_action: None,
             detail_vertical_scroll: 0,
             detail_max_scroll: 0,
+            detail_no_wrap: false,
+            zen_mode: false,
+            detail_horizontal_scroll: 0,
+            detail_max_horizontal_scroll: 0,
+            detail_footnote_rows: Vec::new(),
+            detail_footnote_return: None,
+            detail_return: None,
+            nav_back: Vec::new(),
+            nav_forward: Vec::new(),
+            history_moved: false,
             last_refresh: None,
+            refresh_started: None,
             refresh_in_progress: false,
             refresh_requested: false,
+            refresh_feed_requested: None,
+            scheduled_refresh: None,
+            show_feed_info: false,
+            show_item_explanation: false,
+            show_full_title: false,
+            tour_step: None,
+            first_launch,
+            bulk_add_report: None,
+            failed_feeds: Vec::new(),
+            fetch_summaries: HashMap::new(),
+            fetch_durations: HashMap::new(),
+            failed_feeds_dismissed: false,
+            show_failed_feeds: false,
+            show_quit_prompt: false,
+            retry_failed_requested: false,
             last_domain_fetch: HashMap::new(),
             color_scheme,
             last_session_time,
@@ -263,9 +977,21 @@ impl App {
             preview_max_scroll: 0,
             feed_headers,
             compact: false,
+            narrow: false,
             discovered_feeds: Vec::new(),
             discovered_feed_selection: 0,
+            feed_preview: None,
             feed_refresh_intervals,
+            feed_max_items,
+            feed_date_formats,
+            dashboard_hidden_feeds,
+            feed_display_rules,
+            feed_strip_rules,
+            feed_open_targets,
+            feed_notify,
+            full_text_cache: Arc::new(Mutex::new(HashMap::new())),
+            display_cache: Arc::new(Mutex::new(HashMap::new())),
+            image_art_cache: Arc::new(Mutex::new(HashMap::new())),
             last_feed_refresh: HashMap::new(),
             show_help_overlay: false,
             help_overlay_scroll: 0,
@@ -274,12 +1000,46 @@ impl App {
             selected_link: 0,
             feed_tree: Vec::new(),
             selected_tree_item: None,
+            feed_sort: FeedSortMode::default(),
+            copy_mode: None,
+            show_translation: false,
+            detail_source: DetailSource::Rendered,
+            translations: Arc::new(Mutex::new(HashMap::new())),
+            show_summary_popup: false,
+            summary_popup_scroll: 0,
+            article_summaries: Arc::new(Mutex::new(HashMap::new())),
+            #[cfg(feature = "scripting")]
+            status_strip: Arc::new(Mutex::new(None)),
+            #[cfg(feature = "scripting")]
+            status_strip_run: None,
+            collection_syncs: saved_data.collection_syncs,
+            feed_meta: saved_data.feed_meta,
+            feed_changes: Vec::new(),
+            #[cfg(feature = "sync")]
+            state_log: saved_data.state_log,
+            recent_paths: saved_data.recent_paths,
+            path_input: None,
+            edit_history: EditHistory::default(),
+            #[cfg(feature = "sync")]
+            collection_attempts: HashMap::new(),
+            #[cfg(feature = "sync")]
+            collection_results: Arc::new(Mutex::new(Vec::new())),
+            unsaved_since: None,
+            save_generation: 0,
+            save_lock: Arc::new(Mutex::new(0)),
+            storage,
             keybindings,
         };
 
         app.update_dashboard();
         app.rebuild_feed_tree();
 
+        if !rule_warnings.is_empty() {
+            app.error = Some(format!("Display rules: {}", rule_warnings.join("; ")));
+        }
+        if !alert_warnings.is_empty() {
+            app.error = Some(format!("Alerts: {}", alert_warnings.join("; ")));
+        }
         if !kb_warnings.is_empty() {
             app.error = Some(format!("Keybinding config: {}", kb_warnings.join("; ")));
         }
@@ -299,24 +1059,25 @@ impl App {
         }
     }
 
-    pub fn update_compact_mode(&mut self, terminal_height: u16) {
+    /// Pick the layout for the terminal size: compact below 31 rows, narrow below
+    /// `NARROW_WIDTH` columns. `ui.compact_mode = "never"` turns off both.
+    pub fn update_compact_mode(&mut self, terminal_width: u16, terminal_height: u16) {
         self.compact = match self.config.ui.compact_mode {
             CompactMode::Always => true,
             CompactMode::Never => false,
             CompactMode::Auto => terminal_height <= 30,
         };
+        self.narrow =
+            self.config.ui.compact_mode != CompactMode::Never && terminal_width < NARROW_WIDTH;
     }
 
     pub fn load_bookmarked_feeds(&mut self) {
-        self.feeds.clear();
+        self.clear_subscribed_feeds();
         if self.bookmarks.is_empty() {
             return;
         }
 
-        let timeout = self.config.network.http_timeout;
-        let user_agent = self.config.network.user_agent.clone();
-
-        let client = match Feed::build_client(timeout) {
+        let client = match self.http_client() {
             Ok(c) => c,
             Err(_) => return,
         };
@@ -328,61 +1089,190 @@ impl App {
             .map(|url| {
                 let client = client.clone();
                 let url = url.clone();
-                let ua = user_agent.clone();
-                let hdrs = self.feed_headers.get(&url).cloned();
+                let options = self.request_options(&url);
                 std::thread::spawn(move || {
-                    Feed::fetch_url(&url, &client, Some(&ua), hdrs.as_ref())
-                        .and_then(|r| r.into_feed())
+                    Feed::fetch_url(&url, &client, &options).and_then(|r| r.into_feed())
                 })
             })
             .collect();
 
         // Collect results in original bookmark order
         for handle in handles {
-            if let Ok(Ok(feed)) = handle.join() {
+            if let Ok(Ok(mut feed)) = handle.join() {
+                self.apply_feed_settings(&mut feed);
                 self.feeds.push(feed);
             }
         }
     }
 
-    fn load_saved_data() -> Result<SavedData> {
-        let path = Self::data_path();
-        if !path.exists() {
-            return Ok(SavedData {
-                bookmarks: Vec::new(),
-                categories: Vec::new(),
-                read_items: HashSet::new(),
-                starred_items: HashSet::new(),
-                last_session_time: None,
-            });
+    /// The storage backend `general.storage` picks, falling back to the JSON file when it
+    /// can't be opened.
+    fn open_storage(config: &Config) -> Arc<dyn Storage> {
+        match crate::storage::open(config.general.storage, Self::data_path()) {
+            Ok(storage) => Arc::from(storage),
+            Err(e) => {
+                eprintln!("Warning: {}, using the JSON data file", e);
+                Arc::new(JsonStorage::new(Self::data_path()))
+            }
         }
-
-        let data = fs::read_to_string(path)?;
-        let saved_data: SavedData = serde_json::from_str(&data)?;
-        Ok(saved_data)
     }
 
     pub fn save_data(&self) -> Result<()> {
-        let path = Self::data_path();
-        if let Some(dir) = path.parent() {
-            fs::create_dir_all(dir)?;
-        }
+        let data = self.saved_data();
+        // Hold the lock so an older background snapshot can't land after this one
+        let mut written = self.save_lock.lock().unwrap_or_else(|e| e.into_inner());
+        Self::write_data(self.storage.as_ref(), &data)?;
+        *written = self.save_generation;
+        Ok(())
+    }
 
-        let saved_data = SavedData {
+    fn saved_data(&self) -> SavedData {
+        SavedData {
             bookmarks: self.bookmarks.clone(),
             categories: self.categories.clone(),
             read_items: self.read_items.clone(),
             starred_items: self.starred_items.clone(),
+            alert_items: self.alert_items.clone(),
+            read_later: self.read_later.clone(),
+            muted_feeds: self.muted_feeds.clone(),
+            muted_authors: self.muted_authors.clone(),
+            muted_domains: self.muted_domains.clone(),
+            feed_titles: self.feed_titles.clone(),
+            view_states: self.view_states.clone(),
+            archived_feeds: self
+                .feeds
+                .iter()
+                .filter(|feed| self.archived_feeds.contains(&feed.url))
+                .cloned()
+                .collect(),
+            paused_feeds: self.paused_feeds.clone(),
+            engagement: self.engagement.clone(),
+            item_opens: self.item_opens.clone(),
+            item_tags: self.item_tags.clone(),
+            reading_time: self.reading_time.clone(),
+            collection_syncs: self.collection_syncs.clone(),
+            feed_meta: self.feed_meta.clone(),
+            #[cfg(feature = "sync")]
+            state_log: self.compacted_state_log(),
+            recent_paths: self.recent_paths.clone(),
             last_session_time: Some(Utc::now().to_rfc3339()),
-        };
+        }
+    }
 
-        let json = serde_json::to_string(&saved_data)?;
-        fs::write(path, json)?;
-        Ok(())
+    /// The state log without the entries no sync backend needs any more: ones every
+    /// backend has seen, and ones for items that are neither loaded nor read or starred.
+    #[cfg(feature = "sync")]
+    fn compacted_state_log(&self) -> StateLog {
+        let loaded: HashSet<String> = self
+            .feeds
+            .iter()
+            .enumerate()
+            .flat_map(|(feed_idx, feed)| {
+                (0..feed.items.len()).map(move |item_idx| (feed_idx, item_idx))
+            })
+            .map(|(feed_idx, item_idx)| self.get_item_id(feed_idx, item_idx))
+            .collect();
+        let mut log = self.state_log.clone();
+        log.compact(|id| {
+            loaded.contains(id) || self.read_items.contains(id) || self.starred_items.contains(id)
+        });
+        log
+    }
+
+    fn write_data(storage: &dyn Storage, data: &SavedData) -> Result<()> {
+        // `--read-only` runs keep every change in memory
+        if crate::globals::read_only() {
+            return Ok(());
+        }
+        storage.save(data)
+    }
+
+    /// Record that read/star state changed; it's written out by `save_if_due`.
+    pub fn mark_data_dirty(&mut self) {
+        if self.unsaved_since.is_none() && !crate::globals::read_only() {
+            self.unsaved_since = Some(Instant::now());
+        }
+    }
+
+    pub fn has_unsaved_changes(&self) -> bool {
+        self.unsaved_since.is_some()
+    }
+
+    /// What quitting now would cut short, one line each; empty when nothing is pending.
+    pub fn quit_summary(&self) -> Vec<String> {
+        let mut pending = Vec::new();
+        if self.has_unsaved_changes() {
+            pending.push("Read and star changes not written to disk yet".to_string());
+        }
+        if self.refresh_in_progress {
+            pending.push("A feed refresh is still running".to_string());
+        }
+        let untriaged = self.triage_queue.len().saturating_sub(self.triage_pos);
+        if untriaged > 0 {
+            pending.push(format!(
+                "{} item{} left in the triage pass",
+                untriaged,
+                if untriaged == 1 { "" } else { "s" }
+            ));
+        }
+        pending
+    }
+
+    /// Whether quitting should wait for the user to confirm the quit prompt.
+    pub fn should_confirm_quit(&self) -> bool {
+        self.config.ui.confirm_quit && !self.quit_summary().is_empty()
+    }
+
+    /// Write pending changes on a background thread once they've settled for `SAVE_DEBOUNCE`.
+    pub fn save_if_due(&mut self) {
+        match self.unsaved_since {
+            Some(since) if since.elapsed() >= SAVE_DEBOUNCE => {}
+            _ => return,
+        }
+        self.unsaved_since = None;
+
+        let data = self.saved_data();
+        self.save_generation += 1;
+        let generation = self.save_generation;
+        let lock = Arc::clone(&self.save_lock);
+        let storage = Arc::clone(&self.storage);
+        std::thread::spawn(move || {
+            let mut written = lock.lock().unwrap_or_else(|e| e.into_inner());
+            if *written < generation && Self::write_data(storage.as_ref(), &data).is_ok() {
+                *written = generation;
+            }
+        });
+    }
+
+    /// Persist everything on exit, including the session timestamp once feeds have loaded.
+    pub fn shutdown(&mut self) -> Result<()> {
+        if self.last_refresh.is_some() {
+            self.mark_data_dirty();
+        }
+        self.flush_data()
+    }
+
+    /// Synchronously write any pending changes and wait for in-flight background saves.
+    pub fn flush_data(&mut self) -> Result<()> {
+        if self.unsaved_since.take().is_some() {
+            self.save_generation += 1;
+            self.save_data()
+        } else {
+            drop(self.save_lock.lock().unwrap_or_else(|e| e.into_inner()));
+            Ok(())
+        }
     }
 
     /// Get the data file path with XDG support and backwards compatibility
     fn data_path() -> PathBuf {
+        if let Some(dir) = crate::globals::data_dir_override() {
+            return dir.join("feedr_data.json");
+        }
+        // Profiles are newer than the legacy location, so there's nothing to migrate
+        if crate::globals::profile().is_some() {
+            return Self::xdg_data_path();
+        }
+
         // New XDG-compliant location
         let xdg_path = Self::xdg_data_path();
 
@@ -408,8 +1298,7 @@ impl App {
 
     /// Get the XDG-compliant data path (~/.local/share/feedr/feedr_data.json)
     fn xdg_data_path() -> PathBuf {
-        let mut path = dirs::data_local_dir().unwrap_or_else(|| Path::new(".").to_path_buf());
-        path.push("feedr");
+        let mut path = crate::globals::app_dir(dirs::data_local_dir());
         path.push("feedr_data.json");
         path
     }
@@ -454,39 +1343,164 @@ impl App {
                 .cloned()
                 .collect();
         }
+        if self.item_sort == ItemSort::OldestFirst {
+            self.filtered_dashboard_items.reverse();
+        }
 
-        self.clamp_dashboard_selection();
-    }
-
-    /// Returns the item list currently visible on the dashboard,
-    /// accounting for search mode and active filters.
-    pub fn active_dashboard_items(&self) -> &[(usize, usize)] {
-        if self.is_searching {
-            &self.filtered_items
-        } else if self.filter_options.is_active() {
-            &self.filtered_dashboard_items
+        self.rebuild_dashboard_sections();
+        if self.view == View::FeedItems {
+            self.clamp_feed_item_selection();
         } else {
-            &self.dashboard_items
+            self.clamp_dashboard_selection();
         }
     }
 
-    /// Returns the `(Feed, FeedItem)` at the given position in the active (possibly filtered) list.
-    pub fn active_dashboard_item(&self, idx: usize) -> Option<(&Feed, &FeedItem)> {
-        let items = self.active_dashboard_items();
-        if idx < items.len() {
-            let (feed_idx, item_idx) = items[idx];
-            if let Some(feed) = self.feeds.get(feed_idx) {
-                if let Some(item) = feed.items.get(item_idx) {
-                    return Some((feed, item));
+    /// Recompute the entries of each configured dashboard section. Sections pick from
+    /// every unmuted feed, not just the newest `max_dashboard_items`, and honour
+    /// active filters.
+    fn rebuild_dashboard_sections(&mut self) {
+        if self.config.dashboard_sections.is_empty() {
+            self.dashboard_sections.clear();
+            return;
+        }
+        let filtering = self.filter_options.is_active();
+        let mut candidates: Vec<(usize, usize, Option<DateTime<Utc>>)> = Vec::new();
+        for (feed_idx, feed) in self.feeds.iter().enumerate() {
+            if self.is_feed_hidden(&feed.url) {
+                continue;
+            }
+            for (item_idx, item) in feed.items.iter().enumerate() {
+                if !filtering || self.item_matches_filter(feed_idx, item_idx) {
+                    candidates.push((feed_idx, item_idx, item.parsed_date));
                 }
             }
         }
-        None
-    }
-
-    /// Clamp `selected_item` to stay within the active dashboard item list bounds.
-    pub fn clamp_dashboard_selection(&mut self) {
-        let len = self.active_dashboard_items().len();
+        candidates.sort_by(|a, b| newest_first(&a.2, &b.2));
+
+        let now = Utc::now();
+        let mut sections = Vec::with_capacity(self.config.dashboard_sections.len());
+        for section in &self.config.dashboard_sections {
+            let in_category = |feed_idx: usize| {
+                section.category.as_ref().map_or(true, |name| {
+                    self.get_category_for_feed(&self.feeds[feed_idx].url)
+                        .is_some_and(|idx| self.categories[idx].name.eq_ignore_ascii_case(name))
+                })
+            };
+            let items: Vec<(usize, usize)> = if section.kind == SectionKind::Stale {
+                // Each feed's newest item, oldest first, if it's past the cutoff
+                let cutoff = now - chrono::Duration::days(section.days as i64);
+                let mut newest: Vec<(usize, usize, DateTime<Utc>)> = Vec::new();
+                for (feed_idx, feed) in self.feeds.iter().enumerate() {
+                    if self.is_feed_hidden(&feed.url) || !in_category(feed_idx) {
+                        continue;
+                    }
+                    let latest = feed
+                        .items
+                        .iter()
+                        .enumerate()
+                        .filter_map(|(item_idx, item)| item.parsed_date.map(|d| (item_idx, d)))
+                        .max_by_key(|&(_, date)| date);
+                    if let Some((item_idx, date)) = latest {
+                        if date < cutoff {
+                            newest.push((feed_idx, item_idx, date));
+                        }
+                    }
+                }
+                newest.sort_by_key(|&(_, _, date)| date);
+                newest
+                    .into_iter()
+                    .take(section.limit)
+                    .map(|(feed_idx, item_idx, _)| (feed_idx, item_idx))
+                    .collect()
+            } else {
+                candidates
+                    .iter()
+                    .filter(|&&(feed_idx, item_idx, date)| {
+                        in_category(feed_idx)
+                            && match section.kind {
+                                SectionKind::Latest | SectionKind::Stale => true,
+                                SectionKind::Unread => !self.is_item_read(feed_idx, item_idx),
+                                SectionKind::Starred => self.is_item_starred(feed_idx, item_idx),
+                                SectionKind::Today => date.is_some_and(|d| {
+                                    now.signed_duration_since(d).num_hours() <= 24
+                                }),
+                            }
+                    })
+                    .take(section.limit)
+                    .map(|&(feed_idx, item_idx, _)| (feed_idx, item_idx))
+                    .collect()
+            };
+            let title = section.title.clone().unwrap_or_else(|| {
+                let base = match section.kind {
+                    SectionKind::Latest => "Latest".to_string(),
+                    SectionKind::Unread => "Unread highlights".to_string(),
+                    SectionKind::Starred => "Starred".to_string(),
+                    SectionKind::Today => "Today".to_string(),
+                    SectionKind::Stale => format!("Quiet for {}+ days", section.days),
+                };
+                match &section.category {
+                    Some(category) => format!("{} in {}", base, category),
+                    None => base,
+                }
+            });
+            sections.push(DashboardSectionView {
+                title,
+                kind: section.kind,
+                items,
+            });
+        }
+        self.dashboard_sections = sections;
+        self.focused_section = self
+            .focused_section
+            .min(self.dashboard_sections.len().saturating_sub(1));
+    }
+
+    /// Move the dashboard cursor to the next (or previous) section, wrapping around.
+    pub fn cycle_dashboard_section(&mut self, forward: bool) {
+        let count = self.dashboard_sections.len();
+        if count == 0 {
+            return;
+        }
+        self.focused_section = if forward {
+            (self.focused_section + 1) % count
+        } else {
+            (self.focused_section + count - 1) % count
+        };
+        self.selected_item = (!self.active_dashboard_items().is_empty()).then_some(0);
+        self.reset_preview_scroll();
+    }
+
+    /// Returns the item list currently visible on the dashboard,
+    /// accounting for search mode and active filters.
+    pub fn active_dashboard_items(&self) -> &[(usize, usize)] {
+        if self.is_searching {
+            &self.filtered_items
+        } else if let Some(section) = self.dashboard_sections.get(self.focused_section) {
+            &section.items
+        } else if self.filter_options.is_active() || self.item_sort == ItemSort::OldestFirst {
+            &self.filtered_dashboard_items
+        } else {
+            &self.dashboard_items
+        }
+    }
+
+    /// Returns the `(Feed, FeedItem)` at the given position in the active (possibly filtered) list.
+    pub fn active_dashboard_item(&self, idx: usize) -> Option<(&Feed, &FeedItem)> {
+        let items = self.active_dashboard_items();
+        if idx < items.len() {
+            let (feed_idx, item_idx) = items[idx];
+            if let Some(feed) = self.feeds.get(feed_idx) {
+                if let Some(item) = feed.items.get(item_idx) {
+                    return Some((feed, item));
+                }
+            }
+        }
+        None
+    }
+
+    /// Clamp `selected_item` to stay within the active dashboard item list bounds.
+    pub fn clamp_dashboard_selection(&mut self) {
+        let len = self.active_dashboard_items().len();
         if len == 0 {
             self.selected_item = None;
         } else if let Some(sel) = self.selected_item {
@@ -496,7 +1510,150 @@ impl App {
         }
     }
 
+    /// Item indices of `feed_idx` in the order its page lists them, honouring the live
+    /// filters (other than category) and sort order.
+    pub fn feed_item_order(&self, feed_idx: usize) -> Vec<usize> {
+        let Some(feed) = self.feeds.get(feed_idx) else {
+            return Vec::new();
+        };
+        let filters = FilterOptions {
+            category: None,
+            ..self.filter_options.clone()
+        };
+        let filtering = filters.is_active();
+        let mut order: Vec<usize> = (0..feed.items.len())
+            .filter(|&item_idx| !filtering || self.item_passes(&filters, feed_idx, item_idx))
+            .collect();
+        if self.item_sort == ItemSort::OldestFirst {
+            order.reverse();
+        }
+        order
+    }
+
+    /// Move the cursor on the feed page to the next or previous item in its order.
+    pub fn step_feed_item(&mut self, forward: bool) {
+        let Some(feed_idx) = self.selected_feed else {
+            return;
+        };
+        let order = self.feed_item_order(feed_idx);
+        let position = self
+            .selected_item
+            .and_then(|selected| order.iter().position(|&idx| idx == selected));
+        let next = match position {
+            Some(pos) if forward => order.get(pos + 1),
+            Some(pos) => pos.checked_sub(1).and_then(|pos| order.get(pos)),
+            None => order.first(),
+        };
+        if let Some(&next) = next {
+            self.selected_item = Some(next);
+        }
+    }
+
+    /// Keep the feed page's cursor on an item its filters still show.
+    fn clamp_feed_item_selection(&mut self) {
+        let Some(feed_idx) = self.selected_feed else {
+            return;
+        };
+        let order = self.feed_item_order(feed_idx);
+        if !self
+            .selected_item
+            .is_some_and(|selected| order.contains(&selected))
+        {
+            self.selected_item = order.first().copied();
+        }
+    }
+
+    /// Flip the sort order of the dashboard or the open feed, keeping the selected item.
+    pub fn toggle_item_sort(&mut self) {
+        let selected = self.selected_item_indices();
+        self.item_sort = match self.item_sort {
+            ItemSort::NewestFirst => ItemSort::OldestFirst,
+            ItemSort::OldestFirst => ItemSort::NewestFirst,
+        };
+        self.apply_filters();
+        if self.view == View::Dashboard {
+            if let Some(entry) = selected {
+                if let Some(pos) = self
+                    .active_dashboard_items()
+                    .iter()
+                    .position(|&e| e == entry)
+                {
+                    self.selected_item = Some(pos);
+                }
+            }
+        }
+    }
+
+    /// The scope whose filters and sort order apply in the current view: the open
+    /// feed, the dashboard's category filter, or the whole dashboard. Other views
+    /// leave the live scope alone.
+    fn desired_filter_scope(&self) -> Option<String> {
+        match self.view {
+            View::FeedItems => Some(format!("feed:{}", self.current_feed()?.url)),
+            View::Dashboard if self.filter_scope.starts_with("feed:") => {
+                Some(self.dashboard_scope.clone())
+            }
+            View::Dashboard => Some(match &self.filter_options.category {
+                Some(name) => format!("category:{}", name),
+                None => DASHBOARD_SCOPE.to_string(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Remember the live filters and sort order under their scope, then switch to the
+    /// state saved for the scope of the current view. Called after every input event.
+    pub fn sync_filter_scope(&mut self) {
+        let mut live = ViewState {
+            filters: self.filter_options.clone(),
+            sort: self.item_sort,
+        };
+        // The category is part of the scope key, not of the saved state
+        live.filters.category = None;
+        let changed = if live == ViewState::default() {
+            self.view_states.remove(&self.filter_scope).is_some()
+        } else if self.view_states.get(&self.filter_scope) != Some(&live) {
+            self.view_states.insert(self.filter_scope.clone(), live);
+            true
+        } else {
+            false
+        };
+        if changed {
+            self.mark_data_dirty();
+        }
+
+        let Some(scope) = self.desired_filter_scope() else {
+            return;
+        };
+        if scope == self.filter_scope {
+            if self.view == View::FeedItems {
+                self.clamp_feed_item_selection();
+            }
+            return;
+        }
+        let state = self.view_states.get(&scope).cloned().unwrap_or_default();
+        self.filter_options = state.filters;
+        self.filter_options.category = scope.strip_prefix("category:").map(str::to_string);
+        self.item_sort = state.sort;
+        if !scope.starts_with("feed:") {
+            self.dashboard_scope = scope.clone();
+        }
+        self.filter_scope = scope;
+
+        if self.view == View::FeedItems {
+            self.selected_item = self
+                .selected_feed
+                .and_then(|feed_idx| self.feed_item_order(feed_idx).first().copied());
+        } else {
+            self.apply_filters();
+        }
+    }
+
     fn item_matches_filter(&self, feed_idx: usize, item_idx: usize) -> bool {
+        self.item_passes(&self.filter_options, feed_idx, item_idx)
+    }
+
+    fn item_passes(&self, filters: &FilterOptions, feed_idx: usize, item_idx: usize) -> bool {
         let feed = match self.feeds.get(feed_idx) {
             Some(f) => f,
             None => return false,
@@ -508,7 +1665,7 @@ impl App {
         };
 
         // Check category filter
-        if let Some(category_name) = &self.filter_options.category {
+        if let Some(category_name) = &filters.category {
             let feed_in_category = self
                 .categories
                 .iter()
@@ -519,7 +1676,7 @@ impl App {
         }
 
         // Check age filter using cached parsed_date (avoids re-parsing RFC3339 strings)
-        if let Some(age_filter) = &self.filter_options.age {
+        if let Some(age_filter) = &filters.age {
             if let Some(date) = &item.parsed_date {
                 let now = chrono::Utc::now();
                 let duration = now.signed_duration_since(*date);
@@ -553,7 +1710,7 @@ impl App {
         }
 
         // Check author filter
-        if let Some(has_author) = self.filter_options.has_author {
+        if let Some(has_author) = filters.has_author {
             let item_has_author =
                 item.author.is_some() && !item.author.as_ref().unwrap().is_empty();
             if has_author != item_has_author {
@@ -562,7 +1719,7 @@ impl App {
         }
 
         // Check read status filter
-        if let Some(is_read) = self.filter_options.read_status {
+        if let Some(is_read) = filters.read_status {
             let item_id = self.get_item_id(feed_idx, item_idx);
             let item_is_read = self.read_items.contains(&item_id);
             if is_read != item_is_read {
@@ -571,7 +1728,7 @@ impl App {
         }
 
         // Check starred status filter
-        if let Some(is_starred) = self.filter_options.starred_only {
+        if let Some(is_starred) = filters.starred_only {
             let item_id = self.get_item_id(feed_idx, item_idx);
             let item_is_starred = self.starred_items.contains(&item_id);
             if is_starred != item_is_starred {
@@ -579,8 +1736,23 @@ impl App {
             }
         }
 
+        // Check language filter; items whose language couldn't be detected never match
+        if let Some(code) = filters.language {
+            if item.language != Some(code) {
+                return false;
+            }
+        }
+
+        // Check tag filter against the tags shown for the item
+        if let Some(tag) = &filters.tag {
+            let (confirmed, suggested) = self.item_tag_lists(feed_idx, item_idx);
+            if !confirmed.contains(tag) && !suggested.contains(tag) {
+                return false;
+            }
+        }
+
         // Check content length filter using cached plain_text (avoids HTML parsing)
-        if let Some(min_length) = self.filter_options.min_length {
+        if let Some(min_length) = filters.min_length {
             if let Some(plain_text) = &item.plain_text {
                 if plain_text.len() < min_length {
                     return false;
@@ -610,8 +1782,9 @@ impl App {
     // Mark an item as read
     pub fn mark_item_as_read(&mut self, feed_idx: usize, item_idx: usize) -> Result<()> {
         let item_id = self.get_item_id(feed_idx, item_idx);
-        if !item_id.is_empty() && self.read_items.insert(item_id) {
-            self.save_data()?;
+        if !item_id.is_empty() && self.read_items.insert(item_id.clone()) {
+            self.record_state(&item_id);
+            self.mark_data_dirty();
         }
         Ok(())
     }
@@ -626,16 +1799,120 @@ impl App {
                 false
             } else {
                 // Item is unread, mark as read
-                self.read_items.insert(item_id);
+                self.record_item_dismissed(feed_idx, item_idx);
+                self.read_items.insert(item_id.clone());
                 true
             };
-            self.save_data()?;
+            self.record_state(&item_id);
+            self.mark_data_dirty();
             Ok(is_now_read)
         } else {
             Ok(false)
         }
     }
 
+    /// Count an item as opened, in the detail view or the browser. Only the first open
+    /// adds to its feed's engagement.
+    pub fn record_item_open(&mut self, feed_idx: usize, item_idx: usize) {
+        let item_id = self.get_item_id(feed_idx, item_idx);
+        if item_id.is_empty() {
+            return;
+        }
+        if self.is_searching {
+            self.search_visited.insert(item_id.clone());
+        }
+        let opens = self.item_opens.entry(item_id).or_insert(0);
+        *opens += 1;
+        if *opens == 1 {
+            let url = self.feeds[feed_idx].url.clone();
+            self.engagement.entry(url).or_default().opened += 1;
+        }
+        self.mark_data_dirty();
+    }
+
+    /// Count a trip to the browser for one of a feed's items or the links in them.
+    pub fn record_click(&mut self, feed_idx: usize) {
+        if let Some(feed) = self.feeds.get(feed_idx) {
+            self.engagement.entry(feed.url.clone()).or_default().clicks += 1;
+            self.mark_data_dirty();
+        }
+    }
+
+    /// Count an unread item the user got rid of without ever opening it.
+    fn record_item_dismissed(&mut self, feed_idx: usize, item_idx: usize) {
+        let item_id = self.get_item_id(feed_idx, item_idx);
+        if item_id.is_empty()
+            || self.read_items.contains(&item_id)
+            || self.item_opens.contains_key(&item_id)
+        {
+            return;
+        }
+        let url = self.feeds[feed_idx].url.clone();
+        self.engagement.entry(url).or_default().dismissed += 1;
+        self.mark_data_dirty();
+    }
+
+    /// Mark an item read without it having been opened; returns whether it was unread.
+    fn dismiss_item(&mut self, feed_idx: usize, item_idx: usize) -> bool {
+        self.record_item_dismissed(feed_idx, item_idx);
+        let item_id = self.get_item_id(feed_idx, item_idx);
+        if item_id.is_empty() || !self.read_items.insert(item_id.clone()) {
+            return false;
+        }
+        self.record_state(&item_id);
+        true
+    }
+
+    /// Log an item's current read and starred state for sync backends.
+    #[cfg(feature = "sync")]
+    fn record_state(&mut self, item_id: &str) {
+        self.state_log.record(
+            item_id,
+            self.read_items.contains(item_id),
+            self.starred_items.contains(item_id),
+            Utc::now().timestamp(),
+        );
+    }
+
+    /// Without the `sync` feature there's no state log to keep.
+    #[cfg(not(feature = "sync"))]
+    fn record_state(&mut self, _item_id: &str) {}
+
+    /// Take an item's read and starred state from a sync backend, unless it changed
+    /// here since `changed_at`. Returns whether anything was applied.
+    #[cfg(feature = "sync")]
+    pub fn apply_remote_state(
+        &mut self,
+        item_id: &str,
+        read: bool,
+        starred: bool,
+        changed_at: i64,
+    ) -> bool {
+        if !self
+            .state_log
+            .merge_remote(item_id, read, starred, changed_at)
+        {
+            return false;
+        }
+        for (set, on) in [
+            (&mut self.read_items, read),
+            (&mut self.starred_items, starred),
+        ] {
+            if on {
+                set.insert(item_id.to_string());
+            } else {
+                set.remove(item_id);
+            }
+        }
+        self.mark_data_dirty();
+        true
+    }
+
+    /// How the user has dealt with a feed's items so far.
+    pub fn feed_engagement(&self, url: &str) -> FeedEngagement {
+        self.engagement.get(url).cloned().unwrap_or_default()
+    }
+
     // Check if an item is read
     pub fn is_item_read(&self, feed_idx: usize, item_idx: usize) -> bool {
         let item_id = self.get_item_id(feed_idx, item_idx);
@@ -647,13 +1924,12 @@ impl App {
         let items: Vec<(usize, usize)> = self.active_dashboard_items().to_vec();
         let mut count = 0;
         for (feed_idx, item_idx) in &items {
-            let item_id = self.get_item_id(*feed_idx, *item_idx);
-            if !item_id.is_empty() && self.read_items.insert(item_id) {
+            if self.dismiss_item(*feed_idx, *item_idx) {
                 count += 1;
             }
         }
         if count > 0 {
-            self.save_data()?;
+            self.mark_data_dirty();
         }
         Ok(count)
     }
@@ -663,1353 +1939,6541 @@ impl App {
         let starred = self.get_starred_dashboard_items();
         let mut count = 0;
         for (feed_idx, item_idx) in &starred {
-            let item_id = self.get_item_id(*feed_idx, *item_idx);
-            if !item_id.is_empty() && self.read_items.insert(item_id) {
+            if self.dismiss_item(*feed_idx, *item_idx) {
                 count += 1;
             }
         }
         if count > 0 {
-            self.save_data()?;
-        }
-        Ok(count)
-    }
-
-    /// Mark all items in a specific feed as read, returns count marked.
-    pub fn mark_all_feed_read(&mut self, feed_idx: usize) -> Result<usize> {
-        let mut count = 0;
-        if let Some(feed) = self.feeds.get(feed_idx) {
-            for item_idx in 0..feed.items.len() {
-                let item_id = self.get_item_id(feed_idx, item_idx);
-                if !item_id.is_empty() && self.read_items.insert(item_id) {
-                    count += 1;
-                }
-            }
-        }
-        if count > 0 {
-            self.save_data()?;
+            self.mark_data_dirty();
         }
         Ok(count)
     }
 
-    // Toggle an item's starred status and return whether it's now starred
-    pub fn toggle_item_starred(&mut self, feed_idx: usize, item_idx: usize) -> Result<bool> {
+    /// Add an item to the end of the read-later queue, or take it out if it's queued.
+    /// Returns whether it's now queued.
+    pub fn toggle_read_later(&mut self, feed_idx: usize, item_idx: usize) -> bool {
         let item_id = self.get_item_id(feed_idx, item_idx);
-        if !item_id.is_empty() {
-            let is_now_starred = if self.starred_items.contains(&item_id) {
-                self.starred_items.remove(&item_id);
+        let queued = match self.read_later.iter().position(|id| *id == item_id) {
+            Some(pos) => {
+                self.read_later.remove(pos);
                 false
-            } else {
-                self.starred_items.insert(item_id);
+            }
+            None => {
+                self.read_later.push(item_id);
+                self.prefetch_offline(feed_idx, item_idx);
                 true
-            };
-            self.save_data()?;
-            Ok(is_now_starred)
-        } else {
-            Ok(false)
-        }
+            }
+        };
+        self.mark_data_dirty();
+        queued
     }
 
-    // Check if an item is starred
-    pub fn is_item_starred(&self, feed_idx: usize, item_idx: usize) -> bool {
+    /// Whether an item is in the read-later queue.
+    pub fn is_item_read_later(&self, feed_idx: usize, item_idx: usize) -> bool {
         let item_id = self.get_item_id(feed_idx, item_idx);
-        self.starred_items.contains(&item_id)
+        self.read_later.contains(&item_id)
     }
 
-    // Get starred items from dashboard_items for the Starred view
-    pub fn get_starred_dashboard_items(&self) -> Vec<(usize, usize)> {
-        self.dashboard_items
+    /// The read-later queue in order, resolved to loaded items. Entries whose feed
+    /// isn't loaded (or that dropped out of it) are None.
+    pub fn read_later_entries(&self) -> Vec<Option<(usize, usize)>> {
+        if self.read_later.is_empty() {
+            return Vec::new();
+        }
+        let mut loaded: HashMap<String, (usize, usize)> = HashMap::new();
+        for (feed_idx, feed) in self.feeds.iter().enumerate() {
+            for item_idx in 0..feed.items.len() {
+                loaded.insert(self.get_item_id(feed_idx, item_idx), (feed_idx, item_idx));
+            }
+        }
+        self.read_later
             .iter()
-            .filter(|&&(feed_idx, item_idx)| self.is_item_starred(feed_idx, item_idx))
-            .cloned()
+            .map(|id| loaded.get(id).copied())
             .collect()
     }
 
-    pub fn update_dashboard(&mut self) {
-        // Clear existing dashboard items
-        self.dashboard_items.clear();
-
-        // Get all feeds and sort by most recent first
-        let mut all_items = Vec::new();
+    /// Estimated minutes to read every loaded item in the queue.
+    pub fn read_later_minutes(&self) -> usize {
+        self.read_later_entries()
+            .into_iter()
+            .flatten()
+            .map(|(feed_idx, item_idx)| self.feeds[feed_idx].items[item_idx].reading_minutes())
+            .sum()
+    }
 
-        for (feed_idx, feed) in self.feeds.iter().enumerate() {
-            for (item_idx, item) in feed.items.iter().enumerate() {
-                all_items.push((feed_idx, item_idx, item.parsed_date));
-            }
+    /// Move the queue entry at `idx`, returning its new position.
+    pub fn move_read_later(&mut self, idx: usize, to: QueueMove) -> usize {
+        if idx >= self.read_later.len() {
+            return idx;
         }
-
-        // Sort by date (most recent first)
-        all_items.sort_by(|a, b| match (&a.2, &b.2) {
-            (Some(a_date), Some(b_date)) => b_date.cmp(a_date),
-            (Some(_), None) => std::cmp::Ordering::Less,
-            (None, Some(_)) => std::cmp::Ordering::Greater,
-            (None, None) => std::cmp::Ordering::Equal,
-        });
-
-        // Add items to dashboard (limited by config)
-        let max_items = self.config.general.max_dashboard_items;
-        for (feed_idx, item_idx, _) in all_items.into_iter().take(max_items) {
-            self.dashboard_items.push((feed_idx, item_idx));
+        let last = self.read_later.len() - 1;
+        let target = match to {
+            QueueMove::Top => 0,
+            QueueMove::Up => idx.saturating_sub(1),
+            QueueMove::Down => (idx + 1).min(last),
+            QueueMove::Bottom => last,
+        };
+        if target != idx {
+            let entry = self.read_later.remove(idx);
+            self.read_later.insert(target, entry);
+            self.mark_data_dirty();
         }
-
-        // Apply any active filters
-        self.apply_filters();
+        target
     }
 
-    pub fn add_feed(&mut self, url: &str) -> Result<AddFeedResult> {
-        let timeout = self.config.network.http_timeout;
-        let user_agent = &self.config.network.user_agent;
-        let headers = self.feed_headers.get(url);
-        let client = Feed::build_client(timeout)?;
-        let result = Feed::fetch_url(url, &client, Some(user_agent), headers)?;
-
-        match result {
-            crate::feed::FeedFetchResult::Feed(feed) => {
-                self.feeds.push(feed);
-                if !self.bookmarks.contains(&url.to_string()) {
-                    self.bookmarks.push(url.to_string());
-                }
-                self.update_dashboard();
-                self.rebuild_feed_tree();
-                self.save_data()?;
-                Ok(AddFeedResult::Added)
-            }
-            crate::feed::FeedFetchResult::DiscoveredFeeds { feeds, page_url } => {
-                Ok(AddFeedResult::DiscoveredFeeds { feeds, page_url })
-            }
+    /// Drop the queue entry at `idx`.
+    pub fn remove_read_later(&mut self, idx: usize) {
+        if idx < self.read_later.len() {
+            self.read_later.remove(idx);
+            self.mark_data_dirty();
         }
     }
 
-    fn opml_dfs(outline: &opml::Outline) -> Vec<String> {
-        let mut urls = Vec::<String>::new();
-        if let Some(url) = &outline.xml_url {
-            urls.push(url.to_string())
-        }
-        for o in &outline.outlines {
-            urls.append(&mut Self::opml_dfs(o));
-        }
-        urls
+    /// Remember which items are loaded before a refresh replaces them, so the new ones
+    /// can be summarized when it finishes.
+    pub fn begin_refresh_diff(&mut self) {
+        let ids = self
+            .feeds
+            .iter()
+            .enumerate()
+            .flat_map(|(feed_idx, feed)| {
+                (0..feed.items.len()).map(move |item_idx| (feed_idx, item_idx))
+            })
+            .map(|(feed_idx, item_idx)| self.get_item_id(feed_idx, item_idx))
+            .collect();
+        self.refresh_baseline = Some(ids);
     }
 
-    pub fn import_opml(&mut self, file_path: &str) -> Result<()> {
-        let mut opml_file = match std::fs::File::open(file_path) {
-            Ok(f) => f,
-            Err(e) => return Err(anyhow::anyhow!("Opening file {}. {}", file_path, e)),
-        };
-        let opml_data = match opml::OPML::from_reader(&mut opml_file) {
-            Ok(opml) => opml,
-            Err(e) => return Err(anyhow::anyhow!("OPML decode error. {}", e)),
-        };
-        for feed_list in opml_data.body.outlines {
-            for feed in Self::opml_dfs(&feed_list) {
-                match self.add_feed(&feed) {
-                    Ok(AddFeedResult::Added) => println!("Feed {} added", feed),
-                    Ok(AddFeedResult::DiscoveredFeeds { feeds, .. }) => {
-                        eprintln!(
-                            "Skipping {}: HTML page ({} feed links found, use TUI to select)",
-                            feed,
-                            feeds.len()
-                        );
-                    }
-                    Err(e) => eprintln!("Error adding {}: {}", feed, e),
-                }
+    /// Compare the refreshed feeds with the snapshot from [`Self::begin_refresh_diff`]
+    /// and open the summary popup if anything is new.
+    pub fn finish_refresh_diff(&mut self) {
+        let Some(baseline) = self.refresh_baseline.take() else {
+            return;
+        };
+        let mut entries: Vec<RefreshDiffEntry> = Vec::new();
+        for (feed_idx, feed) in self.feeds.iter().enumerate() {
+            if self.is_feed_hidden(&feed.url) {
+                continue;
+            }
+            let new_ids: Vec<String> = (0..feed.items.len())
+                .map(|item_idx| self.get_item_id(feed_idx, item_idx))
+                .filter(|id| !baseline.contains(id))
+                .collect();
+            if let Some(first) = new_ids.first() {
+                entries.push(RefreshDiffEntry {
+                    feed_url: feed.url.clone(),
+                    feed_title: feed.title.clone(),
+                    new_items: new_ids.len(),
+                    first_new_id: first.clone(),
+                });
             }
         }
-        Ok(())
+        entries.sort_by(|a, b| b.new_items.cmp(&a.new_items));
+        self.refresh_diff = (!entries.is_empty()).then_some(RefreshDiff {
+            entries,
+            selected: 0,
+        });
     }
 
-    pub fn remove_current_feed(&mut self) -> Result<()> {
-        if let Some(idx) = self.selected_feed {
-            if idx < self.feeds.len() {
-                let url = self.feeds[idx].url.clone();
+    /// Close the refresh popup and open the selected feed at its first new item.
+    pub fn jump_to_refresh_diff_entry(&mut self) {
+        let Some(diff) = self.refresh_diff.take() else {
+            return;
+        };
+        let Some(entry) = diff.entries.get(diff.selected) else {
+            return;
+        };
+        let Some(feed_idx) = self.feeds.iter().position(|f| f.url == entry.feed_url) else {
+            return;
+        };
+        let item_idx = (0..self.feeds[feed_idx].items.len())
+            .find(|&item_idx| self.get_item_id(feed_idx, item_idx) == entry.first_new_id)
+            .unwrap_or(0);
+        self.selected_feed = Some(feed_idx);
+        self.selected_item = Some(item_idx);
+        self.view = View::FeedItems;
+    }
 
-                // Remove from feeds
-                self.feeds.remove(idx);
+    /// Muted, archived and `hide_from_dashboard` feeds stay off the dashboard, out of
+    /// triage and out of refresh summaries.
+    fn is_feed_hidden(&self, url: &str) -> bool {
+        self.muted_feeds.contains(url)
+            || self.archived_feeds.contains(url)
+            || self.dashboard_hidden_feeds.contains(url)
+    }
 
-                // Remove from bookmarks
-                if let Some(pos) = self.bookmarks.iter().position(|x| x == &url) {
-                    self.bookmarks.remove(pos);
-                }
+    /// Unsubscribe from a feed but keep it, with its items, read state and stars, under
+    /// "Archived feeds" in the feed list. It's no longer fetched or on the dashboard.
+    pub fn archive_feed(&mut self, feed_idx: usize) -> Result<()> {
+        crate::globals::ensure_writable("Archiving feeds")?;
+        if feed_idx >= self.feeds.len() {
+            return Err(anyhow::anyhow!("Invalid feed index"));
+        }
+        // Archived feeds sit after the subscribed ones, so refreshed feeds slot in
+        // before them in bookmark order
+        let feed = self.feeds.remove(feed_idx);
+        self.bookmarks.retain(|url| *url != feed.url);
+        self.archived_feeds.insert(feed.url.clone());
+        self.feeds.push(feed);
+        self.selected_feed = self.selected_feed.map(|selected| match selected {
+            _ if selected == feed_idx => self.feeds.len() - 1,
+            _ if selected > feed_idx => selected - 1,
+            _ => selected,
+        });
 
-                // Remove from all categories
-                for category in &mut self.categories {
-                    category.remove_feed(&url);
-                }
+        self.mark_data_dirty();
+        self.update_dashboard();
+        self.rebuild_feed_tree();
+        Ok(())
+    }
 
-                // Update selected feed
-                if !self.feeds.is_empty() {
-                    if idx >= self.feeds.len() {
-                        self.selected_feed = Some(self.feeds.len() - 1);
-                    }
-                } else {
-                    self.selected_feed = None;
-                    self.view = View::Dashboard;
-                }
+    /// Subscribe again to an archived feed. It keeps its archived items until the
+    /// refresh this requests brings in the current ones.
+    pub fn unarchive_feed(&mut self, feed_idx: usize) -> Result<()> {
+        crate::globals::ensure_writable("Resubscribing to feeds")?;
+        let Some(feed) = self.feeds.get(feed_idx) else {
+            return Err(anyhow::anyhow!("Invalid feed index"));
+        };
+        let url = feed.url.clone();
+        if !self.archived_feeds.remove(&url) {
+            return Ok(());
+        }
+        self.bookmarks.push(url.clone());
+        self.refresh_feed_requested = Some(url);
 
-                // Update dashboard
-                self.update_dashboard();
-                self.rebuild_feed_tree();
+        self.mark_data_dirty();
+        self.update_dashboard();
+        self.rebuild_feed_tree();
+        Ok(())
+    }
 
-                // Save changes
-                self.save_data()?;
-            }
+    /// Forget earlier failures for the feeds about to be fetched: all of them, or just
+    /// `urls` for a partial refresh.
+    pub fn reset_feed_failures(&mut self, urls: Option<&[String]>) {
+        match urls {
+            Some(urls) => self.failed_feeds.retain(|(url, _)| !urls.contains(url)),
+            None => self.failed_feeds.clear(),
         }
+    }
 
-        Ok(())
+    /// Note that a bookmark failed to load, bringing the banner back if it was dismissed.
+    pub fn record_feed_failure(&mut self, url: &str, error: &anyhow::Error) {
+        self.failed_feeds.retain(|(failed, _)| failed != url);
+        self.failed_feeds
+            .push((url.to_string(), format!("{:#}", error)));
+        self.failed_feeds_dismissed = false;
     }
 
-    pub fn current_feed(&self) -> Option<&Feed> {
-        self.selected_feed.and_then(|idx| self.feeds.get(idx))
+    /// Remember how the last fetch of `url` went, for the feed info popup.
+    pub fn record_fetch(&mut self, url: &str, outcome: &crate::feed::FetchOutcome) {
+        self.fetch_summaries
+            .insert(url.to_string(), outcome.summary());
+        self.fetch_durations
+            .insert(url.to_string(), outcome.elapsed());
     }
 
-    pub fn current_item(&self) -> Option<&FeedItem> {
-        self.current_feed()
-            .and_then(|feed| self.selected_item.and_then(|idx| feed.items.get(idx)))
+    /// URLs of the bookmarks that failed to load last time they were fetched.
+    pub fn failed_feed_urls(&self) -> Vec<String> {
+        self.failed_feeds
+            .iter()
+            .map(|(url, _)| url.clone())
+            .collect()
     }
 
-    pub fn open_current_item_in_browser(&self) -> Result<()> {
-        if let Some(item) = self.current_item() {
-            if let Some(link) = &item.link {
-                open::that(link)?;
-            }
+    /// Whether the dashboard should show the failed-feeds banner.
+    pub fn failed_feeds_banner_visible(&self) -> bool {
+        !self.failed_feeds.is_empty() && !self.failed_feeds_dismissed
+    }
+
+    /// Drop every subscribed feed ahead of a full refresh, keeping the archived ones,
+    /// which aren't fetched.
+    pub fn clear_subscribed_feeds(&mut self) {
+        let archived = &self.archived_feeds;
+        self.feeds.retain(|feed| archived.contains(&feed.url));
+    }
+
+    /// Open the context menu for the item under the cursor, listing what the current view
+    /// can do with it. Returns false when no item is selected.
+    pub fn open_context_menu(&mut self) -> bool {
+        use crate::keybindings::KeyAction;
+
+        let lists = [
+            View::Dashboard,
+            View::FeedItems,
+            View::FeedItemDetail,
+            View::Starred,
+            View::Alerts,
+            View::ReadLater,
+        ];
+        if !lists.contains(&self.view) {
+            return false;
         }
-        Ok(())
+        let Some((feed_idx, item_idx)) = self.selected_item_indices() else {
+            return false;
+        };
+        let item = &self.feeds[feed_idx].items[item_idx];
+        let has_link = item.link.as_deref().is_some_and(|link| !link.is_empty());
+        let detail = self.view == View::FeedItemDetail;
+        // ReadLater has keys of its own; the other lists share the item actions
+        let item_view = matches!(
+            self.view,
+            View::Dashboard | View::FeedItems | View::Starred | View::Alerts
+        ) || detail;
+        let has_event = self.item_event_time(feed_idx, item_idx).is_some();
+        let tools = &self.config.tools;
+        let keybindings = &self.keybindings;
+
+        let mut entries = Vec::new();
+        // Actions run through their key, so one without a key can't be offered
+        let mut add = |available: bool, command: MenuCommand, label: &str| {
+            let bound = match command {
+                MenuCommand::Key(action) => keybindings
+                    .get(&action)
+                    .is_some_and(|bindings| !bindings.is_empty()),
+                _ => true,
+            };
+            if available && bound {
+                entries.push((command, label.to_string()));
+            }
+        };
+        add(!detail, MenuCommand::Key(KeyAction::Select), "Open article");
+        add(
+            has_link,
+            MenuCommand::Key(KeyAction::OpenInBrowser),
+            "Open in browser",
+        );
+        add(
+            item_view,
+            MenuCommand::Key(KeyAction::ToggleStar),
+            if self.is_item_starred(feed_idx, item_idx) {
+                "Unstar"
+            } else {
+                "Star"
+            },
+        );
+        add(
+            item_view,
+            MenuCommand::Key(KeyAction::ToggleRead),
+            if self.is_item_read(feed_idx, item_idx) {
+                "Mark as unread"
+            } else {
+                "Mark as read"
+            },
+        );
+        add(
+            self.view != View::ReadLater,
+            MenuCommand::ToggleReadLater,
+            if self.is_item_read_later(feed_idx, item_idx) {
+                "Remove from read later"
+            } else {
+                "Read later"
+            },
+        );
+        add(
+            self.view == View::ReadLater,
+            MenuCommand::Key(KeyAction::DeleteFeed),
+            "Remove from read later",
+        );
+        add(
+            self.view == View::Alerts,
+            MenuCommand::Key(KeyAction::DeleteFeed),
+            "Dismiss alert",
+        );
+        add(
+            self.feeds[feed_idx].items[item_idx].comments_url.is_some(),
+            MenuCommand::Key(KeyAction::OpenComments),
+            "Open comments",
+        );
+        add(has_link, MenuCommand::CopyLink, "Copy link");
+        add(item_view, MenuCommand::Key(KeyAction::Share), "Share");
+        add(
+            item_view && has_link && !self.config.share.webhooks.is_empty(),
+            MenuCommand::Key(KeyAction::ShareWebhook),
+            "Post to a webhook",
+        );
+        add(detail, MenuCommand::Key(KeyAction::ConfirmTags), "Tag");
+        add(
+            detail,
+            MenuCommand::Key(KeyAction::MuteMenu),
+            "Mute author or domain",
+        );
+        add(
+            true,
+            MenuCommand::ToggleFeedMuted,
+            if self.muted_feeds.contains(&self.feeds[feed_idx].url) {
+                "Unmute source"
+            } else {
+                "Mute source"
+            },
+        );
+        add(
+            item_view && tools.task_command.is_some(),
+            MenuCommand::Key(KeyAction::MakeTask),
+            "Make a task",
+        );
+        add(
+            item_view && has_event,
+            MenuCommand::Key(KeyAction::ExportEvent),
+            "Add event to calendar",
+        );
+        add(detail, MenuCommand::Key(KeyAction::ExportArticle), "Export");
+        add(
+            detail && tools.translate_command.is_some(),
+            MenuCommand::Key(KeyAction::Translate),
+            "Translate",
+        );
+        add(
+            detail && tools.summarize_command.is_some(),
+            MenuCommand::Key(KeyAction::Summarize),
+            "Summarize",
+        );
+        add(
+            detail,
+            MenuCommand::Key(KeyAction::ViewSource),
+            "View source",
+        );
+        add(
+            !detail,
+            MenuCommand::Key(KeyAction::FullTitle),
+            "Show full title",
+        );
+        add(
+            true,
+            MenuCommand::Key(KeyAction::ExplainItem),
+            "Why is this here?",
+        );
+
+        self.context_menu = Some(ContextMenu {
+            feed_idx,
+            item_idx,
+            entries,
+            selected: 0,
+        });
+        true
     }
 
-    pub fn live_search(&mut self, query: &str) {
-        self.search_feeds(query);
+    /// Show the onboarding tour from its first step, over the dashboard it describes.
+    pub fn start_tour(&mut self) {
         self.view = View::Dashboard;
-        let count = self.filtered_items.len();
-        match self.selected_item {
-            Some(sel) if count > 0 && sel >= count => self.selected_item = Some(count - 1),
-            None if count > 0 => self.selected_item = Some(0),
-            _ => {}
+        self.zen_mode = false;
+        self.tour_step = Some(0);
+    }
+
+    /// Go to the tour's next step, or the previous one, ending the tour after its last.
+    pub fn step_tour(&mut self, forward: bool) {
+        let Some(step) = self.tour_step else {
+            return;
+        };
+        if !forward {
+            self.tour_step = Some(step.saturating_sub(1));
+        } else if step + 1 < crate::tour::STEPS.len() {
+            self.tour_step = Some(step + 1);
+        } else {
+            self.end_tour();
         }
     }
 
-    pub fn search_feeds(&mut self, query: &str) {
-        self.search_query = query.to_lowercase();
-        self.is_searching = !query.is_empty();
+    /// Close the tour. Saving makes sure the data file exists, so the next launch
+    /// doesn't count as the first.
+    pub fn end_tour(&mut self) {
+        self.tour_step = None;
+        self.first_launch = false;
+        self.mark_data_dirty();
+    }
 
-        if !self.is_searching {
-            return;
+    /// Mute or unmute a feed, hiding its items from the dashboard. Returns whether it's
+    /// now muted.
+    pub fn toggle_feed_muted(&mut self, feed_url: &str) -> bool {
+        let is_now_muted = if self.muted_feeds.remove(feed_url) {
+            false
+        } else {
+            self.muted_feeds.insert(feed_url.to_string());
+            true
+        };
+        self.mark_data_dirty();
+        self.update_dashboard();
+        is_now_muted
+    }
+
+    /// Whether an item's author or link domain is muted. Muted items stay off the
+    /// dashboard and out of triage but still show on their feed's page.
+    pub fn is_item_muted(&self, feed_idx: usize, item_idx: usize) -> bool {
+        let item = &self.feeds[feed_idx].items[item_idx];
+        let author_muted = item
+            .author
+            .as_deref()
+            .is_some_and(|author| self.muted_authors.contains(&author.trim().to_lowercase()));
+        author_muted || item_domain(item).is_some_and(|domain| self.is_domain_muted(&domain))
+    }
+
+    // A domain is muted along with all its subdomains
+    fn is_domain_muted(&self, domain: &str) -> bool {
+        let mut rest = domain;
+        loop {
+            if self.muted_domains.contains(rest) {
+                return true;
+            }
+            match rest.split_once('.') {
+                Some((_, parent)) if parent.contains('.') => rest = parent,
+                _ => return false,
+            }
         }
+    }
+
+    /// Mute or unmute an author across all feeds. Returns whether they're now muted.
+    pub fn toggle_author_muted(&mut self, author: &str) -> bool {
+        let author = author.trim().to_lowercase();
+        let is_now_muted = if self.muted_authors.remove(&author) {
+            false
+        } else {
+            self.muted_authors.insert(author);
+            true
+        };
+        self.mark_data_dirty();
+        self.update_dashboard();
+        is_now_muted
+    }
+
+    /// Mute or unmute items linking to a domain. Returns whether it's now muted.
+    pub fn toggle_domain_muted(&mut self, domain: &str) -> bool {
+        let is_now_muted = if self.muted_domains.remove(domain) {
+            false
+        } else {
+            self.muted_domains.insert(domain.to_string());
+            true
+        };
+        self.mark_data_dirty();
+        self.update_dashboard();
+        is_now_muted
+    }
 
-        self.filtered_items.clear();
+    /// Enter triage mode with every unread item from unmuted feeds that isn't already
+    /// queued for later, newest first.
+    pub fn start_triage(&mut self) {
+        let mut queue: Vec<(usize, usize, Option<DateTime<Utc>>)> = Vec::new();
         for (feed_idx, feed) in self.feeds.iter().enumerate() {
-            if feed.title_lower.contains(&self.search_query) {
-                // Add all items from matching feed
-                for item_idx in 0..feed.items.len() {
-                    self.filtered_items.push((feed_idx, item_idx));
+            if self.is_feed_hidden(&feed.url) {
+                continue;
+            }
+            for (item_idx, item) in feed.items.iter().enumerate() {
+                if self.is_item_muted(feed_idx, item_idx) {
+                    continue;
                 }
-            } else {
-                // Check individual items using pre-lowercased title and cached plain_text
-                for (item_idx, item) in feed.items.iter().enumerate() {
-                    if item.title_lower.contains(&self.search_query)
-                        || item
-                            .plain_text
-                            .as_ref()
-                            .is_some_and(|pt| pt.to_lowercase().contains(&self.search_query))
-                    {
-                        self.filtered_items.push((feed_idx, item_idx));
-                    }
+                let item_id = self.get_item_id(feed_idx, item_idx);
+                if !self.read_items.contains(&item_id) && !self.read_later.contains(&item_id) {
+                    queue.push((feed_idx, item_idx, item.parsed_date));
                 }
             }
         }
+        queue.sort_by(|a, b| newest_first(&a.2, &b.2));
+        self.triage_queue = queue.into_iter().map(|(f, i, _)| (f, i)).collect();
+        self.triage_pos = 0;
+        self.view = View::Triage;
     }
 
-    pub fn refresh_feeds(&mut self) -> Result<()> {
-        self.is_loading = true;
-        self.refresh_in_progress = true;
-
-        // Group feeds by domain for rate limiting
-        let mut domain_groups: HashMap<String, Vec<String>> = HashMap::new();
-        for url in &self.bookmarks {
-            let domain = Self::extract_domain_from_url(url);
-            domain_groups.entry(domain).or_default().push(url.clone());
+    /// Open a random unread article in the detail view and mark it read. Feeds the user
+    /// has starred items from, or usually opens items from, come up more often. From the
+    /// detail view the next pick replaces the article, keeping its way back. Returns
+    /// false when nothing is unread.
+    pub fn catch_up(&mut self) -> Result<bool> {
+        let current = if self.view == View::FeedItemDetail {
+            self.selected_feed.zip(self.selected_item)
+        } else {
+            None
+        };
+        let mut candidates: Vec<(usize, usize)> = Vec::new();
+        let mut weights: Vec<u64> = Vec::new();
+        for (feed_idx, feed) in self.feeds.iter().enumerate() {
+            if self.is_feed_hidden(&feed.url) {
+                continue;
+            }
+            let weight = self.catch_up_weight(feed_idx);
+            for item_idx in 0..feed.items.len() {
+                if current == Some((feed_idx, item_idx))
+                    || self.is_item_muted(feed_idx, item_idx)
+                    || self.is_item_read(feed_idx, item_idx)
+                {
+                    continue;
+                }
+                candidates.push((feed_idx, item_idx));
+                weights.push(weight);
+            }
         }
 
-        let timeout = self.config.network.http_timeout;
-        let user_agent = self.config.network.user_agent.clone();
-        let rate_limit_delay = self.config.general.refresh_rate_limit_delay;
-
-        // Pre-calculate per-domain delays before spawning threads
-        let domain_delays: HashMap<String, std::time::Duration> = domain_groups
-            .keys()
-            .map(|domain| (domain.clone(), self.calculate_required_delay(domain)))
-            .collect();
+        let roll = uuid::Uuid::new_v4().as_u128() as u64;
+        let Some(pick) = pick_weighted(&weights, roll) else {
+            return Ok(false);
+        };
+        let (feed_idx, item_idx) = candidates[pick];
+        self.show_in_detail(feed_idx, item_idx)?;
+        Ok(true)
+    }
 
-        self.feeds.clear();
+    /// Open an item in the detail view and mark it read. When the detail view is already
+    /// showing an article, the item takes its place and Back still leads where it did.
+    fn show_in_detail(&mut self, feed_idx: usize, item_idx: usize) -> Result<()> {
+        if self.view == View::FeedItemDetail {
+            let detail_return = self.detail_return.take();
+            self.exit_detail_view(View::FeedItemDetail);
+            self.open_item_detail(feed_idx, item_idx);
+            self.detail_return = detail_return;
+        } else {
+            self.open_item_detail(feed_idx, item_idx);
+        }
+        self.mark_item_as_read(feed_idx, item_idx)
+    }
 
-        let client = match Feed::build_client(timeout) {
-            Ok(c) => c,
-            Err(e) => {
-                self.is_loading = false;
-                self.refresh_in_progress = false;
-                return Err(e);
+    /// Start a reading session of about `minutes`: unread items from unmuted feeds,
+    /// newest first, whose estimated reading times add up to no more than that. Opens
+    /// the first one; returns false when no unread item fits.
+    pub fn start_reading_session(&mut self, minutes: usize) -> Result<bool> {
+        let mut candidates: Vec<(usize, usize, Option<DateTime<Utc>>)> = Vec::new();
+        for (feed_idx, feed) in self.feeds.iter().enumerate() {
+            if self.is_feed_hidden(&feed.url) {
+                continue;
+            }
+            for (item_idx, item) in feed.items.iter().enumerate() {
+                if !self.is_item_muted(feed_idx, item_idx) && !self.is_item_read(feed_idx, item_idx)
+                {
+                    candidates.push((feed_idx, item_idx, item.parsed_date));
+                }
+            }
+        }
+        candidates.sort_by(|a, b| newest_first(&a.2, &b.2));
+
+        // Skip what doesn't fit and keep looking for shorter items to fill the time
+        let mut queue = Vec::new();
+        let mut planned = 0;
+        for (feed_idx, item_idx, _) in candidates {
+            let needed = self.feeds[feed_idx].items[item_idx].reading_minutes();
+            if planned + needed <= minutes {
+                planned += needed;
+                queue.push((feed_idx, item_idx));
             }
+        }
+        let Some(&(feed_idx, item_idx)) = queue.first() else {
+            return Ok(false);
         };
+        self.reading_session = Some(ReadingSession {
+            queue,
+            pos: 0,
+            minutes,
+        });
+        self.show_in_detail(feed_idx, item_idx)?;
+        Ok(true)
+    }
 
-        // Clone headers map for use in threads
-        let all_headers = self.feed_headers.clone();
+    /// Move on to the session's next article. Once the last one is done the session
+    /// ends and the detail view closes; returns false then.
+    pub fn advance_reading_session(&mut self) -> Result<bool> {
+        let next = self.reading_session.as_mut().and_then(|session| {
+            session.pos += 1;
+            session.queue.get(session.pos).copied()
+        });
+        match next {
+            Some((feed_idx, item_idx)) => {
+                self.show_in_detail(feed_idx, item_idx)?;
+                Ok(true)
+            }
+            None => {
+                self.back_from_detail_view();
+                Ok(false)
+            }
+        }
+    }
 
-        // Spawn one thread per domain group — domains fetch in parallel,
-        // feeds within the same domain fetch sequentially (rate limiting)
-        let handles: Vec<_> = domain_groups
-            .into_iter()
-            .map(|(domain, urls)| {
-                let client = client.clone();
-                let ua = user_agent.clone();
-                let delay = domain_delays.get(&domain).copied().unwrap_or_default();
-                let rate_limit = std::time::Duration::from_millis(rate_limit_delay);
-                let hdrs = all_headers.clone();
+    /// "2/5 · 7 min left" for the running reading session, counting the open article.
+    pub fn reading_session_status(&self) -> Option<String> {
+        let session = self.reading_session.as_ref()?;
+        let left: usize = session.queue[session.pos.min(session.queue.len())..]
+            .iter()
+            .map(|&(feed_idx, item_idx)| self.feeds[feed_idx].items[item_idx].reading_minutes())
+            .sum();
+        Some(format!(
+            "{}/{} \u{00b7} {} min left",
+            session.pos + 1,
+            session.queue.len(),
+            left
+        ))
+    }
 
-                std::thread::spawn(move || {
-                    if !delay.is_zero() {
-                        std::thread::sleep(delay);
-                    }
+    // How strongly catch-up favours a feed's items
+    fn catch_up_weight(&self, feed_idx: usize) -> u64 {
+        let feed = &self.feeds[feed_idx];
+        let engagement = self.feed_engagement(&feed.url).score().unwrap_or(0) as u64;
+        let starred = (0..feed.items.len()).any(|idx| self.is_item_starred(feed_idx, idx));
+        1 + engagement / 25 + if starred { CATCH_UP_STARRED_BONUS } else { 0 }
+    }
 
-                    let mut results = Vec::new();
-                    for (i, url) in urls.iter().enumerate() {
-                        // Rate-limit between feeds on the same domain
-                        if i > 0 && !rate_limit.is_zero() {
-                            std::thread::sleep(rate_limit);
-                        }
-                        results.push((
-                            url.clone(),
-                            Feed::fetch_url(url, &client, Some(&ua), hdrs.get(url))
-                                .and_then(|r| r.into_feed()),
-                        ));
-                    }
-                    (domain, results)
-                })
-            })
-            .collect();
+    /// The item currently shown in triage mode, or None once the queue is cleared.
+    pub fn current_triage_item(&self) -> Option<(usize, usize)> {
+        self.triage_queue.get(self.triage_pos).copied()
+    }
 
-        // Collect results and update domain fetch times
-        let mut errors = Vec::new();
-        for handle in handles {
-            if let Ok((domain, results)) = handle.join() {
-                for (url, result) in results {
-                    match result {
-                        Ok(feed) => self.feeds.push(feed),
-                        Err(e) => {
-                            errors.push(format!("Failed to refresh feed {}: {}", url, e));
-                        }
-                    }
+    /// Apply a disposition to the current triage item and advance to the next one.
+    pub fn triage(&mut self, action: TriageAction) -> Result<()> {
+        let Some((feed_idx, item_idx)) = self.current_triage_item() else {
+            return Ok(());
+        };
+        match action {
+            TriageAction::ReadLater => {
+                let item_id = self.get_item_id(feed_idx, item_idx);
+                if !self.read_later.contains(&item_id) {
+                    self.read_later.push(item_id);
+                    self.prefetch_offline(feed_idx, item_idx);
+                    self.mark_data_dirty();
                 }
-                self.last_domain_fetch.insert(domain, Instant::now());
+            }
+            TriageAction::Star => {
+                if !self.is_item_starred(feed_idx, item_idx) {
+                    self.toggle_item_starred(feed_idx, item_idx)?;
+                }
+                self.mark_item_as_read(feed_idx, item_idx)?;
+            }
+            TriageAction::Open => {
+                self.open_item_in_browser(feed_idx, item_idx)?;
+                self.mark_item_as_read(feed_idx, item_idx)?;
+            }
+            TriageAction::MarkRead => {
+                self.record_item_dismissed(feed_idx, item_idx);
+                self.mark_item_as_read(feed_idx, item_idx)?;
+            }
+            TriageAction::Skip => self.record_item_dismissed(feed_idx, item_idx),
+            TriageAction::MuteSource => {
+                let url = self.feeds[feed_idx].url.clone();
+                if !self.muted_feeds.contains(&url) {
+                    self.toggle_feed_muted(&url);
+                }
+                // Drop the rest of this feed's items; the next feed's item slides into place
+                let pos = self.triage_pos;
+                let mut idx = 0;
+                self.triage_queue.retain(|&(f, _)| {
+                    idx += 1;
+                    idx <= pos || f != feed_idx
+                });
+                return Ok(());
             }
         }
-
-        if let Some(last_error) = errors.last() {
-            self.error = Some(last_error.clone());
-        }
-
-        self.update_dashboard();
-        self.is_loading = false;
-        self.refresh_in_progress = false;
-        self.last_refresh = Some(Instant::now());
-
+        self.triage_pos += 1;
         Ok(())
     }
 
-    pub fn update_loading_indicator(&mut self) {
-        self.loading_indicator = (self.loading_indicator + 1) % 10;
+    /// Step back to the previous item in triage mode.
+    pub fn triage_back(&mut self) {
+        self.triage_pos = self.triage_pos.saturating_sub(1);
     }
 
-    pub fn get_available_categories(&self) -> Vec<String> {
-        let mut result: Vec<String> = self.categories.iter().map(|c| c.name.clone()).collect();
-        result.sort();
-        result
+    /// Mark all flagged alert items as read, returns count marked.
+    pub fn mark_all_alerts_read(&mut self) -> Result<usize> {
+        let alerts = self.get_alert_dashboard_items();
+        let mut count = 0;
+        for (feed_idx, item_idx) in &alerts {
+            if self.dismiss_item(*feed_idx, *item_idx) {
+                count += 1;
+            }
+        }
+        if count > 0 {
+            self.mark_data_dirty();
+        }
+        Ok(count)
     }
 
-    pub fn get_filter_stats(&self) -> (usize, usize, usize) {
-        let active_count = [
-            self.filter_options.category.is_some(),
-            self.filter_options.age.is_some(),
-            self.filter_options.has_author.is_some(),
-            self.filter_options.read_status.is_some(),
-            self.filter_options.min_length.is_some(),
-            self.filter_options.starred_only.is_some(),
-        ]
-        .iter()
-        .filter(|&&x| x)
-        .count();
+    /// Remove an item from the Alerts view. It's marked read too, so the next scan
+    /// doesn't flag it again.
+    pub fn dismiss_alert(&mut self, feed_idx: usize, item_idx: usize) {
+        let item_id = self.get_item_id(feed_idx, item_idx);
+        if self.alert_items.remove(&item_id).is_some() {
+            self.record_item_dismissed(feed_idx, item_idx);
+            self.read_items.insert(item_id.clone());
+            self.record_state(&item_id);
+            self.mark_data_dirty();
+        }
+    }
 
-        let filtered_count = if self.is_searching {
-            self.filtered_items.len()
-        } else {
-            self.filtered_dashboard_items.len()
-        };
+    /// Mark all items in a specific feed as read, returns count marked.
+    pub fn mark_all_feed_read(&mut self, feed_idx: usize) -> Result<usize> {
+        let mut count = 0;
+        let len = self.feeds.get(feed_idx).map_or(0, |feed| feed.items.len());
+        for item_idx in 0..len {
+            if self.dismiss_item(feed_idx, item_idx) {
+                count += 1;
+            }
+        }
+        if count > 0 {
+            self.mark_data_dirty();
+        }
+        Ok(count)
+    }
 
-        let total_count = if self.is_searching {
-            self.filtered_items.len()
+    // Toggle an item's starred status and return whether it's now starred
+    pub fn toggle_item_starred(&mut self, feed_idx: usize, item_idx: usize) -> Result<bool> {
+        let item_id = self.get_item_id(feed_idx, item_idx);
+        if !item_id.is_empty() {
+            let is_now_starred = if self.starred_items.contains(&item_id) {
+                self.starred_items.remove(&item_id);
+                false
+            } else {
+                self.starred_items.insert(item_id.clone());
+                self.prefetch_offline(feed_idx, item_idx);
+                true
+            };
+            self.record_state(&item_id);
+            self.mark_data_dirty();
+            self.rebuild_dashboard_sections();
+            Ok(is_now_starred)
         } else {
-            self.dashboard_items.len()
-        };
+            Ok(false)
+        }
+    }
 
-        (active_count, filtered_count, total_count)
+    // Check if an item is starred
+    pub fn is_item_starred(&self, feed_idx: usize, item_idx: usize) -> bool {
+        let item_id = self.get_item_id(feed_idx, item_idx);
+        self.starred_items.contains(&item_id)
     }
 
-    pub fn get_filter_summary(&self) -> String {
-        let mut parts = Vec::new();
+    // Get starred items from dashboard_items for the Starred view
+    pub fn get_starred_dashboard_items(&self) -> Vec<(usize, usize)> {
+        self.dashboard_items
+            .iter()
+            .filter(|&&(feed_idx, item_idx)| self.is_item_starred(feed_idx, item_idx))
+            .cloned()
+            .collect()
+    }
 
-        if let Some(category) = &self.filter_options.category {
-            parts.push(format!("Category: {}", category));
+    /// Name of the alert an item matched, if it's been flagged.
+    pub fn item_alert(&self, feed_idx: usize, item_idx: usize) -> Option<&str> {
+        if self.alert_items.is_empty() {
+            return None;
         }
+        let item_id = self.get_item_id(feed_idx, item_idx);
+        self.alert_items.get(&item_id).map(String::as_str)
+    }
 
-        if let Some(age) = &self.filter_options.age {
-            let age_str = match age {
-                TimeFilter::Today => "Today",
-                TimeFilter::ThisWeek => "This Week",
-                TimeFilter::ThisMonth => "This Month",
-                TimeFilter::Older => "Older than a month",
-            };
-            parts.push(format!("Age: {}", age_str));
-        }
+    // Get flagged items from dashboard_items for the Alerts view
+    pub fn get_alert_dashboard_items(&self) -> Vec<(usize, usize)> {
+        self.dashboard_items
+            .iter()
+            .filter(|&&(feed_idx, item_idx)| self.item_alert(feed_idx, item_idx).is_some())
+            .cloned()
+            .collect()
+    }
 
-        if let Some(has_author) = self.filter_options.has_author {
-            parts.push(format!(
-                "Author: {}",
-                if has_author {
-                    "With author"
-                } else {
-                    "No author"
+    /// Flag loaded items that match an alert rule and haven't been flagged before,
+    /// returning them. Read items are skipped so old news doesn't raise alerts.
+    pub fn scan_alerts(&mut self) -> Vec<(usize, usize)> {
+        let mut new_matches = Vec::new();
+        if self.alerts.is_empty() {
+            return new_matches;
+        }
+        for (feed_idx, feed) in self.feeds.iter().enumerate() {
+            let category = self
+                .get_category_for_feed(&feed.url)
+                .map(|idx| self.categories[idx].name.as_str());
+            for (item_idx, item) in feed.items.iter().enumerate() {
+                let item_id = self.get_item_id(feed_idx, item_idx);
+                if self.alert_items.contains_key(&item_id) || self.read_items.contains(&item_id) {
+                    continue;
                 }
-            ));
+                let text = item.plain_text.as_deref();
+                if let Some(alert) = self
+                    .alerts
+                    .iter()
+                    .find(|a| a.matches(&feed.url, category, &item.title, text))
+                {
+                    new_matches.push((feed_idx, item_idx, item_id, alert.name.clone()));
+                }
+            }
         }
+        new_matches
+            .into_iter()
+            .map(|(feed_idx, item_idx, item_id, name)| {
+                self.alert_items.insert(item_id, name);
+                (feed_idx, item_idx)
+            })
+            .collect()
+    }
 
-        if let Some(is_read) = self.filter_options.read_status {
-            parts.push(format!(
-                "Status: {}",
-                if is_read { "Read" } else { "Unread" }
-            ));
+    /// Suggest tags for loaded items that don't have any yet, from keywords that set
+    /// them apart from the rest. Earlier suggestions are kept as they were.
+    pub fn update_suggested_tags(&mut self) {
+        let mut ids = Vec::new();
+        let mut docs = Vec::new();
+        for (feed_idx, feed) in self.feeds.iter().enumerate() {
+            for (item_idx, item) in feed.items.iter().enumerate() {
+                ids.push(self.get_item_id(feed_idx, item_idx));
+                docs.push((
+                    item.title.as_str(),
+                    item.plain_text.as_deref().unwrap_or(""),
+                ));
+            }
         }
-
-        if let Some(length) = self.filter_options.min_length {
-            let length_str = match length {
-                100 => "Short",
-                500 => "Medium",
-                1000 => "Long",
-                _ => "Custom",
-            };
-            parts.push(format!("Length: {}", length_str));
+        let suggestions = crate::tagging::suggest_tags(&docs, crate::tagging::MAX_SUGGESTED_TAGS);
+        for (item_id, tags) in ids.into_iter().zip(suggestions) {
+            if !item_id.is_empty() && !tags.is_empty() {
+                self.suggested_tags.entry(item_id).or_insert(tags);
+            }
         }
+    }
 
-        if let Some(is_starred) = self.filter_options.starred_only {
-            parts.push(format!(
-                "Starred: {}",
-                if is_starred { "Yes" } else { "No" }
-            ));
+    /// An item's confirmed tags, and its suggested ones while none are confirmed.
+    pub fn item_tag_lists(&self, feed_idx: usize, item_idx: usize) -> (&[String], &[String]) {
+        let item_id = self.get_item_id(feed_idx, item_idx);
+        match self.item_tags.get(&item_id) {
+            Some(tags) => (tags, &[]),
+            None => (
+                &[],
+                self.suggested_tags
+                    .get(&item_id)
+                    .map_or(&[][..], Vec::as_slice),
+            ),
         }
+    }
 
-        if parts.is_empty() {
-            "No filters active".to_string()
+    /// Confirm an item's suggested tags, or turn confirmed ones back into suggestions.
+    /// Returns whether they're now confirmed, or None when there are no tags at all.
+    pub fn toggle_tags_confirmed(&mut self, feed_idx: usize, item_idx: usize) -> Option<bool> {
+        let item_id = self.get_item_id(feed_idx, item_idx);
+        let confirmed = if self.item_tags.remove(&item_id).is_some() {
+            false
         } else {
-            parts.join(" | ")
-        }
+            let tags = self.suggested_tags.get(&item_id)?.clone();
+            self.item_tags.insert(item_id, tags);
+            true
+        };
+        self.mark_data_dirty();
+        Some(confirmed)
     }
 
-    // Category management functions
-    pub fn create_category(&mut self, name: &str) -> Result<()> {
-        // Trim the name and check if it's empty
-        let name = name.trim();
-        if name.is_empty() {
-            return Err(anyhow::anyhow!("Category name cannot be empty"));
+    /// Scan for new alert matches and send one desktop notification for what's new and
+    /// wanted, unless it's `general.quiet_hours`.
+    pub fn check_alerts(&mut self) {
+        let new_matches = self.scan_alerts();
+        if !new_matches.is_empty() {
+            self.mark_data_dirty();
         }
-
-        // Check if category with same name exists
-        if self.categories.iter().any(|c| c.name == name) {
-            return Err(anyhow::anyhow!("Category with this name already exists"));
+        if self
+            .config
+            .general
+            .in_quiet_hours(chrono::Local::now().time())
+        {
+            return;
         }
 
-        // Create and add the new category
-        let category = FeedCategory::new(name);
-        self.categories.push(category);
-        self.selected_category = Some(self.categories.len() - 1);
-
-        // Save categories
-        self.save_data()?;
-        self.rebuild_feed_tree();
-
-        Ok(())
+        let notified = self.items_to_notify(&new_matches);
+        let all_alerts = notified.iter().all(|item| new_matches.contains(item));
+        let titles: Vec<&str> = notified
+            .iter()
+            .map(|&(feed_idx, item_idx)| self.feeds[feed_idx].items[item_idx].title.as_str())
+            .collect();
+        match titles.as_slice() {
+            [] => {}
+            [title] if all_alerts => crate::alerts::notify_desktop("Feedr alert", title),
+            [title] => crate::alerts::notify_desktop(
+                &format!("Feedr: {}", self.feeds[notified[0].0].title),
+                title,
+            ),
+            _ => crate::alerts::notify_desktop(
+                &format!(
+                    "Feedr: {} new {}",
+                    titles.len(),
+                    if all_alerts { "alerts" } else { "items" }
+                ),
+                &titles
+                    .iter()
+                    .take(5)
+                    .copied()
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            ),
+        }
     }
 
-    pub fn delete_category(&mut self, idx: usize) -> Result<()> {
-        if idx >= self.categories.len() {
-            return Err(anyhow::anyhow!("Invalid category index"));
-        }
+    /// Which of a feed's new items send a notification: its `notify` setting, else
+    /// `general.notify`.
+    pub fn notify_level(&self, feed_url: &str) -> NotifyLevel {
+        self.feed_notify
+            .get(feed_url)
+            .copied()
+            .unwrap_or(self.config.general.notify)
+    }
 
-        self.categories.remove(idx);
-        if !self.categories.is_empty() && self.selected_category.is_some() {
-            if self.selected_category.unwrap() >= self.categories.len() {
-                self.selected_category = Some(self.categories.len() - 1);
+    /// The items a notification is sent for: `new_matches` whose rule notifies, from
+    /// feeds that don't say `never`, and, when a refresh has just finished, the unread
+    /// items it brought to feeds that say `all`.
+    fn items_to_notify(&self, new_matches: &[(usize, usize)]) -> Vec<(usize, usize)> {
+        let mut items: Vec<(usize, usize)> = new_matches
+            .iter()
+            .copied()
+            .filter(|&(feed_idx, item_idx)| {
+                let name = self.item_alert(feed_idx, item_idx);
+                self.notify_level(&self.feeds[feed_idx].url) != NotifyLevel::Never
+                    && self
+                        .alerts
+                        .iter()
+                        .any(|a| a.notify && Some(a.name.as_str()) == name)
+            })
+            .collect();
+        // Without a snapshot from before the refresh, every loaded item would look new
+        let Some(baseline) = &self.refresh_baseline else {
+            return items;
+        };
+        for (feed_idx, feed) in self.feeds.iter().enumerate() {
+            if self.notify_level(&feed.url) != NotifyLevel::All || self.is_feed_hidden(&feed.url) {
+                continue;
+            }
+            for item_idx in 0..feed.items.len() {
+                let item_id = self.get_item_id(feed_idx, item_idx);
+                if !baseline.contains(&item_id)
+                    && !self.read_items.contains(&item_id)
+                    && !items.contains(&(feed_idx, item_idx))
+                {
+                    items.push((feed_idx, item_idx));
+                }
             }
-        } else {
-            self.selected_category = None;
         }
+        items
+    }
 
-        // Save categories
-        self.save_data()?;
-        self.rebuild_feed_tree();
-
-        Ok(())
+    /// The selected item as (feed URL, item ID). Unlike indices, this survives feeds
+    /// being reloaded and the dashboard being re-sorted.
+    fn selected_item_identity(&self) -> Option<(String, String)> {
+        let (feed_idx, item_idx) = self.selected_item_indices()?;
+        let feed = self.feeds.get(feed_idx)?;
+        feed.items.get(item_idx)?;
+        Some((feed.url.clone(), self.get_item_id(feed_idx, item_idx)))
     }
 
-    pub fn rename_category(&mut self, idx: usize, new_name: &str) -> Result<()> {
-        let new_name = new_name.trim();
-        if new_name.is_empty() {
-            return Err(anyhow::anyhow!("Category name cannot be empty"));
+    /// Point the selection at the item with this identity in the current view's list.
+    /// Returns false when the item isn't loaded or isn't in that list.
+    fn select_item_by_identity(&mut self, (feed_url, item_id): &(String, String)) -> bool {
+        let Some(feed_idx) = self.feeds.iter().position(|f| &f.url == feed_url) else {
+            return false;
+        };
+        let Some(item_idx) = (0..self.feeds[feed_idx].items.len())
+            .find(|&item_idx| &self.get_item_id(feed_idx, item_idx) == item_id)
+        else {
+            return false;
+        };
+        let entry = (feed_idx, item_idx);
+        let position = match self.view {
+            View::Dashboard => self
+                .active_dashboard_items()
+                .iter()
+                .position(|&e| e == entry),
+            View::Starred => self
+                .get_starred_dashboard_items()
+                .iter()
+                .position(|&e| e == entry),
+            View::Alerts => self
+                .get_alert_dashboard_items()
+                .iter()
+                .position(|&e| e == entry),
+            View::FeedItems | View::FeedItemDetail => {
+                self.selected_feed = Some(feed_idx);
+                Some(item_idx)
+            }
+            _ => None,
+        };
+        match position {
+            Some(pos) => {
+                self.selected_item = Some(pos);
+                true
+            }
+            None => false,
         }
+    }
 
-        // Check if another category already has this name
-        if self
-            .categories
-            .iter()
-            .enumerate()
-            .any(|(i, c)| i != idx && c.name == new_name)
-        {
-            return Err(anyhow::anyhow!("Category with this name already exists"));
-        }
+    /// Remember the selected item while a refresh reloads every feed, so it is
+    /// selected again as soon as its feed comes back.
+    pub fn pin_selection(&mut self) {
+        self.selection_anchor = self.selected_item_identity();
+    }
 
-        if idx < self.categories.len() {
-            self.categories[idx].rename(new_name);
-            self.save_data()?;
-            self.rebuild_feed_tree();
-            Ok(())
-        } else {
-            Err(anyhow::anyhow!("Invalid category index"))
-        }
+    /// Stop waiting for a pinned item, e.g. when a refresh finished without it.
+    pub fn unpin_selection(&mut self) {
+        self.selection_anchor = None;
     }
 
-    pub fn assign_feed_to_category(&mut self, feed_url: &str, category_idx: usize) -> Result<()> {
-        if category_idx >= self.categories.len() {
-            return Err(anyhow::anyhow!("Invalid category index"));
+    /// Reselect `identity` after lists were rebuilt, dropping the pin once it's found.
+    fn reselect(&mut self, identity: Option<(String, String)>) {
+        if let Some(identity) = identity {
+            if self.select_item_by_identity(&identity) {
+                self.selection_anchor = None;
+            }
         }
+    }
 
-        // Add feed to the selected category
-        self.categories[category_idx].add_feed(feed_url);
+    pub fn update_dashboard(&mut self) {
+        let identity = self
+            .selection_anchor
+            .clone()
+            .or_else(|| self.selected_item_identity());
 
-        // Save the updated categories
-        self.save_data()?;
-        self.rebuild_feed_tree();
+        // Clear existing dashboard items
+        self.dashboard_items.clear();
 
-        Ok(())
-    }
+        // Get all feeds and sort by most recent first
+        let mut all_items = Vec::new();
 
-    pub fn remove_feed_from_category(&mut self, feed_url: &str, category_idx: usize) -> Result<()> {
-        if category_idx >= self.categories.len() {
-            return Err(anyhow::anyhow!("Invalid category index"));
+        for (feed_idx, feed) in self.feeds.iter().enumerate() {
+            if self.is_feed_hidden(&feed.url) {
+                continue;
+            }
+            for (item_idx, item) in feed.items.iter().enumerate() {
+                if !self.is_item_muted(feed_idx, item_idx) {
+                    all_items.push((feed_idx, item_idx, item.parsed_date));
+                }
+            }
         }
 
-        let removed = self.categories[category_idx].remove_feed(feed_url);
-        if removed {
-            self.save_data()?;
-            self.rebuild_feed_tree();
-            Ok(())
-        } else {
-            Err(anyhow::anyhow!("Feed not found in category"))
+        // Sort by date (most recent first)
+        all_items.sort_by(|a, b| newest_first(&a.2, &b.2));
+
+        // Add items to dashboard (limited by config)
+        let max_items = self.config.general.max_dashboard_items;
+        for (feed_idx, item_idx, _) in all_items.into_iter().take(max_items) {
+            self.dashboard_items.push((feed_idx, item_idx));
         }
-    }
 
-    pub fn toggle_category_expanded(&mut self, idx: usize) -> Result<()> {
-        if idx < self.categories.len() {
-            self.categories[idx].toggle_expanded();
-            self.rebuild_feed_tree();
-            Ok(())
-        } else {
-            Err(anyhow::anyhow!("Invalid category index"))
-        }
+        // Apply any active filters
+        self.apply_filters();
+        self.reselect(identity);
     }
 
-    pub fn rebuild_feed_tree(&mut self) {
-        self.feed_tree.clear();
-        let mut categorized_feeds: std::collections::HashSet<String> =
-            std::collections::HashSet::new();
+    /// Swap in a re-fetched copy of a feed that is already loaded, keeping the
+    /// selection on the same item.
+    pub fn replace_feed(&mut self, pos: usize, mut feed: Feed) {
+        self.note_feed_meta(&feed);
+        self.apply_feed_settings(&mut feed);
+        let identity = self.selected_item_identity();
+        let Some(slot) = self.feeds.get_mut(pos) else {
+            return;
+        };
+        *slot = feed;
+        self.update_dashboard();
+        self.reselect(identity);
+    }
 
-        // Add categories and their feeds
-        for (cat_idx, category) in self.categories.iter().enumerate() {
-            self.feed_tree.push(TreeItem::Category(cat_idx));
-            if category.expanded {
-                for (feed_idx, feed) in self.feeds.iter().enumerate() {
-                    if category.feeds.contains(&feed.url) {
-                        self.feed_tree.push(TreeItem::Feed(feed_idx, Some(cat_idx)));
-                        categorized_feeds.insert(feed.url.clone());
-                    }
-                }
-            } else {
-                // Still track which feeds are categorized even when collapsed
-                for feed in &self.feeds {
-                    if category.feeds.contains(&feed.url) {
-                        categorized_feeds.insert(feed.url.clone());
-                    }
-                }
-            }
+    /// Ask the main loop to re-fetch only the feed open in the FeedItems view.
+    pub fn request_current_feed_refresh(&mut self) {
+        if self.refresh_in_progress {
+            return;
+        }
+        // Archived feeds aren't fetched
+        if let Some(feed) = self
+            .current_feed()
+            .filter(|feed| !self.archived_feeds.contains(&feed.url))
+        {
+            self.refresh_feed_requested = Some(feed.url.clone());
         }
+    }
 
-        // Add uncategorized feeds at the bottom
-        for (feed_idx, feed) in self.feeds.iter().enumerate() {
-            if !categorized_feeds.contains(&feed.url) {
-                self.feed_tree.push(TreeItem::Feed(feed_idx, None));
+    /// Insert a freshly fetched feed at `pos` and merge its items into the
+    /// already-sorted dashboard without rebuilding it from scratch.
+    pub fn insert_feed(&mut self, pos: usize, mut feed: Feed) {
+        self.note_feed_meta(&feed);
+        self.apply_feed_settings(&mut feed);
+        let identity = self
+            .selection_anchor
+            .clone()
+            .or_else(|| self.selected_item_identity());
+        let pos = pos.min(self.feeds.len());
+        self.feeds.insert(pos, feed);
+
+        // Every stored index pointing at or past `pos` moved one slot down
+        for entry in self
+            .dashboard_items
+            .iter_mut()
+            .chain(self.filtered_dashboard_items.iter_mut())
+            .chain(self.filtered_items.iter_mut())
+        {
+            if entry.0 >= pos {
+                entry.0 += 1;
             }
         }
-
-        // Clamp selection
-        if let Some(selected) = self.selected_tree_item {
-            if selected >= self.feed_tree.len() {
-                self.selected_tree_item = if self.feed_tree.is_empty() {
-                    None
-                } else {
-                    Some(self.feed_tree.len() - 1)
-                };
+        if let Some(selected) = self.selected_feed {
+            if selected >= pos {
+                self.selected_feed = Some(selected + 1);
             }
         }
+
+        self.merge_feed_into_dashboard(pos);
+        self.reselect(identity);
     }
 
-    // When managing categories in UI
-    pub fn get_category_for_feed(&self, feed_url: &str) -> Option<usize> {
-        self.categories
+    /// Merge one feed's items into `dashboard_items`, keeping newest-first order.
+    ///
+    /// The top N of the union equals the top N of (current top N + new items),
+    /// so merging into the truncated list and truncating again is exact.
+    fn merge_feed_into_dashboard(&mut self, feed_idx: usize) {
+        let Some(feed) = self.feeds.get(feed_idx) else {
+            return;
+        };
+        if self.is_feed_hidden(&feed.url) {
+            return;
+        }
+
+        let mut incoming: Vec<(usize, usize, Option<DateTime<Utc>>)> = feed
+            .items
             .iter()
-            .position(|c| c.contains_feed(feed_url))
-    }
+            .enumerate()
+            .map(|(item_idx, item)| (feed_idx, item_idx, item.parsed_date))
+            .collect();
+        incoming.sort_by(|a, b| newest_first(&a.2, &b.2));
 
-    /// Update the maximum scroll value based on content height and viewport height
-    pub fn update_detail_max_scroll(&mut self, content_lines: u16, viewport_height: u16) {
-        // Maximum scroll is the content lines minus the viewport height
-        // If content fits in viewport, max scroll is 0
-        self.detail_max_scroll = content_lines.saturating_sub(viewport_height);
+        let max_items = self.config.general.max_dashboard_items;
+        let existing = std::mem::take(&mut self.dashboard_items);
+        let mut merged = Vec::with_capacity((existing.len() + incoming.len()).min(max_items));
+        let mut existing = existing.into_iter().peekable();
+        let mut incoming = incoming.into_iter().peekable();
+
+        while merged.len() < max_items {
+            let take_incoming = match (existing.peek(), incoming.peek()) {
+                (Some(&(f, i)), Some(new)) => {
+                    let current = self.feeds[f].items[i].parsed_date;
+                    newest_first(&new.2, &current) == std::cmp::Ordering::Less
+                }
+                (None, Some(_)) => true,
+                (Some(_), None) => false,
+                (None, None) => break,
+            };
+            if take_incoming {
+                if let Some((f, i, _)) = incoming.next() {
+                    merged.push((f, i));
+                }
+            } else if let Some(entry) = existing.next() {
+                merged.push(entry);
+            }
+        }
+
+        self.dashboard_items = merged;
+        self.apply_filters_for_feed(feed_idx);
     }
 
-    /// Clamp the current scroll position to valid bounds
-    pub fn clamp_detail_scroll(&mut self) {
-        if self.detail_vertical_scroll > self.detail_max_scroll {
-            self.detail_vertical_scroll = self.detail_max_scroll;
+    /// Re-evaluate filters only for `feed_idx`, reusing previous results for other feeds.
+    fn apply_filters_for_feed(&mut self, feed_idx: usize) {
+        if !self.filter_options.is_active() {
+            self.filtered_dashboard_items
+                .clone_from(&self.dashboard_items);
+        } else {
+            let previous: HashSet<(usize, usize)> =
+                self.filtered_dashboard_items.drain(..).collect();
+            let filtered: Vec<(usize, usize)> = self
+                .dashboard_items
+                .iter()
+                .filter(|&&entry| {
+                    if entry.0 == feed_idx {
+                        self.item_matches_filter(entry.0, entry.1)
+                    } else {
+                        previous.contains(&entry)
+                    }
+                })
+                .cloned()
+                .collect();
+            self.filtered_dashboard_items = filtered;
+        }
+        if self.item_sort == ItemSort::OldestFirst {
+            self.filtered_dashboard_items.reverse();
         }
-    }
 
-    /// Update the maximum scroll value for preview pane based on content height and viewport height
-    pub fn update_preview_max_scroll(&mut self, content_lines: u16, viewport_height: u16) {
-        self.preview_max_scroll = content_lines.saturating_sub(viewport_height);
+        self.rebuild_dashboard_sections();
+        self.clamp_dashboard_selection();
     }
 
-    /// Clamp the preview scroll position to valid bounds
-    pub fn clamp_preview_scroll(&mut self) {
-        if self.preview_scroll > self.preview_max_scroll {
-            self.preview_scroll = self.preview_max_scroll;
+    pub fn add_feed(&mut self, url: &str) -> Result<AddFeedResult> {
+        crate::globals::ensure_writable("Adding feeds")?;
+        match self.fetch_feed(url)? {
+            crate::feed::FeedFetchResult::Feed(feed) => {
+                self.subscribe_fetched(url, feed)?;
+                Ok(AddFeedResult::Added)
+            }
+            crate::feed::FeedFetchResult::DiscoveredFeeds { feeds, page_url } => {
+                Ok(AddFeedResult::DiscoveredFeeds { feeds, page_url })
+            }
         }
     }
 
-    /// Exit the detail view and reset scroll position
-    pub fn exit_detail_view(&mut self, new_view: View) {
-        self.detail_vertical_scroll = 0;
-        self.view = new_view;
+    /// Fetch `url` with the configured timeout, user agent and headers, without subscribing.
+    pub fn fetch_feed(&self, url: &str) -> Result<crate::feed::FeedFetchResult> {
+        let client = self.http_client()?;
+        Feed::fetch_url(url, &client, &self.request_options(url))
     }
 
-    /// Check if auto-refresh should trigger
-    pub fn should_auto_refresh(&self) -> bool {
-        if self.refresh_in_progress {
-            return false;
-        }
+    /// The shared HTTP client, with the `[network]` timeouts.
+    pub fn http_client(&self) -> Result<reqwest::blocking::Client> {
+        Feed::build_client_with(
+            self.config.network.http_timeout,
+            self.config.network.connect_timeout,
+        )
+    }
 
-        // Check global refresh
-        if self.config.general.refresh_enabled && self.config.general.auto_refresh_interval > 0 {
-            if let Some(last_refresh) = self.last_refresh {
-                if last_refresh.elapsed().as_secs() >= self.config.general.auto_refresh_interval {
-                    return true;
-                }
-            } else {
-                // Never refreshed, so we should refresh
-                return true;
-            }
+    /// How to request `url`: the `[network]` settings with the feed's own overrides and
+    /// headers from its `[[default_feeds]]` entry.
+    pub fn request_options(&self, url: &str) -> RequestOptions {
+        let network = &self.config.network;
+        let feed = self.config.default_feeds.iter().find(|f| f.url == url);
+        RequestOptions {
+            user_agent: Some(
+                feed.and_then(|f| f.user_agent.clone())
+                    .unwrap_or_else(|| network.user_agent.clone()),
+            ),
+            accept_language: Some(
+                feed.and_then(|f| f.accept_language.clone())
+                    .unwrap_or_else(|| network.accept_language.clone()),
+            ),
+            timeout: feed.and_then(|f| f.timeout),
+            // Only a feed's own connect timeout needs a client of its own
+            connect_timeout: feed.and_then(|f| f.connect_timeout),
+            headers: self.feed_headers.get(url).cloned().unwrap_or_default(),
+            cache: network.http_cache.then(HttpCache::default_location),
+            max_cache_age: 0,
         }
+    }
 
-        // Check per-feed intervals — these act as trigger thresholds for a
-        // global refresh (all feeds are refreshed together). True selective
-        // per-feed refresh is not yet implemented.
-        for (url, &interval) in &self.feed_refresh_intervals {
-            if let Some(last) = self.last_feed_refresh.get(url) {
-                if last.elapsed().as_secs() >= interval {
-                    return true;
-                }
-            } else {
-                // Never refreshed this feed individually — check global last refresh
-                if let Some(last) = self.last_refresh {
-                    if last.elapsed().as_secs() >= interval {
-                        return true;
-                    }
-                } else {
-                    // Never refreshed at all, trigger refresh
-                    return true;
-                }
-            }
+    fn subscribe_fetched(&mut self, url: &str, mut feed: Feed) -> Result<()> {
+        self.apply_feed_settings(&mut feed);
+        self.feeds.push(feed);
+        if !self.bookmarks.contains(&url.to_string()) {
+            self.bookmarks.push(url.to_string());
         }
-
-        false
+        self.update_dashboard();
+        self.rebuild_feed_tree();
+        self.save_data()
     }
 
-    /// Extract domain from URL (e.g., "reddit.com" from "https://www.reddit.com/r/rust/.rss")
-    fn extract_domain_from_url(url: &str) -> String {
-        // Simple domain extraction
-        if let Some(domain_start) = url.find("://") {
-            let after_protocol = &url[domain_start + 3..];
-            let domain_end = after_protocol.find('/').unwrap_or(after_protocol.len());
-            let domain = &after_protocol[..domain_end];
+    /// Show a fetched feed in the preview, where the user can subscribe or cancel.
+    pub fn open_feed_preview(&mut self, url: &str, feed: Feed) {
+        self.feed_preview = Some(FeedPreview {
+            url: url.to_string(),
+            feed,
+            category: None,
+        });
+        self.input_mode = InputMode::PreviewFeed;
+    }
 
-            // Remove www. prefix if present
-            domain.strip_prefix("www.").unwrap_or(domain).to_string()
-        } else {
-            // No protocol, try to extract domain directly
-            let domain_end = url.find('/').unwrap_or(url.len());
-            let domain = &url[..domain_end];
+    /// Step the preview's category through none and each existing category.
+    pub fn cycle_preview_category(&mut self) {
+        let count = self.categories.len();
+        if let Some(preview) = self.feed_preview.as_mut() {
+            preview.category = match preview.category {
+                None if count > 0 => Some(0),
+                Some(idx) if idx + 1 < count => Some(idx + 1),
+                _ => None,
+            };
+        }
+    }
 
-            domain.strip_prefix("www.").unwrap_or(domain).to_string()
+    /// Subscribe to the previewed feed, filing it under the chosen category, and close
+    /// the preview. Returns the feed's title.
+    pub fn subscribe_previewed_feed(&mut self) -> Result<String> {
+        self.input_mode = InputMode::Normal;
+        let Some(preview) = self.feed_preview.take() else {
+            anyhow::bail!("No feed is being previewed");
+        };
+        if self.bookmarks.contains(&preview.url) {
+            anyhow::bail!("Already subscribed to {}", preview.url);
         }
+        let title = preview.feed.title.clone();
+        self.subscribe_fetched(&preview.url, preview.feed)?;
+        if let Some(idx) = preview.category {
+            self.assign_feed_to_category(&preview.url, idx)?;
+        }
+        Ok(title)
     }
 
-    /// Calculate required delay before fetching from a domain
-    fn calculate_required_delay(&self, domain: &str) -> std::time::Duration {
-        if let Some(last_fetch) = self.last_domain_fetch.get(domain) {
-            let elapsed = last_fetch.elapsed();
-            let required_delay =
-                std::time::Duration::from_millis(self.config.general.refresh_rate_limit_delay);
+    pub fn cancel_feed_preview(&mut self) {
+        self.feed_preview = None;
+        self.input_mode = InputMode::Normal;
+    }
 
-            if elapsed < required_delay {
-                required_delay - elapsed
-            } else {
-                std::time::Duration::from_secs(0)
-            }
-        } else {
-            std::time::Duration::from_secs(0)
+    /// Apply the user's settings to a freshly fetched feed: date undated items from their
+    /// text, drop items past its item limit and use their title in place of the one it
+    /// publishes, if they chose one.
+    fn apply_feed_settings(&self, feed: &mut Feed) {
+        let formats = self
+            .feed_date_formats
+            .get(&feed.url)
+            .map_or(&[][..], Vec::as_slice);
+        feed.recover_dates(formats);
+        let max_items = self
+            .feed_max_items
+            .get(&feed.url)
+            .copied()
+            .unwrap_or(self.config.general.max_items_per_feed);
+        if max_items > 0 {
+            feed.keep_newest(max_items);
+        }
+        if let Some(title) = self.feed_titles.get(&feed.url) {
+            feed.title.clone_from(title);
+            feed.title_lower = fold_for_search(title);
         }
     }
 
-    pub fn toggle_preview_pane(&mut self) {
-        self.preview_pane = !self.preview_pane;
-        self.preview_scroll = 0;
-        self.preview_max_scroll = 0;
+    /// Compare a freshly fetched feed's title, site link and description with what it
+    /// published last time, and queue a notice when they differ. A new title doesn't
+    /// relabel the feed: the one it had stays as its custom title until the user adopts
+    /// the new name with `resolve_feed_change`.
+    fn note_feed_meta(&mut self, feed: &Feed) {
+        let new = FeedMeta::of(feed);
+        let Some(old) = self.feed_meta.insert(feed.url.clone(), new.clone()) else {
+            return;
+        };
+        if old == new {
+            return;
+        }
+        if old.title != new.title && !self.feed_titles.contains_key(&feed.url) {
+            self.feed_titles.insert(feed.url.clone(), old.title.clone());
+        }
+        // A notice that's still waiting keeps its original details and takes the new ones
+        match self.feed_changes.iter_mut().find(|c| c.url == feed.url) {
+            Some(pending) => pending.new = new,
+            None => self.feed_changes.push(FeedChange {
+                url: feed.url.clone(),
+                old,
+                new,
+            }),
+        }
+        self.feed_changes.retain(|c| c.old != c.new);
+        self.mark_data_dirty();
     }
 
-    pub fn reset_preview_scroll(&mut self) {
-        self.preview_scroll = 0;
-        self.preview_max_scroll = 0;
+    /// The feed change notice to show now: the oldest one, once no refresh is running
+    /// and no text box is open.
+    pub fn pending_feed_change(&self) -> Option<&FeedChange> {
+        if self.refresh_in_progress || self.input_mode != InputMode::Normal {
+            return None;
+        }
+        self.feed_changes.first()
     }
 
-    /// Get new items since last session, returns (feed_idx, item_idx, feed_title)
-    pub fn get_new_items_since_session(&self) -> Vec<(usize, usize, &str)> {
-        let session_time = match self.last_session_time {
-            Some(t) => t,
-            None => return Vec::new(),
-        };
+    /// Dismiss the oldest feed change notice. With `adopt_title`, the feed is shown
+    /// under the title it now publishes instead of the one it had.
+    pub fn resolve_feed_change(&mut self, adopt_title: bool) {
+        if self.feed_changes.is_empty() {
+            return;
+        }
+        let change = self.feed_changes.remove(0);
+        if !adopt_title || change.old.title == change.new.title {
+            return;
+        }
+        self.feed_titles.remove(&change.url);
+        for feed in self.feeds.iter_mut().filter(|f| f.url == change.url) {
+            feed.title.clone_from(&change.new.title);
+            feed.title_lower = fold_for_search(&change.new.title);
+        }
+        self.update_dashboard();
+        self.rebuild_feed_tree();
+        self.mark_data_dirty();
+    }
 
-        let mut items = Vec::new();
-        for (feed_idx, feed) in self.feeds.iter().enumerate() {
-            for (item_idx, item) in feed.items.iter().enumerate() {
-                if let Some(parsed_date) = item.parsed_date {
-                    if parsed_date > session_time {
-                        items.push((feed_idx, item_idx, feed.title.as_str()));
-                    }
-                }
+    /// Set (or with None, clear) the title shown for a feed instead of its own.
+    /// A cleared title comes back on the feed's next refresh.
+    pub fn set_feed_title(&mut self, url: &str, title: Option<&str>) -> Result<()> {
+        match title.map(str::trim).filter(|t| !t.is_empty()) {
+            Some(title) => {
+                self.feed_titles.insert(url.to_string(), title.to_string());
+            }
+            None => {
+                self.feed_titles.remove(url);
             }
         }
-        items
+        let mut feeds = std::mem::take(&mut self.feeds);
+        for feed in feeds.iter_mut().filter(|feed| feed.url == url) {
+            self.apply_feed_settings(feed);
+        }
+        self.feeds = feeds;
+        self.rebuild_feed_tree();
+        self.save_data()
     }
 
-    /// Get summary stats for the "What's New" view
-    pub fn get_summary_stats(&self) -> (usize, Vec<(String, usize)>) {
-        let new_items = self.get_new_items_since_session();
-        let total = new_items.len();
+    /// Index of the category named `name` (ignoring case), creating it if there is none.
+    pub fn category_index_or_create(&mut self, name: &str) -> Result<usize> {
+        let name = name.trim();
+        if let Some(idx) = self
+            .categories
+            .iter()
+            .position(|c| c.name.eq_ignore_ascii_case(name))
+        {
+            return Ok(idx);
+        }
+        self.create_category(name)?;
+        Ok(self.categories.len() - 1)
+    }
 
-        // Count items per feed
-        let mut feed_counts: HashMap<&str, usize> = HashMap::new();
-        for &(_, _, feed_title) in &new_items {
-            *feed_counts.entry(feed_title).or_insert(0) += 1;
+    fn opml_dfs(outline: &opml::Outline) -> Vec<String> {
+        let mut urls = Vec::<String>::new();
+        if let Some(url) = &outline.xml_url {
+            urls.push(url.to_string())
         }
+        for o in &outline.outlines {
+            urls.append(&mut Self::opml_dfs(o));
+        }
+        urls
+    }
 
-        let mut feeds_with_counts: Vec<(String, usize)> = feed_counts
-            .into_iter()
-            .map(|(name, count)| (name.to_string(), count))
-            .collect();
-        feeds_with_counts.sort_by(|a, b| b.1.cmp(&a.1));
+    /// Every feed URL in an OPML document, in document order.
+    fn opml_document_urls(document: &opml::OPML) -> Vec<String> {
+        document
+            .body
+            .outlines
+            .iter()
+            .flat_map(Self::opml_dfs)
+            .collect()
+    }
 
-        (total, feeds_with_counts)
+    /// Every feed URL in an OPML file (or NetNewsWire account folder), in document order.
+    fn opml_urls(file_path: &str) -> Result<Vec<String>> {
+        let document = crate::reader_import::load_opml(Path::new(file_path))?;
+        Ok(Self::opml_document_urls(&document))
     }
 
-    /// Toggle between light and dark themes
-    pub fn toggle_theme(&mut self) -> Result<()> {
-        use crate::config::Theme;
+    /// URLs from a pasted or loaded list: one or more per line, separated by whitespace.
+    /// Blank lines and lines starting with `#` are skipped.
+    pub fn parse_url_list(text: &str) -> Vec<String> {
+        text.lines()
+            .map(str::trim)
+            .filter(|line| !line.starts_with('#'))
+            .flat_map(str::split_whitespace)
+            .map(crate::packages::expand)
+            .collect()
+    }
 
-        self.config.ui.theme = match self.config.ui.theme {
-            Theme::Dark => Theme::Light,
-            Theme::Light => Theme::Dark,
-        };
+    /// Split import URLs into those worth fetching and duplicates: already subscribed,
+    /// or repeated earlier in the list.
+    fn partition_import_urls(&self, urls: Vec<String>) -> (Vec<String>, Vec<String>) {
+        let mut seen: HashSet<String> = self.bookmarks.iter().cloned().collect();
+        urls.into_iter().partition(|url| seen.insert(url.clone()))
+    }
 
-        // Update cached color scheme
-        self.color_scheme = ColorScheme::from_theme(&self.config.ui.theme);
+    /// Check an OPML file without importing it: fetch each new entry to see whether it's
+    /// a valid feed, and list the duplicates.
+    pub fn import_opml_dry_run(&self, file_path: &str) -> Result<ImportReport> {
+        let (to_check, duplicates) = self.partition_import_urls(Self::opml_urls(file_path)?);
+        let client = self.http_client()?;
 
-        // Save the updated config
-        self.config.save()?;
+        let mut report = ImportReport {
+            duplicates,
+            ..ImportReport::default()
+        };
+        for url in to_check {
+            let result = Feed::fetch_url(&url, &client, &self.request_options(&url))
+                .and_then(|r| r.into_feed());
+            match result {
+                Ok(feed) => report.new.push((url, feed.title)),
+                Err(e) => report.failed.push((url, e.to_string())),
+            }
+        }
+        Ok(report)
+    }
 
+    /// Subscribe to every feed in `urls`, fetching them concurrently (one thread per
+    /// domain, rate-limited within a domain like a refresh). Duplicates are skipped.
+    pub fn add_feeds_bulk(&mut self, urls: Vec<String>) -> Result<ImportReport> {
+        crate::globals::ensure_writable("Adding feeds")?;
+        let (to_fetch, duplicates) = self.partition_import_urls(urls);
+        let client = self.http_client()?;
+        let rate_limit = Duration::from_millis(self.config.general.refresh_rate_limit_delay);
+
+        let mut domain_groups: HashMap<String, Vec<String>> = HashMap::new();
+        for url in &to_fetch {
+            let domain = Self::extract_domain_from_url(url);
+            domain_groups.entry(domain).or_default().push(url.clone());
+        }
+        let handles: Vec<_> = domain_groups
+            .into_values()
+            .map(|urls| {
+                let client = client.clone();
+                let options: Vec<RequestOptions> =
+                    urls.iter().map(|url| self.request_options(url)).collect();
+                std::thread::spawn(move || {
+                    let mut results = Vec::new();
+                    for (i, (url, options)) in urls.into_iter().zip(options).enumerate() {
+                        if i > 0 && !rate_limit.is_zero() {
+                            std::thread::sleep(rate_limit);
+                        }
+                        let result =
+                            Feed::fetch_url(&url, &client, &options).and_then(|r| r.into_feed());
+                        results.push((url, result));
+                    }
+                    results
+                })
+            })
+            .collect();
+        let mut fetched: HashMap<String, Result<Feed>> = HashMap::new();
+        for handle in handles {
+            if let Ok(results) = handle.join() {
+                fetched.extend(results);
+            }
+        }
+
+        // Report and subscribe in the order the URLs were given
+        let mut report = ImportReport {
+            duplicates,
+            ..ImportReport::default()
+        };
+        for url in to_fetch {
+            match fetched.remove(&url) {
+                Some(Ok(mut feed)) => {
+                    self.apply_feed_settings(&mut feed);
+                    report.new.push((url.clone(), feed.title.clone()));
+                    self.feeds.push(feed);
+                    self.bookmarks.push(url);
+                }
+                Some(Err(e)) => report.failed.push((url, e.to_string())),
+                None => report
+                    .failed
+                    .push((url, "Fetch thread panicked".to_string())),
+            }
+        }
+        if !report.new.is_empty() {
+            self.update_dashboard();
+            self.rebuild_feed_tree();
+            self.save_data()?;
+        }
+        Ok(report)
+    }
+
+    /// Subscribe to every feed in an OPML file, or a NetNewsWire account folder. Feeds
+    /// filed in a folder go into the category of the same name.
+    pub fn import_opml(&mut self, file_path: &str) -> Result<()> {
+        crate::globals::ensure_writable("Importing feeds")?;
+        let document = crate::reader_import::load_opml(Path::new(file_path))?;
+        for subscription in crate::reader_import::subscriptions(&document) {
+            let feed = subscription.url;
+            match self.add_feed(&feed) {
+                Ok(AddFeedResult::Added) => {
+                    println!("Feed {} added", feed);
+                    if let Some(folder) = subscription.folder {
+                        let idx = self.category_index_or_create(&folder)?;
+                        self.assign_feed_to_category(&feed, idx)?;
+                    }
+                }
+                Ok(AddFeedResult::DiscoveredFeeds { feeds, .. }) => {
+                    eprintln!(
+                        "Skipping {}: HTML page ({} feed links found, use TUI to select)",
+                        feed,
+                        feeds.len()
+                    );
+                }
+                Err(e) => eprintln!("Error adding {}: {}", feed, e),
+            }
+        }
         Ok(())
     }
 
-    pub fn extract_links_from_current_item(&mut self) {
-        use scraper::{Html, Selector};
+    /// Subscribe to the feeds in an OPML file from the UI: they're fetched like a pasted
+    /// list, and new ones filed in a folder go into the category of the same name.
+    pub fn import_opml_file(&mut self, path: &Path) -> Result<ImportReport> {
+        crate::globals::ensure_writable("Importing feeds")?;
+        let document = crate::reader_import::load_opml(path)?;
+        let subscriptions = crate::reader_import::subscriptions(&document);
+        let urls = subscriptions.iter().map(|s| s.url.clone()).collect();
+        let report = self.add_feeds_bulk(urls)?;
+        for subscription in subscriptions {
+            let Some(folder) = subscription.folder else {
+                continue;
+            };
+            if report.new.iter().any(|(url, _)| *url == subscription.url) {
+                let idx = self.category_index_or_create(&folder)?;
+                self.assign_feed_to_category(&subscription.url, idx)?;
+            }
+        }
+        Ok(report)
+    }
 
-        self.extracted_links.clear();
-        self.selected_link = 0;
+    /// Write the subscriptions to `path` as OPML, returning how many feeds went in.
+    pub fn export_opml_file(&self, path: &Path) -> Result<usize> {
+        fs::write(path, self.export_opml()?)
+            .with_context(|| format!("Writing {}", path.display()))?;
+        Ok(self.bookmarks.len())
+    }
 
-        let description = if let Some(feed_idx) = self.selected_feed {
-            if let Some(item_idx) = self.selected_item {
-                self.feeds
-                    .get(feed_idx)
-                    .and_then(|f| f.items.get(item_idx))
-                    .and_then(|item| item.description.as_ref())
-                    .cloned()
-            } else {
-                None
+    /// Offer a path given to a path dialog first next time.
+    pub fn remember_path(&mut self, path: &str) {
+        crate::path_input::remember(&mut self.recent_paths, path);
+        self.mark_data_dirty();
+    }
+
+    /// Where the keybinding cheat sheet is offered to go: next to the config file.
+    pub fn cheat_sheet_path() -> PathBuf {
+        Config::config_path().with_file_name("keybindings.md")
+    }
+
+    /// Write the effective keybindings to `path` as a Markdown cheat sheet.
+    pub fn export_keys_cheat_sheet(&self, path: &Path) -> Result<()> {
+        let sheet =
+            crate::keybindings::cheat_sheet(&self.keybindings, crate::cli::KeysFormat::Markdown);
+        fs::write(path, sheet).with_context(|| format!("Writing {}", path.display()))
+    }
+
+    /// Subscriptions as an OPML document: one outline per category, then feeds in no
+    /// category at the top level. Titles come from loaded feeds where there are any.
+    pub fn export_opml(&self) -> Result<String> {
+        let feed_outline = |url: &String| {
+            let title = self
+                .feed_titles
+                .get(url)
+                .or_else(|| self.feeds.iter().find(|f| &f.url == url).map(|f| &f.title))
+                .unwrap_or(url);
+            opml::Outline {
+                text: title.clone(),
+                title: Some(title.clone()),
+                r#type: Some("rss".to_string()),
+                xml_url: Some(url.clone()),
+                ..opml::Outline::default()
             }
-        } else {
-            None
         };
 
-        let Some(html_content) = description else {
+        let mut document = opml::OPML::default();
+        document.head = Some(opml::Head {
+            title: Some("Feedr subscriptions".to_string()),
+            ..opml::Head::default()
+        });
+        for category in &self.categories {
+            document.body.outlines.push(opml::Outline {
+                text: category.name.clone(),
+                title: Some(category.name.clone()),
+                outlines: self
+                    .bookmarks
+                    .iter()
+                    .filter(|url| category.feeds.contains(*url))
+                    .map(feed_outline)
+                    .collect(),
+                ..opml::Outline::default()
+            });
+        }
+        document.body.outlines.extend(
+            self.bookmarks
+                .iter()
+                .filter(|url| !self.categories.iter().any(|c| c.feeds.contains(*url)))
+                .map(feed_outline),
+        );
+        document
+            .to_string()
+            .map_err(|e| anyhow::anyhow!("OPML encode error. {}", e))
+    }
+
+    /// Write starred items to a Netscape bookmarks file, returning how many were exported.
+    /// Titles and dates come from loaded feeds; items no longer in any feed fall back to their URL.
+    pub fn export_starred_bookmarks(&self, path: &Path) -> Result<usize> {
+        let mut loaded: HashMap<String, (usize, usize)> = HashMap::new();
+        for (feed_idx, feed) in self.feeds.iter().enumerate() {
+            for item_idx in 0..feed.items.len() {
+                loaded.insert(self.get_item_id(feed_idx, item_idx), (feed_idx, item_idx));
+            }
+        }
+
+        let mut ids: Vec<&String> = self.starred_items.iter().collect();
+        ids.sort();
+        let bookmarks: Vec<crate::netscape::Bookmark> = ids
+            .into_iter()
+            .filter_map(|id| match loaded.get(id) {
+                Some(&(feed_idx, item_idx)) => {
+                    let item = &self.feeds[feed_idx].items[item_idx];
+                    Some(crate::netscape::Bookmark {
+                        title: item.title.clone(),
+                        url: item.link.clone()?,
+                        add_date: item.parsed_date.map(|d| d.timestamp()),
+                    })
+                }
+                // Link-less items are keyed "feedurl_title" and have nothing to bookmark
+                None if url::Url::parse(id).is_ok()
+                    && !self
+                        .bookmarks
+                        .iter()
+                        .any(|b| id.starts_with(&format!("{}_", b))) =>
+                {
+                    Some(crate::netscape::Bookmark {
+                        title: id.clone(),
+                        url: id.clone(),
+                        add_date: None,
+                    })
+                }
+                None => None,
+            })
+            .collect();
+
+        fs::write(path, crate::netscape::export("Feedr Starred", &bookmarks))?;
+        Ok(bookmarks.len())
+    }
+
+    /// Star every link in a Netscape bookmarks file, returning how many were new.
+    pub fn import_starred_bookmarks(&mut self, path: &Path) -> Result<usize> {
+        let html = fs::read_to_string(path)?;
+        let links = crate::netscape::parse(&html).into_iter().map(|b| b.url);
+        self.import_starred_links(links)
+    }
+
+    /// Star every article in another reader's starred export (see
+    /// `reader_import::starred_links`), returning how many were new.
+    pub fn import_starred_export(&mut self, path: &Path) -> Result<usize> {
+        let links = crate::reader_import::starred_links(path)?;
+        self.import_starred_links(links)
+    }
+
+    /// How many articles in a starred export `import_starred_export` would newly star.
+    pub fn import_starred_export_dry_run(&self, path: &Path) -> Result<usize> {
+        let links: HashSet<String> = crate::reader_import::starred_links(path)?
+            .into_iter()
+            .collect();
+        Ok(links
+            .iter()
+            .filter(|link| !self.starred_items.contains(*link))
+            .count())
+    }
+
+    fn import_starred_links(&mut self, links: impl IntoIterator<Item = String>) -> Result<usize> {
+        crate::globals::ensure_writable("Importing stars")?;
+        let mut added = 0;
+        for link in links {
+            if self.starred_items.insert(link.clone()) {
+                self.record_state(&link);
+                added += 1;
+            }
+        }
+        if added > 0 {
+            self.save_data()?;
+        }
+        Ok(added)
+    }
+
+    pub fn remove_current_feed(&mut self) -> Result<()> {
+        crate::globals::ensure_writable("Removing feeds")?;
+        if let Some(idx) = self.selected_feed {
+            if idx < self.feeds.len() {
+                let url = self.feeds[idx].url.clone();
+                let removed = RemovedFeed {
+                    bookmark_index: self.bookmarks.iter().position(|x| x == &url),
+                    feed_index: idx,
+                    feed: self.feeds[idx].clone(),
+                    category_ids: self
+                        .categories
+                        .iter()
+                        .filter(|category| category.contains_feed(&url))
+                        .map(|category| category.id.clone())
+                        .collect(),
+                    title: self.feed_titles.get(&url).cloned(),
+                    engagement: self.engagement.get(&url).cloned(),
+                    archived: self.archived_feeds.contains(&url),
+                    url,
+                };
+                self.drop_feed(&removed.url);
+                HttpCache::default_location().remove(&removed.url);
+                self.edit_history
+                    .record(TreeEdit::RemoveFeed(Box::new(removed)));
+
+                // Update dashboard
+                self.update_dashboard();
+                self.rebuild_feed_tree();
+
+                // Save changes
+                self.save_data()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Forget a loaded feed: its items, subscription, categories and what's kept about it
+    fn drop_feed(&mut self, url: &str) {
+        let Some(idx) = self.feeds.iter().position(|feed| feed.url == url) else {
             return;
         };
 
-        let base_url = self
-            .selected_feed
-            .and_then(|fi| {
-                self.selected_item.and_then(|ii| {
-                    self.feeds
-                        .get(fi)
-                        .and_then(|f| f.items.get(ii).and_then(|item| item.link.as_ref()))
-                })
-            })
-            .and_then(|link| url::Url::parse(link).ok());
+        // Remove from feeds
+        self.feeds.remove(idx);
 
-        let document = Html::parse_document(&html_content);
-        let mut seen_urls = std::collections::HashSet::new();
+        // Remove from bookmarks
+        if let Some(pos) = self.bookmarks.iter().position(|x| x == url) {
+            self.bookmarks.remove(pos);
+        }
 
-        // Extract links
-        if let Ok(selector) = Selector::parse("a[href]") {
-            for element in document.select(&selector) {
-                if let Some(href) = element.value().attr("href") {
-                    let resolved = if let Some(base) = &base_url {
-                        base.join(href)
-                            .map(|u| u.to_string())
-                            .unwrap_or_else(|_| href.to_string())
+        // Remove from all categories
+        for category in &mut self.categories {
+            category.remove_feed(url);
+        }
+        self.feed_titles.remove(url);
+        self.engagement.remove(url);
+        self.archived_feeds.remove(url);
+
+        // Update selected feed
+        if !self.feeds.is_empty() {
+            if self
+                .selected_feed
+                .is_some_and(|selected| selected >= self.feeds.len())
+            {
+                self.selected_feed = Some(self.feeds.len() - 1);
+            }
+        } else {
+            self.selected_feed = None;
+            self.view = View::Dashboard;
+        }
+    }
+
+    // Put a removed feed back where it was, with its categories and what was kept about it
+    fn restore_feed(&mut self, removed: &RemovedFeed) {
+        let url = &removed.url;
+        if let Some(pos) = removed.bookmark_index {
+            self.bookmarks
+                .insert(pos.min(self.bookmarks.len()), url.clone());
+        }
+        for category in &mut self.categories {
+            if removed.category_ids.contains(&category.id) {
+                category.add_feed(url);
+            }
+        }
+        if let Some(title) = &removed.title {
+            self.feed_titles.insert(url.clone(), title.clone());
+        }
+        if let Some(engagement) = &removed.engagement {
+            self.engagement.insert(url.clone(), engagement.clone());
+        }
+        if removed.archived {
+            self.archived_feeds.insert(url.clone());
+        }
+        self.insert_feed(removed.feed_index, removed.feed.clone());
+    }
+
+    /// The edits that can be undone and redone.
+    pub fn edit_history(&self) -> &EditHistory {
+        &self.edit_history
+    }
+
+    /// Undo the last category or subscription edit, returning what it had done. None
+    /// when there's nothing to undo.
+    pub fn undo_edit(&mut self) -> Option<String> {
+        let edit = self.edit_history.undo()?;
+        self.apply_edit(&edit, false);
+        self.finish_edit();
+        Some(edit.describe())
+    }
+
+    /// Make an undone edit again, returning what it does. None when there's nothing to
+    /// redo.
+    pub fn redo_edit(&mut self) -> Option<String> {
+        let edit = self.edit_history.redo()?;
+        self.apply_edit(&edit, true);
+        self.finish_edit();
+        Some(edit.describe())
+    }
+
+    // Make `edit` (`forward`) or reverse it
+    fn apply_edit(&mut self, edit: &TreeEdit, forward: bool) {
+        let find =
+            |categories: &[FeedCategory], id: &str| categories.iter().position(|c| c.id == id);
+        match edit {
+            TreeEdit::CreateCategory(created) => {
+                if forward {
+                    self.categories.push(created.clone());
+                } else {
+                    self.categories.retain(|c| c.id != created.id);
+                }
+            }
+            TreeEdit::RenameCategory { id, from, to } => {
+                if let Some(idx) = find(&self.categories, id) {
+                    self.categories[idx].rename(if forward { to } else { from });
+                }
+            }
+            TreeEdit::DeleteCategory { index, category } => {
+                if forward {
+                    self.categories.retain(|c| c.id != category.id);
+                } else {
+                    let index = (*index).min(self.categories.len());
+                    self.categories.insert(index, category.clone());
+                }
+            }
+            TreeEdit::AssignFeed { category_id, url }
+            | TreeEdit::UnassignFeed { category_id, url } => {
+                let add = forward == matches!(edit, TreeEdit::AssignFeed { .. });
+                if let Some(idx) = find(&self.categories, category_id) {
+                    if add {
+                        self.categories[idx].add_feed(url);
                     } else {
-                        href.to_string()
-                    };
-                    if seen_urls.insert(resolved.clone()) {
-                        let text = element.text().collect::<String>().trim().to_string();
-                        self.extracted_links.push(ExtractedLink {
-                            url: resolved,
-                            text: if text.is_empty() {
-                                "(no text)".to_string()
-                            } else {
-                                text
-                            },
-                            link_type: LinkType::Link,
-                        });
+                        self.categories[idx].remove_feed(url);
                     }
                 }
             }
+            TreeEdit::RemoveFeed(removed) => {
+                if forward {
+                    self.drop_feed(&removed.url);
+                } else {
+                    self.restore_feed(removed);
+                }
+            }
         }
+    }
 
-        // Extract images
-        if let Ok(selector) = Selector::parse("img[src]") {
-            for element in document.select(&selector) {
-                if let Some(src) = element.value().attr("src") {
-                    let resolved = if let Some(base) = &base_url {
-                        base.join(src)
-                            .map(|u| u.to_string())
-                            .unwrap_or_else(|_| src.to_string())
-                    } else {
-                        src.to_string()
-                    };
-                    if seen_urls.insert(resolved.clone()) {
-                        let alt = element.value().attr("alt").unwrap_or("").trim().to_string();
-                        self.extracted_links.push(ExtractedLink {
-                            url: resolved,
-                            text: if alt.is_empty() {
-                                "(image)".to_string()
-                            } else {
-                                alt
-                            },
-                            link_type: LinkType::Image,
-                        });
-                    }
+    fn finish_edit(&mut self) {
+        self.selected_category = match self.categories.len() {
+            0 => None,
+            len => Some(self.selected_category.unwrap_or(0).min(len - 1)),
+        };
+        self.update_dashboard();
+        self.rebuild_feed_tree();
+        self.mark_data_dirty();
+    }
+
+    /// Drop a subscription by URL, including its category memberships. Does not save.
+    pub fn remove_bookmark(&mut self, url: &str) -> bool {
+        let before = self.bookmarks.len();
+        self.bookmarks.retain(|b| b != url);
+        for category in &mut self.categories {
+            category.remove_feed(url);
+        }
+        self.bookmarks.len() != before
+    }
+
+    /// Point a subscription at a new URL, keeping its position and categories. Does not save.
+    pub fn replace_bookmark_url(&mut self, old: &str, new: &str) -> bool {
+        if self.bookmarks.iter().any(|b| b == new) {
+            // Already subscribed under the new URL; just drop the old entry
+            return self.remove_bookmark(old);
+        }
+        let Some(pos) = self.bookmarks.iter().position(|b| b == old) else {
+            return false;
+        };
+        self.bookmarks[pos] = new.to_string();
+        for category in &mut self.categories {
+            if category.remove_feed(old) {
+                category.add_feed(new);
+            }
+        }
+        true
+    }
+
+    pub fn current_feed(&self) -> Option<&Feed> {
+        self.selected_feed.and_then(|idx| self.feeds.get(idx))
+    }
+
+    pub fn current_item(&self) -> Option<&FeedItem> {
+        self.current_feed()
+            .and_then(|feed| self.selected_item.and_then(|idx| feed.items.get(idx)))
+    }
+
+    /// The `(feed_idx, item_idx)` under the cursor in the current view, if any.
+    pub fn selected_item_indices(&self) -> Option<(usize, usize)> {
+        let selected = self.selected_item?;
+        match self.view {
+            View::Dashboard => self.active_dashboard_items().get(selected).copied(),
+            View::Starred => self.get_starred_dashboard_items().get(selected).copied(),
+            View::Alerts => self.get_alert_dashboard_items().get(selected).copied(),
+            View::Triage => self.current_triage_item(),
+            View::ReadLater => self.read_later_entries().get(selected).copied().flatten(),
+            View::Releases => {
+                let feed_idx = self.selected_feed?;
+                Some((feed_idx, self.release_timeline().get(selected)?.item_idx))
+            }
+            View::FeedItems | View::FeedItemDetail => {
+                let feed_idx = self.selected_feed?;
+                self.feeds.get(feed_idx)?.items.get(selected)?;
+                Some((feed_idx, selected))
+            }
+            _ => None,
+        }
+    }
+
+    /// Why an item is listed where it is: the list it's in and its position there, the
+    /// search and filters it passed, alerts it matched and the feed settings that apply.
+    pub fn explain_item(&self, feed_idx: usize, item_idx: usize) -> Vec<String> {
+        let Some(feed) = self.feeds.get(feed_idx) else {
+            return Vec::new();
+        };
+        let Some(item) = feed.items.get(item_idx) else {
+            return Vec::new();
+        };
+        let entry = (feed_idx, item_idx);
+        let position = |list: &[(usize, usize)]| {
+            list.iter()
+                .position(|&e| e == entry)
+                .map_or_else(String::new, |pos| {
+                    format!(" (#{} of {})", pos + 1, list.len())
+                })
+        };
+        let mut lines = Vec::new();
+
+        // Where it's listed, and in what order
+        let searching = self.is_searching && self.view == View::Dashboard;
+        if searching {
+            let needle = fold_for_search(&self.search_query);
+            let field = if feed.title_lower.contains(&needle) {
+                "the feed title"
+            } else if item.title_lower.contains(&needle) {
+                "the title"
+            } else {
+                "the text"
+            };
+            lines.push(format!(
+                "Search result{}: \"{}\" matches {}",
+                position(&self.filtered_items),
+                self.search_query,
+                field
+            ));
+        } else {
+            match self.view {
+                View::Dashboard => match self.dashboard_sections.get(self.focused_section) {
+                    Some(section) => lines.push(format!(
+                        "Section \"{}\"{}: {}",
+                        section.title,
+                        position(&section.items),
+                        match section.kind {
+                            SectionKind::Latest => "newest items",
+                            SectionKind::Unread => "unread items, newest first",
+                            SectionKind::Starred => "starred items, newest first",
+                            SectionKind::Today => "items from the last 24 hours",
+                            SectionKind::Stale => "newest item of feeds gone quiet",
+                        }
+                    )),
+                    None => lines.push(format!(
+                        "Dashboard{}: {}, up to {} items",
+                        position(self.active_dashboard_items()),
+                        self.item_sort.label(),
+                        self.config.general.max_dashboard_items
+                    )),
+                },
+                View::FeedItems => {
+                    let order = self.feed_item_order(feed_idx);
+                    let pos = order.iter().position(|&idx| idx == item_idx).unwrap_or(0);
+                    lines.push(format!(
+                        "Item {} of {} in {}, {}",
+                        pos + 1,
+                        order.len(),
+                        feed.title,
+                        self.item_sort.label()
+                    ));
                 }
+                View::Starred => lines.push("Starred".to_string()),
+                View::Alerts => lines.push("Matched an alert".to_string()),
+                View::ReadLater => lines.push("In the read-later queue".to_string()),
+                View::Triage => lines.push("Unread, queued for triage".to_string()),
+                _ => lines.push(format!(
+                    "Item {} of {} in {}",
+                    item_idx + 1,
+                    feed.items.len(),
+                    feed.title
+                )),
             }
         }
 
-        self.show_link_overlay = !self.extracted_links.is_empty();
-        if self.extracted_links.is_empty() {
-            self.error = Some("No links or images found in this article".to_string());
+        // Filters narrow the dashboard and feed pages; report each one the item passed
+        let filters = &self.filter_options;
+        let filtered_view = matches!(self.view, View::Dashboard | View::FeedItems) && !searching;
+        if filtered_view && filters.is_active() {
+            if let Some(category) = &filters.category {
+                lines.push(format!("✓ Category: feed is in {}", category));
+            }
+            if let Some(age) = &filters.age {
+                let published = item
+                    .formatted_date
+                    .clone()
+                    .unwrap_or_else(|| "unknown".to_string());
+                let label = match age {
+                    TimeFilter::Today => "today",
+                    TimeFilter::ThisWeek => "this week",
+                    TimeFilter::ThisMonth => "this month",
+                    TimeFilter::Older => "older than a month",
+                };
+                lines.push(format!("✓ Age {}: published {}", label, published));
+            }
+            if let Some(has_author) = filters.has_author {
+                lines.push(match (&item.author, has_author) {
+                    (Some(author), true) => format!("✓ Has author: {}", author),
+                    _ => "✓ No author".to_string(),
+                });
+            }
+            if let Some(is_read) = filters.read_status {
+                lines.push(format!(
+                    "✓ Status: {}",
+                    if is_read { "read" } else { "unread" }
+                ));
+            }
+            if let Some(min_length) = filters.min_length {
+                let length = item.plain_text.as_deref().map_or(0, str::len);
+                lines.push(format!(
+                    "✓ Length: {} characters (at least {})",
+                    length, min_length
+                ));
+            }
+            if let Some(is_starred) = filters.starred_only {
+                lines.push(format!(
+                    "✓ Starred: {}",
+                    if is_starred { "yes" } else { "no" }
+                ));
+            }
+            if let Some(code) = filters.language {
+                lines.push(format!("✓ Language: {}", crate::feed::language_name(code)));
+            }
+            if let Some(tag) = &filters.tag {
+                lines.push(format!("✓ Tag: {}", tag));
+            }
+        } else if filtered_view {
+            lines.push("No filters active".to_string());
         }
+
+        // Other things that shaped the list
+        let item_id = self.get_item_id(feed_idx, item_idx);
+        if let Some(alert) = self.alert_items.get(&item_id) {
+            lines.push(format!("Alert \"{}\" matched", alert));
+        }
+        let max_items = self
+            .feed_max_items
+            .get(&feed.url)
+            .copied()
+            .unwrap_or(self.config.general.max_items_per_feed);
+        if max_items > 0 {
+            lines.push(format!(
+                "{} keeps its newest {} items",
+                feed.title, max_items
+            ));
+        }
+        if self.muted_feeds.contains(&feed.url) {
+            lines.push(format!(
+                "{} is muted; hidden from the dashboard",
+                feed.title
+            ));
+        }
+        if self.dashboard_hidden_feeds.contains(&feed.url) {
+            lines.push(format!(
+                "{} is configured with hide_from_dashboard",
+                feed.title
+            ));
+        }
+        if self.archived_feeds.contains(&feed.url) {
+            lines.push(format!(
+                "{} is archived; no longer fetched or on the dashboard",
+                feed.title
+            ));
+        }
+        lines.push(format!(
+            "{}, {}",
+            if self.read_items.contains(&item_id) {
+                "Read"
+            } else {
+                "Unread"
+            },
+            if self.starred_items.contains(&item_id) {
+                "starred"
+            } else {
+                "not starred"
+            }
+        ));
+        lines
+    }
+
+    /// Render the share template for the selected item and hand it to the clipboard or command.
+    pub fn share_selected_item(&self) -> Result<()> {
+        let (feed_idx, item_idx) = self
+            .selected_item_indices()
+            .ok_or_else(|| anyhow::anyhow!("No article selected"))?;
+        let feed = &self.feeds[feed_idx];
+        let text =
+            crate::share::render_template(&self.config.share.template, feed, &feed.items[item_idx]);
+        crate::share::share_text(&text, self.config.share.command.as_deref())
+    }
+
+    /// POST an article to the webhook at `webhook_idx` in `share.webhooks`, using its
+    /// JSON template. Returns the webhook's name for the confirmation message.
+    pub fn share_to_webhook(
+        &self,
+        feed_idx: usize,
+        item_idx: usize,
+        webhook_idx: usize,
+    ) -> Result<String> {
+        let webhook = self
+            .config
+            .share
+            .webhooks
+            .get(webhook_idx)
+            .context("No such webhook")?;
+        let feed = self.feeds.get(feed_idx).context("No article selected")?;
+        let item = feed.items.get(item_idx).context("No article selected")?;
+        let body = crate::share::render_json(&webhook.template, feed, item)?;
+        let client = Feed::build_client(self.config.network.http_timeout)?;
+        crate::share::post_webhook(&client, &webhook.url, body)?;
+        Ok(webhook.name.clone())
+    }
+
+    /// File the selected article as a task by running `tools.task_command` with its
+    /// placeholders filled in.
+    pub fn make_task_from_selected_item(&self) -> Result<()> {
+        let Some(template) = &self.config.tools.task_command else {
+            anyhow::bail!("No task command configured (set tools.task_command)");
+        };
+        let (feed_idx, item_idx) = self
+            .selected_item_indices()
+            .context("No article selected")?;
+        let feed = &self.feeds[feed_idx];
+        let command = crate::share::render_command(template, feed, &feed.items[item_idx]);
+        crate::share::run_shell(&command)
+    }
+
+    /// The event date an item announces, if its title or text mentions one.
+    pub fn item_event_time(
+        &self,
+        feed_idx: usize,
+        item_idx: usize,
+    ) -> Option<crate::calendar::EventTime> {
+        let item = self.feeds.get(feed_idx)?.items.get(item_idx)?;
+        let published = item.parsed_date.unwrap_or_else(Utc::now).date_naive();
+        crate::calendar::find_event_time(&item.title, published)
+            .or_else(|| crate::calendar::find_event_time(item.plain_text.as_deref()?, published))
+    }
+
+    /// Save the event the selected article announces as an .ics file in the export
+    /// directory, then hand it to `tools.calendar_command` when one is set.
+    pub fn export_selected_event(&self) -> Result<PathBuf> {
+        let (feed_idx, item_idx) = self
+            .selected_item_indices()
+            .context("No article selected")?;
+        let when = self
+            .item_event_time(feed_idx, item_idx)
+            .context("No event date found in this article")?;
+        let item = &self.feeds[feed_idx].items[item_idx];
+        let uid = self.get_item_id(feed_idx, item_idx);
+        let description = item
+            .plain_text
+            .as_deref()
+            .map(|text| crate::ui::utils::truncate_str(text.trim(), 500))
+            .unwrap_or_default();
+        let ics = crate::calendar::to_ics(
+            &[crate::calendar::CalendarEvent {
+                uid: &uid,
+                summary: &item.title,
+                url: item.link.as_deref(),
+                description: &description,
+                when,
+            }],
+            Utc::now(),
+        );
+
+        let dir = self.export_dir();
+        fs::create_dir_all(&dir)?;
+        let path = dir.join(format!(
+            "{}.ics",
+            crate::article_export::file_stem(&item.title)
+        ));
+        fs::write(&path, ics)?;
+        if let Some(command) = &self.config.tools.calendar_command {
+            crate::share::run_shell(&command.replace(
+                "{file}",
+                &crate::share::shell_quote(&path.to_string_lossy()),
+            ))?;
+        }
+        Ok(path)
+    }
+
+    pub fn open_current_item_in_browser(&mut self) -> Result<()> {
+        match (self.selected_feed, self.selected_item) {
+            (Some(feed_idx), Some(item_idx)) if self.current_item().is_some() => {
+                self.open_item_in_browser(feed_idx, item_idx)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Open an item's link in the browser, counting it as opened and clicked.
+    pub fn open_item_in_browser(&mut self, feed_idx: usize, item_idx: usize) -> Result<()> {
+        if let Some(link) = self.feeds[feed_idx].items[item_idx].link.clone() {
+            open::that(link)?;
+            self.record_item_open(feed_idx, item_idx);
+            self.record_click(feed_idx);
+        }
+        Ok(())
+    }
+
+    /// Open an item's comments page in the browser, counting it as a click on the feed.
+    /// Returns false when the feed gives no comments link for it.
+    pub fn open_item_comments(&mut self, feed_idx: usize, item_idx: usize) -> Result<bool> {
+        let Some(url) = self.feeds[feed_idx].items[item_idx].comments_url.clone() else {
+            return Ok(false);
+        };
+        open::that(url)?;
+        self.record_click(feed_idx);
+        Ok(true)
+    }
+
+    /// What Enter opens a feed's items in: the feed's `open` setting, else
+    /// `general.open_target`.
+    pub fn open_target(&self, feed_idx: usize) -> OpenTarget {
+        self.feeds
+            .get(feed_idx)
+            .and_then(|feed| self.feed_open_targets.get(&feed.url))
+            .copied()
+            .unwrap_or(self.config.general.open_target)
+    }
+
+    /// Open an item as Enter does: in the browser for feeds that open there, otherwise
+    /// in the detail view (which shows the linked page for `full_text` feeds).
+    pub fn open_item(&mut self, feed_idx: usize, item_idx: usize) -> Result<()> {
+        match self.open_target(feed_idx) {
+            OpenTarget::Browser => self.open_item_in_browser(feed_idx, item_idx),
+            OpenTarget::Reader | OpenTarget::FullText => {
+                self.open_item_detail(feed_idx, item_idx);
+                Ok(())
+            }
+        }
+    }
+
+    /// Open an item the other way, as `o` does in lists: in the browser, or in the
+    /// detail view for feeds whose items Enter already opens in the browser.
+    pub fn open_item_alternate(&mut self, feed_idx: usize, item_idx: usize) -> Result<()> {
+        match self.open_target(feed_idx) {
+            OpenTarget::Browser => {
+                self.open_item_detail(feed_idx, item_idx);
+                Ok(())
+            }
+            OpenTarget::Reader | OpenTarget::FullText => {
+                self.open_item_in_browser(feed_idx, item_idx)
+            }
+        }
+    }
+
+    /// Whether an item was opened from search results earlier this session, so working
+    /// through a result list can skip what's been seen.
+    pub fn is_search_visited(&self, feed_idx: usize, item_idx: usize) -> bool {
+        !self.search_visited.is_empty()
+            && self
+                .search_visited
+                .contains(&self.get_item_id(feed_idx, item_idx))
+    }
+
+    pub fn live_search(&mut self, query: &str) {
+        self.search_feeds(query);
+        self.view = View::Dashboard;
+        let count = self.filtered_items.len();
+        match self.selected_item {
+            Some(sel) if count > 0 && sel >= count => self.selected_item = Some(count - 1),
+            None if count > 0 => self.selected_item = Some(0),
+            _ => {}
+        }
+    }
+
+    pub fn search_feeds(&mut self, query: &str) {
+        self.search_query = query.to_lowercase();
+        self.is_searching = !query.is_empty();
+
+        if !self.is_searching {
+            return;
+        }
+        self.filtered_items = self.find_items(query);
+    }
+
+    /// Items matching a search `query`: every item of a feed whose title matches, and
+    /// items whose title or text match. Case and diacritics are ignored.
+    pub fn find_items(&self, query: &str) -> Vec<(usize, usize)> {
+        // Match against the folded fields cached on each feed and item at parse time
+        let needle = fold_for_search(query);
+        let mut found = Vec::new();
+        for (feed_idx, feed) in self.feeds.iter().enumerate() {
+            if feed.title_lower.contains(&needle) {
+                // Add all items from matching feed
+                for item_idx in 0..feed.items.len() {
+                    found.push((feed_idx, item_idx));
+                }
+            } else {
+                for (item_idx, item) in feed.items.iter().enumerate() {
+                    if item.title_lower.contains(&needle)
+                        || item
+                            .plain_text_lower
+                            .as_ref()
+                            .is_some_and(|pt| pt.contains(&needle))
+                    {
+                        found.push((feed_idx, item_idx));
+                    }
+                }
+            }
+        }
+        found
+    }
+
+    /// Load subscribed feeds from the HTTP cache, without any network requests, for
+    /// headless commands that work on what's already been fetched. Returns how many
+    /// subscriptions had nothing usable cached.
+    pub fn load_cached_feeds(&mut self) -> usize {
+        let cache = HttpCache::default_location();
+        self.clear_subscribed_feeds();
+        let mut missing = 0;
+        for url in self.bookmarks.clone() {
+            match Feed::load_cached(&url, &cache) {
+                Some(Ok(mut feed)) => {
+                    self.apply_feed_settings(&mut feed);
+                    self.feeds.push(feed);
+                }
+                _ => missing += 1,
+            }
+        }
+        missing
+    }
+
+    pub fn refresh_feeds(&mut self) -> Result<()> {
+        self.is_loading = true;
+        self.refresh_in_progress = true;
+        self.refresh_started = Some(Instant::now());
+
+        // Group feeds by domain for rate limiting
+        let mut domain_groups: HashMap<String, Vec<String>> = HashMap::new();
+        for url in &self.bookmarks {
+            let domain = Self::extract_domain_from_url(url);
+            domain_groups.entry(domain).or_default().push(url.clone());
+        }
+
+        let rate_limit_delay = self.config.general.refresh_rate_limit_delay;
+
+        // Pre-calculate per-domain delays before spawning threads
+        let domain_delays: HashMap<String, std::time::Duration> = domain_groups
+            .keys()
+            .map(|domain| (domain.clone(), self.calculate_required_delay(domain)))
+            .collect();
+
+        self.clear_subscribed_feeds();
+
+        let client = match self.http_client() {
+            Ok(c) => c,
+            Err(e) => {
+                self.is_loading = false;
+                self.refresh_in_progress = false;
+                return Err(e);
+            }
+        };
+
+        // Spawn one thread per domain group — domains fetch in parallel,
+        // feeds within the same domain fetch sequentially (rate limiting)
+        let handles: Vec<_> = domain_groups
+            .into_iter()
+            .map(|(domain, urls)| {
+                let client = client.clone();
+                let delay = domain_delays.get(&domain).copied().unwrap_or_default();
+                let rate_limit = std::time::Duration::from_millis(rate_limit_delay);
+                let options: Vec<RequestOptions> =
+                    urls.iter().map(|url| self.request_options(url)).collect();
+
+                std::thread::spawn(move || {
+                    if !delay.is_zero() {
+                        std::thread::sleep(delay);
+                    }
+
+                    let mut results = Vec::new();
+                    for (i, (url, options)) in urls.iter().zip(&options).enumerate() {
+                        // Rate-limit between feeds on the same domain
+                        if i > 0 && !rate_limit.is_zero() {
+                            std::thread::sleep(rate_limit);
+                        }
+                        results.push((url.clone(), Feed::fetch(url, &client, options)));
+                    }
+                    (domain, results)
+                })
+            })
+            .collect();
+
+        // Collect results and update domain fetch times
+        self.reset_feed_failures(None);
+        let mut errors = Vec::new();
+        for handle in handles {
+            if let Ok((domain, results)) = handle.join() {
+                for (url, outcome) in results {
+                    self.record_fetch(&url, &outcome);
+                    match outcome.into_feed(&url) {
+                        Ok(mut feed) => {
+                            self.apply_feed_settings(&mut feed);
+                            self.feeds.push(feed);
+                        }
+                        Err(e) => {
+                            errors.push(format!("Failed to refresh feed {}: {}", url, e));
+                            self.record_feed_failure(&url, &e);
+                        }
+                    }
+                }
+                self.last_domain_fetch.insert(domain, Instant::now());
+            }
+        }
+
+        if let Some(last_error) = errors.last() {
+            self.error = Some(last_error.clone());
+        }
+
+        self.update_dashboard();
+        self.is_loading = false;
+        self.refresh_in_progress = false;
+        self.last_refresh = Some(Instant::now());
+
+        Ok(())
+    }
+
+    pub fn update_loading_indicator(&mut self) {
+        self.loading_indicator = (self.loading_indicator + 1) % 10;
+    }
+
+    pub fn get_available_categories(&self) -> Vec<String> {
+        let mut result: Vec<String> = self.categories.iter().map(|c| c.name.clone()).collect();
+        result.sort();
+        result
+    }
+
+    /// Languages detected across loaded items, most common first.
+    pub fn get_available_languages(&self) -> Vec<&'static str> {
+        let mut counts: HashMap<&'static str, usize> = HashMap::new();
+        for item in self.feeds.iter().flat_map(|feed| &feed.items) {
+            if let Some(code) = item.language {
+                *counts.entry(code).or_default() += 1;
+            }
+        }
+        let mut languages: Vec<(&'static str, usize)> = counts.into_iter().collect();
+        languages.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        languages.into_iter().map(|(code, _)| code).collect()
+    }
+
+    /// Tags on loaded items, confirmed or suggested, most common first.
+    pub fn get_available_tags(&self) -> Vec<String> {
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for (feed_idx, feed) in self.feeds.iter().enumerate() {
+            for item_idx in 0..feed.items.len() {
+                let (confirmed, suggested) = self.item_tag_lists(feed_idx, item_idx);
+                for tag in confirmed.iter().chain(suggested) {
+                    *counts.entry(tag.as_str()).or_default() += 1;
+                }
+            }
+        }
+        let mut tags: Vec<(&str, usize)> = counts.into_iter().collect();
+        tags.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        tags.into_iter().map(|(tag, _)| tag.to_string()).collect()
+    }
+
+    pub fn get_filter_stats(&self) -> (usize, usize, usize) {
+        let active_count = [
+            self.filter_options.category.is_some(),
+            self.filter_options.age.is_some(),
+            self.filter_options.has_author.is_some(),
+            self.filter_options.read_status.is_some(),
+            self.filter_options.min_length.is_some(),
+            self.filter_options.starred_only.is_some(),
+            self.filter_options.language.is_some(),
+            self.filter_options.tag.is_some(),
+        ]
+        .iter()
+        .filter(|&&x| x)
+        .count();
+
+        let filtered_count = if self.is_searching {
+            self.filtered_items.len()
+        } else {
+            self.filtered_dashboard_items.len()
+        };
+
+        let total_count = if self.is_searching {
+            self.filtered_items.len()
+        } else {
+            self.dashboard_items.len()
+        };
+
+        (active_count, filtered_count, total_count)
+    }
+
+    pub fn get_filter_summary(&self) -> String {
+        let mut parts = Vec::new();
+
+        if let Some(category) = &self.filter_options.category {
+            parts.push(format!("Category: {}", category));
+        }
+
+        if let Some(age) = &self.filter_options.age {
+            let age_str = match age {
+                TimeFilter::Today => "Today",
+                TimeFilter::ThisWeek => "This Week",
+                TimeFilter::ThisMonth => "This Month",
+                TimeFilter::Older => "Older than a month",
+            };
+            parts.push(format!("Age: {}", age_str));
+        }
+
+        if let Some(has_author) = self.filter_options.has_author {
+            parts.push(format!(
+                "Author: {}",
+                if has_author {
+                    "With author"
+                } else {
+                    "No author"
+                }
+            ));
+        }
+
+        if let Some(is_read) = self.filter_options.read_status {
+            parts.push(format!(
+                "Status: {}",
+                if is_read { "Read" } else { "Unread" }
+            ));
+        }
+
+        if let Some(length) = self.filter_options.min_length {
+            let length_str = match length {
+                100 => "Short",
+                500 => "Medium",
+                1000 => "Long",
+                _ => "Custom",
+            };
+            parts.push(format!("Length: {}", length_str));
+        }
+
+        if let Some(is_starred) = self.filter_options.starred_only {
+            parts.push(format!(
+                "Starred: {}",
+                if is_starred { "Yes" } else { "No" }
+            ));
+        }
+
+        if let Some(code) = self.filter_options.language {
+            parts.push(format!("Language: {}", crate::feed::language_name(code)));
+        }
+
+        if let Some(tag) = &self.filter_options.tag {
+            parts.push(format!("Tag: {}", tag));
+        }
+
+        if parts.is_empty() {
+            "No filters active".to_string()
+        } else {
+            parts.join(" | ")
+        }
+    }
+
+    // Category management functions
+    pub fn create_category(&mut self, name: &str) -> Result<()> {
+        crate::globals::ensure_writable("Editing categories")?;
+        // Trim the name and check if it's empty
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(anyhow::anyhow!("Category name cannot be empty"));
+        }
+
+        // Check if category with same name exists
+        if self.categories.iter().any(|c| c.name == name) {
+            return Err(anyhow::anyhow!("Category with this name already exists"));
+        }
+
+        // Create and add the new category
+        let category = FeedCategory::new(name);
+        self.edit_history
+            .record(TreeEdit::CreateCategory(category.clone()));
+        self.categories.push(category);
+        self.selected_category = Some(self.categories.len() - 1);
+
+        // Save categories
+        self.save_data()?;
+        self.rebuild_feed_tree();
+
+        Ok(())
+    }
+
+    pub fn delete_category(&mut self, idx: usize) -> Result<()> {
+        crate::globals::ensure_writable("Editing categories")?;
+        if idx >= self.categories.len() {
+            return Err(anyhow::anyhow!("Invalid category index"));
+        }
+
+        let category = self.categories.remove(idx);
+        self.edit_history.record(TreeEdit::DeleteCategory {
+            index: idx,
+            category,
+        });
+        if !self.categories.is_empty() && self.selected_category.is_some() {
+            if self.selected_category.unwrap() >= self.categories.len() {
+                self.selected_category = Some(self.categories.len() - 1);
+            }
+        } else {
+            self.selected_category = None;
+        }
+
+        // Save categories
+        self.save_data()?;
+        self.rebuild_feed_tree();
+
+        Ok(())
+    }
+
+    pub fn rename_category(&mut self, idx: usize, new_name: &str) -> Result<()> {
+        crate::globals::ensure_writable("Editing categories")?;
+        let new_name = new_name.trim();
+        if new_name.is_empty() {
+            return Err(anyhow::anyhow!("Category name cannot be empty"));
+        }
+
+        // Check if another category already has this name
+        if self
+            .categories
+            .iter()
+            .enumerate()
+            .any(|(i, c)| i != idx && c.name == new_name)
+        {
+            return Err(anyhow::anyhow!("Category with this name already exists"));
+        }
+
+        if idx < self.categories.len() {
+            self.edit_history.record(TreeEdit::RenameCategory {
+                id: self.categories[idx].id.clone(),
+                from: self.categories[idx].name.clone(),
+                to: new_name.to_string(),
+            });
+            self.categories[idx].rename(new_name);
+            self.save_data()?;
+            self.rebuild_feed_tree();
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!("Invalid category index"))
+        }
+    }
+
+    pub fn assign_feed_to_category(&mut self, feed_url: &str, category_idx: usize) -> Result<()> {
+        crate::globals::ensure_writable("Editing categories")?;
+        if category_idx >= self.categories.len() {
+            return Err(anyhow::anyhow!("Invalid category index"));
+        }
+
+        // Add feed to the selected category
+        let category = &mut self.categories[category_idx];
+        if !category.contains_feed(feed_url) {
+            category.add_feed(feed_url);
+            self.edit_history.record(TreeEdit::AssignFeed {
+                category_id: category.id.clone(),
+                url: feed_url.to_string(),
+            });
+        }
+
+        // Save the updated categories
+        self.save_data()?;
+        self.rebuild_feed_tree();
+
+        Ok(())
+    }
+
+    pub fn remove_feed_from_category(&mut self, feed_url: &str, category_idx: usize) -> Result<()> {
+        crate::globals::ensure_writable("Editing categories")?;
+        if category_idx >= self.categories.len() {
+            return Err(anyhow::anyhow!("Invalid category index"));
+        }
+
+        let removed = self.categories[category_idx].remove_feed(feed_url);
+        if removed {
+            self.edit_history.record(TreeEdit::UnassignFeed {
+                category_id: self.categories[category_idx].id.clone(),
+                url: feed_url.to_string(),
+            });
+            self.save_data()?;
+            self.rebuild_feed_tree();
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!("Feed not found in category"))
+        }
+    }
+
+    pub fn toggle_category_expanded(&mut self, idx: usize) -> Result<()> {
+        if idx < self.categories.len() {
+            self.categories[idx].toggle_expanded();
+            self.rebuild_feed_tree();
+            Ok(())
+        } else {
+            Err(anyhow::anyhow!("Invalid category index"))
+        }
+    }
+
+    pub fn rebuild_feed_tree(&mut self) {
+        self.feed_tree.clear();
+        let mut categorized_feeds: std::collections::HashSet<String> =
+            std::collections::HashSet::new();
+        let feed_order = self.sorted_feed_indices();
+        let (archived, feed_order): (Vec<usize>, Vec<usize>) = feed_order
+            .into_iter()
+            .partition(|&idx| self.archived_feeds.contains(&self.feeds[idx].url));
+        let pending = self.pending_bookmarks();
+
+        // Add categories and their feeds
+        for (cat_idx, category) in self.categories.iter().enumerate() {
+            self.feed_tree.push(TreeItem::Category(cat_idx));
+            if category.expanded {
+                for &feed_idx in &feed_order {
+                    let feed = &self.feeds[feed_idx];
+                    if category.feeds.contains(&feed.url) {
+                        self.feed_tree.push(TreeItem::Feed(feed_idx, Some(cat_idx)));
+                        categorized_feeds.insert(feed.url.clone());
+                    }
+                }
+                for url in pending.iter().filter(|url| category.feeds.contains(*url)) {
+                    self.feed_tree
+                        .push(TreeItem::Pending(url.clone(), Some(cat_idx)));
+                }
+            } else {
+                // Still track which feeds are categorized even when collapsed
+                for feed in &self.feeds {
+                    if category.feeds.contains(&feed.url) {
+                        categorized_feeds.insert(feed.url.clone());
+                    }
+                }
+            }
+            categorized_feeds.extend(
+                pending
+                    .iter()
+                    .filter(|url| category.feeds.contains(*url))
+                    .cloned(),
+            );
+        }
+
+        // Add uncategorized feeds at the bottom
+        for &feed_idx in &feed_order {
+            if !categorized_feeds.contains(&self.feeds[feed_idx].url) {
+                self.feed_tree.push(TreeItem::Feed(feed_idx, None));
+            }
+        }
+        for url in pending {
+            if !categorized_feeds.contains(&url) {
+                self.feed_tree.push(TreeItem::Pending(url, None));
+            }
+        }
+
+        // Archived feeds go last, under their own heading
+        if !archived.is_empty() {
+            self.feed_tree.push(TreeItem::Archive);
+            if self.archive_expanded {
+                self.feed_tree
+                    .extend(archived.into_iter().map(|idx| TreeItem::Feed(idx, None)));
+            }
+        }
+
+        // Clamp selection
+        if let Some(selected) = self.selected_tree_item {
+            if selected >= self.feed_tree.len() {
+                self.selected_tree_item = if self.feed_tree.is_empty() {
+                    None
+                } else {
+                    Some(self.feed_tree.len() - 1)
+                };
+            }
+        }
+    }
+
+    /// Bookmarks a running refresh hasn't delivered yet, in bookmark order. The feed list
+    /// shows them as placeholder rows so it's complete from the first frame.
+    fn pending_bookmarks(&self) -> Vec<String> {
+        if !self.refresh_in_progress {
+            return Vec::new();
+        }
+        let loaded: HashSet<&str> = self.feeds.iter().map(|feed| feed.url.as_str()).collect();
+        self.bookmarks
+            .iter()
+            .filter(|url| !loaded.contains(url.as_str()))
+            .filter(|url| !self.failed_feeds.iter().any(|(failed, _)| failed == *url))
+            .cloned()
+            .collect()
+    }
+
+    /// What to call a feed that hasn't loaded yet: the title given to it, or the one it
+    /// had at its last refresh, or else its URL.
+    pub fn placeholder_title(&self, url: &str) -> String {
+        self.feed_titles
+            .get(url)
+            .or_else(|| self.feed_meta.get(url).map(|meta| &meta.title))
+            .filter(|title| !title.is_empty())
+            .cloned()
+            .unwrap_or_else(|| url.to_string())
+    }
+
+    /// Feed indices in the order the feed list should show them.
+    fn sorted_feed_indices(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.feeds.len()).collect();
+        // Stable sorts keep bookmark order among ties
+        match self.feed_sort {
+            FeedSortMode::Bookmarked => {}
+            FeedSortMode::Alphabetical => {
+                order.sort_by(|&a, &b| self.feeds[a].title_lower.cmp(&self.feeds[b].title_lower));
+            }
+            FeedSortMode::MostUnread => {
+                order.sort_by_cached_key(|&idx| std::cmp::Reverse(self.unread_count(idx)));
+            }
+            FeedSortMode::RecentlyUpdated => {
+                let latest = |idx: usize| {
+                    self.feeds[idx]
+                        .items
+                        .iter()
+                        .filter_map(|i| i.parsed_date)
+                        .max()
+                };
+                order.sort_by(|&a, &b| newest_first(&latest(a), &latest(b)));
+            }
+            FeedSortMode::LeastEngaged => {
+                // Feeds with nothing to go on yet come last
+                order.sort_by_cached_key(|&idx| {
+                    let score = self.feed_engagement(&self.feeds[idx].url).score();
+                    (score.is_none(), score)
+                });
+            }
+        }
+        order
+    }
+
+    pub fn unread_count(&self, feed_idx: usize) -> usize {
+        self.feeds.get(feed_idx).map_or(0, |feed| {
+            (0..feed.items.len())
+                .filter(|&item_idx| !self.is_item_read(feed_idx, item_idx))
+                .count()
+        })
+    }
+
+    /// Switch to the next feed list sort mode, keeping the selected feed selected.
+    pub fn cycle_feed_sort(&mut self) {
+        let selected = self
+            .selected_tree_item
+            .and_then(|sel| self.feed_tree.get(sel).cloned());
+        self.feed_sort = self.feed_sort.next();
+        self.rebuild_feed_tree();
+        if let Some(TreeItem::Feed(feed_idx, _)) = selected {
+            if let Some(pos) = self
+                .feed_tree
+                .iter()
+                .position(|item| matches!(item, TreeItem::Feed(idx, _) if *idx == feed_idx))
+            {
+                self.selected_tree_item = Some(pos);
+            }
+        }
+    }
+
+    // When managing categories in UI
+    pub fn get_category_for_feed(&self, feed_url: &str) -> Option<usize> {
+        self.categories
+            .iter()
+            .position(|c| c.contains_feed(feed_url))
+    }
+
+    /// Update the maximum scroll value based on content height and viewport height
+    pub fn update_detail_max_scroll(&mut self, content_lines: u16, viewport_height: u16) {
+        // Maximum scroll is the content lines minus the viewport height
+        // If content fits in viewport, max scroll is 0
+        self.detail_max_scroll = content_lines.saturating_sub(viewport_height);
+    }
+
+    /// Clamp the current scroll position to valid bounds
+    pub fn clamp_detail_scroll(&mut self) {
+        if self.detail_vertical_scroll > self.detail_max_scroll {
+            self.detail_vertical_scroll = self.detail_max_scroll;
+        }
+        if self.detail_horizontal_scroll > self.detail_max_horizontal_scroll {
+            self.detail_horizontal_scroll = self.detail_max_horizontal_scroll;
+        }
+    }
+
+    /// Switch the current article between soft-wrap and no-wrap.
+    pub fn toggle_detail_wrap(&mut self) {
+        self.detail_no_wrap = !self.detail_no_wrap;
+        self.detail_horizontal_scroll = 0;
+    }
+
+    /// Jump from the first footnote reference on or below the top of the view to its footnote,
+    /// or back to where the last jump started if the view hasn't moved since.
+    pub fn jump_footnote(&mut self) {
+        let scroll = self.detail_vertical_scroll;
+        if let Some((back, landed)) = self.detail_footnote_return.take() {
+            if scroll == landed {
+                self.detail_vertical_scroll = back;
+                return;
+            }
+        }
+        if let Some(&(_, note_row)) = self
+            .detail_footnote_rows
+            .iter()
+            .find(|(ref_row, _)| *ref_row >= scroll)
+        {
+            let landed = note_row.min(self.detail_max_scroll);
+            self.detail_vertical_scroll = landed;
+            self.detail_footnote_return = Some((scroll, landed));
+        }
+    }
+
+    /// Scroll an unwrapped article sideways by `delta` columns.
+    pub fn scroll_detail_horizontally(&mut self, delta: i32) {
+        if !self.detail_no_wrap {
+            return;
+        }
+        let scrolled = (self.detail_horizontal_scroll as i32 + delta)
+            .clamp(0, self.detail_max_horizontal_scroll as i32);
+        self.detail_horizontal_scroll = scrolled as u16;
+    }
+
+    /// Update the maximum scroll value for preview pane based on content height and viewport height
+    pub fn update_preview_max_scroll(&mut self, content_lines: u16, viewport_height: u16) {
+        self.preview_max_scroll = content_lines.saturating_sub(viewport_height);
+    }
+
+    /// Clamp the preview scroll position to valid bounds
+    pub fn clamp_preview_scroll(&mut self) {
+        if self.preview_scroll > self.preview_max_scroll {
+            self.preview_scroll = self.preview_max_scroll;
+        }
+    }
+
+    /// Where the UI currently is, for the navigation history.
+    pub fn nav_state(&self) -> NavState {
+        NavState {
+            view: self.view.clone(),
+            selected_feed: self.selected_feed,
+            selected_item: self.selected_item,
+            item: self.selected_item_identity(),
+            search_query: self.is_searching.then(|| self.search_query.clone()),
+            detail_vertical_scroll: self.detail_vertical_scroll,
+            detail_return: self.detail_return.clone(),
+        }
+    }
+
+    /// Push `from` onto the back history if the UI has since moved somewhere else.
+    /// Moving the cursor within a list doesn't count as a new place.
+    pub fn record_navigation(&mut self, from: NavState) {
+        if std::mem::take(&mut self.history_moved) || from.same_place(&self.nav_state()) {
+            return;
+        }
+        self.nav_back.push(from);
+        if self.nav_back.len() > MAX_HISTORY {
+            self.nav_back.remove(0);
+        }
+        self.nav_forward.clear();
+    }
+
+    /// Go back to the previous place in the history. Returns false if there is none.
+    pub fn history_back(&mut self) -> bool {
+        let Some(state) = self.nav_back.pop() else {
+            return false;
+        };
+        self.nav_forward.push(self.nav_state());
+        self.restore_nav_state(state);
+        true
+    }
+
+    /// Redo a step undone by [`Self::history_back`]. Returns false if there is none.
+    pub fn history_forward(&mut self) -> bool {
+        let Some(state) = self.nav_forward.pop() else {
+            return false;
+        };
+        self.nav_back.push(self.nav_state());
+        self.restore_nav_state(state);
+        true
+    }
+
+    fn restore_nav_state(&mut self, state: NavState) {
+        self.history_moved = true;
+        self.copy_mode = None;
+        self.show_translation = false;
+        self.detail_source = DetailSource::Rendered;
+        self.detail_footnote_return = None;
+        match &state.search_query {
+            Some(query) => self.search_feeds(query),
+            None => self.is_searching = false,
+        }
+        self.view = state.view;
+        self.selected_feed = state.selected_feed;
+        self.selected_item = state.selected_item;
+        self.detail_vertical_scroll = state.detail_vertical_scroll;
+        self.detail_return = state.detail_return;
+        // Indices may be stale after a refresh; find the item itself when possible
+        if let Some(item) = &state.item {
+            self.select_item_by_identity(item);
+        }
+        if self.view == View::Dashboard {
+            self.clamp_dashboard_selection();
+        }
+        if self.view == View::FeedItemDetail {
+            if let Some((feed_idx, item_idx)) = self.selected_feed.zip(self.selected_item) {
+                self.request_full_text(feed_idx, item_idx);
+            }
+        }
+    }
+
+    /// Open the detail view for an item, remembering which list (dashboard, search
+    /// results, a feed, ...) and position it was opened from.
+    pub fn open_item_detail(&mut self, feed_idx: usize, item_idx: usize) {
+        self.detail_return = Some((self.view.clone(), self.selected_item));
+        self.selected_feed = Some(feed_idx);
+        self.selected_item = Some(item_idx);
+        self.view = View::FeedItemDetail;
+        self.record_item_open(feed_idx, item_idx);
+        self.request_full_text(feed_idx, item_idx);
+    }
+
+    /// Leave the detail view for the list it was opened from, at the same position.
+    /// Ends any reading session.
+    pub fn back_from_detail_view(&mut self) {
+        self.reading_session = None;
+        match self.detail_return.take() {
+            Some((view, selected)) => {
+                let is_dashboard = view == View::Dashboard;
+                self.exit_detail_view(view);
+                self.selected_item = selected;
+                // Reading the item may have filtered it out of the list
+                if is_dashboard {
+                    self.clamp_dashboard_selection();
+                }
+            }
+            None if self.is_searching => {
+                self.exit_detail_view(View::Dashboard);
+                self.selected_item = Some(0);
+            }
+            None => self.exit_detail_view(View::FeedItems),
+        }
+    }
+
+    /// Exit the detail view and reset scroll position
+    pub fn exit_detail_view(&mut self, new_view: View) {
+        self.detail_vertical_scroll = 0;
+        self.detail_no_wrap = false;
+        self.zen_mode = false;
+        self.detail_horizontal_scroll = 0;
+        self.detail_footnote_return = None;
+        self.show_translation = false;
+        self.detail_source = DetailSource::Rendered;
+        self.copy_mode = None;
+        self.view = new_view;
+    }
+
+    /// Check if auto-refresh should trigger
+    pub fn should_auto_refresh(&self) -> bool {
+        if self.refresh_in_progress || self.is_metered() {
+            return false;
+        }
+        if self.global_refresh_due() {
+            return true;
+        }
+        // Feeds on their own schedule. Configured intervals count even for URLs that
+        // aren't subscribed, which fall back to a full refresh.
+        self.feed_refresh_intervals
+            .keys()
+            .chain(&self.bookmarks)
+            .any(|url| self.feed_refresh_due(url))
+    }
+
+    /// Whether the global auto-refresh interval is up. With adaptive refresh every feed
+    /// is on its own schedule instead.
+    pub fn global_refresh_due(&self) -> bool {
+        let general = &self.config.general;
+        if !general.refresh_enabled
+            || general.auto_refresh_interval == 0
+            || general.adaptive_refresh
+        {
+            return false;
+        }
+        match self.last_refresh {
+            Some(last) => last.elapsed().as_secs() >= general.auto_refresh_interval,
+            // Never refreshed, so we should refresh
+            None => true,
+        }
+    }
+
+    /// Start whatever refresh the schedule calls for: a full refresh when the global
+    /// interval is up, otherwise just the feeds whose own interval is. Paused feeds are
+    /// left out of both.
+    pub fn request_scheduled_refresh(&mut self) {
+        let global = self.global_refresh_due();
+        if global && self.paused_feeds.is_empty() {
+            self.refresh_requested = true;
+            return;
+        }
+        let urls: Vec<String> = if global {
+            // Stands in for the full refresh, so the global interval restarts now
+            self.last_refresh = Some(Instant::now());
+            self.bookmarks
+                .iter()
+                .filter(|url| !self.paused_feeds.contains(*url))
+                .cloned()
+                .collect()
+        } else {
+            self.due_feed_urls()
+        };
+        if !urls.is_empty() {
+            self.scheduled_refresh = Some(urls);
+        } else if !global {
+            // Only intervals for URLs that aren't subscribed came due
+            self.refresh_requested = true;
+        }
+    }
+
+    /// Subscribed feeds whose own refresh interval is up.
+    pub fn due_feed_urls(&self) -> Vec<String> {
+        self.bookmarks
+            .iter()
+            .filter(|url| self.feed_refresh_due(url))
+            .cloned()
+            .collect()
+    }
+
+    fn feed_refresh_due(&self, url: &str) -> bool {
+        let schedule = self.feed_schedule(url);
+        schedule
+            .interval
+            .is_some_and(|(_, source)| self.refreshes_individually(source))
+            && schedule.due_in.is_some_and(|due_in| due_in.is_zero())
+    }
+
+    /// Feeds on the global interval are refreshed together, unless adaptive refresh
+    /// schedules every feed by itself.
+    fn refreshes_individually(&self, source: IntervalSource) -> bool {
+        source != IntervalSource::Global || self.config.general.adaptive_refresh
+    }
+
+    /// How often `url` is refreshed automatically: its configured interval, else the
+    /// adaptive one, else the global one. None when it's only refreshed by hand.
+    pub fn refresh_interval(&self, url: &str) -> Option<(u64, IntervalSource)> {
+        if let Some(&interval) = self.feed_refresh_intervals.get(url) {
+            return Some((interval, IntervalSource::Feed));
+        }
+        let general = &self.config.general;
+        if !general.refresh_enabled {
+            return None;
+        }
+        if general.adaptive_refresh {
+            if let Some(interval) = self.adaptive_interval(url) {
+                return Some((interval, IntervalSource::Adaptive));
+            }
+        }
+        (general.auto_refresh_interval > 0)
+            .then_some((general.auto_refresh_interval, IntervalSource::Global))
+    }
+
+    /// Half the typical gap between the feed's recent posts, so it's checked about twice
+    /// per post. None until there are enough dated items to tell.
+    fn adaptive_interval(&self, url: &str) -> Option<u64> {
+        let feed = self.feeds.iter().find(|feed| feed.url == url)?;
+        let mut dates: Vec<DateTime<Utc>> = feed
+            .items
+            .iter()
+            .filter_map(|item| item.parsed_date)
+            .collect();
+        dates.sort_unstable_by(|a, b| b.cmp(a));
+        dates.truncate(ADAPTIVE_SAMPLE_POSTS);
+        let mut gaps: Vec<i64> = dates
+            .windows(2)
+            .map(|pair| (pair[0] - pair[1]).num_seconds())
+            .collect();
+        if gaps.len() < 2 {
+            return None;
+        }
+        gaps.sort_unstable();
+        let median = gaps[gaps.len() / 2].max(0) as u64;
+        let min = match self.config.general.auto_refresh_interval {
+            0 => ADAPTIVE_MIN_INTERVAL,
+            secs => secs,
+        };
+        Some((median / 2).clamp(min, ADAPTIVE_MAX_INTERVAL.max(min)))
+    }
+
+    /// Where `url` stands in the refresh schedule.
+    pub fn feed_schedule(&self, url: &str) -> FeedSchedule {
+        let interval = self.refresh_interval(url);
+        let paused = self.paused_feeds.contains(url);
+        let due_in = interval.filter(|_| !paused).map(|(secs, source)| {
+            let last = if self.refreshes_individually(source) {
+                self.last_feed_refresh
+                    .get(url)
+                    .copied()
+                    .or(self.last_refresh)
+            } else {
+                self.last_refresh
+            };
+            last.map_or(Duration::ZERO, |last| {
+                Duration::from_secs(secs).saturating_sub(last.elapsed())
+            })
+        });
+        FeedSchedule {
+            url: url.to_string(),
+            interval,
+            due_in,
+            last_duration: self.fetch_durations.get(url).copied(),
+            paused,
+        }
+    }
+
+    /// The selected feed's releases, newest first, for the Releases view.
+    pub fn release_timeline(&self) -> Vec<Release> {
+        self.current_feed()
+            .map(crate::releases::timeline)
+            .unwrap_or_default()
+    }
+
+    /// Show the selected feed's version timeline, if it's a release feed.
+    pub fn open_release_timeline(&mut self) -> Result<()> {
+        let feed = self.current_feed().context("No feed selected")?;
+        if !crate::releases::is_release_feed(feed) {
+            anyhow::bail!("{} doesn't look like a release feed", feed.title);
+        }
+        self.view = View::Releases;
+        self.selected_item = (!self.release_timeline().is_empty()).then_some(0);
+        Ok(())
+    }
+
+    /// Open the changes since the previous release for the selected release, or its
+    /// release notes in the browser when the host has no compare page.
+    pub fn open_release_diff(&mut self) -> Result<()> {
+        let (Some(feed_idx), Some(selected)) = (self.selected_feed, self.selected_item) else {
+            return Ok(());
+        };
+        let Some(release) = self.release_timeline().into_iter().nth(selected) else {
+            return Ok(());
+        };
+        match release.diff_url {
+            Some(url) => open::that(url)?,
+            None => self.open_item_in_browser(feed_idx, release.item_idx)?,
+        }
+        Ok(())
+    }
+
+    /// The schedule of every subscription, in subscription order.
+    pub fn feed_schedules(&self) -> Vec<FeedSchedule> {
+        self.bookmarks
+            .iter()
+            .map(|url| self.feed_schedule(url))
+            .collect()
+    }
+
+    /// Pause or resume a feed's scheduled refreshes; refreshing by hand still works.
+    /// Returns whether it's now paused.
+    pub fn toggle_feed_paused(&mut self, url: &str) -> bool {
+        let paused = !self.paused_feeds.remove(url);
+        if paused {
+            self.paused_feeds.insert(url.to_string());
+        }
+        self.mark_data_dirty();
+        paused
+    }
+
+    /// Extract domain from URL (e.g., "reddit.com" from "https://www.reddit.com/r/rust/.rss")
+    fn extract_domain_from_url(url: &str) -> String {
+        // Simple domain extraction
+        if let Some(domain_start) = url.find("://") {
+            let after_protocol = &url[domain_start + 3..];
+            let domain_end = after_protocol.find('/').unwrap_or(after_protocol.len());
+            let domain = &after_protocol[..domain_end];
+
+            // Remove www. prefix if present
+            domain.strip_prefix("www.").unwrap_or(domain).to_string()
+        } else {
+            // No protocol, try to extract domain directly
+            let domain_end = url.find('/').unwrap_or(url.len());
+            let domain = &url[..domain_end];
+
+            domain.strip_prefix("www.").unwrap_or(domain).to_string()
+        }
+    }
+
+    /// Calculate required delay before fetching from a domain
+    fn calculate_required_delay(&self, domain: &str) -> std::time::Duration {
+        if let Some(last_fetch) = self.last_domain_fetch.get(domain) {
+            let elapsed = last_fetch.elapsed();
+            let required_delay =
+                std::time::Duration::from_millis(self.config.general.refresh_rate_limit_delay);
+
+            if elapsed < required_delay {
+                required_delay - elapsed
+            } else {
+                std::time::Duration::from_secs(0)
+            }
+        } else {
+            std::time::Duration::from_secs(0)
+        }
+    }
+
+    pub fn toggle_preview_pane(&mut self) {
+        self.preview_pane = !self.preview_pane;
+        self.preview_scroll = 0;
+        self.preview_max_scroll = 0;
+    }
+
+    pub fn reset_preview_scroll(&mut self) {
+        self.preview_scroll = 0;
+        self.preview_max_scroll = 0;
+    }
+
+    /// Get new items since last session, returns (feed_idx, item_idx, feed_title)
+    pub fn get_new_items_since_session(&self) -> Vec<(usize, usize, &str)> {
+        let session_time = match self.last_session_time {
+            Some(t) => t,
+            None => return Vec::new(),
+        };
+
+        let mut items = Vec::new();
+        for (feed_idx, feed) in self.feeds.iter().enumerate() {
+            for (item_idx, item) in feed.items.iter().enumerate() {
+                if let Some(parsed_date) = item.parsed_date {
+                    if parsed_date > session_time {
+                        items.push((feed_idx, item_idx, feed.title.as_str()));
+                    }
+                }
+            }
+        }
+        items
+    }
+
+    /// Get summary stats for the "What's New" view
+    pub fn get_summary_stats(&self) -> (usize, Vec<(String, usize)>) {
+        let new_items = self.get_new_items_since_session();
+        let total = new_items.len();
+
+        // Count items per feed
+        let mut feed_counts: HashMap<&str, usize> = HashMap::new();
+        for &(_, _, feed_title) in &new_items {
+            *feed_counts.entry(feed_title).or_insert(0) += 1;
+        }
+
+        let mut feeds_with_counts: Vec<(String, usize)> = feed_counts
+            .into_iter()
+            .map(|(name, count)| (name.to_string(), count))
+            .collect();
+        feeds_with_counts.sort_by(|a, b| b.1.cmp(&a.1));
+
+        (total, feeds_with_counts)
+    }
+
+    /// Switch to the theme `ui.auto_theme` picks when it picks a different one than last
+    /// time, so a manual toggle holds until the next scheduled switch. Called every tick.
+    pub fn apply_auto_theme(&mut self) {
+        let colorfgbg = std::env::var("COLORFGBG").ok();
+        let Some(theme) = self
+            .config
+            .ui
+            .auto_theme_choice(chrono::Local::now().time(), colorfgbg.as_deref())
+        else {
+            return;
+        };
+        if self.auto_theme_last.as_ref() == Some(&theme) {
+            return;
+        }
+        self.color_scheme = ColorScheme::from_theme(&theme);
+        self.config.ui.theme = theme.clone();
+        self.auto_theme_last = Some(theme);
+    }
+
+    /// Toggle between light and dark themes. The mono theme toggles to dark.
+    pub fn toggle_theme(&mut self) -> Result<()> {
+        use crate::config::Theme;
+
+        self.config.ui.theme = match self.config.ui.theme {
+            Theme::Dark => Theme::Light,
+            Theme::Light | Theme::Mono => Theme::Dark,
+        };
+
+        // Update cached color scheme
+        self.color_scheme = ColorScheme::from_theme(&self.config.ui.theme);
+
+        // Save the updated config, unless this run leaves it alone
+        if !crate::globals::read_only() {
+            self.config.save()?;
+        }
+
+        Ok(())
+    }
+
+    /// An article's HTML as it should be displayed: the fetched page for full-text feeds
+    /// and prefetched saved items, else the summary or content the feed's display rules
+    /// prefer, with strip rules applied. The stripped HTML is kept per item, so views
+    /// drawing it every frame don't parse it again.
+    pub fn display_html(&self, feed_idx: usize, item_idx: usize) -> Option<String> {
+        let feed = self.feeds.get(feed_idx)?;
+        let item = feed.items.get(item_idx)?;
+        let full_text = match &item.link {
+            Some(link) if self.wants_full_text(feed_idx, item_idx) => self
+                .full_text_cache
+                .lock()
+                .ok()
+                .and_then(|cache| cache.get(link).cloned().flatten()),
+            _ => None,
+        };
+        let Some(rules) = self.feed_display_rules.get(&feed.url) else {
+            return full_text.or_else(|| item.description.clone());
+        };
+
+        let summary = if rules.prefer_summary {
+            item.summary.clone()
+        } else {
+            None
+        };
+        let html = full_text.or(summary).or_else(|| item.description.clone())?;
+        let Some(strip) = self.feed_strip_rules.get(&feed.url) else {
+            return Some(html);
+        };
+
+        let id = self.get_item_id(feed_idx, item_idx);
+        if let Ok(cache) = self.display_cache.lock() {
+            if let Some((source, shown)) = cache.get(&id) {
+                if *source == html {
+                    return Some(shown.clone());
+                }
+            }
+        }
+        let shown = crate::display_rules::apply(strip, &html);
+        if let Ok(mut cache) = self.display_cache.lock() {
+            cache.insert(id, (html, shown.clone()));
+        }
+        Some(shown)
+    }
+
+    /// Whether an item is shown as its linked page: its feed asks for full text (in its
+    /// display rules or as its open target), or it's starred or queued for later while
+    /// `general.prefetch_saved` is on.
+    fn wants_full_text(&self, feed_idx: usize, item_idx: usize) -> bool {
+        let Some(feed) = self.feeds.get(feed_idx) else {
+            return false;
+        };
+        self.feed_display_rules
+            .get(&feed.url)
+            .is_some_and(|rules| rules.full_text)
+            || self.open_target(feed_idx) == OpenTarget::FullText
+            || self.keeps_offline_copy(feed_idx, item_idx)
+    }
+
+    /// Whether to save bandwidth (`network.metered` or `--lite`): no images, article
+    /// pages or background refreshes are downloaded, only what's already on disk is used.
+    pub fn is_metered(&self) -> bool {
+        self.config.network.metered || crate::globals::lite()
+    }
+
+    fn keeps_offline_copy(&self, feed_idx: usize, item_idx: usize) -> bool {
+        self.config.general.prefetch_saved
+            && (self.is_item_starred(feed_idx, item_idx)
+                || self.is_item_read_later(feed_idx, item_idx))
+    }
+
+    /// Start fetching the linked page in the background if the item is shown as its page
+    /// (see `wants_full_text`), using the offline copy when there is one. Until it
+    /// arrives (or if it fails) the feed's own content is shown.
+    pub fn request_full_text(&self, feed_idx: usize, item_idx: usize) {
+        let Some(feed) = self.feeds.get(feed_idx) else {
+            return;
+        };
+        if !self.wants_full_text(feed_idx, item_idx) {
+            return;
+        }
+        let Some(link) = feed.items.get(item_idx).and_then(|item| item.link.clone()) else {
+            return;
+        };
+        let Ok(mut cache) = self.full_text_cache.lock() else {
+            return;
+        };
+        if cache.contains_key(&link) {
+            return;
+        }
+        cache.insert(link.clone(), None);
+
+        let cache = Arc::clone(&self.full_text_cache);
+        let timeout = self.config.network.http_timeout;
+        let user_agent = self.config.network.user_agent.clone();
+        let metered = self.is_metered();
+        std::thread::spawn(move || {
+            let saved = HttpCache::offline_location()
+                .load(&link)
+                .map(|page| String::from_utf8_lossy(&page.body).into_owned());
+            let page = match saved {
+                Some(html) => Ok(html),
+                None if metered => Err(anyhow::anyhow!(
+                    "Not fetching pages on a metered connection"
+                )),
+                None => Feed::build_client(timeout)
+                    .and_then(|client| Feed::fetch_article_page(&link, &client, &user_agent))
+                    .map(|(_, html)| html),
+            };
+            if let (Ok(html), Ok(mut cache)) = (page, cache.lock()) {
+                cache.insert(link, Some(html));
+            }
+        });
+    }
+
+    /// Download a newly saved item's article page and lead image into the offline store
+    /// in the background when `general.prefetch_saved` is on, so it can be read without
+    /// a connection later. Pages already stored aren't fetched again.
+    fn prefetch_offline(&self, feed_idx: usize, item_idx: usize) {
+        if !self.config.general.prefetch_saved || self.is_metered() {
+            return;
+        }
+        let Some(item) = self.feeds.get(feed_idx).and_then(|f| f.items.get(item_idx)) else {
+            return;
+        };
+        let Some(link) = item.link.clone() else {
+            return;
+        };
+        let store = HttpCache::offline_location();
+        if store.load(&link).is_some() {
+            return;
+        }
+        let image = item.image.clone();
+        let timeout = self.config.network.http_timeout;
+        let user_agent = self.config.network.user_agent.clone();
+        std::thread::spawn(move || {
+            let Ok(client) = Feed::build_client(timeout) else {
+                return;
+            };
+            let Ok((_, html)) = Feed::fetch_article_page(&link, &client, &user_agent) else {
+                return;
+            };
+            // A store that can't be written only means the page is fetched when opened
+            let _ = store.store(html.as_bytes(), &CacheMeta::downloaded(&link, "text/html"));
+            let image = image.or_else(|| crate::image_art::first_image(&html, Some(&link)));
+            if let Some(image) = image {
+                if let Ok(bytes) = crate::image_art::download(&image, &client, &user_agent) {
+                    let _ = store.store(&bytes, &CacheMeta::downloaded(&image, "image"));
+                }
+            }
+        });
+    }
+
+    /// Whether a feed is shown as a comic (`comic` in its display rules): the image large,
+    /// with its hover text, instead of the article text.
+    pub fn is_comic(&self, feed_idx: usize) -> bool {
+        self.feeds
+            .get(feed_idx)
+            .and_then(|feed| self.feed_display_rules.get(&feed.url))
+            .is_some_and(|rules| rules.comic)
+    }
+
+    /// A comic's image and hover text: the first image in `html`, else the item's image
+    /// (without any text).
+    pub fn comic_panel(
+        &self,
+        feed_idx: usize,
+        item_idx: usize,
+        html: Option<&str>,
+    ) -> Option<Panel> {
+        let item = self.feeds.get(feed_idx)?.items.get(item_idx)?;
+        html.and_then(|html| crate::image_art::first_panel(html, item.link.as_deref()))
+            .or_else(|| {
+                item.image.clone().map(|url| Panel {
+                    url,
+                    title: None,
+                    alt: None,
+                })
+            })
+    }
+
+    /// Open the current article's image (the comic, or the lead image) in the system's
+    /// image viewer.
+    pub fn open_current_image(&mut self) -> Result<()> {
+        let (Some(feed_idx), Some(item_idx)) = (self.selected_feed, self.selected_item) else {
+            return Ok(());
+        };
+        let html = self.display_html(feed_idx, item_idx);
+        let Some(panel) = self.comic_panel(feed_idx, item_idx, html.as_deref()) else {
+            anyhow::bail!("This article has no image");
+        };
+        open::that(panel.url)?;
+        Ok(())
+    }
+
+    /// Block art for an article's lead image when `ui.image_art` is on, falling back to the
+    /// first image in `html`. Comic feeds always get art for their comic, drawn larger. The
+    /// image is fetched in the background the first time, so this is None until it
+    /// arrives, and stays None if it can't be fetched or decoded.
+    pub fn image_art(
+        &self,
+        feed_idx: usize,
+        item_idx: usize,
+        html: Option<&str>,
+    ) -> Option<ImageArt> {
+        let comic = self.is_comic(feed_idx);
+        if !self.config.ui.image_art && !comic {
+            return None;
+        }
+        let item = self.feeds.get(feed_idx)?.items.get(item_idx)?;
+        let url = if comic {
+            self.comic_panel(feed_idx, item_idx, html)?.url
+        } else {
+            item.image.clone().or_else(|| {
+                html.and_then(|html| crate::image_art::first_image(html, item.link.as_deref()))
+            })?
+        };
+        let mut cache = self.image_art_cache.lock().ok()?;
+        if let Some(art) = cache.get(&url) {
+            return art.clone();
+        }
+        cache.insert(url.clone(), None);
+
+        let cache = Arc::clone(&self.image_art_cache);
+        let timeout = self.config.network.http_timeout;
+        let user_agent = self.config.network.user_agent.clone();
+        let (width, height) = if comic {
+            (COMIC_ART_WIDTH, COMIC_ART_HEIGHT)
+        } else {
+            (
+                self.config.ui.image_art_width,
+                self.config.ui.image_art_height,
+            )
+        };
+        let metered = self.is_metered();
+        std::thread::spawn(move || {
+            let art = match HttpCache::offline_location().load(&url) {
+                Some(saved) => crate::image_art::from_bytes(&saved.body, width, height),
+                None if metered => Err(anyhow::anyhow!(
+                    "Not downloading images on a metered connection"
+                )),
+                None => Feed::build_client(timeout).and_then(|client| {
+                    crate::image_art::fetch(&url, &client, &user_agent, width, height)
+                }),
+            };
+            if let (Ok(art), Ok(mut cache)) = (art, cache.lock()) {
+                cache.insert(url, Some(art));
+            }
+        });
+        None
+    }
+
+    /// The current article as plain text for external tools: title, blank line, body.
+    fn current_article_text(&self) -> Option<String> {
+        let (feed_idx, item_idx) = self.selected_feed.zip(self.selected_item)?;
+        let item = self.feeds.get(feed_idx)?.items.get(item_idx)?;
+        let body = self
+            .display_html(feed_idx, item_idx)
+            .map(|html| crate::ui::utils::article_text(&html))
+            .unwrap_or_default();
+        Some(format!("{}\n\n{}", item.title, body))
+    }
+
+    /// Run `command` on the current article in the background, caching its output in `cache`
+    /// under the item's ID. Cached and in-flight results are reused; failures are retried.
+    fn run_tool_on_current_item(
+        &self,
+        command: &str,
+        cache: &Arc<Mutex<HashMap<String, ToolOutput>>>,
+    ) {
+        let Some((feed_idx, item_idx)) = self.selected_feed.zip(self.selected_item) else {
+            return;
+        };
+        let Some(text) = self.current_article_text() else {
+            return;
+        };
+        let id = self.get_item_id(feed_idx, item_idx);
+        let Ok(mut guard) = cache.lock() else {
+            return;
+        };
+        if matches!(
+            guard.get(&id),
+            Some(ToolOutput::Pending | ToolOutput::Done(_))
+        ) {
+            return;
+        }
+        guard.insert(id.clone(), ToolOutput::Pending);
+
+        let cache = Arc::clone(cache);
+        let command = command.to_string();
+        std::thread::spawn(move || {
+            let output = match crate::share::filter_through_shell(&command, &text) {
+                Ok(out) => ToolOutput::Done(out),
+                Err(e) => ToolOutput::Failed(format!("{:#}", e)),
+            };
+            if let Ok(mut cache) = cache.lock() {
+                cache.insert(id, output);
+            }
+        });
+    }
+
+    /// Show or hide the current article's translation, starting the translate command
+    /// the first time it is shown.
+    pub fn toggle_translation(&mut self) -> Result<()> {
+        if self.show_translation {
+            self.show_translation = false;
+            return Ok(());
+        }
+        let Some(command) = self.config.tools.translate_command.clone() else {
+            anyhow::bail!("No translate command configured (set tools.translate_command)");
+        };
+        self.run_tool_on_current_item(&command, &self.translations);
+        self.show_translation = true;
+        Ok(())
+    }
+
+    /// Step the detail view from the rendered article to its HTML, then to its entry in
+    /// the feed document, then back.
+    pub fn cycle_detail_source(&mut self) {
+        self.detail_source = match self.detail_source {
+            DetailSource::Rendered => DetailSource::Html,
+            DetailSource::Html => {
+                let entry = self
+                    .selected_feed
+                    .zip(self.selected_item)
+                    .map(|(feed_idx, item_idx)| self.raw_entry(feed_idx, item_idx))
+                    .unwrap_or_default();
+                DetailSource::Entry(entry)
+            }
+            DetailSource::Entry(_) => DetailSource::Rendered,
+        };
+        self.detail_vertical_scroll = 0;
+        self.detail_horizontal_scroll = 0;
+    }
+
+    /// The item's entry as it appears in the last download of its feed, which is kept in
+    /// the HTTP cache, or a note saying why it can't be shown.
+    pub fn raw_entry(&self, feed_idx: usize, item_idx: usize) -> String {
+        let Some(feed) = self.feeds.get(feed_idx) else {
+            return String::new();
+        };
+        let Some(item) = feed.items.get(item_idx) else {
+            return String::new();
+        };
+        if !self.config.network.http_cache {
+            return "The feed document isn't kept while network.http_cache is off".to_string();
+        }
+        let Some(cached) = HttpCache::default_location().load(&feed.url) else {
+            return "No downloaded copy of this feed yet; refresh it first".to_string();
+        };
+        crate::raw_entry::find(&String::from_utf8_lossy(&cached.body), item).unwrap_or_else(|| {
+            "This article wasn't found in the last download of its feed".to_string()
+        })
+    }
+
+    /// The current article's translation state, if one has been requested.
+    pub fn current_translation(&self) -> Option<ToolOutput> {
+        let (feed_idx, item_idx) = self.selected_feed.zip(self.selected_item)?;
+        let id = self.get_item_id(feed_idx, item_idx);
+        self.translations.lock().ok()?.get(&id).cloned()
+    }
+
+    /// Open the summary popup for the current article, running the summarize command unless
+    /// a summary is already cached or in progress.
+    pub fn summarize_current_item(&mut self) -> Result<()> {
+        let Some(command) = self.config.tools.summarize_command.clone() else {
+            anyhow::bail!("No summarize command configured (set tools.summarize_command)");
+        };
+        if self.selected_feed.zip(self.selected_item).is_none() {
+            return Ok(());
+        }
+        self.run_tool_on_current_item(&command, &self.article_summaries);
+        self.show_summary_popup = true;
+        self.summary_popup_scroll = 0;
+        Ok(())
+    }
+
+    /// Write `general.metrics_file` for the refresh that just finished, if it's set and
+    /// this isn't a `--read-only` run. A leading `~` in the path is the home directory.
+    pub fn write_metrics(&self) -> Result<()> {
+        let Some(file) = self.config.general.metrics_file.as_deref() else {
+            return Ok(());
+        };
+        if crate::globals::read_only() {
+            return Ok(());
+        }
+        let path = match (file.strip_prefix("~/"), dirs::home_dir()) {
+            (Some(rest), Some(home)) => home.join(rest),
+            _ => PathBuf::from(file),
+        };
+        let metrics = crate::metrics::Metrics {
+            feeds: self.bookmarks.len(),
+            fetch_errors: self.failed_feeds.len(),
+            unread_items: (0..self.feeds.len())
+                .map(|idx| self.unread_count(idx))
+                .sum(),
+            refresh_duration: self
+                .refresh_started
+                .map(|started| started.elapsed())
+                .unwrap_or_default(),
+            refreshed_at: Utc::now().timestamp(),
+        };
+        metrics.write(&path)
+    }
+
+    /// Where exported articles go: `tools.export_dir` (a leading `~` is the home
+    /// directory), or `exports` in the data directory.
+    pub fn export_dir(&self) -> PathBuf {
+        match self.config.tools.export_dir.as_deref() {
+            Some(dir) => match (dir.strip_prefix("~/"), dirs::home_dir()) {
+                (Some(rest), Some(home)) => home.join(rest),
+                _ => PathBuf::from(dir),
+            },
+            None => Self::data_path().with_file_name("exports"),
+        }
+    }
+
+    /// Write the current article as a standalone HTML file in the export directory,
+    /// then convert it with `tools.pdf_command` when one is set. Returns the PDF's
+    /// path if one was made, else the HTML's.
+    pub fn export_current_item(&self) -> Result<PathBuf> {
+        let (feed_idx, item_idx) = self
+            .selected_feed
+            .zip(self.selected_item)
+            .context("No article selected")?;
+        let feed = self.feeds.get(feed_idx).context("No article selected")?;
+        let item = feed.items.get(item_idx).context("No article selected")?;
+        let body = self.display_html(feed_idx, item_idx).unwrap_or_default();
+
+        let dir = self.export_dir();
+        fs::create_dir_all(&dir)?;
+        let stem = crate::article_export::file_stem(&item.title);
+        let html_path = dir.join(format!("{}.html", stem));
+        fs::write(
+            &html_path,
+            crate::article_export::standalone_html(feed, item, &body),
+        )?;
+
+        let Some(command) = &self.config.tools.pdf_command else {
+            return Ok(html_path);
+        };
+        let pdf_path = dir.join(format!("{}.pdf", stem));
+        let command = command
+            .replace(
+                "{input}",
+                &crate::share::shell_quote(&html_path.to_string_lossy()),
+            )
+            .replace(
+                "{output}",
+                &crate::share::shell_quote(&pdf_path.to_string_lossy()),
+            );
+        crate::share::run_shell(&command)?;
+        Ok(pdf_path)
+    }
+
+    /// Count time spent in the detail view toward today's reading time. Called every
+    /// tick; a gap longer than a minute between ticks (a suspended machine) doesn't count.
+    pub fn track_reading_time(&mut self) {
+        if self.view != View::FeedItemDetail {
+            self.reading_tick = None;
+            return;
+        }
+        let now = Instant::now();
+        let Some(last) = self.reading_tick.replace(now) else {
+            return;
+        };
+        let elapsed = now.duration_since(last);
+        if elapsed > Duration::from_secs(60) {
+            return;
+        }
+        let today = chrono::Local::now().date_naive();
+        let key = today.format("%Y-%m-%d").to_string();
+        if !self.reading_time.contains_key(&key) {
+            // A new day: forget days older than three months
+            let cutoff = (today - chrono::Duration::days(90))
+                .format("%Y-%m-%d")
+                .to_string();
+            self.reading_time.retain(|day, _| *day >= cutoff);
+        }
+        *self.reading_time.entry(key).or_insert(0) += elapsed.as_millis() as u64;
+    }
+
+    /// Time spent in the detail view today.
+    pub fn reading_time_today(&self) -> Duration {
+        let key = chrono::Local::now().format("%Y-%m-%d").to_string();
+        Duration::from_millis(self.reading_time.get(&key).copied().unwrap_or(0))
+    }
+
+    /// Run `ui.status_command` in the background once `ui.status_interval` has passed
+    /// since it last started, keeping the first line it prints for the title bar.
+    #[cfg(feature = "scripting")]
+    pub fn update_status_strip(&mut self) {
+        let Some(command) = self.config.ui.status_command.clone() else {
+            return;
+        };
+        let interval = Duration::from_secs(self.config.ui.status_interval);
+        // A run still in progress holds the other reference; don't pile up behind it
+        if self
+            .status_strip_run
+            .is_some_and(|run| run.elapsed() < interval)
+            || Arc::strong_count(&self.status_strip) > 1
+        {
+            return;
+        }
+        self.status_strip_run = Some(Instant::now());
+
+        let strip = Arc::clone(&self.status_strip);
+        std::thread::spawn(move || {
+            let text = match crate::share::filter_through_shell(&command, "") {
+                Ok(out) => out.lines().next().unwrap_or("").trim().to_string(),
+                Err(_) => "status command failed".to_string(),
+            };
+            if let Ok(mut strip) = strip.lock() {
+                *strip = Some(text);
+            }
+        });
+    }
+
+    /// Re-fetch the `[[collections]]` OPML lists that are due in the background, and
+    /// apply the ones that have come back. Called on every tick; does nothing in a
+    /// `--read-only` run, where subscriptions and categories can't change.
+    #[cfg(feature = "sync")]
+    pub fn update_collections(&mut self) {
+        if crate::globals::read_only() {
+            return;
+        }
+        let finished: Vec<_> = match self.collection_results.lock() {
+            Ok(mut results) => results.drain(..).collect(),
+            Err(_) => return,
+        };
+        for (url, result) in finished {
+            let outcome = result
+                .map_err(anyhow::Error::msg)
+                .and_then(|feeds| self.apply_collection(&url, &feeds));
+            match outcome {
+                Ok((added, removed)) => {
+                    self.collection_syncs.insert(url, Utc::now().timestamp());
+                    if added + removed > 0 {
+                        self.success_message = Some(format!(
+                            "Collection synced: {} feeds added, {} removed",
+                            added, removed
+                        ));
+                        self.success_message_time = Some(Instant::now());
+                    }
+                    if let Err(e) = self.save_data() {
+                        self.error = Some(format!("Failed to save collection: {}", e));
+                    }
+                }
+                Err(e) => self.error = Some(format!("Failed to sync collection {}: {}", url, e)),
+            }
+        }
+
+        // A fetch still in progress holds the other reference
+        if Arc::strong_count(&self.collection_results) > 1 || self.is_metered() {
+            return;
+        }
+        let now = Utc::now().timestamp();
+        let due: Vec<String> = self
+            .config
+            .collections
+            .iter()
+            .filter(|c| {
+                let interval = c.interval_hours.saturating_mul(3600);
+                let synced = matches!(self.collection_syncs.get(&c.url),
+                    Some(at) if now - at < interval as i64);
+                let tried = matches!(self.collection_attempts.get(&c.url),
+                    Some(at) if at.elapsed().as_secs() < interval);
+                !synced && !tried
+            })
+            .map(|c| c.url.clone())
+            .collect();
+        if due.is_empty() {
+            return;
+        }
+        let client = match self.http_client() {
+            Ok(client) => client,
+            Err(_) => return,
+        };
+        for url in &due {
+            self.collection_attempts.insert(url.clone(), Instant::now());
+        }
+
+        let results = Arc::clone(&self.collection_results);
+        std::thread::spawn(move || {
+            for url in due {
+                let feeds = client
+                    .get(&url)
+                    .send()
+                    .and_then(|response| response.error_for_status())
+                    .and_then(|response| response.text())
+                    .map_err(|e| e.to_string())
+                    .and_then(|text| {
+                        opml::OPML::from_str(&text).map_err(|e| format!("OPML decode error. {}", e))
+                    })
+                    .map(|document| App::opml_document_urls(&document));
+                if let Ok(mut results) = results.lock() {
+                    results.push((url, feeds));
+                }
+            }
+        });
+    }
+
+    /// Make the category of the collection at `collection_url` match `feeds`: subscribe
+    /// to feeds it doesn't have yet and drop those the list no longer has. A dropped
+    /// feed that's also in another category only leaves this one. New feeds load on
+    /// the next refresh, which is requested here. Returns how many feeds were added
+    /// and removed. Does not save.
+    #[cfg(feature = "sync")]
+    pub fn apply_collection(
+        &mut self,
+        collection_url: &str,
+        feeds: &[String],
+    ) -> Result<(usize, usize)> {
+        crate::globals::ensure_writable("Syncing collections")?;
+        let collection = self
+            .config
+            .collections
+            .iter()
+            .find(|c| c.url == collection_url)
+            .cloned()
+            .context("Collection is no longer configured")?;
+        // An empty list is more likely a broken document than a cleared collection
+        if feeds.is_empty() {
+            anyhow::bail!("The list has no feeds");
+        }
+        let name = collection.category.trim();
+        let cat = match self
+            .categories
+            .iter()
+            .position(|c| c.name.eq_ignore_ascii_case(name))
+        {
+            Some(idx) => idx,
+            None => {
+                self.categories.push(FeedCategory::new(name));
+                self.categories.len() - 1
+            }
+        };
+
+        let mut added = Vec::new();
+        for url in feeds {
+            if !self.bookmarks.contains(url) {
+                self.bookmarks.push(url.clone());
+                added.push(url.clone());
+            }
+            self.categories[cat].add_feed(url);
+        }
+
+        let wanted: HashSet<&String> = feeds.iter().collect();
+        let dropped: Vec<String> = self.categories[cat]
+            .feeds
+            .iter()
+            .filter(|url| !wanted.contains(url))
+            .cloned()
+            .collect();
+        let selected_url = self.current_feed().map(|f| f.url.clone());
+        for url in &dropped {
+            let elsewhere = self
+                .categories
+                .iter()
+                .enumerate()
+                .any(|(idx, c)| idx != cat && c.feeds.contains(url));
+            if elsewhere {
+                self.categories[cat].remove_feed(url);
+            } else {
+                self.remove_bookmark(url);
+                self.feeds.retain(|f| &f.url != url);
+                self.feed_titles.remove(url);
+                self.engagement.remove(url);
+            }
+        }
+        if !dropped.is_empty() {
+            // Keep the selection on the same feed, or leave it if the feed is gone
+            self.selected_feed =
+                selected_url.and_then(|url| self.feeds.iter().position(|f| f.url == url));
+            if self.selected_feed.is_none() && self.view != View::Dashboard {
+                self.selected_item = None;
+                self.view = View::Dashboard;
+            }
+        }
+
+        if !added.is_empty() {
+            self.scheduled_refresh
+                .get_or_insert_with(Vec::new)
+                .extend(added.iter().cloned());
+        }
+        self.update_dashboard();
+        self.rebuild_feed_tree();
+        Ok((added.len(), dropped.len()))
+    }
+
+    /// The status command's latest output, once it has produced some.
+    #[cfg(feature = "scripting")]
+    pub fn status_strip_text(&self) -> Option<String> {
+        self.status_strip
+            .lock()
+            .ok()?
+            .clone()
+            .filter(|text| !text.is_empty())
+    }
+
+    /// Without the `scripting` feature there's no status command to show.
+    #[cfg(not(feature = "scripting"))]
+    pub fn status_strip_text(&self) -> Option<String> {
+        None
+    }
+
+    /// The current article's summary state, if one has been requested.
+    pub fn current_article_summary(&self) -> Option<ToolOutput> {
+        let (feed_idx, item_idx) = self.selected_feed.zip(self.selected_item)?;
+        let id = self.get_item_id(feed_idx, item_idx);
+        self.article_summaries.lock().ok()?.get(&id).cloned()
+    }
+
+    /// Enter copy mode on the current article, starting at the top of the visible content.
+    pub fn enter_copy_mode(&mut self) {
+        let html = self
+            .selected_feed
+            .zip(self.selected_item)
+            .and_then(|(feed_idx, item_idx)| self.display_html(feed_idx, item_idx));
+        let text = match html {
+            Some(html) => crate::ui::utils::article_text(&html),
+            None => return,
+        };
+        self.copy_mode = Some(CopyMode::new(&text, self.detail_vertical_scroll as usize));
+    }
+
+    /// Copy the copy-mode selection to the clipboard and leave copy mode.
+    pub fn yank_copy_selection(&mut self) -> Result<()> {
+        let Some(copy_mode) = self.copy_mode.take() else {
+            return Ok(());
+        };
+        let text = copy_mode.selected_text();
+        crate::share::copy_to_clipboard(&text)?;
+        let count = text.lines().count();
+        self.success_message = Some(format!(
+            "Copied {} line{}",
+            count,
+            if count == 1 { "" } else { "s" }
+        ));
+        self.success_message_time = Some(Instant::now());
+        Ok(())
+    }
+
+    pub fn extract_links_from_current_item(&mut self) {
+        use scraper::{Html, Selector};
+
+        self.extracted_links.clear();
+        self.selected_link = 0;
+
+        let description = if let Some(feed_idx) = self.selected_feed {
+            if let Some(item_idx) = self.selected_item {
+                self.feeds
+                    .get(feed_idx)
+                    .and_then(|f| f.items.get(item_idx))
+                    .and_then(|item| item.description.as_ref())
+                    .cloned()
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
+        let Some(html_content) = description else {
+            return;
+        };
+
+        let base_url = self
+            .selected_feed
+            .and_then(|fi| {
+                self.selected_item.and_then(|ii| {
+                    self.feeds
+                        .get(fi)
+                        .and_then(|f| f.items.get(ii).and_then(|item| item.link.as_ref()))
+                })
+            })
+            .and_then(|link| url::Url::parse(link).ok());
+
+        let document = Html::parse_document(&html_content);
+        let mut seen_urls = std::collections::HashSet::new();
+
+        // Extract links
+        if let Ok(selector) = Selector::parse("a[href]") {
+            for element in document.select(&selector) {
+                if let Some(href) = element.value().attr("href") {
+                    let resolved = if let Some(base) = &base_url {
+                        base.join(href)
+                            .map(|u| u.to_string())
+                            .unwrap_or_else(|_| href.to_string())
+                    } else {
+                        href.to_string()
+                    };
+                    if seen_urls.insert(resolved.clone()) {
+                        let text = element.text().collect::<String>().trim().to_string();
+                        self.extracted_links.push(ExtractedLink {
+                            url: resolved,
+                            text: if text.is_empty() {
+                                "(no text)".to_string()
+                            } else {
+                                text
+                            },
+                            link_type: LinkType::Link,
+                        });
+                    }
+                }
+            }
+        }
+
+        // Extract images
+        if let Ok(selector) = Selector::parse("img[src]") {
+            for element in document.select(&selector) {
+                if let Some(src) = element.value().attr("src") {
+                    let resolved = if let Some(base) = &base_url {
+                        base.join(src)
+                            .map(|u| u.to_string())
+                            .unwrap_or_else(|_| src.to_string())
+                    } else {
+                        src.to_string()
+                    };
+                    if seen_urls.insert(resolved.clone()) {
+                        let alt = element.value().attr("alt").unwrap_or("").trim().to_string();
+                        self.extracted_links.push(ExtractedLink {
+                            url: resolved,
+                            text: if alt.is_empty() {
+                                "(image)".to_string()
+                            } else {
+                                alt
+                            },
+                            link_type: LinkType::Image,
+                        });
+                    }
+                }
+            }
+        }
+
+        self.show_link_overlay = !self.extracted_links.is_empty();
+        if self.extracted_links.is_empty() {
+            self.error = Some("No links or images found in this article".to_string());
+        }
+    }
+}
+
+/// An index into `weights`, each chosen with probability proportional to its weight
+/// for a uniformly random `roll`. None when every weight is zero.
+fn pick_weighted(weights: &[u64], roll: u64) -> Option<usize> {
+    let total: u64 = weights.iter().sum();
+    if total == 0 {
+        return None;
+    }
+    let mut target = roll % total;
+    for (idx, &weight) in weights.iter().enumerate() {
+        if target < weight {
+            return Some(idx);
+        }
+        target -= weight;
+    }
+    None
+}
+
+/// Order items newest first, with undated items last.
+fn newest_first(a: &Option<DateTime<Utc>>, b: &Option<DateTime<Utc>>) -> std::cmp::Ordering {
+    match (a, b) {
+        (Some(a_date), Some(b_date)) => b_date.cmp(a_date),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    }
+}
+
+/// The lowercased domain an item links to, without `www.`.
+pub fn item_domain(item: &FeedItem) -> Option<String> {
+    let domain = App::extract_domain_from_url(item.link.as_deref()?).to_lowercase();
+    (!domain.is_empty()).then_some(domain)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_domain_from_url() {
+        assert_eq!(
+            App::extract_domain_from_url("https://www.reddit.com/r/rust/.rss"),
+            "reddit.com"
+        );
+        assert_eq!(
+            App::extract_domain_from_url("https://news.ycombinator.com/rss"),
+            "news.ycombinator.com"
+        );
+        assert_eq!(
+            App::extract_domain_from_url("http://example.com/feed.xml"),
+            "example.com"
+        );
+        assert_eq!(
+            App::extract_domain_from_url("https://www.example.com/"),
+            "example.com"
+        );
+    }
+
+    /// Helper to create a minimal App with test feeds, avoiding filesystem I/O
+    fn make_test_app() -> App {
+        let mut app = App::new();
+        app.feeds = vec![
+            Feed {
+                url: "https://example.com/feed1".to_string(),
+                title: "Feed One".to_string(),
+                title_lower: "feed one".to_string(),
+                description: None,
+                updated: None,
+                link: None,
+                items: vec![
+                    FeedItem {
+                        link: Some("https://example.com/old".to_string()),
+                        description: Some("Old content".to_string()),
+                        author: Some("Author A".to_string()),
+                        parsed_date: Some(Utc::now() - chrono::Duration::days(30)),
+                        plain_text: Some("Old content".to_string()),
+                        plain_text_lower: Some("old content".to_string()),
+                        ..FeedItem::with_title("Old Article")
+                    },
+                    FeedItem {
+                        link: Some("https://example.com/new".to_string()),
+                        description: Some("New content".to_string()),
+                        parsed_date: Some(Utc::now() - chrono::Duration::hours(1)),
+                        plain_text: Some("New content".to_string()),
+                        plain_text_lower: Some("new content".to_string()),
+                        ..FeedItem::with_title("New Article")
+                    },
+                ],
+            },
+            Feed {
+                url: "https://example.com/feed2".to_string(),
+                title: "Feed Two".to_string(),
+                title_lower: "feed two".to_string(),
+                description: None,
+                updated: None,
+                link: None,
+                items: vec![FeedItem {
+                    link: Some("https://example.com/another".to_string()),
+                    description: Some("Another new content".to_string()),
+                    author: Some("Author B".to_string()),
+                    parsed_date: Some(Utc::now() - chrono::Duration::hours(2)),
+                    plain_text: Some("Another new content".to_string()),
+                    plain_text_lower: Some("another new content".to_string()),
+                    ..FeedItem::with_title("Another New")
+                }],
+            },
+        ];
+        app.update_dashboard();
+        app
+    }
+
+    #[test]
+    fn test_get_new_items_since_session_no_session_time() {
+        let mut app = make_test_app();
+        app.last_session_time = None;
+        let items = app.get_new_items_since_session();
+        assert!(items.is_empty(), "No items when no session time is set");
+    }
+
+    #[test]
+    fn test_get_new_items_since_session_filters_by_date() {
+        let mut app = make_test_app();
+        // Set session time to 12 hours ago — should pick up items from last few hours
+        app.last_session_time = Some(Utc::now() - chrono::Duration::hours(12));
+        let items = app.get_new_items_since_session();
+        // "New Article" (1h ago) and "Another New" (2h ago) are newer than 12h ago
+        assert_eq!(items.len(), 2);
+    }
+
+    #[test]
+    fn test_get_new_items_since_session_skips_no_date() {
+        let mut app = make_test_app();
+        app.last_session_time = Some(Utc::now() - chrono::Duration::hours(12));
+        // Remove parsed_date from one item
+        app.feeds[0].items[1].parsed_date = None;
+        let items = app.get_new_items_since_session();
+        // Only "Another New" from Feed Two should match
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].2, "Feed Two");
+    }
+
+    #[test]
+    fn test_get_summary_stats() {
+        let mut app = make_test_app();
+        app.last_session_time = Some(Utc::now() - chrono::Duration::hours(12));
+        let (total, feeds) = app.get_summary_stats();
+        assert_eq!(total, 2);
+        // Both feeds have 1 new item each
+        assert_eq!(feeds.len(), 2);
+        // Sorted by count descending (both have 1, so order is stable but either is fine)
+        assert!(feeds.iter().all(|(_, count)| *count == 1));
+    }
+
+    #[test]
+    fn test_get_summary_stats_sorting() {
+        let mut app = make_test_app();
+        // Set session time far in the past so all items with dates are "new"
+        app.last_session_time = Some(Utc::now() - chrono::Duration::days(365));
+        let (total, feeds) = app.get_summary_stats();
+        assert_eq!(total, 3);
+        // Feed One has 2 items, Feed Two has 1 — Feed One should be first
+        assert_eq!(feeds[0].0, "Feed One");
+        assert_eq!(feeds[0].1, 2);
+        assert_eq!(feeds[1].0, "Feed Two");
+        assert_eq!(feeds[1].1, 1);
+    }
+
+    #[test]
+    fn test_live_search_clamps_selection() {
+        let mut app = make_test_app();
+        // Start with selection beyond what search will return
+        app.selected_item = Some(100);
+        app.live_search("new");
+        // Should clamp to last result, not reset to 0
+        assert!(app.selected_item.is_some());
+        let sel = app.selected_item.unwrap();
+        assert!(sel < app.filtered_items.len());
+        assert_eq!(sel, app.filtered_items.len() - 1);
+    }
+
+    #[test]
+    fn test_live_search_preserves_valid_selection() {
+        let mut app = make_test_app();
+        app.selected_item = Some(0);
+        app.live_search("new");
+        // Selection 0 is valid, should stay
+        assert_eq!(app.selected_item, Some(0));
+    }
+
+    #[test]
+    fn test_live_search_sets_selection_when_none() {
+        let mut app = make_test_app();
+        app.selected_item = None;
+        app.live_search("new");
+        // Should set to 0 when there are results
+        assert_eq!(app.selected_item, Some(0));
+    }
+
+    #[test]
+    fn test_live_search_no_results() {
+        let mut app = make_test_app();
+        app.selected_item = Some(1);
+        app.live_search("zzzznonexistent");
+        // No results, selection should remain as-is (count == 0, match arm falls through)
+        assert_eq!(app.selected_item, Some(1));
+    }
+
+    #[test]
+    fn test_search_results_opened_stay_visited() {
+        let mut app = make_test_app();
+        app.record_item_open(0, 0);
+        assert!(!app.is_search_visited(0, 0));
+
+        app.live_search("new");
+        let (feed_idx, item_idx) = app.filtered_items[0];
+        app.record_item_open(feed_idx, item_idx);
+        assert!(app.is_search_visited(feed_idx, item_idx));
+        assert!(!app.is_search_visited(app.filtered_items[1].0, app.filtered_items[1].1));
+
+        // Still visited when the same results come up in another search
+        app.search_feeds("");
+        app.live_search("article");
+        assert!(app.is_search_visited(feed_idx, item_idx));
+    }
+
+    #[test]
+    fn test_toggle_preview_pane() {
+        let mut app = make_test_app();
+        assert!(!app.preview_pane);
+        app.preview_scroll = 5;
+        app.preview_max_scroll = 10;
+
+        app.toggle_preview_pane();
+        assert!(app.preview_pane);
+        assert_eq!(app.preview_scroll, 0);
+        assert_eq!(app.preview_max_scroll, 0);
+
+        app.toggle_preview_pane();
+        assert!(!app.preview_pane);
+    }
+
+    #[test]
+    fn test_reset_preview_scroll() {
+        let mut app = make_test_app();
+        app.preview_scroll = 10;
+        app.preview_max_scroll = 20;
+
+        app.reset_preview_scroll();
+        assert_eq!(app.preview_scroll, 0);
+        assert_eq!(app.preview_max_scroll, 0);
+    }
+
+    #[test]
+    fn test_update_preview_max_scroll() {
+        let mut app = make_test_app();
+        app.update_preview_max_scroll(50, 20);
+        assert_eq!(app.preview_max_scroll, 30);
+
+        // Content fits in viewport
+        app.update_preview_max_scroll(10, 20);
+        assert_eq!(app.preview_max_scroll, 0);
+    }
+
+    #[test]
+    fn test_clamp_preview_scroll() {
+        let mut app = make_test_app();
+        app.preview_max_scroll = 10;
+        app.preview_scroll = 15;
+        app.clamp_preview_scroll();
+        assert_eq!(app.preview_scroll, 10);
+
+        // Already valid
+        app.preview_scroll = 5;
+        app.clamp_preview_scroll();
+        assert_eq!(app.preview_scroll, 5);
+    }
+
+    #[test]
+    fn test_should_auto_refresh() {
+        let mut app = App::new();
+
+        // Should not refresh when disabled
+        app.config.general.refresh_enabled = false;
+        app.config.general.auto_refresh_interval = 300;
+        assert!(!app.should_auto_refresh());
+
+        // Should not refresh when interval is 0
+        app.config.general.refresh_enabled = true;
+        app.config.general.auto_refresh_interval = 0;
+        assert!(!app.should_auto_refresh());
+
+        // Should refresh when enabled and never refreshed before
+        app.config.general.refresh_enabled = true;
+        app.config.general.auto_refresh_interval = 300;
+        app.last_refresh = None;
+        assert!(app.should_auto_refresh());
+
+        // Should not refresh on a metered connection
+        app.config.network.metered = true;
+        assert!(!app.should_auto_refresh());
+        app.config.network.metered = false;
+
+        // Should not refresh when in progress
+        app.refresh_in_progress = true;
+        assert!(!app.should_auto_refresh());
+    }
+
+    #[test]
+    fn test_should_auto_refresh_per_feed_interval() {
+        let mut app = App::new();
+        // Disable global refresh
+        app.config.general.refresh_enabled = false;
+        app.config.general.auto_refresh_interval = 0;
+
+        // Add a per-feed interval
+        app.feed_refresh_intervals
+            .insert("https://example.com/feed".to_string(), 60);
+
+        // Never refreshed — should trigger
+        assert!(app.should_auto_refresh());
+
+        // Mark as recently refreshed globally
+        app.last_refresh = Some(std::time::Instant::now());
+        assert!(!app.should_auto_refresh());
+    }
+
+    #[test]
+    fn test_adaptive_interval_follows_posting_rate() {
+        let mut app = make_test_app();
+        app.feed_refresh_intervals.clear();
+        app.config.general.refresh_enabled = true;
+        app.config.general.auto_refresh_interval = 300;
+        app.config.general.adaptive_refresh = true;
+        // A post every six hours
+        let template = app.feeds[0].items[0].clone();
+        app.feeds[0].items = (0..4)
+            .map(|i| FeedItem {
+                parsed_date: Some(Utc::now() - chrono::Duration::hours(6 * i)),
+                ..template.clone()
+            })
+            .collect();
+
+        assert_eq!(
+            app.refresh_interval("https://example.com/feed1"),
+            Some((3 * 60 * 60, IntervalSource::Adaptive))
+        );
+        // Too few dated items to tell, so the global interval applies
+        assert_eq!(
+            app.refresh_interval("https://example.com/feed2"),
+            Some((300, IntervalSource::Global))
+        );
+
+        app.config.general.adaptive_refresh = false;
+        assert_eq!(
+            app.refresh_interval("https://example.com/feed1"),
+            Some((300, IntervalSource::Global))
+        );
+    }
+
+    #[test]
+    fn test_scheduled_refresh_skips_paused_feeds() {
+        let mut app = make_test_app();
+        app.bookmarks = vec![
+            "https://example.com/feed1".to_string(),
+            "https://example.com/feed2".to_string(),
+        ];
+        app.feed_refresh_intervals.clear();
+        app.last_feed_refresh.clear();
+        app.paused_feeds.clear();
+        app.config.general.refresh_enabled = true;
+        app.config.general.auto_refresh_interval = 300;
+        app.last_refresh = None;
+
+        // Nothing paused: the usual full refresh
+        app.request_scheduled_refresh();
+        assert!(app.refresh_requested);
+        assert!(app.scheduled_refresh.is_none());
+
+        app.refresh_requested = false;
+        assert!(app.toggle_feed_paused("https://example.com/feed2"));
+        app.request_scheduled_refresh();
+        assert!(!app.refresh_requested);
+        assert_eq!(
+            app.scheduled_refresh.take(),
+            Some(vec!["https://example.com/feed1".to_string()])
+        );
+        assert!(app.last_refresh.is_some());
+        assert!(app.feed_schedule("https://example.com/feed2").paused);
+        assert_eq!(app.feed_schedule("https://example.com/feed2").due_in, None);
+
+        // Adaptive scheduling refreshes every unpaused feed on its own
+        app.config.general.adaptive_refresh = true;
+        app.last_refresh = None;
+        assert_eq!(
+            app.due_feed_urls(),
+            vec!["https://example.com/feed1".to_string()]
+        );
+        assert!(!app.toggle_feed_paused("https://example.com/feed2"));
+        assert_eq!(app.due_feed_urls().len(), 2);
+    }
+
+    #[test]
+    fn test_rebuild_feed_tree_uncategorized_only() {
+        let mut app = make_test_app();
+        app.categories.clear();
+        app.rebuild_feed_tree();
+
+        // Should have exactly 2 uncategorized feed entries
+        assert_eq!(app.feed_tree.len(), 2);
+        assert!(matches!(app.feed_tree[0], TreeItem::Feed(0, None)));
+        assert!(matches!(app.feed_tree[1], TreeItem::Feed(1, None)));
+    }
+
+    #[test]
+    fn test_rebuild_feed_tree_with_category() {
+        let mut app = make_test_app();
+        app.categories.clear();
+        let mut cat = FeedCategory::new("Tech");
+        cat.add_feed("https://example.com/feed1");
+        app.categories.push(cat);
+        app.rebuild_feed_tree();
+
+        // Category node + expanded feed + 1 uncategorized feed = 3
+        assert_eq!(app.feed_tree.len(), 3);
+        assert!(matches!(app.feed_tree[0], TreeItem::Category(0)));
+        assert!(matches!(app.feed_tree[1], TreeItem::Feed(0, Some(0))));
+        assert!(matches!(app.feed_tree[2], TreeItem::Feed(1, None)));
+    }
+
+    #[test]
+    fn test_rebuild_feed_tree_collapsed_category() {
+        let mut app = make_test_app();
+        app.categories.clear();
+        let mut cat = FeedCategory::new("Tech");
+        cat.add_feed("https://example.com/feed1");
+        cat.expanded = false;
+        app.categories.push(cat);
+        app.rebuild_feed_tree();
+
+        // Category node + 1 uncategorized (feed1 is hidden, feed2 uncategorized)
+        assert_eq!(app.feed_tree.len(), 2);
+        assert!(matches!(app.feed_tree[0], TreeItem::Category(0)));
+        assert!(matches!(app.feed_tree[1], TreeItem::Feed(1, None)));
+    }
+
+    #[test]
+    fn test_rebuild_feed_tree_clamps_selection() {
+        let mut app = make_test_app();
+        app.categories.clear();
+        app.rebuild_feed_tree();
+        // Set selection beyond bounds
+        app.selected_tree_item = Some(100);
+        app.rebuild_feed_tree();
+        assert_eq!(app.selected_tree_item, Some(app.feed_tree.len() - 1));
+    }
+
+    #[test]
+    fn test_rebuild_feed_tree_empty() {
+        let mut app = App::new();
+        app.feeds.clear();
+        app.categories.clear();
+        app.selected_tree_item = Some(5);
+        app.rebuild_feed_tree();
+        assert!(app.feed_tree.is_empty());
+        assert_eq!(app.selected_tree_item, None);
+    }
+
+    #[test]
+    fn test_mark_all_dashboard_read() {
+        let mut app = make_test_app();
+        app.read_items.clear();
+        // All 3 items should be unread initially
+        assert!(!app.is_item_read(0, 0));
+        assert!(!app.is_item_read(0, 1));
+        assert!(!app.is_item_read(1, 0));
+
+        let count = app.mark_all_dashboard_read().unwrap();
+        assert_eq!(count, 3);
+
+        assert!(app.is_item_read(0, 0));
+        assert!(app.is_item_read(0, 1));
+        assert!(app.is_item_read(1, 0));
+
+        // Calling again should mark 0 new items
+        let count = app.mark_all_dashboard_read().unwrap();
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn test_mark_all_feed_read() {
+        let mut app = make_test_app();
+        app.read_items.clear();
+        let count = app.mark_all_feed_read(0).unwrap();
+        assert_eq!(count, 2); // Feed 0 has 2 items
+
+        assert!(app.is_item_read(0, 0));
+        assert!(app.is_item_read(0, 1));
+        assert!(!app.is_item_read(1, 0)); // Feed 1 untouched
+    }
+
+    #[test]
+    fn test_mark_all_feed_read_invalid_index() {
+        let mut app = make_test_app();
+        let count = app.mark_all_feed_read(999).unwrap();
+        assert_eq!(count, 0);
+    }
+
+    #[test]
+    fn test_mark_all_starred_read() {
+        let mut app = make_test_app();
+        app.read_items.clear();
+        app.starred_items.clear();
+        // Star two items
+        app.toggle_item_starred(0, 0).unwrap();
+        app.toggle_item_starred(1, 0).unwrap();
+
+        let count = app.mark_all_starred_read().unwrap();
+        assert_eq!(count, 2);
+        assert!(app.is_item_read(0, 0));
+        assert!(app.is_item_read(1, 0));
+        assert!(!app.is_item_read(0, 1)); // Unstarred item untouched
+    }
+
+    #[test]
+    fn test_extract_links_from_html() {
+        let mut app = make_test_app();
+        app.selected_feed = Some(0);
+        app.selected_item = Some(0);
+
+        // Set HTML content with links and images
+        app.feeds[0].items[0].description = Some(
+            r#"<p>Check out <a href="https://example.com/page">this page</a>
+            and <a href="https://example.com/other">another link</a></p>
+            <img src="https://example.com/image.png" alt="test image">"#
+                .to_string(),
+        );
+
+        app.extract_links_from_current_item();
+
+        assert!(app.show_link_overlay);
+        assert_eq!(app.extracted_links.len(), 3);
+        assert_eq!(app.extracted_links[0].url, "https://example.com/page");
+        assert_eq!(app.extracted_links[0].text, "this page");
+        assert!(matches!(app.extracted_links[0].link_type, LinkType::Link));
+        assert_eq!(app.extracted_links[2].url, "https://example.com/image.png");
+        assert!(matches!(app.extracted_links[2].link_type, LinkType::Image));
+    }
+
+    #[test]
+    fn test_extract_links_deduplicates() {
+        let mut app = make_test_app();
+        app.selected_feed = Some(0);
+        app.selected_item = Some(0);
+
+        app.feeds[0].items[0].description = Some(
+            r#"<a href="https://example.com">link1</a>
+            <a href="https://example.com">link2</a>"#
+                .to_string(),
+        );
+
+        app.extract_links_from_current_item();
+
+        assert_eq!(app.extracted_links.len(), 1);
+    }
+
+    #[test]
+    fn test_extract_links_no_content() {
+        let mut app = make_test_app();
+        app.selected_feed = Some(0);
+        app.selected_item = Some(0);
+        app.feeds[0].items[0].description = None;
+
+        app.extract_links_from_current_item();
+
+        assert!(!app.show_link_overlay);
+        assert!(app.extracted_links.is_empty());
+    }
+
+    #[test]
+    fn test_extract_links_resolves_relative_urls() {
+        let mut app = make_test_app();
+        app.selected_feed = Some(0);
+        app.selected_item = Some(0);
+        // Item has a base URL via its link
+        app.feeds[0].items[0].link = Some("https://example.com/article/123".to_string());
+        app.feeds[0].items[0].description = Some(r#"<a href="/about">About</a>"#.to_string());
+
+        app.extract_links_from_current_item();
+
+        assert_eq!(app.extracted_links.len(), 1);
+        assert_eq!(app.extracted_links[0].url, "https://example.com/about");
+    }
+
+    #[test]
+    fn test_insert_feed_matches_full_rebuild() {
+        let mut app = make_test_app();
+        let feed2 = app.feeds.remove(1);
+        let feed1 = app.feeds.remove(0);
+        app.update_dashboard();
+
+        // Feeds arrive out of bookmark order, as they do from refresh threads
+        app.insert_feed(0, feed2);
+        app.insert_feed(0, feed1);
+        let incremental = app.dashboard_items.clone();
+
+        app.update_dashboard();
+        assert_eq!(incremental, app.dashboard_items);
+        assert_eq!(app.dashboard_items, vec![(0, 1), (1, 0), (0, 0)]);
+    }
+
+    #[test]
+    fn test_insert_feed_respects_max_items_and_filters() {
+        let mut app = make_test_app();
+        app.config.general.max_dashboard_items = 2;
+        app.filter_options.has_author = Some(true);
+        let feed1 = app.feeds.remove(0);
+        app.update_dashboard();
+        assert_eq!(app.filtered_dashboard_items, vec![(0, 0)]);
+
+        app.insert_feed(0, feed1);
+        assert_eq!(app.dashboard_items, vec![(0, 1), (1, 0)]);
+        // Feed Two shifted to index 1; "New Article" has no author
+        assert_eq!(app.filtered_dashboard_items, vec![(1, 0)]);
+    }
+
+    #[test]
+    fn test_search_is_diacritic_insensitive() {
+        let mut app = make_test_app();
+        app.feeds[1].items[0].title = "Café Opening".to_string();
+        app.feeds[1].items[0].title_lower = fold_for_search("Café Opening");
+
+        app.search_feeds("cafe");
+        assert_eq!(app.filtered_items, vec![(1, 0)]);
+
+        app.search_feeds("CAFÉ");
+        assert_eq!(app.filtered_items, vec![(1, 0)]);
+    }
+
+    #[test]
+    fn test_read_changes_are_debounced() {
+        let mut app = make_test_app();
+        assert!(!app.has_unsaved_changes());
+
+        app.mark_item_as_read(0, 0).unwrap();
+        app.toggle_item_starred(0, 1).unwrap();
+        assert!(app.has_unsaved_changes());
+
+        // Within the debounce window nothing is written yet
+        app.save_if_due();
+        assert!(app.has_unsaved_changes());
+    }
+
+    #[test]
+    fn test_mark_dirty_keeps_first_timestamp() {
+        let mut app = make_test_app();
+        let earlier = Instant::now() - Duration::from_secs(10);
+        app.unsaved_since = Some(earlier);
+        app.mark_data_dirty();
+        assert_eq!(app.unsaved_since, Some(earlier));
+    }
+
+    #[test]
+    fn test_shutdown_without_loaded_feeds_has_nothing_to_write() {
+        let mut app = make_test_app();
+        app.last_refresh = None;
+        app.shutdown().unwrap();
+        assert!(!app.has_unsaved_changes());
+    }
+
+    #[test]
+    fn test_unloaded_bookmarks_show_as_placeholders_while_refreshing() {
+        let mut app = make_test_app();
+        app.categories = vec![FeedCategory::new("News")];
+        app.categories[0].add_feed("https://example.com/slow");
+        app.bookmarks = vec![
+            "https://example.com/feed1".to_string(),
+            "https://example.com/slow".to_string(),
+            "https://example.com/broken".to_string(),
+            "https://example.com/unnamed".to_string(),
+        ];
+        app.feed_titles
+            .insert("https://example.com/slow".to_string(), "Slow".to_string());
+        app.failed_feeds = vec![("https://example.com/broken".to_string(), "404".to_string())];
+        app.refresh_in_progress = true;
+        app.rebuild_feed_tree();
+
+        let pending: Vec<(String, Option<usize>)> = app
+            .feed_tree
+            .iter()
+            .filter_map(|item| match item {
+                TreeItem::Pending(url, parent) => Some((url.clone(), *parent)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(
+            pending,
+            vec![
+                ("https://example.com/slow".to_string(), Some(0)),
+                ("https://example.com/unnamed".to_string(), None),
+            ]
+        );
+        assert_eq!(app.placeholder_title("https://example.com/slow"), "Slow");
+        assert_eq!(
+            app.placeholder_title("https://example.com/unnamed"),
+            "https://example.com/unnamed"
+        );
+
+        app.refresh_in_progress = false;
+        app.rebuild_feed_tree();
+        assert!(!app
+            .feed_tree
+            .iter()
+            .any(|item| matches!(item, TreeItem::Pending(..))));
+    }
+
+    #[test]
+    fn test_undo_and_redo_tree_edits() {
+        let mut app = make_test_app();
+        app.bookmarks = app.feeds.iter().map(|feed| feed.url.clone()).collect();
+        let mut news = FeedCategory::new("News");
+        news.add_feed("https://example.com/feed1");
+        app.categories = vec![news.clone(), FeedCategory::new("Other")];
+
+        // Deleting a category comes back in its old place, feeds and all
+        let deleted = app.categories.remove(0);
+        app.edit_history.record(TreeEdit::DeleteCategory {
+            index: 0,
+            category: deleted,
+        });
+        assert_eq!(app.undo_edit().as_deref(), Some("deleted category News"));
+        assert_eq!(app.categories[0].name, "News");
+        assert!(app.categories[0].contains_feed("https://example.com/feed1"));
+        app.redo_edit();
+        assert_eq!(app.categories.len(), 1);
+        app.undo_edit();
+
+        // A removed feed returns with its items, bookmark position and categories
+        let removed = RemovedFeed {
+            url: "https://example.com/feed1".to_string(),
+            bookmark_index: Some(0),
+            feed_index: 0,
+            feed: app.feeds[0].clone(),
+            category_ids: vec![news.id.clone()],
+            title: None,
+            engagement: None,
+            archived: false,
+        };
+        app.drop_feed("https://example.com/feed1");
+        app.edit_history
+            .record(TreeEdit::RemoveFeed(Box::new(removed)));
+        assert_eq!(app.feeds.len(), 1);
+        assert!(!app.categories[0].contains_feed("https://example.com/feed1"));
+        app.undo_edit();
+        assert_eq!(app.feeds[0].url, "https://example.com/feed1");
+        assert_eq!(app.feeds[0].items.len(), 2);
+        assert_eq!(app.bookmarks[0], "https://example.com/feed1");
+        assert!(app.categories[0].contains_feed("https://example.com/feed1"));
+
+        // Undoing everything leaves nothing more to undo, and a new edit drops the redo
+        while app.undo_edit().is_some() {}
+        app.edit_history.record(TreeEdit::RenameCategory {
+            id: news.id.clone(),
+            from: "News".to_string(),
+            to: "Headlines".to_string(),
+        });
+        assert!(app.redo_edit().is_none());
+    }
+
+    #[test]
+    fn test_feed_sort_modes() {
+        let mut app = make_test_app();
+        app.feeds[1].items[0].parsed_date = Some(Utc::now());
+        app.rebuild_feed_tree();
+        let feed_order = |app: &App| -> Vec<usize> {
+            app.feed_tree
+                .iter()
+                .filter_map(|item| match item {
+                    TreeItem::Feed(idx, _) => Some(*idx),
+                    TreeItem::Category(_) | TreeItem::Archive | TreeItem::Pending(..) => None,
+                })
+                .collect()
+        };
+        assert_eq!(feed_order(&app), vec![0, 1]);
+
+        app.feed_sort = FeedSortMode::RecentlyUpdated;
+        app.rebuild_feed_tree();
+        assert_eq!(feed_order(&app), vec![1, 0]);
+
+        // Feed One has two unread items, Feed Two one
+        app.feed_sort = FeedSortMode::MostUnread;
+        app.rebuild_feed_tree();
+        assert_eq!(feed_order(&app), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_engagement_counts_opens_once_and_unopened_dismissals() {
+        let mut app = make_test_app();
+        app.engagement.clear();
+        app.item_opens.clear();
+        app.read_items.clear();
+        let url = "https://example.com/feed1";
+
+        app.open_item_detail(0, 1);
+        app.mark_item_as_read(0, 1).unwrap();
+        app.open_item_detail(0, 1);
+        assert_eq!(app.item_opens.get("https://example.com/new"), Some(&2));
+
+        // Already opened, so reading it again or marking all read isn't a dismissal
+        app.toggle_item_read(0, 1).unwrap();
+        app.toggle_item_read(0, 1).unwrap();
+        assert_eq!(app.mark_all_feed_read(0).unwrap(), 1);
+
+        let engagement = app.feed_engagement(url);
+        assert_eq!(engagement.opened, 1);
+        assert_eq!(engagement.dismissed, 1);
+        assert_eq!(engagement.score(), Some(50));
+        assert_eq!(
+            app.feed_engagement("https://example.com/feed2").score(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_least_engaged_sort_puts_unread_feeds_first() {
+        let mut app = make_test_app();
+        app.categories.clear();
+        app.engagement.clear();
+        app.engagement.insert(
+            "https://example.com/feed1".to_string(),
+            FeedEngagement {
+                opened: 9,
+                clicks: 0,
+                dismissed: 1,
+            },
+        );
+        app.engagement.insert(
+            "https://example.com/feed2".to_string(),
+            FeedEngagement {
+                opened: 0,
+                clicks: 0,
+                dismissed: 12,
+            },
+        );
+        app.feed_sort = FeedSortMode::LeastEngaged;
+        app.rebuild_feed_tree();
+        assert!(matches!(app.feed_tree[0], TreeItem::Feed(1, _)));
+
+        // No activity at all sorts after any score
+        app.engagement.remove("https://example.com/feed2");
+        app.rebuild_feed_tree();
+        assert!(matches!(app.feed_tree[0], TreeItem::Feed(0, _)));
+    }
+
+    #[test]
+    fn test_cycle_feed_sort_keeps_selected_feed() {
+        let mut app = make_test_app();
+        app.feeds[1].items[0].parsed_date = Some(Utc::now());
+        app.feed_sort = FeedSortMode::Alphabetical;
+        app.rebuild_feed_tree();
+        app.selected_tree_item = Some(1);
+
+        app.cycle_feed_sort();
+        assert_eq!(app.feed_sort, FeedSortMode::MostUnread);
+        app.cycle_feed_sort();
+        assert_eq!(app.feed_sort, FeedSortMode::RecentlyUpdated);
+        assert!(matches!(
+            app.feed_tree[app.selected_tree_item.unwrap()],
+            TreeItem::Feed(1, _)
+        ));
+    }
+
+    #[test]
+    fn test_selected_item_indices_per_view() {
+        let mut app = make_test_app();
+        app.view = View::Dashboard;
+        app.selected_item = Some(0);
+        assert_eq!(app.selected_item_indices(), Some((0, 1)));
+
+        app.view = View::FeedItems;
+        app.selected_feed = Some(1);
+        assert_eq!(app.selected_item_indices(), Some((1, 0)));
+
+        app.selected_item = Some(5);
+        assert_eq!(app.selected_item_indices(), None);
+
+        app.view = View::FeedList;
+        app.selected_item = Some(0);
+        assert_eq!(app.selected_item_indices(), None);
+    }
+
+    #[test]
+    fn test_replace_bookmark_url_keeps_categories() {
+        let mut app = make_test_app();
+        app.bookmarks = vec!["https://a.com/feed".into(), "https://b.com/feed".into()];
+        app.categories = vec![FeedCategory::new("Tech")];
+        app.categories[0].add_feed("https://a.com/feed");
+
+        assert!(app.replace_bookmark_url("https://a.com/feed", "https://a.com/rss"));
+        assert_eq!(app.bookmarks[0], "https://a.com/rss");
+        assert!(app.categories[0].contains_feed("https://a.com/rss"));
+        assert!(!app.categories[0].contains_feed("https://a.com/feed"));
+
+        // Replacing with an already-subscribed URL collapses the duplicate
+        assert!(app.replace_bookmark_url("https://a.com/rss", "https://b.com/feed"));
+        assert_eq!(app.bookmarks, vec!["https://b.com/feed".to_string()]);
+    }
+
+    #[test]
+    fn test_export_starred_bookmarks_uses_loaded_titles() {
+        let mut app = make_test_app();
+        app.bookmarks = vec!["https://example.com/feed1".to_string()];
+        app.starred_items = [
+            "https://example.com/new".to_string(),
+            "https://elsewhere.com/gone".to_string(),
+            "https://example.com/feed1_Untitled".to_string(),
+        ]
+        .into_iter()
+        .collect();
+
+        let path = std::env::temp_dir().join(format!("feedr-export-{}.html", uuid::Uuid::new_v4()));
+        let count = app.export_starred_bookmarks(&path).unwrap();
+        let html = fs::read_to_string(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(count, 2);
+        assert!(html.contains(">New Article</A>"));
+        assert!(html.contains("HREF=\"https://elsewhere.com/gone\""));
+        assert!(!html.contains("Untitled"));
+    }
+
+    #[test]
+    fn test_copy_mode_line_and_block_selection() {
+        let mut copy_mode = CopyMode::new("alpha beta\ngamma delta\nepsilon", 0);
+        assert_eq!(copy_mode.selected_text(), "alpha beta");
+
+        copy_mode.toggle_selection(SelectionKind::Lines);
+        copy_mode.move_lines(1);
+        assert_eq!(copy_mode.selected_text(), "alpha beta\ngamma delta");
+
+        // Block selection keeps only the columns between anchor and cursor
+        copy_mode.toggle_selection(SelectionKind::Block);
+        copy_mode.move_cols(3);
+        copy_mode.move_lines(1);
+        assert_eq!(copy_mode.selected_text(), "gamm\nepsi");
+
+        copy_mode.toggle_selection(SelectionKind::Block);
+        assert_eq!(copy_mode.anchor, None);
+        assert_eq!(copy_mode.selected_text(), "epsilon");
+    }
+
+    #[test]
+    fn test_horizontal_scroll_only_when_not_wrapping() {
+        let mut app = make_test_app();
+        app.detail_max_horizontal_scroll = 20;
+
+        app.scroll_detail_horizontally(8);
+        assert_eq!(app.detail_horizontal_scroll, 0);
+
+        app.toggle_detail_wrap();
+        app.scroll_detail_horizontally(8);
+        app.scroll_detail_horizontally(100);
+        assert_eq!(app.detail_horizontal_scroll, 20);
+
+        // Wrap state is per article
+        app.exit_detail_view(View::FeedItems);
+        assert!(!app.detail_no_wrap);
+        assert_eq!(app.detail_horizontal_scroll, 0);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_jump_footnote_goes_to_note_and_back() {
+        let mut app = make_test_app();
+        app.detail_footnote_rows = vec![(3, 40), (12, 42)];
+        app.detail_max_scroll = 41;
+        app.detail_vertical_scroll = 5;
+
+        app.jump_footnote();
+        assert_eq!(app.detail_vertical_scroll, 41);
+        app.jump_footnote();
+        assert_eq!(app.detail_vertical_scroll, 5);
+
+        // After scrolling away, the next press jumps forward again
+        app.jump_footnote();
+        app.detail_vertical_scroll = 0;
+        app.jump_footnote();
+        assert_eq!(app.detail_vertical_scroll, 40);
+    }
 
     #[test]
-    fn test_extract_domain_from_url() {
+    fn test_display_html_applies_feed_rules() {
+        let mut app = make_test_app();
+        app.feeds[0].items[1].description =
+            Some("<p>Body</p><div class=\"ad\">Ad</div>".to_string());
+        app.feeds[0].items[1].summary = Some("<p>Short</p>".to_string());
+
+        // Feeds without rules show their content untouched
         assert_eq!(
-            App::extract_domain_from_url("https://www.reddit.com/r/rust/.rss"),
-            "reddit.com"
+            app.display_html(0, 1).as_deref(),
+            Some("<p>Body</p><div class=\"ad\">Ad</div>")
         );
-        assert_eq!(
-            App::extract_domain_from_url("https://news.ycombinator.com/rss"),
-            "news.ycombinator.com"
+
+        let rules = DisplayRules {
+            strip_selectors: vec![".ad".to_string()],
+            ..Default::default()
+        };
+        app.feed_strip_rules.insert(
+            "https://example.com/feed1".to_string(),
+            crate::display_rules::compile(&rules).0,
         );
-        assert_eq!(
-            App::extract_domain_from_url("http://example.com/feed.xml"),
-            "example.com"
+        app.feed_display_rules
+            .insert("https://example.com/feed1".to_string(), rules);
+        assert_eq!(app.display_html(0, 1).as_deref(), Some("<p>Body</p>"));
+        // Drawn again from the cache, and redone when the item's content changes
+        assert_eq!(app.display_html(0, 1).as_deref(), Some("<p>Body</p>"));
+        app.feeds[0].items[1].description =
+            Some("<p>Edited</p><div class=\"ad\">Ad</div>".to_string());
+        assert_eq!(app.display_html(0, 1).as_deref(), Some("<p>Edited</p>"));
+
+        app.feed_display_rules
+            .get_mut("https://example.com/feed1")
+            .unwrap()
+            .prefer_summary = true;
+        assert_eq!(app.display_html(0, 1).as_deref(), Some("<p>Short</p>"));
+    }
+
+    #[test]
+    fn test_language_filter() {
+        let mut app = make_test_app();
+        app.feeds[0].items[0].language = Some("deu");
+        app.feeds[0].items[1].language = Some("eng");
+        app.feeds[1].items[0].language = Some("eng");
+        app.update_dashboard();
+
+        assert_eq!(app.get_available_languages(), vec!["eng", "deu"]);
+
+        app.filter_options.language = Some("deu");
+        app.apply_filters();
+        assert_eq!(app.filtered_dashboard_items, vec![(0, 0)]);
+        assert_eq!(app.feeds[0].dominant_language(), Some("deu"));
+    }
+
+    #[test]
+    fn test_tags_feed_the_tag_filter() {
+        let mut app = make_test_app();
+        app.item_tags.clear();
+        app.suggested_tags.clear();
+        app.suggested_tags.insert(
+            "https://example.com/new".to_string(),
+            vec!["rust".to_string()],
+        );
+        app.suggested_tags.insert(
+            "https://example.com/another".to_string(),
+            vec!["rust".to_string(), "async".to_string()],
+        );
+        // Items that already have suggestions keep them
+        app.update_suggested_tags();
+        assert_eq!(app.get_available_tags(), vec!["rust", "async"]);
+
+        assert_eq!(app.toggle_tags_confirmed(0, 1), Some(true));
+        let (confirmed, suggested) = app.item_tag_lists(0, 1);
+        assert_eq!(confirmed, ["rust".to_string()]);
+        assert!(suggested.is_empty());
+        assert_eq!(app.toggle_tags_confirmed(0, 0), None);
+
+        app.filter_options.tag = Some("rust".to_string());
+        app.apply_filters();
+        assert_eq!(app.filtered_dashboard_items.len(), 2);
+        app.filter_options.tag = Some("async".to_string());
+        app.apply_filters();
+        assert_eq!(app.filtered_dashboard_items, vec![(1, 0)]);
+
+        // Unconfirming brings the suggestions back
+        assert_eq!(app.toggle_tags_confirmed(0, 1), Some(false));
+        assert_eq!(app.item_tag_lists(0, 1).1, ["rust".to_string()]);
+    }
+
+    #[test]
+    fn test_toggle_translation_requires_command() {
+        let mut app = make_test_app();
+        app.selected_feed = Some(0);
+        app.selected_item = Some(1);
+        app.config.tools.translate_command = None;
+        assert!(app.toggle_translation().is_err());
+        assert!(!app.show_translation);
+
+        app.config.tools.translate_command = Some("cat".to_string());
+        app.toggle_translation().unwrap();
+        assert!(app.show_translation);
+        assert!(app.current_translation().is_some());
+
+        app.exit_detail_view(View::FeedItems);
+        assert!(!app.show_translation);
+    }
+
+    #[test]
+    fn test_detail_source_cycles_and_resets() {
+        let mut app = make_test_app();
+        app.selected_feed = Some(0);
+        app.selected_item = Some(1);
+        app.config.network.http_cache = false;
+        app.detail_vertical_scroll = 5;
+
+        app.cycle_detail_source();
+        assert_eq!(app.detail_source, DetailSource::Html);
+        assert_eq!(app.detail_vertical_scroll, 0);
+        app.cycle_detail_source();
+        assert!(
+            matches!(&app.detail_source, DetailSource::Entry(note) if note.contains("http_cache"))
         );
+        app.cycle_detail_source();
+        assert_eq!(app.detail_source, DetailSource::Rendered);
+
+        app.cycle_detail_source();
+        app.exit_detail_view(View::FeedItems);
+        assert_eq!(app.detail_source, DetailSource::Rendered);
+    }
+
+    #[test]
+    fn test_summarize_caches_per_item() {
+        let mut app = make_test_app();
+        app.selected_feed = Some(0);
+        app.selected_item = Some(0);
+        app.config.tools.summarize_command = None;
+        assert!(app.summarize_current_item().is_err());
+        assert!(!app.show_summary_popup);
+
+        // A cached summary is reused without running the command again
+        let id = app.get_item_id(0, 0);
+        app.article_summaries
+            .lock()
+            .unwrap()
+            .insert(id, ToolOutput::Done("Short version".to_string()));
+        app.config.tools.summarize_command = Some("false".to_string());
+        app.summarize_current_item().unwrap();
+        assert!(app.show_summary_popup);
         assert_eq!(
-            App::extract_domain_from_url("https://www.example.com/"),
-            "example.com"
+            app.current_article_summary(),
+            Some(ToolOutput::Done("Short version".to_string()))
         );
     }
 
-    /// Helper to create a minimal App with test feeds, avoiding filesystem I/O
-    fn make_test_app() -> App {
-        let mut app = App::new();
-        app.feeds = vec![
-            Feed {
-                url: "https://example.com/feed1".to_string(),
-                title: "Feed One".to_string(),
-                title_lower: "feed one".to_string(),
-                items: vec![
-                    FeedItem {
-                        title: "Old Article".to_string(),
-                        title_lower: "old article".to_string(),
-                        link: Some("https://example.com/old".to_string()),
-                        description: Some("Old content".to_string()),
-                        pub_date: None,
-                        author: Some("Author A".to_string()),
-                        formatted_date: None,
-                        parsed_date: Some(Utc::now() - chrono::Duration::days(30)),
-                        plain_text: Some("Old content".to_string()),
-                    },
-                    FeedItem {
-                        title: "New Article".to_string(),
-                        title_lower: "new article".to_string(),
-                        link: Some("https://example.com/new".to_string()),
-                        description: Some("New content".to_string()),
-                        pub_date: None,
-                        author: None,
-                        formatted_date: None,
-                        parsed_date: Some(Utc::now() - chrono::Duration::hours(1)),
-                        plain_text: Some("New content".to_string()),
-                    },
-                ],
-            },
-            Feed {
-                url: "https://example.com/feed2".to_string(),
-                title: "Feed Two".to_string(),
-                title_lower: "feed two".to_string(),
-                items: vec![FeedItem {
-                    title: "Another New".to_string(),
-                    title_lower: "another new".to_string(),
-                    link: Some("https://example.com/another".to_string()),
-                    description: Some("Another new content".to_string()),
-                    pub_date: None,
-                    author: Some("Author B".to_string()),
-                    formatted_date: None,
-                    parsed_date: Some(Utc::now() - chrono::Duration::hours(2)),
-                    plain_text: Some("Another new content".to_string()),
-                }],
-            },
-        ];
+    #[test]
+    fn test_scan_alerts_flags_new_matches_once() {
+        let mut app = make_test_app();
+        app.read_items.clear();
+        app.alert_items.clear();
+        let rule = crate::config::AlertRule {
+            name: Some("New stuff".to_string()),
+            pattern: "new".to_string(),
+            regex: false,
+            feed: Some("https://example.com/feed2".to_string()),
+            category: None,
+            notify: false,
+        };
+        (app.alerts, _) = crate::alerts::compile(&[rule]);
+
+        assert_eq!(app.scan_alerts(), vec![(1, 0)]);
+        assert_eq!(app.item_alert(1, 0), Some("New stuff"));
+        assert_eq!(app.item_alert(0, 1), None); // out of the rule's feed scope
+        assert!(app.scan_alerts().is_empty());
+        assert_eq!(app.get_alert_dashboard_items(), vec![(1, 0)]);
+
+        app.dismiss_alert(1, 0);
+        assert!(app.get_alert_dashboard_items().is_empty());
+        assert!(app.is_item_read(1, 0));
+        assert!(app.scan_alerts().is_empty());
+    }
+
+    #[test]
+    fn test_notify_levels_pick_the_notified_items() {
+        let mut app = make_test_app();
+        app.read_items.clear();
+        app.alert_items.clear();
+        app.muted_feeds.clear();
+        app.archived_feeds.clear();
+        app.dashboard_hidden_feeds.clear();
+        let rule = crate::config::AlertRule {
+            name: None,
+            pattern: "new".to_string(),
+            regex: false,
+            feed: None,
+            category: None,
+            notify: true,
+        };
+        (app.alerts, _) = crate::alerts::compile(&[rule]);
+        let matches = app.scan_alerts();
+        assert_eq!(matches, vec![(0, 1), (1, 0)]);
+
+        // A feed set to never notify stays quiet even about alerts
+        app.config.general.notify = NotifyLevel::Alerts;
+        app.feed_notify
+            .insert("https://example.com/feed2".to_string(), NotifyLevel::Never);
+        assert_eq!(app.items_to_notify(&matches), vec![(0, 1)]);
+
+        // One set to all notifies about everything the refresh brought in
+        app.feed_notify
+            .insert("https://example.com/feed1".to_string(), NotifyLevel::All);
+        assert_eq!(app.items_to_notify(&matches), vec![(0, 1)]);
+        app.refresh_baseline = Some(HashSet::from([app.get_item_id(0, 1)]));
+        assert_eq!(app.items_to_notify(&matches), vec![(0, 1), (0, 0)]);
+        app.read_items.insert(app.get_item_id(0, 0));
+        assert_eq!(app.items_to_notify(&matches), vec![(0, 1)]);
+    }
+
+    #[test]
+    fn test_muted_authors_and_domains_leave_the_dashboard() {
+        let mut app = make_test_app();
+        app.muted_feeds.clear();
+        app.muted_authors.clear();
+        app.muted_domains.clear();
+        app.filter_options.reset();
+        app.feeds[1].items[0].link = Some("https://blog.spam.example/post".to_string());
         app.update_dashboard();
-        app
+        assert_eq!(app.dashboard_items.len(), 3);
+
+        assert!(app.toggle_author_muted(" author a "));
+        assert!(app.is_item_muted(0, 0));
+        assert!(!app.dashboard_items.contains(&(0, 0)));
+
+        // Subdomains of a muted domain are muted too
+        assert!(app.toggle_domain_muted("spam.example"));
+        assert!(app.is_item_muted(1, 0));
+        assert_eq!(app.dashboard_items, vec![(0, 1)]);
+        app.start_triage();
+        assert!(app.triage_queue.iter().all(|&item| item == (0, 1)));
+
+        assert!(!app.toggle_author_muted("Author A"));
+        assert!(!app.is_item_muted(0, 0));
+        assert_eq!(app.dashboard_items.len(), 2);
     }
 
     #[test]
-    fn test_get_new_items_since_session_no_session_time() {
+    fn test_triage_dispositions_advance_and_mute() {
         let mut app = make_test_app();
-        app.last_session_time = None;
-        let items = app.get_new_items_since_session();
-        assert!(items.is_empty(), "No items when no session time is set");
+        app.read_items.clear();
+        app.read_later.clear();
+        app.starred_items.clear();
+        app.muted_feeds.clear();
+        app.start_triage();
+        assert_eq!(app.view, View::Triage);
+        let queued = app.triage_queue.len();
+        assert_eq!(queued, 3);
+
+        let first = app.current_triage_item().unwrap();
+        app.triage(TriageAction::ReadLater).unwrap();
+        assert!(app.is_item_read_later(first.0, first.1));
+        assert!(!app.is_item_read(first.0, first.1));
+        assert_eq!(app.triage_pos, 1);
+
+        let second = app.current_triage_item().unwrap();
+        app.triage(TriageAction::Star).unwrap();
+        assert!(app.is_item_starred(second.0, second.1));
+        assert!(app.is_item_read(second.0, second.1));
+
+        app.triage_back();
+        app.triage(TriageAction::Skip).unwrap();
+        let third = app.current_triage_item().unwrap();
+        app.triage(TriageAction::MuteSource).unwrap();
+        assert!(app.muted_feeds.contains(&app.feeds[third.0].url));
+        assert_eq!(app.current_triage_item(), None);
+        assert!(app
+            .dashboard_items
+            .iter()
+            .all(|&(feed_idx, _)| feed_idx != third.0));
+
+        // Queued and read items stay out of the next triage session
+        app.muted_feeds.clear();
+        app.start_triage();
+        assert_eq!(app.triage_queue, vec![third]);
     }
 
     #[test]
-    fn test_get_new_items_since_session_filters_by_date() {
+    fn test_read_later_reordering() {
         let mut app = make_test_app();
-        // Set session time to 12 hours ago — should pick up items from last few hours
-        app.last_session_time = Some(Utc::now() - chrono::Duration::hours(12));
-        let items = app.get_new_items_since_session();
-        // "New Article" (1h ago) and "Another New" (2h ago) are newer than 12h ago
-        assert_eq!(items.len(), 2);
+        app.read_later = vec![
+            app.get_item_id(0, 0),
+            app.get_item_id(0, 1),
+            "https://gone.example.com/post".to_string(),
+            app.get_item_id(1, 0),
+        ];
+
+        assert_eq!(
+            app.read_later_entries(),
+            vec![Some((0, 0)), Some((0, 1)), None, Some((1, 0))]
+        );
+        assert_eq!(app.read_later_minutes(), 3);
+
+        assert_eq!(app.move_read_later(3, QueueMove::Top), 0);
+        assert_eq!(app.read_later_entries()[0], Some((1, 0)));
+        assert_eq!(app.move_read_later(0, QueueMove::Up), 0);
+        assert_eq!(app.move_read_later(1, QueueMove::Down), 2);
+        assert_eq!(app.move_read_later(0, QueueMove::Bottom), 3);
+        assert_eq!(
+            app.read_later_entries(),
+            vec![Some((0, 1)), Some((0, 0)), None, Some((1, 0))]
+        );
+
+        app.remove_read_later(2);
+        assert_eq!(app.read_later.len(), 3);
     }
 
     #[test]
-    fn test_get_new_items_since_session_skips_no_date() {
+    fn test_refresh_diff_counts_new_items_per_feed() {
         let mut app = make_test_app();
-        app.last_session_time = Some(Utc::now() - chrono::Duration::hours(12));
-        // Remove parsed_date from one item
-        app.feeds[0].items[1].parsed_date = None;
-        let items = app.get_new_items_since_session();
-        // Only "Another New" from Feed Two should match
-        assert_eq!(items.len(), 1);
-        assert_eq!(items[0].2, "Feed Two");
+        app.begin_refresh_diff();
+
+        // Feed two gains two items, feed one none
+        let mut fresh = app.feeds[1].items[0].clone();
+        fresh.link = Some("https://example.com/fresh".to_string());
+        let mut fresher = fresh.clone();
+        fresher.link = Some("https://example.com/fresher".to_string());
+        app.feeds[1].items.insert(0, fresh);
+        app.feeds[1].items.insert(0, fresher);
+        app.finish_refresh_diff();
+
+        let diff = app.refresh_diff.clone().unwrap();
+        assert_eq!(diff.total(), 2);
+        assert_eq!(diff.entries.len(), 1);
+        assert_eq!(diff.entries[0].feed_title, "Feed Two");
+
+        app.jump_to_refresh_diff_entry();
+        assert!(app.refresh_diff.is_none());
+        assert_eq!(app.view, View::FeedItems);
+        assert_eq!((app.selected_feed, app.selected_item), (Some(1), Some(0)));
+
+        // Nothing new: no popup
+        app.begin_refresh_diff();
+        app.finish_refresh_diff();
+        assert!(app.refresh_diff.is_none());
     }
 
     #[test]
-    fn test_get_summary_stats() {
+    fn test_selection_survives_refresh_reload() {
         let mut app = make_test_app();
-        app.last_session_time = Some(Utc::now() - chrono::Duration::hours(12));
-        let (total, feeds) = app.get_summary_stats();
-        assert_eq!(total, 2);
-        // Both feeds have 1 new item each
-        assert_eq!(feeds.len(), 2);
-        // Sorted by count descending (both have 1, so order is stable but either is fine)
-        assert!(feeds.iter().all(|(_, count)| *count == 1));
+        // "Another New" (feed two) is second on the dashboard
+        app.selected_item = Some(1);
+        assert_eq!(app.selected_item_indices(), Some((1, 0)));
+
+        // A refresh clears every feed and they come back in any order
+        app.pin_selection();
+        let feeds = std::mem::take(&mut app.feeds);
+        app.update_dashboard();
+        let mut feeds = feeds.into_iter();
+        let feed1 = feeds.next().unwrap();
+        let mut feed2 = feeds.next().unwrap();
+        let mut newer = feed2.items[0].clone();
+        newer.link = Some("https://example.com/newest".to_string());
+        newer.parsed_date = Some(Utc::now());
+        feed2.items.insert(0, newer);
+        app.insert_feed(0, feed1);
+        app.insert_feed(0, feed2);
+
+        let (feed_idx, item_idx) = app.selected_item_indices().unwrap();
+        assert_eq!(app.feeds[feed_idx].items[item_idx].title, "Another New");
+        assert_eq!(app.selected_item, Some(2));
+
+        // Re-sorting keeps following the item once it's been found
+        app.update_dashboard();
+        assert_eq!(app.selected_item, Some(2));
     }
 
     #[test]
-    fn test_get_summary_stats_sorting() {
+    fn test_back_from_detail_returns_to_origin() {
         let mut app = make_test_app();
-        // Set session time far in the past so all items with dates are "new"
-        app.last_session_time = Some(Utc::now() - chrono::Duration::days(365));
-        let (total, feeds) = app.get_summary_stats();
-        assert_eq!(total, 3);
-        // Feed One has 2 items, Feed Two has 1 — Feed One should be first
-        assert_eq!(feeds[0].0, "Feed One");
-        assert_eq!(feeds[0].1, 2);
-        assert_eq!(feeds[1].0, "Feed Two");
-        assert_eq!(feeds[1].1, 1);
+
+        // From search results
+        app.search_feeds("new");
+        app.view = View::Dashboard;
+        app.selected_item = Some(1);
+        let (feed_idx, item_idx) = app.selected_item_indices().unwrap();
+        app.open_item_detail(feed_idx, item_idx);
+        assert_eq!(app.view, View::FeedItemDetail);
+        app.back_from_detail_view();
+        assert_eq!(app.view, View::Dashboard);
+        assert!(app.is_searching);
+        assert_eq!(app.selected_item, Some(1));
+
+        // From a feed's item list
+        app.is_searching = false;
+        app.view = View::FeedItems;
+        app.selected_feed = Some(0);
+        app.selected_item = Some(1);
+        app.open_item_detail(0, 1);
+        app.back_from_detail_view();
+        assert_eq!(app.view, View::FeedItems);
+        assert_eq!((app.selected_feed, app.selected_item), (Some(0), Some(1)));
     }
 
     #[test]
-    fn test_live_search_clamps_selection() {
+    fn test_replace_feed_keeps_selected_item() {
         let mut app = make_test_app();
-        // Start with selection beyond what search will return
-        app.selected_item = Some(100);
-        app.live_search("new");
-        // Should clamp to last result, not reset to 0
-        assert!(app.selected_item.is_some());
-        let sel = app.selected_item.unwrap();
-        assert!(sel < app.filtered_items.len());
-        assert_eq!(sel, app.filtered_items.len() - 1);
+        app.view = View::FeedItems;
+        app.selected_feed = Some(0);
+        app.selected_item = Some(1); // "New Article"
+
+        let mut refetched = app.feeds[0].clone();
+        let mut newest = refetched.items[1].clone();
+        newest.link = Some("https://example.com/newest".to_string());
+        refetched.items.insert(0, newest);
+        app.replace_feed(0, refetched);
+
+        assert_eq!(app.feeds[0].items.len(), 3);
+        assert_eq!(app.selected_item, Some(2));
+        assert_eq!(app.current_item().unwrap().title, "New Article");
     }
 
     #[test]
-    fn test_live_search_preserves_valid_selection() {
+    fn test_dashboard_sections_have_independent_lists() {
+        use crate::config::DashboardSection;
+        let section = |kind| DashboardSection {
+            kind,
+            title: None,
+            category: None,
+            limit: 10,
+            days: 7,
+        };
         let mut app = make_test_app();
+        app.config.dashboard_sections = vec![
+            section(SectionKind::Unread),
+            section(SectionKind::Starred),
+            section(SectionKind::Stale),
+        ];
+        app.feeds[1].items[0].parsed_date = Some(Utc::now() - chrono::Duration::days(10));
+        app.mark_item_as_read(0, 1).unwrap();
+        app.toggle_item_starred(1, 0).unwrap();
+        app.update_dashboard();
+
+        assert_eq!(app.dashboard_sections.len(), 3);
+        assert_eq!(app.dashboard_sections[0].items, vec![(1, 0), (0, 0)]);
+        assert_eq!(app.dashboard_sections[1].items, vec![(1, 0)]);
+        // Feed two's newest item is ten days old; feed one posted an hour ago
+        assert_eq!(app.dashboard_sections[2].items, vec![(1, 0)]);
+        assert_eq!(app.dashboard_sections[2].title, "Quiet for 7+ days");
+
+        // The cursor works on the focused section
         app.selected_item = Some(0);
-        app.live_search("new");
-        // Selection 0 is valid, should stay
-        assert_eq!(app.selected_item, Some(0));
+        assert_eq!(app.selected_item_indices(), Some((1, 0)));
+        app.cycle_dashboard_section(true);
+        assert_eq!(app.focused_section, 1);
+        assert_eq!(app.active_dashboard_items(), &[(1, 0)]);
+        app.cycle_dashboard_section(false);
+        app.cycle_dashboard_section(false);
+        assert_eq!(app.focused_section, 2);
+        assert_eq!(app.selected_item_indices(), Some((1, 0)));
     }
 
     #[test]
-    fn test_live_search_sets_selection_when_none() {
+    fn test_feed_title_override_survives_refetch() {
         let mut app = make_test_app();
-        app.selected_item = None;
-        app.live_search("new");
-        // Should set to 0 when there are results
-        assert_eq!(app.selected_item, Some(0));
+        app.feed_titles
+            .insert(app.feeds[0].url.clone(), "My Feed".to_string());
+
+        let refetched = app.feeds[0].clone();
+        app.replace_feed(0, refetched);
+        assert_eq!(app.feeds[0].title, "My Feed");
+        assert_eq!(app.feeds[0].title_lower, "my feed");
+
+        let mut other = app.feeds[1].clone();
+        other.url = "https://example.com/feed3".to_string();
+        app.insert_feed(2, other);
+        assert_eq!(app.feeds[2].title, "Feed Two");
     }
 
     #[test]
-    fn test_live_search_no_results() {
+    fn test_import_partition_flags_duplicates() {
         let mut app = make_test_app();
-        app.selected_item = Some(1);
-        app.live_search("zzzznonexistent");
-        // No results, selection should remain as-is (count == 0, match arm falls through)
+        app.bookmarks = vec!["https://example.com/feed1".to_string()];
+        let urls = [
+            "https://example.com/feed1",
+            "https://example.com/new",
+            "https://example.com/new",
+            "https://example.com/other",
+        ]
+        .map(String::from)
+        .to_vec();
+
+        let (to_check, duplicates) = app.partition_import_urls(urls);
+        assert_eq!(
+            to_check,
+            vec!["https://example.com/new", "https://example.com/other"]
+        );
+        assert_eq!(
+            duplicates,
+            vec!["https://example.com/feed1", "https://example.com/new"]
+        );
+    }
+
+    #[test]
+    fn test_explain_item_lists_position_and_filters() {
+        let mut app = make_test_app();
+        app.filter_options.read_status = Some(false);
+        app.apply_filters();
+        let (feed_idx, item_idx) = app.filtered_dashboard_items[0];
+
+        let lines = app.explain_item(feed_idx, item_idx);
+        assert!(lines[0].starts_with("Dashboard (#1 of "), "{:?}", lines);
+        assert!(lines.contains(&"✓ Status: unread".to_string()));
+        assert_eq!(lines.last().unwrap(), "Unread, not starred");
+    }
+
+    #[test]
+    fn test_filters_and_sort_are_remembered_per_scope() {
+        let mut app = make_test_app();
+        app.view = View::Dashboard;
+        app.filter_options.read_status = Some(false);
+        app.apply_filters();
+        app.sync_filter_scope();
+
+        // A feed starts from its own state, not the dashboard's
+        app.view = View::FeedItems;
+        app.selected_feed = Some(0);
+        app.sync_filter_scope();
+        assert!(!app.filter_options.is_active());
+        assert_eq!(app.feed_item_order(0), vec![0, 1]);
+        assert_eq!(app.selected_item, Some(0));
+
+        app.toggle_item_sort();
+        assert_eq!(app.feed_item_order(0), vec![1, 0]);
+
+        app.view = View::Dashboard;
+        app.sync_filter_scope();
+        assert_eq!(app.filter_options.read_status, Some(false));
+        assert_eq!(app.item_sort, ItemSort::NewestFirst);
+
+        app.view = View::FeedItems;
+        app.sync_filter_scope();
+        assert_eq!(app.item_sort, ItemSort::OldestFirst);
         assert_eq!(app.selected_item, Some(1));
+        assert!(!app.filter_options.is_active());
     }
 
     #[test]
-    fn test_toggle_preview_pane() {
+    fn test_feed_preview_cycles_categories_and_cancels() {
         let mut app = make_test_app();
-        assert!(!app.preview_pane);
-        app.preview_scroll = 5;
-        app.preview_max_scroll = 10;
+        app.categories = vec![FeedCategory::new("News"), FeedCategory::new("Tech")];
+        let feed = app.feeds[1].clone();
+        app.open_feed_preview("https://example.com/feed3", feed);
+        assert_eq!(app.input_mode, InputMode::PreviewFeed);
+
+        let category = |app: &App| app.feed_preview.as_ref().unwrap().category;
+        assert_eq!(category(&app), None);
+        app.cycle_preview_category();
+        assert_eq!(category(&app), Some(0));
+        app.cycle_preview_category();
+        assert_eq!(category(&app), Some(1));
+        app.cycle_preview_category();
+        assert_eq!(category(&app), None);
+
+        // Cancelling subscribes to nothing
+        app.cancel_feed_preview();
+        assert!(app.feed_preview.is_none());
+        assert_eq!(app.input_mode, InputMode::Normal);
+        assert!(!app
+            .bookmarks
+            .contains(&"https://example.com/feed3".to_string()));
+    }
 
-        app.toggle_preview_pane();
-        assert!(app.preview_pane);
-        assert_eq!(app.preview_scroll, 0);
-        assert_eq!(app.preview_max_scroll, 0);
+    #[test]
+    fn test_parse_url_list_skips_blanks_and_comments() {
+        let text = "https://a.example/feed\n\n  # my feeds\nhttps://b.example/rss https://c.example/atom\n";
+        assert_eq!(
+            App::parse_url_list(text),
+            vec![
+                "https://a.example/feed",
+                "https://b.example/rss",
+                "https://c.example/atom"
+            ]
+        );
+        assert_eq!(
+            App::parse_url_list("crates:serde pypi:requests"),
+            vec![
+                "https://crates.io/api/v1/crates/serde",
+                "https://pypi.org/pypi/requests/json"
+            ]
+        );
+    }
 
-        app.toggle_preview_pane();
-        assert!(!app.preview_pane);
+    #[test]
+    fn test_archived_feed_keeps_items_but_leaves_dashboard() {
+        let mut app = make_test_app();
+        app.bookmarks = vec![
+            "https://example.com/feed1".to_string(),
+            "https://example.com/feed2".to_string(),
+        ];
+        app.update_dashboard();
+        app.rebuild_feed_tree();
+
+        app.archive_feed(0).unwrap();
+        assert_eq!(app.bookmarks, vec!["https://example.com/feed2".to_string()]);
+        assert_eq!(app.feeds.len(), 2);
+        assert_eq!(app.feeds[1].url, "https://example.com/feed1");
+        assert_eq!(app.feeds[1].items.len(), 2);
+        assert!(app
+            .dashboard_items
+            .iter()
+            .all(|(feed_idx, _)| *feed_idx == 0));
+        assert!(matches!(app.feed_tree[0], TreeItem::Feed(0, None)));
+        assert!(matches!(app.feed_tree[1], TreeItem::Archive));
+        assert!(matches!(app.feed_tree[2], TreeItem::Feed(1, None)));
+
+        app.archive_expanded = false;
+        app.rebuild_feed_tree();
+        assert_eq!(app.feed_tree.len(), 2);
+
+        app.unarchive_feed(1).unwrap();
+        assert!(app.archived_feeds.is_empty());
+        assert!(app
+            .bookmarks
+            .contains(&"https://example.com/feed1".to_string()));
+        assert_eq!(
+            app.refresh_feed_requested.as_deref(),
+            Some("https://example.com/feed1")
+        );
+        assert!(!app
+            .feed_tree
+            .iter()
+            .any(|item| matches!(item, TreeItem::Archive)));
     }
 
     #[test]
-    fn test_reset_preview_scroll() {
+    fn test_hide_from_dashboard_keeps_feed_in_list() {
         let mut app = make_test_app();
-        app.preview_scroll = 10;
-        app.preview_max_scroll = 20;
+        app.dashboard_hidden_feeds
+            .insert("https://example.com/feed2".to_string());
+        app.update_dashboard();
+        app.rebuild_feed_tree();
 
-        app.reset_preview_scroll();
-        assert_eq!(app.preview_scroll, 0);
-        assert_eq!(app.preview_max_scroll, 0);
+        assert!(app
+            .dashboard_items
+            .iter()
+            .all(|(feed_idx, _)| *feed_idx == 0));
+        assert!(app
+            .feed_tree
+            .iter()
+            .any(|item| matches!(item, TreeItem::Feed(1, None))));
     }
 
     #[test]
-    fn test_update_preview_max_scroll() {
+    fn test_feed_failures_are_tracked_per_refresh() {
         let mut app = make_test_app();
-        app.update_preview_max_scroll(50, 20);
-        assert_eq!(app.preview_max_scroll, 30);
+        let error = anyhow::anyhow!("HTTP 500");
+        app.record_feed_failure("https://example.com/a", &error);
+        app.record_feed_failure("https://example.com/b", &error);
+        app.record_feed_failure("https://example.com/a", &anyhow::anyhow!("timed out"));
+        assert_eq!(app.failed_feeds.len(), 2);
+        assert_eq!(app.failed_feeds[1].1, "timed out");
+        assert!(app.failed_feeds_banner_visible());
+
+        app.failed_feeds_dismissed = true;
+        assert!(!app.failed_feeds_banner_visible());
+
+        // Retrying one feed forgets only its failure; a new failure brings the banner back
+        app.reset_feed_failures(Some(&["https://example.com/a".to_string()]));
+        assert_eq!(
+            app.failed_feed_urls(),
+            vec!["https://example.com/b".to_string()]
+        );
+        app.record_feed_failure("https://example.com/a", &error);
+        assert!(app.failed_feeds_banner_visible());
 
-        // Content fits in viewport
-        app.update_preview_max_scroll(10, 20);
-        assert_eq!(app.preview_max_scroll, 0);
+        app.reset_feed_failures(None);
+        assert!(!app.failed_feeds_banner_visible());
     }
 
     #[test]
-    fn test_clamp_preview_scroll() {
+    fn test_request_options_apply_per_feed_overrides() {
         let mut app = make_test_app();
-        app.preview_max_scroll = 10;
-        app.preview_scroll = 15;
-        app.clamp_preview_scroll();
-        assert_eq!(app.preview_scroll, 10);
-
-        // Already valid
-        app.preview_scroll = 5;
-        app.clamp_preview_scroll();
-        assert_eq!(app.preview_scroll, 5);
+        let feed: crate::config::DefaultFeed = toml::from_str(
+            "url = \"https://slow.example.com/feed\"\ntimeout = 60\nuser_agent = \"Custom/1.0\"",
+        )
+        .unwrap();
+        app.config.default_feeds = vec![feed];
+        app.config.network.accept_language = "de-DE".to_string();
+
+        let slow = app.request_options("https://slow.example.com/feed");
+        assert_eq!(slow.user_agent.as_deref(), Some("Custom/1.0"));
+        assert_eq!(slow.accept_language.as_deref(), Some("de-DE"));
+        assert_eq!(slow.timeout, Some(60));
+        assert_eq!(slow.connect_timeout, None);
+
+        let other = app.request_options("https://example.com/feed1");
+        assert_eq!(
+            other.user_agent.as_deref(),
+            Some(app.config.network.user_agent.as_str())
+        );
+        assert_eq!(other.timeout, None);
     }
 
     #[test]
-    fn test_should_auto_refresh() {
-        let mut app = App::new();
-
-        // Should not refresh when disabled
-        app.config.general.refresh_enabled = false;
-        app.config.general.auto_refresh_interval = 300;
-        assert!(!app.should_auto_refresh());
-
-        // Should not refresh when interval is 0
-        app.config.general.refresh_enabled = true;
-        app.config.general.auto_refresh_interval = 0;
-        assert!(!app.should_auto_refresh());
-
-        // Should refresh when enabled and never refreshed before
-        app.config.general.refresh_enabled = true;
-        app.config.general.auto_refresh_interval = 300;
-        app.last_refresh = None;
-        assert!(app.should_auto_refresh());
-
-        // Should not refresh when in progress
-        app.refresh_in_progress = true;
-        assert!(!app.should_auto_refresh());
+    fn test_export_opml_groups_feeds_by_category() {
+        let mut app = make_test_app();
+        app.bookmarks = vec![
+            "https://example.com/feed1".to_string(),
+            "https://example.com/feed2".to_string(),
+        ];
+        app.feed_titles.clear();
+        let mut category = FeedCategory::new("Tech");
+        category
+            .feeds
+            .insert("https://example.com/feed2".to_string());
+        app.categories = vec![category];
+
+        let exported = app.export_opml().unwrap();
+        let document = opml::OPML::from_str(&exported).unwrap();
+        let outlines = &document.body.outlines;
+        assert_eq!(outlines.len(), 2);
+        assert_eq!(outlines[0].text, "Tech");
+        assert_eq!(
+            outlines[0].outlines[0].xml_url.as_deref(),
+            Some("https://example.com/feed2")
+        );
+        assert_eq!(outlines[0].outlines[0].text, "Feed Two");
+        assert_eq!(outlines[1].text, "Feed One");
+        assert_eq!(
+            outlines[1].xml_url.as_deref(),
+            Some("https://example.com/feed1")
+        );
     }
 
     #[test]
-    fn test_should_auto_refresh_per_feed_interval() {
-        let mut app = App::new();
-        // Disable global refresh
-        app.config.general.refresh_enabled = false;
-        app.config.general.auto_refresh_interval = 0;
+    fn test_export_opml_file_and_remember_path() {
+        let mut app = make_test_app();
+        app.bookmarks = vec!["https://example.com/feed1".to_string()];
+        app.recent_paths = vec!["old.opml".to_string()];
+        let path = std::env::temp_dir().join(format!("feedr-export-{}.opml", uuid::Uuid::new_v4()));
+
+        assert_eq!(app.export_opml_file(&path).unwrap(), 1);
+        let written = fs::read_to_string(&path).unwrap();
+        let _ = fs::remove_file(&path);
+        assert!(written.contains("https://example.com/feed1"));
+
+        app.remember_path("~/feeds.opml");
+        app.remember_path("old.opml");
+        assert_eq!(app.recent_paths, ["old.opml", "~/feeds.opml"]);
+        assert!(app.has_unsaved_changes());
+    }
 
-        // Add a per-feed interval
-        app.feed_refresh_intervals
-            .insert("https://example.com/feed".to_string(), 60);
+    #[test]
+    fn test_export_current_item_writes_html_then_pdf() {
+        let mut app = make_test_app();
+        let dir = std::env::temp_dir().join(format!("feedr-export-{}", uuid::Uuid::new_v4()));
+        app.config.tools.export_dir = Some(dir.to_string_lossy().into_owned());
+        app.config.tools.pdf_command = None;
+        app.selected_feed = Some(0);
+        app.selected_item = Some(1);
 
-        // Never refreshed — should trigger
-        assert!(app.should_auto_refresh());
+        let html_path = app.export_current_item().unwrap();
+        assert_eq!(html_path, dir.join("new-article.html"));
+        let html = fs::read_to_string(&html_path).unwrap();
+        assert!(html.contains("<h1>New Article</h1>"));
 
-        // Mark as recently refreshed globally
-        app.last_refresh = Some(std::time::Instant::now());
-        assert!(!app.should_auto_refresh());
+        #[cfg(all(unix, feature = "scripting"))]
+        {
+            app.config.tools.pdf_command = Some("cp {input} {output}".to_string());
+            let pdf_path = app.export_current_item().unwrap();
+            assert_eq!(pdf_path, dir.join("new-article.pdf"));
+            assert_eq!(fs::read_to_string(&pdf_path).unwrap(), html);
+        }
+        let _ = fs::remove_dir_all(&dir);
     }
 
+    #[cfg(all(unix, feature = "scripting"))]
     #[test]
-    fn test_rebuild_feed_tree_uncategorized_only() {
+    fn test_make_task_runs_command_with_quoted_placeholders() {
         let mut app = make_test_app();
-        app.categories.clear();
-        app.rebuild_feed_tree();
-
-        // Should have exactly 2 uncategorized feed entries
-        assert_eq!(app.feed_tree.len(), 2);
-        assert!(matches!(app.feed_tree[0], TreeItem::Feed(0, None)));
-        assert!(matches!(app.feed_tree[1], TreeItem::Feed(1, None)));
+        app.view = View::FeedItems;
+        app.selected_feed = Some(0);
+        app.selected_item = Some(0);
+        app.config.tools.task_command = None;
+        assert!(app.make_task_from_selected_item().is_err());
+
+        let out = std::env::temp_dir().join(format!("feedr-task-{}", uuid::Uuid::new_v4()));
+        app.config.tools.task_command = Some(format!(
+            "printf '%s|%s' {{title}} {{url}} > {}",
+            crate::share::shell_quote(&out.to_string_lossy())
+        ));
+        app.make_task_from_selected_item().unwrap();
+        assert_eq!(
+            fs::read_to_string(&out).unwrap(),
+            "Old Article|https://example.com/old"
+        );
+        let _ = fs::remove_file(&out);
     }
 
     #[test]
-    fn test_rebuild_feed_tree_with_category() {
+    fn test_export_selected_event_writes_ics() {
         let mut app = make_test_app();
-        app.categories.clear();
-        let mut cat = FeedCategory::new("Tech");
-        cat.add_feed("https://example.com/feed1");
-        app.categories.push(cat);
-        app.rebuild_feed_tree();
-
-        // Category node + expanded feed + 1 uncategorized feed = 3
-        assert_eq!(app.feed_tree.len(), 3);
-        assert!(matches!(app.feed_tree[0], TreeItem::Category(0)));
-        assert!(matches!(app.feed_tree[1], TreeItem::Feed(0, Some(0))));
-        assert!(matches!(app.feed_tree[2], TreeItem::Feed(1, None)));
+        let dir = std::env::temp_dir().join(format!("feedr-event-{}", uuid::Uuid::new_v4()));
+        app.config.tools.export_dir = Some(dir.to_string_lossy().into_owned());
+        app.config.tools.calendar_command = None;
+        app.view = View::FeedItems;
+        app.selected_feed = Some(0);
+        app.selected_item = Some(1);
+        assert!(app.export_selected_event().is_err());
+
+        app.feeds[0].items[1].title = "Meetup on 2030-06-01 at 7pm".to_string();
+        let path = app.export_selected_event().unwrap();
+        let ics = fs::read_to_string(&path).unwrap();
+        assert!(ics.contains("DTSTART:20300601T190000\r\n"));
+        assert!(ics.contains("SUMMARY:Meetup on 2030-06-01 at 7pm\r\n"));
+        let _ = fs::remove_dir_all(&dir);
     }
 
+    #[cfg(all(unix, feature = "scripting"))]
     #[test]
-    fn test_rebuild_feed_tree_collapsed_category() {
+    fn test_status_strip_keeps_first_output_line() {
         let mut app = make_test_app();
-        app.categories.clear();
-        let mut cat = FeedCategory::new("Tech");
-        cat.add_feed("https://example.com/feed1");
-        cat.expanded = false;
-        app.categories.push(cat);
-        app.rebuild_feed_tree();
-
-        // Category node + 1 uncategorized (feed1 is hidden, feed2 uncategorized)
-        assert_eq!(app.feed_tree.len(), 2);
-        assert!(matches!(app.feed_tree[0], TreeItem::Category(0)));
-        assert!(matches!(app.feed_tree[1], TreeItem::Feed(1, None)));
+        app.config.ui.status_command = None;
+        app.update_status_strip();
+        assert_eq!(app.status_strip_text(), None);
+
+        app.config.ui.status_command = Some("echo '  12:34 '; echo second".to_string());
+        app.update_status_strip();
+        let started = Instant::now();
+        while app.status_strip_text().is_none() && started.elapsed() < Duration::from_secs(5) {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(app.status_strip_text().as_deref(), Some("12:34"));
     }
 
     #[test]
-    fn test_rebuild_feed_tree_clamps_selection() {
+    fn test_reading_time_counts_detail_view_ticks() {
         let mut app = make_test_app();
-        app.categories.clear();
-        app.rebuild_feed_tree();
-        // Set selection beyond bounds
-        app.selected_tree_item = Some(100);
-        app.rebuild_feed_tree();
-        assert_eq!(app.selected_tree_item, Some(app.feed_tree.len() - 1));
+        app.reading_time.clear();
+        app.view = View::FeedItemDetail;
+        app.track_reading_time();
+        assert_eq!(app.reading_time_today(), Duration::ZERO);
+
+        app.reading_tick = Some(Instant::now() - Duration::from_secs(2));
+        app.track_reading_time();
+        let read = app.reading_time_today();
+        assert!(read >= Duration::from_secs(2) && read < Duration::from_secs(3));
+
+        // A long gap, like a suspended laptop, isn't reading
+        app.reading_tick = Some(Instant::now() - Duration::from_secs(600));
+        app.track_reading_time();
+        assert!(app.reading_time_today() < Duration::from_secs(3));
+
+        app.view = View::Dashboard;
+        app.track_reading_time();
+        assert!(app.reading_tick.is_none());
     }
 
     #[test]
-    fn test_rebuild_feed_tree_empty() {
-        let mut app = App::new();
-        app.feeds.clear();
-        app.categories.clear();
-        app.selected_tree_item = Some(5);
-        app.rebuild_feed_tree();
-        assert!(app.feed_tree.is_empty());
-        assert_eq!(app.selected_tree_item, None);
+    fn test_auto_theme_switches_once_per_change() {
+        use crate::config::{AutoTheme, Theme};
+        let mut app = make_test_app();
+        app.config.ui.theme = Theme::Light;
+        app.config.ui.auto_theme = AutoTheme::Schedule;
+        // An empty light stretch: always dark
+        app.config.ui.light_theme_at = "00:00".to_string();
+        app.config.ui.dark_theme_at = "00:00".to_string();
+        app.apply_auto_theme();
+        assert_eq!(app.config.ui.theme, Theme::Dark);
+
+        // A manual switch sticks until the schedule picks something new
+        app.config.ui.theme = Theme::Light;
+        app.apply_auto_theme();
+        assert_eq!(app.config.ui.theme, Theme::Light);
     }
 
+    #[cfg(feature = "sync")]
     #[test]
-    fn test_mark_all_dashboard_read() {
+    fn test_apply_collection_adds_and_drops_feeds() {
         let mut app = make_test_app();
-        app.read_items.clear();
-        // All 3 items should be unread initially
-        assert!(!app.is_item_read(0, 0));
-        assert!(!app.is_item_read(0, 1));
-        assert!(!app.is_item_read(1, 0));
+        app.bookmarks = vec![
+            "https://example.com/feed1".to_string(),
+            "https://example.com/feed2".to_string(),
+        ];
+        app.categories.clear();
+        app.scheduled_refresh = None;
+        let list = "https://example.com/list.opml";
+        app.config.collections = vec![crate::config::Collection {
+            url: list.to_string(),
+            category: "Curated".to_string(),
+            interval_hours: 24,
+        }];
+
+        let feeds = vec![
+            "https://example.com/feed2".to_string(),
+            "https://example.com/feed3".to_string(),
+        ];
+        assert_eq!(app.apply_collection(list, &feeds).unwrap(), (1, 0));
+        assert_eq!(app.categories[0].name, "Curated");
+        assert_eq!(app.categories[0].feeds.len(), 2);
+        assert!(app
+            .bookmarks
+            .contains(&"https://example.com/feed3".to_string()));
+        assert_eq!(
+            app.scheduled_refresh,
+            Some(vec!["https://example.com/feed3".to_string()])
+        );
 
-        let count = app.mark_all_dashboard_read().unwrap();
-        assert_eq!(count, 3);
+        // feed2 left the list: it's unsubscribed; feed3 stays but also sits elsewhere
+        app.categories.push(FeedCategory::new("Mine"));
+        app.categories[1].add_feed("https://example.com/feed3");
+        let feeds = vec!["https://example.com/feed1".to_string()];
+        assert_eq!(app.apply_collection(list, &feeds).unwrap(), (0, 2));
+        assert!(!app
+            .bookmarks
+            .contains(&"https://example.com/feed2".to_string()));
+        assert!(app
+            .feeds
+            .iter()
+            .all(|f| f.url != "https://example.com/feed2"));
+        assert!(app
+            .bookmarks
+            .contains(&"https://example.com/feed3".to_string()));
+        assert_eq!(app.categories[0].feeds.len(), 1);
 
-        assert!(app.is_item_read(0, 0));
-        assert!(app.is_item_read(0, 1));
-        assert!(app.is_item_read(1, 0));
+        // An empty list is refused rather than emptying the category
+        assert!(app.apply_collection(list, &[]).is_err());
+        assert_eq!(app.categories[0].feeds.len(), 1);
+    }
 
-        // Calling again should mark 0 new items
-        let count = app.mark_all_dashboard_read().unwrap();
-        assert_eq!(count, 0);
+    #[test]
+    fn test_feed_title_change_asks_before_relabeling() {
+        let mut app = make_test_app();
+        app.feed_titles.clear();
+        app.feed_meta.clear();
+        app.feed_changes.clear();
+        let fresh = app.feeds[0].clone();
+        // First sighting is just remembered
+        app.replace_feed(0, fresh.clone());
+        assert!(app.feed_changes.is_empty());
+
+        let mut renamed = fresh.clone();
+        renamed.title = "Feed One, Renamed".to_string();
+        renamed.link = Some("https://example.com/".to_string());
+        app.replace_feed(0, renamed.clone());
+        assert_eq!(app.feeds[0].title, "Feed One");
+        let change = app.pending_feed_change().unwrap();
+        assert_eq!(change.old.title, "Feed One");
+        assert_eq!(change.new.link.as_deref(), Some("https://example.com/"));
+
+        // Refreshing again doesn't repeat the notice
+        app.replace_feed(0, renamed);
+        assert_eq!(app.feed_changes.len(), 1);
+
+        app.resolve_feed_change(true);
+        assert!(app.feed_changes.is_empty());
+        assert_eq!(app.feeds[0].title, "Feed One, Renamed");
+        assert!(!app.feed_titles.contains_key("https://example.com/feed1"));
     }
 
+    #[cfg(feature = "sync")]
     #[test]
-    fn test_mark_all_feed_read() {
+    fn test_read_and_star_changes_get_sync_versions() {
         let mut app = make_test_app();
-        app.read_items.clear();
-        let count = app.mark_all_feed_read(0).unwrap();
-        assert_eq!(count, 2); // Feed 0 has 2 items
+        app.state_log = StateLog::default();
+        let id = app.get_item_id(0, 0);
+        app.toggle_item_read(0, 0).unwrap();
+        let token = app.state_log.token();
+        app.toggle_item_starred(0, 0).unwrap();
 
-        assert!(app.is_item_read(0, 0));
-        assert!(app.is_item_read(0, 1));
-        assert!(!app.is_item_read(1, 0)); // Feed 1 untouched
+        let changes = app.state_log.changes_since(token);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].0, id);
+        assert!(changes[0].1.read && changes[0].1.starred);
+
+        // A remote change older than the local one is ignored, a newer one applied
+        assert!(!app.apply_remote_state(&id, false, false, 0));
+        assert!(app.is_item_starred(0, 0));
+        assert!(app.apply_remote_state(&id, false, false, i64::MAX));
+        assert!(!app.is_item_starred(0, 0));
+        assert!(!app.read_items.contains(&id));
     }
 
+    #[cfg(feature = "sync")]
     #[test]
-    fn test_mark_all_feed_read_invalid_index() {
+    fn test_saved_state_log_drops_entries_for_missing_items() {
         let mut app = make_test_app();
-        let count = app.mark_all_feed_read(999).unwrap();
-        assert_eq!(count, 0);
+        app.state_log = StateLog::default();
+        app.toggle_item_read(0, 0).unwrap();
+        app.apply_remote_state("https://gone.example/1", false, false, 100);
+        app.apply_remote_state("https://gone.example/2", true, false, 100);
+
+        let log = app.compacted_state_log();
+        assert!(log.get(&app.get_item_id(0, 0)).is_some());
+        assert!(log.get("https://gone.example/1").is_none());
+        // Still read, so still known
+        assert!(log.get("https://gone.example/2").is_some());
     }
 
     #[test]
-    fn test_mark_all_starred_read() {
+    fn test_saved_items_show_prefetched_page() {
         let mut app = make_test_app();
-        app.read_items.clear();
+        app.config.general.prefetch_saved = true;
+        app.feed_display_rules.clear();
+        app.read_later.clear();
         app.starred_items.clear();
-        // Star two items
-        app.toggle_item_starred(0, 0).unwrap();
-        app.toggle_item_starred(1, 0).unwrap();
-
-        let count = app.mark_all_starred_read().unwrap();
-        assert_eq!(count, 2);
-        assert!(app.is_item_read(0, 0));
-        assert!(app.is_item_read(1, 0));
-        assert!(!app.is_item_read(0, 1)); // Unstarred item untouched
+        let link = app.feeds[0].items[0].link.clone().unwrap();
+        app.full_text_cache
+            .lock()
+            .unwrap()
+            .insert(link.clone(), Some("<p>Saved page</p>".to_string()));
+
+        assert_ne!(app.display_html(0, 0).as_deref(), Some("<p>Saved page</p>"));
+        app.starred_items.insert(link);
+        assert_eq!(app.display_html(0, 0).as_deref(), Some("<p>Saved page</p>"));
+
+        app.config.general.prefetch_saved = false;
+        assert_ne!(app.display_html(0, 0).as_deref(), Some("<p>Saved page</p>"));
     }
 
     #[test]
-    fn test_extract_links_from_html() {
+    fn test_open_target_per_feed_overrides_global() {
         let mut app = make_test_app();
-        app.selected_feed = Some(0);
-        app.selected_item = Some(0);
-
-        // Set HTML content with links and images
-        app.feeds[0].items[0].description = Some(
-            r#"<p>Check out <a href="https://example.com/page">this page</a>
-            and <a href="https://example.com/other">another link</a></p>
-            <img src="https://example.com/image.png" alt="test image">"#
-                .to_string(),
+        app.feed_display_rules.clear();
+        app.starred_items.clear();
+        app.read_later.clear();
+        app.config.general.open_target = OpenTarget::FullText;
+        let url = app.feeds[0].url.clone();
+        app.feed_open_targets
+            .insert(url.clone(), OpenTarget::Reader);
+        assert_eq!(app.open_target(0), OpenTarget::Reader);
+        assert_eq!(app.open_target(1), OpenTarget::FullText);
+
+        let link = app.feeds[0].items[0].link.clone().unwrap();
+        app.full_text_cache
+            .lock()
+            .unwrap()
+            .insert(link, Some("<p>Linked page</p>".to_string()));
+        assert_ne!(
+            app.display_html(0, 0).as_deref(),
+            Some("<p>Linked page</p>")
+        );
+        app.feed_open_targets
+            .insert(url.clone(), OpenTarget::FullText);
+        assert_eq!(
+            app.display_html(0, 0).as_deref(),
+            Some("<p>Linked page</p>")
         );
 
-        app.extract_links_from_current_item();
+        // With Enter going to the browser, `o` opens the reader instead
+        app.feed_open_targets.insert(url, OpenTarget::Browser);
+        app.open_item_alternate(0, 0).unwrap();
+        assert_eq!(app.view, View::FeedItemDetail);
+        assert_eq!(app.selected_item, Some(0));
+    }
 
-        assert!(app.show_link_overlay);
-        assert_eq!(app.extracted_links.len(), 3);
-        assert_eq!(app.extracted_links[0].url, "https://example.com/page");
-        assert_eq!(app.extracted_links[0].text, "this page");
-        assert!(matches!(app.extracted_links[0].link_type, LinkType::Link));
-        assert_eq!(app.extracted_links[2].url, "https://example.com/image.png");
-        assert!(matches!(app.extracted_links[2].link_type, LinkType::Image));
+    #[test]
+    fn test_comic_feeds_show_image_with_hover_text() {
+        let mut app = make_test_app();
+        app.feed_display_rules.clear();
+        assert!(!app.is_comic(0));
+        app.feed_display_rules.insert(
+            "https://example.com/feed1".to_string(),
+            DisplayRules {
+                comic: true,
+                ..Default::default()
+            },
+        );
+        assert!(app.is_comic(0));
+        assert!(!app.is_comic(1));
+
+        let html = r#"<img src="/comics/1.png" title="The punchline" alt="Two stick figures">"#;
+        let panel = app.comic_panel(0, 0, Some(html)).unwrap();
+        assert_eq!(panel.url, "https://example.com/comics/1.png");
+        assert_eq!(panel.caption(), Some("The punchline"));
+
+        app.feeds[0].items[0].image = Some("https://example.com/cover.jpg".to_string());
+        let panel = app.comic_panel(0, 0, Some("<p>No image</p>")).unwrap();
+        assert_eq!(panel.url, "https://example.com/cover.jpg");
+        assert_eq!(panel.caption(), None);
     }
 
     #[test]
-    fn test_extract_links_deduplicates() {
+    fn test_release_timeline_view() {
         let mut app = make_test_app();
+        app.view = View::FeedItems;
         app.selected_feed = Some(0);
         app.selected_item = Some(0);
+        assert!(app.open_release_timeline().is_err());
+        assert_eq!(app.view, View::FeedItems);
+
+        app.feeds[0].items[0].title = "v1.2.0-rc.1".to_string();
+        app.feeds[0].items[1].title = "v1.2.0".to_string();
+        app.feeds[0].items.push(FeedItem {
+            title: "v1.1.0".to_string(),
+            link: Some("https://example.com/v1.1.0".to_string()),
+            ..app.feeds[0].items[1].clone()
+        });
+        app.open_release_timeline().unwrap();
+        assert_eq!(app.view, View::Releases);
+        let versions: Vec<String> = app
+            .release_timeline()
+            .into_iter()
+            .map(|release| release.version.text)
+            .collect();
+        assert_eq!(versions, vec!["v1.2.0", "v1.2.0-rc.1", "v1.1.0"]);
+        // The cursor points at the item announcing the selected release
+        app.selected_item = Some(1);
+        assert_eq!(app.selected_item_indices(), Some((0, 0)));
+    }
 
-        app.feeds[0].items[0].description = Some(
-            r#"<a href="https://example.com">link1</a>
-            <a href="https://example.com">link2</a>"#
-                .to_string(),
+    #[test]
+    fn test_pick_weighted() {
+        assert_eq!(pick_weighted(&[], 7), None);
+        assert_eq!(pick_weighted(&[0, 0], 7), None);
+        let picks: Vec<Option<usize>> =
+            (0..6).map(|roll| pick_weighted(&[1, 0, 5], roll)).collect();
+        assert_eq!(
+            picks,
+            vec![Some(0), Some(2), Some(2), Some(2), Some(2), Some(2)]
         );
-
-        app.extract_links_from_current_item();
-
-        assert_eq!(app.extracted_links.len(), 1);
     }
 
     #[test]
-    fn test_extract_links_no_content() {
+    fn test_catch_up_opens_unread_items_until_none_left() {
         let mut app = make_test_app();
-        app.selected_feed = Some(0);
-        app.selected_item = Some(0);
-        app.feeds[0].items[0].description = None;
+        app.read_items.clear();
+        app.starred_items
+            .insert("https://example.com/another".to_string());
+        let mut seen = HashSet::new();
+        for _ in 0..3 {
+            assert!(app.catch_up().unwrap());
+            assert_eq!(app.view, View::FeedItemDetail);
+            let picked = app.selected_feed.zip(app.selected_item).unwrap();
+            assert!(seen.insert(picked), "an item came up twice");
+            assert!(app.is_item_read(picked.0, picked.1));
+        }
+        assert!(!app.catch_up().unwrap());
+        // The way back still leads to where catch-up started
+        app.back_from_detail_view();
+        assert_eq!(app.view, View::Dashboard);
+    }
 
-        app.extract_links_from_current_item();
+    #[test]
+    fn test_reading_session_fits_the_time_window() {
+        let mut app = make_test_app();
+        app.read_items.clear();
+        // A five-minute read that doesn't fit a three-minute session
+        app.feeds[0].items[1].plain_text = Some("word ".repeat(1000));
+        assert!(app.start_reading_session(3).unwrap());
+        let session = app.reading_session.clone().unwrap();
+        assert_eq!(session.queue, vec![(1, 0), (0, 0)]);
+        assert_eq!(app.selected_item_indices(), Some((1, 0)));
+        assert_eq!(
+            app.reading_session_status().as_deref(),
+            Some("1/2 \u{00b7} 2 min left")
+        );
 
-        assert!(!app.show_link_overlay);
-        assert!(app.extracted_links.is_empty());
+        assert!(app.advance_reading_session().unwrap());
+        assert_eq!(app.selected_item_indices(), Some((0, 0)));
+        assert!(app.is_item_read(1, 0));
+        assert!(!app.advance_reading_session().unwrap());
+        assert_eq!(app.view, View::Dashboard);
+        assert!(app.reading_session.is_none());
+        assert!(!app.is_item_read(0, 1));
     }
 
     #[test]
-    fn test_extract_links_resolves_relative_urls() {
+    fn test_undated_items_are_dated_from_their_text() {
         let mut app = make_test_app();
-        app.selected_feed = Some(0);
-        app.selected_item = Some(0);
-        // Item has a base URL via its link
-        app.feeds[0].items[0].link = Some("https://example.com/article/123".to_string());
-        app.feeds[0].items[0].description = Some(r#"<a href="/about">About</a>"#.to_string());
-
-        app.extract_links_from_current_item();
+        let mut feed = app.feeds[0].clone();
+        for item in &mut feed.items {
+            item.parsed_date = None;
+            item.pub_date = None;
+        }
+        feed.items[0].plain_text = Some("Stand: 02.05.2024".to_string());
+        feed.items[1].plain_text = Some("vor 2 Stunden".to_string());
+        app.feed_date_formats
+            .insert(feed.url.clone(), vec!["%d.%m.%Y".to_string()]);
 
-        assert_eq!(app.extracted_links.len(), 1);
-        assert_eq!(app.extracted_links[0].url, "https://example.com/about");
+        app.replace_feed(0, feed);
+        let items = &app.feeds[0].items;
+        assert_eq!(
+            items[0].parsed_date.map(|d| d.date_naive()),
+            chrono::NaiveDate::from_ymd_opt(2024, 5, 2)
+        );
+        let hours_ago = (Utc::now() - items[1].parsed_date.unwrap()).num_hours();
+        assert_eq!(hours_ago, 2);
     }
 }
@@ -0,0 +1,133 @@
+use crate::feed::{Feed, FeedItem};
+use regex::Regex;
+
+// Kept small so the page reads and prints well without any external assets
+const STYLE: &str = "body { max-width: 42em; margin: 2em auto; padding: 0 1em; \
+font: 17px/1.6 Georgia, serif; color: #222; }\n\
+header { border-bottom: 1px solid #ddd; margin-bottom: 1.5em; }\n\
+h1 { font-size: 1.8em; line-height: 1.25; margin-bottom: 0.3em; }\n\
+.meta { color: #666; font: 14px sans-serif; }\n\
+img, video { max-width: 100%; height: auto; }\n\
+pre { overflow-x: auto; background: #f5f5f5; padding: 0.8em; }\n\
+blockquote { border-left: 3px solid #ccc; margin-left: 0; padding-left: 1em; color: #555; }\n\
+@media print { body { margin: 0; max-width: none; } a { color: inherit; } }\n";
+
+/// Render an article as a standalone HTML page: a styled header with the title, feed,
+/// author, date and original link, then `body_html`. Scripts are dropped, and images
+/// stay pointed at their original URLs, with relative ones resolved against the link.
+pub fn standalone_html(feed: &Feed, item: &FeedItem, body_html: &str) -> String {
+    let link = item.link.as_deref().unwrap_or("");
+    let meta: Vec<String> = [
+        Some(feed.title.as_str()),
+        item.author.as_deref(),
+        item.parsed_date
+            .map(|dt| dt.format("%B %d, %Y").to_string())
+            .as_deref()
+            .or(item.pub_date.as_deref()),
+    ]
+    .into_iter()
+    .flatten()
+    .filter(|part| !part.is_empty())
+    .map(escape)
+    .collect();
+    let base = if link.is_empty() {
+        String::new()
+    } else {
+        format!("<base href=\"{}\">\n", escape(link))
+    };
+    let original = if link.is_empty() {
+        String::new()
+    } else {
+        format!(
+            "<p class=\"meta\"><a href=\"{0}\">{0}</a></p>\n",
+            escape(link)
+        )
+    };
+
+    format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n{}<title>{}</title>\n\
+         <style>\n{}</style>\n</head>\n<body>\n<article>\n<header>\n<h1>{}</h1>\n\
+         <p class=\"meta\">{}</p>\n{}</header>\n{}\n</article>\n</body>\n</html>\n",
+        base,
+        escape(&item.title),
+        STYLE,
+        escape(&item.title),
+        meta.join(" &middot; "),
+        original,
+        strip_scripts(body_html)
+    )
+}
+
+/// A file name stem for an article: its title lowercased, with runs of anything but
+/// letters and digits turned into single dashes, at most 60 characters long.
+pub fn file_stem(title: &str) -> String {
+    let mut stem = String::new();
+    for c in title.chars().flat_map(char::to_lowercase) {
+        if c.is_alphanumeric() {
+            stem.push(c);
+        } else if !stem.is_empty() && !stem.ends_with('-') {
+            stem.push('-');
+        }
+        if stem.chars().count() >= 60 {
+            break;
+        }
+    }
+    let stem = stem.trim_end_matches('-');
+    if stem.is_empty() {
+        "article".to_string()
+    } else {
+        stem.to_string()
+    }
+}
+
+fn strip_scripts(html: &str) -> String {
+    let script = Regex::new(r"(?is)<script\b.*?</script\s*>").expect("valid script pattern");
+    script.replace_all(html, "").into_owned()
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_standalone_html_has_header_and_drops_scripts() {
+        let feed = Feed {
+            url: "https://example.com/feed.xml".to_string(),
+            title: "Example & Co".to_string(),
+            items: vec![],
+            title_lower: "example & co".to_string(),
+            description: None,
+            updated: None,
+            link: None,
+        };
+        let item = FeedItem {
+            link: Some("https://example.com/posts/hello".to_string()),
+            author: Some("Jane".to_string()),
+            ..FeedItem::with_title("Hello <world>")
+        };
+        let html = standalone_html(
+            &feed,
+            &item,
+            "<p>Body</p><img src=\"/a.png\"><script>alert(1)</script>",
+        );
+        assert!(html.contains("<base href=\"https://example.com/posts/hello\">"));
+        assert!(html.contains("<h1>Hello &lt;world&gt;</h1>"));
+        assert!(html.contains("Example &amp; Co &middot; Jane"));
+        assert!(html.contains("<img src=\"/a.png\">"));
+        assert!(!html.contains("alert(1)"));
+    }
+
+    #[test]
+    fn test_file_stem_slugifies_titles() {
+        assert_eq!(file_stem("Rust 1.80: What's New?"), "rust-1-80-what-s-new");
+        assert_eq!(file_stem("???"), "article");
+        assert!(file_stem(&"long ".repeat(40)).chars().count() <= 60);
+    }
+}
@@ -0,0 +1,296 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, Utc};
+use regex::Regex;
+use std::sync::OnceLock;
+
+const MONTHS: &str = "jan(?:uary)?|feb(?:ruary)?|mar(?:ch)?|apr(?:il)?|may|june?|july?|\
+aug(?:ust)?|sep(?:t(?:ember)?)?|oct(?:ober)?|nov(?:ember)?|dec(?:ember)?";
+
+// How far past a date we look for the time it starts at
+const TIME_WINDOW: usize = 40;
+
+/// When an event announced in an article happens, as written: a local date and,
+/// if the article gives one, a start time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EventTime {
+    pub date: NaiveDate,
+    pub time: Option<NaiveTime>,
+}
+
+impl EventTime {
+    /// Short label like "Sat May 17, 2025 18:30".
+    pub fn label(&self) -> String {
+        match self.time {
+            Some(time) => format!(
+                "{} {}",
+                self.date.format("%a %b %-d, %Y"),
+                time.format("%H:%M")
+            ),
+            None => self.date.format("%a %b %-d, %Y").to_string(),
+        }
+    }
+}
+
+/// One calendar entry to export.
+pub struct CalendarEvent<'a> {
+    pub uid: &'a str,
+    pub summary: &'a str,
+    pub url: Option<&'a str>,
+    pub description: &'a str,
+    pub when: EventTime,
+}
+
+struct Patterns {
+    iso: Regex,
+    month_day: Regex,
+    day_month: Regex,
+    time: Regex,
+}
+
+fn patterns() -> &'static Patterns {
+    static PATTERNS: OnceLock<Patterns> = OnceLock::new();
+    PATTERNS.get_or_init(|| Patterns {
+        iso: Regex::new(r"\b(\d{4})-(\d{2})-(\d{2})\b").expect("valid ISO date pattern"),
+        month_day: Regex::new(&format!(
+            r"(?i)\b({})\.?\s+(\d{{1,2}})(?:st|nd|rd|th)?\b(?:,?\s+(\d{{4}})\b)?",
+            MONTHS
+        ))
+        .expect("valid month-day pattern"),
+        day_month: Regex::new(&format!(
+            r"(?i)\b(\d{{1,2}})(?:st|nd|rd|th)?\s+({})\b\.?(?:,?\s+(\d{{4}})\b)?",
+            MONTHS
+        ))
+        .expect("valid day-month pattern"),
+        time: Regex::new(r"(?i)\b(\d{1,2})(?::(\d{2}))?\s*([ap]\.?m\.?)|\b(\d{1,2}):(\d{2})\b")
+            .expect("valid time pattern"),
+    })
+}
+
+fn month_number(name: &str) -> Option<u32> {
+    let prefix: String = name.chars().take(3).collect::<String>().to_lowercase();
+    let month = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ]
+    .iter()
+    .position(|m| *m == prefix)?;
+    Some(month as u32 + 1)
+}
+
+/// Find the first date in `text`, along with a start time written shortly after it.
+/// Dates without a year are taken to be the next one on or after `published`, since
+/// announcements are about upcoming events.
+pub fn find_event_time(text: &str, published: NaiveDate) -> Option<EventTime> {
+    let patterns = patterns();
+    let mut candidates: Vec<(usize, usize, NaiveDate)> = Vec::new();
+
+    if let Some(caps) = patterns.iso.captures(text) {
+        let whole = caps.get(0)?;
+        if let Some(date) = NaiveDate::from_ymd_opt(
+            caps[1].parse().ok()?,
+            caps[2].parse().ok()?,
+            caps[3].parse().ok()?,
+        ) {
+            candidates.push((whole.start(), whole.end(), date));
+        }
+    }
+    for (regex, month_group, day_group) in
+        [(&patterns.month_day, 1, 2), (&patterns.day_month, 2, 1)]
+    {
+        for caps in regex.captures_iter(text) {
+            let whole = caps.get(0)?;
+            let month = month_number(&caps[month_group]);
+            let day: Option<u32> = caps[day_group].parse().ok();
+            let year: Option<i32> = caps.get(3).and_then(|y| y.as_str().parse().ok());
+            let date = month.zip(day).and_then(|(month, day)| match year {
+                Some(year) => NaiveDate::from_ymd_opt(year, month, day),
+                None => NaiveDate::from_ymd_opt(published.year(), month, day).and_then(|date| {
+                    if date < published {
+                        NaiveDate::from_ymd_opt(published.year() + 1, month, day)
+                    } else {
+                        Some(date)
+                    }
+                }),
+            });
+            if let Some(date) = date {
+                candidates.push((whole.start(), whole.end(), date));
+                break;
+            }
+        }
+    }
+
+    let (_, end, date) = candidates.into_iter().min_by_key(|(start, _, _)| *start)?;
+    Some(EventTime {
+        date,
+        time: find_time(&text[end..]),
+    })
+}
+
+fn find_time(after: &str) -> Option<NaiveTime> {
+    let caps = patterns().time.captures(after)?;
+    if caps.get(0)?.start() > TIME_WINDOW {
+        return None;
+    }
+    if let Some(meridiem) = caps.get(3) {
+        let hour: u32 = caps[1].parse().ok()?;
+        let minute: u32 = caps.get(2).map_or(Some(0), |m| m.as_str().parse().ok())?;
+        if !(1..=12).contains(&hour) {
+            return None;
+        }
+        let pm = meridiem.as_str().to_lowercase().starts_with('p');
+        let hour = match (hour, pm) {
+            (12, false) => 0,
+            (12, true) => 12,
+            (hour, true) => hour + 12,
+            (hour, false) => hour,
+        };
+        NaiveTime::from_hms_opt(hour, minute, 0)
+    } else {
+        NaiveTime::from_hms_opt(caps[4].parse().ok()?, caps[5].parse().ok()?, 0)
+    }
+}
+
+/// Render events as an iCalendar (.ics) document. Timed events last an hour and use
+/// floating local time, since articles rarely say which time zone they mean; events
+/// without a time are all-day.
+pub fn to_ics(events: &[CalendarEvent], stamp: DateTime<Utc>) -> String {
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//feedr//EN".to_string(),
+    ];
+    for event in events {
+        lines.push("BEGIN:VEVENT".to_string());
+        lines.push(format!("UID:{}@feedr", escape(event.uid)));
+        lines.push(format!("DTSTAMP:{}", stamp.format("%Y%m%dT%H%M%SZ")));
+        match event.when.time {
+            Some(time) => {
+                let start = event.when.date.and_time(time);
+                lines.push(format!("DTSTART:{}", start.format("%Y%m%dT%H%M%S")));
+                lines.push(format!(
+                    "DTEND:{}",
+                    (start + Duration::hours(1)).format("%Y%m%dT%H%M%S")
+                ));
+            }
+            None => {
+                let date = event.when.date;
+                lines.push(format!("DTSTART;VALUE=DATE:{}", date.format("%Y%m%d")));
+                if let Some(next) = date.succ_opt() {
+                    lines.push(format!("DTEND;VALUE=DATE:{}", next.format("%Y%m%d")));
+                }
+            }
+        }
+        lines.push(format!("SUMMARY:{}", escape(event.summary)));
+        if let Some(url) = event.url {
+            lines.push(format!("URL:{}", url));
+        }
+        if !event.description.is_empty() {
+            lines.push(format!("DESCRIPTION:{}", escape(event.description)));
+        }
+        lines.push("END:VEVENT".to_string());
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    let mut out = String::new();
+    for line in lines {
+        out.push_str(&fold(&line));
+        out.push_str("\r\n");
+    }
+    out
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+// Lines longer than 75 octets continue on the next line after a single space
+fn fold(line: &str) -> String {
+    let mut out = String::new();
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            out.push_str("\r\n ");
+            width = 1;
+        }
+        out.push(c);
+        width += c.len_utf8();
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_find_event_time_reads_common_formats() {
+        let published = day(2025, 3, 1);
+        assert_eq!(
+            find_event_time("Join us on March 14th, 2025 at 6:30 pm", published),
+            Some(EventTime {
+                date: day(2025, 3, 14),
+                time: NaiveTime::from_hms_opt(18, 30, 0),
+            })
+        );
+        assert_eq!(
+            find_event_time("Meetup: 2025-04-02 19:00, Berlin", published),
+            Some(EventTime {
+                date: day(2025, 4, 2),
+                time: NaiveTime::from_hms_opt(19, 0, 0),
+            })
+        );
+        // No year: the next 12 January after publication
+        assert_eq!(
+            find_event_time("Next session 12 Jan", published),
+            Some(EventTime {
+                date: day(2026, 1, 12),
+                time: None,
+            })
+        );
+        assert_eq!(find_event_time("Nothing scheduled yet", published), None);
+    }
+
+    #[test]
+    fn test_to_ics_writes_timed_and_all_day_events() {
+        let stamp = DateTime::parse_from_rfc3339("2025-03-01T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let ics = to_ics(
+            &[
+                CalendarEvent {
+                    uid: "a1",
+                    summary: "Rust meetup; talks, pizza",
+                    url: Some("https://example.com/meetup"),
+                    description: "",
+                    when: EventTime {
+                        date: day(2025, 3, 14),
+                        time: NaiveTime::from_hms_opt(18, 30, 0),
+                    },
+                },
+                CalendarEvent {
+                    uid: "b2",
+                    summary: "Conference",
+                    url: None,
+                    description: "Two days\nof talks",
+                    when: EventTime {
+                        date: day(2025, 5, 20),
+                        time: None,
+                    },
+                },
+            ],
+            stamp,
+        );
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.contains("DTSTART:20250314T183000\r\nDTEND:20250314T193000\r\n"));
+        assert!(ics.contains("SUMMARY:Rust meetup\\; talks\\, pizza\r\n"));
+        assert!(ics.contains("DTSTART;VALUE=DATE:20250520\r\nDTEND;VALUE=DATE:20250521\r\n"));
+        assert!(ics.contains("DESCRIPTION:Two days\\nof talks\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    }
+}
@@ -0,0 +1,251 @@
+// Command-line interface definitions. This file only depends on clap so that build.rs can
+// `include!` it to generate man pages and shell completions at build time.
+
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ReadFormat {
+    Text,
+    Md,
+    Html,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum KeysFormat {
+    /// Aligned plain-text columns
+    Table,
+    /// Markdown tables, one per group
+    Markdown,
+}
+
+#[derive(Parser)]
+#[command(name = "feedr")]
+#[command(version = env!("CARGO_PKG_VERSION"))]
+#[command(about = "A feature-rich terminal-based RSS/Atom feed reader written in Rust")]
+#[command(
+    long_about = "Feedr is a modern terminal-based RSS/Atom feed reader with advanced filtering, categorization, and search capabilities. It supports both RSS and Atom feeds with compression handling and provides an intuitive TUI interface."
+)]
+pub struct Cli {
+    #[command(flatten)]
+    pub global: GlobalArgs,
+
+    /// OPML file to import (same as `feedr import`)
+    #[arg(short, long, value_name = "FILE PATH", hide = true)]
+    pub import: Option<String>,
+
+    /// With --import, report what would be added without importing
+    #[arg(long, requires = "import", hide = true)]
+    pub dry_run: bool,
+
+    /// Try feedr on bundled sample feeds, without the network or your own config and data
+    #[arg(long, conflicts_with = "import")]
+    pub demo: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Commands>,
+}
+
+/// Flags accepted before or after any subcommand.
+#[derive(Args)]
+pub struct GlobalArgs {
+    /// Directory holding the data file (subscriptions, read and starred state)
+    #[arg(long, global = true, value_name = "DIR")]
+    pub data_dir: Option<PathBuf>,
+
+    /// Use a named profile, with its own config and data
+    #[arg(long, global = true, value_name = "NAME")]
+    pub profile: Option<String>,
+
+    /// Report progress and per-feed fetch details on stderr
+    #[arg(short, long, global = true)]
+    pub verbose: bool,
+
+    /// Save bandwidth on a metered connection: no images, page fetches or background refreshes
+    #[arg(long, global = true, visible_alias = "metered")]
+    pub lite: bool,
+
+    /// Change nothing on disk: no saving, no subscription or category edits
+    #[arg(long, global = true)]
+    pub read_only: bool,
+}
+
+#[derive(Subcommand)]
+pub enum Commands {
+    /// Launch the interactive reader (the default)
+    Tui,
+    /// Subscribe to every feed in an OPML file, filing folders as categories
+    Import {
+        /// OPML file exported from another reader, or a NetNewsWire account folder
+        file: String,
+
+        /// Report what would be added, duplicates and invalid feeds without importing
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Also star the articles in this export of starred items (bookmarks HTML, JSON Feed or URL list)
+        #[arg(long, value_name = "FILE")]
+        starred: Option<PathBuf>,
+    },
+    /// Write subscriptions as OPML, grouped by category
+    Export {
+        /// Output file; prints to stdout when omitted
+        file: Option<PathBuf>,
+
+        /// Don't fetch feeds for their titles; feeds without a custom title use their URL
+        #[arg(long)]
+        no_fetch: bool,
+    },
+    /// List subscriptions without fetching them
+    List {
+        /// Print a JSON array instead of tab-separated lines
+        #[arg(long)]
+        json: bool,
+    },
+    /// Fetch every feed without launching the UI
+    Refresh,
+    /// Fetch every feed into the cache for cron or a timer, without counting as a session
+    Fetch {
+        /// Print nothing unless a feed fails
+        #[arg(short, long)]
+        quiet: bool,
+    },
+    /// Run `feedr fetch` on a schedule with a user-level systemd timer or launchd agent
+    Service {
+        #[command(subcommand)]
+        action: ServiceAction,
+    },
+    /// Search fetched items, as `/` does in the UI, and print the matches
+    Search {
+        /// Text to find in feed titles, item titles and article text
+        query: String,
+
+        /// Only search feeds with this URL, or whose title contains this text
+        #[arg(long)]
+        feed: Option<String>,
+
+        /// Only items published within this long, e.g. 12h, 7d or 2w
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Print a JSON array instead of tab-separated lines
+        #[arg(long)]
+        json: bool,
+
+        /// Fetch feeds first instead of searching the cached copies
+        #[arg(long)]
+        refresh: bool,
+    },
+    /// Fetch every feed and print feeds and items, with read and starred state, as JSON
+    Dump {
+        /// Leave out items already read
+        #[arg(long)]
+        unread: bool,
+    },
+    /// Manage configuration
+    Config {
+        #[command(subcommand)]
+        action: Option<ConfigAction>,
+
+        /// Open interactive TUI config editor
+        #[arg(long)]
+        tui: bool,
+    },
+    /// Subscribe to a feed without launching the UI
+    Add {
+        /// Feed URL, or a web page that links to one feed
+        #[arg(required_unless_present = "file", conflicts_with = "file")]
+        url: Option<String>,
+
+        /// Add every feed listed in a file, one URL per line ("-" reads standard input)
+        #[arg(long, value_name = "FILE")]
+        file: Option<PathBuf>,
+
+        /// Category to put the feed in, created if needed
+        #[arg(long)]
+        category: Option<String>,
+
+        /// Title to show instead of the feed's own
+        #[arg(long, conflicts_with = "file")]
+        title: Option<String>,
+    },
+    /// Check subscriptions for broken, moved, duplicate or stale feeds
+    Doctor {
+        /// Apply safe fixes (follow permanent redirects, drop duplicates) without asking
+        #[arg(long)]
+        fix: bool,
+    },
+    /// Import or export starred articles as a browser bookmarks file
+    Bookmarks {
+        #[command(subcommand)]
+        action: BookmarksAction,
+    },
+    /// Print the effective keybindings, including remappings from the config
+    Keys {
+        /// Output format
+        #[arg(long, value_enum, default_value_t = KeysFormat::Table)]
+        format: KeysFormat,
+    },
+    /// Print a shell completion script to stdout
+    Completions {
+        /// Shell to generate completions for
+        shell: clap_complete::Shell,
+    },
+    /// Print an article to stdout
+    Read {
+        /// Article URL, or an item ID from a subscribed feed
+        target: String,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = ReadFormat::Text)]
+        format: ReadFormat,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum BookmarksAction {
+    /// Write starred articles to a Netscape bookmarks HTML file
+    Export {
+        /// Output file
+        file: PathBuf,
+    },
+    /// Star every link in a Netscape bookmarks HTML file
+    Import {
+        /// Bookmarks file exported from a browser or bookmark manager
+        file: PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum ServiceAction {
+    /// Write and start a timer that runs `feedr fetch --quiet` in the background
+    Install {
+        /// How often to fetch, e.g. 15m, 2h or 1d
+        #[arg(long, default_value = "30m")]
+        every: String,
+
+        /// Print the service files instead of installing them
+        #[arg(long)]
+        print: bool,
+    },
+    /// Stop the timer and remove its files
+    Uninstall,
+}
+
+#[derive(Subcommand)]
+pub enum ConfigAction {
+    /// Get a config value by key
+    Get {
+        /// Config key in dot-notation (e.g. ui.theme)
+        key: String,
+    },
+    /// Set a config value
+    Set {
+        /// Config key in dot-notation (e.g. ui.theme)
+        key: String,
+        /// New value
+        value: String,
+    },
+    /// List all config keys and values
+    List,
+}
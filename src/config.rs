@@ -3,7 +3,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fmt;
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::PathBuf;
 
 /// Main configuration structure for Feedr
 #[derive(Clone, Debug, Serialize, Deserialize, Default)]
@@ -15,6 +15,16 @@ pub struct Config {
     #[serde(default)]
     pub ui: UiConfig,
     #[serde(default)]
+    pub share: ShareConfig,
+    #[serde(default)]
+    pub tools: ToolsConfig,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alerts: Vec<AlertRule>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub dashboard_sections: Vec<DashboardSection>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub collections: Vec<Collection>,
+    #[serde(default)]
     pub default_feeds: Vec<DefaultFeed>,
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub keybindings: HashMap<String, toml::Value>,
@@ -25,15 +35,39 @@ pub struct GeneralConfig {
     /// Maximum number of items to show on the dashboard
     #[serde(default = "default_max_dashboard_items")]
     pub max_dashboard_items: usize,
+    /// Newest items kept from each feed (0 = keep everything the feed returns)
+    #[serde(default)]
+    pub max_items_per_feed: usize,
     /// Auto-refresh interval in seconds (0 = disabled)
     #[serde(default)]
     pub auto_refresh_interval: u64,
     /// Enable automatic background refresh
     #[serde(default)]
     pub refresh_enabled: bool,
+    /// Schedule each feed on its own, refreshing it about twice as often as it posts
+    #[serde(default)]
+    pub adaptive_refresh: bool,
     /// Delay in milliseconds between requests to the same domain (for rate limiting)
     #[serde(default = "default_refresh_rate_limit_delay")]
     pub refresh_rate_limit_delay: u64,
+    /// File Prometheus-style metrics are written to after each refresh; None = off
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metrics_file: Option<String>,
+    /// Download the article page and lead image of starred and read-later items
+    #[serde(default)]
+    pub prefetch_saved: bool,
+    /// What Enter opens an item in (reader, browser, full_text)
+    #[serde(default)]
+    pub open_target: OpenTarget,
+    /// Where read state, subscriptions and categories are saved (json, sqlite)
+    #[serde(default)]
+    pub storage: StorageBackend,
+    /// Which new items send a desktop notification (all, alerts, never)
+    #[serde(default)]
+    pub notify: NotifyLevel,
+    /// Local times notifications are held back, as "HH:MM-HH:MM"; None = never
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quiet_hours: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -44,6 +78,21 @@ pub struct NetworkConfig {
     /// User agent string for HTTP requests
     #[serde(default = "default_user_agent")]
     pub user_agent: String,
+    /// Limit on establishing a connection in seconds (0 = only http_timeout applies)
+    #[serde(default)]
+    pub connect_timeout: u64,
+    /// Accept-Language header sent with feed requests
+    #[serde(default = "default_accept_language")]
+    pub accept_language: String,
+    /// Keep fetched feeds on disk to revalidate them and to fall back on when offline
+    #[serde(default = "default_true")]
+    pub http_cache: bool,
+    /// Seconds a cached feed is reused at startup without asking the server (0 = always ask)
+    #[serde(default = "default_cache_max_age")]
+    pub cache_max_age: u64,
+    /// Save bandwidth: skip images, page fetches and background refreshes
+    #[serde(default)]
+    pub metered: bool,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -54,12 +103,69 @@ pub struct UiConfig {
     /// Error message display timeout in milliseconds
     #[serde(default = "default_error_timeout")]
     pub error_display_timeout: u64,
-    /// Color theme (light or dark)
+    /// Color theme (light, dark or mono)
     #[serde(default)]
     pub theme: Theme,
+    /// Pick the theme automatically (off, schedule, terminal)
+    #[serde(default)]
+    pub auto_theme: AutoTheme,
+    /// Local time the schedule switches to the light theme (HH:MM)
+    #[serde(default = "default_light_theme_at")]
+    pub light_theme_at: String,
+    /// Local time the schedule switches to the dark theme (HH:MM)
+    #[serde(default = "default_dark_theme_at")]
+    pub dark_theme_at: String,
     /// Compact mode for small terminals (auto, always, never)
     #[serde(default)]
     pub compact_mode: CompactMode,
+    /// Read-state marker shown in item lists (dot, bracket, none)
+    #[serde(default)]
+    pub read_indicator: ReadIndicator,
+    /// Show a text preview under each item in a feed's item list
+    #[serde(default = "default_true")]
+    pub show_snippet: bool,
+    /// Longest item preview in characters
+    #[serde(default = "default_snippet_length")]
+    pub snippet_length: usize,
+    /// Show item authors in lists
+    #[serde(default = "default_true")]
+    pub show_author: bool,
+    /// Where the dashboard puts each item's feed name (above, inline, hidden)
+    #[serde(default)]
+    pub feed_name: FeedNamePosition,
+    /// How item lists show publication dates (auto, relative, absolute, hidden)
+    #[serde(default)]
+    pub date_style: DateStyle,
+    /// Text column width in columns for zen reading mode
+    #[serde(default = "default_reading_width")]
+    pub reading_width: u16,
+    /// Draw article lead images as block art in the detail view
+    #[serde(default)]
+    pub image_art: bool,
+    /// Largest image art width in columns
+    #[serde(default = "default_image_art_width")]
+    pub image_art_width: u16,
+    /// Largest image art height in rows
+    #[serde(default = "default_image_art_height")]
+    pub image_art_height: u16,
+    /// Shell command whose first output line is shown on the right of the title bar
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_command: Option<String>,
+    /// Seconds between runs of the status command
+    #[serde(default = "default_status_interval")]
+    pub status_interval: u64,
+    /// Daily reading goal in minutes, tracked in the title bar (0 = no goal)
+    #[serde(default)]
+    pub reading_goal: u64,
+    /// Minutes of reading a time-boxed session (Z) queues up
+    #[serde(default = "default_session_minutes")]
+    pub session_minutes: u64,
+    /// Ask before quitting while changes are unsaved, a refresh runs or triage is unfinished
+    #[serde(default = "default_true")]
+    pub confirm_quit: bool,
+    /// Domain -> symbol shown in place of the initial badge for that site's feeds
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub icons: HashMap<String, String>,
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
@@ -68,6 +174,92 @@ pub enum Theme {
     Light,
     #[default]
     Dark,
+    /// No colors: bold, underline and reverse video only
+    Mono,
+}
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ReadIndicator {
+    /// ● for unread, ○ for read
+    #[default]
+    Dot,
+    /// [N] for unread, [ ] for read
+    Bracket,
+    /// No marker; read state is shown by color only
+    None,
+}
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AutoTheme {
+    /// Only `theme` and the toggle key decide
+    #[default]
+    Off,
+    /// Light between `light_theme_at` and `dark_theme_at`, dark otherwise
+    Schedule,
+    /// Follow the terminal's background, as reported in `COLORFGBG`
+    Terminal,
+}
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum FeedNamePosition {
+    /// On its own line above the item title
+    #[default]
+    Above,
+    /// Before the title, on the same line
+    Inline,
+    /// Not shown; only the feed badge remains
+    Hidden,
+}
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DateStyle {
+    /// "3 hours ago" for the past week, then the date
+    #[default]
+    Auto,
+    /// Always relative: "3 hours ago", "2 months ago"
+    Relative,
+    /// Always the date, as 2024-05-17
+    Absolute,
+    /// No dates in lists
+    Hidden,
+}
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OpenTarget {
+    /// The detail view, with the feed's own content
+    #[default]
+    Reader,
+    /// The item's link in the web browser
+    Browser,
+    /// The detail view, with the article text of the linked page
+    FullText,
+}
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum StorageBackend {
+    /// One JSON file, feedr_data.json
+    #[default]
+    Json,
+    /// A SQLite database, feedr_data.db
+    Sqlite,
+}
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum NotifyLevel {
+    /// Every item a refresh brings in
+    All,
+    /// Items matching an alert rule with `notify` on
+    #[default]
+    Alerts,
+    /// Nothing
+    Never,
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
@@ -79,6 +271,146 @@ pub enum CompactMode {
     Never,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ShareConfig {
+    /// Share text template; supports {title}, {url}, {feed}, {author} and {date}
+    #[serde(default = "default_share_template")]
+    pub template: String,
+    /// Shell command that receives the shared text on stdin; None = copy to clipboard
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<String>,
+    /// Chat webhooks the share-to-webhook action (Y) can post articles to
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub webhooks: Vec<Webhook>,
+}
+
+/// A Slack, Matrix or other chat webhook that takes a JSON payload.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Webhook {
+    /// Name shown in the webhook picker
+    pub name: String,
+    /// URL the payload is POSTed to
+    pub url: String,
+    /// JSON body; the share placeholders are filled with JSON-escaped values
+    #[serde(default = "default_webhook_template")]
+    pub template: String,
+}
+
+/// External commands article text can be piped through.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ToolsConfig {
+    /// Shell command that reads article text on stdin and writes a translation to stdout
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translate_command: Option<String>,
+    /// Whether a translation replaces the original or is shown next to it
+    #[serde(default)]
+    pub translate_display: TranslateDisplay,
+    /// Shell command that reads article text on stdin and writes a summary to stdout
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summarize_command: Option<String>,
+    /// Directory exported articles are written to; defaults to `exports` in the data directory
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub export_dir: Option<String>,
+    /// Shell command converting an exported article to PDF; `{input}` and `{output}` are
+    /// replaced with the HTML and PDF paths
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pdf_command: Option<String>,
+    /// Shell command that files an article as a task; `{title}`, `{url}`, `{feed}`,
+    /// `{author}` and `{date}` are replaced with quoted values
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub task_command: Option<String>,
+    /// Shell command that imports an event's .ics file; `{file}` is replaced with its path
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub calendar_command: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum TranslateDisplay {
+    #[default]
+    Replace,
+    SideBySide,
+}
+
+/// A remote OPML list followed as a dynamic collection: its feeds are kept in a
+/// category of their own, subscribed when the list adds them and dropped when it
+/// removes them.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Collection {
+    /// URL of the OPML document
+    pub url: String,
+    /// Category the collection's feeds are kept in
+    pub category: String,
+    /// Hours between re-fetches of the list
+    #[serde(default = "default_collection_interval")]
+    pub interval_hours: u64,
+}
+
+/// A saved search: new items matching it are flagged, listed in the Alerts view and
+/// announced with a desktop notification.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct AlertRule {
+    /// Name shown next to matching items; defaults to the pattern
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    /// Keyword (case-insensitive) or regular expression matched against title and text
+    pub pattern: String,
+    /// Treat `pattern` as a regular expression instead of a plain keyword
+    #[serde(default)]
+    pub regex: bool,
+    /// Only match items from this feed URL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feed: Option<String>,
+    /// Only match items from feeds in this category
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// Send a desktop notification for new matches
+    #[serde(default = "default_true")]
+    pub notify: bool,
+}
+
+/// One block of a sectioned dashboard. With no sections configured the dashboard is
+/// a single list of the latest items.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct DashboardSection {
+    pub kind: SectionKind,
+    /// Heading for the block; defaults to one describing `kind`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Only include feeds in this category
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    /// Most entries to list
+    #[serde(default = "default_section_limit")]
+    pub limit: usize,
+    /// For `stale`: feeds with nothing newer than this many days
+    #[serde(default = "default_stale_days")]
+    pub days: u64,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SectionKind {
+    /// Newest items, read or not
+    Latest,
+    /// Newest unread items
+    Unread,
+    /// Starred items
+    Starred,
+    /// Items published today
+    Today,
+    /// Feeds that haven't published in a while, by their newest item
+    Stale,
+}
+
+fn default_section_limit() -> usize {
+    10
+}
+
+fn default_stale_days() -> u64 {
+    30
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct DefaultFeed {
     pub url: String,
@@ -89,9 +421,69 @@ pub struct DefaultFeed {
     /// Per-feed refresh interval in seconds; None = use global interval
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub refresh_interval: Option<u64>,
+    /// Newest items kept from this feed; None = use general.max_items_per_feed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_items: Option<usize>,
+    /// Whole-request timeout in seconds; None = use network.http_timeout
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u64>,
+    /// Connect timeout in seconds; None = use network.connect_timeout
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connect_timeout: Option<u64>,
+    /// User agent for this feed's requests; None = use network.user_agent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_agent: Option<String>,
+    /// Accept-Language for this feed's requests; None = use network.accept_language
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accept_language: Option<String>,
+    /// Keep this feed's items off the dashboard; it's still browsable from the feed list
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub hide_from_dashboard: bool,
+    /// How this feed's article content is cleaned up before display
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display: Option<DisplayRules>,
+    /// What Enter opens this feed's items in; None = use general.open_target
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub open: Option<OpenTarget>,
+    /// Which of this feed's new items send a notification; None = use general.notify
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notify: Option<NotifyLevel>,
+    /// chrono formats (e.g. "%d.%m.%Y") of the dates written in undated items' text
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub date_formats: Vec<String>,
+}
+
+/// Per-feed content display rules, applied when an article is rendered.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct DisplayRules {
+    /// CSS selectors whose elements are removed (e.g. ".share-buttons")
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub strip_selectors: Vec<String>,
+    /// Regex-delimited spans of the raw HTML to cut
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub strip_ranges: Vec<StripRange>,
+    /// Show the entry summary even when the feed also provides full content
+    #[serde(default)]
+    pub prefer_summary: bool,
+    /// Fetch the linked page and show its article text instead of the feed content
+    #[serde(default)]
+    pub full_text: bool,
+    /// Show the article's image large with its hover/alt text, for webcomics
+    #[serde(default)]
+    pub comic: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StripRange {
+    pub start: String,
+    pub end: String,
 }
 
 // Default value functions
+fn default_true() -> bool {
+    true
+}
+
 fn default_max_dashboard_items() -> usize {
     100
 }
@@ -108,10 +500,143 @@ fn default_user_agent() -> String {
     "Mozilla/5.0 (compatible; Feedr/1.0; +https://github.com/bahdotsh/feedr)".to_string()
 }
 
+fn default_cache_max_age() -> u64 {
+    300
+}
+
+fn default_accept_language() -> String {
+    crate::feed::DEFAULT_ACCEPT_LANGUAGE.to_string()
+}
+
+fn default_share_template() -> String {
+    "\"{title}\" {url} via {feed}".to_string()
+}
+
+fn default_collection_interval() -> u64 {
+    24
+}
+
+fn default_webhook_template() -> String {
+    r#"{"text": "{title} {url}"}"#.to_string()
+}
+
 fn default_tick_rate() -> u64 {
     100
 }
 
+fn default_reading_width() -> u16 {
+    80
+}
+
+fn default_snippet_length() -> usize {
+    100
+}
+
+fn default_status_interval() -> u64 {
+    60
+}
+
+fn default_session_minutes() -> u64 {
+    15
+}
+
+fn default_light_theme_at() -> String {
+    "07:00".to_string()
+}
+
+fn default_dark_theme_at() -> String {
+    "19:00".to_string()
+}
+
+impl GeneralConfig {
+    /// Whether local time `now` falls in `quiet_hours`. The hours may run past midnight,
+    /// as in "22:00-07:00".
+    pub fn in_quiet_hours(&self, now: chrono::NaiveTime) -> bool {
+        self.quiet_hours
+            .as_deref()
+            .and_then(parse_quiet_hours)
+            .is_some_and(|(start, end)| {
+                if start <= end {
+                    start <= now && now < end
+                } else {
+                    now >= start || now < end
+                }
+            })
+    }
+}
+
+// "HH:MM-HH:MM" as its start and end times
+fn parse_quiet_hours(value: &str) -> Option<(chrono::NaiveTime, chrono::NaiveTime)> {
+    let (start, end) = value.split_once('-')?;
+    let time = |text: &str| chrono::NaiveTime::parse_from_str(text.trim(), "%H:%M").ok();
+    Some((time(start)?, time(end)?))
+}
+
+impl UiConfig {
+    /// The theme `auto_theme` asks for at local time `now`, given the terminal's
+    /// `COLORFGBG` value; None when it's off or can't tell.
+    pub fn auto_theme_choice(
+        &self,
+        now: chrono::NaiveTime,
+        colorfgbg: Option<&str>,
+    ) -> Option<Theme> {
+        match self.auto_theme {
+            AutoTheme::Off => None,
+            AutoTheme::Schedule => {
+                let light_at =
+                    chrono::NaiveTime::parse_from_str(&self.light_theme_at, "%H:%M").ok()?;
+                let dark_at =
+                    chrono::NaiveTime::parse_from_str(&self.dark_theme_at, "%H:%M").ok()?;
+                // The light stretch may run past midnight for night-shift schedules
+                let is_light = if light_at <= dark_at {
+                    light_at <= now && now < dark_at
+                } else {
+                    now >= light_at || now < dark_at
+                };
+                Some(if is_light { Theme::Light } else { Theme::Dark })
+            }
+            AutoTheme::Terminal => {
+                // "fg;bg" (or "fg;other;bg"): the background is the last ANSI color index
+                let background: u8 = colorfgbg?.rsplit(';').next()?.trim().parse().ok()?;
+                Some(if background == 7 || (9..=15).contains(&background) {
+                    Theme::Light
+                } else {
+                    Theme::Dark
+                })
+            }
+        }
+    }
+
+    /// The icon set in `[ui.icons]` for `domain`, or for the closest parent domain
+    /// listed there, so `"rust-lang.org"` also covers `blog.rust-lang.org`.
+    pub fn icon_for(&self, domain: &str) -> Option<&str> {
+        if self.icons.is_empty() {
+            return None;
+        }
+        let domain = domain.to_lowercase();
+        let mut candidate = domain.trim_start_matches("www.");
+        loop {
+            let icon = self.icons.iter().find_map(|(key, icon)| {
+                let key = key.trim().to_lowercase();
+                (key.trim_start_matches("www.") == candidate && !icon.trim().is_empty())
+                    .then(|| icon.trim())
+            });
+            if icon.is_some() {
+                return icon;
+            }
+            candidate = candidate.split_once('.')?.1;
+        }
+    }
+}
+
+fn default_image_art_width() -> u16 {
+    48
+}
+
+fn default_image_art_height() -> u16 {
+    12
+}
+
 fn default_error_timeout() -> u64 {
     3000
 }
@@ -120,9 +645,17 @@ impl Default for GeneralConfig {
     fn default() -> Self {
         Self {
             max_dashboard_items: default_max_dashboard_items(),
+            max_items_per_feed: 0,
             auto_refresh_interval: 0,
             refresh_enabled: false,
+            adaptive_refresh: false,
             refresh_rate_limit_delay: default_refresh_rate_limit_delay(),
+            metrics_file: None,
+            prefetch_saved: false,
+            open_target: OpenTarget::default(),
+            storage: StorageBackend::default(),
+            notify: NotifyLevel::default(),
+            quiet_hours: None,
         }
     }
 }
@@ -132,6 +665,11 @@ impl Default for NetworkConfig {
         Self {
             http_timeout: default_http_timeout(),
             user_agent: default_user_agent(),
+            connect_timeout: 0,
+            accept_language: default_accept_language(),
+            http_cache: true,
+            cache_max_age: default_cache_max_age(),
+            metered: false,
         }
     }
 }
@@ -142,7 +680,36 @@ impl Default for UiConfig {
             tick_rate: default_tick_rate(),
             error_display_timeout: default_error_timeout(),
             theme: Theme::default(),
+            auto_theme: AutoTheme::default(),
+            light_theme_at: default_light_theme_at(),
+            dark_theme_at: default_dark_theme_at(),
             compact_mode: CompactMode::default(),
+            read_indicator: ReadIndicator::default(),
+            show_snippet: true,
+            snippet_length: default_snippet_length(),
+            show_author: true,
+            feed_name: FeedNamePosition::default(),
+            date_style: DateStyle::default(),
+            reading_width: default_reading_width(),
+            image_art: false,
+            image_art_width: default_image_art_width(),
+            image_art_height: default_image_art_height(),
+            status_command: None,
+            status_interval: default_status_interval(),
+            reading_goal: 0,
+            session_minutes: default_session_minutes(),
+            confirm_quit: true,
+            icons: HashMap::new(),
+        }
+    }
+}
+
+impl Default for ShareConfig {
+    fn default() -> Self {
+        Self {
+            template: default_share_template(),
+            command: None,
+            webhooks: Vec::new(),
         }
     }
 }
@@ -152,6 +719,7 @@ impl fmt::Display for Theme {
         match self {
             Theme::Light => write!(f, "light"),
             Theme::Dark => write!(f, "dark"),
+            Theme::Mono => write!(f, "mono"),
         }
     }
 }
@@ -166,22 +734,145 @@ impl fmt::Display for CompactMode {
     }
 }
 
+impl fmt::Display for ReadIndicator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadIndicator::Dot => write!(f, "dot"),
+            ReadIndicator::Bracket => write!(f, "bracket"),
+            ReadIndicator::None => write!(f, "none"),
+        }
+    }
+}
+
+impl fmt::Display for AutoTheme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AutoTheme::Off => write!(f, "off"),
+            AutoTheme::Schedule => write!(f, "schedule"),
+            AutoTheme::Terminal => write!(f, "terminal"),
+        }
+    }
+}
+
+impl fmt::Display for FeedNamePosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FeedNamePosition::Above => write!(f, "above"),
+            FeedNamePosition::Inline => write!(f, "inline"),
+            FeedNamePosition::Hidden => write!(f, "hidden"),
+        }
+    }
+}
+
+impl fmt::Display for DateStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateStyle::Auto => write!(f, "auto"),
+            DateStyle::Relative => write!(f, "relative"),
+            DateStyle::Absolute => write!(f, "absolute"),
+            DateStyle::Hidden => write!(f, "hidden"),
+        }
+    }
+}
+
+impl fmt::Display for NotifyLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NotifyLevel::All => write!(f, "all"),
+            NotifyLevel::Alerts => write!(f, "alerts"),
+            NotifyLevel::Never => write!(f, "never"),
+        }
+    }
+}
+
+impl fmt::Display for StorageBackend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StorageBackend::Json => write!(f, "json"),
+            StorageBackend::Sqlite => write!(f, "sqlite"),
+        }
+    }
+}
+
+impl fmt::Display for OpenTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OpenTarget::Reader => write!(f, "reader"),
+            OpenTarget::Browser => write!(f, "browser"),
+            OpenTarget::FullText => write!(f, "full_text"),
+        }
+    }
+}
+
+impl fmt::Display for TranslateDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TranslateDisplay::Replace => write!(f, "replace"),
+            TranslateDisplay::SideBySide => write!(f, "side_by_side"),
+        }
+    }
+}
+
 impl Config {
     /// Get a config value by dot-notation key
     pub fn get_value(&self, key: &str) -> Result<String> {
         match key {
             "general.max_dashboard_items" => Ok(self.general.max_dashboard_items.to_string()),
+            "general.max_items_per_feed" => Ok(self.general.max_items_per_feed.to_string()),
             "general.auto_refresh_interval" => Ok(self.general.auto_refresh_interval.to_string()),
             "general.refresh_enabled" => Ok(self.general.refresh_enabled.to_string()),
+            "general.adaptive_refresh" => Ok(self.general.adaptive_refresh.to_string()),
             "general.refresh_rate_limit_delay" => {
                 Ok(self.general.refresh_rate_limit_delay.to_string())
             }
+            "general.metrics_file" => Ok(self.general.metrics_file.clone().unwrap_or_default()),
+            "general.prefetch_saved" => Ok(self.general.prefetch_saved.to_string()),
+            "general.open_target" => Ok(self.general.open_target.to_string()),
+            "general.storage" => Ok(self.general.storage.to_string()),
+            "general.notify" => Ok(self.general.notify.to_string()),
+            "general.quiet_hours" => Ok(self.general.quiet_hours.clone().unwrap_or_default()),
             "network.http_timeout" => Ok(self.network.http_timeout.to_string()),
             "network.user_agent" => Ok(self.network.user_agent.clone()),
+            "network.connect_timeout" => Ok(self.network.connect_timeout.to_string()),
+            "network.accept_language" => Ok(self.network.accept_language.clone()),
+            "network.http_cache" => Ok(self.network.http_cache.to_string()),
+            "network.cache_max_age" => Ok(self.network.cache_max_age.to_string()),
+            "network.metered" => Ok(self.network.metered.to_string()),
             "ui.tick_rate" => Ok(self.ui.tick_rate.to_string()),
             "ui.error_display_timeout" => Ok(self.ui.error_display_timeout.to_string()),
             "ui.theme" => Ok(self.ui.theme.to_string()),
+            "ui.auto_theme" => Ok(self.ui.auto_theme.to_string()),
+            "ui.light_theme_at" => Ok(self.ui.light_theme_at.clone()),
+            "ui.dark_theme_at" => Ok(self.ui.dark_theme_at.clone()),
             "ui.compact_mode" => Ok(self.ui.compact_mode.to_string()),
+            "ui.read_indicator" => Ok(self.ui.read_indicator.to_string()),
+            "ui.show_snippet" => Ok(self.ui.show_snippet.to_string()),
+            "ui.snippet_length" => Ok(self.ui.snippet_length.to_string()),
+            "ui.show_author" => Ok(self.ui.show_author.to_string()),
+            "ui.feed_name" => Ok(self.ui.feed_name.to_string()),
+            "ui.date_style" => Ok(self.ui.date_style.to_string()),
+            "ui.reading_width" => Ok(self.ui.reading_width.to_string()),
+            "ui.image_art" => Ok(self.ui.image_art.to_string()),
+            "ui.image_art_width" => Ok(self.ui.image_art_width.to_string()),
+            "ui.image_art_height" => Ok(self.ui.image_art_height.to_string()),
+            "ui.status_command" => Ok(self.ui.status_command.clone().unwrap_or_default()),
+            "ui.status_interval" => Ok(self.ui.status_interval.to_string()),
+            "ui.reading_goal" => Ok(self.ui.reading_goal.to_string()),
+            "ui.session_minutes" => Ok(self.ui.session_minutes.to_string()),
+            "ui.confirm_quit" => Ok(self.ui.confirm_quit.to_string()),
+            "share.template" => Ok(self.share.template.clone()),
+            "share.command" => Ok(self.share.command.clone().unwrap_or_default()),
+            "tools.translate_command" => {
+                Ok(self.tools.translate_command.clone().unwrap_or_default())
+            }
+            "tools.translate_display" => Ok(self.tools.translate_display.to_string()),
+            "tools.summarize_command" => {
+                Ok(self.tools.summarize_command.clone().unwrap_or_default())
+            }
+            "tools.export_dir" => Ok(self.tools.export_dir.clone().unwrap_or_default()),
+            "tools.pdf_command" => Ok(self.tools.pdf_command.clone().unwrap_or_default()),
+            "tools.task_command" => Ok(self.tools.task_command.clone().unwrap_or_default()),
+            "tools.calendar_command" => Ok(self.tools.calendar_command.clone().unwrap_or_default()),
             k if k.starts_with("default_feeds") => {
                 bail!("Feed management is not supported via CLI. Use 'feedr config --tui' instead.")
             }
@@ -199,6 +890,13 @@ impl Config {
                 }
                 self.general.max_dashboard_items = v;
             }
+            "general.max_items_per_feed" => {
+                let v: usize = value.parse().context("Expected a non-negative integer")?;
+                if v > 10000 {
+                    bail!("Value must be between 0 and 10000");
+                }
+                self.general.max_items_per_feed = v;
+            }
             "general.auto_refresh_interval" => {
                 let v: u64 = value.parse().context("Expected a non-negative integer")?;
                 if v > 86400 {
@@ -210,6 +908,44 @@ impl Config {
                 let v: bool = value.parse().context("Expected 'true' or 'false'")?;
                 self.general.refresh_enabled = v;
             }
+            "general.adaptive_refresh" => {
+                let v: bool = value.parse().context("Expected 'true' or 'false'")?;
+                self.general.adaptive_refresh = v;
+            }
+            "general.prefetch_saved" => {
+                let v: bool = value.parse().context("Expected 'true' or 'false'")?;
+                self.general.prefetch_saved = v;
+            }
+            "general.open_target" => match value {
+                "reader" => self.general.open_target = OpenTarget::Reader,
+                "browser" => self.general.open_target = OpenTarget::Browser,
+                "full_text" => self.general.open_target = OpenTarget::FullText,
+                _ => bail!(
+                    "Invalid open_target '{}'. Valid values: reader, browser, full_text",
+                    value
+                ),
+            },
+            "general.storage" => match value {
+                "json" => self.general.storage = StorageBackend::Json,
+                "sqlite" => self.general.storage = StorageBackend::Sqlite,
+                _ => bail!("Invalid storage '{}'. Valid values: json, sqlite", value),
+            },
+            "general.notify" => match value {
+                "all" => self.general.notify = NotifyLevel::All,
+                "alerts" => self.general.notify = NotifyLevel::Alerts,
+                "never" => self.general.notify = NotifyLevel::Never,
+                _ => bail!(
+                    "Invalid notify '{}'. Valid values: all, alerts, never",
+                    value
+                ),
+            },
+            "general.quiet_hours" => {
+                let value = value.trim();
+                if !value.is_empty() && parse_quiet_hours(value).is_none() {
+                    bail!("Expected a time range as HH:MM-HH:MM, e.g. 22:00-07:00");
+                }
+                self.general.quiet_hours = (!value.is_empty()).then(|| value.to_string());
+            }
             "general.refresh_rate_limit_delay" => {
                 let v: u64 = value.parse().context("Expected a non-negative integer")?;
                 if v > 60000 {
@@ -217,6 +953,14 @@ impl Config {
                 }
                 self.general.refresh_rate_limit_delay = v;
             }
+            "general.metrics_file" => {
+                let value = value.trim();
+                self.general.metrics_file = if value.is_empty() {
+                    None
+                } else {
+                    Some(value.to_string())
+                };
+            }
             "network.http_timeout" => {
                 let v: u64 = value.parse().context("Expected a positive integer")?;
                 if !(1..=300).contains(&v) {
@@ -230,6 +974,34 @@ impl Config {
                 }
                 self.network.user_agent = value.to_string();
             }
+            "network.connect_timeout" => {
+                let v: u64 = value.parse().context("Expected a non-negative integer")?;
+                if v > 300 {
+                    bail!("Value must be between 0 and 300");
+                }
+                self.network.connect_timeout = v;
+            }
+            "network.accept_language" => {
+                if value.is_empty() {
+                    bail!("Accept-Language cannot be empty");
+                }
+                self.network.accept_language = value.to_string();
+            }
+            "network.http_cache" => {
+                let v: bool = value.parse().context("Expected 'true' or 'false'")?;
+                self.network.http_cache = v;
+            }
+            "network.cache_max_age" => {
+                let v: u64 = value.parse().context("Expected a non-negative integer")?;
+                if v > 86400 {
+                    bail!("Value must be between 0 and 86400");
+                }
+                self.network.cache_max_age = v;
+            }
+            "network.metered" => {
+                let v: bool = value.parse().context("Expected 'true' or 'false'")?;
+                self.network.metered = v;
+            }
             "ui.tick_rate" => {
                 let v: u64 = value.parse().context("Expected a positive integer")?;
                 if !(10..=1000).contains(&v) {
@@ -247,8 +1019,28 @@ impl Config {
             "ui.theme" => match value {
                 "light" => self.ui.theme = Theme::Light,
                 "dark" => self.ui.theme = Theme::Dark,
-                _ => bail!("Invalid theme '{}'. Valid values: light, dark", value),
+                "mono" => self.ui.theme = Theme::Mono,
+                _ => bail!("Invalid theme '{}'. Valid values: light, dark, mono", value),
+            },
+            "ui.auto_theme" => match value {
+                "off" => self.ui.auto_theme = AutoTheme::Off,
+                "schedule" => self.ui.auto_theme = AutoTheme::Schedule,
+                "terminal" => self.ui.auto_theme = AutoTheme::Terminal,
+                _ => bail!(
+                    "Invalid auto_theme '{}'. Valid values: off, schedule, terminal",
+                    value
+                ),
             },
+            "ui.light_theme_at" | "ui.dark_theme_at" => {
+                let value = value.trim();
+                chrono::NaiveTime::parse_from_str(value, "%H:%M")
+                    .context("Expected a time as HH:MM, e.g. 07:30")?;
+                if key == "ui.light_theme_at" {
+                    self.ui.light_theme_at = value.to_string();
+                } else {
+                    self.ui.dark_theme_at = value.to_string();
+                }
+            }
             "ui.compact_mode" => match value {
                 "auto" => self.ui.compact_mode = CompactMode::Auto,
                 "always" => self.ui.compact_mode = CompactMode::Always,
@@ -258,6 +1050,183 @@ impl Config {
                     value
                 ),
             },
+            "ui.read_indicator" => match value {
+                "dot" => self.ui.read_indicator = ReadIndicator::Dot,
+                "bracket" => self.ui.read_indicator = ReadIndicator::Bracket,
+                "none" => self.ui.read_indicator = ReadIndicator::None,
+                _ => bail!(
+                    "Invalid read_indicator '{}'. Valid values: dot, bracket, none",
+                    value
+                ),
+            },
+            "ui.show_snippet" => {
+                let v: bool = value.parse().context("Expected 'true' or 'false'")?;
+                self.ui.show_snippet = v;
+            }
+            "ui.snippet_length" => {
+                let v: usize = value.parse().context("Expected a positive integer")?;
+                if !(20..=500).contains(&v) {
+                    bail!("Value must be between 20 and 500");
+                }
+                self.ui.snippet_length = v;
+            }
+            "ui.show_author" => {
+                let v: bool = value.parse().context("Expected 'true' or 'false'")?;
+                self.ui.show_author = v;
+            }
+            "ui.feed_name" => match value {
+                "above" => self.ui.feed_name = FeedNamePosition::Above,
+                "inline" => self.ui.feed_name = FeedNamePosition::Inline,
+                "hidden" => self.ui.feed_name = FeedNamePosition::Hidden,
+                _ => bail!(
+                    "Invalid feed_name '{}'. Valid values: above, inline, hidden",
+                    value
+                ),
+            },
+            "ui.date_style" => match value {
+                "auto" => self.ui.date_style = DateStyle::Auto,
+                "relative" => self.ui.date_style = DateStyle::Relative,
+                "absolute" => self.ui.date_style = DateStyle::Absolute,
+                "hidden" => self.ui.date_style = DateStyle::Hidden,
+                _ => bail!(
+                    "Invalid date_style '{}'. Valid values: auto, relative, absolute, hidden",
+                    value
+                ),
+            },
+            "ui.reading_width" => {
+                let v: u16 = value.parse().context("Expected a positive integer")?;
+                if !(40..=200).contains(&v) {
+                    bail!("Value must be between 40 and 200");
+                }
+                self.ui.reading_width = v;
+            }
+            "ui.image_art" => {
+                let v: bool = value.parse().context("Expected 'true' or 'false'")?;
+                self.ui.image_art = v;
+            }
+            "ui.image_art_width" => {
+                let v: u16 = value.parse().context("Expected a positive integer")?;
+                if !(8..=200).contains(&v) {
+                    bail!("Value must be between 8 and 200");
+                }
+                self.ui.image_art_width = v;
+            }
+            "ui.image_art_height" => {
+                let v: u16 = value.parse().context("Expected a positive integer")?;
+                if !(4..=100).contains(&v) {
+                    bail!("Value must be between 4 and 100");
+                }
+                self.ui.image_art_height = v;
+            }
+            "ui.status_command" => {
+                let value = value.trim();
+                self.ui.status_command = if value.is_empty() {
+                    None
+                } else {
+                    Some(value.to_string())
+                };
+            }
+            "ui.status_interval" => {
+                let v: u64 = value.parse().context("Expected a positive integer")?;
+                if !(5..=86400).contains(&v) {
+                    bail!("Value must be between 5 and 86400");
+                }
+                self.ui.status_interval = v;
+            }
+            "ui.reading_goal" => {
+                let v: u64 = value.parse().context("Expected a positive integer")?;
+                if v > 1440 {
+                    bail!("Value must be between 0 and 1440");
+                }
+                self.ui.reading_goal = v;
+            }
+            "ui.session_minutes" => {
+                let v: u64 = value.parse().context("Expected a positive integer")?;
+                if !(1..=480).contains(&v) {
+                    bail!("Value must be between 1 and 480");
+                }
+                self.ui.session_minutes = v;
+            }
+            "ui.confirm_quit" => {
+                let v: bool = value.parse().context("Expected 'true' or 'false'")?;
+                self.ui.confirm_quit = v;
+            }
+            "share.template" => {
+                if value.trim().is_empty() {
+                    bail!("Share template cannot be empty");
+                }
+                self.share.template = value.to_string();
+            }
+            "share.command" => {
+                let value = value.trim();
+                self.share.command = if value.is_empty() {
+                    None
+                } else {
+                    Some(value.to_string())
+                };
+            }
+            "tools.translate_command" => {
+                let value = value.trim();
+                self.tools.translate_command = if value.is_empty() {
+                    None
+                } else {
+                    Some(value.to_string())
+                };
+            }
+            "tools.translate_display" => match value {
+                "replace" => self.tools.translate_display = TranslateDisplay::Replace,
+                "side_by_side" => self.tools.translate_display = TranslateDisplay::SideBySide,
+                _ => bail!(
+                    "Invalid translate_display '{}'. Valid values: replace, side_by_side",
+                    value
+                ),
+            },
+            "tools.summarize_command" => {
+                let value = value.trim();
+                self.tools.summarize_command = if value.is_empty() {
+                    None
+                } else {
+                    Some(value.to_string())
+                };
+            }
+            "tools.export_dir" => {
+                let value = value.trim();
+                self.tools.export_dir = if value.is_empty() {
+                    None
+                } else {
+                    Some(value.to_string())
+                };
+            }
+            "tools.pdf_command" => {
+                let value = value.trim();
+                if !value.is_empty() && !value.contains("{input}") {
+                    bail!("PDF command must contain {{input}}");
+                }
+                self.tools.pdf_command = if value.is_empty() {
+                    None
+                } else {
+                    Some(value.to_string())
+                };
+            }
+            "tools.task_command" => {
+                let value = value.trim();
+                self.tools.task_command = if value.is_empty() {
+                    None
+                } else {
+                    Some(value.to_string())
+                };
+            }
+            "tools.calendar_command" => {
+                let value = value.trim();
+                if !value.is_empty() && !value.contains("{file}") {
+                    bail!("Calendar command must contain {{file}}");
+                }
+                self.tools.calendar_command = if value.is_empty() {
+                    None
+                } else {
+                    Some(value.to_string())
+                };
+            }
             k if k.starts_with("default_feeds") => {
                 bail!("Feed management is not supported via CLI. Use 'feedr config --tui' instead.")
             }
@@ -284,6 +1253,9 @@ impl Config {
             let config = Config::default();
 
             // Try to save the default config for future use
+            if crate::globals::read_only() {
+                return Ok(config);
+            }
             if let Err(e) = config.save() {
                 // Don't fail if we can't save, just use defaults
                 eprintln!("Warning: Could not create default config file: {}", e);
@@ -295,6 +1267,7 @@ impl Config {
 
     /// Save configuration to the XDG config directory
     pub fn save(&self) -> Result<()> {
+        crate::globals::ensure_writable("Saving the config")?;
         let config_path = Self::config_path();
 
         // Ensure the parent directory exists
@@ -314,8 +1287,7 @@ impl Config {
 
     /// Get the path to the config file following XDG specifications
     pub fn config_path() -> PathBuf {
-        let mut path = dirs::config_dir().unwrap_or_else(|| Path::new(".").to_path_buf());
-        path.push("feedr");
+        let mut path = crate::globals::app_dir(dirs::config_dir());
         path.push("config.toml");
         path
     }
@@ -334,8 +1306,18 @@ impl Config {
              # Background Refresh Settings:\n\
              # - refresh_enabled: Enable automatic background refresh (default: false)\n\
              # - auto_refresh_interval: Time in seconds between auto-refreshes (default: 0/disabled)\n\
+             # - adaptive_refresh: Refresh each feed on its own schedule, based on how often it posts (default: false)\n\
              # - refresh_rate_limit_delay: Delay in milliseconds between requests to same domain (default: 2000ms)\n\
              #   This prevents \"too many requests\" errors, especially for Reddit feeds\n\
+             # - max_items_per_feed: Keep only the newest N items from each feed (default: 0/all)\n\
+             #   Override it for one feed with max_items in its [[default_feeds]] entry\n\
+             # - prefetch_saved: Download starred and read-later articles for offline reading (default: false)\n\
+             # - open_target: What Enter opens an item in: reader (default), browser, or full_text\n\
+             #   Override it for one feed with open in its [[default_feeds]] entry\n\
+             # - storage: Where read state, subscriptions and categories are saved: json (default) or sqlite\n\
+             # - notify: Which new items send a desktop notification: all, alerts (default) or never\n\
+             #   Override it for one feed with notify in its [[default_feeds]] entry\n\
+             # - quiet_hours: Hold notifications back between two local times, e.g. \"22:00-07:00\"\n\
              #\n\
              # UI Theme Settings:\n\
              # - theme: Choose between \"light\" or \"dark\" theme (default: dark)\n\
@@ -346,10 +1328,86 @@ impl Config {
              # refresh_enabled = true\n\
              # auto_refresh_interval = 300\n\
              # refresh_rate_limit_delay = 2000\n\
+             # metrics_file = \"~/.local/share/node_exporter/feedr.prom\"  # refresh metrics\n\
              #\n\
              # [ui]\n\
              # theme = \"light\"\n\
+             # auto_theme = \"off\"  # off (default), schedule, or terminal\n\
+             # light_theme_at = \"07:00\"  # with auto_theme = \"schedule\", light from 07:00\n\
+             # dark_theme_at = \"19:00\"  # and dark from 19:00\n\
              # compact_mode = \"auto\"  # auto (default), always, or never\n\
+             # read_indicator = \"dot\"  # dot (default), bracket, or none\n\
+             # show_snippet = true  # text preview under each item in a feed's list\n\
+             # snippet_length = 100  # longest preview in characters\n\
+             # show_author = true  # item authors in lists\n\
+             # feed_name = \"above\"  # dashboard feed name: above (default), inline, or hidden\n\
+             # date_style = \"auto\"  # auto (default), relative, absolute, or hidden\n\
+             # reading_width = 80  # text width in zen reading mode ('z' on an article)\n\
+             # image_art = false  # draw lead images as block art, at most\n\
+             # image_art_width = 48  # this many columns\n\
+             # image_art_height = 12  # and this many rows\n\
+             # status_command = \"date +%H:%M\"  # output shown on the right of the title bar\n\
+             # status_interval = 60  # seconds between runs\n\
+             # reading_goal = 30  # daily reading goal in minutes, shown in the title bar\n\
+             # session_minutes = 15  # length of a reading session ('Z')\n\
+             # confirm_quit = true  # ask before quitting with unsaved changes or a refresh running\n\
+             #\n\
+             # Symbols shown instead of the initial badge for feeds from a site (and its subdomains):\n\
+             # [ui.icons]\n\
+             # \"blog.rust-lang.org\" = \"🦀\"\n\
+             # \"github.com\" = \"🐙\"\n\
+             #\n\
+             # Sharing (press 'y' on an article):\n\
+             # [share]\n\
+             # template = '\"{{title}}\" {{url}} via {{feed}}'\n\
+             # command = \"wl-copy\"  # optional; defaults to the system clipboard\n\
+             #\n\
+             # Chat webhooks (press 'Y' to post an article to one):\n\
+             # [[share.webhooks]]\n\
+             # name = \"team\"\n\
+             # url = \"https://hooks.slack.com/services/...\"\n\
+             # template = '{{\"text\": \"<{{url}}|{{title}}> ({{feed}})\"}}'  # JSON body\n\
+             #\n\
+             # External tools (article text is piped to stdin, output read from stdout):\n\
+             # [tools]\n\
+             # translate_command = \"trans -b :en\"  # press 'T' in the detail view\n\
+             # translate_display = \"replace\"  # replace or side_by_side\n\
+             # summarize_command = \"ollama run llama3 'Summarize this article:'\"  # press 'S'\n\
+             # export_dir = \"~/Documents/articles\"  # where 'x' writes articles as HTML\n\
+             # pdf_command = \"weasyprint {{input}} {{output}}\"  # also convert them to PDF\n\
+             # task_command = \"task add {{title}} {{url}} +reading\"  # press 'N' to file a task\n\
+             # calendar_command = \"khal import --batch {{file}}\"  # press 'E' on an event\n\
+             #\n\
+             # Remote OPML lists kept in sync with a category of their own:\n\
+             # [[collections]]\n\
+             # url = \"https://example.com/curated.opml\"\n\
+             # category = \"Curated\"\n\
+             # interval_hours = 24  # how often to re-fetch the list\n\
+             #\n\
+             # Keyword alerts (matches are flagged, listed with 'A' and sent as notifications):\n\
+             # [[alerts]]\n\
+             # pattern = \"rust 2024\"\n\
+             # category = \"Tech\"  # optional scope; or feed = \"https://...\"\n\
+             #\n\
+             # [[alerts]]\n\
+             # name = \"CVE\"\n\
+             # pattern = \"CVE-\\\\d{{4}}-\\\\d+\"\n\
+             # regex = true\n\
+             # notify = false\n\
+             #\n\
+             # Dashboard sections (each is its own block; cycle focus with ']' and '['):\n\
+             # [[dashboard_sections]]\n\
+             # kind = \"unread\"  # latest, unread, starred, today or stale\n\
+             # title = \"Unread highlights\"\n\
+             # limit = 8\n\
+             #\n\
+             # [[dashboard_sections]]\n\
+             # kind = \"today\"\n\
+             # category = \"Tech\"\n\
+             #\n\
+             # [[dashboard_sections]]\n\
+             # kind = \"stale\"\n\
+             # days = 60\n\
              #\n\
              # Example default feeds configuration:\n\
              # [[default_feeds]]\n\
@@ -364,7 +1422,17 @@ impl Config {
              # [[default_feeds]]\n\
              # url = \"https://private.example.com/feed.xml\"\n\
              # [default_feeds.headers]\n\
-             # Authorization = \"Bearer your_token_here\"\n",
+             # Authorization = \"Bearer your_token_here\"\n\
+             #\n\
+             # Display rules example (clean up content before it is shown):\n\
+             # [[default_feeds]]\n\
+             # url = \"https://noisy.example.com/feed\"\n\
+             # [default_feeds.display]\n\
+             # strip_selectors = [\".share-buttons\", \"aside\"]\n\
+             # strip_ranges = [{{ start = \"<!-- ad -->\", end = \"<!-- /ad -->\" }}]\n\
+             # prefer_summary = false\n\
+             # full_text = false  # fetch the linked page instead\n\
+             # comic = false  # show the image large with its hover text (webcomics)\n",
             toml
         )
     }
@@ -403,6 +1471,90 @@ mod tests {
         assert_eq!(headers.get("X-Custom").unwrap(), "value");
     }
 
+    #[test]
+    fn test_default_feed_with_display_rules() {
+        let toml_str = r#"
+            [[default_feeds]]
+            url = "https://noisy.example.com/feed"
+            [default_feeds.display]
+            strip_selectors = [".share-buttons"]
+            strip_ranges = [{ start = "<!-- ad -->", end = "<!-- /ad -->" }]
+            full_text = true
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let rules = config.default_feeds[0].display.as_ref().unwrap();
+        assert_eq!(rules.strip_selectors, vec![".share-buttons"]);
+        assert_eq!(rules.strip_ranges[0].end, "<!-- /ad -->");
+        assert!(rules.full_text);
+        assert!(!rules.prefer_summary);
+        assert!(!rules.comic);
+    }
+
+    #[test]
+    fn test_open_target_per_feed_and_global() {
+        let toml_str = r#"
+            [general]
+            open_target = "full_text"
+
+            [[default_feeds]]
+            url = "https://xkcd.com/rss.xml"
+            open = "browser"
+
+            [[default_feeds]]
+            url = "https://example.com/feed.xml"
+        "#;
+        let mut config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.general.open_target, OpenTarget::FullText);
+        assert_eq!(config.default_feeds[0].open, Some(OpenTarget::Browser));
+        assert_eq!(config.default_feeds[1].open, None);
+
+        config
+            .validate_and_set("general.open_target", "browser")
+            .unwrap();
+        assert_eq!(config.get_value("general.open_target").unwrap(), "browser");
+        assert!(config
+            .validate_and_set("general.open_target", "tab")
+            .is_err());
+    }
+
+    #[test]
+    fn test_notify_levels_and_quiet_hours() {
+        let toml_str = r#"
+            [general]
+            notify = "never"
+            quiet_hours = "22:00-07:00"
+
+            [[default_feeds]]
+            url = "https://example.com/firehose.xml"
+            notify = "alerts"
+        "#;
+        let mut config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.general.notify, NotifyLevel::Never);
+        assert_eq!(config.default_feeds[0].notify, Some(NotifyLevel::Alerts));
+
+        let at = |time: &str| chrono::NaiveTime::parse_from_str(time, "%H:%M").unwrap();
+        assert!(config.general.in_quiet_hours(at("02:00")));
+        assert!(config.general.in_quiet_hours(at("22:00")));
+        assert!(!config.general.in_quiet_hours(at("07:00")));
+        assert!(!config.general.in_quiet_hours(at("12:30")));
+
+        config
+            .validate_and_set("general.quiet_hours", "13:00-14:00")
+            .unwrap();
+        assert!(config.general.in_quiet_hours(at("13:30")));
+        assert!(!config.general.in_quiet_hours(at("02:00")));
+        assert!(config
+            .validate_and_set("general.quiet_hours", "10pm-7am")
+            .is_err());
+        config.validate_and_set("general.quiet_hours", "").unwrap();
+        assert_eq!(config.general.quiet_hours, None);
+        assert!(!config.general.in_quiet_hours(at("13:30")));
+
+        config.validate_and_set("general.notify", "all").unwrap();
+        assert_eq!(config.get_value("general.notify").unwrap(), "all");
+        assert!(config.validate_and_set("general.notify", "some").is_err());
+    }
+
     #[test]
     fn test_config_serialization() {
         let config = Config::default();
@@ -418,4 +1570,195 @@ mod tests {
             deserialized.network.http_timeout
         );
     }
+
+    #[test]
+    fn test_auto_theme_follows_schedule_and_terminal() {
+        let at = |s: &str| chrono::NaiveTime::parse_from_str(s, "%H:%M").unwrap();
+        let mut ui = UiConfig::default();
+        assert_eq!(ui.auto_theme_choice(at("12:00"), None), None);
+
+        ui.auto_theme = AutoTheme::Schedule;
+        assert_eq!(ui.auto_theme_choice(at("12:00"), None), Some(Theme::Light));
+        assert_eq!(ui.auto_theme_choice(at("19:00"), None), Some(Theme::Dark));
+        assert_eq!(ui.auto_theme_choice(at("03:00"), None), Some(Theme::Dark));
+        // Light across midnight
+        ui.light_theme_at = "22:00".to_string();
+        ui.dark_theme_at = "06:00".to_string();
+        assert_eq!(ui.auto_theme_choice(at("01:00"), None), Some(Theme::Light));
+        assert_eq!(ui.auto_theme_choice(at("12:00"), None), Some(Theme::Dark));
+
+        ui.auto_theme = AutoTheme::Terminal;
+        assert_eq!(
+            ui.auto_theme_choice(at("12:00"), Some("15;0")),
+            Some(Theme::Dark)
+        );
+        assert_eq!(
+            ui.auto_theme_choice(at("12:00"), Some("0;default;15")),
+            Some(Theme::Light)
+        );
+        assert_eq!(ui.auto_theme_choice(at("12:00"), Some("default")), None);
+        assert_eq!(ui.auto_theme_choice(at("12:00"), None), None);
+
+        let mut config = Config::default();
+        assert!(config.validate_and_set("ui.dark_theme_at", "20:30").is_ok());
+        assert_eq!(config.ui.dark_theme_at, "20:30");
+        assert!(config.validate_and_set("ui.light_theme_at", "7am").is_err());
+    }
+
+    #[test]
+    fn test_read_indicator_set_and_get() {
+        let mut config = Config::default();
+        assert_eq!(config.get_value("ui.read_indicator").unwrap(), "dot");
+        config
+            .validate_and_set("ui.read_indicator", "bracket")
+            .unwrap();
+        assert_eq!(config.ui.read_indicator, ReadIndicator::Bracket);
+        assert!(config
+            .validate_and_set("ui.read_indicator", "stars")
+            .is_err());
+    }
+
+    #[test]
+    fn test_list_row_settings() {
+        let mut config = Config::default();
+        assert_eq!(config.get_value("ui.snippet_length").unwrap(), "100");
+        assert_eq!(config.get_value("ui.feed_name").unwrap(), "above");
+        assert_eq!(config.get_value("ui.date_style").unwrap(), "auto");
+        config.validate_and_set("ui.show_snippet", "false").unwrap();
+        config.validate_and_set("ui.snippet_length", "60").unwrap();
+        config.validate_and_set("ui.feed_name", "inline").unwrap();
+        config
+            .validate_and_set("ui.date_style", "relative")
+            .unwrap();
+        assert!(!config.ui.show_snippet);
+        assert_eq!(config.ui.snippet_length, 60);
+        assert_eq!(config.ui.feed_name, FeedNamePosition::Inline);
+        assert_eq!(config.ui.date_style, DateStyle::Relative);
+        assert!(config.validate_and_set("ui.snippet_length", "5").is_err());
+        assert!(config.validate_and_set("ui.date_style", "fuzzy").is_err());
+    }
+
+    #[test]
+    fn test_reading_width_range() {
+        let mut config = Config::default();
+        assert_eq!(config.get_value("ui.reading_width").unwrap(), "80");
+        config.validate_and_set("ui.reading_width", "100").unwrap();
+        assert_eq!(config.ui.reading_width, 100);
+        assert!(config.validate_and_set("ui.reading_width", "20").is_err());
+        assert!(config.validate_and_set("ui.reading_width", "wide").is_err());
+    }
+
+    #[test]
+    fn test_share_command_empty_clears() {
+        let mut config = Config::default();
+        config.validate_and_set("share.command", "wl-copy").unwrap();
+        assert_eq!(config.share.command.as_deref(), Some("wl-copy"));
+        config.validate_and_set("share.command", "  ").unwrap();
+        assert_eq!(config.share.command, None);
+        assert!(config.validate_and_set("share.template", "").is_err());
+    }
+
+    #[test]
+    fn test_tools_translate_settings() {
+        let mut config = Config::default();
+        assert_eq!(config.tools.translate_display, TranslateDisplay::Replace);
+        config
+            .validate_and_set("tools.translate_display", "side_by_side")
+            .unwrap();
+        assert_eq!(
+            config.get_value("tools.translate_display").unwrap(),
+            "side_by_side"
+        );
+        assert!(config
+            .validate_and_set("tools.translate_display", "below")
+            .is_err());
+    }
+
+    #[test]
+    fn test_alert_rules_parse_with_defaults() {
+        let toml_str = r#"
+            [[alerts]]
+            pattern = "rust"
+            category = "Tech"
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.alerts.len(), 1);
+        let rule = &config.alerts[0];
+        assert_eq!(rule.category.as_deref(), Some("Tech"));
+        assert!(rule.notify);
+        assert!(!rule.regex);
+    }
+
+    #[test]
+    fn test_dashboard_sections_parse_with_defaults() {
+        let toml_str = r#"
+            [[dashboard_sections]]
+            kind = "today"
+            category = "Tech"
+
+            [[dashboard_sections]]
+            kind = "stale"
+            days = 60
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let sections = &config.dashboard_sections;
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].kind, SectionKind::Today);
+        assert_eq!(sections[0].category.as_deref(), Some("Tech"));
+        assert_eq!(sections[0].limit, 10);
+        assert_eq!(sections[1].days, 60);
+    }
+
+    #[test]
+    fn test_share_webhooks_parse_with_default_template() {
+        let toml_str = r#"
+            [[share.webhooks]]
+            name = "team"
+            url = "https://hooks.example.com/abc"
+
+            [[share.webhooks]]
+            name = "matrix"
+            url = "https://matrix.example.com/hook"
+            template = '{"body": "{title}"}'
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.share.webhooks.len(), 2);
+        assert_eq!(
+            config.share.webhooks[0].template,
+            default_webhook_template()
+        );
+        assert_eq!(config.share.webhooks[1].template, r#"{"body": "{title}"}"#);
+        assert_eq!(config.share.template, default_share_template());
+    }
+
+    #[test]
+    fn test_collections_parse_with_default_interval() {
+        let toml_str = r#"
+            [[collections]]
+            url = "https://example.com/list.opml"
+            category = "Curated"
+        "#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert_eq!(config.collections.len(), 1);
+        assert_eq!(config.collections[0].category, "Curated");
+        assert_eq!(config.collections[0].interval_hours, 24);
+    }
+
+    #[test]
+    fn test_icon_for_matches_domain_and_parents() {
+        let config: Config = toml::from_str(
+            r#"
+            [ui.icons]
+            "rust-lang.org" = "🦀"
+            "www.github.com" = "🐙"
+            "blank.example" = " "
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.ui.icon_for("blog.rust-lang.org"), Some("🦀"));
+        assert_eq!(config.ui.icon_for("Rust-Lang.org"), Some("🦀"));
+        assert_eq!(config.ui.icon_for("github.com"), Some("🐙"));
+        assert_eq!(config.ui.icon_for("blank.example"), None);
+        assert_eq!(config.ui.icon_for("example.com"), None);
+    }
 }
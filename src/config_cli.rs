@@ -24,6 +24,10 @@ pub fn list() -> Result<()> {
             "general.max_dashboard_items",
             "Maximum dashboard items (1-10000)",
         ),
+        (
+            "general.max_items_per_feed",
+            "Newest items kept per feed (0=all, max 10000)",
+        ),
         (
             "general.auto_refresh_interval",
             "Auto-refresh interval in seconds (0=disabled, max 86400)",
@@ -32,22 +36,163 @@ pub fn list() -> Result<()> {
             "general.refresh_enabled",
             "Enable background refresh (true/false)",
         ),
+        (
+            "general.adaptive_refresh",
+            "Schedule feeds by how often they post (true/false)",
+        ),
+        (
+            "general.prefetch_saved",
+            "Download saved articles for offline reading (true/false)",
+        ),
+        (
+            "general.open_target",
+            "What Enter opens items in (reader, browser, full_text)",
+        ),
+        ("general.storage", "Where saved data is kept (json, sqlite)"),
+        (
+            "general.notify",
+            "Which new items send notifications (all, alerts, never)",
+        ),
+        (
+            "general.quiet_hours",
+            "No notifications between these times (HH:MM-HH:MM, empty = off)",
+        ),
         (
             "general.refresh_rate_limit_delay",
             "Rate limit delay in ms between same-domain requests (0-60000)",
         ),
+        (
+            "general.metrics_file",
+            "File refresh metrics are written to (empty = off)",
+        ),
         (
             "network.http_timeout",
             "HTTP request timeout in seconds (1-300)",
         ),
         ("network.user_agent", "User agent string for HTTP requests"),
+        (
+            "network.connect_timeout",
+            "Connect timeout in seconds (0=only http_timeout, max 300)",
+        ),
+        (
+            "network.accept_language",
+            "Accept-Language header for feed requests",
+        ),
+        (
+            "network.http_cache",
+            "Cache fetched feeds on disk (true, false)",
+        ),
+        (
+            "network.cache_max_age",
+            "Seconds a cached feed is reused at startup (0-86400)",
+        ),
+        (
+            "network.metered",
+            "Save bandwidth on a metered connection (true, false)",
+        ),
         ("ui.tick_rate", "UI update tick rate in ms (10-1000)"),
         (
             "ui.error_display_timeout",
             "Error message timeout in ms (500-30000)",
         ),
-        ("ui.theme", "Color theme (light, dark)"),
+        ("ui.theme", "Color theme (light, dark, mono)"),
+        (
+            "ui.auto_theme",
+            "Pick the theme automatically (off, schedule, terminal)",
+        ),
+        ("ui.light_theme_at", "Scheduled switch to light (HH:MM)"),
+        ("ui.dark_theme_at", "Scheduled switch to dark (HH:MM)"),
         ("ui.compact_mode", "Compact mode (auto, always, never)"),
+        (
+            "ui.read_indicator",
+            "Read marker in lists (dot, bracket, none)",
+        ),
+        (
+            "ui.show_snippet",
+            "Show item previews in lists (true/false)",
+        ),
+        (
+            "ui.snippet_length",
+            "Longest item preview in characters (20-500)",
+        ),
+        ("ui.show_author", "Show item authors in lists (true/false)"),
+        (
+            "ui.feed_name",
+            "Dashboard feed name (above, inline, hidden)",
+        ),
+        (
+            "ui.date_style",
+            "Dates in lists (auto, relative, absolute, hidden)",
+        ),
+        (
+            "ui.reading_width",
+            "Zen mode text width in columns (40-200)",
+        ),
+        ("ui.image_art", "Draw lead images as block art (true/false)"),
+        (
+            "ui.image_art_width",
+            "Image art max width in columns (8-200)",
+        ),
+        (
+            "ui.image_art_height",
+            "Image art max height in rows (4-100)",
+        ),
+        (
+            "ui.status_command",
+            "Command whose output is shown in the title bar",
+        ),
+        (
+            "ui.status_interval",
+            "Seconds between status command runs (5-86400)",
+        ),
+        (
+            "ui.reading_goal",
+            "Daily reading goal in minutes (0 = none, max 1440)",
+        ),
+        (
+            "ui.session_minutes",
+            "Minutes of reading in a time-boxed session (1-480)",
+        ),
+        (
+            "ui.confirm_quit",
+            "Ask before quitting with pending changes (true/false)",
+        ),
+        (
+            "share.template",
+            "Share text ({title}, {url}, {feed}, {author}, {date})",
+        ),
+        (
+            "share.command",
+            "Command receiving shared text (empty = clipboard)",
+        ),
+        (
+            "tools.translate_command",
+            "Command translating article text from stdin",
+        ),
+        (
+            "tools.translate_display",
+            "Show translations (replace, side_by_side)",
+        ),
+        (
+            "tools.summarize_command",
+            "Command summarizing article text from stdin",
+        ),
+        (
+            "tools.export_dir",
+            "Directory articles are exported to (empty = data dir)",
+        ),
+        (
+            "tools.pdf_command",
+            "Command converting exports to PDF ({input}, {output})",
+        ),
+        (
+            "tools.task_command",
+            "Command filing an article as a task ({title}, {url}, ...)",
+        ),
+        (
+            "tools.calendar_command",
+            "Command importing an event's .ics file ({file})",
+        ),
     ];
 
     for (key, desc) in keys {
@@ -16,14 +16,18 @@ pub enum ConfigSection {
     General,
     Network,
     Ui,
+    Share,
+    Tools,
     DefaultFeeds,
 }
 
 impl ConfigSection {
-    pub const ALL: [ConfigSection; 4] = [
+    pub const ALL: [ConfigSection; 6] = [
         ConfigSection::General,
         ConfigSection::Network,
         ConfigSection::Ui,
+        ConfigSection::Share,
+        ConfigSection::Tools,
         ConfigSection::DefaultFeeds,
     ];
 
@@ -32,6 +36,8 @@ impl ConfigSection {
             ConfigSection::General => "General",
             ConfigSection::Network => "Network",
             ConfigSection::Ui => "UI",
+            ConfigSection::Share => "Share",
+            ConfigSection::Tools => "Tools",
             ConfigSection::DefaultFeeds => "Default Feeds",
         }
     }
@@ -41,7 +47,9 @@ impl ConfigSection {
             ConfigSection::General => 0,
             ConfigSection::Network => 1,
             ConfigSection::Ui => 2,
-            ConfigSection::DefaultFeeds => 3,
+            ConfigSection::Share => 3,
+            ConfigSection::Tools => 4,
+            ConfigSection::DefaultFeeds => 5,
         }
     }
 
@@ -49,7 +57,9 @@ impl ConfigSection {
         match self {
             ConfigSection::General => 4,
             ConfigSection::Network => 2,
-            ConfigSection::Ui => 4,
+            ConfigSection::Ui => 5,
+            ConfigSection::Share => 2,
+            ConfigSection::Tools => 3,
             ConfigSection::DefaultFeeds => config.default_feeds.len().max(1),
         }
     }
@@ -79,6 +89,13 @@ pub fn get_fields(section: ConfigSection, config: &Config) -> Vec<FieldInfo> {
                 kind: FieldKind::Text,
                 description: "1-10000".into(),
             },
+            FieldInfo {
+                key: "general.max_items_per_feed".into(),
+                label: "Max Items Per Feed".into(),
+                value: config.general.max_items_per_feed.to_string(),
+                kind: FieldKind::Text,
+                description: "0=all, max 10000".into(),
+            },
             FieldInfo {
                 key: "general.auto_refresh_interval".into(),
                 label: "Auto Refresh Interval".into(),
@@ -93,6 +110,48 @@ pub fn get_fields(section: ConfigSection, config: &Config) -> Vec<FieldInfo> {
                 kind: FieldKind::Bool,
                 description: "true/false".into(),
             },
+            FieldInfo {
+                key: "general.adaptive_refresh".into(),
+                label: "Adaptive Refresh".into(),
+                value: config.general.adaptive_refresh.to_string(),
+                kind: FieldKind::Bool,
+                description: "Schedule feeds by how often they post".into(),
+            },
+            FieldInfo {
+                key: "general.prefetch_saved".into(),
+                label: "Prefetch Saved Articles".into(),
+                value: config.general.prefetch_saved.to_string(),
+                kind: FieldKind::Bool,
+                description: "Download starred and read-later articles for offline reading".into(),
+            },
+            FieldInfo {
+                key: "general.open_target".into(),
+                label: "Open Items In".into(),
+                value: config.general.open_target.to_string(),
+                kind: FieldKind::Enum,
+                description: "reader, browser, full_text".into(),
+            },
+            FieldInfo {
+                key: "general.storage".into(),
+                label: "Storage".into(),
+                value: config.general.storage.to_string(),
+                kind: FieldKind::Enum,
+                description: "json, sqlite (takes effect on restart)".into(),
+            },
+            FieldInfo {
+                key: "general.notify".into(),
+                label: "Notify About".into(),
+                value: config.general.notify.to_string(),
+                kind: FieldKind::Enum,
+                description: "all, alerts, never".into(),
+            },
+            FieldInfo {
+                key: "general.quiet_hours".into(),
+                label: "Quiet Hours".into(),
+                value: config.general.quiet_hours.clone().unwrap_or_default(),
+                kind: FieldKind::Text,
+                description: "HH:MM-HH:MM without notifications (empty = off)".into(),
+            },
             FieldInfo {
                 key: "general.refresh_rate_limit_delay".into(),
                 label: "Rate Limit Delay".into(),
@@ -100,6 +159,13 @@ pub fn get_fields(section: ConfigSection, config: &Config) -> Vec<FieldInfo> {
                 kind: FieldKind::Text,
                 description: "Milliseconds (0-60000)".into(),
             },
+            FieldInfo {
+                key: "general.metrics_file".into(),
+                label: "Metrics File".into(),
+                value: config.general.metrics_file.clone().unwrap_or_default(),
+                kind: FieldKind::Text,
+                description: "Prometheus textfile written after refreshes; empty = off".into(),
+            },
         ],
         ConfigSection::Network => vec![
             FieldInfo {
@@ -116,6 +182,41 @@ pub fn get_fields(section: ConfigSection, config: &Config) -> Vec<FieldInfo> {
                 kind: FieldKind::Text,
                 description: "Non-empty string".into(),
             },
+            FieldInfo {
+                key: "network.connect_timeout".into(),
+                label: "Connect Timeout".into(),
+                value: config.network.connect_timeout.to_string(),
+                kind: FieldKind::Text,
+                description: "Seconds (0=use HTTP timeout, max 300)".into(),
+            },
+            FieldInfo {
+                key: "network.accept_language".into(),
+                label: "Accept-Language".into(),
+                value: config.network.accept_language.clone(),
+                kind: FieldKind::Text,
+                description: "e.g. en-US,en;q=0.9".into(),
+            },
+            FieldInfo {
+                key: "network.http_cache".into(),
+                label: "HTTP Cache".into(),
+                value: config.network.http_cache.to_string(),
+                kind: FieldKind::Bool,
+                description: "true/false".into(),
+            },
+            FieldInfo {
+                key: "network.cache_max_age".into(),
+                label: "Cache Max Age".into(),
+                value: config.network.cache_max_age.to_string(),
+                kind: FieldKind::Text,
+                description: "Seconds reused at startup (0=always ask, max 86400)".into(),
+            },
+            FieldInfo {
+                key: "network.metered".into(),
+                label: "Metered Connection".into(),
+                value: config.network.metered.to_string(),
+                kind: FieldKind::Bool,
+                description: "No images, page fetches or background refreshes".into(),
+            },
         ],
         ConfigSection::Ui => vec![
             FieldInfo {
@@ -137,7 +238,28 @@ pub fn get_fields(section: ConfigSection, config: &Config) -> Vec<FieldInfo> {
                 label: "Theme".into(),
                 value: config.ui.theme.to_string(),
                 kind: FieldKind::Enum,
-                description: "light, dark".into(),
+                description: "light, dark, mono".into(),
+            },
+            FieldInfo {
+                key: "ui.auto_theme".into(),
+                label: "Auto Theme".into(),
+                value: config.ui.auto_theme.to_string(),
+                kind: FieldKind::Enum,
+                description: "off, schedule, terminal".into(),
+            },
+            FieldInfo {
+                key: "ui.light_theme_at".into(),
+                label: "Light Theme At".into(),
+                value: config.ui.light_theme_at.clone(),
+                kind: FieldKind::Text,
+                description: "HH:MM, with auto_theme = schedule".into(),
+            },
+            FieldInfo {
+                key: "ui.dark_theme_at".into(),
+                label: "Dark Theme At".into(),
+                value: config.ui.dark_theme_at.clone(),
+                kind: FieldKind::Text,
+                description: "HH:MM, with auto_theme = schedule".into(),
             },
             FieldInfo {
                 key: "ui.compact_mode".into(),
@@ -146,6 +268,178 @@ pub fn get_fields(section: ConfigSection, config: &Config) -> Vec<FieldInfo> {
                 kind: FieldKind::Enum,
                 description: "auto, always, never".into(),
             },
+            FieldInfo {
+                key: "ui.read_indicator".into(),
+                label: "Read Indicator".into(),
+                value: config.ui.read_indicator.to_string(),
+                kind: FieldKind::Enum,
+                description: "dot, bracket, none".into(),
+            },
+            FieldInfo {
+                key: "ui.show_snippet".into(),
+                label: "Show Snippet".into(),
+                value: config.ui.show_snippet.to_string(),
+                kind: FieldKind::Bool,
+                description: "true/false".into(),
+            },
+            FieldInfo {
+                key: "ui.snippet_length".into(),
+                label: "Snippet Length".into(),
+                value: config.ui.snippet_length.to_string(),
+                kind: FieldKind::Text,
+                description: "Characters (20-500)".into(),
+            },
+            FieldInfo {
+                key: "ui.show_author".into(),
+                label: "Show Author".into(),
+                value: config.ui.show_author.to_string(),
+                kind: FieldKind::Bool,
+                description: "true/false".into(),
+            },
+            FieldInfo {
+                key: "ui.feed_name".into(),
+                label: "Feed Name".into(),
+                value: config.ui.feed_name.to_string(),
+                kind: FieldKind::Enum,
+                description: "above, inline, hidden".into(),
+            },
+            FieldInfo {
+                key: "ui.date_style".into(),
+                label: "Date Style".into(),
+                value: config.ui.date_style.to_string(),
+                kind: FieldKind::Enum,
+                description: "auto, relative, absolute, hidden".into(),
+            },
+            FieldInfo {
+                key: "ui.reading_width".into(),
+                label: "Reading Width".into(),
+                value: config.ui.reading_width.to_string(),
+                kind: FieldKind::Text,
+                description: "Zen mode columns (40-200)".into(),
+            },
+            FieldInfo {
+                key: "ui.image_art".into(),
+                label: "Image Art".into(),
+                value: config.ui.image_art.to_string(),
+                kind: FieldKind::Bool,
+                description: "true/false".into(),
+            },
+            FieldInfo {
+                key: "ui.image_art_width".into(),
+                label: "Image Art Width".into(),
+                value: config.ui.image_art_width.to_string(),
+                kind: FieldKind::Text,
+                description: "Columns (8-200)".into(),
+            },
+            FieldInfo {
+                key: "ui.image_art_height".into(),
+                label: "Image Art Height".into(),
+                value: config.ui.image_art_height.to_string(),
+                kind: FieldKind::Text,
+                description: "Rows (4-100)".into(),
+            },
+            FieldInfo {
+                key: "ui.status_command".into(),
+                label: "Status Command".into(),
+                value: config.ui.status_command.clone().unwrap_or_default(),
+                kind: FieldKind::Text,
+                description: "First output line shown in the title bar".into(),
+            },
+            FieldInfo {
+                key: "ui.status_interval".into(),
+                label: "Status Interval".into(),
+                value: config.ui.status_interval.to_string(),
+                kind: FieldKind::Text,
+                description: "Seconds (5-86400)".into(),
+            },
+            FieldInfo {
+                key: "ui.reading_goal".into(),
+                label: "Reading Goal".into(),
+                value: config.ui.reading_goal.to_string(),
+                kind: FieldKind::Text,
+                description: "Minutes a day (0 = none)".into(),
+            },
+            FieldInfo {
+                key: "ui.session_minutes".into(),
+                label: "Session Length".into(),
+                value: config.ui.session_minutes.to_string(),
+                kind: FieldKind::Text,
+                description: "Minutes of reading per session (1-480)".into(),
+            },
+            FieldInfo {
+                key: "ui.confirm_quit".into(),
+                label: "Confirm Quit".into(),
+                value: config.ui.confirm_quit.to_string(),
+                kind: FieldKind::Bool,
+                description: "Ask first when work is pending".into(),
+            },
+        ],
+        ConfigSection::Share => vec![
+            FieldInfo {
+                key: "share.template".into(),
+                label: "Template".into(),
+                value: config.share.template.clone(),
+                kind: FieldKind::Text,
+                description: "{title} {url} {feed} {author} {date}".into(),
+            },
+            FieldInfo {
+                key: "share.command".into(),
+                label: "Command".into(),
+                value: config.share.command.clone().unwrap_or_default(),
+                kind: FieldKind::Text,
+                description: "Receives text on stdin (empty = clipboard)".into(),
+            },
+        ],
+        ConfigSection::Tools => vec![
+            FieldInfo {
+                key: "tools.translate_command".into(),
+                label: "Translate Command".into(),
+                value: config.tools.translate_command.clone().unwrap_or_default(),
+                kind: FieldKind::Text,
+                description: "Article text on stdin, translation on stdout".into(),
+            },
+            FieldInfo {
+                key: "tools.translate_display".into(),
+                label: "Translate Display".into(),
+                value: config.tools.translate_display.to_string(),
+                kind: FieldKind::Enum,
+                description: "replace, side_by_side".into(),
+            },
+            FieldInfo {
+                key: "tools.summarize_command".into(),
+                label: "Summarize Command".into(),
+                value: config.tools.summarize_command.clone().unwrap_or_default(),
+                kind: FieldKind::Text,
+                description: "Article text on stdin, summary on stdout".into(),
+            },
+            FieldInfo {
+                key: "tools.export_dir".into(),
+                label: "Export Directory".into(),
+                value: config.tools.export_dir.clone().unwrap_or_default(),
+                kind: FieldKind::Text,
+                description: "Empty = exports in the data directory".into(),
+            },
+            FieldInfo {
+                key: "tools.pdf_command".into(),
+                label: "PDF Command".into(),
+                value: config.tools.pdf_command.clone().unwrap_or_default(),
+                kind: FieldKind::Text,
+                description: "{input} HTML, {output} PDF; empty = HTML only".into(),
+            },
+            FieldInfo {
+                key: "tools.task_command".into(),
+                label: "Task Command".into(),
+                value: config.tools.task_command.clone().unwrap_or_default(),
+                kind: FieldKind::Text,
+                description: "{title}, {url}, {feed}, {author}, {date}".into(),
+            },
+            FieldInfo {
+                key: "tools.calendar_command".into(),
+                label: "Calendar Command".into(),
+                value: config.tools.calendar_command.clone().unwrap_or_default(),
+                kind: FieldKind::Text,
+                description: "{file} = event .ics; empty = just save it".into(),
+            },
         ],
         ConfigSection::DefaultFeeds => {
             if config.default_feeds.is_empty() {
@@ -267,18 +561,55 @@ impl ConfigEditor {
             }
             FieldKind::Enum => {
                 let new_val = match field.key.as_str() {
-                    "ui.theme" => {
-                        if field.value == "dark" {
-                            "light"
-                        } else {
-                            "dark"
-                        }
-                    }
+                    "ui.theme" => match field.value.as_str() {
+                        "dark" => "light",
+                        "light" => "mono",
+                        _ => "dark",
+                    },
+                    "ui.auto_theme" => match field.value.as_str() {
+                        "off" => "schedule",
+                        "schedule" => "terminal",
+                        _ => "off",
+                    },
                     "ui.compact_mode" => match field.value.as_str() {
                         "auto" => "always",
                         "always" => "never",
                         _ => "auto",
                     },
+                    "ui.read_indicator" => match field.value.as_str() {
+                        "dot" => "bracket",
+                        "bracket" => "none",
+                        _ => "dot",
+                    },
+                    "ui.feed_name" => match field.value.as_str() {
+                        "above" => "inline",
+                        "inline" => "hidden",
+                        _ => "above",
+                    },
+                    "general.open_target" => match field.value.as_str() {
+                        "reader" => "browser",
+                        "browser" => "full_text",
+                        _ => "reader",
+                    },
+                    "general.storage" => match field.value.as_str() {
+                        "json" => "sqlite",
+                        _ => "json",
+                    },
+                    "general.notify" => match field.value.as_str() {
+                        "all" => "alerts",
+                        "alerts" => "never",
+                        _ => "all",
+                    },
+                    "ui.date_style" => match field.value.as_str() {
+                        "auto" => "relative",
+                        "relative" => "absolute",
+                        "absolute" => "hidden",
+                        _ => "auto",
+                    },
+                    "tools.translate_display" => match field.value.as_str() {
+                        "replace" => "side_by_side",
+                        _ => "replace",
+                    },
                     _ => return,
                 };
                 if let Err(e) = self.config.validate_and_set(&field.key, new_val) {
@@ -361,6 +692,16 @@ impl ConfigEditor {
             category,
             headers: None,
             refresh_interval: None,
+            max_items: None,
+            timeout: None,
+            connect_timeout: None,
+            user_agent: None,
+            accept_language: None,
+            hide_from_dashboard: false,
+            display: None,
+            open: None,
+            notify: None,
+            date_formats: Vec::new(),
         });
         self.dirty = true;
         self.adding_feed = false;
@@ -420,7 +761,10 @@ fn run_editor(
     editor: &mut ConfigEditor,
 ) -> Result<()> {
     loop {
-        terminal.draw(|f| config_ui::render(f, editor))?;
+        terminal.draw(|f| {
+            config_ui::render(f, editor);
+            editor.color_scheme.finish(f.buffer_mut());
+        })?;
 
         if event::poll(Duration::from_millis(100))? {
             if let Event::Key(key) = event::read()? {
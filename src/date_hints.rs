@@ -0,0 +1,317 @@
+//! Publication dates for items whose feed leaves them out but whose text gives one away:
+//! "vor 3 Tagen", "il y a 2 heures", "12. März 2024", or whatever format a feed's
+//! `date_formats` hints describe.
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc};
+
+/// Words of an item's title and text searched for a date; dates sit near the top.
+const SCAN_WORDS: usize = 60;
+
+// Words that put a number of units in the past when they come before it
+const PAST_PREFIXES: &[&[&str]] = &[&["vor"], &["il", "y", "a"], &["hace"], &["há"]];
+// ... or after it
+const PAST_SUFFIXES: &[&str] = &["ago", "fa", "geleden", "sedan", "siden"];
+
+const ONE: &[&str] = &[
+    "a", "an", "one", "ein", "eine", "einem", "einer", "einen", "un", "une", "una", "uno", "um",
+    "uma", "een", "één",
+];
+
+const MINUTES: &[&str] = &[
+    "minute", "minutes", "min", "mins", "minuten", "minuto", "minutos", "minuti", "minuut",
+    "minuter", "minutter", "minutt",
+];
+const HOURS: &[&str] = &[
+    "hour", "hours", "hr", "hrs", "stunde", "stunden", "heure", "heures", "hora", "horas", "ora",
+    "ore", "uur", "timme", "timmar",
+];
+const DAYS: &[&str] = &[
+    "day", "days", "tag", "tage", "tagen", "jour", "jours", "día", "días", "dia", "dias", "giorno",
+    "giorni", "dag", "dagen", "dagar",
+];
+const WEEKS: &[&str] = &[
+    "week",
+    "weeks",
+    "woche",
+    "wochen",
+    "semaine",
+    "semaines",
+    "semana",
+    "semanas",
+    "settimana",
+    "settimane",
+    "weken",
+    "veckor",
+];
+const MONTHS: &[&str] = &[
+    "month", "months", "monat", "monate", "monaten", "mois", "mes", "meses", "mese", "mesi",
+    "maand", "maanden",
+];
+const YEARS: &[&str] = &[
+    "year", "years", "jahr", "jahre", "jahren", "an", "ans", "année", "années", "año", "años",
+    "anno", "anni", "ano", "anos", "jaar", "jaren",
+];
+
+const YESTERDAY: &[&str] = &[
+    "yesterday",
+    "gestern",
+    "ayer",
+    "ieri",
+    "ontem",
+    "gisteren",
+    "igår",
+];
+const TODAY: &[&str] = &["today", "heute", "hoy", "oggi", "hoje", "vandaag", "idag"];
+
+// Month names (and common abbreviations) in English, German, French, Spanish, Italian,
+// Portuguese and Dutch, January first
+const MONTH_NAMES: &[&[&str]] = &[
+    &[
+        "january", "jan", "januar", "janvier", "enero", "gennaio", "janeiro", "januari", "jänner",
+    ],
+    &[
+        "february",
+        "feb",
+        "februar",
+        "février",
+        "febrero",
+        "febbraio",
+        "fevereiro",
+        "februari",
+    ],
+    &[
+        "march", "mar", "märz", "mars", "marzo", "março", "maart", "mär",
+    ],
+    &["april", "apr", "avril", "abril", "aprile"],
+    &["may", "mai", "mayo", "maggio", "maio", "mei"],
+    &["june", "jun", "juni", "juin", "junio", "giugno", "junho"],
+    &["july", "jul", "juli", "juillet", "julio", "luglio", "julho"],
+    &["august", "aug", "août", "agosto", "augustus"],
+    &[
+        "september",
+        "sep",
+        "sept",
+        "septembre",
+        "septiembre",
+        "settembre",
+        "setembro",
+    ],
+    &[
+        "october", "oct", "oktober", "octobre", "octubre", "ottobre", "outubro", "okt",
+    ],
+    &["november", "nov", "novembre", "noviembre", "novembro"],
+    &[
+        "december",
+        "dec",
+        "dezember",
+        "décembre",
+        "diciembre",
+        "dicembre",
+        "dezembro",
+        "dez",
+    ],
+];
+
+/// The date `text` gives for an item, as of `now`: first by the feed's `formats`
+/// (chrono format strings such as `"%d.%m.%Y"`), then by the built-in heuristics for
+/// relative ("3 days ago", "vor 3 Tagen") and written-out ("12. März 2024") dates.
+pub fn recover_date(text: &str, formats: &[String], now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let words: Vec<&str> = text.split_whitespace().take(SCAN_WORDS).collect();
+    by_formats(&words, formats)
+        .or_else(|| relative(&words, now))
+        .or_else(|| written_out(&words))
+        // A date in the future is more likely an event than when the item was posted
+        .filter(|date| *date <= now)
+}
+
+// Try each format against runs of one to five words
+fn by_formats(words: &[&str], formats: &[String]) -> Option<DateTime<Utc>> {
+    for format in formats {
+        for start in 0..words.len() {
+            for len in 1..=5.min(words.len() - start) {
+                let candidate = words[start..start + len]
+                    .join(" ")
+                    .trim_matches(|c: char| matches!(c, ',' | ';' | '(' | ')' | '[' | ']'))
+                    .to_string();
+                if let Ok(date) = DateTime::parse_from_str(&candidate, format) {
+                    return Some(date.with_timezone(&Utc));
+                }
+                if let Ok(date) = NaiveDateTime::parse_from_str(&candidate, format) {
+                    return Some(Utc.from_utc_datetime(&date));
+                }
+                if let Ok(date) = NaiveDate::parse_from_str(&candidate, format) {
+                    return midnight(date);
+                }
+            }
+        }
+    }
+    None
+}
+
+// Lowercase words with surrounding punctuation removed
+fn tokens(words: &[&str]) -> Vec<String> {
+    words
+        .iter()
+        .copied()
+        .flat_map(|word| word.split('\''))
+        .map(|word| {
+            word.trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase()
+        })
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+fn unit(word: &str) -> Option<Duration> {
+    if MINUTES.contains(&word) {
+        Some(Duration::minutes(1))
+    } else if HOURS.contains(&word) {
+        Some(Duration::hours(1))
+    } else if DAYS.contains(&word) {
+        Some(Duration::days(1))
+    } else if WEEKS.contains(&word) {
+        Some(Duration::weeks(1))
+    } else if MONTHS.contains(&word) {
+        Some(Duration::days(30))
+    } else if YEARS.contains(&word) {
+        Some(Duration::days(365))
+    } else {
+        None
+    }
+}
+
+fn count(word: &str) -> Option<i64> {
+    if ONE.contains(&word) {
+        return Some(1);
+    }
+    word.parse().ok().filter(|n| (1..=1000).contains(n))
+}
+
+fn relative(words: &[&str], now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let tokens = tokens(words);
+    let amount = |number: Option<&String>, unit_word: Option<&String>| {
+        Some(unit(unit_word?)? * count(number?)? as i32)
+    };
+    for idx in 0..tokens.len() {
+        let word = tokens[idx].as_str();
+        if TODAY.contains(&word) {
+            return Some(now);
+        }
+        if YESTERDAY.contains(&word) {
+            return Some(now - Duration::days(1));
+        }
+        // "vor 3 Tagen", "il y a 2 heures", "hace una semana"
+        for prefix in PAST_PREFIXES {
+            let end = idx + prefix.len();
+            if tokens.get(idx..end).is_some_and(|run| run == *prefix) {
+                if let Some(ago) = amount(tokens.get(end), tokens.get(end + 1)) {
+                    return Some(now - ago);
+                }
+            }
+        }
+        // "3 days ago", "2 giorni fa", "5 minuten geleden"
+        if tokens
+            .get(idx + 2)
+            .is_some_and(|suffix| PAST_SUFFIXES.contains(&suffix.as_str()))
+        {
+            if let Some(ago) = amount(tokens.get(idx), tokens.get(idx + 1)) {
+                return Some(now - ago);
+            }
+        }
+    }
+    None
+}
+
+fn month_number(word: &str) -> Option<u32> {
+    let word = word.trim_end_matches('.');
+    MONTH_NAMES
+        .iter()
+        .position(|names| names.contains(&word))
+        .map(|idx| idx as u32 + 1)
+}
+
+// "12. März 2024", "3 de marzo de 2024", "March 3, 2024"
+fn written_out(words: &[&str]) -> Option<DateTime<Utc>> {
+    let tokens: Vec<String> = tokens(words)
+        .into_iter()
+        .filter(|word| !matches!(word.as_str(), "de" | "del" | "of" | "the"))
+        .collect();
+    let day = |word: &String| word.parse::<u32>().ok().filter(|d| (1..=31).contains(d));
+    let year = |word: &String| {
+        word.parse::<i32>()
+            .ok()
+            .filter(|y| (1990..=2100).contains(y))
+    };
+    tokens.windows(3).find_map(|run| {
+        let (d, m, y) = match (day(&run[0]), month_number(&run[1]), year(&run[2])) {
+            (Some(d), Some(m), Some(y)) => (d, m, y),
+            _ => match (month_number(&run[0]), day(&run[1]), year(&run[2])) {
+                (Some(m), Some(d), Some(y)) => (d, m, y),
+                _ => return None,
+            },
+        };
+        midnight(NaiveDate::from_ymd_opt(y, m, d)?)
+    })
+}
+
+fn midnight(date: NaiveDate) -> Option<DateTime<Utc>> {
+    Some(Utc.from_utc_datetime(&date.and_hms_opt(0, 0, 0)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 6, 15, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_relative_dates_in_several_languages() {
+        let cases = [
+            ("Veröffentlicht vor 3 Tagen von Anna", Duration::days(3)),
+            ("Publié il y a 2 heures", Duration::hours(2)),
+            ("hace una semana", Duration::weeks(1)),
+            ("Posted 5 minutes ago", Duration::minutes(5)),
+            ("Pubblicato 2 giorni fa", Duration::days(2)),
+            ("Gestern, 14:00", Duration::days(1)),
+        ];
+        for (text, ago) in cases {
+            assert_eq!(
+                recover_date(text, &[], now()),
+                Some(now() - ago),
+                "{}",
+                text
+            );
+        }
+        assert_eq!(recover_date("Nothing to see here", &[], now()), None);
+    }
+
+    #[test]
+    fn test_written_out_dates() {
+        let expected = Utc.with_ymd_and_hms(2024, 3, 12, 0, 0, 0).unwrap();
+        assert_eq!(
+            recover_date("Am 12. März 2024 hieß es", &[], now()),
+            Some(expected)
+        );
+        assert_eq!(
+            recover_date("el 12 de marzo de 2024", &[], now()),
+            Some(expected)
+        );
+        assert_eq!(
+            recover_date("Updated March 12, 2024.", &[], now()),
+            Some(expected)
+        );
+        // An announced event isn't a publication date
+        assert_eq!(recover_date("Join us on 1 July 2030", &[], now()), None);
+    }
+
+    #[test]
+    fn test_feed_format_hints_come_first() {
+        let formats = vec!["%d/%m/%Y".to_string()];
+        assert_eq!(
+            recover_date("Stand: 02/05/2024 (vor 3 Tagen)", &formats, now()),
+            Some(Utc.with_ymd_and_hms(2024, 5, 2, 0, 0, 0).unwrap())
+        );
+    }
+}
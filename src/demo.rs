@@ -0,0 +1,127 @@
+use crate::app::App;
+use crate::feed::{Feed, FetchOutcome};
+use anyhow::Result;
+
+/// The bundled fixture feeds `--demo` subscribes to: URL, category and document. The
+/// `.invalid` host never resolves, so nothing in demo mode can reach the network.
+const FEEDS: [(&str, &str, &str); 4] = [
+    (
+        "https://demo.feedr.invalid/rust-weekly.xml",
+        "Tech",
+        include_str!("../fixtures/demo/rust-weekly.xml"),
+    ),
+    (
+        "https://demo.feedr.invalid/ferris-times.xml",
+        "Tech",
+        include_str!("../fixtures/demo/ferris-times.xml"),
+    ),
+    (
+        "https://demo.feedr.invalid/lighthouse-photos.xml",
+        "Life",
+        include_str!("../fixtures/demo/lighthouse-photos.xml"),
+    ),
+    (
+        "https://demo.feedr.invalid/carnet-de-cuisine.xml",
+        "Life",
+        include_str!("../fixtures/demo/carnet-de-cuisine.xml"),
+    ),
+];
+
+// Item state set up so every view has something to show
+const STARRED: [&str; 2] = [
+    "https://demo.feedr.invalid/rust/borrow-checker-async",
+    "https://demo.feedr.invalid/photos/north-cape-fog",
+];
+const READ: [&str; 3] = [
+    "https://demo.feedr.invalid/rust/error-handling",
+    "https://demo.feedr.invalid/times/security-advisory",
+    "https://demo.feedr.invalid/cuisine/tarte-pommes",
+];
+const READ_LATER: [&str; 2] = [
+    "https://demo.feedr.invalid/rust/ratatui-intro",
+    "https://demo.feedr.invalid/times/terminal-renaissance",
+];
+
+/// An app subscribed to the fixture feeds, with categories and some items starred, read
+/// and queued for later. Its config and data live in the demo directory (see
+/// `globals::demo_dir`), so the real ones are left alone.
+pub fn app() -> Result<App> {
+    let mut app = App::new();
+    app.config = crate::config::Config::default();
+    app.bookmarks = FEEDS.iter().map(|(url, _, _)| url.to_string()).collect();
+    for (url, category, _) in FEEDS {
+        let idx = app.category_index_or_create(category)?;
+        app.assign_feed_to_category(url, idx)?;
+    }
+    app.starred_items = STARRED.iter().map(|id| id.to_string()).collect();
+    app.read_items = READ.iter().map(|id| id.to_string()).collect();
+    app.read_later = READ_LATER.iter().map(|id| id.to_string()).collect();
+    app.save_data()?;
+    Ok(app)
+}
+
+/// Load the fixture feeds straight into `app`, as its first refresh would, for rendering
+/// without running the event loop (as the UI snapshot tests do).
+pub fn load_feeds(app: &mut App) {
+    for (url, _, _) in FEEDS {
+        if let Some(Ok(feed)) = fixture(url).map(|outcome| outcome.into_feed(url)) {
+            app.feeds.push(feed);
+        }
+    }
+    app.update_dashboard();
+    app.rebuild_feed_tree();
+}
+
+/// The fixture for `url` as if it had just been fetched, when running with `--demo`.
+/// None outside demo mode, or for URLs that aren't fixtures.
+pub fn outcome(url: &str) -> Option<FetchOutcome> {
+    if !crate::globals::demo() {
+        return None;
+    }
+    fixture(url)
+}
+
+fn fixture(url: &str) -> Option<FetchOutcome> {
+    let (_, _, document) = FEEDS.iter().find(|(fixture, _, _)| *fixture == url)?;
+    Some(Feed::parse_document(url, document.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixtures_parse_deterministically() {
+        let counts: Vec<usize> = FEEDS
+            .iter()
+            .map(|(url, _, _)| fixture(url).unwrap().into_feed(url).unwrap().items.len())
+            .collect();
+        assert_eq!(counts, vec![4, 3, 2, 2]);
+
+        let rust = fixture(FEEDS[0].0).unwrap().into_feed(FEEDS[0].0).unwrap();
+        assert_eq!(rust.title, "Rustacean Weekly");
+        assert_eq!(
+            rust.items[0].link.as_deref(),
+            Some("https://demo.feedr.invalid/rust/borrow-checker-async")
+        );
+        assert!(fixture("https://example.com/feed.xml").is_none());
+        // Outside demo mode real fetches are never replaced
+        assert!(outcome(FEEDS[0].0).is_none());
+    }
+
+    #[test]
+    fn test_demo_state_refers_to_fixture_items() {
+        let links: Vec<String> = FEEDS
+            .iter()
+            .flat_map(|(url, _, _)| fixture(url).unwrap().into_feed(url).unwrap().items)
+            .filter_map(|item| item.link)
+            .collect();
+        for id in STARRED.iter().chain(&READ).chain(&READ_LATER) {
+            assert!(
+                links.iter().any(|link| link == id),
+                "{} isn't a fixture item",
+                id
+            );
+        }
+    }
+}
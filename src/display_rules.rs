@@ -0,0 +1,131 @@
+use crate::config::DisplayRules;
+use regex::Regex;
+use scraper::{Html, Selector};
+
+/// A feed's strip rules with the patterns and selectors compiled, built once when the
+/// config is loaded.
+#[derive(Debug, Clone, Default)]
+pub struct StripRules {
+    ranges: Vec<(Regex, Regex)>,
+    selectors: Vec<Selector>,
+}
+
+/// Compile a feed's strip rules, returning the usable ones and a description of each
+/// selector or pattern that won't compile.
+pub fn compile(rules: &DisplayRules) -> (StripRules, Vec<String>) {
+    let mut strip = StripRules::default();
+    let mut warnings = Vec::new();
+    for selector in &rules.strip_selectors {
+        match Selector::parse(selector) {
+            Ok(parsed) => strip.selectors.push(parsed),
+            Err(_) => warnings.push(format!("invalid selector '{}'", selector)),
+        }
+    }
+    for range in &rules.strip_ranges {
+        let [start, end] = [&range.start, &range.end]
+            .map(|p| Regex::new(p).map_err(|e| format!("invalid regex '{}': {}", p, e)));
+        match (start, end) {
+            (Ok(start), Ok(end)) => strip.ranges.push((start, end)),
+            (start, end) => warnings.extend(start.err().into_iter().chain(end.err())),
+        }
+    }
+    (strip, warnings)
+}
+
+/// Apply a feed's strip rules to article HTML: regex ranges are cut from the raw HTML
+/// first, then elements matching the strip selectors are removed.
+pub fn apply(rules: &StripRules, html: &str) -> String {
+    let mut html = html.to_string();
+    for (start, end) in &rules.ranges {
+        html = strip_range(&html, start, end);
+    }
+    if rules.selectors.is_empty() {
+        return html;
+    }
+
+    let mut document = Html::parse_fragment(&html);
+    let matched: Vec<_> = rules
+        .selectors
+        .iter()
+        .flat_map(|selector| document.select(selector).map(|el| el.id()))
+        .collect();
+    if matched.is_empty() {
+        return html;
+    }
+    for id in matched {
+        if let Some(mut node) = document.tree.get_mut(id) {
+            node.detach();
+        }
+    }
+    document.root_element().inner_html()
+}
+
+/// Remove every span from a `start` match through the next `end` match. A start
+/// without a matching end cuts to the end of the content.
+fn strip_range(html: &str, start: &Regex, end: &Regex) -> String {
+    let mut out = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(open) = start.find(rest) {
+        out.push_str(&rest[..open.start()]);
+        rest = &rest[open.end()..];
+        match end.find(rest) {
+            Some(close) => rest = &rest[close.end()..],
+            None => return out,
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::StripRange;
+
+    #[test]
+    fn test_apply_strips_selectors_and_ranges() {
+        let rules = DisplayRules {
+            strip_selectors: vec![".share-buttons".to_string(), "aside".to_string()],
+            strip_ranges: vec![StripRange {
+                start: "<!-- ad -->".to_string(),
+                end: "<!-- /ad -->".to_string(),
+            }],
+            ..Default::default()
+        };
+        let html = "<p>Keep</p><!-- ad --><p>Buy now</p><!-- /ad -->\
+                    <div class=\"share-buttons\">Share</div><aside>Related</aside><p>Also keep</p>";
+
+        let (strip, warnings) = compile(&rules);
+        assert!(warnings.is_empty());
+        let out = apply(&strip, html);
+        assert!(out.contains("Keep") && out.contains("Also keep"));
+        assert!(!out.contains("Buy now"));
+        assert!(!out.contains("Share"));
+        assert!(!out.contains("Related"));
+    }
+
+    #[test]
+    fn test_strip_range_without_end_cuts_to_end() {
+        let start = Regex::new("The post .* appeared first").unwrap();
+        let end = Regex::new("never-matches").unwrap();
+        assert_eq!(
+            strip_range("Body. The post X appeared first on Y.", &start, &end),
+            "Body. "
+        );
+    }
+
+    #[test]
+    fn test_compile_reports_bad_patterns() {
+        let rules = DisplayRules {
+            strip_selectors: vec!["div..x".to_string()],
+            strip_ranges: vec![StripRange {
+                start: "(".to_string(),
+                end: "ok".to_string(),
+            }],
+            ..Default::default()
+        };
+        let (strip, warnings) = compile(&rules);
+        assert_eq!(warnings.len(), 2);
+        assert!(strip.ranges.is_empty() && strip.selectors.is_empty());
+    }
+}
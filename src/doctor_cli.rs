@@ -0,0 +1,315 @@
+use crate::app::App;
+use crate::feed::{Feed, FeedFetchResult, RequestOptions};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use std::collections::HashMap;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::sync::mpsc;
+use std::time::Duration;
+use url::Url;
+
+/// Feeds without a post for this long are reported as stale.
+const STALE_AFTER_DAYS: i64 = 365;
+const MAX_REDIRECTS: usize = 10;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Issue {
+    Unreachable(String),
+    PermanentRedirect(String),
+    ParseFailed(String),
+    NotAFeed(Option<String>),
+    Duplicate(String),
+    Stale(Option<DateTime<Utc>>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Fix {
+    UpdateUrl(String),
+    Remove,
+}
+
+impl Issue {
+    fn describe(&self) -> String {
+        match self {
+            Issue::Unreachable(e) => format!("unreachable: {}", e),
+            Issue::PermanentRedirect(to) => format!("permanently redirects to {}", to),
+            Issue::ParseFailed(e) => format!("not parseable as RSS/Atom: {}", e),
+            Issue::NotAFeed(Some(found)) => format!("is a web page; it links to feed {}", found),
+            Issue::NotAFeed(None) => "is a web page with no feed links".to_string(),
+            Issue::Duplicate(of) => format!("duplicate of {}", of),
+            Issue::Stale(Some(date)) => {
+                format!("no posts since {}", date.format("%B %d, %Y"))
+            }
+            Issue::Stale(None) => "feed has no posts".to_string(),
+        }
+    }
+
+    /// The fix `--fix` applies without asking; only changes that can't lose a subscription.
+    fn safe_fix(&self) -> Option<Fix> {
+        match self {
+            Issue::PermanentRedirect(to) => Some(Fix::UpdateUrl(to.clone())),
+            Issue::NotAFeed(Some(found)) => Some(Fix::UpdateUrl(found.clone())),
+            Issue::Duplicate(_) => Some(Fix::Remove),
+            _ => None,
+        }
+    }
+}
+
+/// Check every bookmark and print a report. With `fix`, safe fixes are applied
+/// automatically; otherwise, on a terminal, each fixable issue is offered interactively.
+pub fn run(fix: bool) -> Result<()> {
+    let mut app = App::new();
+    if app.bookmarks.is_empty() {
+        println!("No subscriptions to check.");
+        return Ok(());
+    }
+
+    println!("Checking {} feeds...\n", app.bookmarks.len());
+    let mut report = duplicate_issues(&app.bookmarks);
+    for (url, issues) in check_all(&app) {
+        report.entry(url).or_default().extend(issues);
+    }
+
+    let mut problem_count = 0;
+    let mut fixes: Vec<(String, Fix)> = Vec::new();
+    let interactive = !fix && io::stdin().is_terminal();
+
+    // Keep report order stable by following bookmark order
+    for url in app.bookmarks.clone() {
+        let Some(issues) = report.get(&url).filter(|i| !i.is_empty()) else {
+            continue;
+        };
+        println!("\u{2717} {}", url);
+        for issue in issues {
+            problem_count += 1;
+            println!("    {}", issue.describe());
+        }
+
+        // One fix per feed: the first issue that has a safe fix wins
+        let suggested = issues.iter().find_map(Issue::safe_fix);
+        let chosen = if fix {
+            suggested
+        } else if interactive {
+            prompt_fix(suggested)?
+        } else {
+            None
+        };
+        if let Some(chosen) = chosen {
+            fixes.push((url.clone(), chosen));
+        }
+    }
+
+    println!(
+        "\n{} feeds checked, {} issue{} found",
+        app.bookmarks.len(),
+        problem_count,
+        if problem_count == 1 { "" } else { "s" }
+    );
+
+    if fixes.is_empty() {
+        if problem_count > 0 && !fix && !interactive {
+            println!("Run `feedr doctor --fix` to apply safe fixes (redirects, duplicates).");
+        }
+        return Ok(());
+    }
+
+    for (url, chosen) in &fixes {
+        match chosen {
+            Fix::UpdateUrl(new) => {
+                if app.replace_bookmark_url(url, new) {
+                    println!("Updated {} -> {}", url, new);
+                }
+            }
+            Fix::Remove => {
+                if app.remove_bookmark(url) {
+                    println!("Removed {}", url);
+                }
+            }
+        }
+    }
+    app.save_data()
+        .context("Failed to save fixed subscriptions")?;
+    Ok(())
+}
+
+/// Ask what to do about one feed. Enter accepts the suggested fix.
+fn prompt_fix(suggested: Option<Fix>) -> Result<Option<Fix>> {
+    let prompt = match &suggested {
+        Some(Fix::UpdateUrl(new)) => format!("    Update URL to {}? [Y/n/r(emove)] ", new),
+        Some(Fix::Remove) => "    Remove this subscription? [Y/n] ".to_string(),
+        None => "    Remove this subscription? [y/N] ".to_string(),
+    };
+    print!("{}", prompt);
+    io::stdout().flush()?;
+
+    let mut answer = String::new();
+    io::stdin().lock().read_line(&mut answer)?;
+    let answer = answer.trim().to_lowercase();
+    Ok(match (answer.as_str(), suggested) {
+        ("r", _) => Some(Fix::Remove),
+        ("" | "y", Some(fix)) => Some(fix),
+        ("y", None) => Some(Fix::Remove),
+        _ => None,
+    })
+}
+
+/// Canonical form used to spot the same feed subscribed under cosmetically different URLs.
+fn normalize_url(url: &str) -> String {
+    match Url::parse(url) {
+        Ok(parsed) => {
+            let host = parsed.host_str().unwrap_or("").trim_start_matches("www.");
+            let path = parsed.path().trim_end_matches('/');
+            match parsed.query() {
+                Some(q) => format!("{}{}?{}", host, path, q),
+                None => format!("{}{}", host, path),
+            }
+        }
+        Err(_) => url.trim_end_matches('/').to_lowercase(),
+    }
+}
+
+fn duplicate_issues(bookmarks: &[String]) -> HashMap<String, Vec<Issue>> {
+    let mut seen: HashMap<String, &String> = HashMap::new();
+    let mut report: HashMap<String, Vec<Issue>> = HashMap::new();
+    for url in bookmarks {
+        match seen.get(&normalize_url(url)) {
+            Some(first) => report
+                .entry(url.clone())
+                .or_default()
+                .push(Issue::Duplicate((*first).clone())),
+            None => {
+                seen.insert(normalize_url(url), url);
+            }
+        }
+    }
+    report
+}
+
+/// Run network checks for all bookmarks in parallel, one thread per feed.
+fn check_all(app: &App) -> Vec<(String, Vec<Issue>)> {
+    let (tx, rx) = mpsc::channel();
+    let timeout = app.config.network.http_timeout;
+    for url in &app.bookmarks {
+        let tx = tx.clone();
+        let url = url.clone();
+        let mut options = app.request_options(&url);
+        // A cached copy would hide exactly the problems this is looking for
+        options.cache = None;
+        std::thread::spawn(move || {
+            let issues = check_feed(&url, timeout, &options);
+            let _ = tx.send((url, issues));
+        });
+    }
+    drop(tx);
+    rx.into_iter().collect()
+}
+
+fn check_feed(url: &str, timeout: u64, options: &RequestOptions) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let timeout = options.timeout.unwrap_or(timeout);
+    let user_agent = options.user_agent.as_deref().unwrap_or_default();
+
+    let final_url = match follow_redirects(url, timeout, user_agent) {
+        Ok((final_url, permanent)) => {
+            if permanent && final_url != url {
+                issues.push(Issue::PermanentRedirect(final_url.clone()));
+            }
+            final_url
+        }
+        Err(e) => {
+            issues.push(Issue::Unreachable(format!("{:#}", e)));
+            return issues;
+        }
+    };
+
+    let fetched = Feed::build_client(timeout)
+        .and_then(|client| Feed::fetch_url(&final_url, &client, options));
+    match fetched {
+        Ok(FeedFetchResult::Feed(feed)) => {
+            let latest = feed.items.iter().filter_map(|i| i.parsed_date).max();
+            let cutoff = Utc::now() - ChronoDuration::days(STALE_AFTER_DAYS);
+            // Undated items can't prove staleness, so only flag feeds that are empty or provably old
+            if feed.items.is_empty() || latest.is_some_and(|d| d < cutoff) {
+                issues.push(Issue::Stale(latest));
+            }
+        }
+        Ok(FeedFetchResult::DiscoveredFeeds { feeds, .. }) => {
+            issues.push(Issue::NotAFeed(feeds.first().map(|f| f.url.clone())));
+        }
+        Err(e) => issues.push(Issue::ParseFailed(e.to_string())),
+    }
+    issues
+}
+
+/// Follow redirects by hand so permanent (301/308) hops can be told apart from temporary ones.
+/// Returns the final URL and whether every hop taken was permanent.
+fn follow_redirects(url: &str, timeout: u64, user_agent: &str) -> Result<(String, bool)> {
+    let client = reqwest::blocking::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .timeout(Duration::from_secs(timeout))
+        .build()?;
+
+    let mut current = url.to_string();
+    let mut all_permanent = true;
+    for _ in 0..MAX_REDIRECTS {
+        let response = client
+            .get(&current)
+            .header("User-Agent", user_agent)
+            .send()?;
+        let status = response.status();
+        if !status.is_redirection() {
+            // Only the status matters here; the body is fetched again by the feed parser
+            return Ok((current, all_permanent));
+        }
+        let location = response
+            .headers()
+            .get(reqwest::header::LOCATION)
+            .and_then(|v| v.to_str().ok())
+            .context("redirect without a Location header")?;
+        current = Url::parse(&current)?.join(location)?.to_string();
+        all_permanent &= matches!(status.as_u16(), 301 | 308);
+    }
+    anyhow::bail!("more than {} redirects", MAX_REDIRECTS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_url_ignores_cosmetic_differences() {
+        assert_eq!(
+            normalize_url("https://www.example.com/feed/"),
+            normalize_url("http://example.com/feed")
+        );
+        assert_ne!(
+            normalize_url("https://example.com/feed?tag=a"),
+            normalize_url("https://example.com/feed?tag=b")
+        );
+    }
+
+    #[test]
+    fn test_duplicate_issues_points_at_first_subscription() {
+        let bookmarks = vec![
+            "https://example.com/feed".to_string(),
+            "https://other.com/rss".to_string(),
+            "http://www.example.com/feed/".to_string(),
+        ];
+        let report = duplicate_issues(&bookmarks);
+        assert_eq!(report.len(), 1);
+        assert_eq!(
+            report["http://www.example.com/feed/"],
+            vec![Issue::Duplicate("https://example.com/feed".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_safe_fixes_never_remove_unique_feeds() {
+        assert_eq!(Issue::Unreachable("x".into()).safe_fix(), None);
+        assert_eq!(Issue::Stale(None).safe_fix(), None);
+        assert_eq!(
+            Issue::PermanentRedirect("https://new".into()).safe_fix(),
+            Some(Fix::UpdateUrl("https://new".into()))
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use crate::app::App;
+use crate::list_cli::{self, Subscription};
+use anyhow::Result;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+struct DumpedFeed {
+    #[serde(flatten)]
+    subscription: Subscription,
+    items: Vec<DumpedItem>,
+}
+
+#[derive(Debug, Serialize)]
+struct DumpedItem {
+    id: String,
+    title: String,
+    link: Option<String>,
+    author: Option<String>,
+    published: Option<String>,
+    read: bool,
+    starred: bool,
+}
+
+/// Fetch every subscription and print feeds and their items as JSON, with read and
+/// starred state, for scripts and backups. With `unread_only`, read items are left out.
+pub fn run(unread_only: bool) -> Result<()> {
+    let mut app = App::new();
+    app.refresh_feeds()?;
+    for (url, error) in &app.failed_feeds {
+        eprintln!("Failed to fetch {}: {}", url, error);
+    }
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&dump(&app, unread_only))?
+    );
+    Ok(())
+}
+
+fn dump(app: &App, unread_only: bool) -> Vec<DumpedFeed> {
+    list_cli::subscriptions(app)
+        .into_iter()
+        .map(|subscription| {
+            let items = match app.feeds.iter().position(|f| f.url == subscription.url) {
+                Some(feed_idx) => app.feeds[feed_idx]
+                    .items
+                    .iter()
+                    .enumerate()
+                    .filter(|&(item_idx, _)| !unread_only || !app.is_item_read(feed_idx, item_idx))
+                    .map(|(item_idx, item)| DumpedItem {
+                        id: app.get_item_id(feed_idx, item_idx),
+                        title: item.title.clone(),
+                        link: item.link.clone(),
+                        author: item.author.clone(),
+                        published: item.parsed_date.map(|d| d.to_rfc3339()),
+                        read: app.is_item_read(feed_idx, item_idx),
+                        starred: app.is_item_starred(feed_idx, item_idx),
+                    })
+                    .collect(),
+                None => Vec::new(),
+            };
+            DumpedFeed {
+                subscription,
+                items,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feed::{Feed, FeedItem};
+
+    fn item(title: &str) -> FeedItem {
+        FeedItem {
+            link: Some(format!("https://example.com/{}", title.to_lowercase())),
+            ..FeedItem::with_title(title)
+        }
+    }
+
+    #[test]
+    fn test_dump_reports_item_state_and_skips_read_when_asked() {
+        let mut app = App::new();
+        app.bookmarks = vec![
+            "https://example.com/feed1".to_string(),
+            "https://example.com/feed2".to_string(),
+        ];
+        app.feed_titles.clear();
+        app.categories.clear();
+        app.feeds = vec![Feed {
+            url: "https://example.com/feed1".to_string(),
+            title: "Feed One".to_string(),
+            title_lower: "feed one".to_string(),
+            description: None,
+            updated: None,
+            link: None,
+            items: vec![item("Read"), item("Unread")],
+        }];
+        app.read_items = ["https://example.com/read".to_string()].into();
+        app.starred_items.clear();
+
+        let all = dump(&app, false);
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[0].subscription.title.as_deref(), Some("Feed One"));
+        assert_eq!(all[0].items.len(), 2);
+        assert!(all[0].items[0].read);
+        assert!(
+            all[1].items.is_empty(),
+            "feed that didn't load has no items"
+        );
+
+        let unread = dump(&app, true);
+        assert_eq!(unread[0].items.len(), 1);
+        assert_eq!(unread[0].items[0].title, "Unread");
+    }
+}
@@ -0,0 +1,147 @@
+//! Undo and redo for edits to the subscription tree: creating, renaming and deleting
+//! categories, filing feeds into them and removing feeds. Each edit records enough to be
+//! reversed and replayed; `App` applies them.
+
+use crate::app::FeedEngagement;
+use crate::feed::{Feed, FeedCategory};
+
+/// Edits kept for undoing, oldest dropped first.
+pub const MAX_EDITS: usize = 100;
+
+/// A change to the categories or subscriptions. Categories are identified by id, since
+/// their positions shift as others come and go.
+#[derive(Clone, Debug)]
+pub enum TreeEdit {
+    CreateCategory(FeedCategory),
+    RenameCategory {
+        id: String,
+        from: String,
+        to: String,
+    },
+    DeleteCategory {
+        index: usize,
+        category: FeedCategory,
+    },
+    AssignFeed {
+        category_id: String,
+        url: String,
+    },
+    UnassignFeed {
+        category_id: String,
+        url: String,
+    },
+    RemoveFeed(Box<RemovedFeed>),
+}
+
+/// Everything removing a feed dropped, to put it back as it was.
+#[derive(Clone, Debug)]
+pub struct RemovedFeed {
+    pub url: String,
+    /// Position in the bookmark list; None for an archived feed
+    pub bookmark_index: Option<usize>,
+    pub feed_index: usize,
+    pub feed: Feed,
+    pub category_ids: Vec<String>,
+    pub title: Option<String>,
+    pub engagement: Option<FeedEngagement>,
+    pub archived: bool,
+}
+
+impl TreeEdit {
+    /// What the edit did, e.g. "deleted category News", for the undo/redo message.
+    pub fn describe(&self) -> String {
+        match self {
+            TreeEdit::CreateCategory(category) => format!("created category {}", category.name),
+            TreeEdit::RenameCategory { from, to, .. } => {
+                format!("renamed category {} to {}", from, to)
+            }
+            TreeEdit::DeleteCategory { category, .. } => {
+                format!("deleted category {}", category.name)
+            }
+            TreeEdit::AssignFeed { url, .. } => format!("filed {} into a category", url),
+            TreeEdit::UnassignFeed { url, .. } => format!("took {} out of a category", url),
+            TreeEdit::RemoveFeed(removed) => format!("removed {}", removed.feed.title),
+        }
+    }
+}
+
+/// The undo and redo stacks.
+#[derive(Clone, Debug, Default)]
+pub struct EditHistory {
+    undo: Vec<TreeEdit>,
+    redo: Vec<TreeEdit>,
+}
+
+impl EditHistory {
+    /// Remember a new edit. Anything undone before it can no longer be redone.
+    pub fn record(&mut self, edit: TreeEdit) {
+        self.redo.clear();
+        self.undo.push(edit);
+        if self.undo.len() > MAX_EDITS {
+            self.undo.remove(0);
+        }
+    }
+
+    /// The edit to undo next, moved over to the redo stack.
+    pub fn undo(&mut self) -> Option<TreeEdit> {
+        let edit = self.undo.pop()?;
+        self.redo.push(edit.clone());
+        Some(edit)
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// The edit to redo next, moved back to the undo stack.
+    pub fn redo(&mut self) -> Option<TreeEdit> {
+        let edit = self.redo.pop()?;
+        self.undo.push(edit.clone());
+        Some(edit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rename(to: &str) -> TreeEdit {
+        TreeEdit::RenameCategory {
+            id: "id".to_string(),
+            from: "Old".to_string(),
+            to: to.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_new_edit_clears_redo() {
+        let mut history = EditHistory::default();
+        history.record(rename("A"));
+        history.record(rename("B"));
+        assert!(matches!(history.undo(), Some(TreeEdit::RenameCategory { to, .. }) if to == "B"));
+        assert!(matches!(history.redo(), Some(TreeEdit::RenameCategory { to, .. }) if to == "B"));
+        history.undo();
+        history.record(rename("C"));
+        assert!(history.redo().is_none());
+        assert!(matches!(history.undo(), Some(TreeEdit::RenameCategory { to, .. }) if to == "C"));
+        assert!(matches!(history.undo(), Some(TreeEdit::RenameCategory { to, .. }) if to == "A"));
+        assert!(history.undo().is_none());
+    }
+
+    #[test]
+    fn test_history_is_capped() {
+        let mut history = EditHistory::default();
+        for i in 0..MAX_EDITS + 5 {
+            history.record(rename(&i.to_string()));
+        }
+        let mut count = 0;
+        while history.undo().is_some() {
+            count += 1;
+        }
+        assert_eq!(count, MAX_EDITS);
+    }
+}
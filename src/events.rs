@@ -11,11 +11,20 @@
 //   - CategoryManagement: all keys (n/e/d/Enter/Space/r/j/k/q/Esc/?)
 //   - FilterMode: all filter-cycling keys (c/t/a/r/s/l/x/Esc)
 //   - SelectDiscoveredFeed: j/k/Enter/Esc
+//   - PreviewFeed: Enter/s (subscribe), c/Tab (category) and Esc/q (cancel)
 //   - All text input modes (InsertUrl, SearchMode, CategoryNameInput)
-//   - Detail view: g/G and Ctrl+u/Ctrl+d for scrolling
-
-use crate::app::{AddFeedResult, App, CategoryAction, InputMode, TimeFilter, TreeItem, View};
+//   - Detail view: g/G and Ctrl+u/Ctrl+d for scrolling, Left/Right for horizontal scrolling
+//   - Copy mode: h/l/Left/Right/0/$/Home, Ctrl+v, y/Enter and Esc
+//   - Triage: l (read later), x (mute source), n/Right (skip), Left (previous) and Esc
+//   - Read later: K/J (move up/down) and T/B (move to top/bottom)
+
+use crate::app::{
+    AddFeedResult, App, CategoryAction, ContextMenu, InputMode, MenuCommand, QueueMove,
+    SelectionKind, TimeFilter, TreeItem, TriageAction, View,
+};
+use crate::feed::FeedFetchResult;
 use crate::keybindings::KeyAction;
+use crate::path_input::{PathInput, PathKind};
 use anyhow::Result;
 use crossterm::event::{
     self, Event, KeyCode, KeyEventKind, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
@@ -24,6 +33,60 @@ use crossterm::event::{
 // ── Shared action helpers ──────────────────────────────────────────
 // These eliminate duplicated blocks that were identical across views.
 
+/// Show why `what` can't be done in a `--read-only` run and return true, so dialogs
+/// for edits that would be refused don't open at all.
+fn refused_read_only(app: &mut App, what: &str) -> bool {
+    match crate::globals::ensure_writable(what) {
+        Ok(()) => false,
+        Err(e) => {
+            app.error = Some(e.to_string());
+            true
+        }
+    }
+}
+
+/// Undo the last category or subscription edit, or redo the last undone one.
+fn handle_undo(app: &mut App, redo: bool) {
+    let edit = if redo {
+        app.redo_edit()
+    } else {
+        app.undo_edit()
+    };
+    match edit {
+        Some(edit) => {
+            let verb = if redo { "Redid" } else { "Undid" };
+            app.success_message = Some(format!("{}: {}", verb, edit));
+            app.success_message_time = Some(std::time::Instant::now());
+        }
+        None => app.error = Some(format!("Nothing to {}", if redo { "redo" } else { "undo" })),
+    }
+}
+
+/// Archive the feed at `feed_idx`, or resubscribe to it if it's already archived.
+fn handle_toggle_archived(app: &mut App, feed_idx: usize) {
+    let Some(feed) = app.feeds.get(feed_idx) else {
+        return;
+    };
+    let title = feed.title.clone();
+    let archived = app.archived_feeds.contains(&feed.url);
+    let result = if archived {
+        app.unarchive_feed(feed_idx)
+    } else {
+        app.archive_feed(feed_idx)
+    };
+    match result {
+        Ok(()) => {
+            app.success_message = Some(if archived {
+                format!("\u{2713} Resubscribed to {}", title)
+            } else {
+                format!("\u{2713} Archived {}", title)
+            });
+            app.success_message_time = Some(std::time::Instant::now());
+        }
+        Err(e) => app.error = Some(format!("Failed to archive feed: {}", e)),
+    }
+}
+
 fn handle_toggle_theme(app: &mut App) {
     if let Err(e) = app.toggle_theme() {
         app.error = Some(format!("Failed to toggle theme: {}", e));
@@ -44,6 +107,39 @@ fn handle_open_search(app: &mut App) {
     app.input_mode = InputMode::SearchMode;
 }
 
+fn handle_catch_up(app: &mut App) {
+    match app.catch_up() {
+        Ok(true) => {}
+        Ok(false) => {
+            app.success_message = Some("Nothing unread to catch up on".to_string());
+            app.success_message_time = Some(std::time::Instant::now());
+        }
+        Err(e) => app.error = Some(format!("Failed to mark item as read: {}", e)),
+    }
+}
+
+fn handle_reading_session(app: &mut App) {
+    let minutes = app.config.ui.session_minutes as usize;
+    match app.start_reading_session(minutes) {
+        Ok(true) => {}
+        Ok(false) => {
+            app.success_message = Some(format!("No unread articles fit in {} min", minutes));
+            app.success_message_time = Some(std::time::Instant::now());
+        }
+        Err(e) => app.error = Some(format!("Failed to mark item as read: {}", e)),
+    }
+}
+
+/// Whether to quit now; with pending work and `ui.confirm_quit` on, the quit prompt opens
+/// instead.
+fn handle_quit(app: &mut App) -> bool {
+    if app.should_confirm_quit() {
+        app.show_quit_prompt = true;
+        return false;
+    }
+    true
+}
+
 fn handle_show_help(app: &mut App) {
     app.show_help_overlay = true;
     app.help_overlay_scroll = 0;
@@ -69,6 +165,156 @@ fn handle_toggle_star_current(app: &mut App) {
     }
 }
 
+fn handle_share(app: &mut App) {
+    match app.share_selected_item() {
+        Ok(()) => {
+            app.success_message = Some(if app.config.share.command.is_some() {
+                "\u{2713} Shared".to_string()
+            } else {
+                "\u{2713} Copied to clipboard".to_string()
+            });
+            app.success_message_time = Some(std::time::Instant::now());
+        }
+        Err(e) => app.error = Some(format!("Failed to share: {}", e)),
+    }
+}
+
+fn handle_share_webhook(app: &mut App) {
+    let Some((feed_idx, item_idx)) = app.selected_item_indices() else {
+        return;
+    };
+    match app.config.share.webhooks.len() {
+        0 => {
+            app.error =
+                Some("No webhooks configured (add [[share.webhooks]] to the config)".to_string())
+        }
+        1 => post_to_webhook(app, feed_idx, item_idx, 0),
+        _ => app.webhook_menu = Some((feed_idx, item_idx)),
+    }
+}
+
+fn post_to_webhook(app: &mut App, feed_idx: usize, item_idx: usize, webhook_idx: usize) {
+    match app.share_to_webhook(feed_idx, item_idx, webhook_idx) {
+        Ok(name) => {
+            app.success_message = Some(format!("\u{2713} Posted to {}", name));
+            app.success_message_time = Some(std::time::Instant::now());
+        }
+        Err(e) => app.error = Some(format!("Failed to post to webhook: {:#}", e)),
+    }
+}
+
+fn handle_make_task(app: &mut App) {
+    match app.make_task_from_selected_item() {
+        Ok(()) => {
+            app.success_message = Some("\u{2713} Task created".to_string());
+            app.success_message_time = Some(std::time::Instant::now());
+        }
+        Err(e) => app.error = Some(format!("Failed to create task: {:#}", e)),
+    }
+}
+
+fn handle_export_event(app: &mut App) {
+    match app.export_selected_event() {
+        Ok(path) => {
+            app.success_message = Some(if app.config.tools.calendar_command.is_some() {
+                "\u{2713} Event added to calendar".to_string()
+            } else {
+                format!("\u{2713} Event saved to {}", path.display())
+            });
+            app.success_message_time = Some(std::time::Instant::now());
+        }
+        Err(e) => app.error = Some(format!("Failed to export event: {:#}", e)),
+    }
+}
+
+fn handle_confirm_tags(app: &mut App) {
+    let (Some(feed_idx), Some(item_idx)) = (app.selected_feed, app.selected_item) else {
+        return;
+    };
+    match app.toggle_tags_confirmed(feed_idx, item_idx) {
+        Some(confirmed) => {
+            app.success_message = Some(if confirmed {
+                "\u{2713} Tags confirmed".to_string()
+            } else {
+                "Tags back to suggestions".to_string()
+            });
+            app.success_message_time = Some(std::time::Instant::now());
+        }
+        None => app.error = Some("No tags suggested for this article".to_string()),
+    }
+}
+
+fn handle_mute_menu_key(app: &mut App, feed_idx: usize, item_idx: usize, code: KeyCode) {
+    let item = &app.feeds[feed_idx].items[item_idx];
+    let message = match code {
+        KeyCode::Char('a') => match item.author.clone().filter(|a| !a.trim().is_empty()) {
+            Some(author) => {
+                if app.toggle_author_muted(&author) {
+                    format!("Muted author {}", author)
+                } else {
+                    format!("Unmuted author {}", author)
+                }
+            }
+            None => return,
+        },
+        KeyCode::Char('d') => match crate::app::item_domain(item) {
+            Some(domain) => {
+                if app.toggle_domain_muted(&domain) {
+                    format!("Muted {}", domain)
+                } else {
+                    format!("Unmuted {}", domain)
+                }
+            }
+            None => return,
+        },
+        _ => return,
+    };
+    app.success_message = Some(message);
+    app.success_message_time = Some(std::time::Instant::now());
+}
+
+fn run_menu_command(app: &mut App, menu: &ContextMenu, command: MenuCommand) -> Result<bool> {
+    let message = match command {
+        // Act as if the action's key was pressed on the item, which is still selected
+        MenuCommand::Key(action) => {
+            let Some(binding) = app.keybindings.get(&action).and_then(|b| b.first()) else {
+                return Ok(false);
+            };
+            let key = crossterm::event::KeyEvent::new(binding.code, binding.modifiers);
+            return handle_key_event(app, key);
+        }
+        MenuCommand::CopyLink => {
+            let item = &app.feeds[menu.feed_idx].items[menu.item_idx];
+            let link = item.link.clone().unwrap_or_default();
+            match crate::share::copy_to_clipboard(&link) {
+                Ok(()) => "\u{2713} Link copied".to_string(),
+                Err(e) => {
+                    app.error = Some(format!("Failed to copy link: {}", e));
+                    return Ok(false);
+                }
+            }
+        }
+        MenuCommand::ToggleReadLater => {
+            if app.toggle_read_later(menu.feed_idx, menu.item_idx) {
+                "Added to read later".to_string()
+            } else {
+                "Removed from read later".to_string()
+            }
+        }
+        MenuCommand::ToggleFeedMuted => {
+            let url = app.feeds[menu.feed_idx].url.clone();
+            if app.toggle_feed_muted(&url) {
+                "Muted feed".to_string()
+            } else {
+                "Unmuted feed".to_string()
+            }
+        }
+    };
+    app.success_message = Some(message);
+    app.success_message_time = Some(std::time::Instant::now());
+    Ok(false)
+}
+
 fn handle_toggle_read_current(app: &mut App) {
     if let Some(feed_idx) = app.selected_feed {
         if let Some(item_idx) = app.selected_item {
@@ -89,6 +335,323 @@ fn handle_toggle_read_current(app: &mut App) {
     }
 }
 
+fn handle_copy_mode_key(app: &mut App, key: &crossterm::event::KeyEvent) {
+    // Ctrl+v must be checked before the copy mode binding, which also matches it
+    if key.code == KeyCode::Char('v') && key.modifiers.contains(KeyModifiers::CONTROL) {
+        if let Some(copy_mode) = app.copy_mode.as_mut() {
+            copy_mode.toggle_selection(SelectionKind::Block);
+        }
+        return;
+    }
+    if key.code == KeyCode::Esc || app.key_matches(KeyAction::Quit, key) {
+        app.copy_mode = None;
+        return;
+    }
+    if key.code == KeyCode::Char('y') || key.code == KeyCode::Enter {
+        if let Err(e) = app.yank_copy_selection() {
+            app.error = Some(format!("Failed to copy: {}", e));
+        }
+        return;
+    }
+
+    let is_copy_key = app.key_matches(KeyAction::CopyMode, key);
+    let is_up = app.key_matches(KeyAction::MoveUp, key);
+    let is_down = app.key_matches(KeyAction::MoveDown, key);
+    let is_page_up = app.key_matches(KeyAction::PageUp, key);
+    let is_page_down = app.key_matches(KeyAction::PageDown, key);
+    let is_top = app.key_matches(KeyAction::JumpTop, key);
+    let is_bottom = app.key_matches(KeyAction::JumpBottom, key);
+    let Some(copy_mode) = app.copy_mode.as_mut() else {
+        return;
+    };
+    match key.code {
+        // Keep hardcoded: h/l move the cursor here rather than going back or extracting links
+        KeyCode::Left | KeyCode::Char('h') => copy_mode.move_cols(-1),
+        KeyCode::Right | KeyCode::Char('l') => copy_mode.move_cols(1),
+        KeyCode::Char('0') | KeyCode::Home => copy_mode.line_start(),
+        KeyCode::Char('$') => copy_mode.line_end(),
+        _ if is_copy_key => copy_mode.toggle_selection(SelectionKind::Lines),
+        _ if is_up => copy_mode.move_lines(-1),
+        _ if is_down => copy_mode.move_lines(1),
+        _ if is_page_up => copy_mode.move_lines(-10),
+        _ if is_page_down => copy_mode.move_lines(10),
+        _ if is_top => copy_mode.move_lines(isize::MIN / 2),
+        _ if is_bottom => copy_mode.move_lines(isize::MAX / 2),
+        _ => {}
+    }
+}
+
+fn handle_triage_key(app: &mut App, key: &crossterm::event::KeyEvent) {
+    let action = match key.code {
+        // Keep hardcoded: single-key dispositions that don't exist as actions elsewhere
+        KeyCode::Char('l') => TriageAction::ReadLater,
+        KeyCode::Char('x') => TriageAction::MuteSource,
+        KeyCode::Char('n') | KeyCode::Right => TriageAction::Skip,
+        KeyCode::Left => {
+            app.triage_back();
+            return;
+        }
+        KeyCode::Esc => {
+            app.view = View::Dashboard;
+            return;
+        }
+        _ if app.key_matches(KeyAction::ToggleStar, key) => TriageAction::Star,
+        _ if app.key_matches(KeyAction::OpenInBrowser, key) => TriageAction::Open,
+        _ if app.key_matches(KeyAction::ToggleRead, key) => TriageAction::MarkRead,
+        _ if app.key_matches(KeyAction::MoveDown, key) => TriageAction::Skip,
+        _ if app.key_matches(KeyAction::MoveUp, key) => {
+            app.triage_back();
+            return;
+        }
+        _ if app.key_matches(KeyAction::Quit, key) || app.key_matches(KeyAction::Back, key) => {
+            app.view = View::Dashboard;
+            return;
+        }
+        _ if app.key_matches(KeyAction::Help, key) => {
+            handle_show_help(app);
+            return;
+        }
+        _ => return,
+    };
+    if let Err(e) = app.triage(action) {
+        app.error = Some(format!("Failed to triage item: {}", e));
+    }
+}
+
+fn handle_read_later_key(app: &mut App, key: &crossterm::event::KeyEvent) {
+    let len = app.read_later.len();
+    let selected = app.selected_item.filter(|&idx| idx < len);
+    let queue_move = match key.code {
+        // Keep hardcoded: reordering keys only exist in this view
+        KeyCode::Char('K') => Some(QueueMove::Up),
+        KeyCode::Char('J') => Some(QueueMove::Down),
+        KeyCode::Char('T') => Some(QueueMove::Top),
+        KeyCode::Char('B') => Some(QueueMove::Bottom),
+        _ => None,
+    };
+    if let Some(queue_move) = queue_move {
+        if let Some(idx) = selected {
+            app.selected_item = Some(app.move_read_later(idx, queue_move));
+        }
+        return;
+    }
+
+    match key.code {
+        _ if app.key_matches(KeyAction::Quit, key) || app.key_matches(KeyAction::Back, key) => {
+            app.view = View::Dashboard;
+            app.selected_item = None;
+        }
+        _ if app.key_matches(KeyAction::MoveUp, key) => {
+            if len > 0 {
+                app.selected_item = Some(selected.map_or(0, |idx| idx.saturating_sub(1)));
+            }
+        }
+        _ if app.key_matches(KeyAction::MoveDown, key) => {
+            if len > 0 {
+                app.selected_item = Some(selected.map_or(0, |idx| (idx + 1).min(len - 1)));
+            }
+        }
+        _ if app.key_matches(KeyAction::Select, key) => {
+            if let Some((feed_idx, item_idx)) = app.selected_item_indices() {
+                if let Err(e) = app.open_item(feed_idx, item_idx) {
+                    app.error = Some(format!("Failed to open link: {}", e));
+                }
+                if let Err(e) = app.mark_item_as_read(feed_idx, item_idx) {
+                    app.error = Some(format!("Failed to mark item as read: {}", e));
+                }
+            }
+        }
+        _ if app.key_matches(KeyAction::OpenInBrowser, key) => {
+            if let Some((feed_idx, item_idx)) = app.selected_item_indices() {
+                if let Err(e) = app.open_item_alternate(feed_idx, item_idx) {
+                    app.error = Some(format!("Failed to open link: {}", e));
+                }
+            }
+        }
+        _ if app.key_matches(KeyAction::DeleteFeed, key) => {
+            if let Some(idx) = selected {
+                app.remove_read_later(idx);
+                let len = app.read_later.len();
+                app.selected_item = (len > 0).then(|| idx.min(len - 1));
+            }
+        }
+        _ if app.key_matches(KeyAction::ToggleTheme, key) => {
+            handle_toggle_theme(app);
+        }
+        _ if app.key_matches(KeyAction::Help, key) => {
+            handle_show_help(app);
+        }
+        _ => {}
+    }
+}
+
+fn handle_releases_key(app: &mut App, key: &crossterm::event::KeyEvent) {
+    let len = app.release_timeline().len();
+    match key.code {
+        _ if app.key_matches(KeyAction::Quit, key) || app.key_matches(KeyAction::Back, key) => {
+            app.view = View::FeedItems;
+            app.selected_item = app
+                .current_feed()
+                .is_some_and(|feed| !feed.items.is_empty())
+                .then_some(0);
+        }
+        _ if app.key_matches(KeyAction::MoveUp, key) => {
+            if len > 0 {
+                app.selected_item = Some(app.selected_item.map_or(0, |idx| idx.saturating_sub(1)));
+            }
+        }
+        _ if app.key_matches(KeyAction::MoveDown, key) => {
+            if len > 0 {
+                app.selected_item = Some(app.selected_item.map_or(0, |idx| (idx + 1).min(len - 1)));
+            }
+        }
+        _ if app.key_matches(KeyAction::Select, key) => {
+            if let Some((feed_idx, item_idx)) = app.selected_item_indices() {
+                app.open_item_detail(feed_idx, item_idx);
+                if let Err(e) = app.mark_item_as_read(feed_idx, item_idx) {
+                    app.error = Some(format!("Failed to mark item as read: {}", e));
+                }
+            }
+        }
+        _ if app.key_matches(KeyAction::OpenInBrowser, key) => {
+            if let Err(e) = app.open_release_diff() {
+                app.error = Some(format!("Failed to open link: {}", e));
+            }
+        }
+        _ if app.key_matches(KeyAction::ToggleTheme, key) => {
+            handle_toggle_theme(app);
+        }
+        _ if app.key_matches(KeyAction::Help, key) => {
+            handle_show_help(app);
+        }
+        _ => {}
+    }
+}
+
+/// Open a path dialog, with `text` filled in as the suggested path.
+fn open_path_dialog(app: &mut App, mode: InputMode, text: &str) {
+    let kind = match mode {
+        InputMode::ImportOpmlPath => PathKind::Existing,
+        _ => PathKind::Writable,
+    };
+    app.path_input = Some(PathInput::new(kind, text));
+    app.input_mode = mode;
+}
+
+/// Keys in the path dialogs: the path field takes editing keys, Enter runs the import
+/// or export once the path checks out and Esc cancels.
+fn handle_path_dialog_key(app: &mut App, key: &crossterm::event::KeyEvent) {
+    let Some(input) = app.path_input.as_mut() else {
+        app.input_mode = InputMode::Normal;
+        return;
+    };
+    if input.handle_key(key.code, &app.recent_paths) {
+        return;
+    }
+    match key.code {
+        KeyCode::Enter => {
+            let path = match input.validate() {
+                Ok(path) => path,
+                Err(reason) => {
+                    app.error = Some(reason);
+                    return;
+                }
+            };
+            let text = input.text.trim().to_string();
+            let action = match app.input_mode {
+                InputMode::ImportOpmlPath => "import",
+                _ => "export",
+            };
+            let result = match app.input_mode {
+                InputMode::ImportOpmlPath => app
+                    .import_opml_file(&path)
+                    .map(|report| app.bulk_add_report = Some(report)),
+                InputMode::ExportOpmlPath => app.export_opml_file(&path).map(|count| {
+                    app.success_message =
+                        Some(format!("\u{2713} Exported {} feeds to {}", count, text));
+                }),
+                _ => app.export_keys_cheat_sheet(&path).map(|()| {
+                    app.success_message = Some(format!("Keybindings written to {}", text));
+                }),
+            };
+            match result {
+                Ok(()) => {
+                    app.success_message_time = Some(std::time::Instant::now());
+                    app.remember_path(&text);
+                }
+                Err(e) => app.error = Some(format!("Failed to {}: {}", action, e)),
+            }
+            app.path_input = None;
+            app.input_mode = InputMode::Normal;
+        }
+        KeyCode::Esc => {
+            app.path_input = None;
+            app.input_mode = InputMode::Normal;
+        }
+        _ => {}
+    }
+}
+
+fn handle_open_scheduler(app: &mut App) {
+    app.view = View::Scheduler;
+    app.selected_item = (!app.bookmarks.is_empty()).then_some(0);
+}
+
+fn handle_scheduler_key(app: &mut App, key: &crossterm::event::KeyEvent) {
+    let len = app.bookmarks.len();
+    let selected_url = app
+        .selected_item
+        .and_then(|idx| app.bookmarks.get(idx))
+        .cloned();
+    match key.code {
+        // Keep hardcoded: pausing only exists in this view
+        KeyCode::Char('p') => {
+            if let Some(url) = selected_url {
+                let paused = app.toggle_feed_paused(&url);
+                app.success_message = Some(if paused {
+                    "Scheduled refreshes paused".to_string()
+                } else {
+                    "Scheduled refreshes resumed".to_string()
+                });
+                app.success_message_time = Some(std::time::Instant::now());
+            }
+        }
+        _ if app.key_matches(KeyAction::Quit, key) || app.key_matches(KeyAction::Back, key) => {
+            app.view = View::Dashboard;
+            app.selected_item = None;
+        }
+        _ if app.key_matches(KeyAction::MoveUp, key) => {
+            if len > 0 {
+                app.selected_item = Some(app.selected_item.map_or(0, |idx| idx.saturating_sub(1)));
+            }
+        }
+        _ if app.key_matches(KeyAction::MoveDown, key) => {
+            if len > 0 {
+                app.selected_item = Some(app.selected_item.map_or(0, |idx| (idx + 1).min(len - 1)));
+            }
+        }
+        _ if app.key_matches(KeyAction::Select, key)
+            || app.key_matches(KeyAction::RefreshFeed, key) =>
+        {
+            if let Some(url) = selected_url {
+                if !app.refresh_in_progress {
+                    app.refresh_feed_requested = Some(url);
+                }
+            }
+        }
+        _ if app.key_matches(KeyAction::Refresh, key) => {
+            handle_refresh(app);
+        }
+        _ if app.key_matches(KeyAction::ToggleTheme, key) => {
+            handle_toggle_theme(app);
+        }
+        _ if app.key_matches(KeyAction::Help, key) => {
+            handle_show_help(app);
+        }
+        _ => {}
+    }
+}
+
 // ── Event entry point ──────────────────────────────────────────────
 
 pub(crate) fn handle_events(app: &mut App) -> Result<bool> {
@@ -96,16 +659,120 @@ pub(crate) fn handle_events(app: &mut App) -> Result<bool> {
 
     // Handle mouse events
     if let Event::Mouse(mouse) = &event {
-        return handle_mouse_event(app, *mouse);
+        let from = app.nav_state();
+        let quit = handle_mouse_event(app, *mouse)?;
+        app.sync_filter_scope();
+        app.record_navigation(from);
+        return Ok(quit);
     }
 
     if let Event::Key(key) = event {
         return handle_key_event(app, key);
     }
+    if let Event::Paste(text) = event {
+        handle_paste(app, &text);
+    }
     Ok(false)
 }
 
+/// Pasted text goes into whichever text box is open, with line breaks turned into
+/// spaces so a pasted list of feed URLs can be added in one go.
+fn handle_paste(app: &mut App, text: &str) {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    match app.input_mode {
+        InputMode::InsertUrl | InputMode::CategoryNameInput => app.input.push_str(&text),
+        InputMode::ImportOpmlPath | InputMode::ExportOpmlPath | InputMode::ExportKeysPath => {
+            if let Some(input) = app.path_input.as_mut() {
+                input.text.push_str(&text);
+            }
+        }
+        InputMode::SearchMode => {
+            app.input.push_str(&text);
+            let query = app.input.clone();
+            app.live_search(&query);
+        }
+        _ => {}
+    }
+}
+
+/// Handle a key press, recording it into the macro being recorded, if any.
 pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -> Result<bool> {
+    if matches!(key.kind, KeyEventKind::Release) {
+        return Ok(false);
+    }
+    // Macro keys only count where a plain key isn't text being typed
+    if app.input_mode == InputMode::Normal && !app.show_help_overlay && app.tour_step.is_none() {
+        if app.key_matches(KeyAction::RecordMacro, &key) {
+            handle_record_macro(app);
+            return Ok(false);
+        }
+        if app.key_matches(KeyAction::PlayMacro, &key) {
+            return handle_play_macro(app);
+        }
+    }
+    // A key that only dismisses an error wouldn't do the same on replay
+    if app.error.is_none() {
+        if let Some(keys) = app.macro_recording.as_mut() {
+            keys.push(key);
+        }
+    }
+    apply_key_event(app, key)
+}
+
+/// Start recording a macro, or stop and keep what was recorded.
+fn handle_record_macro(app: &mut App) {
+    app.success_message = Some(match app.macro_recording.take() {
+        Some(keys) if keys.is_empty() => "Macro recording cancelled".to_string(),
+        Some(keys) => {
+            let message = format!("Recorded a macro of {} keys", keys.len());
+            app.recorded_macro = keys;
+            message
+        }
+        None => {
+            app.macro_recording = Some(Vec::new());
+            format!(
+                "Recording macro, press {} to stop",
+                crate::keybindings::key_display(&KeyAction::RecordMacro, &app.keybindings)
+            )
+        }
+    });
+    app.success_message_time = Some(std::time::Instant::now());
+}
+
+/// Replay the recorded macro key by key, stopping at the first error.
+fn handle_play_macro(app: &mut App) -> Result<bool> {
+    if app.macro_recording.is_some() {
+        app.error = Some("Can't replay a macro while recording one".to_string());
+        return Ok(false);
+    }
+    if app.recorded_macro.is_empty() {
+        app.error = Some(format!(
+            "No macro recorded yet, press {} to record one",
+            crate::keybindings::key_display(&KeyAction::RecordMacro, &app.keybindings)
+        ));
+        return Ok(false);
+    }
+    for key in app.recorded_macro.clone() {
+        if apply_key_event(app, key)? {
+            return Ok(true);
+        }
+        if app.error.is_some() {
+            break;
+        }
+    }
+    Ok(false)
+}
+
+/// Handle a key press and record any change of place in the navigation history.
+fn apply_key_event(app: &mut App, key: crossterm::event::KeyEvent) -> Result<bool> {
+    let from = app.nav_state();
+    let quit = dispatch_key_event(app, key)?;
+    app.sync_filter_scope();
+    app.record_navigation(from);
+    Ok(quit)
+}
+
+fn dispatch_key_event(app: &mut App, key: crossterm::event::KeyEvent) -> Result<bool> {
     if matches!(key.kind, KeyEventKind::Release) {
         return Ok(false);
     }
@@ -113,6 +780,20 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
         app.error = None;
         return Ok(false);
     }
+    // The tour consumes all keys: Esc leaves it, the back keys step back, any other goes on
+    if app.tour_step.is_some() {
+        if key.code == KeyCode::Esc
+            || app.key_matches(KeyAction::Quit, &key)
+            || app.key_matches(KeyAction::Back, &key)
+        {
+            app.end_tour();
+        } else {
+            let back = app.key_matches(KeyAction::HistoryBack, &key)
+                || app.key_matches(KeyAction::MoveUp, &key);
+            app.step_tour(!back);
+        }
+        return Ok(false);
+    }
     // Help overlay consumes all keys
     if app.show_help_overlay {
         if key.code == KeyCode::Esc
@@ -125,6 +806,10 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
             app.help_overlay_scroll = app.help_overlay_scroll.saturating_add(1);
         } else if app.key_matches(KeyAction::MoveUp, &key) {
             app.help_overlay_scroll = app.help_overlay_scroll.saturating_sub(1);
+        } else if app.key_matches(KeyAction::ExportKeys, &key) {
+            app.show_help_overlay = false;
+            let default_path = App::cheat_sheet_path().to_string_lossy().to_string();
+            open_path_dialog(app, InputMode::ExportKeysPath, &default_path);
         } else {
             app.show_help_overlay = false;
         }
@@ -148,16 +833,154 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
             || app.key_matches(KeyAction::OpenInBrowser, &key)
         {
             if let Some(link) = app.extracted_links.get(app.selected_link) {
-                if let Err(e) = open::that(&link.url) {
-                    app.error = Some(format!("Failed to open link: {}", e));
+                match open::that(&link.url) {
+                    Ok(()) => {
+                        if let Some(feed_idx) = app.selected_feed {
+                            app.record_click(feed_idx);
+                        }
+                    }
+                    Err(e) => app.error = Some(format!("Failed to open link: {}", e)),
                 }
             }
         }
         return Ok(false);
     }
+    // Refresh summary popup consumes all keys
+    if let Some(diff) = app.refresh_diff.as_mut() {
+        if app.key_matches(KeyAction::MoveDown, &key) {
+            diff.selected = (diff.selected + 1).min(diff.entries.len().saturating_sub(1));
+        } else if app.key_matches(KeyAction::MoveUp, &key) {
+            diff.selected = diff.selected.saturating_sub(1);
+        } else if app.key_matches(KeyAction::Select, &key) {
+            app.jump_to_refresh_diff_entry();
+        } else {
+            app.refresh_diff = None;
+        }
+        return Ok(false);
+    }
+    // Feed change notice: adopt a feed's new title, or keep the current one on any other key
+    if app.pending_feed_change().is_some() {
+        app.resolve_feed_change(key.code == KeyCode::Char('a'));
+        return Ok(false);
+    }
+    // Failed feeds popup: retry them, dismiss the banner, or close
+    if app.show_failed_feeds {
+        app.show_failed_feeds = false;
+        if app.key_matches(KeyAction::Refresh, &key) {
+            app.retry_failed_requested = true;
+        } else if key.code == KeyCode::Char('d') {
+            app.failed_feeds_dismissed = true;
+        }
+        return Ok(false);
+    }
+    // Context menu: move through the entries and run one, or close on any other key
+    if let Some(mut menu) = app.context_menu.take() {
+        let last = menu.entries.len().saturating_sub(1);
+        if app.key_matches(KeyAction::MoveDown, &key) {
+            menu.selected = (menu.selected + 1).min(last);
+            app.context_menu = Some(menu);
+        } else if app.key_matches(KeyAction::MoveUp, &key) {
+            menu.selected = menu.selected.saturating_sub(1);
+            app.context_menu = Some(menu);
+        } else if app.key_matches(KeyAction::Select, &key) {
+            if let Some(&(command, _)) = menu.entries.get(menu.selected) {
+                return run_menu_command(app, &menu, command);
+            }
+        }
+        return Ok(false);
+    }
+    // Mute menu: mute the item's author or domain, or close on any other key
+    if let Some((feed_idx, item_idx)) = app.mute_menu.take() {
+        handle_mute_menu_key(app, feed_idx, item_idx, key.code);
+        return Ok(false);
+    }
+    // Webhook picker: post to the numbered webhook, or close on any other key
+    if let Some((feed_idx, item_idx)) = app.webhook_menu.take() {
+        if let KeyCode::Char(c) = key.code {
+            let choice = c.to_digit(10).map_or(0, |d| d as usize);
+            if (1..=app.config.share.webhooks.len()).contains(&choice) {
+                post_to_webhook(app, feed_idx, item_idx, choice - 1);
+            }
+        }
+        return Ok(false);
+    }
+    // Feed info, item explanation, full title and bulk add results popups close on any key
+    if app.show_feed_info
+        || app.show_item_explanation
+        || app.show_full_title
+        || app.bulk_add_report.is_some()
+    {
+        app.show_feed_info = false;
+        app.show_item_explanation = false;
+        app.show_full_title = false;
+        app.bulk_add_report = None;
+        return Ok(false);
+    }
+    // Summary popup consumes all keys
+    if app.show_summary_popup {
+        if app.key_matches(KeyAction::MoveDown, &key) {
+            app.summary_popup_scroll = app.summary_popup_scroll.saturating_add(1);
+        } else if app.key_matches(KeyAction::MoveUp, &key) {
+            app.summary_popup_scroll = app.summary_popup_scroll.saturating_sub(1);
+        } else {
+            app.show_summary_popup = false;
+        }
+        return Ok(false);
+    }
+    // Quit prompt: save and quit, or cancel on any other key
+    if app.show_quit_prompt {
+        app.show_quit_prompt = false;
+        return Ok(matches!(key.code, KeyCode::Char('y') | KeyCode::Enter)
+            || app.key_matches(KeyAction::Quit, &key)
+            || app.key_matches(KeyAction::ForceQuit, &key));
+    }
     // Force quit from any view
     if app.key_matches(KeyAction::ForceQuit, &key) {
-        return Ok(true);
+        return Ok(handle_quit(app));
+    }
+    // Copy mode consumes all keys in the detail view
+    if app.copy_mode.is_some() && app.view == View::FeedItemDetail {
+        handle_copy_mode_key(app, &key);
+        return Ok(false);
+    }
+    // History keys work the same in every view
+    if app.input_mode == InputMode::Normal {
+        if app.key_matches(KeyAction::HistoryBack, &key) {
+            app.history_back();
+            return Ok(false);
+        }
+        if app.key_matches(KeyAction::HistoryForward, &key) {
+            app.history_forward();
+            return Ok(false);
+        }
+        if app.key_matches(KeyAction::ExplainItem, &key) && app.selected_item_indices().is_some() {
+            app.show_item_explanation = true;
+            return Ok(false);
+        }
+        if app.key_matches(KeyAction::OpenComments, &key) {
+            if let Some((feed_idx, item_idx)) = app.selected_item_indices() {
+                match app.open_item_comments(feed_idx, item_idx) {
+                    Ok(true) => {}
+                    Ok(false) => {
+                        app.success_message = Some("No comments link for this item".to_string());
+                        app.success_message_time = Some(std::time::Instant::now());
+                    }
+                    Err(e) => app.error = Some(format!("Failed to open comments: {}", e)),
+                }
+                return Ok(false);
+            }
+        }
+        if app.key_matches(KeyAction::FullTitle, &key) && app.selected_item_indices().is_some() {
+            app.show_full_title = true;
+            return Ok(false);
+        }
+        if app.key_matches(KeyAction::ContextMenu, &key) && app.open_context_menu() {
+            return Ok(false);
+        }
+        if app.key_matches(KeyAction::Tour, &key) {
+            app.start_tour();
+            return Ok(false);
+        }
     }
     match app.input_mode {
         InputMode::Normal => match app.view {
@@ -220,16 +1043,18 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
                     }
                 }
                 // Configurable keybindings via match guards
-                _ if app.key_matches(KeyAction::Quit, &key) => return Ok(true),
+                _ if app.key_matches(KeyAction::Quit, &key) => return Ok(handle_quit(app)),
                 // OpenCategoryManagement must come before CycleCategory
                 // because Ctrl+c matches both (NONE modifier is a subset of any).
                 _ if app.key_matches(KeyAction::OpenCategoryManagement, &key) => {
-                    app.view = View::CategoryManagement;
-                    app.selected_category = if !app.categories.is_empty() {
-                        Some(0)
-                    } else {
-                        None
-                    };
+                    if !refused_read_only(app, "Editing categories") {
+                        app.view = View::CategoryManagement;
+                        app.selected_category = if !app.categories.is_empty() {
+                            Some(0)
+                        } else {
+                            None
+                        };
+                    }
                 }
                 _ if app.key_matches(KeyAction::CycleCategory, &key) => {
                     let categories = app.get_available_categories();
@@ -256,13 +1081,47 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
                     app.filter_mode = true;
                     app.input_mode = InputMode::FilterMode;
                 }
+                _ if app.key_matches(KeyAction::ToggleItemSort, &key) => {
+                    app.toggle_item_sort();
+                }
+                _ if app.key_matches(KeyAction::OpenAlerts, &key) => {
+                    app.view = View::Alerts;
+                    app.selected_item = None;
+                }
+                _ if app.key_matches(KeyAction::Triage, &key) => {
+                    app.start_triage();
+                }
+                _ if app.key_matches(KeyAction::CatchUp, &key) => {
+                    handle_catch_up(app);
+                }
+                _ if app.key_matches(KeyAction::ReadingSession, &key) => {
+                    handle_reading_session(app);
+                }
+                _ if app.key_matches(KeyAction::NextSection, &key) => {
+                    app.cycle_dashboard_section(true);
+                }
+                _ if app.key_matches(KeyAction::PrevSection, &key) => {
+                    app.cycle_dashboard_section(false);
+                }
+                _ if app.key_matches(KeyAction::OpenReadLater, &key) => {
+                    app.view = View::ReadLater;
+                    app.selected_item = (!app.read_later.is_empty()).then_some(0);
+                }
                 _ if app.key_matches(KeyAction::AddFeed, &key) => {
-                    app.input.clear();
-                    app.input_mode = InputMode::InsertUrl;
+                    if !refused_read_only(app, "Adding feeds") {
+                        app.input.clear();
+                        app.input_mode = InputMode::InsertUrl;
+                    }
                 }
                 _ if app.key_matches(KeyAction::Refresh, &key) => {
                     handle_refresh(app);
                 }
+                _ if app.key_matches(KeyAction::ShowFailedFeeds, &key) => {
+                    app.show_failed_feeds = !app.failed_feeds.is_empty();
+                }
+                _ if app.key_matches(KeyAction::OpenScheduler, &key) => {
+                    handle_open_scheduler(app);
+                }
                 _ if app.key_matches(KeyAction::ToggleTheme, &key) => {
                     handle_toggle_theme(app);
                 }
@@ -329,9 +1188,9 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
                         let active = app.active_dashboard_items();
                         if selected < active.len() {
                             let (feed_idx, item_idx) = active[selected];
-                            app.selected_feed = Some(feed_idx);
-                            app.selected_item = Some(item_idx);
-                            app.view = View::FeedItemDetail;
+                            if let Err(e) = app.open_item(feed_idx, item_idx) {
+                                app.error = Some(format!("Failed to open link: {}", e));
+                            }
                             // Auto-mark as read when viewing detail
                             if let Err(e) = app.mark_item_as_read(feed_idx, item_idx) {
                                 app.error = Some(format!("Failed to mark item as read: {}", e));
@@ -339,14 +1198,22 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
                         }
                     }
                 }
+                _ if app.key_matches(KeyAction::Share, &key) => {
+                    handle_share(app);
+                }
+                _ if app.key_matches(KeyAction::ShareWebhook, &key) => {
+                    handle_share_webhook(app);
+                }
+                _ if app.key_matches(KeyAction::MakeTask, &key) => {
+                    handle_make_task(app);
+                }
+                _ if app.key_matches(KeyAction::ExportEvent, &key) => {
+                    handle_export_event(app);
+                }
                 _ if app.key_matches(KeyAction::OpenInBrowser, &key) => {
-                    if let Some(selected) = app.selected_item {
-                        if let Some((_, item)) = app.active_dashboard_item(selected) {
-                            if let Some(link) = &item.link {
-                                if let Err(e) = open::that(link) {
-                                    app.error = Some(format!("Failed to open link: {}", e));
-                                }
-                            }
+                    if let Some((feed_idx, item_idx)) = app.selected_item_indices() {
+                        if let Err(e) = app.open_item_alternate(feed_idx, item_idx) {
+                            app.error = Some(format!("Failed to open link: {}", e));
                         }
                     }
                 }
@@ -403,7 +1270,23 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
                         app.selected_item = None;
                     }
                 }
-                // All other FeedList keys are configurable
+                // All other FeedList keys are configurable. The OPML dialogs and redo come
+                // first because Ctrl+o, Ctrl+e and Ctrl+r also match plain o, e and r.
+                _ if app.key_matches(KeyAction::Redo, &key) => handle_undo(app, true),
+                // Ctrl+u pages up elsewhere; don't let it undo here
+                _ if app.key_matches(KeyAction::Undo, &key)
+                    && !app.key_matches(KeyAction::PageUp, &key) =>
+                {
+                    handle_undo(app, false)
+                }
+                _ if app.key_matches(KeyAction::ImportOpml, &key) => {
+                    if !refused_read_only(app, "Importing feeds") {
+                        open_path_dialog(app, InputMode::ImportOpmlPath, "");
+                    }
+                }
+                _ if app.key_matches(KeyAction::ExportOpml, &key) => {
+                    open_path_dialog(app, InputMode::ExportOpmlPath, "");
+                }
                 _ if app.key_matches(KeyAction::Quit, &key) => {
                     app.view = View::Dashboard;
                     app.selected_item = None;
@@ -427,7 +1310,7 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
                                     app.error = Some(format!("Failed to delete category: {}", e));
                                 }
                             }
-                            None => {}
+                            Some(TreeItem::Archive | TreeItem::Pending(..)) | None => {}
                         }
                     }
                 }
@@ -449,20 +1332,29 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
                                     app.error = Some(format!("Failed to toggle category: {}", e));
                                 }
                             }
-                            None => {}
+                            Some(TreeItem::Archive) => {
+                                app.archive_expanded = !app.archive_expanded;
+                                app.rebuild_feed_tree();
+                            }
+                            Some(TreeItem::Pending(..)) | None => {}
                         }
                     }
                 }
                 // OpenCategoryManagement before AssignCategory (modifier ordering)
                 _ if app.key_matches(KeyAction::OpenCategoryManagement, &key) => {
-                    app.view = View::CategoryManagement;
-                    app.selected_category = if !app.categories.is_empty() {
-                        Some(0)
-                    } else {
-                        None
-                    };
+                    if !refused_read_only(app, "Editing categories") {
+                        app.view = View::CategoryManagement;
+                        app.selected_category = if !app.categories.is_empty() {
+                            Some(0)
+                        } else {
+                            None
+                        };
+                    }
                 }
                 _ if app.key_matches(KeyAction::AssignCategory, &key) => {
+                    if refused_read_only(app, "Editing categories") {
+                        return Ok(false);
+                    }
                     if let Some(sel) = app.selected_tree_item {
                         if let Some(TreeItem::Feed(feed_idx, _)) = app.feed_tree.get(sel) {
                             if *feed_idx < app.feeds.len() {
@@ -475,8 +1367,38 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
                     }
                 }
                 _ if app.key_matches(KeyAction::AddFeed, &key) => {
-                    app.input.clear();
-                    app.input_mode = InputMode::InsertUrl;
+                    if !refused_read_only(app, "Adding feeds") {
+                        app.input.clear();
+                        app.input_mode = InputMode::InsertUrl;
+                    }
+                }
+                _ if app.key_matches(KeyAction::CycleFeedSort, &key) => {
+                    app.cycle_feed_sort();
+                }
+                _ if app.key_matches(KeyAction::OpenScheduler, &key) => {
+                    handle_open_scheduler(app);
+                }
+                _ if app.key_matches(KeyAction::ToggleMute, &key) => {
+                    let selected = app
+                        .selected_tree_item
+                        .and_then(|sel| app.feed_tree.get(sel));
+                    if let Some(TreeItem::Feed(feed_idx, _)) = selected.cloned() {
+                        let url = app.feeds[feed_idx].url.clone();
+                        app.success_message = Some(if app.toggle_feed_muted(&url) {
+                            "Muted feed".to_string()
+                        } else {
+                            "Unmuted feed".to_string()
+                        });
+                        app.success_message_time = Some(std::time::Instant::now());
+                    }
+                }
+                _ if app.key_matches(KeyAction::ArchiveFeed, &key) => {
+                    let selected = app
+                        .selected_tree_item
+                        .and_then(|sel| app.feed_tree.get(sel));
+                    if let Some(TreeItem::Feed(feed_idx, _)) = selected.cloned() {
+                        handle_toggle_archived(app, feed_idx);
+                    }
                 }
                 _ if app.key_matches(KeyAction::OpenSearch, &key) => {
                     handle_open_search(app);
@@ -546,7 +1468,7 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
                                     Some(format!("\u{2713} Marked {} items as read", total));
                                 app.success_message_time = Some(std::time::Instant::now());
                             }
-                            None => {}
+                            Some(TreeItem::Archive | TreeItem::Pending(..)) | None => {}
                         }
                     }
                 }
@@ -578,30 +1500,55 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
                 _ if app.key_matches(KeyAction::Refresh, &key) => {
                     handle_refresh(app);
                 }
+                _ if app.key_matches(KeyAction::RefreshFeed, &key) => {
+                    app.request_current_feed_refresh();
+                }
+                _ if app.key_matches(KeyAction::FeedInfo, &key) => {
+                    app.show_feed_info = app.current_feed().is_some();
+                }
+                _ if app.key_matches(KeyAction::ReleaseTimeline, &key) => {
+                    if let Err(e) = app.open_release_timeline() {
+                        app.error = Some(e.to_string());
+                    }
+                }
+                _ if app.key_matches(KeyAction::ArchiveFeed, &key) => {
+                    if let Some(feed_idx) = app.selected_feed {
+                        handle_toggle_archived(app, feed_idx);
+                    }
+                }
+                _ if app.key_matches(KeyAction::AssignCategory, &key) => {
+                    if refused_read_only(app, "Editing categories") {
+                        return Ok(false);
+                    }
+                    if let Some(feed) = app.current_feed() {
+                        let feed_url = feed.url.clone();
+                        app.category_action = Some(CategoryAction::AddFeedToCategory(feed_url));
+                        app.view = View::CategoryManagement;
+                    }
+                }
                 _ if app.key_matches(KeyAction::ToggleTheme, &key) => {
                     handle_toggle_theme(app);
                 }
                 _ if app.key_matches(KeyAction::MoveUp, &key) => {
-                    if let Some(selected) = app.selected_item {
-                        if selected > 0 {
-                            app.selected_item = Some(selected - 1);
-                        }
-                    }
+                    app.step_feed_item(false);
                 }
                 _ if app.key_matches(KeyAction::MoveDown, &key) => {
-                    if let Some(selected) = app.selected_item {
-                        if let Some(feed) = app.current_feed() {
-                            if selected < feed.items.len().saturating_sub(1) {
-                                app.selected_item = Some(selected + 1);
-                            }
-                        }
-                    }
+                    app.step_feed_item(true);
+                }
+                _ if app.key_matches(KeyAction::OpenFilter, &key) => {
+                    app.filter_mode = true;
+                    app.input_mode = InputMode::FilterMode;
+                }
+                _ if app.key_matches(KeyAction::ToggleItemSort, &key) => {
+                    app.toggle_item_sort();
                 }
                 _ if app.key_matches(KeyAction::Select, &key) => {
                     if app.selected_item.is_some() {
-                        app.view = View::FeedItemDetail;
                         if let Some(feed_idx) = app.selected_feed {
                             if let Some(item_idx) = app.selected_item {
+                                if let Err(e) = app.open_item(feed_idx, item_idx) {
+                                    app.error = Some(format!("Failed to open link: {}", e));
+                                }
                                 if let Err(e) = app.mark_item_as_read(feed_idx, item_idx) {
                                     app.error = Some(format!("Failed to mark item as read: {}", e));
                                 }
@@ -609,10 +1556,25 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
                         }
                     }
                 }
+                _ if app.key_matches(KeyAction::Share, &key) => {
+                    handle_share(app);
+                }
+                _ if app.key_matches(KeyAction::ShareWebhook, &key) => {
+                    handle_share_webhook(app);
+                }
+                _ if app.key_matches(KeyAction::MakeTask, &key) => {
+                    handle_make_task(app);
+                }
+                _ if app.key_matches(KeyAction::ExportEvent, &key) => {
+                    handle_export_event(app);
+                }
                 _ if app.key_matches(KeyAction::OpenInBrowser, &key) => {
-                    if app.selected_item.is_some() {
-                        if let Err(e) = app.open_current_item_in_browser() {
-                            app.error = Some(format!("Failed to open link: {}", e));
+                    if let (Some(feed_idx), Some(item_idx)) = (app.selected_feed, app.selected_item)
+                    {
+                        if app.current_item().is_some() {
+                            if let Err(e) = app.open_item_alternate(feed_idx, item_idx) {
+                                app.error = Some(format!("Failed to open link: {}", e));
+                            }
                         }
                     }
                 }
@@ -665,27 +1627,74 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
                 KeyCode::Char('l') => {
                     app.extract_links_from_current_item();
                 }
+                KeyCode::Left => app.scroll_detail_horizontally(-8),
+                KeyCode::Right => app.scroll_detail_horizontally(8),
+                _ if app.key_matches(KeyAction::CopyMode, &key) => {
+                    app.enter_copy_mode();
+                }
+                _ if app.key_matches(KeyAction::ToggleWrap, &key) => {
+                    app.toggle_detail_wrap();
+                }
+                _ if app.key_matches(KeyAction::ZenMode, &key) => {
+                    app.zen_mode = !app.zen_mode;
+                }
+                _ if app.key_matches(KeyAction::JumpFootnote, &key) => {
+                    app.jump_footnote();
+                }
+                _ if app.key_matches(KeyAction::ViewSource, &key) => {
+                    app.cycle_detail_source();
+                }
+                _ if app.key_matches(KeyAction::Translate, &key) => {
+                    if let Err(e) = app.toggle_translation() {
+                        app.error = Some(format!("Failed to translate: {}", e));
+                    }
+                }
+                _ if app.key_matches(KeyAction::Summarize, &key) => {
+                    if let Err(e) = app.summarize_current_item() {
+                        app.error = Some(format!("Failed to summarize: {}", e));
+                    }
+                }
+                _ if app.key_matches(KeyAction::ConfirmTags, &key) => {
+                    handle_confirm_tags(app);
+                }
+                _ if app.key_matches(KeyAction::MuteMenu, &key) => {
+                    app.mute_menu = app.selected_feed.zip(app.selected_item);
+                }
+                _ if app.key_matches(KeyAction::CatchUp, &key) => {
+                    handle_catch_up(app);
+                }
+                _ if app.key_matches(KeyAction::Select, &key) && app.reading_session.is_some() => {
+                    match app.advance_reading_session() {
+                        Ok(true) => {}
+                        Ok(false) => {
+                            app.success_message = Some("Reading session done".to_string());
+                            app.success_message_time = Some(std::time::Instant::now());
+                        }
+                        Err(e) => app.error = Some(format!("Failed to mark item as read: {}", e)),
+                    }
+                }
+                _ if app.key_matches(KeyAction::ExportArticle, &key) => {
+                    match app.export_current_item() {
+                        Ok(path) => {
+                            app.success_message = Some(format!("Exported to {}", path.display()));
+                            app.success_message_time = Some(std::time::Instant::now());
+                        }
+                        Err(e) => app.error = Some(format!("Failed to export article: {:#}", e)),
+                    }
+                }
                 // Configurable keybindings via match guards
                 _ if app.key_matches(KeyAction::Quit, &key) => {
-                    if app.is_searching {
-                        app.exit_detail_view(View::Dashboard);
-                        app.selected_item = Some(0);
-                    } else {
-                        app.exit_detail_view(View::FeedItems);
-                    }
+                    app.back_from_detail_view();
                 }
                 _ if app.key_matches(KeyAction::ToggleStar, &key) => {
                     handle_toggle_star_current(app);
                 }
                 _ if app.key_matches(KeyAction::Back, &key) => {
-                    if app.is_searching {
-                        app.exit_detail_view(View::Dashboard);
-                        app.selected_item = Some(0);
-                    } else {
-                        app.exit_detail_view(View::FeedItems);
-                    }
+                    app.back_from_detail_view();
                 }
                 _ if app.key_matches(KeyAction::Home, &key) => {
+                    app.detail_return = None;
+                    app.reading_session = None;
                     app.exit_detail_view(View::Dashboard);
                     app.selected_item = None;
                 }
@@ -704,6 +1713,23 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
                 _ if app.key_matches(KeyAction::Refresh, &key) => {
                     handle_refresh(app);
                 }
+                _ if app.key_matches(KeyAction::Share, &key) => {
+                    handle_share(app);
+                }
+                _ if app.key_matches(KeyAction::ShareWebhook, &key) => {
+                    handle_share_webhook(app);
+                }
+                _ if app.key_matches(KeyAction::MakeTask, &key) => {
+                    handle_make_task(app);
+                }
+                _ if app.key_matches(KeyAction::ExportEvent, &key) => {
+                    handle_export_event(app);
+                }
+                _ if app.key_matches(KeyAction::OpenImage, &key) => {
+                    if let Err(e) = app.open_current_image() {
+                        app.error = Some(format!("Failed to open image: {}", e));
+                    }
+                }
                 _ if app.key_matches(KeyAction::OpenInBrowser, &key) => {
                     if let Err(e) = app.open_current_item_in_browser() {
                         app.error = Some(format!("Failed to open link: {}", e));
@@ -767,9 +1793,9 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
                     if let Some(selected) = app.selected_item {
                         if selected < starred.len() {
                             let (feed_idx, item_idx) = starred[selected];
-                            app.selected_feed = Some(feed_idx);
-                            app.selected_item = Some(item_idx);
-                            app.view = View::FeedItemDetail;
+                            if let Err(e) = app.open_item(feed_idx, item_idx) {
+                                app.error = Some(format!("Failed to open link: {}", e));
+                            }
                             if let Err(e) = app.mark_item_as_read(feed_idx, item_idx) {
                                 app.error = Some(format!("Failed to mark item as read: {}", e));
                             }
@@ -822,20 +1848,26 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
                         }
                     }
                 }
+                _ if app.key_matches(KeyAction::Share, &key) => {
+                    handle_share(app);
+                }
+                _ if app.key_matches(KeyAction::ShareWebhook, &key) => {
+                    handle_share_webhook(app);
+                }
+                _ if app.key_matches(KeyAction::MakeTask, &key) => {
+                    handle_make_task(app);
+                }
+                _ if app.key_matches(KeyAction::ExportEvent, &key) => {
+                    handle_export_event(app);
+                }
                 _ if app.key_matches(KeyAction::OpenInBrowser, &key) => {
                     let starred = app.get_starred_dashboard_items();
                     if let Some(selected) = app.selected_item {
                         if selected < starred.len() {
                             let (feed_idx, item_idx) = starred[selected];
-                            let prev_feed = app.selected_feed;
-                            app.selected_feed = Some(feed_idx);
-                            app.selected_item = Some(item_idx);
-                            if let Err(e) = app.open_current_item_in_browser() {
+                            if let Err(e) = app.open_item_alternate(feed_idx, item_idx) {
                                 app.error = Some(format!("Failed to open link: {}", e));
                             }
-                            // Restore selection for starred view
-                            app.selected_feed = prev_feed;
-                            app.selected_item = Some(selected);
                         }
                     }
                 }
@@ -862,6 +1894,120 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
                 }
                 _ => {}
             },
+            View::Triage => handle_triage_key(app, &key),
+            View::ReadLater => handle_read_later_key(app, &key),
+            View::Scheduler => handle_scheduler_key(app, &key),
+            View::Releases => handle_releases_key(app, &key),
+            View::Alerts => match key.code {
+                _ if app.key_matches(KeyAction::Quit, &key)
+                    || app.key_matches(KeyAction::Back, &key) =>
+                {
+                    app.view = View::Dashboard;
+                    app.selected_item = None;
+                }
+                _ if app.key_matches(KeyAction::MoveUp, &key) => {
+                    if let Some(selected) = app.selected_item {
+                        app.selected_item = Some(selected.saturating_sub(1));
+                    } else if !app.get_alert_dashboard_items().is_empty() {
+                        app.selected_item = Some(0);
+                    }
+                }
+                _ if app.key_matches(KeyAction::MoveDown, &key) => {
+                    let alerts = app.get_alert_dashboard_items();
+                    if let Some(selected) = app.selected_item {
+                        if selected < alerts.len().saturating_sub(1) {
+                            app.selected_item = Some(selected + 1);
+                        }
+                    } else if !alerts.is_empty() {
+                        app.selected_item = Some(0);
+                    }
+                }
+                _ if app.key_matches(KeyAction::Select, &key) => {
+                    if let Some((feed_idx, item_idx)) = app.selected_item_indices() {
+                        if let Err(e) = app.open_item(feed_idx, item_idx) {
+                            app.error = Some(format!("Failed to open link: {}", e));
+                        }
+                        if let Err(e) = app.mark_item_as_read(feed_idx, item_idx) {
+                            app.error = Some(format!("Failed to mark item as read: {}", e));
+                        }
+                    }
+                }
+                _ if app.key_matches(KeyAction::DeleteFeed, &key) => {
+                    if let Some((feed_idx, item_idx)) = app.selected_item_indices() {
+                        app.dismiss_alert(feed_idx, item_idx);
+                        let remaining = app.get_alert_dashboard_items().len();
+                        app.selected_item = app
+                            .selected_item
+                            .map(|selected| selected.min(remaining.saturating_sub(1)))
+                            .filter(|_| remaining > 0);
+                    }
+                }
+                _ if app.key_matches(KeyAction::ToggleStar, &key) => {
+                    if let Some((feed_idx, item_idx)) = app.selected_item_indices() {
+                        match app.toggle_item_starred(feed_idx, item_idx) {
+                            Ok(is_now_starred) => {
+                                app.success_message = Some(if is_now_starred {
+                                    "\u{2605} Starred".to_string()
+                                } else {
+                                    "\u{2606} Unstarred".to_string()
+                                });
+                                app.success_message_time = Some(std::time::Instant::now());
+                            }
+                            Err(e) => {
+                                app.error = Some(format!("Failed to toggle star: {}", e));
+                            }
+                        }
+                    }
+                }
+                _ if app.key_matches(KeyAction::ToggleRead, &key) => {
+                    if let Some((feed_idx, item_idx)) = app.selected_item_indices() {
+                        if let Err(e) = app.toggle_item_read(feed_idx, item_idx) {
+                            app.error = Some(format!("Failed to toggle read status: {}", e));
+                        }
+                    }
+                }
+                _ if app.key_matches(KeyAction::Share, &key) => {
+                    handle_share(app);
+                }
+                _ if app.key_matches(KeyAction::ShareWebhook, &key) => {
+                    handle_share_webhook(app);
+                }
+                _ if app.key_matches(KeyAction::MakeTask, &key) => {
+                    handle_make_task(app);
+                }
+                _ if app.key_matches(KeyAction::ExportEvent, &key) => {
+                    handle_export_event(app);
+                }
+                _ if app.key_matches(KeyAction::OpenInBrowser, &key) => {
+                    if let Some((feed_idx, item_idx)) = app.selected_item_indices() {
+                        if let Err(e) = app.open_item_alternate(feed_idx, item_idx) {
+                            app.error = Some(format!("Failed to open link: {}", e));
+                        }
+                    }
+                }
+                _ if app.key_matches(KeyAction::MarkAllRead, &key) => {
+                    match app.mark_all_alerts_read() {
+                        Ok(count) => {
+                            app.success_message =
+                                Some(format!("\u{2713} Marked {} items as read", count));
+                            app.success_message_time = Some(std::time::Instant::now());
+                        }
+                        Err(e) => {
+                            app.error = Some(format!("Failed to mark all read: {}", e));
+                        }
+                    }
+                }
+                _ if app.key_matches(KeyAction::ToggleTheme, &key) => {
+                    handle_toggle_theme(app);
+                }
+                _ if app.key_matches(KeyAction::OpenSearch, &key) => {
+                    handle_open_search(app);
+                }
+                _ if app.key_matches(KeyAction::Help, &key) => {
+                    handle_show_help(app);
+                }
+                _ => {}
+            },
             View::Summary => match key.code {
                 KeyCode::Char('q') => {
                     app.view = View::Dashboard;
@@ -875,6 +2021,13 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
             },
             View::CategoryManagement => {
                 match key.code {
+                    // Before the hardcoded keys, since Ctrl+r would also remove a feed
+                    _ if app.key_matches(KeyAction::Redo, &key) => handle_undo(app, true),
+                    _ if app.key_matches(KeyAction::Undo, &key)
+                        && !app.key_matches(KeyAction::PageUp, &key) =>
+                    {
+                        handle_undo(app, false)
+                    }
                     KeyCode::Esc | KeyCode::Char('q') => {
                         // Return to previous view
                         app.view = View::FeedList;
@@ -975,11 +2128,21 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
         },
         InputMode::InsertUrl => match key.code {
             KeyCode::Enter => {
-                let url = app.input.trim().to_string();
-                if !url.is_empty() {
-                    match app.add_feed(&url) {
-                        Ok(AddFeedResult::Added) => {}
-                        Ok(AddFeedResult::DiscoveredFeeds { feeds, page_url }) => {
+                let url = crate::packages::expand(&app.input);
+                let urls = App::parse_url_list(&url);
+                if urls.len() > 1 {
+                    match app.add_feeds_bulk(urls) {
+                        Ok(report) => app.bulk_add_report = Some(report),
+                        Err(e) => app.error = Some(format!("Failed to add feeds: {}", e)),
+                    }
+                } else if !url.is_empty() {
+                    match app.fetch_feed(&url) {
+                        Ok(FeedFetchResult::Feed(feed)) => {
+                            app.input.clear();
+                            app.open_feed_preview(&url, feed);
+                            return Ok(false);
+                        }
+                        Ok(FeedFetchResult::DiscoveredFeeds { feeds, page_url }) => {
                             if feeds.is_empty() {
                                 app.error = Some(format!(
                                     "No RSS/Atom feed links found on this page: {}",
@@ -1013,6 +2176,9 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
             }
             _ => {}
         },
+        InputMode::ImportOpmlPath | InputMode::ExportOpmlPath | InputMode::ExportKeysPath => {
+            handle_path_dialog_key(app, &key);
+        }
         InputMode::SelectDiscoveredFeed => match key.code {
             KeyCode::Up | KeyCode::Char('k') => {
                 if app.discovered_feed_selection > 0 {
@@ -1027,9 +2193,14 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
             KeyCode::Enter => {
                 if let Some(discovered) = app.discovered_feeds.get(app.discovered_feed_selection) {
                     let feed_url = discovered.url.clone();
-                    match app.add_feed(&feed_url) {
-                        Ok(AddFeedResult::Added) => {}
-                        Ok(AddFeedResult::DiscoveredFeeds { .. }) => {
+                    match app.fetch_feed(&feed_url) {
+                        Ok(FeedFetchResult::Feed(feed)) => {
+                            app.discovered_feeds.clear();
+                            app.discovered_feed_selection = 0;
+                            app.open_feed_preview(&feed_url, feed);
+                            return Ok(false);
+                        }
+                        Ok(FeedFetchResult::DiscoveredFeeds { .. }) => {
                             app.error =
                                 Some("Discovered feed URL also returned an HTML page".to_string());
                         }
@@ -1049,6 +2220,20 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
             }
             _ => {}
         },
+        InputMode::PreviewFeed => match key.code {
+            KeyCode::Enter | KeyCode::Char('s') => match app.subscribe_previewed_feed() {
+                Ok(title) => {
+                    app.success_message = Some(format!("\u{2713} Subscribed to {}", title));
+                    app.success_message_time = Some(std::time::Instant::now());
+                }
+                Err(e) => {
+                    app.error = Some(format!("Failed to subscribe: {}", e));
+                }
+            },
+            KeyCode::Char('c') | KeyCode::Tab => app.cycle_preview_category(),
+            KeyCode::Esc | KeyCode::Char('q') => app.cancel_feed_preview(),
+            _ => {}
+        },
         InputMode::SearchMode => match key.code {
             KeyCode::Enter => {
                 // Results already shown live; just exit search input mode
@@ -1076,7 +2261,8 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
                 app.filter_mode = false;
                 app.input_mode = InputMode::Normal;
             }
-            KeyCode::Char('c') => {
+            // A feed page has no category of its own to narrow by
+            KeyCode::Char('c') if app.view != View::FeedItems => {
                 let categories = app.get_available_categories();
                 app.filter_options.category = if categories.is_empty() {
                     None
@@ -1143,6 +2329,30 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
                 };
                 app.apply_filters();
             }
+            KeyCode::Char('g') => {
+                // Cycle through detected languages, most common first
+                let languages = app.get_available_languages();
+                app.filter_options.language = match app.filter_options.language {
+                    None => languages.first().copied(),
+                    Some(current) => languages
+                        .iter()
+                        .position(|&code| code == current)
+                        .and_then(|idx| languages.get(idx + 1).copied()),
+                };
+                app.apply_filters();
+            }
+            KeyCode::Char('#') => {
+                // Cycle through tags, most common first
+                let tags = app.get_available_tags();
+                app.filter_options.tag = match &app.filter_options.tag {
+                    None => tags.first().cloned(),
+                    Some(current) => tags
+                        .iter()
+                        .position(|tag| tag == current)
+                        .and_then(|idx| tags.get(idx + 1).cloned()),
+                };
+                app.apply_filters();
+            }
             KeyCode::Char('x') => {
                 // Clear all filters
                 app.filter_options.reset();
@@ -1192,6 +2402,13 @@ pub(crate) fn handle_key_event(app: &mut App, key: crossterm::event::KeyEvent) -
 }
 
 fn handle_mouse_event(app: &mut App, mouse: MouseEvent) -> Result<bool> {
+    // A click moves the tour on; the rest of the mouse waits until it's over
+    if app.tour_step.is_some() {
+        if matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left)) {
+            app.step_tour(true);
+        }
+        return Ok(false);
+    }
     // Dismiss overlays on any click
     if matches!(mouse.kind, MouseEventKind::Down(MouseButton::Left)) {
         if app.show_help_overlay {
@@ -1202,9 +2419,44 @@ fn handle_mouse_event(app: &mut App, mouse: MouseEvent) -> Result<bool> {
             app.show_link_overlay = false;
             return Ok(false);
         }
+        if app.show_summary_popup {
+            app.show_summary_popup = false;
+            return Ok(false);
+        }
+        if app.refresh_diff.is_some() {
+            app.refresh_diff = None;
+            return Ok(false);
+        }
+        if app.show_feed_info
+            || app.show_item_explanation
+            || app.show_full_title
+            || app.bulk_add_report.is_some()
+        {
+            app.show_feed_info = false;
+            app.show_item_explanation = false;
+            app.show_full_title = false;
+            app.bulk_add_report = None;
+            return Ok(false);
+        }
+        if app.mute_menu.take().is_some()
+            || app.webhook_menu.take().is_some()
+            || app.context_menu.take().is_some()
+        {
+            return Ok(false);
+        }
+        if app.show_failed_feeds {
+            app.show_failed_feeds = false;
+            return Ok(false);
+        }
     }
 
     match mouse.kind {
+        // Right-click opens the context menu of the selected item
+        MouseEventKind::Down(MouseButton::Right) => {
+            if app.input_mode == InputMode::Normal && app.context_menu.is_none() {
+                app.open_context_menu();
+            }
+        }
         MouseEventKind::ScrollUp => {
             // Scroll up — same as pressing 'k'
             if app.input_mode == InputMode::Normal {
@@ -1226,18 +2478,12 @@ fn handle_mouse_event(app: &mut App, mouse: MouseEvent) -> Result<bool> {
                             }
                         }
                     }
-                    View::FeedItems => {
-                        if let Some(selected) = app.selected_item {
-                            if selected > 0 {
-                                app.selected_item = Some(selected - 1);
-                            }
-                        }
-                    }
+                    View::FeedItems => app.step_feed_item(false),
                     View::FeedItemDetail => {
                         app.detail_vertical_scroll = app.detail_vertical_scroll.saturating_sub(3);
                         app.clamp_detail_scroll();
                     }
-                    View::Starred => {
+                    View::Starred | View::Alerts => {
                         if let Some(selected) = app.selected_item {
                             if selected > 0 {
                                 app.selected_item = Some(selected - 1);
@@ -1283,28 +2529,24 @@ fn handle_mouse_event(app: &mut App, mouse: MouseEvent) -> Result<bool> {
                             app.selected_tree_item = Some(0);
                         }
                     }
-                    View::FeedItems => {
-                        if let Some(selected) = app.selected_item {
-                            if let Some(feed) = app.current_feed() {
-                                if selected < feed.items.len().saturating_sub(1) {
-                                    app.selected_item = Some(selected + 1);
-                                }
-                            }
-                        }
-                    }
+                    View::FeedItems => app.step_feed_item(true),
                     View::FeedItemDetail => {
                         if app.detail_vertical_scroll < app.detail_max_scroll {
                             app.detail_vertical_scroll =
                                 app.detail_vertical_scroll.saturating_add(3);
                         }
                     }
-                    View::Starred => {
-                        let starred_len = app.get_starred_dashboard_items().len();
+                    View::Starred | View::Alerts => {
+                        let item_count = if app.view == View::Alerts {
+                            app.get_alert_dashboard_items().len()
+                        } else {
+                            app.get_starred_dashboard_items().len()
+                        };
                         if let Some(selected) = app.selected_item {
-                            if selected < starred_len.saturating_sub(1) {
+                            if selected < item_count.saturating_sub(1) {
                                 app.selected_item = Some(selected + 1);
                             }
-                        } else if starred_len > 0 {
+                        } else if item_count > 0 {
                             app.selected_item = Some(0);
                         }
                     }
@@ -1350,28 +2592,26 @@ mod tests {
                 url: "https://example.com/feed1".to_string(),
                 title: "Feed One".to_string(),
                 title_lower: "feed one".to_string(),
+                description: None,
+                updated: None,
+                link: None,
                 items: vec![
                     FeedItem {
-                        title: "Old Article".to_string(),
-                        title_lower: "old article".to_string(),
                         link: Some("https://example.com/old".to_string()),
                         description: Some("Old content".to_string()),
-                        pub_date: None,
                         author: Some("Author A".to_string()),
-                        formatted_date: None,
                         parsed_date: Some(Utc::now() - chrono::Duration::days(30)),
                         plain_text: Some("Old content".to_string()),
+                        plain_text_lower: Some("old content".to_string()),
+                        ..FeedItem::with_title("Old Article")
                     },
                     FeedItem {
-                        title: "New Article".to_string(),
-                        title_lower: "new article".to_string(),
                         link: Some("https://example.com/new".to_string()),
                         description: Some("New content".to_string()),
-                        pub_date: None,
-                        author: None,
-                        formatted_date: None,
                         parsed_date: Some(Utc::now() - chrono::Duration::hours(1)),
                         plain_text: Some("New content".to_string()),
+                        plain_text_lower: Some("new content".to_string()),
+                        ..FeedItem::with_title("New Article")
                     },
                 ],
             },
@@ -1379,16 +2619,17 @@ mod tests {
                 url: "https://example.com/feed2".to_string(),
                 title: "Feed Two".to_string(),
                 title_lower: "feed two".to_string(),
+                description: None,
+                updated: None,
+                link: None,
                 items: vec![FeedItem {
-                    title: "Another New".to_string(),
-                    title_lower: "another new".to_string(),
                     link: Some("https://example.com/another".to_string()),
                     description: Some("Another new content".to_string()),
-                    pub_date: None,
                     author: Some("Author B".to_string()),
-                    formatted_date: None,
                     parsed_date: Some(Utc::now() - chrono::Duration::hours(2)),
                     plain_text: Some("Another new content".to_string()),
+                    plain_text_lower: Some("another new content".to_string()),
+                    ..FeedItem::with_title("Another New")
                 }],
             },
         ];
@@ -1405,6 +2646,11 @@ mod tests {
             View::FeedItems,
             View::FeedItemDetail,
             View::Starred,
+            View::Alerts,
+            View::Triage,
+            View::ReadLater,
+            View::Scheduler,
+            View::Releases,
             View::CategoryManagement,
             View::Summary,
         ];
@@ -1417,6 +2663,198 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_scheduler_pauses_and_refreshes_selected_feed() {
+        let mut app = make_test_app();
+        app.bookmarks = vec![
+            "https://example.com/feed1".to_string(),
+            "https://example.com/feed2".to_string(),
+        ];
+        app.paused_feeds.clear();
+        app.refresh_in_progress = false;
+
+        handle_key_event(&mut app, make_key(KeyCode::Char('P'), KeyModifiers::SHIFT)).unwrap();
+        assert_eq!(app.view, View::Scheduler);
+
+        handle_key_event(&mut app, make_key(KeyCode::Char('j'), KeyModifiers::NONE)).unwrap();
+        handle_key_event(&mut app, make_key(KeyCode::Char('j'), KeyModifiers::NONE)).unwrap();
+        assert_eq!(app.selected_item, Some(1));
+
+        handle_key_event(&mut app, make_key(KeyCode::Char('p'), KeyModifiers::NONE)).unwrap();
+        assert!(app.paused_feeds.contains("https://example.com/feed2"));
+
+        handle_key_event(&mut app, make_key(KeyCode::Enter, KeyModifiers::NONE)).unwrap();
+        assert_eq!(
+            app.refresh_feed_requested.as_deref(),
+            Some("https://example.com/feed2")
+        );
+    }
+
+    #[test]
+    fn test_full_title_popup_opens_on_a_selected_item() {
+        let mut app = make_test_app();
+        app.view = View::FeedItems;
+        app.selected_feed = Some(0);
+        app.selected_item = None;
+        let press = |app: &mut App, code: KeyCode, modifiers: KeyModifiers| {
+            handle_key_event(app, make_key(code, modifiers)).unwrap();
+        };
+
+        press(&mut app, KeyCode::Char('F'), KeyModifiers::SHIFT);
+        assert!(!app.show_full_title);
+
+        app.selected_item = Some(0);
+        press(&mut app, KeyCode::Char('F'), KeyModifiers::SHIFT);
+        assert!(app.show_full_title);
+
+        // Any key closes it without doing anything else
+        press(&mut app, KeyCode::Char('j'), KeyModifiers::NONE);
+        assert!(!app.show_full_title);
+        assert_eq!(app.selected_item, Some(0));
+    }
+
+    #[test]
+    fn test_tour_steps_through_and_leaves_the_view_alone() {
+        let mut app = make_test_app();
+        app.starred_items.clear();
+        app.view = View::FeedItems;
+        app.selected_feed = Some(0);
+        let press = |app: &mut App, code: KeyCode| {
+            handle_key_event(app, make_key(code, KeyModifiers::NONE)).unwrap();
+        };
+
+        press(&mut app, KeyCode::F(1));
+        assert_eq!(app.tour_step, Some(0));
+        assert_eq!(app.view, View::Dashboard);
+
+        // Keys move through the tour instead of acting on the dashboard
+        press(&mut app, KeyCode::Char('j'));
+        press(&mut app, KeyCode::Char('s'));
+        assert_eq!(app.tour_step, Some(2));
+        press(&mut app, KeyCode::Left);
+        assert_eq!(app.tour_step, Some(1));
+        assert!(app.starred_items.is_empty());
+
+        press(&mut app, KeyCode::Esc);
+        assert_eq!(app.tour_step, None);
+        assert_eq!(app.view, View::Dashboard);
+
+        // Going on past the last step ends it
+        app.start_tour();
+        for _ in 0..crate::tour::STEPS.len() {
+            press(&mut app, KeyCode::Enter);
+        }
+        assert_eq!(app.tour_step, None);
+    }
+
+    #[test]
+    fn test_context_menu_runs_the_chosen_action() {
+        let mut app = make_test_app();
+        app.read_later.clear();
+        app.starred_items.clear();
+        app.view = View::FeedItems;
+        app.selected_feed = Some(0);
+        app.selected_item = Some(0);
+        let press = |app: &mut App, code: KeyCode| {
+            handle_key_event(app, make_key(code, KeyModifiers::NONE)).unwrap();
+        };
+
+        press(&mut app, KeyCode::Char('.'));
+        let menu = app
+            .context_menu
+            .clone()
+            .expect("menu opens on a selected item");
+        let labels: Vec<&str> = menu
+            .entries
+            .iter()
+            .map(|(_, label)| label.as_str())
+            .collect();
+        assert!(labels.contains(&"Open in browser"));
+        assert!(!labels.contains(&"Translate"));
+        let read_later = menu
+            .entries
+            .iter()
+            .position(|(command, _)| *command == MenuCommand::ToggleReadLater)
+            .unwrap();
+        for _ in 0..read_later {
+            press(&mut app, KeyCode::Char('j'));
+        }
+        press(&mut app, KeyCode::Enter);
+        assert!(app.context_menu.is_none());
+        assert!(app.is_item_read_later(0, 0));
+
+        // Key actions run as if their key was pressed in the view
+        press(&mut app, KeyCode::Char('.'));
+        let star = app
+            .context_menu
+            .as_ref()
+            .unwrap()
+            .entries
+            .iter()
+            .position(|(_, label)| label == "Star")
+            .unwrap();
+        for _ in 0..star {
+            press(&mut app, KeyCode::Char('j'));
+        }
+        press(&mut app, KeyCode::Enter);
+        assert!(app.is_item_starred(0, 0));
+        assert_eq!(app.view, View::FeedItems);
+
+        // Any other key closes the menu without doing anything
+        press(&mut app, KeyCode::Char('.'));
+        press(&mut app, KeyCode::Char('z'));
+        assert!(app.context_menu.is_none());
+    }
+
+    #[test]
+    fn test_mute_menu_mutes_author_from_detail_view() {
+        let mut app = make_test_app();
+        app.muted_authors.clear();
+        app.open_item_detail(0, 0);
+
+        handle_key_event(&mut app, make_key(KeyCode::Char('m'), KeyModifiers::NONE)).unwrap();
+        assert_eq!(app.mute_menu, Some((0, 0)));
+
+        handle_key_event(&mut app, make_key(KeyCode::Char('a'), KeyModifiers::NONE)).unwrap();
+        assert!(app.mute_menu.is_none());
+        assert!(app.muted_authors.contains("author a"));
+        assert_eq!(app.view, View::FeedItemDetail);
+
+        // Any other key just closes the menu
+        handle_key_event(&mut app, make_key(KeyCode::Char('m'), KeyModifiers::NONE)).unwrap();
+        handle_key_event(&mut app, make_key(KeyCode::Char('z'), KeyModifiers::NONE)).unwrap();
+        assert!(app.mute_menu.is_none());
+        assert!(!app.zen_mode);
+    }
+
+    #[test]
+    fn test_share_webhook_opens_picker_for_several_webhooks() {
+        let mut app = make_test_app();
+        app.open_item_detail(0, 0);
+
+        handle_key_event(&mut app, make_key(KeyCode::Char('Y'), KeyModifiers::SHIFT)).unwrap();
+        assert!(app.error.is_some());
+        assert!(app.webhook_menu.is_none());
+
+        app.error = None;
+        app.config.share.webhooks = ["team", "matrix"]
+            .iter()
+            .map(|name| crate::config::Webhook {
+                name: name.to_string(),
+                url: "http://127.0.0.1:9/hook".to_string(),
+                template: r#"{"text": "{title}"}"#.to_string(),
+            })
+            .collect();
+        handle_key_event(&mut app, make_key(KeyCode::Char('Y'), KeyModifiers::SHIFT)).unwrap();
+        assert_eq!(app.webhook_menu, Some((0, 0)));
+
+        // Keys that aren't a webhook's number just close the picker
+        handle_key_event(&mut app, make_key(KeyCode::Char('z'), KeyModifiers::NONE)).unwrap();
+        assert!(app.webhook_menu.is_none());
+        assert!(!app.zen_mode);
+        assert!(app.error.is_none());
+    }
+
     #[test]
     fn test_help_overlay_consumes_keys() {
         let mut app = make_test_app();
@@ -1809,4 +3247,89 @@ mod tests {
         assert!(!result);
         assert_eq!(app.view, View::FeedList);
     }
+
+    #[test]
+    fn test_history_back_and_forward() {
+        let mut app = make_test_app();
+        app.view = View::Dashboard;
+        app.selected_item = Some(1);
+
+        // Open the second dashboard item and scroll it
+        let _ = handle_key_event(&mut app, make_key(KeyCode::Enter, KeyModifiers::NONE)).unwrap();
+        assert_eq!(app.view, View::FeedItemDetail);
+        app.detail_vertical_scroll = 7;
+        let opened = (app.selected_feed, app.selected_item);
+
+        // Jump home, then walk back and forward again
+        let _ = handle_key_event(&mut app, make_key(KeyCode::Home, KeyModifiers::NONE)).unwrap();
+        assert_eq!(app.view, View::Dashboard);
+        assert_eq!(app.selected_item, None);
+
+        let back = make_key(KeyCode::Backspace, KeyModifiers::NONE);
+        let _ = handle_key_event(&mut app, back).unwrap();
+        assert_eq!(app.view, View::FeedItemDetail);
+        assert_eq!((app.selected_feed, app.selected_item), opened);
+        assert_eq!(app.detail_vertical_scroll, 7);
+
+        let _ = handle_key_event(&mut app, make_key(KeyCode::Left, KeyModifiers::ALT)).unwrap();
+        assert_eq!(app.view, View::Dashboard);
+        assert_eq!(app.selected_item, Some(1));
+
+        let forward = make_key(KeyCode::Right, KeyModifiers::ALT);
+        let _ = handle_key_event(&mut app, forward).unwrap();
+        assert_eq!(app.view, View::FeedItemDetail);
+        let _ = handle_key_event(&mut app, forward).unwrap();
+        assert_eq!(app.view, View::Dashboard);
+        assert_eq!(app.selected_item, None);
+    }
+
+    #[test]
+    fn test_macro_records_and_replays_keys() {
+        let mut app = make_test_app();
+        app.view = View::Dashboard;
+        app.selected_item = None;
+
+        let record = make_key(KeyCode::Char('Q'), KeyModifiers::SHIFT);
+        let play = make_key(KeyCode::Char('@'), KeyModifiers::NONE);
+        let _ = handle_key_event(&mut app, record).unwrap();
+        let _ =
+            handle_key_event(&mut app, make_key(KeyCode::Char('j'), KeyModifiers::NONE)).unwrap();
+        let _ = handle_key_event(&mut app, record).unwrap();
+        assert!(app.macro_recording.is_none());
+        assert_eq!(app.recorded_macro.len(), 1);
+        assert_eq!(app.selected_item, Some(0));
+
+        let _ = handle_key_event(&mut app, play).unwrap();
+        assert_eq!(app.selected_item, Some(1));
+
+        // Replaying while recording is refused rather than recorded
+        let _ = handle_key_event(&mut app, record).unwrap();
+        let _ = handle_key_event(&mut app, play).unwrap();
+        assert!(app.error.is_some());
+        assert_eq!(app.macro_recording, Some(Vec::new()));
+    }
+
+    #[test]
+    fn test_quit_with_pending_work_asks_first() {
+        let mut app = make_test_app();
+        app.view = View::Dashboard;
+        app.config.ui.confirm_quit = true;
+        app.mark_data_dirty();
+        let quit = make_key(KeyCode::Char('q'), KeyModifiers::NONE);
+
+        assert!(!handle_key_event(&mut app, quit).unwrap());
+        assert!(app.show_quit_prompt);
+        // Any other key cancels
+        let other = make_key(KeyCode::Char('n'), KeyModifiers::NONE);
+        assert!(!handle_key_event(&mut app, other).unwrap());
+        assert!(!app.show_quit_prompt);
+
+        let _ = handle_key_event(&mut app, quit).unwrap();
+        let yes = make_key(KeyCode::Char('y'), KeyModifiers::NONE);
+        assert!(handle_key_event(&mut app, yes).unwrap());
+
+        // Turned off, quitting never asks
+        app.config.ui.confirm_quit = false;
+        assert!(handle_key_event(&mut app, quit).unwrap());
+    }
 }
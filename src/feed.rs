@@ -1,12 +1,14 @@
+use crate::http_cache::{CacheMeta, CachedResponse, HttpCache};
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use feed_rs::parser;
 use scraper::{Html, Selector};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt;
 use std::sync::OnceLock;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
 use url::Url;
 use uuid::Uuid;
 
@@ -17,6 +19,15 @@ pub struct Feed {
     pub items: Vec<FeedItem>,
     #[serde(skip)]
     pub title_lower: String,
+    /// The feed's own description (RSS `description`, Atom `subtitle`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// When the publisher says the feed last changed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub updated: Option<DateTime<Utc>>,
+    /// The site the feed belongs to, as the feed links to it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub link: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -24,6 +35,9 @@ pub struct FeedItem {
     pub title: String,
     pub link: Option<String>,
     pub description: Option<String>,
+    /// The entry summary, kept only when `description` holds the full content instead
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
     pub pub_date: Option<String>,
     pub author: Option<String>,
     pub formatted_date: Option<String>,
@@ -33,6 +47,38 @@ pub struct FeedItem {
     pub plain_text: Option<String>,
     #[serde(skip)]
     pub title_lower: String,
+    #[serde(skip)]
+    pub plain_text_lower: Option<String>,
+    /// Detected ISO 639-3 language code, when detection is confident
+    #[serde(skip)]
+    pub language: Option<&'static str>,
+    /// Lead image from the entry's media enclosures or thumbnails
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    /// Discussion page for the entry: RSS `<comments>` or an Atom `replies` link
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comments_url: Option<String>,
+    /// Number of comments, when the feed gives it (`slash:comments`, `thr:total`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment_count: Option<u32>,
+    /// Values from feed extensions (podcast episodes, geotags, media thumbnails), keyed by
+    /// the `meta` constants
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub metadata: BTreeMap<String, String>,
+}
+
+/// Keys of [`FeedItem::metadata`].
+pub mod meta {
+    /// Episode number (`itunes:episode`)
+    pub const EPISODE: &str = "episode";
+    /// Season number (`itunes:season`)
+    pub const SEASON: &str = "season";
+    /// Running time in seconds (`itunes:duration`, `media:content duration`)
+    pub const DURATION: &str = "duration";
+    /// "latitude longitude" in decimal degrees (`georss:point`, `geo:lat`/`geo:long`)
+    pub const GEO: &str = "geo";
+    /// URL of the first media thumbnail (`media:thumbnail`, `itunes:image`)
+    pub const THUMBNAIL: &str = "thumbnail";
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -134,6 +180,181 @@ impl FeedFetchResult {
     }
 }
 
+/// Request timeout used when nothing else is configured.
+pub const DEFAULT_TIMEOUT_SECS: u64 = 15;
+
+/// Accept-Language sent when nothing else is configured.
+pub const DEFAULT_ACCEPT_LANGUAGE: &str = "en-US,en;q=0.9";
+
+/// Settings for the requests made for one feed, on top of the shared client's.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RequestOptions {
+    /// User-Agent header; None sends feedr's own
+    pub user_agent: Option<String>,
+    /// Accept-Language header; None sends `DEFAULT_ACCEPT_LANGUAGE`
+    pub accept_language: Option<String>,
+    /// Whole-request timeout in seconds, replacing the client's
+    pub timeout: Option<u64>,
+    /// Connect timeout in seconds; setting it makes the request use a client of its own
+    pub connect_timeout: Option<u64>,
+    /// Extra headers sent with the request
+    pub headers: HashMap<String, String>,
+    /// Where responses are kept for revalidation and offline use; None disables caching
+    pub cache: Option<HttpCache>,
+    /// Use a cached response younger than this many seconds without a request (0 = never)
+    pub max_cache_age: u64,
+}
+
+/// Timing and size of a fetch that got a response body.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FetchStats {
+    pub elapsed: Duration,
+    pub bytes: usize,
+    /// Where the request ended up after redirects
+    pub final_url: String,
+    /// Set when the body came from the disk cache rather than the response
+    pub cache: Option<CacheUse>,
+}
+
+/// Why a cached body was used in place of a downloaded one.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CacheUse {
+    /// Fetched recently enough that no request was made
+    Fresh,
+    /// The server answered 304 Not Modified
+    NotModified,
+    /// The request failed, so the last good copy stands in
+    Offline,
+}
+
+/// Everything a single feed request can come to, with enough context to explain it.
+pub enum FetchOutcome {
+    /// Got a feed, or an HTML page with feed links.
+    Success {
+        result: FeedFetchResult,
+        stats: FetchStats,
+    },
+    /// The server says the copy we already have is current (HTTP 304).
+    NotModified { elapsed: Duration },
+    /// The server answered with a non-success status.
+    HttpError {
+        status: reqwest::StatusCode,
+        elapsed: Duration,
+    },
+    /// A response came back but wasn't a feed.
+    ParseError {
+        error: String,
+        content_type: String,
+        /// The start of the response body, for telling what the server sent instead
+        preview: String,
+        stats: FetchStats,
+    },
+    /// The request never got a complete response (DNS, TLS, timeout, ...).
+    NetworkError { error: String, elapsed: Duration },
+}
+
+impl FetchOutcome {
+    pub fn is_success(&self) -> bool {
+        matches!(
+            self,
+            FetchOutcome::Success { .. } | FetchOutcome::NotModified { .. }
+        )
+    }
+
+    /// How long the request took, successful or not.
+    pub fn elapsed(&self) -> Duration {
+        match self {
+            FetchOutcome::Success { stats, .. } | FetchOutcome::ParseError { stats, .. } => {
+                stats.elapsed
+            }
+            FetchOutcome::NotModified { elapsed }
+            | FetchOutcome::HttpError { elapsed, .. }
+            | FetchOutcome::NetworkError { elapsed, .. } => *elapsed,
+        }
+    }
+
+    /// One line describing the outcome, e.g. "Fetched 12.3 KB in 240 ms".
+    pub fn summary(&self) -> String {
+        let ms = self.elapsed().as_millis();
+        match self {
+            FetchOutcome::Success { stats, .. } => match stats.cache {
+                None => format!("Fetched {} in {} ms", format_size(stats.bytes), ms),
+                Some(CacheUse::Fresh) => {
+                    format!("Used the cached copy ({})", format_size(stats.bytes))
+                }
+                Some(CacheUse::NotModified) => format!(
+                    "Not modified (304) in {} ms, reused the cached {}",
+                    ms,
+                    format_size(stats.bytes)
+                ),
+                Some(CacheUse::Offline) => {
+                    format!("Request failed after {} ms, showing the cached copy", ms)
+                }
+            },
+            FetchOutcome::NotModified { .. } => format!("Not modified (304) in {} ms", ms),
+            FetchOutcome::HttpError { status, .. } => {
+                format!("HTTP {} after {} ms", status, ms)
+            }
+            FetchOutcome::ParseError {
+                content_type,
+                stats,
+                ..
+            } => format!(
+                "Not a feed ({}, {}) after {} ms",
+                format_size(stats.bytes),
+                content_type,
+                ms
+            ),
+            FetchOutcome::NetworkError { .. } => format!("Request failed after {} ms", ms),
+        }
+    }
+
+    /// The fetched feed or discovered links, or an error carrying the outcome's context.
+    pub fn into_result(self, url: &str) -> Result<FeedFetchResult> {
+        match self {
+            FetchOutcome::Success { result, .. } => Ok(result),
+            FetchOutcome::NotModified { .. } => Err(anyhow::anyhow!(
+                "Feed not modified since the last fetch: {}",
+                url
+            )),
+            FetchOutcome::HttpError { status, .. } => Err(anyhow::anyhow!(
+                "HTTP error {}: Failed to fetch feed from {}",
+                status,
+                url
+            )),
+            FetchOutcome::ParseError {
+                error,
+                content_type,
+                preview,
+                stats,
+            } => Err(anyhow::anyhow!(
+                "Failed to parse feed (RSS/Atom) from URL: {} (final URL: {}, {} bytes, content-type: {}, preview: {}): {}",
+                url,
+                stats.final_url,
+                stats.bytes,
+                content_type,
+                preview,
+                error
+            )),
+            FetchOutcome::NetworkError { error, .. } => Err(anyhow::anyhow!(error)),
+        }
+    }
+
+    /// The fetched feed, or an error if there wasn't one.
+    pub fn into_feed(self, url: &str) -> Result<Feed> {
+        self.into_result(url)?.into_feed()
+    }
+}
+
+/// Byte count for display: "512 B", "12.3 KB", "4.0 MB".
+fn format_size(bytes: usize) -> String {
+    match bytes {
+        0..=1023 => format!("{} B", bytes),
+        1024..=1_048_575 => format!("{:.1} KB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MB", bytes as f64 / 1_048_576.0),
+    }
+}
+
 pub fn discover_feeds_from_html(html: &[u8], base_url: &Url) -> Vec<DiscoveredFeed> {
     static SELECTOR: OnceLock<Selector> = OnceLock::new();
     let selector = SELECTOR.get_or_init(|| Selector::parse("link[rel=alternate]").unwrap());
@@ -190,9 +411,73 @@ pub fn discover_feeds_from_html(html: &[u8], base_url: &Url) -> Vec<DiscoveredFe
 }
 
 impl Feed {
+    /// The language most of this feed's items are written in, if any were detected.
+    pub fn dominant_language(&self) -> Option<&'static str> {
+        let mut counts: HashMap<&'static str, usize> = HashMap::new();
+        for code in self.items.iter().filter_map(|item| item.language) {
+            *counts.entry(code).or_default() += 1;
+        }
+        counts
+            .into_iter()
+            .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(a.0)))
+            .map(|(code, _)| code)
+    }
+
+    /// When the feed last changed: its own timestamp, or else its newest item's.
+    pub fn last_updated(&self) -> Option<DateTime<Utc>> {
+        self.updated
+            .or_else(|| self.items.iter().filter_map(|i| i.parsed_date).max())
+    }
+
+    /// Date the items the feed left undated from what their title or text says, trying
+    /// the feed's `formats` before the built-in heuristics (see `date_hints`).
+    pub fn recover_dates(&mut self, formats: &[String]) {
+        let now = Utc::now();
+        for item in self
+            .items
+            .iter_mut()
+            .filter(|item| item.parsed_date.is_none())
+        {
+            let text = format!(
+                "{} {}",
+                item.title,
+                item.plain_text.as_deref().unwrap_or_default()
+            );
+            if let Some(date) = crate::date_hints::recover_date(&text, formats, now) {
+                item.pub_date = Some(date.to_rfc3339());
+                item.formatted_date = Some(format_date(date));
+                item.parsed_date = Some(date);
+            }
+        }
+    }
+
+    /// Keep only the `max` newest items (undated items count as oldest), in feed order.
+    pub fn keep_newest(&mut self, max: usize) {
+        if self.items.len() <= max {
+            return;
+        }
+        let mut by_date: Vec<usize> = (0..self.items.len()).collect();
+        // Stable sort, so equally dated items keep the feed's own order
+        by_date.sort_by(|&a, &b| self.items[b].parsed_date.cmp(&self.items[a].parsed_date));
+        let keep: HashSet<usize> = by_date.into_iter().take(max).collect();
+        let mut idx = 0;
+        self.items.retain(|_| {
+            idx += 1;
+            keep.contains(&(idx - 1))
+        });
+    }
+
+    /// Fill in the fields that aren't saved to disk, for a feed loaded back from it.
+    pub fn restore_derived_fields(&mut self) {
+        self.title_lower = fold_for_search(&self.title);
+        for item in &mut self.items {
+            item.restore_derived_fields();
+        }
+    }
+
     /// Fetch and parse a feed from a URL with default timeout
     pub fn from_url(url: &str) -> Result<Self> {
-        Self::from_url_with_config(url, 15, None, None)
+        Self::from_url_with_config(url, DEFAULT_TIMEOUT_SECS, None, None)
     }
 
     /// Fetch and parse a feed from a URL with custom timeout
@@ -209,28 +494,119 @@ impl Feed {
         custom_headers: Option<&HashMap<String, String>>,
     ) -> Result<Self> {
         let client = Self::build_client(timeout_secs)?;
-        Self::fetch_url(url, &client, user_agent, custom_headers)?.into_feed()
+        let options = RequestOptions {
+            user_agent: user_agent.map(str::to_string),
+            headers: custom_headers.cloned().unwrap_or_default(),
+            ..RequestOptions::default()
+        };
+        Self::fetch_url(url, &client, &options)?.into_feed()
     }
 
     /// Build a shared HTTP client with the given timeout
     pub fn build_client(timeout_secs: u64) -> Result<reqwest::blocking::Client> {
-        reqwest::blocking::Client::builder()
+        Self::build_client_with(timeout_secs, 0)
+    }
+
+    /// Build a shared HTTP client with an overall timeout and, unless it's 0, a separate
+    /// limit on establishing the connection.
+    pub fn build_client_with(
+        timeout_secs: u64,
+        connect_timeout_secs: u64,
+    ) -> Result<reqwest::blocking::Client> {
+        let mut builder = reqwest::blocking::Client::builder()
             .redirect(reqwest::redirect::Policy::limited(10))
-            .timeout(Duration::from_secs(timeout_secs))
-            .build()
-            .context("Failed to create HTTP client")
+            .timeout(Duration::from_secs(timeout_secs));
+        if connect_timeout_secs > 0 {
+            builder = builder.connect_timeout(Duration::from_secs(connect_timeout_secs));
+        }
+        builder.build().context("Failed to create HTTP client")
+    }
+
+    /// Fetch an article's web page, returning its `<title>` and the HTML of its main content
+    /// (the first of `<article>`, `<main>` or `<body>`, skipping page chrome where possible).
+    pub fn fetch_article_page(
+        url: &str,
+        client: &reqwest::blocking::Client,
+        user_agent: &str,
+    ) -> Result<(String, String)> {
+        let body = client
+            .get(url)
+            .header("User-Agent", user_agent)
+            .send()
+            .and_then(|r| r.error_for_status())
+            .with_context(|| format!("Failed to fetch {}", url))?
+            .text()?;
+
+        let document = Html::parse_document(&body);
+        let title = Selector::parse("title")
+            .ok()
+            .and_then(|sel| document.select(&sel).next())
+            .map(|el| el.text().collect::<String>().trim().to_string())
+            .filter(|t| !t.is_empty())
+            .unwrap_or_else(|| url.to_string());
+
+        let html = ["article", "main", "body"]
+            .iter()
+            .filter_map(|tag| Selector::parse(tag).ok())
+            .find_map(|sel| document.select(&sel).next().map(|el| el.inner_html()))
+            .unwrap_or_else(|| body.clone());
+
+        Ok((title, html))
     }
 
     /// Fetch a URL and return either a parsed feed or discovered feed links.
     pub fn fetch_url(
         url: &str,
         client: &reqwest::blocking::Client,
-        user_agent: Option<&str>,
-        custom_headers: Option<&HashMap<String, String>>,
+        options: &RequestOptions,
     ) -> Result<FeedFetchResult> {
+        Self::fetch(url, client, options).into_result(url)
+    }
+
+    /// Fetch a URL, describing what happened whether or not it produced a feed.
+    pub fn fetch(
+        url: &str,
+        client: &reqwest::blocking::Client,
+        options: &RequestOptions,
+    ) -> FetchOutcome {
         let default_user_agent =
             "Mozilla/5.0 (compatible; Feedr/1.0; +https://github.com/bahdotsh/feedr)";
-        let ua = user_agent.unwrap_or(default_user_agent);
+        let ua = options.user_agent.as_deref().unwrap_or(default_user_agent);
+        let accept_language = options
+            .accept_language
+            .as_deref()
+            .unwrap_or(DEFAULT_ACCEPT_LANGUAGE);
+        let started = Instant::now();
+
+        // A copy fetched moments ago (e.g. before a restart) is used without asking again
+        let cached = options.cache.as_ref().and_then(|cache| cache.load(url));
+        if let Some(cached) = &cached {
+            let age = Utc::now() - cached.meta.fetched_at;
+            if options.max_cache_age > 0 && age.num_seconds() < options.max_cache_age as i64 {
+                return Self::parse_cached(url, cached, CacheUse::Fresh, started);
+            }
+        }
+
+        // A feed with its own connect timeout can't share the client's
+        let own_client;
+        let client = match options.connect_timeout {
+            Some(connect_timeout) => {
+                let timeout = options.timeout.unwrap_or(DEFAULT_TIMEOUT_SECS);
+                match Self::build_client_with(timeout, connect_timeout) {
+                    Ok(c) => {
+                        own_client = c;
+                        &own_client
+                    }
+                    Err(e) => {
+                        return FetchOutcome::NetworkError {
+                            error: format!("{:#}", e),
+                            elapsed: started.elapsed(),
+                        }
+                    }
+                }
+            }
+            None => client,
+        };
 
         let mut request = client
             .get(url)
@@ -239,95 +615,387 @@ impl Feed {
                 "Accept",
                 "application/rss+xml, application/atom+xml, application/xml, text/xml, */*",
             )
-            .header("Accept-Language", "en-US,en;q=0.9")
+            .header("Accept-Language", accept_language)
             .header("Accept-Encoding", "gzip, deflate")
-            .header("Cache-Control", "no-cache")
             .header("Connection", "keep-alive");
-
-        if let Some(headers) = custom_headers {
-            for (key, value) in headers {
-                request = request.header(key, value);
+        if let Some(timeout) = options.timeout {
+            request = request.timeout(Duration::from_secs(timeout));
+        }
+        // Ask whether the cached copy is still current instead of re-downloading it
+        match cached.as_ref().map(|c| &c.meta) {
+            Some(meta) if meta.etag.is_some() || meta.last_modified.is_some() => {
+                if let Some(etag) = &meta.etag {
+                    request = request.header("If-None-Match", etag);
+                }
+                if let Some(last_modified) = &meta.last_modified {
+                    request = request.header("If-Modified-Since", last_modified);
+                }
             }
+            _ => request = request.header("Cache-Control", "no-cache"),
         }
 
-        let response = request.send().context("Failed to fetch feed")?;
+        for (key, value) in &options.headers {
+            request = request.header(key, value);
+        }
+
+        let network_error = |error: String| match &cached {
+            // Offline: better the last copy than nothing
+            Some(cached) => Self::parse_cached(url, cached, CacheUse::Offline, started),
+            None => FetchOutcome::NetworkError {
+                error,
+                elapsed: started.elapsed(),
+            },
+        };
+
+        let response = match request.send() {
+            Ok(response) => response,
+            Err(e) => {
+                return network_error(format!(
+                    "{:#}",
+                    anyhow::Error::new(e).context("Failed to fetch feed")
+                ))
+            }
+        };
 
         // Check if we got redirected or have an unusual status
         let final_url = response.url().clone();
         let status = response.status();
-        let content_type = response
-            .headers()
-            .get("content-type")
-            .and_then(|ct| ct.to_str().ok())
-            .unwrap_or("unknown")
+        let header = |name: &str| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+        let content_type = header("content-type")
+            .unwrap_or_else(|| "unknown".to_string())
             .to_lowercase();
-
+        let etag = header("etag");
+        let last_modified = header("last-modified");
+
+        if status == reqwest::StatusCode::NOT_MODIFIED {
+            return match &cached {
+                Some(cached) => Self::parse_cached(url, cached, CacheUse::NotModified, started),
+                None => FetchOutcome::NotModified {
+                    elapsed: started.elapsed(),
+                },
+            };
+        }
         if !status.is_success() {
-            return Err(anyhow::anyhow!(
-                "HTTP error {}: Failed to fetch feed from {}",
+            return FetchOutcome::HttpError {
                 status,
-                url
-            ));
+                elapsed: started.elapsed(),
+            };
         }
 
-        let content = response.bytes().context("Failed to read response body")?;
+        let content = match response.bytes() {
+            Ok(content) => content.to_vec(),
+            Err(e) => {
+                return network_error(format!(
+                    "{:#}",
+                    anyhow::Error::new(e).context("Failed to read response body")
+                ))
+            }
+        };
+        // ActivityPub actors and paged outboxes link to the document holding the posts
+        let (content, final_url) = match crate::activitypub::fetch_posts_page(&content, client, ua)
+        {
+            Some(Ok((page, page_url))) => match Url::parse(&page_url) {
+                Ok(page_url) => (page, page_url),
+                Err(_) => (page, final_url),
+            },
+            Some(Err(e)) => return network_error(format!("{:#}", e)),
+            None => (content, final_url),
+        };
+        // Sitemaps only list page URLs; the newest pages' titles are looked up
+        let content = match crate::sitemap::fetch_titles(&content, client, ua) {
+            Some(Ok(sitemap)) => sitemap,
+            Some(Err(e)) => return network_error(format!("{:#}", e)),
+            None => content,
+        };
+        let stats = FetchStats {
+            elapsed: started.elapsed(),
+            bytes: content.len(),
+            final_url: final_url.to_string(),
+            cache: None,
+        };
+        let outcome = Self::parse_body(url, &content, content_type.clone(), &final_url, stats);
+
+        // Only keep real feeds; error pages and HTML shouldn't replace a good copy
+        if let (
+            Some(cache),
+            FetchOutcome::Success {
+                result: FeedFetchResult::Feed(_),
+                ..
+            },
+        ) = (&options.cache, &outcome)
+        {
+            let meta = CacheMeta {
+                url: url.to_string(),
+                final_url: final_url.to_string(),
+                content_type,
+                etag,
+                last_modified,
+                fetched_at: Utc::now(),
+            };
+            // A cache that can't be written only costs a re-download next time
+            let _ = cache.store(&content, &meta);
+        }
+        outcome
+    }
+
+    /// The last good copy of `url` in `cache`, parsed without making a request. None
+    /// when nothing is cached for it.
+    pub fn load_cached(url: &str, cache: &HttpCache) -> Option<Result<Feed>> {
+        let cached = cache.load(url)?;
+        Some(Self::parse_cached(url, &cached, CacheUse::Fresh, Instant::now()).into_feed(url))
+    }
+
+    /// Parse a feed document that didn't come over the network, such as a bundled
+    /// fixture, as if it had just been fetched from `url`.
+    pub fn parse_document(url: &str, content: &[u8]) -> FetchOutcome {
+        let Ok(final_url) = Url::parse(url) else {
+            return FetchOutcome::NetworkError {
+                error: format!("Invalid URL: {}", url),
+                elapsed: Duration::ZERO,
+            };
+        };
+        let stats = FetchStats {
+            elapsed: Duration::ZERO,
+            bytes: content.len(),
+            final_url: url.to_string(),
+            cache: None,
+        };
+        Self::parse_body(
+            url,
+            content,
+            "application/xml".to_string(),
+            &final_url,
+            stats,
+        )
+    }
+
+    /// Parse an in-memory feed document as served by `url`. Malformed input is an
+    /// error, never a panic; the property tests and `fuzz/` hold it to that.
+    pub fn from_bytes(url: &str, content: &[u8]) -> Result<Feed> {
+        Self::parse_document(url, content).into_feed(url)
+    }
+
+    /// Parse a body from the cache as if it had just been fetched.
+    fn parse_cached(
+        url: &str,
+        cached: &CachedResponse,
+        cache_use: CacheUse,
+        started: Instant,
+    ) -> FetchOutcome {
+        let final_url = Url::parse(&cached.meta.final_url).or_else(|_| Url::parse(url));
+        let Ok(final_url) = final_url else {
+            return FetchOutcome::NetworkError {
+                error: format!("Invalid URL: {}", url),
+                elapsed: started.elapsed(),
+            };
+        };
+        let stats = FetchStats {
+            elapsed: started.elapsed(),
+            bytes: cached.body.len(),
+            final_url: final_url.to_string(),
+            cache: Some(cache_use),
+        };
+        Self::parse_body(
+            url,
+            &cached.body,
+            cached.meta.content_type.clone(),
+            &final_url,
+            stats,
+        )
+    }
+
+    /// Turn a response body into a feed, discovered feed links, or a parse error.
+    fn parse_body(
+        url: &str,
+        content: &[u8],
+        content_type: String,
+        final_url: &Url,
+        stats: FetchStats,
+    ) -> FetchOutcome {
+        let preview = |len: usize| {
+            String::from_utf8_lossy(&content[..std::cmp::min(len, content.len())])
+                .trim()
+                .to_string()
+        };
 
         // Reject suspiciously short responses (likely empty/error pages)
         if content.len() < 100 {
-            return Err(anyhow::anyhow!(
-                "Response too short ({} bytes), might be empty or an error page",
-                content.len()
-            ));
+            return FetchOutcome::ParseError {
+                error: format!(
+                    "Response too short ({} bytes), might be empty or an error page",
+                    content.len()
+                ),
+                content_type,
+                preview: preview(300),
+                stats,
+            };
+        }
+
+        // Package registry APIs answer with JSON; their versions become the items
+        if let Some(result) = crate::packages::to_feed(url, content) {
+            return match result {
+                Ok(feed) => FetchOutcome::Success {
+                    result: FeedFetchResult::Feed(feed),
+                    stats,
+                },
+                Err(e) => FetchOutcome::ParseError {
+                    error: e.to_string(),
+                    content_type,
+                    preview: preview(300),
+                    stats,
+                },
+            };
+        }
+
+        // Sites with a broken feed may still keep a sitemap of their pages
+        if let Some(sitemap) = crate::sitemap::parse(content) {
+            return match crate::sitemap::to_feed(url, &sitemap) {
+                Ok(feed) => FetchOutcome::Success {
+                    result: FeedFetchResult::Feed(feed),
+                    stats,
+                },
+                Err(e) => FetchOutcome::ParseError {
+                    error: e.to_string(),
+                    content_type,
+                    preview: preview(300),
+                    stats,
+                },
+            };
+        }
+
+        // ActivityPub outboxes are JSON, not RSS or Atom
+        if let Some(doc) = crate::activitypub::parse_document(content) {
+            return match crate::activitypub::outbox_to_feed(url, &doc) {
+                Ok(feed) => FetchOutcome::Success {
+                    result: FeedFetchResult::Feed(feed),
+                    stats,
+                },
+                Err(e) => FetchOutcome::ParseError {
+                    error: e.to_string(),
+                    content_type,
+                    preview: preview(300),
+                    stats,
+                },
+            };
         }
 
         // Try parsing as feed first — some servers serve valid feeds with text/html content-type
-        let feed = match parser::parse(&content[..]) {
+        let feed = match parser::parse(content) {
             Ok(f) => f,
             Err(parse_err) => {
                 // Parse failed — check if this looks like HTML and try feed discovery
-                let content_start =
-                    String::from_utf8_lossy(&content[..std::cmp::min(200, content.len())]);
-                let trimmed_lower = content_start.trim_start().to_lowercase();
+                let trimmed_lower = preview(200).to_lowercase();
                 if content_type.contains("text/html")
                     || trimmed_lower.starts_with("<!doctype html")
                     || trimmed_lower.starts_with("<html")
                 {
-                    let discovered = discover_feeds_from_html(&content, &final_url);
-                    return Ok(FeedFetchResult::DiscoveredFeeds {
-                        feeds: discovered,
-                        page_url: final_url.to_string(),
-                    });
+                    let discovered = discover_feeds_from_html(content, final_url);
+                    return FetchOutcome::Success {
+                        result: FeedFetchResult::DiscoveredFeeds {
+                            feeds: discovered,
+                            page_url: final_url.to_string(),
+                        },
+                        stats,
+                    };
                 }
 
-                let content_preview =
-                    String::from_utf8_lossy(&content[..std::cmp::min(300, content.len())]);
-                return Err(parse_err).with_context(|| {
-                    format!(
-                        "Failed to parse feed (RSS/Atom) from URL: {} (final URL: {}, {} bytes, content-type: {}, preview: {})",
-                        url, final_url, content.len(), content_type, content_preview.trim()
-                    )
-                });
+                return FetchOutcome::ParseError {
+                    error: parse_err.to_string(),
+                    content_type,
+                    preview: preview(300),
+                    stats,
+                };
             }
         };
 
-        let items = feed.entries.iter().map(FeedItem::from_feed_entry).collect();
+        let mut items: Vec<FeedItem> = feed.entries.iter().map(FeedItem::from_feed_entry).collect();
+        crate::raw_entry::add_extensions(&String::from_utf8_lossy(content), &mut items);
 
         let title = feed
             .title
             .map(|t| t.content)
             .unwrap_or_else(|| "Untitled Feed".to_string());
-        let title_lower = title.to_lowercase();
-
-        Ok(FeedFetchResult::Feed(Feed {
-            url: url.to_string(),
-            title,
-            items,
-            title_lower,
-        }))
+        let title_lower = fold_for_search(&title);
+        let description = feed
+            .description
+            .map(|d| {
+                html2text::from_read(d.content.as_bytes(), 10_000)
+                    .trim()
+                    .to_string()
+            })
+            .filter(|d| !d.is_empty());
+        let link = feed
+            .links
+            .iter()
+            .find(|l| l.rel.as_deref() != Some("self"))
+            .map(|l| l.href.clone());
+
+        FetchOutcome::Success {
+            result: FeedFetchResult::Feed(Feed {
+                url: url.to_string(),
+                title,
+                items,
+                title_lower,
+                description,
+                updated: feed.updated,
+                link,
+            }),
+            stats,
+        }
     }
 }
 
+/// Average reading speed used for reading time estimates.
+const WORDS_PER_MINUTE: usize = 230;
+
 impl FeedItem {
+    /// Estimated minutes to read the item's text, at least one.
+    pub fn reading_minutes(&self) -> usize {
+        let words = self
+            .plain_text
+            .as_deref()
+            .map_or(0, |text| text.split_whitespace().count());
+        words.div_ceil(WORDS_PER_MINUTE).max(1)
+    }
+
+    /// A whole-number extension value, such as the episode number or duration.
+    pub fn metadata_number(&self, key: &str) -> Option<u64> {
+        self.metadata.get(key)?.parse().ok()
+    }
+
+    /// Latitude and longitude the item is tagged with.
+    pub fn geo_point(&self) -> Option<(f64, f64)> {
+        let (lat, lon) = self.metadata.get(meta::GEO)?.split_once(' ')?;
+        Some((lat.parse().ok()?, lon.parse().ok()?))
+    }
+
+    /// Recompute the parsed date, plain text, search keys and language, which aren't
+    /// serialized, from the fields that are.
+    fn restore_derived_fields(&mut self) {
+        self.parsed_date = self
+            .pub_date
+            .as_deref()
+            .and_then(|date| DateTime::parse_from_rfc3339(date).ok())
+            .map(|date| date.with_timezone(&Utc));
+        self.plain_text = self
+            .description
+            .as_ref()
+            .map(|desc| html2text::from_read(desc.as_bytes(), 80));
+        self.title_lower = fold_for_search(&self.title);
+        self.plain_text_lower = self.plain_text.as_deref().map(fold_for_search);
+        self.language = detect_language(&format!(
+            "{}\n{}",
+            self.title,
+            self.plain_text.as_deref().unwrap_or("")
+        ));
+    }
+
     fn from_feed_entry(entry: &feed_rs::model::Entry) -> Self {
         // Extract publication date - try multiple date formats
         let (pub_date_string, formatted_date, parsed_date) =
@@ -355,13 +1023,17 @@ impl FeedItem {
         });
 
         // Extract content/description - prefer content over summary
-        let description = if let Some(content) = entry.content.as_ref() {
-            Some(content.body.clone().unwrap_or_default())
+        let entry_summary = entry
+            .summary
+            .as_ref()
+            .map(|summary| summary.content.clone());
+        let (description, summary) = if let Some(content) = entry.content.as_ref() {
+            (
+                Some(content.body.clone().unwrap_or_default()),
+                entry_summary,
+            )
         } else {
-            entry
-                .summary
-                .as_ref()
-                .map(|summary| summary.content.clone())
+            (entry_summary, None)
         };
 
         // Cache plain text from description (avoids repeated HTML parsing)
@@ -371,29 +1043,132 @@ impl FeedItem {
 
         // Extract the primary link
         let link = entry.links.first().map(|link| link.href.clone());
+        // Extension values feed-rs reads; `raw_entry::add_extensions` adds the rest
+        let mut metadata = BTreeMap::new();
+        let duration = entry.media.iter().find_map(|media| {
+            media
+                .duration
+                .or_else(|| media.content.iter().find_map(|content| content.duration))
+        });
+        if let Some(duration) = duration.filter(|d| !d.is_zero()) {
+            metadata.insert(meta::DURATION.to_string(), duration.as_secs().to_string());
+        }
+        if let Some(thumbnail) = entry
+            .media
+            .iter()
+            .find_map(|media| media.thumbnails.first())
+        {
+            metadata.insert(meta::THUMBNAIL.to_string(), thumbnail.image.uri.clone());
+        }
+
+        // RSS `<comments>` isn't read by feed-rs; `raw_entry::add_extensions` fills it in
+        let comments_url = entry
+            .links
+            .iter()
+            .find(|link| link.rel.as_deref() == Some("replies"))
+            .map(|link| link.href.clone());
+
+        // Lead image: an image enclosure, else the first media thumbnail
+        let image = entry
+            .media
+            .iter()
+            .flat_map(|media| &media.content)
+            .find(|content| {
+                content
+                    .content_type
+                    .as_ref()
+                    .is_some_and(|mime| mime.type_().as_str() == "image")
+            })
+            .and_then(|content| content.url.as_ref().map(|url| url.to_string()))
+            .or_else(|| {
+                entry
+                    .media
+                    .iter()
+                    .find_map(|media| media.thumbnails.first())
+                    .map(|thumbnail| thumbnail.image.uri.clone())
+            });
 
         let title = entry
             .title
             .as_ref()
             .map(|t| t.content.clone())
             .unwrap_or_else(|| "Untitled".to_string());
-        let title_lower = title.to_lowercase();
+        let title_lower = fold_for_search(&title);
+        let plain_text_lower = plain_text.as_deref().map(fold_for_search);
+        let language = detect_language(&format!(
+            "{}\n{}",
+            title,
+            plain_text.as_deref().unwrap_or("")
+        ));
 
         FeedItem {
             title,
             link,
             description,
+            summary,
             pub_date: pub_date_string,
             author,
             formatted_date,
             parsed_date,
             plain_text,
             title_lower,
+            plain_text_lower,
+            language,
+            image,
+            comments_url,
+            comment_count: None,
+            metadata,
+        }
+    }
+}
+
+#[cfg(test)]
+impl FeedItem {
+    /// An item with only a title, for tests to fill in the fields they need with `..`.
+    pub(crate) fn with_title(title: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            link: None,
+            description: None,
+            summary: None,
+            pub_date: None,
+            author: None,
+            formatted_date: None,
+            parsed_date: None,
+            plain_text: None,
+            title_lower: fold_for_search(title),
+            plain_text_lower: None,
+            language: None,
+            image: None,
+            comments_url: None,
+            comment_count: None,
+            metadata: BTreeMap::new(),
         }
     }
 }
 
-fn format_date(dt: DateTime<Utc>) -> String {
+/// Detect the language of `text`, returning its ISO 639-3 code only when whatlang
+/// is confident; short titles without content often aren't.
+pub fn detect_language(text: &str) -> Option<&'static str> {
+    whatlang::detect(text)
+        .filter(|info| info.is_reliable())
+        .map(|info| info.lang().code())
+}
+
+/// English name for an ISO 639-3 code from [`detect_language`].
+pub fn language_name(code: &str) -> &'static str {
+    whatlang::Lang::from_code(code).map_or("Unknown", |lang| lang.eng_name())
+}
+
+/// Lowercase and strip diacritics so "Café" and "cafe" compare equal.
+pub fn fold_for_search(text: &str) -> String {
+    text.nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .collect::<String>()
+        .to_lowercase()
+}
+
+pub(crate) fn format_date(dt: DateTime<Utc>) -> String {
     // Calculate how long ago the item was published
     let now = Utc::now();
     let diff = now.signed_duration_since(dt);
@@ -410,6 +1185,44 @@ fn format_date(dt: DateTime<Utc>) -> String {
     }
 }
 
+/// How long before `now` the item was published, however long ago that was.
+pub(crate) fn relative_date(dt: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    match age(dt, now) {
+        None => "just now".to_string(),
+        Some((1, unit)) => format!("1 {} ago", unit),
+        Some((count, unit)) => format!("{} {}s ago", count, unit),
+    }
+}
+
+/// `relative_date` in as few characters as possible ("5m", "3h", "2w", "4mo"), for
+/// narrow terminals.
+pub(crate) fn short_relative_date(dt: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    match age(dt, now) {
+        None => "now".to_string(),
+        Some((count, unit)) => {
+            let unit = match unit {
+                "month" => "mo",
+                unit => &unit[..1],
+            };
+            format!("{}{}", count, unit)
+        }
+    }
+}
+
+// The age of `dt` in its largest whole unit, or None for under a minute
+fn age(dt: DateTime<Utc>, now: DateTime<Utc>) -> Option<(i64, &'static str)> {
+    let diff = now.signed_duration_since(dt);
+    Some(match diff.num_minutes() {
+        m if m < 1 => return None,
+        m if m < 60 => (m, "minute"),
+        _ if diff.num_hours() < 24 => (diff.num_hours(), "hour"),
+        _ if diff.num_days() < 7 => (diff.num_days(), "day"),
+        _ if diff.num_days() < 30 => (diff.num_days() / 7, "week"),
+        _ if diff.num_days() < 365 => (diff.num_days() / 30, "month"),
+        _ => (diff.num_days() / 365, "year"),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -517,6 +1330,9 @@ mod tests {
             title: "Test Feed".to_string(),
             items: vec![],
             title_lower: "test feed".to_string(),
+            description: None,
+            updated: None,
+            link: None,
         };
         let result = FeedFetchResult::Feed(feed);
         let feed = result.into_feed().unwrap();
@@ -548,4 +1364,214 @@ mod tests {
         let err = result.into_feed().unwrap_err();
         assert!(err.to_string().contains("No RSS/Atom feed links found"));
     }
+
+    #[test]
+    fn test_fold_for_search_strips_case_and_diacritics() {
+        assert_eq!(fold_for_search("Café"), "cafe");
+        assert_eq!(fold_for_search("NAÏVE Résumé"), "naive resume");
+        assert_eq!(fold_for_search("plain"), "plain");
+    }
+
+    #[test]
+    fn test_detect_language() {
+        assert_eq!(
+            detect_language("The quick brown fox jumps over the lazy dog while the farmer watches from the porch."),
+            Some("eng")
+        );
+        assert_eq!(
+            detect_language("El rápido zorro marrón salta sobre el perro perezoso mientras el granjero mira desde el porche."),
+            Some("spa")
+        );
+        assert_eq!(language_name("deu"), "German");
+    }
+
+    #[test]
+    fn test_reading_minutes_rounds_up() {
+        let mut item = FeedItem::with_title("Long read");
+        assert_eq!(item.reading_minutes(), 1);
+        item.plain_text = Some("word ".repeat(231));
+        assert_eq!(item.reading_minutes(), 2);
+    }
+
+    #[test]
+    fn test_keep_newest_keeps_feed_order() {
+        let item = |title: &str, days_ago: Option<i64>| FeedItem {
+            parsed_date: days_ago.map(|d| Utc::now() - chrono::Duration::days(d)),
+            ..FeedItem::with_title(title)
+        };
+        let mut feed = Feed {
+            url: "https://example.com/feed.xml".to_string(),
+            title: "Test Feed".to_string(),
+            items: vec![
+                item("two days", Some(2)),
+                item("undated", None),
+                item("today", Some(0)),
+                item("five days", Some(5)),
+            ],
+            title_lower: "test feed".to_string(),
+            description: None,
+            updated: None,
+            link: None,
+        };
+
+        feed.keep_newest(10);
+        assert_eq!(feed.items.len(), 4);
+        feed.keep_newest(2);
+        let titles: Vec<&str> = feed.items.iter().map(|i| i.title.as_str()).collect();
+        assert_eq!(titles, vec!["two days", "today"]);
+    }
+
+    #[test]
+    fn test_restore_derived_fields_after_round_trip() {
+        let published = Utc::now() - chrono::Duration::days(3);
+        let feed = Feed {
+            url: "https://example.com/feed.xml".to_string(),
+            title: "Café Notes".to_string(),
+            items: vec![FeedItem {
+                title_lower: String::new(),
+                description: Some("<p>Hello <b>world</b></p>".to_string()),
+                pub_date: Some(published.to_rfc3339()),
+                parsed_date: Some(published),
+                ..FeedItem::with_title("Über Post")
+            }],
+            title_lower: String::new(),
+            description: None,
+            updated: None,
+            link: None,
+        };
+
+        let json = serde_json::to_string(&feed).unwrap();
+        let mut loaded: Feed = serde_json::from_str(&json).unwrap();
+        assert!(loaded.items[0].parsed_date.is_none());
+        loaded.restore_derived_fields();
+
+        assert_eq!(loaded.title_lower, fold_for_search("Café Notes"));
+        let item = &loaded.items[0];
+        assert_eq!(item.parsed_date, Some(published));
+        assert!(item.plain_text.as_deref().unwrap().contains("world"));
+        assert_eq!(item.title_lower, fold_for_search("Über Post"));
+    }
+
+    #[test]
+    fn test_fetch_outcome_summaries_and_errors() {
+        let stats = FetchStats {
+            elapsed: Duration::from_millis(240),
+            bytes: 12_595,
+            final_url: "https://example.com/feed.xml".to_string(),
+            cache: None,
+        };
+        let http = FetchOutcome::HttpError {
+            status: reqwest::StatusCode::NOT_FOUND,
+            elapsed: Duration::from_millis(80),
+        };
+        assert!(!http.is_success());
+        assert_eq!(http.summary(), "HTTP 404 Not Found after 80 ms");
+        let err = http.into_feed("https://example.com/feed").unwrap_err();
+        assert!(err.to_string().contains("404 Not Found"));
+
+        let parse = FetchOutcome::ParseError {
+            error: "no root element".to_string(),
+            content_type: "text/plain".to_string(),
+            preview: "Service unavailable".to_string(),
+            stats: stats.clone(),
+        };
+        assert_eq!(
+            parse.summary(),
+            "Not a feed (12.3 KB, text/plain) after 240 ms"
+        );
+        let message = parse
+            .into_feed("https://example.com/feed")
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains("Service unavailable"));
+        assert!(message.contains("no root element"));
+
+        let not_modified = FetchOutcome::NotModified {
+            elapsed: Duration::from_millis(30),
+        };
+        assert!(not_modified.is_success());
+        assert_eq!(not_modified.summary(), "Not modified (304) in 30 ms");
+
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(3 * 1_048_576), "3.0 MB");
+    }
+
+    #[test]
+    fn test_fetch_uses_fresh_cached_copy_without_a_request() {
+        let dir = std::env::temp_dir().join(format!("feedr-fetch-cache-{}", Uuid::new_v4()));
+        let cache = HttpCache::new(dir.clone());
+        let url = "https://feedr.invalid/feed.xml";
+        let body = r#"<?xml version="1.0"?><rss version="2.0"><channel><title>Cached</title>
+            <item><title>From disk</title><link>https://feedr.invalid/1</link></item>
+            </channel></rss>"#;
+        let meta = CacheMeta {
+            url: url.to_string(),
+            final_url: url.to_string(),
+            content_type: "application/rss+xml".to_string(),
+            etag: None,
+            last_modified: None,
+            fetched_at: Utc::now(),
+        };
+        cache.store(body.as_bytes(), &meta).unwrap();
+
+        let options = RequestOptions {
+            cache: Some(cache),
+            max_cache_age: 300,
+            ..RequestOptions::default()
+        };
+        let client = Feed::build_client(1).unwrap();
+        let outcome = Feed::fetch(url, &client, &options);
+        let FetchOutcome::Success { result, stats } = outcome else {
+            panic!("expected the cached feed");
+        };
+        assert_eq!(stats.cache, Some(CacheUse::Fresh));
+        let feed = result.into_feed().unwrap();
+        assert_eq!(feed.title, "Cached");
+        assert_eq!(feed.items[0].title, "From disk");
+        let _ = std::fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_relative_date_picks_the_largest_unit() {
+        let now = Utc::now();
+        assert_eq!(relative_date(now, now), "just now");
+        assert_eq!(
+            relative_date(now - chrono::Duration::minutes(1), now),
+            "1 minute ago"
+        );
+        assert_eq!(
+            relative_date(now - chrono::Duration::hours(5), now),
+            "5 hours ago"
+        );
+        assert_eq!(
+            relative_date(now - chrono::Duration::days(15), now),
+            "2 weeks ago"
+        );
+        assert_eq!(
+            relative_date(now - chrono::Duration::days(95), now),
+            "3 months ago"
+        );
+        assert_eq!(
+            relative_date(now - chrono::Duration::days(800), now),
+            "2 years ago"
+        );
+    }
+
+    #[test]
+    fn test_short_relative_date() {
+        let now = Utc::now();
+        assert_eq!(short_relative_date(now, now), "now");
+        assert_eq!(
+            short_relative_date(now - chrono::Duration::hours(5), now),
+            "5h"
+        );
+        assert_eq!(
+            short_relative_date(now - chrono::Duration::days(95), now),
+            "3mo"
+        );
+        assert_eq!(
+            short_relative_date(now - chrono::Duration::days(800), now),
+            "2y"
+        );
+    }
 }
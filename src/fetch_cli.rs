@@ -0,0 +1,62 @@
+use crate::app::App;
+use crate::globals;
+use anyhow::Result;
+
+/// Exit status when some feeds couldn't be fetched.
+pub const EXIT_PARTIAL: i32 = 2;
+/// Exit status when no feed could be fetched, e.g. while offline.
+pub const EXIT_ALL_FAILED: i32 = 3;
+
+/// Fetch every subscription into the HTTP cache for cron or a systemd timer, so the
+/// UI opens on current feeds. Unlike `feedr refresh` it doesn't count as a session.
+/// Returns the exit status: 0 when every feed was fetched, `EXIT_PARTIAL` or
+/// `EXIT_ALL_FAILED` otherwise. With `quiet`, only failures are printed.
+pub fn run(quiet: bool) -> Result<i32> {
+    let mut app = App::new();
+    if !app.config.network.http_cache {
+        anyhow::bail!("network.http_cache is off, so there's no cache to fetch into");
+    }
+    let total = app.bookmarks.len();
+    globals::log(&format!("Fetching {} feeds", total));
+    app.refresh_feeds()?;
+    app.write_metrics()?;
+
+    for url in &app.bookmarks {
+        if let Some(summary) = app.fetch_summaries.get(url) {
+            globals::log(&format!("  {}: {}", url, summary));
+        }
+    }
+    let failed = app.failed_feeds.len();
+    if !quiet {
+        println!(
+            "Fetched {} of {} feeds into the cache",
+            total - failed,
+            total
+        );
+    }
+    for (url, error) in &app.failed_feeds {
+        eprintln!("  ! {}: {}", url, error);
+    }
+    Ok(exit_status(total, failed))
+}
+
+fn exit_status(total: usize, failed: usize) -> i32 {
+    match failed {
+        0 => 0,
+        _ if failed >= total => EXIT_ALL_FAILED,
+        _ => EXIT_PARTIAL,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_status_tells_partial_from_total_failure() {
+        assert_eq!(exit_status(0, 0), 0);
+        assert_eq!(exit_status(5, 0), 0);
+        assert_eq!(exit_status(5, 2), EXIT_PARTIAL);
+        assert_eq!(exit_status(5, 5), EXIT_ALL_FAILED);
+    }
+}
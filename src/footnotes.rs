@@ -0,0 +1,189 @@
+use html2text::render::text_renderer::TrivialDecorator;
+use scraper::node::Text;
+use scraper::{ElementRef, Html, Node, Selector};
+use std::collections::HashMap;
+
+/// Heading of the footnotes section appended to rendered articles.
+pub const HEADING: &str = "Footnotes";
+
+/// Pull footnotes out of article HTML. Each reference (a `#fragment` link inside `<sup>`, or
+/// one marked as a footnote) becomes a plain `[^n]` marker, and the elements it points at are
+/// removed from the body and returned as plain text, numbered in order of first reference.
+pub fn extract(html: &str) -> (String, Vec<String>) {
+    let mut document = Html::parse_fragment(html);
+    let (Ok(anchors), Ok(with_id)) = (Selector::parse("a[href^='#']"), Selector::parse("[id]"))
+    else {
+        return (html.to_string(), Vec::new());
+    };
+
+    let targets: HashMap<&str, ElementRef> = document
+        .select(&with_id)
+        .filter_map(|el| el.value().id().map(|id| (id, el)))
+        .collect();
+
+    // (node to replace, target id) for every footnote reference
+    let mut refs = Vec::new();
+    for anchor in document.select(&anchors) {
+        let Some(id) = anchor.value().attr("href").map(|h| &h[1..]) else {
+            continue;
+        };
+        let Some(target) = targets.get(id) else {
+            continue;
+        };
+        let in_sup = anchor
+            .parent()
+            .and_then(ElementRef::wrap)
+            .filter(|p| p.value().name() == "sup");
+        if in_sup.is_none() && !is_marked_footnote_ref(anchor) {
+            continue;
+        }
+        // A backlink inside a footnote pointing at its own reference is not a reference
+        if target.id() == anchor.id() || anchor.ancestors().any(|a| a.id() == target.id()) {
+            continue;
+        }
+        refs.push((in_sup.unwrap_or(anchor).id(), id.to_string()));
+    }
+
+    let mut numbers: HashMap<String, usize> = HashMap::new();
+    let mut notes = Vec::new();
+    let mut definitions = Vec::new();
+    for (_, id) in &refs {
+        if numbers.contains_key(id) {
+            continue;
+        }
+        let target = targets[id.as_str()];
+        numbers.insert(id.clone(), notes.len() + 1);
+        notes.push(note_text(target));
+        definitions.push(target.id());
+    }
+
+    // References nested in other footnotes are dropped along with their footnote
+    let refs: Vec<_> = refs
+        .into_iter()
+        .filter(|(node, _)| {
+            document
+                .tree
+                .get(*node)
+                .is_some_and(|n| !n.ancestors().any(|a| definitions.contains(&a.id())))
+        })
+        .map(|(node, id)| (node, numbers[&id]))
+        .collect();
+
+    // Footnote containers ("footnotes" class or endnotes role) go too, with their <hr>
+    let containers: Vec<_> = document
+        .tree
+        .root()
+        .descendants()
+        .filter(|n| {
+            n.value().as_element().is_some_and(|el| {
+                el.classes().any(|c| c.eq_ignore_ascii_case("footnotes"))
+                    || el.attr("role") == Some("doc-endnotes")
+            }) && n.descendants().any(|d| definitions.contains(&d.id()))
+        })
+        .map(|n| n.id())
+        .collect();
+
+    if notes.is_empty() {
+        return (html.to_string(), Vec::new());
+    }
+
+    for (node, number) in refs {
+        if let Some(mut node) = document.tree.get_mut(node) {
+            node.insert_before(Node::Text(Text {
+                text: marker(number).as_str().into(),
+            }));
+            node.detach();
+        }
+    }
+    for node in definitions.into_iter().chain(containers) {
+        if let Some(mut node) = document.tree.get_mut(node) {
+            node.detach();
+        }
+    }
+
+    (document.root_element().inner_html(), notes)
+}
+
+fn is_marked_footnote_ref(anchor: ElementRef) -> bool {
+    let el = anchor.value();
+    el.attr("rel") == Some("footnote")
+        || el.attr("role") == Some("doc-noteref")
+        || el.classes().any(|c| c.eq_ignore_ascii_case("footnote-ref"))
+}
+
+/// The `[^n]` marker used for footnote `n`, markdown style so it can't be mistaken for
+/// html2text's `[n]` link references.
+pub fn marker(number: usize) -> String {
+    format!("[^{}]", number)
+}
+
+/// A footnote's text on one line, without its "back to reference" arrows.
+fn note_text(definition: ElementRef) -> String {
+    // Trivial decorator: no link reference lists or emphasis markers in a one-line note
+    let text = html2text::from_read_with_decorator(
+        definition.inner_html().as_bytes(),
+        10_000,
+        TrivialDecorator::new(),
+    );
+    text.replace(['\u{21a9}', '\u{fe0e}'], "")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Logical line indexes of the first reference to each footnote and of the footnote itself,
+/// in `text` as produced by [`crate::ui::utils::article_text`].
+pub fn jump_lines(text: &str) -> Vec<(usize, usize)> {
+    let lines: Vec<&str> = text.lines().collect();
+    let Some(heading) = lines.iter().rposition(|l| *l == HEADING) else {
+        return Vec::new();
+    };
+
+    let mut jumps = Vec::new();
+    for (note_line, line) in lines.iter().enumerate().skip(heading + 1) {
+        let Some(label) = line.split(' ').next().filter(|m| m.starts_with("[^")) else {
+            continue;
+        };
+        if let Some(ref_line) = lines[..heading].iter().position(|l| l.contains(label)) {
+            jumps.push((ref_line, note_line));
+        }
+    }
+    jumps
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ARTICLE: &str = r##"<p>Claim<sup id="fnref1"><a href="#fn1">1</a></sup> and
+        another<sup><a href="#fn2">2</a></sup>, again<sup><a href="#fn1">1</a></sup>.</p>
+        <p><a href="#top">Top</a></p>
+        <div class="footnotes"><hr><ol>
+        <li id="fn1"><p>First source. <a href="#fnref1">&#8617;</a></p></li>
+        <li id="fn2"><p>Second <em>source</em>.</p></li>
+        </ol></div>"##;
+
+    #[test]
+    fn test_extract_numbers_references_and_moves_notes() {
+        let (body, notes) = extract(ARTICLE);
+        assert_eq!(notes, vec!["First source.", "Second source."]);
+        assert!(body.contains("Claim[^1] and"));
+        assert!(body.contains("another[^2], again[^1]."));
+        assert!(!body.contains("First source"));
+        assert!(!body.contains("<hr>"));
+        // Ordinary in-page links are left alone
+        assert!(body.contains("href=\"#top\""));
+    }
+
+    #[test]
+    fn test_extract_leaves_html_without_footnotes_untouched() {
+        let html = "<p>Plain <sup>2</sup> text</p>";
+        assert_eq!(extract(html), (html.to_string(), Vec::new()));
+    }
+
+    #[test]
+    fn test_jump_lines_pairs_reference_with_note() {
+        let text = "Intro[1]\n\nClaim[^1] here\n\nMore[^2]\n\nFootnotes\n\n[^1] One\n\n[^2] Two";
+        assert_eq!(jump_lines(text), vec![(2, 8), (4, 10)]);
+    }
+}
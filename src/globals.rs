@@ -0,0 +1,119 @@
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Settings from the command-line flags every subcommand accepts.
+#[derive(Clone, Debug, Default)]
+pub struct GlobalOptions {
+    /// Directory holding the data file instead of the platform default
+    pub data_dir: Option<PathBuf>,
+    /// Named profile with its own config and data, kept apart from the default ones
+    pub profile: Option<String>,
+    /// Report progress and per-feed fetch details on stderr
+    pub verbose: bool,
+    /// Save bandwidth as if `network.metered` were set
+    pub lite: bool,
+    /// Show the bundled fixture feeds, keeping config and data in `demo_dir`
+    pub demo: bool,
+    /// Leave the config and data file as they are, refusing edits to subscriptions
+    pub read_only: bool,
+}
+
+static OPTIONS: OnceLock<GlobalOptions> = OnceLock::new();
+
+/// Record the global options for the rest of the process. Call once, before anything
+/// loads the config or data file.
+pub fn init(mut options: GlobalOptions) -> Result<()> {
+    if let Some(profile) = &options.profile {
+        if profile.is_empty()
+            || !profile
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            anyhow::bail!(
+                "Invalid profile name \"{}\": use letters, digits, '-' and '_'",
+                profile
+            );
+        }
+    }
+    if options.demo {
+        // A fresh scratch directory each run, so the demo starts the same every time
+        let dir = demo_dir();
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir)?;
+        options.data_dir = Some(dir);
+        options.profile = None;
+        options.lite = true;
+    }
+    OPTIONS
+        .set(options)
+        .map_err(|_| anyhow::anyhow!("Global options were already set"))
+}
+
+fn options() -> &'static GlobalOptions {
+    OPTIONS.get_or_init(GlobalOptions::default)
+}
+
+pub fn verbose() -> bool {
+    options().verbose
+}
+
+/// Whether this run is `--demo`.
+pub fn demo() -> bool {
+    options().demo
+}
+
+/// Where `--demo` keeps its config and data, so the real ones are never touched.
+pub fn demo_dir() -> PathBuf {
+    std::env::temp_dir().join(format!("feedr-demo-{}", std::process::id()))
+}
+
+/// Whether `--lite` was given for this run.
+pub fn lite() -> bool {
+    options().lite
+}
+
+/// Whether `--read-only` was given for this run.
+pub fn read_only() -> bool {
+    options().read_only
+}
+
+/// Fail with "`what` is disabled in read-only mode" when running `--read-only`.
+pub fn ensure_writable(what: &str) -> Result<()> {
+    if read_only() {
+        anyhow::bail!("{} is disabled in read-only mode", what);
+    }
+    Ok(())
+}
+
+/// Print a progress message on stderr when `--verbose` is set.
+pub fn log(message: &str) {
+    if verbose() {
+        eprintln!("{}", message);
+    }
+}
+
+/// The directory given with `--data-dir`, if any.
+pub fn data_dir_override() -> Option<&'static Path> {
+    options().data_dir.as_deref()
+}
+
+/// The active profile, if one was chosen with `--profile`.
+pub fn profile() -> Option<&'static str> {
+    options().profile.as_deref()
+}
+
+/// Feedr's directory under `base` (e.g. `~/.config/feedr`), or the profile's own
+/// directory beneath it (`~/.config/feedr/profiles/<name>`).
+pub fn app_dir(base: Option<PathBuf>) -> PathBuf {
+    if demo() {
+        return demo_dir();
+    }
+    let mut path = base.unwrap_or_else(|| Path::new(".").to_path_buf());
+    path.push("feedr");
+    if let Some(profile) = profile() {
+        path.push("profiles");
+        path.push(profile);
+    }
+    path
+}
@@ -0,0 +1,163 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Raw feed responses kept on disk, keyed by URL, along with the validators needed to
+/// ask the server whether they're still current.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HttpCache {
+    dir: PathBuf,
+}
+
+/// A cached response body and what's known about it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CachedResponse {
+    pub body: Vec<u8>,
+    pub meta: CacheMeta,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CacheMeta {
+    pub url: String,
+    /// Where the request ended up after redirects
+    pub final_url: String,
+    pub content_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_modified: Option<String>,
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl CacheMeta {
+    /// Metadata for a plain download, with no validators to revalidate it by.
+    pub fn downloaded(url: &str, content_type: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            final_url: url.to_string(),
+            content_type: content_type.to_string(),
+            etag: None,
+            last_modified: None,
+            fetched_at: Utc::now(),
+        }
+    }
+}
+
+impl HttpCache {
+    pub fn new(dir: PathBuf) -> Self {
+        Self { dir }
+    }
+
+    /// The cache under the user's cache directory (e.g. `~/.cache/feedr/http`).
+    pub fn default_location() -> Self {
+        Self::named("http")
+    }
+
+    /// Article pages and images of saved items, kept for reading offline
+    /// (e.g. `~/.cache/feedr/offline`).
+    pub fn offline_location() -> Self {
+        Self::named("offline")
+    }
+
+    fn named(name: &str) -> Self {
+        let mut dir = dirs::cache_dir().unwrap_or_else(|| Path::new(".").to_path_buf());
+        dir.push("feedr");
+        dir.push(name);
+        Self::new(dir)
+    }
+
+    /// The cached response for `url`, if there is a readable one.
+    pub fn load(&self, url: &str) -> Option<CachedResponse> {
+        let key = cache_key(url);
+        let meta: CacheMeta =
+            serde_json::from_slice(&fs::read(self.dir.join(format!("{}.json", key))).ok()?).ok()?;
+        // Guard against the (unlikely) hash collision
+        if meta.url != url {
+            return None;
+        }
+        let body = fs::read(self.dir.join(format!("{}.body", key))).ok()?;
+        Some(CachedResponse { body, meta })
+    }
+
+    /// Save a response, replacing any earlier one for the same URL.
+    pub fn store(&self, body: &[u8], meta: &CacheMeta) -> Result<()> {
+        fs::create_dir_all(&self.dir)
+            .with_context(|| format!("Failed to create {}", self.dir.display()))?;
+        let key = cache_key(&meta.url);
+        // Body first, so metadata never points at a body that isn't there yet
+        write_atomic(&self.dir.join(format!("{}.body", key)), body)?;
+        write_atomic(
+            &self.dir.join(format!("{}.json", key)),
+            &serde_json::to_vec(meta)?,
+        )
+    }
+
+    /// Drop the cached response for `url`, if any.
+    pub fn remove(&self, url: &str) {
+        let key = cache_key(url);
+        let _ = fs::remove_file(self.dir.join(format!("{}.json", key)));
+        let _ = fs::remove_file(self.dir.join(format!("{}.body", key)));
+    }
+}
+
+/// File name stem for `url`: a 64-bit FNV-1a hash, stable across runs and Rust versions.
+fn cache_key(url: &str) -> String {
+    let hash = url.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    });
+    format!("{:016x}", hash)
+}
+
+/// Write to a temp file first so an interrupted write never leaves a truncated entry.
+fn write_atomic(path: &Path, contents: &[u8]) -> Result<()> {
+    let tmp_path = path.with_extension("tmp");
+    fs::write(&tmp_path, contents)?;
+    fs::rename(tmp_path, path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn meta(url: &str) -> CacheMeta {
+        CacheMeta {
+            url: url.to_string(),
+            final_url: url.to_string(),
+            content_type: "application/rss+xml".to_string(),
+            etag: Some("\"abc\"".to_string()),
+            last_modified: None,
+            fetched_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_store_and_load_round_trip() {
+        let dir = std::env::temp_dir().join(format!("feedr-http-cache-{}", uuid::Uuid::new_v4()));
+        let cache = HttpCache::new(dir.clone());
+        let url = "https://example.com/feed.xml";
+        assert_eq!(cache.load(url), None);
+
+        cache.store(b"<rss/>", &meta(url)).unwrap();
+        let cached = cache.load(url).unwrap();
+        assert_eq!(cached.body, b"<rss/>");
+        assert_eq!(cached.meta.etag.as_deref(), Some("\"abc\""));
+        assert_eq!(cache.load("https://example.com/other.xml"), None);
+
+        cache.remove(url);
+        assert_eq!(cache.load(url), None);
+        let _ = fs::remove_dir_all(dir);
+    }
+
+    #[test]
+    fn test_cache_key_is_stable() {
+        assert_eq!(cache_key(""), "cbf29ce484222325");
+        assert_eq!(cache_key("a"), "af63dc4c8601ec8c");
+        assert_ne!(
+            cache_key("https://example.com/a"),
+            cache_key("https://example.com/b")
+        );
+    }
+}
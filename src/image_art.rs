@@ -0,0 +1,202 @@
+use anyhow::{bail, Context, Result};
+#[cfg(feature = "images")]
+use image::imageops::FilterType;
+use scraper::{Html, Selector};
+
+/// Largest image download accepted for block art, in bytes.
+const MAX_IMAGE_BYTES: usize = 10 * 1024 * 1024;
+
+/// An image scaled down to terminal cells. Each cell is drawn as an upper half
+/// block with the top pixel as foreground and the bottom pixel as background.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ImageArt {
+    pub rows: Vec<Vec<([u8; 3], [u8; 3])>>,
+}
+
+impl ImageArt {
+    pub fn width(&self) -> u16 {
+        self.rows.first().map_or(0, |row| row.len() as u16)
+    }
+
+    pub fn height(&self) -> u16 {
+        self.rows.len() as u16
+    }
+}
+
+/// Decode `bytes` and scale the image to fit within `max_width` x `max_height` cells,
+/// keeping its aspect ratio. Images are never scaled up.
+#[cfg(feature = "images")]
+pub fn from_bytes(bytes: &[u8], max_width: u16, max_height: u16) -> Result<ImageArt> {
+    let image = image::load_from_memory(bytes).context("Unsupported image format")?;
+    let (width, height) = (image.width().max(1), image.height().max(1));
+
+    // Two pixels per cell vertically, so cells come out roughly square
+    let scale = (max_width as f64 / width as f64)
+        .min((max_height as f64 * 2.0) / height as f64)
+        .min(1.0);
+    let target_width = ((width as f64 * scale).round() as u32).max(1);
+    let target_height = ((height as f64 * scale).round() as u32).max(2) & !1;
+
+    let pixels = image::imageops::resize(
+        &image.to_rgb8(),
+        target_width,
+        target_height,
+        FilterType::Triangle,
+    );
+    let rows = (0..target_height / 2)
+        .map(|row| {
+            (0..target_width)
+                .map(|col| {
+                    (
+                        pixels.get_pixel(col, row * 2).0,
+                        pixels.get_pixel(col, row * 2 + 1).0,
+                    )
+                })
+                .collect()
+        })
+        .collect();
+    Ok(ImageArt { rows })
+}
+
+/// Without the `images` feature there's no decoder; every image is reported as unsupported.
+#[cfg(not(feature = "images"))]
+pub fn from_bytes(_bytes: &[u8], _max_width: u16, _max_height: u16) -> Result<ImageArt> {
+    bail!("feedr was built without the `images` feature")
+}
+
+/// Download the image at `url` and convert it with [`from_bytes`].
+pub fn fetch(
+    url: &str,
+    client: &reqwest::blocking::Client,
+    user_agent: &str,
+    max_width: u16,
+    max_height: u16,
+) -> Result<ImageArt> {
+    from_bytes(&download(url, client, user_agent)?, max_width, max_height)
+}
+
+/// Download the image at `url`, refusing anything over the size limit.
+pub fn download(
+    url: &str,
+    client: &reqwest::blocking::Client,
+    user_agent: &str,
+) -> Result<Vec<u8>> {
+    let bytes = client
+        .get(url)
+        .header("User-Agent", user_agent)
+        .send()
+        .and_then(|r| r.error_for_status())
+        .with_context(|| format!("Failed to fetch {}", url))?
+        .bytes()?;
+    if bytes.len() > MAX_IMAGE_BYTES {
+        bail!("Image is too large ({} bytes)", bytes.len());
+    }
+    Ok(bytes.to_vec())
+}
+
+/// An article's main image with the text that goes with it: the hover text (`title`)
+/// webcomics like xkcd put the punchline in, and the `alt` description.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Panel {
+    pub url: String,
+    pub title: Option<String>,
+    pub alt: Option<String>,
+}
+
+impl Panel {
+    /// The text to show with the image: the hover text, else the alt text.
+    pub fn caption(&self) -> Option<&str> {
+        self.title.as_deref().or(self.alt.as_deref())
+    }
+}
+
+/// The first `<img>` in an article's HTML, resolved against `base` when it's relative.
+pub fn first_image(html: &str, base: Option<&str>) -> Option<String> {
+    first_panel(html, base).map(|panel| panel.url)
+}
+
+/// The first `<img>` in an article's HTML with its hover and alt text.
+pub fn first_panel(html: &str, base: Option<&str>) -> Option<Panel> {
+    let selector = Selector::parse("img[src]").ok()?;
+    let document = Html::parse_fragment(html);
+    let img = document.select(&selector).next()?.value();
+    let src = img.attr("src")?;
+    let base = base.and_then(|b| url::Url::parse(b).ok());
+    let url = match base {
+        Some(base) => base.join(src).ok()?.to_string(),
+        None => src.to_string(),
+    };
+    let text = |name: &str| {
+        img.attr(name)
+            .map(str::trim)
+            .filter(|text| !text.is_empty())
+            .map(str::to_string)
+    };
+    Some(Panel {
+        url,
+        title: text("title"),
+        alt: text("alt"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "images")]
+    use image::{ImageOutputFormat, Rgb, RgbImage};
+    #[cfg(feature = "images")]
+    use std::io::Cursor;
+
+    #[cfg(feature = "images")]
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        // Red top half, blue bottom half
+        let image = RgbImage::from_fn(width, height, |_, y| {
+            if y < height / 2 {
+                Rgb([255, 0, 0])
+            } else {
+                Rgb([0, 0, 255])
+            }
+        });
+        let mut bytes = Cursor::new(Vec::new());
+        image.write_to(&mut bytes, ImageOutputFormat::Png).unwrap();
+        bytes.into_inner()
+    }
+
+    #[cfg(feature = "images")]
+    #[test]
+    fn test_from_bytes_fits_within_max_size() {
+        let art = from_bytes(&png(400, 200), 40, 20).unwrap();
+        assert_eq!(art.width(), 40);
+        assert_eq!(art.height(), 10);
+        assert_eq!(art.rows[0][0].0, [255, 0, 0]);
+        assert_eq!(art.rows[9][0].1, [0, 0, 255]);
+
+        // Small images keep their size
+        let art = from_bytes(&png(8, 8), 40, 20).unwrap();
+        assert_eq!((art.width(), art.height()), (8, 4));
+
+        assert!(from_bytes(b"not an image", 40, 20).is_err());
+    }
+
+    #[test]
+    fn test_first_image_resolves_relative_src() {
+        let html = r#"<p>Intro</p><img src="/img/lead.png"><img src="second.png">"#;
+        assert_eq!(
+            first_image(html, Some("https://example.com/posts/1")).as_deref(),
+            Some("https://example.com/img/lead.png")
+        );
+        assert_eq!(first_image("<p>No images</p>", None), None);
+    }
+
+    #[test]
+    fn test_first_panel_keeps_hover_text() {
+        let html = r#"<img src="//imgs.xkcd.com/comics/tar.png" title="I don't know what's worse." alt="tar">"#;
+        let panel = first_panel(html, Some("https://xkcd.com/1168/")).unwrap();
+        assert_eq!(panel.url, "https://imgs.xkcd.com/comics/tar.png");
+        assert_eq!(panel.caption(), Some("I don't know what's worse."));
+
+        let panel = first_panel(r#"<img src="a.png" alt=" A cat " title="">"#, None).unwrap();
+        assert_eq!(panel.title, None);
+        assert_eq!(panel.caption(), Some("A cat"));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::cli::KeysFormat;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use std::collections::HashMap;
 use std::str::FromStr;
@@ -9,10 +10,20 @@ pub enum KeyAction {
     ForceQuit,
     Back,
     Home,
+    HistoryBack,
+    HistoryForward,
+    ExplainItem,
     ToggleTheme,
     Refresh,
+    RefreshFeed,
+    ShowFailedFeeds,
+    OpenScheduler,
     Help,
+    Tour,
+    ExportKeys,
     OpenSearch,
+    RecordMacro,
+    PlayMacro,
     // Navigation
     MoveUp,
     MoveDown,
@@ -27,63 +38,495 @@ pub enum KeyAction {
     ToggleRead,
     ToggleStar,
     MarkAllRead,
+    OpenAlerts,
+    Triage,
+    CatchUp,
+    ReadingSession,
+    OpenReadLater,
+    ToggleMute,
+    ArchiveFeed,
+    FeedInfo,
+    ReleaseTimeline,
     OpenInBrowser,
+    OpenComments,
     TogglePreview,
+    NextSection,
+    PrevSection,
+    Share,
+    ShareWebhook,
+    ContextMenu,
+    FullTitle,
     // Filter/Category
     OpenFilter,
+    ToggleItemSort,
     CycleCategory,
     OpenCategoryManagement,
     AssignCategory,
     // Detail
     ExtractLinks,
+    CopyMode,
+    ToggleWrap,
+    ZenMode,
+    JumpFootnote,
+    Translate,
+    Summarize,
+    ConfirmTags,
+    MuteMenu,
+    ExportArticle,
+    MakeTask,
+    ExportEvent,
+    OpenImage,
+    ViewSource,
     ScrollPreviewUp,
     ScrollPreviewDown,
     // Tree
     ToggleExpand,
+    CycleFeedSort,
+    ImportOpml,
+    ExportOpml,
+    Undo,
+    Redo,
     // Tab
     NextTab,
     PrevTab,
 }
 
+/// Every action with its config name (as used in `[keybindings]`), the group it's listed
+/// under and what it does. The cheat sheet and config parsing both come from this table.
+const ACTIONS: &[(KeyAction, &str, &str, &str)] = &[
+    (
+        KeyAction::Quit,
+        "quit",
+        "Global",
+        "Quit, or go back from a sub-view",
+    ),
+    (
+        KeyAction::ForceQuit,
+        "force_quit",
+        "Global",
+        "Quit from any view",
+    ),
+    (
+        KeyAction::Back,
+        "back",
+        "Global",
+        "Go back to the previous view",
+    ),
+    (KeyAction::Home, "home", "Global", "Go to the dashboard"),
+    (
+        KeyAction::HistoryBack,
+        "history_back",
+        "Global",
+        "Back to the previous place",
+    ),
+    (
+        KeyAction::HistoryForward,
+        "history_forward",
+        "Global",
+        "Forward again after going back",
+    ),
+    (
+        KeyAction::ExplainItem,
+        "explain_item",
+        "Global",
+        "Explain why the selected item is shown",
+    ),
+    (
+        KeyAction::ToggleTheme,
+        "toggle_theme",
+        "Global",
+        "Toggle theme (dark/light)",
+    ),
+    (KeyAction::Refresh, "refresh", "Global", "Refresh all feeds"),
+    (
+        KeyAction::RefreshFeed,
+        "refresh_feed",
+        "Global",
+        "Refresh the selected feed",
+    ),
+    (
+        KeyAction::ShowFailedFeeds,
+        "failed_feeds",
+        "Global",
+        "Show feeds that failed to load",
+    ),
+    (
+        KeyAction::OpenScheduler,
+        "open_scheduler",
+        "Global",
+        "Show the refresh schedule",
+    ),
+    (KeyAction::Help, "help", "Global", "Show the help overlay"),
+    (
+        KeyAction::Tour,
+        "tour",
+        "Global",
+        "Replay the onboarding tour",
+    ),
+    (
+        KeyAction::ExportKeys,
+        "export_keys",
+        "Global",
+        "Export this cheat sheet (in the help overlay)",
+    ),
+    (
+        KeyAction::OpenSearch,
+        "open_search",
+        "Global",
+        "Search across all feeds",
+    ),
+    (
+        KeyAction::RecordMacro,
+        "record_macro",
+        "Global",
+        "Start or stop recording a macro",
+    ),
+    (
+        KeyAction::PlayMacro,
+        "play_macro",
+        "Global",
+        "Replay the recorded macro",
+    ),
+    (KeyAction::MoveUp, "move_up", "Navigation", "Move up"),
+    (KeyAction::MoveDown, "move_down", "Navigation", "Move down"),
+    (KeyAction::PageUp, "page_up", "Navigation", "Page up"),
+    (KeyAction::PageDown, "page_down", "Navigation", "Page down"),
+    (
+        KeyAction::JumpTop,
+        "jump_top",
+        "Navigation",
+        "Jump to the top",
+    ),
+    (
+        KeyAction::JumpBottom,
+        "jump_bottom",
+        "Navigation",
+        "Jump to the bottom",
+    ),
+    (
+        KeyAction::Select,
+        "select",
+        "Navigation",
+        "Open the selected entry",
+    ),
+    (
+        KeyAction::AddFeed,
+        "add_feed",
+        "Items and feeds",
+        "Add a feed",
+    ),
+    (
+        KeyAction::DeleteFeed,
+        "delete_feed",
+        "Items and feeds",
+        "Delete a feed or category",
+    ),
+    (
+        KeyAction::ToggleRead,
+        "toggle_read",
+        "Items and feeds",
+        "Toggle read/unread",
+    ),
+    (
+        KeyAction::ToggleStar,
+        "toggle_star",
+        "Items and feeds",
+        "Star/unstar an article",
+    ),
+    (
+        KeyAction::MarkAllRead,
+        "mark_all_read",
+        "Items and feeds",
+        "Mark everything visible as read",
+    ),
+    (
+        KeyAction::OpenAlerts,
+        "open_alerts",
+        "Items and feeds",
+        "Show keyword alerts",
+    ),
+    (
+        KeyAction::Triage,
+        "triage",
+        "Items and feeds",
+        "Triage unread items one at a time",
+    ),
+    (
+        KeyAction::CatchUp,
+        "catch_up",
+        "Items and feeds",
+        "Open a random unread article, favouring starred and well-read feeds",
+    ),
+    (
+        KeyAction::ReadingSession,
+        "reading_session",
+        "Items and feeds",
+        "Read unread articles that fit in ui.session_minutes, one after another",
+    ),
+    (
+        KeyAction::OpenReadLater,
+        "open_read_later",
+        "Items and feeds",
+        "Show the read-later queue",
+    ),
+    (
+        KeyAction::ToggleMute,
+        "toggle_mute",
+        "Items and feeds",
+        "Mute/unmute a feed",
+    ),
+    (
+        KeyAction::ArchiveFeed,
+        "archive_feed",
+        "Items and feeds",
+        "Archive a feed / resubscribe",
+    ),
+    (
+        KeyAction::FeedInfo,
+        "feed_info",
+        "Items and feeds",
+        "Show feed details",
+    ),
+    (
+        KeyAction::ReleaseTimeline,
+        "release_timeline",
+        "Items and feeds",
+        "Show a release feed's version timeline",
+    ),
+    (
+        KeyAction::OpenInBrowser,
+        "open_in_browser",
+        "Items and feeds",
+        "Open in the browser",
+    ),
+    (
+        KeyAction::OpenComments,
+        "open_comments",
+        "Items and feeds",
+        "Open the item's comments in the browser",
+    ),
+    (
+        KeyAction::TogglePreview,
+        "toggle_preview",
+        "Items and feeds",
+        "Toggle the preview pane",
+    ),
+    (
+        KeyAction::NextSection,
+        "next_section",
+        "Items and feeds",
+        "Focus the next dashboard section",
+    ),
+    (
+        KeyAction::PrevSection,
+        "prev_section",
+        "Items and feeds",
+        "Focus the previous dashboard section",
+    ),
+    (
+        KeyAction::Share,
+        "share",
+        "Items and feeds",
+        "Share (copy or run command)",
+    ),
+    (
+        KeyAction::ShareWebhook,
+        "share_webhook",
+        "Items and feeds",
+        "Post the article to a configured chat webhook",
+    ),
+    (
+        KeyAction::ContextMenu,
+        "context_menu",
+        "Items and feeds",
+        "Show everything you can do with the selected item",
+    ),
+    (
+        KeyAction::FullTitle,
+        "full_title",
+        "Items and feeds",
+        "Show the selected item's full title and snippet",
+    ),
+    (
+        KeyAction::OpenFilter,
+        "open_filter",
+        "Filters and categories",
+        "Open the filter menu",
+    ),
+    (
+        KeyAction::ToggleItemSort,
+        "toggle_item_sort",
+        "Filters and categories",
+        "Toggle newest/oldest first",
+    ),
+    (
+        KeyAction::CycleCategory,
+        "cycle_category",
+        "Filters and categories",
+        "Cycle the category filter",
+    ),
+    (
+        KeyAction::OpenCategoryManagement,
+        "open_category_management",
+        "Filters and categories",
+        "Manage categories",
+    ),
+    (
+        KeyAction::AssignCategory,
+        "assign_category",
+        "Filters and categories",
+        "Assign a feed to a category",
+    ),
+    (
+        KeyAction::ExtractLinks,
+        "extract_links",
+        "Article",
+        "List the article's links",
+    ),
+    (
+        KeyAction::CopyMode,
+        "copy_mode",
+        "Article",
+        "Select text to copy",
+    ),
+    (
+        KeyAction::ToggleWrap,
+        "toggle_wrap",
+        "Article",
+        "Toggle line wrapping",
+    ),
+    (KeyAction::ZenMode, "zen_mode", "Article", "Toggle zen mode"),
+    (
+        KeyAction::JumpFootnote,
+        "jump_footnote",
+        "Article",
+        "Jump to a footnote and back",
+    ),
+    (
+        KeyAction::Translate,
+        "translate",
+        "Article",
+        "Translate with the configured command",
+    ),
+    (
+        KeyAction::Summarize,
+        "summarize",
+        "Article",
+        "Summarize with the configured command",
+    ),
+    (
+        KeyAction::ConfirmTags,
+        "confirm_tags",
+        "Article",
+        "Confirm the suggested tags, or undo that",
+    ),
+    (
+        KeyAction::MuteMenu,
+        "mute_menu",
+        "Article",
+        "Mute the article's author or domain",
+    ),
+    (
+        KeyAction::ExportArticle,
+        "export_article",
+        "Article",
+        "Export as standalone HTML (and PDF, if configured)",
+    ),
+    (
+        KeyAction::MakeTask,
+        "make_task",
+        "Article",
+        "File the article as a task with the configured command",
+    ),
+    (
+        KeyAction::ExportEvent,
+        "export_event",
+        "Article",
+        "Save the event the article announces as .ics",
+    ),
+    (
+        KeyAction::OpenImage,
+        "open_image",
+        "Article",
+        "Open the article's image in the system image viewer",
+    ),
+    (
+        KeyAction::ViewSource,
+        "view_source",
+        "Article",
+        "Cycle between the article text, its HTML and its feed entry",
+    ),
+    (
+        KeyAction::ScrollPreviewUp,
+        "scroll_preview_up",
+        "Article",
+        "Scroll the preview pane up",
+    ),
+    (
+        KeyAction::ScrollPreviewDown,
+        "scroll_preview_down",
+        "Article",
+        "Scroll the preview pane down",
+    ),
+    (
+        KeyAction::ToggleExpand,
+        "toggle_expand",
+        "Feed tree",
+        "Expand/collapse a category",
+    ),
+    (
+        KeyAction::CycleFeedSort,
+        "cycle_feed_sort",
+        "Feed tree",
+        "Cycle the feed sort order",
+    ),
+    (
+        KeyAction::ImportOpml,
+        "import_opml",
+        "Feed tree",
+        "Import feeds from an OPML file",
+    ),
+    (
+        KeyAction::ExportOpml,
+        "export_opml",
+        "Feed tree",
+        "Export subscriptions to an OPML file",
+    ),
+    (
+        KeyAction::Undo,
+        "undo",
+        "Feed tree",
+        "Undo the last category or subscription edit",
+    ),
+    (
+        KeyAction::Redo,
+        "redo",
+        "Feed tree",
+        "Redo the last undone edit",
+    ),
+    (KeyAction::NextTab, "next_tab", "Tabs", "Next view"),
+    (KeyAction::PrevTab, "prev_tab", "Tabs", "Previous view"),
+];
+
+impl KeyAction {
+    /// The action's name in the `[keybindings]` config section.
+    pub fn name(&self) -> &'static str {
+        ACTIONS
+            .iter()
+            .find(|(action, ..)| action == self)
+            .map_or("", |&(_, name, ..)| name)
+    }
+}
+
 impl FromStr for KeyAction {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match s {
-            "quit" => Ok(Self::Quit),
-            "force_quit" => Ok(Self::ForceQuit),
-            "back" => Ok(Self::Back),
-            "home" => Ok(Self::Home),
-            "toggle_theme" => Ok(Self::ToggleTheme),
-            "refresh" => Ok(Self::Refresh),
-            "help" => Ok(Self::Help),
-            "open_search" => Ok(Self::OpenSearch),
-            "move_up" => Ok(Self::MoveUp),
-            "move_down" => Ok(Self::MoveDown),
-            "page_up" => Ok(Self::PageUp),
-            "page_down" => Ok(Self::PageDown),
-            "jump_top" => Ok(Self::JumpTop),
-            "jump_bottom" => Ok(Self::JumpBottom),
-            "select" => Ok(Self::Select),
-            "add_feed" => Ok(Self::AddFeed),
-            "delete_feed" => Ok(Self::DeleteFeed),
-            "toggle_read" => Ok(Self::ToggleRead),
-            "toggle_star" => Ok(Self::ToggleStar),
-            "mark_all_read" => Ok(Self::MarkAllRead),
-            "open_in_browser" => Ok(Self::OpenInBrowser),
-            "toggle_preview" => Ok(Self::TogglePreview),
-            "open_filter" => Ok(Self::OpenFilter),
-            "cycle_category" => Ok(Self::CycleCategory),
-            "open_category_management" => Ok(Self::OpenCategoryManagement),
-            "assign_category" => Ok(Self::AssignCategory),
-            "extract_links" => Ok(Self::ExtractLinks),
-            "scroll_preview_up" => Ok(Self::ScrollPreviewUp),
-            "scroll_preview_down" => Ok(Self::ScrollPreviewDown),
-            "toggle_expand" => Ok(Self::ToggleExpand),
-            "next_tab" => Ok(Self::NextTab),
-            "prev_tab" => Ok(Self::PrevTab),
-            _ => Err(()),
-        }
+        ACTIONS
+            .iter()
+            .find(|(_, name, ..)| *name == s)
+            .map(|(action, ..)| action.clone())
+            .ok_or(())
     }
 }
 
@@ -108,6 +551,13 @@ impl KeyBinding {
         }
     }
 
+    pub fn with_alt(code: KeyCode) -> Self {
+        Self {
+            code,
+            modifiers: KeyModifiers::ALT,
+        }
+    }
+
     pub fn with_shift(code: KeyCode) -> Self {
         Self {
             code,
@@ -136,10 +586,24 @@ pub fn default_keybindings() -> KeyBindingMap {
         vec![
             KeyBinding::new(KeyCode::Char('h')),
             KeyBinding::new(KeyCode::Esc),
-            KeyBinding::new(KeyCode::Backspace),
         ],
     );
     map.insert(KeyAction::Home, vec![KeyBinding::new(KeyCode::Home)]);
+    map.insert(
+        KeyAction::HistoryBack,
+        vec![
+            KeyBinding::new(KeyCode::Backspace),
+            KeyBinding::with_alt(KeyCode::Left),
+        ],
+    );
+    map.insert(
+        KeyAction::HistoryForward,
+        vec![KeyBinding::with_alt(KeyCode::Right)],
+    );
+    map.insert(
+        KeyAction::ExplainItem,
+        vec![KeyBinding::new(KeyCode::Char('W'))],
+    );
     map.insert(
         KeyAction::ToggleTheme,
         vec![KeyBinding::new(KeyCode::Char('t'))],
@@ -149,10 +613,23 @@ pub fn default_keybindings() -> KeyBindingMap {
         vec![KeyBinding::new(KeyCode::Char('r'))],
     );
     map.insert(KeyAction::Help, vec![KeyBinding::new(KeyCode::Char('?'))]);
+    map.insert(KeyAction::Tour, vec![KeyBinding::new(KeyCode::F(1))]);
+    map.insert(
+        KeyAction::ExportKeys,
+        vec![KeyBinding::new(KeyCode::Char('e'))],
+    );
     map.insert(
         KeyAction::OpenSearch,
         vec![KeyBinding::new(KeyCode::Char('/'))],
     );
+    map.insert(
+        KeyAction::RecordMacro,
+        vec![KeyBinding::with_shift(KeyCode::Char('Q'))],
+    );
+    map.insert(
+        KeyAction::PlayMacro,
+        vec![KeyBinding::new(KeyCode::Char('@'))],
+    );
 
     // Navigation
     map.insert(
@@ -221,16 +698,37 @@ pub fn default_keybindings() -> KeyBindingMap {
         KeyAction::OpenInBrowser,
         vec![KeyBinding::new(KeyCode::Char('o'))],
     );
+    map.insert(
+        KeyAction::OpenComments,
+        vec![KeyBinding::new(KeyCode::Char('b'))],
+    );
     map.insert(
         KeyAction::TogglePreview,
         vec![KeyBinding::new(KeyCode::Char('p'))],
     );
+    map.insert(KeyAction::Share, vec![KeyBinding::new(KeyCode::Char('y'))]);
+    map.insert(
+        KeyAction::ShareWebhook,
+        vec![KeyBinding::with_shift(KeyCode::Char('Y'))],
+    );
+    map.insert(
+        KeyAction::ContextMenu,
+        vec![KeyBinding::new(KeyCode::Char('.'))],
+    );
+    map.insert(
+        KeyAction::FullTitle,
+        vec![KeyBinding::new(KeyCode::Char('F'))],
+    );
 
     // Filter/Category
     map.insert(
         KeyAction::OpenFilter,
         vec![KeyBinding::new(KeyCode::Char('f'))],
     );
+    map.insert(
+        KeyAction::ToggleItemSort,
+        vec![KeyBinding::new(KeyCode::Char('O'))],
+    );
     map.insert(
         KeyAction::CycleCategory,
         vec![KeyBinding::new(KeyCode::Char('c'))],
@@ -249,6 +747,111 @@ pub fn default_keybindings() -> KeyBindingMap {
         KeyAction::ExtractLinks,
         vec![KeyBinding::new(KeyCode::Char('l'))],
     );
+    map.insert(
+        KeyAction::CopyMode,
+        vec![KeyBinding::new(KeyCode::Char('v'))],
+    );
+    map.insert(
+        KeyAction::ToggleWrap,
+        vec![KeyBinding::new(KeyCode::Char('w'))],
+    );
+    map.insert(
+        KeyAction::ZenMode,
+        vec![KeyBinding::new(KeyCode::Char('z'))],
+    );
+    map.insert(
+        KeyAction::JumpFootnote,
+        vec![KeyBinding::new(KeyCode::Char('f'))],
+    );
+    map.insert(
+        KeyAction::OpenAlerts,
+        vec![KeyBinding::with_shift(KeyCode::Char('A'))],
+    );
+    map.insert(KeyAction::Triage, vec![KeyBinding::new(KeyCode::Char('i'))]);
+    map.insert(
+        KeyAction::CatchUp,
+        vec![KeyBinding::with_shift(KeyCode::Char('D'))],
+    );
+    map.insert(
+        KeyAction::ReadingSession,
+        vec![KeyBinding::with_shift(KeyCode::Char('Z'))],
+    );
+    map.insert(
+        KeyAction::OpenReadLater,
+        vec![KeyBinding::with_shift(KeyCode::Char('L'))],
+    );
+    map.insert(
+        KeyAction::ToggleMute,
+        vec![KeyBinding::with_shift(KeyCode::Char('M'))],
+    );
+    map.insert(
+        KeyAction::ArchiveFeed,
+        vec![KeyBinding::with_shift(KeyCode::Char('U'))],
+    );
+    map.insert(
+        KeyAction::NextSection,
+        vec![KeyBinding::new(KeyCode::Char(']'))],
+    );
+    map.insert(
+        KeyAction::PrevSection,
+        vec![KeyBinding::new(KeyCode::Char('['))],
+    );
+    map.insert(
+        KeyAction::RefreshFeed,
+        vec![KeyBinding::with_shift(KeyCode::Char('R'))],
+    );
+    map.insert(
+        KeyAction::ShowFailedFeeds,
+        vec![KeyBinding::new(KeyCode::Char('!'))],
+    );
+    map.insert(
+        KeyAction::OpenScheduler,
+        vec![KeyBinding::with_shift(KeyCode::Char('P'))],
+    );
+    map.insert(
+        KeyAction::FeedInfo,
+        vec![KeyBinding::with_shift(KeyCode::Char('I'))],
+    );
+    map.insert(
+        KeyAction::ReleaseTimeline,
+        vec![KeyBinding::with_shift(KeyCode::Char('H'))],
+    );
+    map.insert(
+        KeyAction::Translate,
+        vec![KeyBinding::with_shift(KeyCode::Char('T'))],
+    );
+    map.insert(
+        KeyAction::Summarize,
+        vec![KeyBinding::with_shift(KeyCode::Char('S'))],
+    );
+    map.insert(
+        KeyAction::ConfirmTags,
+        vec![KeyBinding::new(KeyCode::Char('#'))],
+    );
+    map.insert(
+        KeyAction::MuteMenu,
+        vec![KeyBinding::new(KeyCode::Char('m'))],
+    );
+    map.insert(
+        KeyAction::ExportArticle,
+        vec![KeyBinding::new(KeyCode::Char('x'))],
+    );
+    map.insert(
+        KeyAction::MakeTask,
+        vec![KeyBinding::with_shift(KeyCode::Char('N'))],
+    );
+    map.insert(
+        KeyAction::ExportEvent,
+        vec![KeyBinding::with_shift(KeyCode::Char('E'))],
+    );
+    map.insert(
+        KeyAction::OpenImage,
+        vec![KeyBinding::with_shift(KeyCode::Char('V'))],
+    );
+    map.insert(
+        KeyAction::ViewSource,
+        vec![KeyBinding::with_shift(KeyCode::Char('C'))],
+    );
     map.insert(
         KeyAction::ScrollPreviewUp,
         vec![
@@ -269,6 +872,24 @@ pub fn default_keybindings() -> KeyBindingMap {
         KeyAction::ToggleExpand,
         vec![KeyBinding::new(KeyCode::Char(' '))],
     );
+    map.insert(
+        KeyAction::CycleFeedSort,
+        vec![KeyBinding::new(KeyCode::Char('s'))],
+    );
+
+    map.insert(
+        KeyAction::ImportOpml,
+        vec![KeyBinding::with_ctrl(KeyCode::Char('o'))],
+    );
+    map.insert(
+        KeyAction::ExportOpml,
+        vec![KeyBinding::with_ctrl(KeyCode::Char('e'))],
+    );
+    map.insert(KeyAction::Undo, vec![KeyBinding::new(KeyCode::Char('u'))]);
+    map.insert(
+        KeyAction::Redo,
+        vec![KeyBinding::with_ctrl(KeyCode::Char('r'))],
+    );
 
     // Tab
     map.insert(KeyAction::NextTab, vec![KeyBinding::new(KeyCode::Tab)]);
@@ -389,41 +1010,112 @@ pub fn build_keybindings(
 
 /// Get display string for the first binding of an action
 pub fn key_display(action: &KeyAction, map: &KeyBindingMap) -> String {
-    if let Some(bindings) = map.get(action) {
-        if let Some(binding) = bindings.first() {
-            let mut parts = Vec::new();
-            if binding.modifiers.contains(KeyModifiers::CONTROL) {
-                parts.push("Ctrl".to_string());
-            }
-            if binding.modifiers.contains(KeyModifiers::SHIFT) {
-                parts.push("Shift".to_string());
+    map.get(action)
+        .and_then(|bindings| bindings.first())
+        .map_or_else(|| "?".to_string(), binding_display)
+}
+
+/// Display string for one binding, e.g. "q", "Ctrl+q" or "Shift+Tab"
+pub fn binding_display(binding: &KeyBinding) -> String {
+    let mut parts = Vec::new();
+    if binding.modifiers.contains(KeyModifiers::CONTROL) {
+        parts.push("Ctrl".to_string());
+    }
+    if binding.modifiers.contains(KeyModifiers::SHIFT) {
+        parts.push("Shift".to_string());
+    }
+    if binding.modifiers.contains(KeyModifiers::ALT) {
+        parts.push("Alt".to_string());
+    }
+    let key_name = match binding.code {
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::Enter => "Enter".to_string(),
+        KeyCode::Esc => "Esc".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::Backspace => "Backspace".to_string(),
+        KeyCode::Up => "\u{2191}".to_string(),
+        KeyCode::Down => "\u{2193}".to_string(),
+        KeyCode::Left => "\u{2190}".to_string(),
+        KeyCode::Right => "\u{2192}".to_string(),
+        KeyCode::Home => "Home".to_string(),
+        KeyCode::End => "End".to_string(),
+        KeyCode::PageUp => "PgUp".to_string(),
+        KeyCode::PageDown => "PgDn".to_string(),
+        KeyCode::F(n) => format!("F{}", n),
+        _ => "?".to_string(),
+    };
+    parts.push(key_name);
+    parts.join("+")
+}
+
+/// The effective keybindings (defaults plus config overrides) as a cheat sheet,
+/// one section per group, listing every action with all of its keys.
+pub fn cheat_sheet(map: &KeyBindingMap, format: KeysFormat) -> String {
+    let keys_for = |action: &KeyAction| -> Vec<String> {
+        map.get(action)
+            .map(|bindings| bindings.iter().map(binding_display).collect())
+            .unwrap_or_default()
+    };
+    let mut groups: Vec<&str> = Vec::new();
+    for &(_, _, group, _) in ACTIONS {
+        if !groups.contains(&group) {
+            groups.push(group);
+        }
+    }
+
+    let mut out = String::new();
+    match format {
+        KeysFormat::Table => {
+            let keys_width = ACTIONS
+                .iter()
+                .map(|(action, ..)| keys_for(action).join(", ").chars().count())
+                .max()
+                .unwrap_or(0);
+            let name_width = ACTIONS
+                .iter()
+                .map(|(_, name, ..)| name.len())
+                .max()
+                .unwrap_or(0);
+            for group in groups {
+                if !out.is_empty() {
+                    out.push('\n');
+                }
+                out.push_str(group);
+                out.push('\n');
+                for (action, name, _, description) in ACTIONS.iter().filter(|a| a.2 == group) {
+                    out.push_str(&format!(
+                        "  {:<name_width$}  {:<keys_width$}  {}\n",
+                        name,
+                        keys_for(action).join(", "),
+                        description
+                    ));
+                }
             }
-            if binding.modifiers.contains(KeyModifiers::ALT) {
-                parts.push("Alt".to_string());
+        }
+        KeysFormat::Markdown => {
+            out.push_str("# Feedr keybindings\n");
+            for group in groups {
+                out.push_str(&format!(
+                    "\n## {}\n\n| Action | Keys | Description |\n|--------|------|-------------|\n",
+                    group
+                ));
+                for (action, name, _, description) in ACTIONS.iter().filter(|a| a.2 == group) {
+                    let keys: Vec<String> = keys_for(action)
+                        .iter()
+                        .map(|key| format!("`{}`", key.replace('|', "\\|")))
+                        .collect();
+                    out.push_str(&format!(
+                        "| `{}` | {} | {} |\n",
+                        name,
+                        keys.join(", "),
+                        description
+                    ));
+                }
             }
-            let key_name = match binding.code {
-                KeyCode::Char(' ') => "Space".to_string(),
-                KeyCode::Char(c) => c.to_string(),
-                KeyCode::Enter => "Enter".to_string(),
-                KeyCode::Esc => "Esc".to_string(),
-                KeyCode::Tab => "Tab".to_string(),
-                KeyCode::Backspace => "Backspace".to_string(),
-                KeyCode::Up => "\u{2191}".to_string(),
-                KeyCode::Down => "\u{2193}".to_string(),
-                KeyCode::Left => "\u{2190}".to_string(),
-                KeyCode::Right => "\u{2192}".to_string(),
-                KeyCode::Home => "Home".to_string(),
-                KeyCode::End => "End".to_string(),
-                KeyCode::PageUp => "PgUp".to_string(),
-                KeyCode::PageDown => "PgDn".to_string(),
-                KeyCode::F(n) => format!("F{}", n),
-                _ => "?".to_string(),
-            };
-            parts.push(key_name);
-            return parts.join("+");
         }
     }
-    "?".to_string()
+    out
 }
 
 #[cfg(test)]
@@ -604,4 +1296,39 @@ mod tests {
         // Multi-char key name that isn't a special key
         assert!(parse_key_string("abc").is_none());
     }
+
+    #[test]
+    fn test_action_names_round_trip() {
+        for action in default_keybindings().keys() {
+            let name = action.name();
+            assert!(!name.is_empty(), "{:?} is missing from ACTIONS", action);
+            assert_eq!(name.parse::<KeyAction>(), Ok(action.clone()));
+        }
+        assert_eq!("no_such_action".parse::<KeyAction>(), Err(()));
+    }
+
+    #[test]
+    fn test_cheat_sheet_shows_remapped_keys() {
+        let mut overrides = HashMap::new();
+        overrides.insert(
+            "toggle_star".to_string(),
+            toml::Value::Array(vec![
+                toml::Value::String("x".to_string()),
+                toml::Value::String("Ctrl+s".to_string()),
+            ]),
+        );
+        let (map, _) = build_keybindings(&overrides);
+
+        let markdown = cheat_sheet(&map, KeysFormat::Markdown);
+        assert!(markdown.contains("| `toggle_star` | `x`, `Ctrl+s` | Star/unstar an article |"));
+        assert!(markdown.contains("\n## Navigation\n"));
+
+        let table = cheat_sheet(&map, KeysFormat::Table);
+        let line = table
+            .lines()
+            .find(|line| line.trim_start().starts_with("toggle_star "))
+            .unwrap();
+        assert!(line.contains("x, Ctrl+s"));
+        assert!(line.ends_with("Star/unstar an article"));
+    }
 }
@@ -0,0 +1,16 @@
+use crate::cli::KeysFormat;
+use crate::config::Config;
+use crate::keybindings::{build_keybindings, cheat_sheet};
+use anyhow::Result;
+
+/// Print the keybindings in effect: the defaults with the config's `[keybindings]`
+/// overrides applied. Invalid overrides are reported on stderr, as the TUI would ignore them.
+pub fn run(format: KeysFormat) -> Result<()> {
+    let config = Config::load()?;
+    let (map, warnings) = build_keybindings(&config.keybindings);
+    for warning in warnings {
+        eprintln!("Warning: keybindings: {}", warning);
+    }
+    print!("{}", cheat_sheet(&map, format));
+    Ok(())
+}
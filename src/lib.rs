@@ -1,10 +1,50 @@
+pub mod activitypub;
+pub mod add_cli;
+pub mod alerts;
 pub mod app;
+pub mod article_export;
+pub mod calendar;
+pub mod cli;
 pub mod config;
 pub mod config_cli;
 pub mod config_tui;
 pub mod config_ui;
+pub mod date_hints;
+pub mod demo;
+pub mod display_rules;
+pub mod doctor_cli;
+pub mod dump_cli;
+pub mod edit_history;
 pub mod events;
 pub mod feed;
+pub mod fetch_cli;
+pub mod footnotes;
+pub mod globals;
+pub mod http_cache;
+pub mod image_art;
 pub mod keybindings;
+pub mod keys_cli;
+pub mod list_cli;
+pub mod metrics;
+pub mod netscape;
+pub mod packages;
+pub mod path_input;
+pub mod raw_entry;
+pub mod read_cli;
+pub mod reader_import;
+pub mod refresh_cli;
+pub mod releases;
+pub mod search_cli;
+pub mod service_cli;
+pub mod share;
+pub mod sitemap;
+pub mod storage;
+#[cfg(feature = "sync")]
+pub mod sync_state;
+pub mod tagging;
+pub mod tour;
 pub mod tui;
 pub mod ui;
+
+#[cfg(test)]
+mod proptests;
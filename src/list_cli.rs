@@ -0,0 +1,93 @@
+use crate::app::App;
+use anyhow::Result;
+use serde::Serialize;
+
+/// A subscription as the headless commands report it.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Subscription {
+    pub url: String,
+    /// The title the user chose, or the feed's own once it has been fetched
+    pub title: Option<String>,
+    pub category: Option<String>,
+    pub muted: bool,
+}
+
+/// Subscriptions in the order they were added.
+pub fn subscriptions(app: &App) -> Vec<Subscription> {
+    app.bookmarks
+        .iter()
+        .map(|url| Subscription {
+            url: url.clone(),
+            title: app
+                .feed_titles
+                .get(url)
+                .or_else(|| app.feeds.iter().find(|f| &f.url == url).map(|f| &f.title))
+                .cloned(),
+            category: app
+                .categories
+                .iter()
+                .find(|c| c.feeds.contains(url))
+                .map(|c| c.name.clone()),
+            muted: app.muted_feeds.contains(url),
+        })
+        .collect()
+}
+
+/// Print subscriptions without fetching them, one per line: URL, title and category
+/// separated by tabs (empty when unset), or as a JSON array.
+pub fn run(json: bool) -> Result<()> {
+    let app = App::new();
+    let subscriptions = subscriptions(&app);
+    if json {
+        println!("{}", serde_json::to_string_pretty(&subscriptions)?);
+        return Ok(());
+    }
+    for sub in &subscriptions {
+        println!("{}", format_row(sub));
+    }
+    Ok(())
+}
+
+fn format_row(sub: &Subscription) -> String {
+    format!(
+        "{}\t{}\t{}",
+        sub.url,
+        sub.title.as_deref().unwrap_or(""),
+        sub.category.as_deref().unwrap_or("")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feed::FeedCategory;
+
+    #[test]
+    fn test_subscriptions_include_titles_and_categories() {
+        let mut app = App::new();
+        app.bookmarks = vec![
+            "https://example.com/feed1".to_string(),
+            "https://example.com/feed2".to_string(),
+        ];
+        app.feeds.clear();
+        app.feed_titles.clear();
+        app.muted_feeds.clear();
+        app.feed_titles.insert(
+            "https://example.com/feed2".to_string(),
+            "Feed Two".to_string(),
+        );
+        let mut category = FeedCategory::new("Tech");
+        category
+            .feeds
+            .insert("https://example.com/feed2".to_string());
+        app.categories = vec![category];
+
+        let subs = subscriptions(&app);
+        assert_eq!(subs.len(), 2);
+        assert_eq!(format_row(&subs[0]), "https://example.com/feed1\t\t");
+        assert_eq!(
+            format_row(&subs[1]),
+            "https://example.com/feed2\tFeed Two\tTech"
+        );
+    }
+}
@@ -1,64 +1,115 @@
 use anyhow::Result;
-use clap::{Parser, Subcommand};
-use feedr::app::App;
-use feedr::{config_cli, config_tui, tui};
-
-#[derive(Parser)]
-#[command(name = "feedr")]
-#[command(version = env!("CARGO_PKG_VERSION"))]
-#[command(about = "A feature-rich terminal-based RSS/Atom feed reader written in Rust")]
-#[command(
-    long_about = "Feedr is a modern terminal-based RSS/Atom feed reader with advanced filtering, categorization, and search capabilities. It supports both RSS and Atom feeds with compression handling and provides an intuitive TUI interface."
-)]
-#[command(args_conflicts_with_subcommands = true)]
-struct Cli {
-    /// OPML file to import
-    #[arg(short, long, value_name = "FILE PATH")]
-    import: Option<String>,
-
-    #[command(subcommand)]
-    command: Option<Commands>,
-}
-
-#[derive(Subcommand)]
-enum Commands {
-    /// Manage configuration
-    Config {
-        #[command(subcommand)]
-        action: Option<ConfigAction>,
-
-        /// Open interactive TUI config editor
-        #[arg(long)]
-        tui: bool,
-    },
-}
-
-#[derive(Subcommand)]
-enum ConfigAction {
-    /// Get a config value by key
-    Get {
-        /// Config key in dot-notation (e.g. ui.theme)
-        key: String,
-    },
-    /// Set a config value
-    Set {
-        /// Config key in dot-notation (e.g. ui.theme)
-        key: String,
-        /// New value
-        value: String,
-    },
-    /// List all config keys and values
-    List,
-}
+use clap::{CommandFactory, Parser};
+use feedr::app::{App, ImportReport};
+use feedr::cli::{BookmarksAction, Cli, Commands, ConfigAction, ServiceAction};
+use feedr::globals::{self, GlobalOptions};
+use feedr::{
+    add_cli, config_cli, config_tui, demo, doctor_cli, dump_cli, fetch_cli, keys_cli, list_cli,
+    read_cli, refresh_cli, search_cli, service_cli, tui,
+};
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
+    globals::init(GlobalOptions {
+        data_dir: cli.global.data_dir,
+        profile: cli.global.profile,
+        verbose: cli.global.verbose,
+        lite: cli.global.lite,
+        read_only: cli.global.read_only,
+        demo: cli.demo,
+    })?;
+
+    if cli.demo {
+        if cli.command.is_some() {
+            anyhow::bail!("--demo only runs the interactive reader");
+        }
+        let result = tui::run(demo::app()?);
+        let _ = std::fs::remove_dir_all(globals::demo_dir());
+        return result;
+    }
+
+    let command = match (cli.command, cli.import) {
+        (Some(_), Some(_)) => {
+            anyhow::bail!("--import can't be combined with a subcommand; use `feedr import`")
+        }
+        (Some(command), None) => command,
+        (None, Some(file)) => Commands::Import {
+            file,
+            dry_run: cli.dry_run,
+            starred: None,
+        },
+        (None, None) => Commands::Tui,
+    };
 
-    match cli.command {
-        Some(Commands::Config {
+    match command {
+        Commands::Tui => tui::run(App::new()),
+        Commands::Import {
+            file,
+            dry_run: true,
+            starred,
+        } => {
+            let app = App::new();
+            print_import_report(&app.import_opml_dry_run(&file)?);
+            if let Some(path) = starred {
+                let count = app.import_starred_export_dry_run(&path)?;
+                println!("Would star {} new items from {}", count, path.display());
+            }
+            println!("\nDry run: nothing was imported.");
+            Ok(())
+        }
+        Commands::Import { file, starred, .. } => {
+            let mut app = App::new();
+            app.import_opml(&file)?;
+            if let Some(path) = starred {
+                let count = app.import_starred_export(&path)?;
+                println!("Starred {} new items from {}", count, path.display());
+            }
+            Ok(())
+        }
+        Commands::Export { file, no_fetch } => {
+            let mut app = App::new();
+            if !no_fetch {
+                app.refresh_feeds()?;
+            }
+            let opml = app.export_opml()?;
+            match file {
+                Some(file) => {
+                    std::fs::write(&file, opml)?;
+                    println!(
+                        "Exported {} feeds to {}",
+                        app.bookmarks.len(),
+                        file.display()
+                    );
+                }
+                None => println!("{}", opml),
+            }
+            Ok(())
+        }
+        Commands::List { json } => list_cli::run(json),
+        Commands::Refresh => refresh_cli::run(),
+        Commands::Fetch { quiet } => {
+            let status = fetch_cli::run(quiet)?;
+            if status != 0 {
+                std::process::exit(status);
+            }
+            Ok(())
+        }
+        Commands::Service { action } => match action {
+            ServiceAction::Install { every, print } => service_cli::install(&every, print),
+            ServiceAction::Uninstall => service_cli::uninstall(),
+        },
+        Commands::Search {
+            query,
+            feed,
+            since,
+            json,
+            refresh,
+        } => search_cli::run(&query, feed.as_deref(), since.as_deref(), json, refresh),
+        Commands::Dump { unread } => dump_cli::run(unread),
+        Commands::Config {
             action,
             tui: use_tui,
-        }) => {
+        } => {
             if use_tui {
                 return config_tui::run();
             }
@@ -68,16 +119,62 @@ fn main() -> Result<()> {
                 Some(ConfigAction::List) | None => config_cli::list(),
             }
         }
-        None => match cli.import {
-            Some(file_path) => {
-                let mut app = App::new();
-                app.import_opml(&file_path)
-            }
-            None => {
-                let app = App::new();
-                tui::run(app)?;
-                Ok(())
+        Commands::Add {
+            url: Some(url),
+            category,
+            title,
+            ..
+        } => add_cli::run(&url, category.as_deref(), title.as_deref()),
+        Commands::Add {
+            file: Some(file),
+            category,
+            ..
+        } => add_cli::run_file(&file, category.as_deref()),
+        Commands::Add { .. } => unreachable!("clap requires a URL or --file"),
+        Commands::Doctor { fix } => doctor_cli::run(fix),
+        Commands::Bookmarks { action } => {
+            let mut app = App::new();
+            match action {
+                BookmarksAction::Export { file } => {
+                    // Fetch feeds so exported bookmarks get real titles and dates
+                    app.refresh_feeds()?;
+                    let count = app.export_starred_bookmarks(&file)?;
+                    println!("Exported {} starred items to {}", count, file.display());
+                }
+                BookmarksAction::Import { file } => {
+                    let count = app.import_starred_bookmarks(&file)?;
+                    println!("Starred {} new items from {}", count, file.display());
+                }
             }
-        },
+            Ok(())
+        }
+        Commands::Keys { format } => keys_cli::run(format),
+        Commands::Completions { shell } => {
+            clap_complete::generate(shell, &mut Cli::command(), "feedr", &mut std::io::stdout());
+            Ok(())
+        }
+        Commands::Read { target, format } => read_cli::run(&target, format),
+    }
+}
+
+fn print_import_report(report: &ImportReport) {
+    println!("Would add {} feeds:", report.new.len());
+    for (url, title) in &report.new {
+        println!("  + {} ({})", title, url);
+    }
+    if !report.duplicates.is_empty() {
+        println!(
+            "\nAlready subscribed or listed twice ({}):",
+            report.duplicates.len()
+        );
+        for url in &report.duplicates {
+            println!("  = {}", url);
+        }
+    }
+    if !report.failed.is_empty() {
+        println!("\nFailed validation ({}):", report.failed.len());
+        for (url, reason) in &report.failed {
+            println!("  ! {}: {}", url, reason);
+        }
     }
 }
@@ -0,0 +1,97 @@
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+/// Reader health after a refresh, in the shape monitoring tools expect.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Metrics {
+    pub feeds: usize,
+    pub fetch_errors: usize,
+    pub unread_items: usize,
+    pub refresh_duration: Duration,
+    /// Unix time the refresh finished
+    pub refreshed_at: i64,
+}
+
+impl Metrics {
+    /// The metrics in the Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let gauges: [(&str, &str, String); 5] = [
+            (
+                "feedr_feeds_total",
+                "Subscribed feeds.",
+                self.feeds.to_string(),
+            ),
+            (
+                "feedr_fetch_errors",
+                "Feeds that failed to load on the last refresh.",
+                self.fetch_errors.to_string(),
+            ),
+            (
+                "feedr_unread_items",
+                "Unread items across loaded feeds.",
+                self.unread_items.to_string(),
+            ),
+            (
+                "feedr_last_refresh_duration_seconds",
+                "How long the last refresh took.",
+                format!("{:.3}", self.refresh_duration.as_secs_f64()),
+            ),
+            (
+                "feedr_last_refresh_timestamp_seconds",
+                "Unix time the last refresh finished.",
+                self.refreshed_at.to_string(),
+            ),
+        ];
+        let mut out = String::new();
+        for (name, help, value) in gauges {
+            out.push_str(&format!(
+                "# HELP {0} {1}\n# TYPE {0} gauge\n{0} {2}\n",
+                name, help, value
+            ));
+        }
+        out
+    }
+
+    /// Write the metrics to `path`, replacing it in one step so a collector never
+    /// reads a half-written file.
+    pub fn write(&self, path: &Path) -> Result<()> {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        let tmp = path.with_extension("prom.tmp");
+        fs::write(&tmp, self.render())
+            .with_context(|| format!("Failed to write {}", tmp.display()))?;
+        fs::rename(&tmp, path).with_context(|| format!("Failed to write {}", path.display()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_metrics_render_and_write() {
+        let metrics = Metrics {
+            feeds: 12,
+            fetch_errors: 1,
+            unread_items: 340,
+            refresh_duration: Duration::from_millis(2500),
+            refreshed_at: 1_700_000_000,
+        };
+        let text = metrics.render();
+        assert!(text.contains("# TYPE feedr_feeds_total gauge\nfeedr_feeds_total 12\n"));
+        assert!(text.contains("\nfeedr_fetch_errors 1\n"));
+        assert!(text.contains("\nfeedr_unread_items 340\n"));
+        assert!(text.contains("\nfeedr_last_refresh_duration_seconds 2.500\n"));
+        assert!(text.contains("\nfeedr_last_refresh_timestamp_seconds 1700000000\n"));
+
+        let dir = std::env::temp_dir().join(format!("feedr-metrics-{}", uuid::Uuid::new_v4()));
+        let path = dir.join("feedr.prom");
+        metrics.write(&path).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), text);
+        assert!(!path.with_extension("prom.tmp").exists());
+        let _ = fs::remove_dir_all(dir);
+    }
+}
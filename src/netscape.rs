@@ -0,0 +1,111 @@
+use scraper::{Html, Selector};
+
+/// One entry in a Netscape bookmarks file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bookmark {
+    pub title: String,
+    pub url: String,
+    /// Unix timestamp, written as ADD_DATE when known
+    pub add_date: Option<i64>,
+}
+
+/// Render bookmarks in the Netscape bookmarks HTML format browsers import and export,
+/// grouped in a single folder named `folder`.
+pub fn export(folder: &str, bookmarks: &[Bookmark]) -> String {
+    let mut out = String::from(
+        "<!DOCTYPE NETSCAPE-Bookmark-file-1>\n\
+         <!-- This is an automatically generated file. -->\n\
+         <META HTTP-EQUIV=\"Content-Type\" CONTENT=\"text/html; charset=UTF-8\">\n\
+         <TITLE>Bookmarks</TITLE>\n\
+         <H1>Bookmarks</H1>\n\
+         <DL><p>\n",
+    );
+    out.push_str(&format!(
+        "    <DT><H3>{}</H3>\n    <DL><p>\n",
+        escape(folder)
+    ));
+    for bookmark in bookmarks {
+        let add_date = bookmark
+            .add_date
+            .map(|d| format!(" ADD_DATE=\"{}\"", d))
+            .unwrap_or_default();
+        out.push_str(&format!(
+            "        <DT><A HREF=\"{}\"{}>{}</A>\n",
+            escape(&bookmark.url),
+            add_date,
+            escape(&bookmark.title)
+        ));
+    }
+    out.push_str("    </DL><p>\n</DL><p>\n");
+    out
+}
+
+/// Read every http(s) link out of a Netscape bookmarks file, ignoring folder structure.
+pub fn parse(html: &str) -> Vec<Bookmark> {
+    let document = Html::parse_document(html);
+    let Ok(selector) = Selector::parse("a[href]") else {
+        return Vec::new();
+    };
+    document
+        .select(&selector)
+        .filter_map(|a| {
+            let url = a.value().attr("href")?.trim().to_string();
+            if !url.starts_with("http://") && !url.starts_with("https://") {
+                return None;
+            }
+            let title = a.text().collect::<String>().trim().to_string();
+            let add_date = a
+                .value()
+                .attr("add_date")
+                .and_then(|d| d.trim().parse().ok());
+            Some(Bookmark {
+                title: if title.is_empty() { url.clone() } else { title },
+                url,
+                add_date,
+            })
+        })
+        .collect()
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_then_parse_round_trips() {
+        let bookmarks = vec![
+            Bookmark {
+                title: "Rust & <Friends>".to_string(),
+                url: "https://example.com/a?x=1&y=2".to_string(),
+                add_date: Some(1_700_000_000),
+            },
+            Bookmark {
+                title: "Plain".to_string(),
+                url: "https://example.com/b".to_string(),
+                add_date: None,
+            },
+        ];
+        let html = export("Feedr Starred", &bookmarks);
+        assert!(html.starts_with("<!DOCTYPE NETSCAPE-Bookmark-file-1>"));
+        assert_eq!(parse(&html), bookmarks);
+    }
+
+    #[test]
+    fn test_parse_skips_non_http_links() {
+        let html = r#"<DL><p>
+            <DT><A HREF="javascript:alert(1)">Bookmarklet</A>
+            <DT><A HREF="place:sort=8">Smart folder</A>
+            <DT><A HREF="https://example.com/">Example</A>
+        </DL>"#;
+        let parsed = parse(html);
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].title, "Example");
+    }
+}
@@ -0,0 +1,345 @@
+use crate::feed::{format_date, Feed, FeedItem};
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+
+// Link texts PyPI projects commonly give their changelog in `project_urls`
+const CHANGELOG_LABELS: [&str; 5] = [
+    "changelog",
+    "changes",
+    "release notes",
+    "history",
+    "what's new",
+];
+
+/// Expand a package shorthand to the registry API URL that's subscribed to:
+/// `crates:<name>` for crates.io, `pypi:<name>` for PyPI. Anything else is returned as is.
+pub fn expand(input: &str) -> String {
+    let input = input.trim();
+    let valid = |name: &str| {
+        !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    };
+    match input.split_once(':') {
+        Some(("crates" | "crate", name)) if valid(name) => {
+            format!("https://crates.io/api/v1/crates/{}", name)
+        }
+        Some(("pypi", name)) if valid(name) => format!("https://pypi.org/pypi/{}/json", name),
+        _ => input.to_string(),
+    }
+}
+
+/// A package's versions as a feed when `url` is a crates.io or PyPI package API URL, one
+/// item per release, newest first, each linking to its changelog. None for other URLs.
+pub fn to_feed(url: &str, content: &[u8]) -> Option<Result<Feed>> {
+    let parsed = url::Url::parse(url).ok()?;
+    let segments: Vec<&str> = parsed.path_segments()?.collect();
+    let build = match (parsed.host_str()?, segments.as_slice()) {
+        ("crates.io", ["api", "v1", "crates", name]) if !name.is_empty() => crate_feed,
+        ("pypi.org", ["pypi", name, "json"]) if !name.is_empty() => pypi_feed,
+        _ => return None,
+    };
+    let result = serde_json::from_slice::<Value>(content)
+        .map_err(|e| anyhow!("Package JSON decode error. {}", e))
+        .and_then(|doc| build(url, &doc));
+    Some(result)
+}
+
+fn crate_feed(url: &str, doc: &Value) -> Result<Feed> {
+    let info = doc
+        .get("crate")
+        .ok_or_else(|| anyhow!("crates.io response has no \"crate\""))?;
+    let name = text(info, "name").ok_or_else(|| anyhow!("crates.io response has no name"))?;
+    let repository = text(info, "repository");
+    let versions: Vec<&Value> = doc
+        .get("versions")
+        .and_then(Value::as_array)
+        .map(|versions| {
+            versions
+                .iter()
+                .filter(|v| !v.get("yanked").and_then(Value::as_bool).unwrap_or(false))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    // Newest first, so each version's predecessor is the next entry
+    let items = versions
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, version)| {
+            let num = text(version, "num")?;
+            let previous = versions.get(idx + 1).and_then(|v| text(v, "num"));
+            let mut links = Vec::new();
+            if let Some(previous) = &previous {
+                links.push((
+                    format!("https://diff.rs/{}/{}/{}", name, previous, num),
+                    format!("Changes since {}", previous),
+                ));
+            }
+            if let Some(repository) = &repository {
+                links.push((repository.clone(), "Repository".to_string()));
+            }
+            links.push((
+                format!("https://docs.rs/{}/{}", name, num),
+                "Documentation".to_string(),
+            ));
+            let author = version
+                .get("published_by")
+                .and_then(|user| text(user, "login"));
+            Some(item(
+                format!("{} {}", name, num),
+                format!("https://crates.io/crates/{}/{}", name, num),
+                text(info, "description"),
+                &links,
+                date(version, "created_at"),
+                author,
+            ))
+        })
+        .collect();
+
+    Ok(feed(
+        url,
+        format!("{} (crates.io)", name),
+        text(info, "description"),
+        format!("https://crates.io/crates/{}", name),
+        items,
+    ))
+}
+
+fn pypi_feed(url: &str, doc: &Value) -> Result<Feed> {
+    let info = doc
+        .get("info")
+        .ok_or_else(|| anyhow!("PyPI response has no \"info\""))?;
+    let name = text(info, "name").ok_or_else(|| anyhow!("PyPI response has no name"))?;
+    let changelog = info
+        .get("project_urls")
+        .and_then(Value::as_object)
+        .and_then(|urls| {
+            urls.iter().find_map(|(label, link)| {
+                let label = label.to_lowercase();
+                CHANGELOG_LABELS
+                    .iter()
+                    .any(|known| label.contains(known))
+                    .then(|| link.as_str().map(str::to_string))
+                    .flatten()
+            })
+        });
+
+    // A release's date is its first upload; releases with no live files are skipped
+    let mut releases: Vec<(String, Option<DateTime<Utc>>)> = doc
+        .get("releases")
+        .and_then(Value::as_object)
+        .map(|releases| {
+            releases
+                .iter()
+                .filter_map(|(version, files)| {
+                    let files: Vec<&Value> = files
+                        .as_array()?
+                        .iter()
+                        .filter(|f| !f.get("yanked").and_then(Value::as_bool).unwrap_or(false))
+                        .collect();
+                    if files.is_empty() {
+                        return None;
+                    }
+                    let uploaded = files
+                        .iter()
+                        .filter_map(|f| date(f, "upload_time_iso_8601"))
+                        .min();
+                    Some((version.clone(), uploaded))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    releases.sort_by(|a, b| b.1.cmp(&a.1));
+
+    let items = releases
+        .into_iter()
+        .map(|(version, uploaded)| {
+            let mut links = vec![(
+                format!("https://pypi.org/project/{}/{}/", name, version),
+                "Release files".to_string(),
+            )];
+            if let Some(changelog) = &changelog {
+                links.insert(0, (changelog.clone(), "Changelog".to_string()));
+            }
+            item(
+                format!("{} {}", name, version),
+                format!("https://pypi.org/project/{}/{}/", name, version),
+                text(info, "summary"),
+                &links,
+                uploaded,
+                None,
+            )
+        })
+        .collect();
+
+    Ok(feed(
+        url,
+        format!("{} (PyPI)", name),
+        text(info, "summary"),
+        format!("https://pypi.org/project/{}/", name),
+        items,
+    ))
+}
+
+fn feed(
+    url: &str,
+    title: String,
+    description: Option<String>,
+    link: String,
+    items: Vec<FeedItem>,
+) -> Feed {
+    let mut feed = Feed {
+        url: url.to_string(),
+        title,
+        items,
+        title_lower: String::new(),
+        description,
+        updated: None,
+        link: Some(link),
+    };
+    feed.restore_derived_fields();
+    feed
+}
+
+fn item(
+    title: String,
+    link: String,
+    summary: Option<String>,
+    links: &[(String, String)],
+    published: Option<DateTime<Utc>>,
+    author: Option<String>,
+) -> FeedItem {
+    let mut html = String::new();
+    if let Some(summary) = &summary {
+        html.push_str(&format!("<p>{}</p>", escape(summary)));
+    }
+    html.push_str("<ul>");
+    for (href, label) in links {
+        html.push_str(&format!(
+            "<li><a href=\"{}\">{}</a></li>",
+            escape(href),
+            escape(label)
+        ));
+    }
+    html.push_str("</ul>");
+
+    FeedItem {
+        title,
+        link: Some(link),
+        description: Some(html),
+        summary,
+        pub_date: published.map(|date| date.to_rfc3339()),
+        author,
+        formatted_date: published.map(format_date),
+        parsed_date: None,
+        plain_text: None,
+        title_lower: String::new(),
+        plain_text_lower: None,
+        language: None,
+        image: None,
+        comments_url: None,
+        comment_count: None,
+        metadata: Default::default(),
+    }
+}
+
+fn text(value: &Value, key: &str) -> Option<String> {
+    value
+        .get(key)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+}
+
+fn date(value: &Value, key: &str) -> Option<DateTime<Utc>> {
+    let date = text(value, key)?;
+    DateTime::parse_from_rfc3339(&date)
+        .ok()
+        .map(|date| date.with_timezone(&Utc))
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_shorthands() {
+        assert_eq!(
+            expand("crates:serde"),
+            "https://crates.io/api/v1/crates/serde"
+        );
+        assert_eq!(
+            expand(" pypi:requests "),
+            "https://pypi.org/pypi/requests/json"
+        );
+        assert_eq!(
+            expand("https://example.com/feed"),
+            "https://example.com/feed"
+        );
+        assert_eq!(expand("crates:"), "crates:");
+    }
+
+    #[test]
+    fn test_crate_versions_become_items() {
+        let json = br#"{
+            "crate": {"name": "serde", "description": "A serialization framework",
+                      "repository": "https://github.com/serde-rs/serde"},
+            "versions": [
+                {"num": "1.0.201", "created_at": "2024-05-10T12:00:00+00:00", "yanked": true},
+                {"num": "1.0.200", "created_at": "2024-05-01T12:00:00+00:00", "yanked": false,
+                 "published_by": {"login": "dtolnay"}},
+                {"num": "1.0.199", "created_at": "2024-04-20T12:00:00+00:00", "yanked": false}
+            ]
+        }"#;
+        let feed = to_feed("https://crates.io/api/v1/crates/serde", json)
+            .unwrap()
+            .unwrap();
+        assert_eq!(feed.title, "serde (crates.io)");
+        let titles: Vec<&str> = feed.items.iter().map(|i| i.title.as_str()).collect();
+        assert_eq!(titles, vec!["serde 1.0.200", "serde 1.0.199"]);
+        assert!(feed.items[0]
+            .description
+            .as_deref()
+            .unwrap()
+            .contains("https://diff.rs/serde/1.0.199/1.0.200"));
+        assert_eq!(feed.items[0].author.as_deref(), Some("dtolnay"));
+        assert!(feed.items[0].parsed_date.is_some());
+
+        assert!(to_feed("https://example.com/feed", json).is_none());
+    }
+
+    #[test]
+    fn test_pypi_releases_become_items() {
+        let json = br#"{
+            "info": {"name": "requests", "summary": "HTTP for Humans.",
+                     "project_urls": {"Changelog": "https://requests.readthedocs.io/en/latest/community/updates/"}},
+            "releases": {
+                "2.31.0": [{"upload_time_iso_8601": "2023-05-22T15:12:44.175Z", "yanked": false}],
+                "2.32.0": [{"upload_time_iso_8601": "2024-05-20T15:12:44.175Z", "yanked": true}],
+                "2.32.3": [{"upload_time_iso_8601": "2024-05-29T15:12:44.175Z", "yanked": false}],
+                "0.0.1": []
+            }
+        }"#;
+        let feed = to_feed("https://pypi.org/pypi/requests/json", json)
+            .unwrap()
+            .unwrap();
+        let titles: Vec<&str> = feed.items.iter().map(|i| i.title.as_str()).collect();
+        assert_eq!(titles, vec!["requests 2.32.3", "requests 2.31.0"]);
+        assert!(feed.items[0]
+            .description
+            .as_deref()
+            .unwrap()
+            .contains("community/updates"));
+    }
+}
@@ -0,0 +1,257 @@
+//! The file path field of the UI's path dialogs (OPML import and export, the keybinding
+//! cheat sheet): `~` expansion, shell-style tab completion, recently used paths and a
+//! check that the path can be read or written before anything runs.
+
+use crossterm::event::KeyCode;
+use std::fs;
+use std::path::PathBuf;
+
+/// Recent paths remembered for the path dialogs, newest first.
+pub const RECENT_PATHS: usize = 10;
+
+/// What a path dialog does with its path.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PathKind {
+    /// Reads a file (or folder) that must already exist
+    Existing,
+    /// Writes a file, in a directory that must already exist
+    Writable,
+}
+
+/// A path being typed into a dialog.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PathInput {
+    pub text: String,
+    pub kind: PathKind,
+    recent_pos: Option<usize>, // Recent path shown in place of typed text, if any
+}
+
+impl PathInput {
+    pub fn new(kind: PathKind, text: &str) -> Self {
+        Self {
+            text: text.to_string(),
+            kind,
+            recent_pos: None,
+        }
+    }
+
+    /// Edit the path with `code`: Tab completes it, Up/Down step through `recent`
+    /// (older with Up, back to an empty field past the newest). Returns false for keys
+    /// the field doesn't use, such as Enter and Esc.
+    pub fn handle_key(&mut self, code: KeyCode, recent: &[String]) -> bool {
+        match code {
+            KeyCode::Tab => self.text = complete(&self.text),
+            KeyCode::Up => self.cycle_recent(recent, true),
+            KeyCode::Down => self.cycle_recent(recent, false),
+            KeyCode::Char(c) => self.text.push(c),
+            KeyCode::Backspace => {
+                self.text.pop();
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn cycle_recent(&mut self, recent: &[String], older: bool) {
+        if recent.is_empty() {
+            return;
+        }
+        let last = recent.len() - 1;
+        self.recent_pos = match (self.recent_pos, older) {
+            (None, true) => Some(0),
+            (Some(pos), true) => Some((pos + 1).min(last)),
+            (Some(0), false) | (None, false) => None,
+            (Some(pos), false) => Some(pos - 1),
+        };
+        self.text = self
+            .recent_pos
+            .map(|pos| recent[pos].clone())
+            .unwrap_or_default();
+    }
+
+    /// The path with `~` expanded, or why it can't be used for this dialog.
+    pub fn validate(&self) -> Result<PathBuf, String> {
+        let text = self.text.trim();
+        if text.is_empty() {
+            return Err("Enter a path".to_string());
+        }
+        let path = expand(text);
+        match self.kind {
+            PathKind::Existing if !path.exists() => Err(format!("{} doesn't exist", text)),
+            PathKind::Writable if path.is_dir() => Err(format!("{} is a directory", text)),
+            PathKind::Writable => match path.parent() {
+                Some(dir) if !dir.as_os_str().is_empty() && !dir.is_dir() => {
+                    Err(format!("No directory {}", dir.display()))
+                }
+                _ => Ok(path),
+            },
+            PathKind::Existing => Ok(path),
+        }
+    }
+}
+
+/// `text` as a path, with a leading `~` standing for the home directory.
+pub fn expand(text: &str) -> PathBuf {
+    let rest = match text.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with('/') => rest.trim_start_matches('/'),
+        _ => return PathBuf::from(text),
+    };
+    match dirs::home_dir() {
+        Some(home) => home.join(rest),
+        None => PathBuf::from(text),
+    }
+}
+
+/// Complete the last component of `input` from the entries of its directory: to the
+/// whole name when only one entry matches (with a trailing `/` for directories), or
+/// as far as the matching names agree. Input that matches nothing comes back as is,
+/// and a leading `~` is kept.
+pub fn complete(input: &str) -> String {
+    if input == "~" {
+        return "~/".to_string();
+    }
+    let (dir, prefix) = match input.rfind('/') {
+        Some(idx) => (&input[..=idx], &input[idx + 1..]),
+        None => ("", input),
+    };
+    let listed = if dir.is_empty() {
+        PathBuf::from(".")
+    } else {
+        expand(dir)
+    };
+    let Ok(entries) = fs::read_dir(listed) else {
+        return input.to_string();
+    };
+    let mut matches: Vec<(String, bool)> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            // Hidden entries only when asked for
+            if !name.starts_with(prefix) || (name.starts_with('.') && !prefix.starts_with('.')) {
+                return None;
+            }
+            let is_dir = entry.path().is_dir();
+            Some((name, is_dir))
+        })
+        .collect();
+    match matches.len() {
+        0 => input.to_string(),
+        1 => {
+            let (name, is_dir) = matches.remove(0);
+            format!("{}{}{}", dir, name, if is_dir { "/" } else { "" })
+        }
+        _ => {
+            let first = matches[0].0.as_str();
+            let common = matches
+                .iter()
+                .fold(first, |common, (name, _)| common_prefix(common, name));
+            format!("{}{}", dir, common)
+        }
+    }
+}
+
+// The longest start `a` and `b` share
+fn common_prefix<'a>(a: &'a str, b: &str) -> &'a str {
+    let len: usize = a
+        .chars()
+        .zip(b.chars())
+        .take_while(|(x, y)| x == y)
+        .map(|(x, _)| x.len_utf8())
+        .sum();
+    &a[..len]
+}
+
+/// Put `path` at the front of `recent`, dropping an older copy and anything past
+/// `RECENT_PATHS`.
+pub fn remember(recent: &mut Vec<String>, path: &str) {
+    recent.retain(|p| p != path);
+    recent.insert(0, path.to_string());
+    recent.truncate(RECENT_PATHS);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complete_unique_and_shared_prefixes() {
+        let dir = std::env::temp_dir().join(format!("feedr-paths-{}", uuid::Uuid::new_v4()));
+        fs::create_dir_all(dir.join("feeds")).unwrap();
+        fs::write(dir.join("subscriptions-2023.opml"), "").unwrap();
+        fs::write(dir.join("subscriptions-2024.opml"), "").unwrap();
+        fs::write(dir.join(".hidden"), "").unwrap();
+        let base = format!("{}/", dir.display());
+
+        assert_eq!(complete(&format!("{}fe", base)), format!("{}feeds/", base));
+        assert_eq!(
+            complete(&format!("{}sub", base)),
+            format!("{}subscriptions-202", base)
+        );
+        assert_eq!(complete(&format!("{}nope", base)), format!("{}nope", base));
+        assert_eq!(complete(&format!("{}.h", base)), format!("{}.hidden", base));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_validate_checks_the_path_fits_the_dialog() {
+        let dir = std::env::temp_dir();
+        let existing = dir.to_string_lossy().to_string();
+        let missing = dir.join(format!("feedr-missing-{}", uuid::Uuid::new_v4()));
+        let missing = missing.to_string_lossy().to_string();
+
+        assert!(PathInput::new(PathKind::Existing, &existing)
+            .validate()
+            .is_ok());
+        assert!(PathInput::new(PathKind::Existing, &missing)
+            .validate()
+            .is_err());
+        assert!(PathInput::new(PathKind::Writable, &missing)
+            .validate()
+            .is_ok());
+        assert!(PathInput::new(PathKind::Writable, &existing)
+            .validate()
+            .is_err());
+        let nested = format!("{}/out.opml", missing);
+        assert!(PathInput::new(PathKind::Writable, &nested)
+            .validate()
+            .is_err());
+        assert!(PathInput::new(PathKind::Writable, "  ").validate().is_err());
+    }
+
+    #[test]
+    fn test_tilde_expands_to_home() {
+        if let Some(home) = dirs::home_dir() {
+            assert_eq!(expand("~/feeds.opml"), home.join("feeds.opml"));
+            assert_eq!(expand("~"), home);
+        }
+        assert_eq!(expand("~user/x"), PathBuf::from("~user/x"));
+        assert_eq!(expand("/tmp/x"), PathBuf::from("/tmp/x"));
+    }
+
+    #[test]
+    fn test_up_and_down_step_through_recent_paths() {
+        let recent = vec!["new.opml".to_string(), "old.opml".to_string()];
+        let mut input = PathInput::new(PathKind::Existing, "");
+        input.handle_key(KeyCode::Up, &recent);
+        assert_eq!(input.text, "new.opml");
+        input.handle_key(KeyCode::Up, &recent);
+        input.handle_key(KeyCode::Up, &recent);
+        assert_eq!(input.text, "old.opml");
+        input.handle_key(KeyCode::Down, &recent);
+        input.handle_key(KeyCode::Down, &recent);
+        assert!(input.text.is_empty());
+        assert!(!input.handle_key(KeyCode::Enter, &recent));
+    }
+
+    #[test]
+    fn test_remember_keeps_newest_first_without_repeats() {
+        let mut recent = vec!["a.opml".to_string(), "b.opml".to_string()];
+        remember(&mut recent, "b.opml");
+        assert_eq!(recent, ["b.opml", "a.opml"]);
+        for i in 0..20 {
+            remember(&mut recent, &format!("{}.opml", i));
+        }
+        assert_eq!(recent.len(), RECENT_PATHS);
+        assert_eq!(recent[0], "19.opml");
+    }
+}
@@ -0,0 +1,109 @@
+//! Property tests for the parsing path: whatever a feed in the wild contains, parsing
+//! it and preparing it for display must not panic. `fuzz/` runs the same entry point
+//! under libFuzzer for longer sessions.
+
+use crate::feed::{fold_for_search, format_date, relative_date, Feed};
+use crate::ui::utils::{article_text, format_content_for_reading, truncate_str, truncate_url};
+use chrono::{TimeZone, Utc};
+use proptest::prelude::*;
+use unicode_width::UnicodeWidthStr;
+
+const URL: &str = "https://example.com/feed.xml";
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+// An RSS document with one item per (title, description, pubDate)
+fn rss(items: &[(String, String, String)]) -> String {
+    let mut doc = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<rss version=\"2.0\"><channel>\
+         <title>Property feed</title><link>https://example.com/</link>\
+         <description>Generated</description>",
+    );
+    for (idx, (title, description, date)) in items.iter().enumerate() {
+        doc.push_str(&format!(
+            "<item><title>{}</title><link>https://example.com/{}</link>\
+             <description>{}</description><pubDate>{}</pubDate></item>",
+            escape_xml(title),
+            idx,
+            escape_xml(description),
+            escape_xml(date)
+        ));
+    }
+    doc.push_str("</channel></rss>");
+    doc
+}
+
+// Text without the control characters XML 1.0 doesn't allow
+fn xml_text() -> impl Strategy<Value = String> {
+    "[^\\x00-\\x08\\x0b\\x0c\\x0e-\\x1f\\u{fffe}\\u{ffff}]{0,80}"
+}
+
+proptest! {
+    #[test]
+    fn arbitrary_bytes_never_panic(content in proptest::collection::vec(any::<u8>(), 0..4096)) {
+        let _ = Feed::from_bytes(URL, &content);
+    }
+
+    #[test]
+    fn mangled_rss_never_panics(cut in 0usize..2000, junk in "\\PC{0,40}") {
+        // A real document truncated mid-stream with garbage spliced in
+        let doc = rss(&[(
+            "Title".to_string(),
+            "<p>Body</p>".to_string(),
+            "Mon, 01 Jan 2024 00:00:00 GMT".to_string(),
+        )]);
+        let mut cut = cut.min(doc.len());
+        while !doc.is_char_boundary(cut) {
+            cut -= 1;
+        }
+        let _ = Feed::from_bytes(URL, format!("{}{}", &doc[..cut], junk).as_bytes());
+    }
+
+    #[test]
+    fn generated_rss_keeps_every_item(
+        items in proptest::collection::vec((xml_text(), xml_text(), xml_text()), 1..8)
+    ) {
+        let feed = Feed::from_bytes(URL, rss(&items).as_bytes()).unwrap();
+        prop_assert_eq!(feed.items.len(), items.len());
+        for item in &feed.items {
+            prop_assert_eq!(&item.title_lower, &fold_for_search(&item.title));
+        }
+    }
+
+    #[test]
+    fn dates_always_format(secs in -62_000_000_000i64..250_000_000_000, now in 0i64..4_000_000_000) {
+        let dt = Utc.timestamp_opt(secs, 0).unwrap();
+        let now = Utc.timestamp_opt(now, 0).unwrap();
+        prop_assert!(!format_date(dt).is_empty());
+        let relative = relative_date(dt, now);
+        prop_assert!(relative == "just now" || relative.ends_with(" ago"));
+    }
+
+    #[test]
+    fn html_always_converts_to_text(html in "\\PC{0,400}") {
+        let _ = article_text(&html);
+        let _ = format_content_for_reading(&html);
+    }
+
+    #[test]
+    fn truncation_fits_and_keeps_char_boundaries(s in "\\PC{0,60}", max in 0usize..40) {
+        let truncated = truncate_str(&s, max);
+        if s.width() <= max {
+            prop_assert_eq!(&truncated, &s);
+        } else {
+            prop_assert!(truncated.ends_with("..."));
+            prop_assert!(truncated.width() <= max.max(3));
+        }
+        let _ = truncate_url(&s, max);
+    }
+
+    #[test]
+    fn folding_lowercases(s in "\\PC{0,60}") {
+        let folded = fold_for_search(&s);
+        prop_assert_eq!(folded.to_lowercase(), folded);
+    }
+}
@@ -0,0 +1,321 @@
+//! The raw feed entry behind an article: its `<item>`/`<entry>` element or JSON Feed
+//! object, cut out of the feed document as it was downloaded.
+
+use crate::feed::{meta, FeedItem};
+
+/// The part of `document` that `item` was parsed from, or None when it can't be told
+/// apart from the other entries. JSON Feed objects come back pretty-printed; XML
+/// elements as written.
+pub fn find(document: &str, item: &FeedItem) -> Option<String> {
+    if document.trim_start().starts_with('{') {
+        find_json(document, item)
+    } else {
+        find_xml(document, item)
+    }
+}
+
+fn find_json(document: &str, item: &FeedItem) -> Option<String> {
+    let feed: serde_json::Value = serde_json::from_str(document).ok()?;
+    let entry = feed.get("items")?.as_array()?.iter().find(|entry| {
+        let field = |name: &str| entry.get(name).and_then(|value| value.as_str());
+        let by_link = item.link.as_deref().is_some_and(|link| {
+            ["url", "external_url", "id"]
+                .iter()
+                .any(|name| field(name) == Some(link))
+        });
+        by_link || field("title") == Some(item.title.as_str())
+    })?;
+    serde_json::to_string_pretty(entry).ok()
+}
+
+fn find_xml(document: &str, item: &FeedItem) -> Option<String> {
+    let entries: Vec<&str> = ["item", "entry"]
+        .iter()
+        .flat_map(|tag| elements(document, tag))
+        .collect();
+    // The link identifies an entry best; titles can repeat
+    let link = item.link.as_deref().filter(|link| !link.is_empty());
+    let found = link
+        .and_then(|link| {
+            entries
+                .iter()
+                .find(|entry| entry.contains(link) || entry.contains(&escape(link)))
+        })
+        .or_else(|| {
+            let title = item.title.trim();
+            entries
+                .iter()
+                .filter(|_| !title.is_empty())
+                .find(|entry| entry.contains(title) || entry.contains(&escape(title)))
+        })?;
+    Some(found.trim().to_string())
+}
+
+/// Fill in what feed-rs doesn't read about an entry: the RSS `<comments>` link, the comment
+/// count from `<slash:comments>`, Atom threading's `<thr:total>` or `thr:count` on the
+/// replies link, and the iTunes and geo extensions that go into `metadata`. Entries are
+/// matched to `items` by position, so nothing changes when their numbers differ.
+pub fn add_extensions(document: &str, items: &mut [FeedItem]) {
+    if document.trim_start().starts_with('{') {
+        return;
+    }
+    let entries: Vec<&str> = ["item", "entry"]
+        .iter()
+        .flat_map(|tag| elements(document, tag))
+        .collect();
+    if entries.len() != items.len() {
+        return;
+    }
+    for (entry, item) in entries.into_iter().zip(items) {
+        if item.comments_url.is_none() {
+            item.comments_url = element_text(entry, "comments")
+                .filter(|url| url.starts_with("http://") || url.starts_with("https://"));
+        }
+        item.comment_count = element_text(entry, "slash:comments")
+            .or_else(|| element_text(entry, "thr:total"))
+            .or_else(|| attribute(entry, "thr:count"))
+            .and_then(|count| count.parse().ok());
+        add_metadata(entry, item);
+    }
+}
+
+fn add_metadata(entry: &str, item: &mut FeedItem) {
+    let number = |tag: &str| {
+        element_text(entry, tag)
+            .and_then(|text| text.parse::<u32>().ok())
+            .filter(|n| *n > 0)
+    };
+    if let Some(episode) = number("itunes:episode") {
+        item.metadata
+            .insert(meta::EPISODE.to_string(), episode.to_string());
+    }
+    if let Some(season) = number("itunes:season") {
+        item.metadata
+            .insert(meta::SEASON.to_string(), season.to_string());
+    }
+    if let Some(seconds) = element_text(entry, "itunes:duration")
+        .and_then(|text| parse_duration(&text))
+        .filter(|seconds| *seconds > 0)
+    {
+        item.metadata
+            .insert(meta::DURATION.to_string(), seconds.to_string());
+    }
+
+    let (lat, lon) = match element_text(entry, "georss:point") {
+        Some(point) => {
+            let mut parts = point.split_whitespace().map(str::to_string);
+            (parts.next(), parts.next())
+        }
+        None => (
+            element_text(entry, "geo:lat"),
+            element_text(entry, "geo:long"),
+        ),
+    };
+    let degrees = |text: Option<String>, limit: f64| {
+        text.and_then(|text| text.parse::<f64>().ok())
+            .filter(|value| value.abs() <= limit)
+    };
+    if let (Some(lat), Some(lon)) = (degrees(lat, 90.0), degrees(lon, 180.0)) {
+        item.metadata
+            .insert(meta::GEO.to_string(), format!("{} {}", lat, lon));
+    }
+
+    if !item.metadata.contains_key(meta::THUMBNAIL) {
+        let image = entry
+            .find("<itunes:image")
+            .and_then(|start| attribute(&entry[start..], "href"))
+            .map(|url| unescape(&url))
+            .filter(|url| url.starts_with("http://") || url.starts_with("https://"));
+        if let Some(image) = image {
+            item.metadata.insert(meta::THUMBNAIL.to_string(), image);
+        }
+    }
+}
+
+// `itunes:duration` is seconds, `MM:SS` or `HH:MM:SS`
+fn parse_duration(text: &str) -> Option<u64> {
+    text.split(':').try_fold(0u64, |total, part| {
+        let part = part.trim();
+        // Some feeds write fractional seconds
+        let value = part.split('.').next()?.parse::<u64>().ok()?;
+        Some(total * 60 + value)
+    })
+}
+
+// The text inside the first `<tag>` element of `entry`, without CDATA markers
+fn element_text(entry: &str, tag: &str) -> Option<String> {
+    let element = *elements(entry, tag).first()?;
+    let inner = &element[element.find('>')? + 1..element.rfind("</")?];
+    let inner = inner.trim();
+    let inner = inner
+        .strip_prefix("<![CDATA[")
+        .and_then(|text| text.strip_suffix("]]>"))
+        .unwrap_or(inner);
+    let text = unescape(inner.trim());
+    (!text.is_empty()).then_some(text)
+}
+
+// The value of the first `name="…"` attribute in `entry`
+fn attribute(entry: &str, name: &str) -> Option<String> {
+    let start = entry.find(&format!("{}=", name))? + name.len() + 1;
+    let quote = entry[start..]
+        .chars()
+        .next()
+        .filter(|c| *c == '"' || *c == '\'')?;
+    let value = &entry[start + 1..];
+    Some(value[..value.find(quote)?].to_string())
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&amp;", "&")
+}
+
+// Every `<tag …>…</tag>` element in `document`, outermost first
+fn elements<'a>(document: &'a str, tag: &str) -> Vec<&'a str> {
+    let open = format!("<{}", tag);
+    let close = format!("</{}>", tag);
+    let mut found = Vec::new();
+    let mut rest = 0;
+    while let Some(pos) = document[rest..].find(&open) {
+        let start = rest + pos;
+        let after = start + open.len();
+        // `<items>` or `<entryLink>` aren't the element we're after
+        let boundary = document[after..]
+            .chars()
+            .next()
+            .is_some_and(|c| c == '>' || c == '/' || c.is_whitespace());
+        if !boundary {
+            rest = after;
+            continue;
+        }
+        let Some(end) = document[after..].find(&close) else {
+            break;
+        };
+        let end = after + end + close.len();
+        found.push(&document[start..end]);
+        rest = end;
+    }
+    found
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(title: &str, link: Option<&str>) -> FeedItem {
+        FeedItem {
+            link: link.map(str::to_string),
+            ..FeedItem::with_title(title)
+        }
+    }
+
+    #[test]
+    fn test_finds_rss_and_atom_entries_by_link() {
+        let rss = r#"<rss><channel><title>Blog</title>
+<item><title>First</title><link>https://example.com/a?x=1&amp;y=2</link></item>
+<item><title>Second</title><link>https://example.com/b</link></item>
+</channel></rss>"#;
+        let found = find(rss, &item("First", Some("https://example.com/a?x=1&y=2"))).unwrap();
+        assert!(found.starts_with("<item>") && found.contains("First"));
+        let found = find(rss, &item("Renamed", Some("https://example.com/b"))).unwrap();
+        assert!(found.contains("Second"));
+
+        let atom = r#"<feed><entry xml:lang="en"><title>Only</title>
+<link href="https://example.com/c"/></entry></feed>"#;
+        let found = find(atom, &item("Only", Some("https://example.com/c"))).unwrap();
+        assert!(found.starts_with("<entry xml:lang=\"en\">") && found.ends_with("</entry>"));
+        assert!(find(atom, &item("Missing", Some("https://example.com/z"))).is_none());
+    }
+
+    #[test]
+    fn test_add_comments_from_rss_and_atom() {
+        let rss = r#"<rss xmlns:slash="http://purl.org/rss/1.0/modules/slash/"><channel>
+<item><title>First</title><comments>https://news.example.com/item?id=1&amp;p=2</comments>
+<slash:comments>42</slash:comments></item>
+<item><title>Second</title><comments><![CDATA[https://example.com/b#comments]]></comments></item>
+<item><title>Third</title></item>
+</channel></rss>"#;
+        let mut items = vec![
+            item("First", None),
+            item("Second", None),
+            item("Third", None),
+        ];
+        add_extensions(rss, &mut items);
+        assert_eq!(
+            items[0].comments_url.as_deref(),
+            Some("https://news.example.com/item?id=1&p=2")
+        );
+        assert_eq!(items[0].comment_count, Some(42));
+        assert_eq!(
+            items[1].comments_url.as_deref(),
+            Some("https://example.com/b#comments")
+        );
+        assert_eq!(items[1].comment_count, None);
+        assert_eq!(items[2].comments_url, None);
+
+        let atom = r#"<feed><entry><title>Only</title>
+<link rel="replies" href="https://example.com/c/comments" thr:count="7"/></entry></feed>"#;
+        let mut items = vec![item("Only", None)];
+        items[0].comments_url = Some("https://example.com/c/comments".to_string());
+        add_extensions(atom, &mut items);
+        assert_eq!(items[0].comment_count, Some(7));
+
+        // Can't tell which entry is which, so nothing is filled in
+        let mut items = vec![item("First", None)];
+        add_extensions(rss, &mut items);
+        assert_eq!(items[0].comments_url, None);
+    }
+
+    #[test]
+    fn test_add_extensions_reads_podcast_and_geo_metadata() {
+        let rss = r#"<rss><channel>
+<item><title>Episode</title><itunes:episodeType>full</itunes:episodeType>
+<itunes:episode>42</itunes:episode><itunes:season>2</itunes:season>
+<itunes:duration>00:38:12</itunes:duration>
+<itunes:image href="https://example.com/cover.jpg?a=1&amp;b=2"/></item>
+<item><title>Short</title><itunes:duration>95</itunes:duration>
+<georss:point>45.256 -71.92</georss:point></item>
+<item><title>Elsewhere</title><geo:lat>91</geo:lat><geo:long>10</geo:long>
+<itunes:duration>soon</itunes:duration></item>
+</channel></rss>"#;
+        let mut items = vec![
+            item("Episode", None),
+            item("Short", None),
+            item("Elsewhere", None),
+        ];
+        add_extensions(rss, &mut items);
+        let get = |item: &FeedItem, key: &str| item.metadata.get(key).cloned();
+        assert_eq!(get(&items[0], meta::EPISODE).as_deref(), Some("42"));
+        assert_eq!(get(&items[0], meta::SEASON).as_deref(), Some("2"));
+        assert_eq!(get(&items[0], meta::DURATION).as_deref(), Some("2292"));
+        assert_eq!(
+            get(&items[0], meta::THUMBNAIL).as_deref(),
+            Some("https://example.com/cover.jpg?a=1&b=2")
+        );
+        assert_eq!(get(&items[1], meta::DURATION).as_deref(), Some("95"));
+        assert_eq!(get(&items[1], meta::GEO).as_deref(), Some("45.256 -71.92"));
+        // Out-of-range coordinates and unreadable durations are left out
+        assert!(items[2].metadata.is_empty());
+    }
+
+    #[test]
+    fn test_finds_json_feed_items() {
+        let json = r#"{"version": "https://jsonfeed.org/version/1.1", "items": [
+            {"id": "1", "url": "https://example.com/one", "title": "One"},
+            {"id": "2", "title": "Two", "content_text": "hi"}
+        ]}"#;
+        let found = find(json, &item("One", Some("https://example.com/one"))).unwrap();
+        assert!(found.contains("\"id\": \"1\""));
+        let found = find(json, &item("Two", None)).unwrap();
+        assert!(found.contains("content_text"));
+    }
+}
@@ -0,0 +1,137 @@
+use crate::app::App;
+use crate::feed::Feed;
+use crate::ui::utils::format_content_for_reading;
+use anyhow::{bail, Result};
+use html2text::render::text_renderer::TrivialDecorator;
+
+pub use crate::cli::ReadFormat;
+
+/// Wrap width used for text and markdown output, matching the detail view.
+const READ_WIDTH: usize = 100;
+
+/// An article resolved from a URL or item ID, with its content still as HTML.
+struct Article {
+    title: String,
+    link: Option<String>,
+    html: String,
+}
+
+/// Print an article to stdout. `target` is either an item ID from a subscribed
+/// feed or any article URL.
+pub fn run(target: &str, format: ReadFormat) -> Result<()> {
+    let app = App::new();
+    let article = match find_in_bookmarks(&app, target)? {
+        Some(article) => article,
+        None => fetch_page(&app, target)?,
+    };
+    println!("{}", render(&article, format));
+    Ok(())
+}
+
+/// Look the target up among subscribed feeds' items. Only the feed whose URL
+/// prefixes a `feedurl_title` style ID is fetched; plain links fall back to
+/// fetching the page itself.
+fn find_in_bookmarks(app: &App, target: &str) -> Result<Option<Article>> {
+    let Some(feed_url) = app
+        .bookmarks
+        .iter()
+        .find(|url| target.starts_with(&format!("{}_", url)))
+    else {
+        return Ok(None);
+    };
+
+    let client = app.http_client()?;
+    let feed = Feed::fetch_url(feed_url, &client, &app.request_options(feed_url))?.into_feed()?;
+
+    let title = &target[feed_url.len() + 1..];
+    Ok(feed
+        .items
+        .into_iter()
+        .find(|item| item.title == title)
+        .map(|item| Article {
+            title: item.title,
+            link: item.link,
+            html: item.description.unwrap_or_default(),
+        }))
+}
+
+fn fetch_page(app: &App, url: &str) -> Result<Article> {
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        bail!("'{}' is not a URL or a known item ID", url);
+    }
+
+    let client = Feed::build_client(app.config.network.http_timeout)?;
+    let (title, html) = Feed::fetch_article_page(url, &client, &app.config.network.user_agent)?;
+
+    Ok(Article {
+        title,
+        link: Some(url.to_string()),
+        html,
+    })
+}
+
+fn render(article: &Article, format: ReadFormat) -> String {
+    let link = article.link.as_deref().unwrap_or("");
+    match format {
+        ReadFormat::Text => {
+            let body = html2text::from_read_with_decorator(
+                article.html.as_bytes(),
+                READ_WIDTH,
+                TrivialDecorator::new(),
+            );
+            format!(
+                "{}\n{}\n\n{}",
+                article.title,
+                link,
+                format_content_for_reading(&body)
+            )
+        }
+        // html2text's default decorator already emits markdown-style headings, emphasis and links
+        ReadFormat::Md => {
+            let body = html2text::from_read(article.html.as_bytes(), READ_WIDTH);
+            format!("# {}\n\n<{}>\n\n{}", article.title, link, body.trim_end())
+        }
+        ReadFormat::Html => format!(
+            "<article>\n<h1>{}</h1>\n<p><a href=\"{}\">{}</a></p>\n{}\n</article>",
+            escape_html(&article.title),
+            escape_html(link),
+            escape_html(link),
+            article.html
+        ),
+    }
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn article() -> Article {
+        Article {
+            title: "Hello".to_string(),
+            link: Some("https://example.com/hello".to_string()),
+            html: "<p>First <em>para</em></p>".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_render_md_has_heading_and_link() {
+        let out = render(&article(), ReadFormat::Md);
+        assert!(out.starts_with("# Hello\n\n<https://example.com/hello>"));
+        assert!(out.contains("para"));
+    }
+
+    #[test]
+    fn test_render_text_strips_markup() {
+        let out = render(&article(), ReadFormat::Text);
+        assert!(out.starts_with("Hello\nhttps://example.com/hello"));
+        assert!(out.contains("First para"));
+        assert!(!out.contains("<p>"));
+    }
+}
@@ -0,0 +1,177 @@
+use anyhow::{anyhow, Context, Result};
+use serde_json::Value;
+use std::path::{Path, PathBuf};
+
+/// Where NetNewsWire keeps an account's subscriptions inside the account folder.
+const NETNEWSWIRE_OPML: &str = "Subscriptions.opml";
+
+/// A feed from another reader's export, with the folder it was filed in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Subscription {
+    pub url: String,
+    pub folder: Option<String>,
+}
+
+/// Load an OPML export. `path` may also be a NetNewsWire account folder (such as
+/// `Accounts/OnMyMac`), in which case its `Subscriptions.opml` is read.
+pub fn load_opml(path: &Path) -> Result<opml::OPML> {
+    let file_path: PathBuf = if path.is_dir() {
+        path.join(NETNEWSWIRE_OPML)
+    } else {
+        path.to_path_buf()
+    };
+    let mut file = std::fs::File::open(&file_path)
+        .map_err(|e| anyhow!("Opening file {}. {}", file_path.display(), e))?;
+    opml::OPML::from_reader(&mut file).map_err(|e| anyhow!("OPML decode error. {}", e))
+}
+
+/// Every feed in `document`, in document order. Feeds inside an outline without a feed
+/// URL of its own (a NetNewsWire or Reeder folder) are filed under that outline's name;
+/// folders nested deeper keep the name of the top-level folder.
+pub fn subscriptions(document: &opml::OPML) -> Vec<Subscription> {
+    let mut found = Vec::new();
+    for outline in &document.body.outlines {
+        collect(outline, None, &mut found);
+    }
+    found
+}
+
+fn collect(outline: &opml::Outline, folder: Option<&str>, found: &mut Vec<Subscription>) {
+    if let Some(url) = &outline.xml_url {
+        found.push(Subscription {
+            url: url.clone(),
+            folder: folder.map(str::to_string),
+        });
+    }
+    let name = outline
+        .title
+        .as_deref()
+        .unwrap_or(&outline.text)
+        .trim()
+        .to_string();
+    let folder = match folder {
+        None if outline.xml_url.is_none() && !name.is_empty() => Some(name.as_str()),
+        folder => folder,
+    };
+    for child in &outline.outlines {
+        collect(child, folder, found);
+    }
+}
+
+/// Article links from a starred-items export: a Netscape bookmarks file (Reeder's
+/// bookmark export), a JSON Feed or a JSON list of articles, or one URL per line.
+pub fn starred_links(path: &Path) -> Result<Vec<String>> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    parse_starred(&text)
+}
+
+fn parse_starred(text: &str) -> Result<Vec<String>> {
+    let trimmed = text.trim_start();
+    if trimmed.starts_with('{') || trimmed.starts_with('[') {
+        let value: Value =
+            serde_json::from_str(text).map_err(|e| anyhow!("Starred JSON decode error. {}", e))?;
+        let items = match &value {
+            Value::Array(items) => items,
+            _ => value
+                .get("items")
+                .and_then(Value::as_array)
+                .ok_or_else(|| anyhow!("Starred JSON has no \"items\" list"))?,
+        };
+        return Ok(items.iter().filter_map(json_link).collect());
+    }
+    if trimmed.starts_with('<') {
+        return Ok(crate::netscape::parse(text)
+            .into_iter()
+            .map(|bookmark| bookmark.url)
+            .collect());
+    }
+    Ok(text
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with("http://") || line.starts_with("https://"))
+        .map(str::to_string)
+        .collect())
+}
+
+// A JSON Feed item's url (or external_url), a plain URL string, or a "link" field
+fn json_link(item: &Value) -> Option<String> {
+    let url = match item {
+        Value::String(url) => url.as_str(),
+        _ => ["url", "external_url", "link"]
+            .iter()
+            .find_map(|key| item.get(*key).and_then(Value::as_str))?,
+    };
+    let url = url.trim();
+    (url.starts_with("http://") || url.starts_with("https://")).then(|| url.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscriptions_keep_folders() {
+        let document = opml::OPML::from_str(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<opml version="1.1">
+  <head><title>Subscriptions-OnMyMac.opml</title></head>
+  <body>
+    <outline text="Daring Fireball" xmlUrl="https://daringfireball.net/feeds/main"/>
+    <outline text="Tech" title="Tech">
+      <outline text="Six Colors" xmlUrl="https://sixcolors.com/feed/"/>
+      <outline text="Deeper">
+        <outline text="Deep" xmlUrl="https://example.com/deep.xml"/>
+      </outline>
+    </outline>
+  </body>
+</opml>"#,
+        )
+        .unwrap();
+        let found = subscriptions(&document);
+        assert_eq!(
+            found,
+            vec![
+                Subscription {
+                    url: "https://daringfireball.net/feeds/main".to_string(),
+                    folder: None,
+                },
+                Subscription {
+                    url: "https://sixcolors.com/feed/".to_string(),
+                    folder: Some("Tech".to_string()),
+                },
+                Subscription {
+                    url: "https://example.com/deep.xml".to_string(),
+                    folder: Some("Tech".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_starred_formats() {
+        let json_feed = r#"{"version": "https://jsonfeed.org/version/1.1", "items": [
+            {"id": "1", "url": "https://example.com/a"},
+            {"id": "2", "external_url": "https://example.com/b"},
+            {"id": "3"}
+        ]}"#;
+        assert_eq!(
+            parse_starred(json_feed).unwrap(),
+            vec!["https://example.com/a", "https://example.com/b"]
+        );
+        assert_eq!(
+            parse_starred(r#"["https://example.com/c", {"link": "https://example.com/d"}]"#)
+                .unwrap(),
+            vec!["https://example.com/c", "https://example.com/d"]
+        );
+        assert_eq!(
+            parse_starred(r#"<DL><DT><A HREF="https://example.com/e">E</A></DL>"#).unwrap(),
+            vec!["https://example.com/e"]
+        );
+        assert_eq!(
+            parse_starred("# starred\nhttps://example.com/f\n\nnot a link\n").unwrap(),
+            vec!["https://example.com/f"]
+        );
+        assert!(parse_starred("{\"version\": 1}").is_err());
+    }
+}
@@ -0,0 +1,37 @@
+use crate::app::App;
+use crate::globals;
+use anyhow::Result;
+
+/// Fetch every subscription without launching the UI, so the HTTP cache and session
+/// state are current, and report how it went. Fails if any feed couldn't be fetched,
+/// so cron jobs and scripts notice.
+pub fn run() -> Result<()> {
+    let mut app = App::new();
+    globals::log(&format!("Refreshing {} feeds", app.bookmarks.len()));
+    app.refresh_feeds()?;
+    app.write_metrics()?;
+
+    let new_items = app.get_new_items_since_session().len();
+    for url in &app.bookmarks {
+        if let Some(summary) = app.fetch_summaries.get(url) {
+            globals::log(&format!("  {}: {}", url, summary));
+        }
+    }
+    // Refreshing counts as a session, so the next one reports items newer than this
+    app.shutdown()?;
+
+    let failed = app.failed_feeds.len();
+    println!(
+        "Refreshed {} of {} feeds, {} new items since the last session",
+        app.bookmarks.len() - failed,
+        app.bookmarks.len(),
+        new_items
+    );
+    for (url, error) in &app.failed_feeds {
+        eprintln!("  ! {}: {}", url, error);
+    }
+    if failed > 0 {
+        anyhow::bail!("{} feeds failed to refresh", failed);
+    }
+    Ok(())
+}
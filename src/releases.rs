@@ -0,0 +1,305 @@
+use crate::feed::Feed;
+use chrono::{DateTime, Utc};
+use regex::Regex;
+use std::cmp::Ordering;
+use std::sync::OnceLock;
+
+/// Share of item titles that must carry a version number for a feed of unknown origin
+/// to count as a release feed.
+const VERSIONED_SHARE: f64 = 0.8;
+/// Fewest items a feed of unknown origin needs before it's judged by its titles.
+const MIN_RELEASES: usize = 3;
+
+/// A version number as it appears in a release title: "v1.2.0", "tokio-1.35.1",
+/// "2.0.0-rc.1". A missing patch number counts as 0.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    /// Pre-release label ("rc.1", "beta.2"); None for a stable release
+    pub pre: Option<String>,
+    /// The version as written, with any leading "v" (usually the git tag)
+    pub text: String,
+}
+
+impl Version {
+    /// The first version number in `title`.
+    pub fn parse(title: &str) -> Option<Version> {
+        static PATTERN: OnceLock<Regex> = OnceLock::new();
+        let pattern = PATTERN.get_or_init(|| {
+            Regex::new(r"\b[vV]?(\d+)\.(\d+)(?:\.(\d+))?(?:-([0-9A-Za-z][0-9A-Za-z.]*))?\b")
+                .expect("valid version pattern")
+        });
+        let captures = pattern.captures(title)?;
+        let number = |idx: usize| {
+            captures
+                .get(idx)
+                .map_or(Some(0), |m| m.as_str().parse::<u64>().ok())
+        };
+        Some(Version {
+            major: number(1)?,
+            minor: number(2)?,
+            patch: number(3)?,
+            pre: captures.get(4).map(|m| m.as_str().to_string()),
+            text: captures.get(0)?.as_str().to_string(),
+        })
+    }
+
+    pub fn is_prerelease(&self) -> bool {
+        self.pre.is_some()
+    }
+
+    fn base(&self) -> (u64, u64, u64) {
+        (self.major, self.minor, self.patch)
+    }
+}
+
+/// One release in a feed's version timeline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Release {
+    pub version: Version,
+    /// The feed item announcing it
+    pub item_idx: usize,
+    pub date: Option<DateTime<Utc>>,
+    /// Changes since the previous release (the previous stable one, for a stable
+    /// release), when the feed's host has a compare page
+    pub diff_url: Option<String>,
+}
+
+/// Whether `feed` announces software releases: GitHub release or tag feeds, crates.io
+/// crate feeds, or any feed whose items are nearly all titled with a version number.
+pub fn is_release_feed(feed: &Feed) -> bool {
+    if github_repo(&feed.url).is_some() || crate_name(&feed.url).is_some() {
+        return true;
+    }
+    let versioned = feed
+        .items
+        .iter()
+        .filter(|item| Version::parse(&item.title).is_some())
+        .count();
+    feed.items.len() >= MIN_RELEASES
+        && versioned as f64 >= feed.items.len() as f64 * VERSIONED_SHARE
+}
+
+/// The feed's releases, newest version first, with each pre-release listed right after
+/// the release it leads up to. Items without a version number are left out, and a
+/// version announced twice is listed once.
+pub fn timeline(feed: &Feed) -> Vec<Release> {
+    let mut releases: Vec<Release> = Vec::new();
+    for (item_idx, item) in feed.items.iter().enumerate() {
+        let Some(version) = Version::parse(&item.title) else {
+            continue;
+        };
+        if releases
+            .iter()
+            .any(|r| r.version.base() == version.base() && r.version.pre == version.pre)
+        {
+            continue;
+        }
+        releases.push(Release {
+            version,
+            item_idx,
+            date: item.parsed_date,
+            diff_url: None,
+        });
+    }
+
+    // Oldest first to find each release's predecessor
+    releases.sort_by(oldest_first);
+    let mut previous_stable: Option<String> = None;
+    let mut previous: Option<String> = None;
+    for release in &mut releases {
+        let tag = tag(feed, release);
+        let base = if release.version.is_prerelease() {
+            previous.as_deref()
+        } else {
+            previous_stable.as_deref()
+        };
+        release.diff_url = base.and_then(|base| diff_url(&feed.url, base, &tag));
+        if !release.version.is_prerelease() {
+            previous_stable = Some(tag.clone());
+        }
+        previous = Some(tag);
+    }
+
+    // Newest version first, a stable release ahead of its own pre-releases
+    releases.sort_by(|a, b| {
+        b.version
+            .base()
+            .cmp(&a.version.base())
+            .then_with(|| a.version.is_prerelease().cmp(&b.version.is_prerelease()))
+            .then_with(|| b.date.cmp(&a.date))
+    });
+    releases
+}
+
+fn oldest_first(a: &Release, b: &Release) -> Ordering {
+    a.version
+        .base()
+        .cmp(&b.version.base())
+        .then_with(|| b.version.is_prerelease().cmp(&a.version.is_prerelease()))
+        .then_with(|| a.date.cmp(&b.date))
+        .then_with(|| a.version.pre.cmp(&b.version.pre))
+}
+
+// The release's git tag: the last part of a GitHub release link, else the version as
+// the title writes it
+fn tag(feed: &Feed, release: &Release) -> String {
+    feed.items[release.item_idx]
+        .link
+        .as_deref()
+        .and_then(|link| link.split_once("/releases/tag/"))
+        .map(|(_, tag)| tag.trim_end_matches('/').to_string())
+        .filter(|tag| !tag.is_empty())
+        .unwrap_or_else(|| release.version.text.clone())
+}
+
+fn diff_url(feed_url: &str, from: &str, to: &str) -> Option<String> {
+    if let Some(repo) = github_repo(feed_url) {
+        return Some(format!(
+            "https://github.com/{}/compare/{}...{}",
+            repo, from, to
+        ));
+    }
+    let name = crate_name(feed_url)?;
+    let strip = |tag: &str| tag.trim_start_matches(['v', 'V']).to_string();
+    Some(format!(
+        "https://diff.rs/{}/{}/{}",
+        name,
+        strip(from),
+        strip(to)
+    ))
+}
+
+// "owner/repo" for https://github.com/owner/repo/releases.atom (or tags.atom)
+fn github_repo(url: &str) -> Option<String> {
+    let url = url::Url::parse(url).ok()?;
+    if url.host_str()? != "github.com" {
+        return None;
+    }
+    let segments: Vec<&str> = url.path_segments()?.collect();
+    match segments.as_slice() {
+        [owner, repo, "releases.atom" | "tags.atom"] => Some(format!("{}/{}", owner, repo)),
+        _ => None,
+    }
+}
+
+// The crate of a crates.io feed: https://crates.io/api/v1/rss/crates/<name>.xml, or the
+// crate API URL a `crates:<name>` package watch subscribes to
+fn crate_name(url: &str) -> Option<String> {
+    let url = url::Url::parse(url).ok()?;
+    if url.host_str()? != "crates.io" {
+        return None;
+    }
+    let path = url.path();
+    let name = match path.strip_prefix("/api/v1/rss/crates/") {
+        Some(name) => name.strip_suffix(".xml").unwrap_or(name),
+        None => path.strip_prefix("/api/v1/crates/")?,
+    };
+    (!name.is_empty() && !name.contains('/')).then(|| name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feed::FeedItem;
+
+    fn feed(url: &str, titles: &[(&str, Option<&str>)]) -> Feed {
+        Feed {
+            url: url.to_string(),
+            title: "Releases".to_string(),
+            title_lower: "releases".to_string(),
+            description: None,
+            updated: None,
+            link: None,
+            items: titles
+                .iter()
+                .map(|(title, link)| FeedItem {
+                    link: link.map(str::to_string),
+                    ..FeedItem::with_title(title)
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_version_parse() {
+        let version = Version::parse("Release v1.2.3-rc.1: the big one").unwrap();
+        assert_eq!((version.major, version.minor, version.patch), (1, 2, 3));
+        assert_eq!(version.pre.as_deref(), Some("rc.1"));
+        assert_eq!(version.text, "v1.2.3-rc.1");
+        assert_eq!(Version::parse("tokio-1.35").unwrap().text, "1.35");
+        assert!(Version::parse("Weekly notes").is_none());
+    }
+
+    #[test]
+    fn test_timeline_groups_prereleases_with_diff_links() {
+        let tag = |t: &str| format!("https://github.com/acme/tool/releases/tag/{}", t);
+        let (t1, t2, t3, t4) = (
+            tag("v1.1.0"),
+            tag("v1.2.0-rc.1"),
+            tag("v1.2.0"),
+            tag("v1.3.0-beta.1"),
+        );
+        let feed = feed(
+            "https://github.com/acme/tool/releases.atom",
+            &[
+                ("v1.3.0-beta.1", Some(t4.as_str())),
+                ("v1.2.0", Some(t3.as_str())),
+                ("v1.2.0-rc.1", Some(t2.as_str())),
+                ("v1.1.0", Some(t1.as_str())),
+                ("Roadmap update", None),
+            ],
+        );
+        assert!(is_release_feed(&feed));
+
+        let releases = timeline(&feed);
+        let order: Vec<&str> = releases.iter().map(|r| r.version.text.as_str()).collect();
+        assert_eq!(
+            order,
+            vec!["v1.3.0-beta.1", "v1.2.0", "v1.2.0-rc.1", "v1.1.0"]
+        );
+        assert_eq!(
+            releases[1].diff_url.as_deref(),
+            Some("https://github.com/acme/tool/compare/v1.1.0...v1.2.0")
+        );
+        assert_eq!(
+            releases[0].diff_url.as_deref(),
+            Some("https://github.com/acme/tool/compare/v1.2.0...v1.3.0-beta.1")
+        );
+        assert_eq!(releases[3].diff_url, None);
+    }
+
+    #[test]
+    fn test_release_feed_detection() {
+        let crates = feed(
+            "https://crates.io/api/v1/rss/crates/serde.xml",
+            &[("serde 1.0.200", None), ("serde 1.0.199", None)],
+        );
+        assert!(is_release_feed(&crates));
+        assert!(is_release_feed(&feed(
+            "https://crates.io/api/v1/crates/serde",
+            &[]
+        )));
+        assert_eq!(
+            timeline(&crates)[0].diff_url.as_deref(),
+            Some("https://diff.rs/serde/1.0.199/1.0.200")
+        );
+
+        let versioned = feed(
+            "https://example.com/feed.xml",
+            &[("App 2.1", None), ("App 2.0", None), ("App 1.9", None)],
+        );
+        assert!(is_release_feed(&versioned));
+        let blog = feed(
+            "https://example.com/blog.xml",
+            &[
+                ("Hello", None),
+                ("Version 2.0 is out", None),
+                ("Thanks", None),
+            ],
+        );
+        assert!(!is_release_feed(&blog));
+    }
+}
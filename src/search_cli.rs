@@ -0,0 +1,197 @@
+use crate::app::App;
+use crate::feed::fold_for_search;
+use anyhow::{bail, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+
+/// A search result as `feedr search` reports it.
+#[derive(Debug, Serialize)]
+struct SearchHit {
+    id: String,
+    feed: String,
+    feed_url: String,
+    title: String,
+    link: Option<String>,
+    published: Option<String>,
+    read: bool,
+    starred: bool,
+}
+
+/// Search fetched items with the same matching as the UI's `/` and print the results,
+/// newest first: one per line (date, feed, title and link, tab-separated) or as JSON.
+/// Feeds come from the HTTP cache unless `refresh` is set, so it works offline.
+pub fn run(
+    query: &str,
+    feed: Option<&str>,
+    since: Option<&str>,
+    json: bool,
+    refresh: bool,
+) -> Result<()> {
+    let cutoff = since
+        .map(|since| parse_since(since).map(|age| Utc::now() - age))
+        .transpose()?;
+
+    let mut app = App::new();
+    if refresh {
+        app.refresh_feeds()?;
+        for (url, error) in &app.failed_feeds {
+            eprintln!("Failed to fetch {}: {}", url, error);
+        }
+    } else {
+        let missing = app.load_cached_feeds();
+        if missing > 0 {
+            eprintln!(
+                "{} feeds have nothing cached yet; run `feedr refresh` or pass --refresh",
+                missing
+            );
+        }
+    }
+
+    let hits = search(&app, query, feed, cutoff);
+    if json {
+        println!("{}", serde_json::to_string_pretty(&hits)?);
+        return Ok(());
+    }
+    for hit in &hits {
+        println!(
+            "{}\t{}\t{}\t{}",
+            hit.published.as_deref().unwrap_or(""),
+            hit.feed,
+            hit.title,
+            hit.link.as_deref().unwrap_or("")
+        );
+    }
+    Ok(())
+}
+
+/// How far back `--since` reaches: a number followed by m, h, d or w ("7d").
+fn parse_since(since: &str) -> Result<Duration> {
+    let since = since.trim();
+    let split = since.len() - since.chars().last().map_or(0, char::len_utf8);
+    let (count, unit) = since.split_at(split);
+    let Ok(count) = count.parse::<i64>() else {
+        bail!("Invalid --since \"{}\": expected e.g. 12h, 7d or 2w", since);
+    };
+    Ok(match unit {
+        "m" => Duration::minutes(count),
+        "h" => Duration::hours(count),
+        "d" => Duration::days(count),
+        "w" => Duration::weeks(count),
+        _ => bail!("Invalid --since \"{}\": expected e.g. 12h, 7d or 2w", since),
+    })
+}
+
+// Matches for `query`, limited to feeds whose URL is `feed` or whose title contains it,
+// and to items published after `cutoff`
+fn search(
+    app: &App,
+    query: &str,
+    feed: Option<&str>,
+    cutoff: Option<DateTime<Utc>>,
+) -> Vec<SearchHit> {
+    let feed_filter = feed.map(|feed| (feed, fold_for_search(feed)));
+    let mut found: Vec<(usize, usize)> = app
+        .find_items(query)
+        .into_iter()
+        .filter(|&(feed_idx, item_idx)| {
+            let source = &app.feeds[feed_idx];
+            let item = &source.items[item_idx];
+            let feed_matches = match &feed_filter {
+                Some((url, needle)) => source.url == *url || source.title_lower.contains(needle),
+                None => true,
+            };
+            let recent = match cutoff {
+                Some(cutoff) => matches!(item.parsed_date, Some(date) if date >= cutoff),
+                None => true,
+            };
+            feed_matches && recent
+        })
+        .collect();
+    found.sort_by_key(|&(feed_idx, item_idx)| {
+        std::cmp::Reverse(app.feeds[feed_idx].items[item_idx].parsed_date)
+    });
+
+    found
+        .into_iter()
+        .map(|(feed_idx, item_idx)| {
+            let source = &app.feeds[feed_idx];
+            let item = &source.items[item_idx];
+            SearchHit {
+                id: app.get_item_id(feed_idx, item_idx),
+                feed: source.title.clone(),
+                feed_url: source.url.clone(),
+                title: item.title.clone(),
+                link: item.link.clone(),
+                published: item.parsed_date.map(|d| d.to_rfc3339()),
+                read: app.is_item_read(feed_idx, item_idx),
+                starred: app.is_item_starred(feed_idx, item_idx),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feed::{Feed, FeedItem};
+
+    fn item(title: &str, days_old: i64) -> FeedItem {
+        FeedItem {
+            link: Some(format!("https://example.com/{}", title.to_lowercase())),
+            parsed_date: Some(Utc::now() - Duration::days(days_old)),
+            ..FeedItem::with_title(title)
+        }
+    }
+
+    fn feed(url: &str, title: &str, items: Vec<FeedItem>) -> Feed {
+        Feed {
+            url: url.to_string(),
+            title: title.to_string(),
+            title_lower: fold_for_search(title),
+            description: None,
+            updated: None,
+            link: None,
+            items,
+        }
+    }
+
+    #[test]
+    fn test_search_filters_by_feed_and_age_newest_first() {
+        let mut app = App::new();
+        app.feeds = vec![
+            feed(
+                "https://example.com/rust.xml",
+                "Rust Blog",
+                vec![item("Rust 1.0", 30), item("Async Rust", 2)],
+            ),
+            feed(
+                "https://example.com/news.xml",
+                "News",
+                vec![item("Rust in the kernel", 1)],
+            ),
+        ];
+
+        let all = search(&app, "rust", None, None);
+        let titles: Vec<&str> = all.iter().map(|hit| hit.title.as_str()).collect();
+        assert_eq!(titles, vec!["Rust in the kernel", "Async Rust", "Rust 1.0"]);
+
+        let recent = search(
+            &app,
+            "rust",
+            Some("rust blog"),
+            Some(Utc::now() - Duration::days(7)),
+        );
+        assert_eq!(recent.len(), 1);
+        assert_eq!(recent[0].title, "Async Rust");
+        assert_eq!(recent[0].feed_url, "https://example.com/rust.xml");
+    }
+
+    #[test]
+    fn test_parse_since_units() {
+        assert_eq!(parse_since("7d").unwrap(), Duration::days(7));
+        assert_eq!(parse_since("12h").unwrap(), Duration::hours(12));
+        assert_eq!(parse_since("2w").unwrap(), Duration::weeks(2));
+        assert!(parse_since("soon").is_err());
+        assert!(parse_since("").is_err());
+    }
+}
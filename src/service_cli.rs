@@ -0,0 +1,349 @@
+use crate::config::Config;
+use crate::globals;
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// A user-level scheduler that can run `feedr fetch` on a timer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Scheduler {
+    Systemd,
+    Launchd,
+}
+
+impl Scheduler {
+    fn current() -> Result<Self> {
+        if cfg!(target_os = "macos") {
+            Ok(Scheduler::Launchd)
+        } else if cfg!(target_os = "linux") {
+            Ok(Scheduler::Systemd)
+        } else {
+            bail!("feedr service supports systemd (Linux) and launchd (macOS) only")
+        }
+    }
+
+    // The files making up the service, with their contents
+    fn files(self, args: &[String], every: &str, seconds: u64) -> Result<Vec<(PathBuf, String)>> {
+        Ok(match self {
+            Scheduler::Systemd => {
+                let dir = systemd_dir()?;
+                vec![
+                    (
+                        dir.join(format!("{}.service", unit_name())),
+                        systemd_service(args),
+                    ),
+                    (
+                        dir.join(format!("{}.timer", unit_name())),
+                        systemd_timer(every, seconds),
+                    ),
+                ]
+            }
+            Scheduler::Launchd => vec![(launchd_path()?, launchd_plist(args, seconds))],
+        })
+    }
+
+    // Commands that start the installed service
+    fn start_commands(self, files: &[(PathBuf, String)]) -> Vec<Vec<String>> {
+        match self {
+            Scheduler::Systemd => vec![
+                command(&["systemctl", "--user", "daemon-reload"]),
+                command(&[
+                    "systemctl",
+                    "--user",
+                    "enable",
+                    "--now",
+                    &format!("{}.timer", unit_name()),
+                ]),
+            ],
+            Scheduler::Launchd => {
+                let plist = files[0].0.to_string_lossy();
+                // Unloading first picks up a changed plist when reinstalling
+                vec![
+                    command(&["launchctl", "unload", &plist]),
+                    command(&["launchctl", "load", "-w", &plist]),
+                ]
+            }
+        }
+    }
+
+    // Commands that stop the service before its files are removed
+    fn stop_commands(self) -> Result<Vec<Vec<String>>> {
+        Ok(match self {
+            Scheduler::Systemd => vec![command(&[
+                "systemctl",
+                "--user",
+                "disable",
+                "--now",
+                &format!("{}.timer", unit_name()),
+            ])],
+            Scheduler::Launchd => vec![command(&[
+                "launchctl",
+                "unload",
+                "-w",
+                &launchd_path()?.to_string_lossy(),
+            ])],
+        })
+    }
+}
+
+/// Write a user-level systemd timer (or a launchd agent on macOS) that runs
+/// `feedr fetch --quiet` every `every` ("30m", "2h"), and start it. With `print`, the
+/// files are printed instead of written.
+pub fn install(every: &str, print: bool) -> Result<()> {
+    let seconds = parse_interval(every)?;
+    let scheduler = Scheduler::current()?;
+    let exe = std::env::current_exe().context("Couldn't find the feedr executable")?;
+    let files = scheduler.files(&fetch_args(&exe), every, seconds)?;
+
+    if print {
+        for (path, contents) in &files {
+            println!("# {}\n{}", path.display(), contents);
+        }
+        return Ok(());
+    }
+    for (path, contents) in &files {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, contents).with_context(|| format!("Couldn't write {}", path.display()))?;
+        println!("Wrote {}", path.display());
+    }
+    let commands = scheduler.start_commands(&files);
+    // launchctl complains when unloading an agent that isn't loaded yet
+    let required = match scheduler {
+        Scheduler::Systemd => &commands[..],
+        Scheduler::Launchd => &commands[1..],
+    };
+    if scheduler == Scheduler::Launchd {
+        let _ = run_quietly(&commands[0]);
+    }
+    for cmd in required {
+        if let Err(e) = run_quietly(cmd) {
+            eprintln!("The files are in place; start the service with:");
+            for cmd in required {
+                eprintln!("  {}", cmd.join(" "));
+            }
+            bail!("Couldn't start the service: {}", e);
+        }
+    }
+    println!("Fetching feeds every {}", every);
+
+    let config = Config::load().unwrap_or_default();
+    if !config.network.http_cache {
+        println!("Note: network.http_cache is off, so the fetches will fail until it's turned on");
+    } else if config.network.cache_max_age < seconds {
+        println!(
+            "Note: set network.cache_max_age to at least {} so the UI opens on the fetched copies:\n  feedr config set network.cache_max_age {}",
+            seconds, seconds
+        );
+    }
+    Ok(())
+}
+
+/// Stop the service `install` set up and remove its files.
+pub fn uninstall() -> Result<()> {
+    let scheduler = Scheduler::current()?;
+    for cmd in scheduler.stop_commands()? {
+        let _ = run_quietly(&cmd);
+    }
+    // Only the paths are needed
+    let files = scheduler.files(&[], "", 0)?;
+    let mut removed = false;
+    for (path, _) in &files {
+        if path.exists() {
+            fs::remove_file(path).with_context(|| format!("Couldn't remove {}", path.display()))?;
+            println!("Removed {}", path.display());
+            removed = true;
+        }
+    }
+    if !removed {
+        println!("No feedr service is installed");
+    } else if scheduler == Scheduler::Systemd {
+        let _ = run_quietly(&command(&["systemctl", "--user", "daemon-reload"]));
+    }
+    Ok(())
+}
+
+/// An interval such as "15m", "2h" or "1d" in seconds. At least a minute, since the
+/// schedulers don't go finer in practice.
+fn parse_interval(every: &str) -> Result<u64> {
+    let every = every.trim();
+    let split = every.len() - every.chars().last().map_or(0, char::len_utf8);
+    let (count, unit) = every.split_at(split);
+    let seconds = match (count.parse::<u64>(), unit) {
+        (Ok(count), "m") => count.checked_mul(60),
+        (Ok(count), "h") => count.checked_mul(60 * 60),
+        (Ok(count), "d") => count.checked_mul(24 * 60 * 60),
+        _ => bail!("Invalid --every \"{}\": expected e.g. 15m, 2h or 1d", every),
+    };
+    let Some(seconds) = seconds else {
+        bail!("Invalid --every \"{}\": interval too large", every);
+    };
+    if seconds == 0 {
+        bail!("--every must be at least 1m");
+    }
+    Ok(seconds)
+}
+
+// `feedr fetch --quiet`, keeping the profile and data directory of this run
+fn fetch_args(exe: &Path) -> Vec<String> {
+    let mut args = vec![exe.to_string_lossy().to_string()];
+    if let Some(profile) = globals::profile() {
+        args.push("--profile".to_string());
+        args.push(profile.to_string());
+    }
+    if let Some(dir) = globals::data_dir_override() {
+        let dir = fs::canonicalize(dir).unwrap_or_else(|_| dir.to_path_buf());
+        args.push("--data-dir".to_string());
+        args.push(dir.to_string_lossy().to_string());
+    }
+    args.push("fetch".to_string());
+    args.push("--quiet".to_string());
+    args
+}
+
+// Each profile gets its own service, so they can run side by side
+fn unit_name() -> String {
+    match globals::profile() {
+        Some(profile) => format!("feedr-fetch-{}", profile),
+        None => "feedr-fetch".to_string(),
+    }
+}
+
+fn systemd_dir() -> Result<PathBuf> {
+    let config = dirs::config_dir().context("Couldn't find the config directory")?;
+    Ok(config.join("systemd").join("user"))
+}
+
+fn launchd_path() -> Result<PathBuf> {
+    let home = dirs::home_dir().context("Couldn't find the home directory")?;
+    let label = launchd_label();
+    Ok(home
+        .join("Library")
+        .join("LaunchAgents")
+        .join(format!("{}.plist", label)))
+}
+
+fn launchd_label() -> String {
+    format!("io.github.bahdotsh.{}", unit_name())
+}
+
+fn systemd_service(args: &[String]) -> String {
+    let exec: Vec<String> = args.iter().map(|arg| systemd_quote(arg)).collect();
+    format!(
+        "[Unit]\n\
+         Description=Fetch feeds into feedr's cache\n\
+         Wants=network-online.target\n\
+         After=network-online.target\n\
+         \n\
+         [Service]\n\
+         Type=oneshot\n\
+         ExecStart={}\n",
+        exec.join(" ")
+    )
+}
+
+fn systemd_timer(every: &str, seconds: u64) -> String {
+    format!(
+        "[Unit]\n\
+         Description=Fetch feeds for feedr every {}\n\
+         \n\
+         [Timer]\n\
+         OnBootSec=2min\n\
+         OnUnitActiveSec={}s\n\
+         \n\
+         [Install]\n\
+         WantedBy=timers.target\n",
+        every, seconds
+    )
+}
+
+fn systemd_quote(arg: &str) -> String {
+    // `%` starts a specifier in unit files
+    let arg = arg.replace('%', "%%");
+    if arg.is_empty() || arg.contains(|c: char| c.is_whitespace() || c == '"' || c == '\\') {
+        format!("\"{}\"", arg.replace('\\', "\\\\").replace('"', "\\\""))
+    } else {
+        arg
+    }
+}
+
+fn launchd_plist(args: &[String], seconds: u64) -> String {
+    let args: String = args
+        .iter()
+        .map(|arg| format!("        <string>{}</string>\n", escape(arg)))
+        .collect();
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+<plist version="1.0">
+<dict>
+    <key>Label</key>
+    <string>{}</string>
+    <key>ProgramArguments</key>
+    <array>
+{}    </array>
+    <key>StartInterval</key>
+    <integer>{}</integer>
+    <key>RunAtLoad</key>
+    <true/>
+</dict>
+</plist>
+"#,
+        launchd_label(),
+        args,
+        seconds
+    )
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+fn command(parts: &[&str]) -> Vec<String> {
+    parts.iter().map(|part| part.to_string()).collect()
+}
+
+// Run `cmd`, failing with its stderr (or why it couldn't start) when it doesn't succeed
+fn run_quietly(cmd: &[String]) -> Result<()> {
+    let output = Command::new(&cmd[0]).args(&cmd[1..]).output()?;
+    if !output.status.success() {
+        bail!("{}", String::from_utf8_lossy(&output.stderr).trim());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_interval() {
+        assert_eq!(parse_interval("15m").unwrap(), 900);
+        assert_eq!(parse_interval("2h").unwrap(), 7200);
+        assert_eq!(parse_interval("1d").unwrap(), 86400);
+        assert!(parse_interval("0m").is_err());
+        assert!(parse_interval("30").is_err());
+        assert!(parse_interval("5s").is_err());
+        assert!(parse_interval("99999999999999999d")
+            .unwrap_err()
+            .to_string()
+            .contains("interval too large"));
+    }
+
+    #[test]
+    fn test_generated_files_run_feedr_fetch() {
+        let args = command(&["/opt/my apps/feedr", "fetch", "--quiet"]);
+        let service = systemd_service(&args);
+        assert!(service.contains("ExecStart=\"/opt/my apps/feedr\" fetch --quiet\n"));
+        assert!(service.contains("Type=oneshot"));
+        assert!(systemd_timer("30m", 1800).contains("OnUnitActiveSec=1800s"));
+
+        let plist = launchd_plist(&args, 1800);
+        assert!(plist.contains("<string>/opt/my apps/feedr</string>"));
+        assert!(plist.contains("<integer>1800</integer>"));
+    }
+}
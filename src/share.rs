@@ -0,0 +1,377 @@
+use crate::feed::{Feed, FeedItem};
+use anyhow::{bail, Context, Result};
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Fill a share template's `{title}`, `{url}`, `{feed}`, `{author}` and `{date}` placeholders.
+pub fn render_template(template: &str, feed: &Feed, item: &FeedItem) -> String {
+    fill_placeholders(template, feed, item, str::to_string)
+}
+
+/// Fill the same placeholders in a shell command, each value quoted as one argument.
+pub fn render_command(template: &str, feed: &Feed, item: &FeedItem) -> String {
+    fill_placeholders(template, feed, item, shell_quote)
+}
+
+/// Fill the placeholders in a webhook's JSON template with JSON-escaped values, and
+/// check that the result is valid JSON.
+pub fn render_json(template: &str, feed: &Feed, item: &FeedItem) -> Result<String> {
+    let body = fill_placeholders(template, feed, item, json_escape);
+    serde_json::from_str::<serde_json::Value>(&body)
+        .context("Webhook template isn't valid JSON")?;
+    Ok(body)
+}
+
+/// POST a JSON `body` to a webhook, failing on any non-success status.
+#[cfg(feature = "sync")]
+pub fn post_webhook(client: &reqwest::blocking::Client, url: &str, body: String) -> Result<()> {
+    let response = client
+        .post(url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .body(body)
+        .send()
+        .with_context(|| format!("Failed to reach {}", url))?;
+    let status = response.status();
+    if !status.is_success() {
+        bail!(
+            "Webhook returned {}: {}",
+            status,
+            response.text().unwrap_or_default().trim()
+        );
+    }
+    Ok(())
+}
+
+/// Without the `sync` feature there are no outside services to post to.
+#[cfg(not(feature = "sync"))]
+pub fn post_webhook(_client: &reqwest::blocking::Client, _url: &str, _body: String) -> Result<()> {
+    bail!("feedr was built without the `sync` feature")
+}
+
+/// Replace each placeholder with `value` of the item's field, in one pass over the template
+/// so text that came from a value (a title containing `{url}`, say) is never filled again.
+fn fill_placeholders(
+    template: &str,
+    feed: &Feed,
+    item: &FeedItem,
+    value: fn(&str) -> String,
+) -> String {
+    let fields = [
+        ("{title}", item.title.as_str()),
+        ("{url}", item.link.as_deref().unwrap_or("")),
+        ("{feed}", feed.title.as_str()),
+        ("{author}", item.author.as_deref().unwrap_or("")),
+        ("{date}", item.formatted_date.as_deref().unwrap_or("")),
+    ];
+    let mut filled = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        filled.push_str(&rest[..start]);
+        rest = &rest[start..];
+        match fields.iter().find(|(name, _)| rest.starts_with(name)) {
+            Some((name, text)) => {
+                filled.push_str(&value(text));
+                rest = &rest[name.len()..];
+            }
+            None => {
+                filled.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+    filled.push_str(rest);
+    filled
+}
+
+/// Send `text` to the configured share command, or to the system clipboard when none is set.
+pub fn share_text(text: &str, command: Option<&str>) -> Result<()> {
+    match command {
+        Some(cmd) => pipe_to_shell(cmd, text),
+        None => copy_to_clipboard(text),
+    }
+}
+
+/// Copy `text` to the system clipboard using whichever platform tool is available.
+pub fn copy_to_clipboard(text: &str) -> Result<()> {
+    for (program, args) in clipboard_candidates() {
+        if pipe_to(program, args, text).is_ok() {
+            return Ok(());
+        }
+    }
+    bail!("No clipboard tool found (install wl-clipboard, xclip or xsel)")
+}
+
+fn clipboard_candidates() -> Vec<(&'static str, &'static [&'static str])> {
+    if cfg!(target_os = "macos") {
+        vec![("pbcopy", &[])]
+    } else if cfg!(windows) {
+        vec![("clip", &[])]
+    } else {
+        let mut candidates: Vec<(&'static str, &'static [&'static str])> = Vec::new();
+        if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+            candidates.push(("wl-copy", &[]));
+        }
+        candidates.push(("xclip", &["-selection", "clipboard"]));
+        candidates.push(("xsel", &["--clipboard", "--input"]));
+        candidates
+    }
+}
+
+/// Run `cmd` through the platform shell with `text` on stdin.
+#[cfg(feature = "scripting")]
+pub fn pipe_to_shell(cmd: &str, text: &str) -> Result<()> {
+    let (shell, flag) = shell();
+    pipe_to(shell, &[flag, cmd], text).with_context(|| format!("Command '{}' failed", cmd))
+}
+
+/// Run `cmd` through the platform shell with `text` on stdin and return what it prints.
+#[cfg(feature = "scripting")]
+pub fn filter_through_shell(cmd: &str, text: &str) -> Result<String> {
+    let (shell, flag) = shell();
+    let mut child = Command::new(shell)
+        .args([flag, cmd])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to run '{}'", cmd))?;
+
+    // Feed stdin from another thread so a command that writes before it finishes
+    // reading can't deadlock on a full pipe
+    let mut stdin = child.stdin.take().context("Failed to open stdin")?;
+    let input = text.to_string();
+    let writer = std::thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let output = child.wait_with_output()?;
+    let _ = writer.join();
+
+    if !output.status.success() {
+        bail!(
+            "'{}' exited with {}: {}",
+            cmd,
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .trim_end()
+        .to_string())
+}
+
+/// Run `cmd` through the platform shell and wait for it, reporting its stderr on failure.
+#[cfg(feature = "scripting")]
+pub fn run_shell(cmd: &str) -> Result<()> {
+    let (shell, flag) = shell();
+    let output = Command::new(shell)
+        .args([flag, cmd])
+        .stdin(Stdio::null())
+        .output()
+        .with_context(|| format!("Failed to run '{}'", cmd))?;
+    if !output.status.success() {
+        bail!(
+            "'{}' exited with {}: {}",
+            cmd,
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    Ok(())
+}
+
+// Without the `scripting` feature no configured command is run; each of these fails
+#[cfg(not(feature = "scripting"))]
+pub fn pipe_to_shell(_cmd: &str, _text: &str) -> Result<()> {
+    without_scripting()
+}
+
+#[cfg(not(feature = "scripting"))]
+pub fn filter_through_shell(_cmd: &str, _text: &str) -> Result<String> {
+    without_scripting()
+}
+
+#[cfg(not(feature = "scripting"))]
+pub fn run_shell(_cmd: &str) -> Result<()> {
+    without_scripting()
+}
+
+#[cfg(not(feature = "scripting"))]
+fn without_scripting<T>() -> Result<T> {
+    bail!("feedr was built without the `scripting` feature")
+}
+
+/// Quote `s` as a single argument for the platform shell.
+pub fn shell_quote(s: &str) -> String {
+    if cfg!(windows) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        format!("'{}'", s.replace('\'', "'\\''"))
+    }
+}
+
+// The inside of a JSON string literal, so templates supply the quotes themselves
+fn json_escape(s: &str) -> String {
+    let quoted = serde_json::Value::from(s).to_string();
+    quoted[1..quoted.len() - 1].to_string()
+}
+
+#[cfg(feature = "scripting")]
+fn shell() -> (&'static str, &'static str) {
+    if cfg!(windows) {
+        ("cmd", "/C")
+    } else {
+        ("sh", "-c")
+    }
+}
+
+fn pipe_to(program: &str, args: &[&str], text: &str) -> Result<()> {
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(text.as_bytes())?;
+    }
+    let status = child.wait()?;
+    if !status.success() {
+        bail!("{} exited with {}", program, status);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_template_fills_placeholders() {
+        let feed = Feed {
+            url: "https://example.com/feed.xml".to_string(),
+            title: "Example Blog".to_string(),
+            items: vec![],
+            title_lower: "example blog".to_string(),
+            description: None,
+            updated: None,
+            link: None,
+        };
+        let item = FeedItem {
+            link: Some("https://example.com/hello".to_string()),
+            formatted_date: Some("2 hours ago".to_string()),
+            ..FeedItem::with_title("Hello World")
+        };
+
+        assert_eq!(
+            render_template("\"{title}\" {url} via {feed}", &feed, &item),
+            "\"Hello World\" https://example.com/hello via Example Blog"
+        );
+        assert_eq!(
+            render_template("{title} by {author} ({date})", &feed, &item),
+            "Hello World by  (2 hours ago)"
+        );
+        #[cfg(unix)]
+        assert_eq!(
+            render_command("task add {title} {url}", &feed, &item),
+            "task add 'Hello World' 'https://example.com/hello'"
+        );
+    }
+
+    #[test]
+    fn test_render_template_leaves_placeholders_in_item_data() {
+        let feed = Feed {
+            url: "https://example.com/feed.xml".to_string(),
+            title: "Deals {url}".to_string(),
+            items: vec![],
+            title_lower: "deals {url}".to_string(),
+            description: None,
+            updated: None,
+            link: None,
+        };
+        let item = FeedItem {
+            link: Some("https://example.com/{title}".to_string()),
+            author: Some("{date}".to_string()),
+            formatted_date: Some("today".to_string()),
+            ..FeedItem::with_title("50% {feed} off")
+        };
+
+        assert_eq!(
+            render_template("{title} by {author} in {feed}: {url} {other}", &feed, &item),
+            "50% {feed} off by {date} in Deals {url}: https://example.com/{title} {other}"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_render_command_does_not_refill_values() {
+        let feed = Feed {
+            url: "https://example.com/feed.xml".to_string(),
+            title: "Example Blog".to_string(),
+            items: vec![],
+            title_lower: "example blog".to_string(),
+            description: None,
+            updated: None,
+            link: None,
+        };
+        let item = FeedItem {
+            link: Some("https://example.com/it's".to_string()),
+            ..FeedItem::with_title("a{url}")
+        };
+
+        let command = render_command("printf '%s|' {title} {url}", &feed, &item);
+        assert_eq!(
+            command,
+            "printf '%s|' 'a{url}' 'https://example.com/it'\\''s'"
+        );
+        #[cfg(feature = "scripting")]
+        assert_eq!(
+            filter_through_shell(&command, "").unwrap(),
+            "a{url}|https://example.com/it's|"
+        );
+    }
+
+    #[cfg(all(unix, feature = "scripting"))]
+    #[test]
+    fn test_filter_through_shell_returns_output() {
+        assert_eq!(
+            filter_through_shell("tr a-z A-Z", "hello").unwrap(),
+            "HELLO"
+        );
+        assert!(filter_through_shell("exit 3", "").is_err());
+    }
+
+    #[cfg(all(unix, feature = "scripting"))]
+    #[test]
+    fn test_shell_quote_survives_the_shell() {
+        let tricky = "it's \"$HOME\" `date`";
+        assert_eq!(
+            filter_through_shell(&format!("printf %s {}", shell_quote(tricky)), "").unwrap(),
+            tricky
+        );
+        assert!(run_shell("exit 0").is_ok());
+        assert!(run_shell("exit 2").is_err());
+    }
+
+    #[test]
+    fn test_render_json_escapes_values() {
+        let feed = Feed {
+            url: "https://example.com/feed.xml".to_string(),
+            title: "Example \\ Blog".to_string(),
+            items: vec![],
+            title_lower: "example \\ blog".to_string(),
+            description: None,
+            updated: None,
+            link: None,
+        };
+        let item = FeedItem {
+            link: Some("https://example.com/hi".to_string()),
+            ..FeedItem::with_title("Say \"hi\"\nnow")
+        };
+
+        let body = render_json(r#"{"text": "{title} {url} ({feed})"}"#, &feed, &item).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&body).unwrap();
+        assert_eq!(
+            value["text"],
+            "Say \"hi\"\nnow https://example.com/hi (Example \\ Blog)"
+        );
+        assert!(render_json("{\"text\": {title}}", &feed, &item).is_err());
+    }
+}
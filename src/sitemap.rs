@@ -0,0 +1,332 @@
+use crate::feed::{format_date, Feed, FeedItem};
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+/// Newest pages of a sitemap shown as items; older ones are left out like a feed's
+/// old entries would be.
+const MAX_ENTRIES: usize = 20;
+
+/// One page listed in a sitemap (or one sitemap listed in a sitemap index).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Entry {
+    pub loc: String,
+    pub lastmod: Option<DateTime<Utc>>,
+    /// Title from a news sitemap's `<news:title>`, or one looked up while fetching
+    pub title: Option<String>,
+}
+
+/// A sitemap document: a `<urlset>` of pages or a `<sitemapindex>` of other sitemaps.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Sitemap {
+    Pages(Vec<Entry>),
+    Index(Vec<Entry>),
+}
+
+fn pattern(cell: &'static OnceLock<Regex>, re: &str) -> &'static Regex {
+    cell.get_or_init(|| Regex::new(re).expect("valid sitemap pattern"))
+}
+
+/// `content` as a sitemap, if its root element is `<urlset>` or `<sitemapindex>`.
+pub fn parse(content: &[u8]) -> Option<Sitemap> {
+    static ROOT: OnceLock<Regex> = OnceLock::new();
+    static URL: OnceLock<Regex> = OnceLock::new();
+    static SITEMAP: OnceLock<Regex> = OnceLock::new();
+
+    let text = std::str::from_utf8(content)
+        .ok()?
+        .trim_start_matches('\u{feff}');
+    // The root element, after the XML declaration, stylesheets and comments
+    let root = pattern(
+        &ROOT,
+        r"^(?s)\s*(?:<\?.*?\?>\s*|<!--.*?-->\s*)*<(?:[\w-]+:)?(urlset|sitemapindex)[\s>]",
+    )
+    .captures(text)?;
+    if root.get(1)?.as_str() == "urlset" {
+        let block = pattern(&URL, r"(?s)<(?:[\w-]+:)?url>(.*?)</(?:[\w-]+:)?url>");
+        Some(Sitemap::Pages(entries(text, block)))
+    } else {
+        let block = pattern(
+            &SITEMAP,
+            r"(?s)<(?:[\w-]+:)?sitemap>(.*?)</(?:[\w-]+:)?sitemap>",
+        );
+        Some(Sitemap::Index(entries(text, block)))
+    }
+}
+
+fn entries(text: &str, block: &Regex) -> Vec<Entry> {
+    block
+        .captures_iter(text)
+        .filter_map(|captures| {
+            let body = captures.get(1)?.as_str();
+            Some(Entry {
+                loc: element(body, "loc")?,
+                lastmod: element(body, "lastmod").and_then(|date| parse_date(&date)),
+                title: element(body, "news:title"),
+            })
+        })
+        .collect()
+}
+
+// The text of the first `<name>` element in `body`, unescaped, if it isn't empty
+fn element(body: &str, name: &str) -> Option<String> {
+    let start = body.find(&format!("<{}>", name))? + name.len() + 2;
+    let end = start + body[start..].find(&format!("</{}>", name))?;
+    let raw = body[start..end].trim();
+    let raw = raw
+        .strip_prefix("<![CDATA[")
+        .and_then(|inner| inner.strip_suffix("]]>"))
+        .map(str::to_string)
+        .unwrap_or_else(|| unescape(raw));
+    let text = raw.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+fn parse_date(date: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(date)
+        .map(|date| date.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            let day = NaiveDate::parse_from_str(date.get(..10)?, "%Y-%m-%d").ok()?;
+            Some(Utc.from_utc_datetime(&day.and_hms_opt(0, 0, 0)?))
+        })
+}
+
+/// The pages a sitemap shows as items: the newest `MAX_ENTRIES` by last modification,
+/// undated pages after dated ones.
+fn newest(mut pages: Vec<Entry>) -> Vec<Entry> {
+    // Sitemaps usually list the oldest pages first
+    pages.reverse();
+    pages.sort_by(|a, b| match (&a.lastmod, &b.lastmod) {
+        (Some(a), Some(b)) => b.cmp(a),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+    pages.truncate(MAX_ENTRIES);
+    pages
+}
+
+/// For a sitemap (following an index to its newest sitemap), look up the titles of
+/// the newest pages and return a sitemap document that carries them, to be parsed and
+/// cached in place of the original. None when `content` isn't a sitemap.
+///
+/// Titles are remembered for the rest of the session, so a refresh only fetches the
+/// pages that are new since the last one.
+pub fn fetch_titles(
+    content: &[u8],
+    client: &reqwest::blocking::Client,
+    user_agent: &str,
+) -> Option<Result<Vec<u8>>> {
+    let pages = match parse(content)? {
+        Sitemap::Pages(pages) => pages,
+        Sitemap::Index(sitemaps) => {
+            let Some(latest) = newest(sitemaps).into_iter().next() else {
+                return Some(Err(anyhow!("The sitemap index lists no sitemaps")));
+            };
+            let body = match fetch(&latest.loc, client, user_agent) {
+                Ok(body) => body,
+                Err(e) => return Some(Err(e)),
+            };
+            match parse(&body) {
+                Some(Sitemap::Pages(pages)) => pages,
+                _ => return Some(Err(anyhow!("{} isn't a sitemap of pages", latest.loc))),
+            }
+        }
+    };
+
+    static TITLES: OnceLock<Mutex<HashMap<String, String>>> = OnceLock::new();
+    let titles = TITLES.get_or_init(Default::default);
+    let mut pages = newest(pages);
+    for page in pages.iter_mut().filter(|page| page.title.is_none()) {
+        let known = titles
+            .lock()
+            .ok()
+            .and_then(|memo| memo.get(&page.loc).cloned());
+        page.title = known.or_else(|| {
+            // A page that can't be fetched keeps a title made from its URL
+            let (title, _) = Feed::fetch_article_page(&page.loc, client, user_agent).ok()?;
+            (title != page.loc).then_some(title)
+        });
+        if let (Some(title), Ok(mut memo)) = (&page.title, titles.lock()) {
+            memo.insert(page.loc.clone(), title.clone());
+        }
+    }
+    Some(Ok(to_document(&pages).into_bytes()))
+}
+
+fn fetch(url: &str, client: &reqwest::blocking::Client, user_agent: &str) -> Result<Vec<u8>> {
+    let body = client
+        .get(url)
+        .header("User-Agent", user_agent)
+        .send()
+        .and_then(|r| r.error_for_status())
+        .and_then(|r| r.bytes())
+        .with_context(|| format!("Failed to fetch {}", url))?;
+    Ok(body.to_vec())
+}
+
+/// A `<urlset>` of `pages`, with each known title as a Google News `<news:title>`.
+fn to_document(pages: &[Entry]) -> String {
+    let mut doc = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\" \
+         xmlns:news=\"http://www.google.com/schemas/sitemap-news/0.9\">\n",
+    );
+    for page in pages {
+        doc.push_str(&format!("<url><loc>{}</loc>", escape(&page.loc)));
+        if let Some(lastmod) = page.lastmod {
+            doc.push_str(&format!("<lastmod>{}</lastmod>", lastmod.to_rfc3339()));
+        }
+        if let Some(title) = &page.title {
+            doc.push_str(&format!(
+                "<news:news><news:title>{}</news:title></news:news>",
+                escape(title)
+            ));
+        }
+        doc.push_str("</url>\n");
+    }
+    doc.push_str("</urlset>\n");
+    doc
+}
+
+/// Turn a sitemap's newest pages into a feed named after the site. Pages without a
+/// known title are named after the last part of their URL.
+pub fn to_feed(url: &str, sitemap: &Sitemap) -> Result<Feed> {
+    let Sitemap::Pages(pages) = sitemap else {
+        return Err(anyhow!(
+            "This is a sitemap index; subscribe to one of the sitemaps it lists"
+        ));
+    };
+    let site = url::Url::parse(url)
+        .ok()
+        .and_then(|u| {
+            u.host_str()
+                .map(|h| h.trim_start_matches("www.").to_string())
+        })
+        .unwrap_or_else(|| url.to_string());
+    let items = newest(pages.clone())
+        .into_iter()
+        .map(|page| FeedItem {
+            title: page.title.clone().unwrap_or_else(|| slug_title(&page.loc)),
+            link: Some(page.loc),
+            description: None,
+            summary: None,
+            pub_date: page.lastmod.map(|date| date.to_rfc3339()),
+            author: None,
+            formatted_date: page.lastmod.map(format_date),
+            parsed_date: None,
+            plain_text: None,
+            title_lower: String::new(),
+            plain_text_lower: None,
+            language: None,
+            image: None,
+            comments_url: None,
+            comment_count: None,
+            metadata: Default::default(),
+        })
+        .collect();
+
+    let mut feed = Feed {
+        url: url.to_string(),
+        title: format!("{} (sitemap)", site),
+        items,
+        title_lower: String::new(),
+        description: None,
+        updated: None,
+        link: Some(format!("https://{}/", site)),
+    };
+    feed.restore_derived_fields();
+    Ok(feed)
+}
+
+// "https://example.com/blog/hello-big-world.html" -> "Hello big world"
+fn slug_title(loc: &str) -> String {
+    let slug = loc
+        .trim_end_matches('/')
+        .rsplit('/')
+        .next()
+        .unwrap_or(loc)
+        .split(['.', '?', '#'])
+        .next()
+        .unwrap_or_default()
+        .replace(['-', '_'], " ");
+    let slug = slug.trim();
+    let mut chars = slug.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => loc.to_string(),
+    }
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const URLSET: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <url><loc>https://www.example.com/blog/first-post/</loc><lastmod>2024-01-05</lastmod></url>
+  <url><loc>https://www.example.com/about</loc></url>
+  <url><loc>https://www.example.com/blog/a-newer_post.html</loc><lastmod>2024-03-01T10:00:00+00:00</lastmod></url>
+</urlset>"#;
+
+    #[test]
+    fn test_sitemap_pages_become_items_newest_first() {
+        let sitemap = parse(URLSET.as_bytes()).unwrap();
+        let feed = to_feed("https://www.example.com/sitemap.xml", &sitemap).unwrap();
+        assert_eq!(feed.title, "example.com (sitemap)");
+        let titles: Vec<&str> = feed.items.iter().map(|i| i.title.as_str()).collect();
+        assert_eq!(titles, vec!["A newer post", "First post", "About"]);
+        assert!(feed.items[0].parsed_date.is_some());
+        assert!(feed.items[2].parsed_date.is_none());
+
+        assert!(parse(b"<rss version=\"2.0\"><channel></channel></rss>").is_none());
+    }
+
+    #[test]
+    fn test_fetched_titles_survive_the_rewritten_document() {
+        let Some(Sitemap::Pages(mut pages)) = parse(URLSET.as_bytes()) else {
+            panic!("expected a urlset");
+        };
+        pages[1].title = Some("About <us> & more".to_string());
+        let rewritten = to_document(&pages);
+        let feed = to_feed(
+            "https://www.example.com/sitemap.xml",
+            &parse(rewritten.as_bytes()).unwrap(),
+        )
+        .unwrap();
+        assert!(feed.items.iter().any(|i| i.title == "About <us> & more"));
+    }
+
+    #[test]
+    fn test_sitemap_index_is_recognized() {
+        let index = br#"<?xml version="1.0"?>
+<sitemapindex xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <sitemap><loc>https://example.com/sitemap-2023.xml</loc><lastmod>2023-12-31</lastmod></sitemap>
+  <sitemap><loc>https://example.com/sitemap-2024.xml</loc><lastmod>2024-06-30</lastmod></sitemap>
+</sitemapindex>"#;
+        let Some(Sitemap::Index(sitemaps)) = parse(index) else {
+            panic!("expected a sitemap index");
+        };
+        assert_eq!(
+            newest(sitemaps)[0].loc,
+            "https://example.com/sitemap-2024.xml"
+        );
+    }
+}
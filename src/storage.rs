@@ -0,0 +1,637 @@
+use crate::app::SavedData;
+use crate::config::StorageBackend;
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Somewhere the app's saved state lives: subscriptions, categories, read and star state,
+/// archived feeds with their items, and the rest of [`SavedData`]. Subscribed feeds and
+/// their items aren't part of it; they come from the network or the HTTP cache on each
+/// start. Saves hand over a full snapshot, and a backend works out what changed.
+pub trait Storage: std::fmt::Debug + Send + Sync {
+    /// The saved state, or None when nothing has been saved yet.
+    fn load(&self) -> Result<Option<SavedData>>;
+
+    /// Replace the saved state with `data`.
+    fn save(&self, data: &SavedData) -> Result<()>;
+
+    /// The file the state is kept in.
+    fn path(&self) -> &Path;
+}
+
+/// Open the storage `backend` picks. `json_path` is where the JSON data file lives; the
+/// other backends keep their file next to it.
+pub fn open(backend: StorageBackend, json_path: PathBuf) -> Result<Box<dyn Storage>> {
+    Ok(match backend {
+        StorageBackend::Json => Box::new(JsonStorage::new(json_path)),
+        StorageBackend::Sqlite => open_sqlite(json_path)?,
+    })
+}
+
+#[cfg(feature = "sqlite")]
+fn open_sqlite(json_path: PathBuf) -> Result<Box<dyn Storage>> {
+    Ok(Box::new(sqlite::SqliteStorage::open(json_path)?))
+}
+
+/// Without the `sqlite` feature there's no database driver; opening one fails.
+#[cfg(not(feature = "sqlite"))]
+fn open_sqlite(_json_path: PathBuf) -> Result<Box<dyn Storage>> {
+    anyhow::bail!("feedr was built without the `sqlite` feature")
+}
+
+/// Everything in one JSON file, `feedr_data.json`. Simple to read, back up and edit by
+/// hand, but the whole file is rewritten on every save.
+#[derive(Debug)]
+pub struct JsonStorage {
+    path: PathBuf,
+}
+
+impl JsonStorage {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+impl Storage for JsonStorage {
+    fn load(&self) -> Result<Option<SavedData>> {
+        if !self.path.exists() {
+            return Ok(None);
+        }
+        let data = fs::read_to_string(&self.path)?;
+        let saved_data = serde_json::from_str(&data)
+            .with_context(|| format!("Couldn't parse {}", self.path.display()))?;
+        Ok(Some(saved_data))
+    }
+
+    fn save(&self, data: &SavedData) -> Result<()> {
+        let json = serde_json::to_string(data)?;
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        // Write to a temp file first so an interrupted write never truncates the data file
+        let tmp_path = self.path.with_extension("json.tmp");
+        fs::write(&tmp_path, json)?;
+        fs::rename(tmp_path, &self.path)?;
+        Ok(())
+    }
+
+    fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite {
+    use super::{JsonStorage, Storage};
+    use crate::app::SavedData;
+    use crate::feed::{Feed, FeedCategory};
+    #[cfg(feature = "sync")]
+    use crate::sync_state::StateEntry;
+    use anyhow::{bail, Context, Result};
+    use rusqlite::{params, Connection, OpenFlags, Transaction};
+    use serde_json::{Map, Value};
+    use std::collections::{BTreeMap, HashSet};
+    use std::path::{Path, PathBuf};
+    use std::sync::Mutex;
+
+    const SCHEMA: &str = "
+        CREATE TABLE IF NOT EXISTS subscriptions (
+            position INTEGER NOT NULL,
+            url TEXT PRIMARY KEY
+        );
+        CREATE TABLE IF NOT EXISTS categories (
+            position INTEGER NOT NULL,
+            id TEXT PRIMARY KEY,
+            name TEXT NOT NULL,
+            expanded INTEGER NOT NULL
+        );
+        CREATE TABLE IF NOT EXISTS category_feeds (
+            category_id TEXT NOT NULL,
+            url TEXT NOT NULL,
+            PRIMARY KEY (category_id, url)
+        );
+        CREATE TABLE IF NOT EXISTS read_items (id TEXT PRIMARY KEY);
+        CREATE TABLE IF NOT EXISTS starred_items (id TEXT PRIMARY KEY);
+        CREATE TABLE IF NOT EXISTS archived_feeds (
+            url TEXT PRIMARY KEY,
+            feed TEXT NOT NULL
+        );
+        CREATE TABLE IF NOT EXISTS state_log (
+            id TEXT PRIMARY KEY,
+            read INTEGER NOT NULL,
+            starred INTEGER NOT NULL,
+            version INTEGER NOT NULL,
+            changed_at INTEGER NOT NULL
+        );
+        CREATE TABLE IF NOT EXISTS state (
+            key TEXT PRIMARY KEY,
+            value TEXT NOT NULL
+        );
+    ";
+
+    // Parts of `SavedData` with a table of their own; everything else is a JSON value in
+    // `state`, so new fields are stored without a schema change. The state log's entries
+    // have a table too, with the rest of the log left in `state`
+    const TABLE_FIELDS: [&str; 5] = [
+        "bookmarks",
+        "categories",
+        "read_items",
+        "starred_items",
+        "archived_feeds",
+    ];
+
+    /// State in a SQLite database, `feedr_data.db`. Read and star state, subscriptions and
+    /// categories get tables of their own, so a save only writes the rows that changed
+    /// since the last one and the data can be queried with any SQLite tool.
+    #[derive(Debug)]
+    pub struct SqliteStorage {
+        path: PathBuf,
+        db: Mutex<Database>,
+        // Imported from on the first run, before the database has anything in it
+        json: JsonStorage,
+    }
+
+    #[derive(Debug)]
+    struct Database {
+        /// None when read-only and no database has been created yet
+        conn: Option<Connection>,
+        /// What the database held after the last save, once known
+        saved: Option<Rows>,
+    }
+
+    /// The saved data split the way the tables hold it, for comparing one save with the
+    /// next.
+    #[derive(Debug)]
+    struct Rows {
+        subscriptions: Vec<String>,
+        categories: Vec<FeedCategory>,
+        read_items: HashSet<String>,
+        starred_items: HashSet<String>,
+        /// Feed URL to the feed as JSON
+        archived_feeds: BTreeMap<String, String>,
+        /// The state log's entries, by item
+        #[cfg(feature = "sync")]
+        state_log: BTreeMap<String, StateEntry>,
+        /// The other `SavedData` fields, as JSON
+        state: BTreeMap<String, String>,
+    }
+
+    impl SqliteStorage {
+        pub fn open(json_path: PathBuf) -> Result<Self> {
+            let path = json_path.with_extension("db");
+            let context = || format!("Couldn't open {}", path.display());
+            let conn = if crate::globals::read_only() {
+                // Nothing is written: no directory, no schema, and until a writable run
+                // creates the database, the JSON file is read instead
+                if path.exists() {
+                    Some(
+                        Connection::open_with_flags(&path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+                            .with_context(context)?,
+                    )
+                } else {
+                    None
+                }
+            } else {
+                if let Some(dir) = path.parent() {
+                    std::fs::create_dir_all(dir)?;
+                }
+                let conn = Connection::open(&path).with_context(context)?;
+                conn.execute_batch(SCHEMA)?;
+                Some(conn)
+            };
+            Ok(Self {
+                path,
+                db: Mutex::new(Database { conn, saved: None }),
+                json: JsonStorage::new(json_path),
+            })
+        }
+    }
+
+    impl Rows {
+        fn from_data(data: &SavedData) -> Result<Self> {
+            let Value::Object(mut data) = serde_json::to_value(data)? else {
+                bail!("Saved data isn't a JSON object");
+            };
+            let mut table = |field: &str| data.remove(field).unwrap_or(Value::Null);
+            let subscriptions = serde_json::from_value(table("bookmarks"))?;
+            let categories = serde_json::from_value(table("categories"))?;
+            let read_items = serde_json::from_value(table("read_items"))?;
+            let starred_items = serde_json::from_value(table("starred_items"))?;
+            let archived: Vec<Feed> = serde_json::from_value(table("archived_feeds"))?;
+            debug_assert!(TABLE_FIELDS.iter().all(|field| !data.contains_key(*field)));
+            #[cfg(feature = "sync")]
+            let state_log = split_state_log(&mut data)?;
+            Ok(Self {
+                subscriptions,
+                categories,
+                read_items,
+                starred_items,
+                archived_feeds: archived
+                    .iter()
+                    .map(|feed| serde_json::to_string(feed).map(|json| (feed.url.clone(), json)))
+                    .collect::<serde_json::Result<_>>()?,
+                #[cfg(feature = "sync")]
+                state_log,
+                state: data
+                    .into_iter()
+                    .map(|(key, value)| (key, value.to_string()))
+                    .collect(),
+            })
+        }
+
+        fn into_data(self) -> Result<SavedData> {
+            let mut data = Map::new();
+            for (key, value) in self.state {
+                data.insert(key, serde_json::from_str(&value)?);
+            }
+            #[cfg(feature = "sync")]
+            join_state_log(&mut data, self.state_log)?;
+            data.insert("bookmarks".into(), self.subscriptions.into());
+            data.insert("categories".into(), serde_json::to_value(self.categories)?);
+            data.insert("read_items".into(), serde_json::to_value(self.read_items)?);
+            data.insert(
+                "starred_items".into(),
+                serde_json::to_value(self.starred_items)?,
+            );
+            let archived = self
+                .archived_feeds
+                .values()
+                .map(|feed| serde_json::from_str(feed))
+                .collect::<serde_json::Result<Vec<Value>>>()?;
+            data.insert("archived_feeds".into(), archived.into());
+            Ok(serde_json::from_value(Value::Object(data))?)
+        }
+
+        fn read(conn: &Connection) -> Result<Self> {
+            let column = |sql: &str| -> Result<Vec<String>> {
+                let mut stmt = conn.prepare(sql)?;
+                let values = stmt.query_map([], |row| row.get::<_, String>(0))?;
+                Ok(values.collect::<rusqlite::Result<_>>()?)
+            };
+            let pairs = |sql: &str| -> Result<BTreeMap<String, String>> {
+                let mut stmt = conn.prepare(sql)?;
+                let values = stmt.query_map([], |row| {
+                    Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+                })?;
+                Ok(values.collect::<rusqlite::Result<_>>()?)
+            };
+
+            let mut categories = Vec::new();
+            let mut stmt =
+                conn.prepare("SELECT id, name, expanded FROM categories ORDER BY position")?;
+            let rows = stmt.query_map([], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, bool>(2)?,
+                ))
+            })?;
+            for row in rows {
+                let (id, name, expanded): (String, String, bool) = row?;
+                let mut feeds =
+                    conn.prepare("SELECT url FROM category_feeds WHERE category_id = ?1")?;
+                let feeds = feeds
+                    .query_map([&id], |row| row.get::<_, String>(0))?
+                    .collect::<rusqlite::Result<HashSet<String>>>()?;
+                categories.push(FeedCategory {
+                    id,
+                    name,
+                    feeds,
+                    expanded,
+                });
+            }
+
+            Ok(Self {
+                subscriptions: column("SELECT url FROM subscriptions ORDER BY position")?,
+                categories,
+                read_items: column("SELECT id FROM read_items")?.into_iter().collect(),
+                starred_items: column("SELECT id FROM starred_items")?
+                    .into_iter()
+                    .collect(),
+                archived_feeds: pairs("SELECT url, feed FROM archived_feeds")?,
+                #[cfg(feature = "sync")]
+                state_log: read_state_log(conn)?,
+                state: pairs("SELECT key, value FROM state")?,
+            })
+        }
+
+        /// Change the tables from holding `old` to holding `self`.
+        fn write(&self, tx: &Transaction, old: &Rows) -> Result<()> {
+            if self.subscriptions != old.subscriptions {
+                tx.execute("DELETE FROM subscriptions", [])?;
+                let mut insert = tx.prepare(
+                    "INSERT OR IGNORE INTO subscriptions (position, url) VALUES (?1, ?2)",
+                )?;
+                for (position, url) in self.subscriptions.iter().enumerate() {
+                    insert.execute(params![position as i64, url])?;
+                }
+            }
+            if self.categories != old.categories {
+                tx.execute_batch("DELETE FROM categories; DELETE FROM category_feeds;")?;
+                let mut insert = tx.prepare(
+                    "INSERT INTO categories (position, id, name, expanded) VALUES (?1, ?2, ?3, ?4)",
+                )?;
+                let mut insert_feed =
+                    tx.prepare("INSERT INTO category_feeds (category_id, url) VALUES (?1, ?2)")?;
+                for (position, category) in self.categories.iter().enumerate() {
+                    insert.execute(params![
+                        position as i64,
+                        category.id,
+                        category.name,
+                        category.expanded
+                    ])?;
+                    for url in &category.feeds {
+                        insert_feed.execute(params![category.id, url])?;
+                    }
+                }
+            }
+            write_set(tx, "read_items", &old.read_items, &self.read_items)?;
+            write_set(tx, "starred_items", &old.starred_items, &self.starred_items)?;
+            write_map(
+                tx,
+                "archived_feeds",
+                "url",
+                &old.archived_feeds,
+                &self.archived_feeds,
+            )?;
+            write_map(tx, "state", "key", &old.state, &self.state)?;
+            #[cfg(feature = "sync")]
+            write_state_log(tx, &old.state_log, &self.state_log)?;
+            Ok(())
+        }
+    }
+
+    /// Take the state log's entries out of `data`, leaving the rest of the log for `state`.
+    #[cfg(feature = "sync")]
+    fn split_state_log(data: &mut Map<String, Value>) -> Result<BTreeMap<String, StateEntry>> {
+        let Some(Value::Object(log)) = data.get_mut("state_log") else {
+            return Ok(BTreeMap::new());
+        };
+        Ok(match log.remove("entries") {
+            Some(entries) => serde_json::from_value(entries)?,
+            None => BTreeMap::new(),
+        })
+    }
+
+    /// Put the state log's entries back into `data`.
+    #[cfg(feature = "sync")]
+    fn join_state_log(
+        data: &mut Map<String, Value>,
+        state_log: BTreeMap<String, StateEntry>,
+    ) -> Result<()> {
+        let Some(Value::Object(log)) = data.get_mut("state_log") else {
+            return Ok(());
+        };
+        // Databases written before the log had a table keep its entries in `state`
+        if let Value::Object(entries) = log.entry("entries").or_insert(Map::new().into()) {
+            for (id, entry) in state_log {
+                entries.insert(id, serde_json::to_value(entry)?);
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "sync")]
+    fn read_state_log(conn: &Connection) -> Result<BTreeMap<String, StateEntry>> {
+        // Read-only runs don't add the table to databases from before it existed
+        let tables: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND name = 'state_log'",
+            [],
+            |row| row.get(0),
+        )?;
+        if tables == 0 {
+            return Ok(BTreeMap::new());
+        }
+        let mut stmt =
+            conn.prepare("SELECT id, read, starred, version, changed_at FROM state_log")?;
+        let entries = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                StateEntry {
+                    read: row.get(1)?,
+                    starred: row.get(2)?,
+                    version: row.get(3)?,
+                    changed_at: row.get(4)?,
+                },
+            ))
+        })?;
+        Ok(entries.collect::<rusqlite::Result<_>>()?)
+    }
+
+    /// Bring the `state_log` table from `old` to `new`, like `write_map`.
+    #[cfg(feature = "sync")]
+    fn write_state_log(
+        tx: &Transaction,
+        old: &BTreeMap<String, StateEntry>,
+        new: &BTreeMap<String, StateEntry>,
+    ) -> Result<()> {
+        let mut delete = tx.prepare("DELETE FROM state_log WHERE id = ?1")?;
+        for gone in old.keys().filter(|id| !new.contains_key(*id)) {
+            delete.execute([gone])?;
+        }
+        let mut insert = tx.prepare(
+            "INSERT OR REPLACE INTO state_log (id, read, starred, version, changed_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+        )?;
+        for (id, entry) in new {
+            if old.get(id) != Some(entry) {
+                insert.execute(params![
+                    id,
+                    entry.read,
+                    entry.starred,
+                    entry.version,
+                    entry.changed_at
+                ])?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Delete the ids `old` has and `new` doesn't from `table`, and insert the new ones.
+    fn write_set(
+        tx: &Transaction,
+        table: &str,
+        old: &HashSet<String>,
+        new: &HashSet<String>,
+    ) -> Result<()> {
+        let mut delete = tx.prepare(&format!("DELETE FROM {} WHERE id = ?1", table))?;
+        for id in old.difference(new) {
+            delete.execute([id])?;
+        }
+        let mut insert =
+            tx.prepare(&format!("INSERT OR IGNORE INTO {} (id) VALUES (?1)", table))?;
+        for id in new.difference(old) {
+            insert.execute([id])?;
+        }
+        Ok(())
+    }
+
+    /// Bring a two-column `table` keyed by `key` from `old` to `new`, touching only the
+    /// keys whose value changed.
+    fn write_map(
+        tx: &Transaction,
+        table: &str,
+        key: &str,
+        old: &BTreeMap<String, String>,
+        new: &BTreeMap<String, String>,
+    ) -> Result<()> {
+        let mut delete = tx.prepare(&format!("DELETE FROM {} WHERE {} = ?1", table, key))?;
+        for gone in old.keys().filter(|k| !new.contains_key(*k)) {
+            delete.execute([gone])?;
+        }
+        let mut insert =
+            tx.prepare(&format!("INSERT OR REPLACE INTO {} VALUES (?1, ?2)", table))?;
+        for (k, value) in new {
+            if old.get(k) != Some(value) {
+                insert.execute(params![k, value])?;
+            }
+        }
+        Ok(())
+    }
+
+    impl Storage for SqliteStorage {
+        fn load(&self) -> Result<Option<SavedData>> {
+            let db = self.db.lock().unwrap_or_else(|e| e.into_inner());
+            let Some(conn) = &db.conn else {
+                return self.json.load();
+            };
+            let rows = Rows::read(conn)?;
+            // A save always writes some state, so an empty table means a new database
+            if rows.state.is_empty() {
+                return self.json.load();
+            }
+            Ok(Some(rows.into_data()?))
+        }
+
+        fn save(&self, data: &SavedData) -> Result<()> {
+            let rows = Rows::from_data(data)?;
+            let mut db = self.db.lock().unwrap_or_else(|e| e.into_inner());
+            let Database { conn, saved } = &mut *db;
+            let Some(conn) = conn else {
+                bail!("{} was opened read-only", self.path.display());
+            };
+            let old = match saved.take() {
+                Some(old) => old,
+                None => Rows::read(conn)?,
+            };
+            let tx = conn.transaction()?;
+            rows.write(&tx, &old)?;
+            tx.commit()?;
+            *saved = Some(rows);
+            Ok(())
+        }
+
+        fn path(&self) -> &Path {
+            &self.path
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn sample() -> SavedData {
+        serde_json::from_value(json!({
+            "bookmarks": ["https://b.example/feed", "https://a.example/feed"],
+            "categories": [{
+                "id": "c1",
+                "name": "News",
+                "feeds": ["https://a.example/feed"],
+                "expanded": true
+            }],
+            "read_items": ["https://a.example/1"],
+            "starred_items": ["https://a.example/2"],
+            "muted_feeds": ["https://b.example/feed"],
+            "state_log": {
+                "version": 1,
+                "entries": {
+                    "https://a.example/1": {
+                        "read": true,
+                        "starred": false,
+                        "version": 1,
+                        "changed_at": 100
+                    }
+                }
+            },
+        }))
+        .unwrap()
+    }
+
+    fn temp_dir() -> PathBuf {
+        std::env::temp_dir().join(format!("feedr-storage-{}", uuid::Uuid::new_v4()))
+    }
+
+    fn as_json(data: &SavedData) -> Value {
+        serde_json::to_value(data).unwrap()
+    }
+
+    #[test]
+    fn test_json_storage_round_trip() {
+        let dir = temp_dir();
+        let storage = open(StorageBackend::Json, dir.join("feedr_data.json")).unwrap();
+        assert!(storage.load().unwrap().is_none());
+
+        storage.save(&sample()).unwrap();
+        assert_eq!(
+            as_json(&storage.load().unwrap().unwrap()),
+            as_json(&sample())
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn test_sqlite_storage_imports_json_then_round_trips() {
+        let dir = temp_dir();
+        let json_path = dir.join("feedr_data.json");
+        JsonStorage::new(json_path.clone()).save(&sample()).unwrap();
+
+        let storage = open(StorageBackend::Sqlite, json_path).unwrap();
+        assert_eq!(storage.path(), dir.join("feedr_data.db"));
+        // Nothing in the database yet, so the JSON file is read
+        assert_eq!(
+            as_json(&storage.load().unwrap().unwrap()),
+            as_json(&sample())
+        );
+
+        let mut changed = as_json(&sample());
+        changed["read_items"] = json!(["https://a.example/1", "https://b.example/3"]);
+        changed["bookmarks"] = json!(["https://a.example/feed"]);
+        let changed: SavedData = serde_json::from_value(changed).unwrap();
+        storage.save(&changed).unwrap();
+
+        let loaded = as_json(&storage.load().unwrap().unwrap());
+        let mut read: Vec<String> = serde_json::from_value(loaded["read_items"].clone()).unwrap();
+        read.sort();
+        assert_eq!(read, ["https://a.example/1", "https://b.example/3"]);
+        assert_eq!(loaded["bookmarks"], json!(["https://a.example/feed"]));
+        assert_eq!(loaded["categories"], as_json(&sample())["categories"]);
+        assert_eq!(loaded["muted_feeds"], json!(["https://b.example/feed"]));
+        assert_eq!(loaded["state_log"], as_json(&sample())["state_log"]);
+        #[cfg(feature = "sync")]
+        {
+            let db = rusqlite::Connection::open(dir.join("feedr_data.db")).unwrap();
+            let logged: i64 = db
+                .query_row("SELECT COUNT(*) FROM state_log", [], |row| row.get(0))
+                .unwrap();
+            assert_eq!(logged, 1);
+        }
+
+        // A later save only writes what changed, including removals
+        let mut changed = loaded;
+        changed["read_items"] = json!(["https://b.example/3"]);
+        changed["muted_feeds"] = json!([]);
+        storage
+            .save(&serde_json::from_value(changed).unwrap())
+            .unwrap();
+        let reopened = open(StorageBackend::Sqlite, dir.join("feedr_data.json")).unwrap();
+        let loaded = as_json(&reopened.load().unwrap().unwrap());
+        assert_eq!(loaded["read_items"], json!(["https://b.example/3"]));
+        assert_eq!(loaded["starred_items"], json!(["https://a.example/2"]));
+        assert_eq!(loaded["muted_feeds"], json!([]));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
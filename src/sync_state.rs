@@ -0,0 +1,166 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+
+/// One item's read and starred state, with the version it was last changed at.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateEntry {
+    pub read: bool,
+    pub starred: bool,
+    /// Local change counter value; sync tokens are compared against it
+    pub version: u64,
+    /// Unix time of the change, used to settle conflicts with a remote copy
+    pub changed_at: i64,
+}
+
+/// Versioned read/star state, so a sync backend can ask for the changes made since
+/// the last token it saw instead of replaying the whole state every time.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct StateLog {
+    /// The latest version handed out, which is also the current sync token
+    #[serde(default)]
+    version: u64,
+    #[serde(default)]
+    entries: HashMap<String, StateEntry>,
+    /// The token each sync backend has caught up to, by backend name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    synced: BTreeMap<String, u64>,
+}
+
+impl StateLog {
+    /// A token covering every change recorded so far.
+    pub fn token(&self) -> u64 {
+        self.version
+    }
+
+    pub fn get(&self, id: &str) -> Option<&StateEntry> {
+        self.entries.get(id)
+    }
+
+    /// Record an item's state as of `now`, giving it a new version if it changed.
+    pub fn record(&mut self, id: &str, read: bool, starred: bool, now: i64) {
+        if self
+            .entries
+            .get(id)
+            .is_some_and(|e| e.read == read && e.starred == starred)
+        {
+            return;
+        }
+        self.version += 1;
+        self.entries.insert(
+            id.to_string(),
+            StateEntry {
+                read,
+                starred,
+                version: self.version,
+                changed_at: now,
+            },
+        );
+    }
+
+    /// Note that `backend` has seen every change up to `token`.
+    pub fn acknowledge(&mut self, backend: &str, token: u64) {
+        self.synced.insert(backend.to_string(), token);
+    }
+
+    /// Drop what no backend still needs: entries every backend has seen, and entries
+    /// for items `exists` no longer knows about. With no backends syncing, entries are
+    /// only dropped for missing items.
+    pub fn compact(&mut self, exists: impl Fn(&str) -> bool) {
+        let lowest = self.synced.values().min().copied();
+        self.entries
+            .retain(|id, entry| exists(id) && lowest.map_or(true, |token| entry.version > token));
+    }
+
+    /// Entries changed after `token`, oldest change first.
+    pub fn changes_since(&self, token: u64) -> Vec<(&str, &StateEntry)> {
+        let mut changes: Vec<(&str, &StateEntry)> = self
+            .entries
+            .iter()
+            .filter(|(_, entry)| entry.version > token)
+            .map(|(id, entry)| (id.as_str(), entry))
+            .collect();
+        changes.sort_by_key(|(_, entry)| entry.version);
+        changes
+    }
+
+    /// Take a change made elsewhere at `changed_at` unless the local state changed
+    /// later. Returns whether it was taken; taken changes get a local version too, so
+    /// other backends hear about them.
+    pub fn merge_remote(&mut self, id: &str, read: bool, starred: bool, changed_at: i64) -> bool {
+        if self
+            .entries
+            .get(id)
+            .is_some_and(|e| e.changed_at > changed_at)
+        {
+            return false;
+        }
+        self.record(id, read, starred, changed_at);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_changes_since_token() {
+        let mut log = StateLog::default();
+        log.record("a", true, false, 100);
+        log.record("b", false, true, 101);
+        let token = log.token();
+        assert_eq!(token, 2);
+
+        // Recording the same state again isn't a change
+        log.record("a", true, false, 102);
+        assert!(log.changes_since(token).is_empty());
+
+        log.record("a", false, false, 103);
+        let changes = log.changes_since(token);
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].0, "a");
+        assert!(!changes[0].1.read);
+        assert_eq!(
+            log.changes_since(0)
+                .iter()
+                .map(|(id, _)| *id)
+                .collect::<Vec<_>>(),
+            vec!["b", "a"]
+        );
+    }
+
+    #[test]
+    fn test_compact_keeps_changes_a_backend_hasnt_seen() {
+        let mut log = StateLog::default();
+        log.record("a", true, false, 100);
+        log.record("b", true, false, 101);
+        log.record("gone", true, false, 102);
+        log.compact(|id| id != "gone");
+        assert!(log.get("gone").is_none());
+        assert!(log.get("a").is_some());
+
+        log.acknowledge("miniflux", 1);
+        log.acknowledge("feedbin", 2);
+        log.compact(|_| true);
+        // Only feedbin has seen b
+        assert!(log.get("a").is_none());
+        assert!(log.get("b").is_some());
+
+        log.acknowledge("miniflux", log.token());
+        log.compact(|_| true);
+        assert!(log.get("b").is_none());
+        assert_eq!(log.token(), 3);
+    }
+
+    #[test]
+    fn test_merge_remote_keeps_newer_local_changes() {
+        let mut log = StateLog::default();
+        log.record("a", true, false, 200);
+        assert!(!log.merge_remote("a", false, false, 150));
+        assert!(log.get("a").unwrap().read);
+
+        assert!(log.merge_remote("a", true, true, 250));
+        assert!(log.get("a").unwrap().starred);
+        assert_eq!(log.get("a").unwrap().version, log.token());
+    }
+}
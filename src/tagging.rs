@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+/// Most tags suggested for one item.
+pub const MAX_SUGGESTED_TAGS: usize = 3;
+
+// Common English words that never make useful tags
+const STOPWORDS: &[&str] = &[
+    "about", "above", "after", "again", "against", "all", "also", "and", "any", "are", "because",
+    "been", "before", "being", "below", "between", "both", "but", "can", "could", "did", "does",
+    "doing", "down", "during", "each", "even", "every", "few", "first", "for", "from", "get",
+    "gets", "had", "has", "have", "having", "her", "here", "hers", "him", "his", "how", "into",
+    "its", "just", "last", "like", "made", "make", "many", "more", "most", "much", "must", "new",
+    "next", "not", "now", "off", "once", "one", "only", "other", "our", "out", "over", "own",
+    "read", "said", "same", "says", "she", "should", "since", "some", "still", "such", "than",
+    "that", "the", "their", "them", "then", "there", "these", "they", "this", "those", "through",
+    "too", "two", "under", "until", "use", "used", "using", "very", "via", "was", "way", "were",
+    "what", "when", "where", "which", "while", "who", "why", "will", "with", "would", "year",
+    "years", "yet", "you", "your",
+];
+
+// Lowercased words of three or more letters, minus stopwords and plain numbers
+fn words(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| word.chars().count() >= 3 && !word.chars().all(|c| c.is_ascii_digit()))
+        .map(str::to_lowercase)
+        .filter(|word| !STOPWORDS.contains(&word.as_str()))
+}
+
+/// Suggest up to `max_tags` tags for each `(title, text)` document: the words that
+/// set it apart from the others by TF-IDF, best first. Title words count double. A
+/// word has to appear in at least two documents, so tags are shared between items,
+/// and words in more than half of them are too common to say anything.
+pub fn suggest_tags(docs: &[(&str, &str)], max_tags: usize) -> Vec<Vec<String>> {
+    let counts: Vec<HashMap<String, usize>> = docs
+        .iter()
+        .map(|(title, text)| {
+            let mut counts = HashMap::new();
+            for word in words(title) {
+                *counts.entry(word).or_default() += 2;
+            }
+            for word in words(text) {
+                *counts.entry(word).or_default() += 1;
+            }
+            counts
+        })
+        .collect();
+
+    let mut doc_freq: HashMap<&str, usize> = HashMap::new();
+    for doc in &counts {
+        for word in doc.keys() {
+            *doc_freq.entry(word.as_str()).or_default() += 1;
+        }
+    }
+
+    let total_docs = docs.len() as f64;
+    counts
+        .iter()
+        .map(|doc| {
+            let length: usize = doc.values().sum();
+            let mut scored: Vec<(f64, &str)> = doc
+                .iter()
+                .filter_map(|(word, &count)| {
+                    let df = doc_freq[word.as_str()];
+                    (df >= 2 && df * 2 <= docs.len()).then(|| {
+                        let tf = count as f64 / length as f64;
+                        (tf * (total_docs / df as f64).ln(), word.as_str())
+                    })
+                })
+                .collect();
+            scored.sort_by(|a, b| b.0.total_cmp(&a.0).then(a.1.cmp(b.1)));
+            scored
+                .into_iter()
+                .take(max_tags)
+                .map(|(_, word)| word.to_string())
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suggest_tags_prefers_distinctive_shared_words() {
+        let docs = [
+            ("Rust 1.80 released", "The Rust compiler gets faster builds"),
+            ("Async Rust in practice", "Writing async code with tokio"),
+            ("Gardening tips", "Tomatoes need sun and water"),
+            ("Tomatoes in winter", "Growing tomatoes indoors with lamps"),
+            ("Election results", "Votes were counted overnight"),
+        ];
+        let tags = suggest_tags(&docs, MAX_SUGGESTED_TAGS);
+        assert_eq!(tags[0], vec!["rust"]);
+        assert_eq!(tags[1], vec!["rust"]);
+        assert_eq!(tags[2], vec!["tomatoes"]);
+        assert_eq!(tags[3], vec!["tomatoes"]);
+        // Nothing it shares with another item
+        assert!(tags[4].is_empty());
+    }
+
+    #[test]
+    fn test_suggest_tags_skips_stopwords_and_numbers() {
+        let docs = [
+            ("The 2024 report", "about the report"),
+            ("The 2024 summary", "about the summary"),
+            ("Other news", "nothing else"),
+            ("More news", "something else"),
+        ];
+        let tags = suggest_tags(&docs, MAX_SUGGESTED_TAGS);
+        assert!(tags[0].is_empty());
+        assert_eq!(tags[2], vec!["news", "else"]);
+    }
+}
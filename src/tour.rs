@@ -0,0 +1,118 @@
+//! The guided tour shown on first launch: a few steps, each pointing at a part of the
+//! screen and the keys that work there. It can be replayed with the `tour` key.
+
+use crate::keybindings::{key_display, KeyAction, KeyBindingMap};
+use std::str::FromStr;
+
+/// The part of the screen a step points at.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TourRegion {
+    /// Nothing in particular; the step sits in the middle of the screen
+    Screen,
+    /// The title bar with the view tabs
+    TitleBar,
+    /// The current view's list or article
+    Content,
+    /// The key hints at the bottom
+    HelpBar,
+}
+
+/// One step of the tour. `text` names keys as `{action}`, by their config names, so it
+/// shows the user's own bindings.
+#[derive(Debug)]
+pub struct TourStep {
+    pub region: TourRegion,
+    pub title: &'static str,
+    pub text: &'static str,
+}
+
+pub const STEPS: &[TourStep] = &[
+    TourStep {
+        region: TourRegion::Screen,
+        title: "Welcome to feedr",
+        text: "This short tour points out where things are. Any key goes on, {history_back} \
+               goes back a step and Esc leaves the tour. Replay it any time with {tour}.",
+    },
+    TourStep {
+        region: TourRegion::TitleBar,
+        title: "Views",
+        text: "The tabs up here are feedr's views: the dashboard, your feeds, starred items and \
+               more. {next_tab} moves to the next one and {home} comes back to the dashboard.",
+    },
+    TourStep {
+        region: TourRegion::Content,
+        title: "The dashboard",
+        text: "New items from every feed, newest first. Move with {move_down} and {move_up}, \
+               open one with {select}, mark it read with {toggle_read} and star it with \
+               {toggle_star}.",
+    },
+    TourStep {
+        region: TourRegion::Content,
+        title: "Adding feeds",
+        text: "Press {add_feed} and paste a feed or website URL; feedr finds the feed a page \
+               links to. Subscriptions from another reader come over with \
+               `feedr import subscriptions.opml`.",
+    },
+    TourStep {
+        region: TourRegion::Content,
+        title: "Filters and search",
+        text: "{open_filter} narrows the list by category, age, read state and more. \
+               {open_search} searches the titles and text of every item.",
+    },
+    TourStep {
+        region: TourRegion::Content,
+        title: "Categories",
+        text: "Group feeds into categories with {open_category_management}. On the dashboard, \
+               {cycle_category} shows one category at a time; in the feed list it files the \
+               selected feed.",
+    },
+    TourStep {
+        region: TourRegion::Content,
+        title: "Everything about an item",
+        text: "{context_menu} lists every action for the selected item, so there's no need to \
+               remember their keys, and {explain_item} tells why an item is listed.",
+    },
+    TourStep {
+        region: TourRegion::HelpBar,
+        title: "Key hints",
+        text: "The bar down here shows the main keys of the view you're in, and {help} lists \
+               them all. Happy reading!",
+    },
+];
+
+/// `step`'s text with each `{action}` replaced by the first key bound to it.
+pub fn step_text(step: &TourStep, keybindings: &KeyBindingMap) -> String {
+    let mut text = String::new();
+    let mut rest = step.text;
+    while let Some(start) = rest.find('{') {
+        let Some(end) = rest[start..].find('}').map(|end| start + end) else {
+            break;
+        };
+        text.push_str(&rest[..start]);
+        match KeyAction::from_str(&rest[start + 1..end]) {
+            Ok(action) => text.push_str(&key_display(&action, keybindings)),
+            Err(()) => text.push_str(&rest[start..=end]),
+        }
+        rest = &rest[end + 1..];
+    }
+    text.push_str(rest);
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::keybindings::default_keybindings;
+
+    #[test]
+    fn test_steps_name_known_actions() {
+        let keybindings = default_keybindings();
+        for step in STEPS {
+            let text = step_text(step, &keybindings);
+            assert!(!text.contains('{'), "{}: {}", step.title, text);
+        }
+        let welcome = step_text(&STEPS[0], &keybindings);
+        assert!(welcome.contains("Replay it any time with F1."));
+        assert!(step_text(&STEPS[2], &keybindings).contains("mark it read with Space"));
+    }
+}
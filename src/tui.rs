@@ -1,10 +1,12 @@
 use crate::app::{App, View};
 use crate::events::handle_events;
-use crate::feed::Feed;
+use crate::feed::{Feed, FetchOutcome};
 use crate::ui;
 use anyhow::Result;
 use crossterm::{
-    event::{self, DisableMouseCapture, EnableMouseCapture},
+    event::{
+        self, DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+    },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
@@ -12,26 +14,41 @@ use ratatui::{
     backend::{Backend, CrosstermBackend},
     Terminal,
 };
-use std::sync::mpsc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
 use std::{io, time::Duration};
 
 pub fn run(mut app: App) -> Result<()> {
     // Set up terminal
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
+    app.apply_auto_theme();
+    // Show new users around before anything else
+    if app.first_launch {
+        app.start_tour();
+    }
 
     // Run the main application loop
     let result = run_app(&mut terminal, &mut app);
 
+    // Make sure pending changes and session state reach disk before exiting
+    let flush_result = app.shutdown();
+
     // Clean up terminal
     disable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
-        DisableMouseCapture
+        DisableMouseCapture,
+        DisableBracketedPaste
     )?;
     terminal.show_cursor()?;
 
@@ -39,39 +56,67 @@ pub fn run(mut app: App) -> Result<()> {
     if let Err(err) = result {
         println!("Error: {:?}", err);
     }
+    if let Err(err) = flush_result {
+        println!("Failed to save data: {:?}", err);
+    }
 
     Ok(())
 }
 
-/// Spawn background threads to fetch all bookmarked feeds, sending results through the channel.
-/// Returns the sender's pending count and the receiver.
-fn spawn_feed_refresh(app: &mut App) -> (usize, mpsc::Receiver<(usize, Result<Feed>)>) {
-    let (feed_tx, feed_rx) = mpsc::channel::<(usize, Result<Feed>)>();
+/// How fetching one bookmark went: (bookmark index, url, outcome).
+type FetchMessage = (usize, String, FetchOutcome);
+
+/// Spawn background threads to fetch all bookmarked feeds (or just `only`), sending results
+/// through the channel. Returns the sender's pending count and the receiver. Threads drop
+/// their result once `cancel` is set. At `startup`, recently cached feeds aren't re-requested.
+fn spawn_feed_refresh(
+    app: &mut App,
+    cancel: &Arc<AtomicBool>,
+    only: Option<&[String]>,
+    startup: bool,
+) -> (usize, mpsc::Receiver<FetchMessage>) {
+    let (feed_tx, feed_rx) = mpsc::channel::<FetchMessage>();
     let mut pending_count: usize = 0;
+    app.reset_feed_failures(only);
 
     if !app.bookmarks.is_empty() {
-        let timeout = app.config.network.http_timeout;
-        let user_agent = app.config.network.user_agent.clone();
-        let all_headers = app.feed_headers.clone();
-
-        if let Ok(client) = Feed::build_client(timeout) {
-            pending_count = app.bookmarks.len();
-            app.is_loading = true;
-            app.refresh_in_progress = true;
+        if let Ok(client) = app.http_client() {
             for (idx, url) in app.bookmarks.iter().enumerate() {
+                if only.is_some_and(|only| !only.contains(url)) {
+                    continue;
+                }
+                pending_count += 1;
                 let client = client.clone();
                 let url = url.clone();
-                let ua = user_agent.clone();
+                let mut options = app.request_options(&url);
+                if startup && app.is_metered() {
+                    // Any cached copy will do; `r` still asks the server
+                    options.max_cache_age = i64::MAX as u64;
+                } else if startup {
+                    options.max_cache_age = app.config.network.cache_max_age;
+                }
                 let tx = feed_tx.clone();
-                let hdrs = all_headers.get(&url).cloned();
+                let cancel = Arc::clone(cancel);
                 std::thread::spawn(move || {
-                    let result = Feed::fetch_url(&url, &client, Some(&ua), hdrs.as_ref())
-                        .and_then(|r| r.into_feed());
-                    let _ = tx.send((idx, result));
+                    if cancel.load(Ordering::Relaxed) {
+                        return;
+                    }
+                    let outcome = crate::demo::outcome(&url)
+                        .unwrap_or_else(|| Feed::fetch(&url, &client, &options));
+                    if !cancel.load(Ordering::Relaxed) {
+                        let _ = tx.send((idx, url, outcome));
+                    }
                 });
             }
         }
     }
+    if pending_count > 0 {
+        app.is_loading = true;
+        app.refresh_in_progress = true;
+        app.refresh_started = Some(std::time::Instant::now());
+        // Feeds not loaded yet show as placeholder rows until they arrive
+        app.rebuild_feed_tree();
+    }
 
     (pending_count, feed_rx)
 }
@@ -81,12 +126,24 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Result<()>
     let tick_rate = Duration::from_millis(app.config.ui.tick_rate);
     let error_timeout = Duration::from_millis(app.config.ui.error_display_timeout);
 
+    // Set by SIGTERM/SIGHUP or on quit; also tells in-flight fetches to drop their results
+    let shutdown = Arc::new(AtomicBool::new(false));
+    signal_hook::flag::register(signal_hook::consts::SIGTERM, Arc::clone(&shutdown))?;
+    #[cfg(unix)]
+    signal_hook::flag::register(signal_hook::consts::SIGHUP, Arc::clone(&shutdown))?;
+
     // Initial load of bookmarked feeds
-    let (mut pending_count, mut feed_rx) = spawn_feed_refresh(app);
+    let (mut pending_count, mut feed_rx) = spawn_feed_refresh(app, &shutdown, None, true);
+    // The feeds being re-fetched on their own, if the running refresh isn't a full one
+    let mut partial_refresh: Option<Vec<String>> = None;
 
     loop {
+        if shutdown.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+
         terminal.draw(|f| {
-            app.update_compact_mode(f.size().height);
+            app.update_compact_mode(f.size().width, f.size().height);
             ui::render(f, app);
         })?;
 
@@ -94,55 +151,112 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Result<()>
         if app.refresh_requested {
             app.refresh_requested = false;
             if !app.refresh_in_progress {
-                app.feeds.clear();
+                app.pin_selection();
+                app.begin_refresh_diff();
+                app.clear_subscribed_feeds();
                 app.update_dashboard();
                 app.rebuild_feed_tree();
-                let (count, rx) = spawn_feed_refresh(app);
+                let (count, rx) = spawn_feed_refresh(app, &shutdown, None, false);
+                pending_count = count;
+                feed_rx = rx;
+                partial_refresh = None;
+            }
+        } else if let Some(url) = app.refresh_feed_requested.take() {
+            if !app.refresh_in_progress {
+                let urls = vec![url];
+                let (count, rx) = spawn_feed_refresh(app, &shutdown, Some(&urls), false);
+                pending_count = count;
+                feed_rx = rx;
+                partial_refresh = Some(urls);
+            }
+        } else if let Some(urls) = app.scheduled_refresh.take() {
+            if !app.refresh_in_progress {
+                let (count, rx) = spawn_feed_refresh(app, &shutdown, Some(&urls), false);
+                pending_count = count;
+                feed_rx = rx;
+                partial_refresh = Some(urls);
+            }
+        } else if app.retry_failed_requested {
+            app.retry_failed_requested = false;
+            if !app.refresh_in_progress {
+                let urls = app.failed_feed_urls();
+                let (count, rx) = spawn_feed_refresh(app, &shutdown, Some(&urls), false);
                 pending_count = count;
                 feed_rx = rx;
+                partial_refresh = Some(urls);
             }
         }
 
         // Drain any feeds that arrived from background threads
         if pending_count > 0 {
-            while let Ok((idx, result)) = feed_rx.try_recv() {
-                if let Ok(feed) = result {
-                    // Insert at the correct position to maintain bookmark order,
-                    // or append if earlier feeds haven't arrived yet
-                    let insert_pos = app
-                        .feeds
-                        .iter()
-                        .position(|f| {
-                            app.bookmarks
+            while let Ok((idx, url, outcome)) = feed_rx.try_recv() {
+                app.record_fetch(&url, &outcome);
+                // Not modified: nothing new, whatever copy is loaded stays
+                let result = match outcome {
+                    FetchOutcome::NotModified { .. } => None,
+                    outcome => Some(outcome.into_feed(&url)),
+                };
+                match result {
+                    Some(Ok(feed)) => {
+                        if let Some(pos) = app.feeds.iter().position(|f| f.url == feed.url) {
+                            // A single-feed refresh replaces the copy that is already loaded
+                            app.replace_feed(pos, feed);
+                        } else {
+                            // Insert at the correct position to maintain bookmark order,
+                            // or append if earlier feeds haven't arrived yet
+                            let insert_pos = app
+                                .feeds
                                 .iter()
-                                .position(|b| b == &f.url)
-                                .unwrap_or(usize::MAX)
-                                > idx
-                        })
-                        .unwrap_or(app.feeds.len());
-                    app.feeds.insert(insert_pos, feed);
-                    app.update_dashboard();
-                    app.rebuild_feed_tree();
+                                .position(|f| {
+                                    app.bookmarks
+                                        .iter()
+                                        .position(|b| b == &f.url)
+                                        .unwrap_or(usize::MAX)
+                                        > idx
+                                })
+                                .unwrap_or(app.feeds.len());
+                            app.insert_feed(insert_pos, feed);
+                        }
+                        app.rebuild_feed_tree();
+                    }
+                    Some(Err(e)) => {
+                        app.record_feed_failure(&url, &e);
+                        // Drop the feed's placeholder row
+                        app.rebuild_feed_tree();
+                    }
+                    None => {}
                 }
                 pending_count -= 1;
                 if pending_count == 0 {
                     app.is_loading = false;
                     app.refresh_in_progress = false;
-                    let now = std::time::Instant::now();
-                    app.last_refresh = Some(now);
-                    for url in &app.bookmarks {
-                        app.last_feed_refresh.insert(url.clone(), now);
-                    }
-                    app.update_dashboard();
                     app.rebuild_feed_tree();
-                    // Show summary view if there are new items since last session
-                    if app.show_summary {
-                        app.show_summary = false;
-                        let (total, _) = app.get_summary_stats();
-                        if total > 0 {
-                            app.view = View::Summary;
+                    let now = std::time::Instant::now();
+                    if let Some(urls) = partial_refresh.take() {
+                        for url in urls {
+                            app.last_feed_refresh.insert(url, now);
+                        }
+                    } else {
+                        app.last_refresh = Some(now);
+                        for url in &app.bookmarks {
+                            app.last_feed_refresh.insert(url.clone(), now);
                         }
+                        // Show summary view if there are new items since last session
+                        if app.show_summary {
+                            app.show_summary = false;
+                            let (total, _) = app.get_summary_stats();
+                            if total > 0 {
+                                app.view = View::Summary;
+                            }
+                        }
+                    }
+                    app.check_alerts();
+                    if let Err(e) = app.write_metrics() {
+                        app.error = Some(format!("Failed to write metrics: {}", e));
                     }
+                    app.update_suggested_tags();
+                    app.finish_refresh_diff();
+                    app.unpin_selection();
                     // Save current time as session time now that feeds are loaded
                     let _ = app.save_data();
                 }
@@ -163,6 +277,7 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Result<()>
         if event::poll(timeout)? {
             // Handle user input
             if handle_events(app)? {
+                shutdown.store(true, Ordering::Relaxed);
                 return Ok(());
             }
         } else if last_tick.elapsed() >= tick_rate {
@@ -185,9 +300,19 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> Result<()>
                 }
             }
 
+            // Write out read/star changes once they've settled
+            app.save_if_due();
+
+            #[cfg(feature = "scripting")]
+            app.update_status_strip();
+            app.track_reading_time();
+            app.apply_auto_theme();
+            #[cfg(feature = "sync")]
+            app.update_collections();
+
             // Check if auto-refresh should trigger
             if app.should_auto_refresh() {
-                app.refresh_requested = true;
+                app.request_scheduled_refresh();
             }
 
             last_tick = std::time::Instant::now();
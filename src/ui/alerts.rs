@@ -0,0 +1,158 @@
+use crate::app::App;
+use crate::ui::utils::{feed_badge, item_meta, read_marker, ALERT_COLOR};
+use crate::ui::ColorScheme;
+use ratatui::{
+    backend::Backend,
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, List, ListItem, ListState, Padding, Paragraph},
+    Frame,
+};
+
+pub(super) fn render_alerts<B: Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    area: Rect,
+    colors: &ColorScheme,
+) {
+    let alert_items = app.get_alert_dashboard_items();
+    let title = format!(" \u{2691} Alerts ({}) ", alert_items.len());
+
+    if alert_items.is_empty() {
+        let hint = if app.alerts.is_empty() {
+            "Add [[alerts]] rules to your config to watch for keywords"
+        } else {
+            "New articles matching your alert rules will show up here"
+        };
+
+        let mut text = Text::default();
+        text.lines.push(Line::from(""));
+        text.lines.push(Line::from(Span::styled(
+            "       \u{2691}       ",
+            Style::default().fg(ALERT_COLOR),
+        )));
+        text.lines.push(Line::from(""));
+        text.lines.push(Line::from(Span::styled(
+            "No alerts",
+            Style::default()
+                .fg(colors.text)
+                .add_modifier(Modifier::BOLD),
+        )));
+        text.lines.push(Line::from(""));
+        text.lines.push(Line::from(Span::styled(
+            hint,
+            Style::default().fg(colors.highlight),
+        )));
+
+        let paragraph = Paragraph::new(text).alignment(Alignment::Center).block(
+            Block::default()
+                .title(title)
+                .title_alignment(Alignment::Center)
+                .borders(Borders::ALL)
+                .border_type(colors.border_normal)
+                .border_style(Style::default().fg(colors.border))
+                .style(Style::default().bg(colors.surface))
+                .padding(Padding::new(2, 2, 2, 2)),
+        );
+
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let arrow = colors.get_arrow_right();
+    let read_indicator = app.config.ui.read_indicator;
+    let items: Vec<ListItem> = alert_items
+        .iter()
+        .enumerate()
+        .map(|(idx, &(feed_idx, item_idx))| {
+            let feed = &app.feeds[feed_idx];
+            let item = &feed.items[item_idx];
+            let meta = item_meta(item, &app.config.ui, app.narrow).unwrap_or_default();
+            let is_selected = app.selected_item == Some(idx);
+            let is_read = app.is_item_read(feed_idx, item_idx);
+            let alert_name = app.item_alert(feed_idx, item_idx).unwrap_or_default();
+
+            ListItem::new(vec![
+                Line::from(vec![
+                    Span::styled(
+                        if is_selected {
+                            format!("{} ", arrow)
+                        } else {
+                            "  ".to_string()
+                        },
+                        Style::default().fg(colors.highlight),
+                    ),
+                    feed_badge(&feed.title, &feed.url, &app.config.ui),
+                    Span::raw(" "),
+                    Span::styled(
+                        feed.title.to_string(),
+                        Style::default()
+                            .fg(if is_selected {
+                                colors.secondary
+                            } else {
+                                colors.text_secondary
+                            })
+                            .add_modifier(Modifier::BOLD),
+                    ),
+                    Span::styled(
+                        format!(" \u{2691} {}", alert_name),
+                        Style::default().fg(ALERT_COLOR),
+                    ),
+                ]),
+                Line::from(vec![
+                    Span::styled("  ", Style::default()),
+                    read_marker(read_indicator, is_read, colors),
+                    Span::styled(
+                        &item.title,
+                        Style::default()
+                            .fg(if is_read {
+                                colors.text_secondary
+                            } else {
+                                ALERT_COLOR
+                            })
+                            .add_modifier(if is_selected {
+                                Modifier::BOLD
+                            } else {
+                                Modifier::empty()
+                            }),
+                    ),
+                ]),
+                Line::from(vec![
+                    Span::styled("  ", Style::default()),
+                    Span::styled(meta, Style::default().fg(colors.muted)),
+                ]),
+                Line::from(""),
+            ])
+            .style(Style::default().fg(colors.text).bg(if is_selected {
+                colors.selected_bg
+            } else {
+                colors.background
+            }))
+        })
+        .collect();
+
+    let alert_list = List::new(items)
+        .block(
+            Block::default()
+                .title(title)
+                .title_alignment(Alignment::Center)
+                .borders(Borders::ALL)
+                .border_type(colors.border_normal)
+                .border_style(Style::default().fg(colors.border))
+                .style(Style::default().bg(colors.surface))
+                .padding(Padding::new(2, 1, 1, 1)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(colors.selected_bg)
+                .fg(colors.highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("");
+
+    let mut state = ListState::default();
+    state.select(app.selected_item);
+
+    f.render_stateful_widget(alert_list, area, &mut state);
+}
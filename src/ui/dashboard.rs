@@ -1,5 +1,10 @@
-use crate::app::App;
-use crate::ui::utils::{count_wrapped_lines, format_content_for_reading};
+use crate::app::{App, ItemSort};
+use crate::config::{FeedNamePosition, SectionKind};
+use crate::keybindings::{key_display, KeyAction};
+use crate::ui::utils::{
+    alert_flag, count_wrapped_lines, feed_badge, format_content_for_reading, item_meta,
+    read_marker, ALERT_COLOR,
+};
 use crate::ui::ColorScheme;
 use html2text::from_read;
 use ratatui::{
@@ -17,12 +22,41 @@ pub(super) fn render_dashboard<B: Backend>(
     area: Rect,
     colors: &ColorScheme,
 ) {
+    // Keep failed feeds from silently vanishing: a one-line banner above the list
+    let area = if app.failed_feeds_banner_visible() && !app.is_searching && area.height > 4 {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(0)])
+            .split(area);
+        render_failed_feeds_banner(f, app, chunks[0], colors);
+        chunks[1]
+    } else {
+        area
+    };
+
+    if !app.is_searching && !app.dashboard_sections.is_empty() {
+        render_dashboard_sections(f, app, area, colors);
+        return;
+    }
+
     let search_icon = colors.get_icon_search();
     let mut title = if app.is_searching {
-        let result_count = app.active_dashboard_items().len();
+        let results = app.active_dashboard_items();
+        let visited = results
+            .iter()
+            .filter(|&&(feed_idx, item_idx)| app.is_search_visited(feed_idx, item_idx))
+            .count();
+        let visited = if visited > 0 {
+            format!(", {} visited", visited)
+        } else {
+            String::new()
+        };
         format!(
-            " {} Search: '{}' \u{2014} {} results across all feeds ",
-            search_icon, app.search_query, result_count
+            " {} Search: '{}' \u{2014} {} results across all feeds{} ",
+            search_icon,
+            app.search_query,
+            results.len(),
+            visited
         )
     } else {
         format!(" {} Latest Updates ", colors.get_icon_dashboard())
@@ -32,6 +66,9 @@ pub(super) fn render_dashboard<B: Backend>(
     if app.filter_options.is_active() {
         title = format!("{} | {} Filtered", title, search_icon);
     }
+    if app.item_sort == ItemSort::OldestFirst && !app.is_searching {
+        title = format!("{} | Oldest first", title);
+    }
 
     // Determine which item list to use — borrow as a slice to avoid cloning
     let items_to_display: &[(usize, usize)] = app.active_dashboard_items();
@@ -246,114 +283,159 @@ pub(super) fn render_dashboard<B: Backend>(
     let arrow = colors.get_arrow_right();
     let success_icon = colors.get_icon_success();
     let is_compact = app.compact;
+    let read_indicator = app.config.ui.read_indicator;
+    let feed_name = app.config.ui.feed_name;
     let items: Vec<ListItem> = items_to_display
         .iter()
         .enumerate()
         .map(|(idx, &(feed_idx, item_idx))| {
             let (feed, item) = app.active_dashboard_item(idx).unwrap();
 
-            let date_str = item.formatted_date.as_deref().unwrap_or("Unknown date");
+            let meta = item_meta(item, &app.config.ui, app.narrow);
             let is_selected = app.selected_item == Some(idx);
             let is_read = app.is_item_read(feed_idx, item_idx);
             let is_starred = app.is_item_starred(feed_idx, item_idx);
+            let is_alert = app.item_alert(feed_idx, item_idx).is_some();
+            // Results already opened during this session's searches fade back
+            let is_visited = app.is_searching && app.is_search_visited(feed_idx, item_idx);
+            let pointer = Span::styled(
+                if is_selected {
+                    format!("{} ", arrow)
+                } else {
+                    "  ".to_string()
+                },
+                Style::default().fg(colors.highlight),
+            );
+            let star = Span::styled(
+                if is_starred { " \u{2605}" } else { "" },
+                Style::default().fg(Color::Rgb(255, 215, 0)),
+            );
+            let read_icon = Span::styled(
+                if is_read {
+                    format!(" {}", success_icon)
+                } else {
+                    "".to_string()
+                },
+                Style::default().fg(colors.success),
+            );
 
             if is_compact {
                 // Compact: single line per item
-                ListItem::new(Line::from(vec![
-                    Span::styled(
-                        if is_selected {
-                            format!("{} ", arrow)
-                        } else {
-                            "  ".to_string()
-                        },
-                        Style::default().fg(colors.highlight),
-                    ),
-                    Span::styled(
+                let mut spans = vec![
+                    pointer,
+                    read_marker(read_indicator, is_read, colors),
+                    feed_badge(&feed.title, &feed.url, &app.config.ui),
+                    Span::raw(" "),
+                ];
+                if feed_name != FeedNamePosition::Hidden {
+                    spans.push(Span::styled(
                         format!("{} | ", feed.title),
                         Style::default()
                             .fg(colors.text_secondary)
                             .add_modifier(Modifier::BOLD),
-                    ),
+                    ));
+                }
+                spans.extend([
                     Span::styled(
                         &item.title,
-                        Style::default().fg(if is_read { colors.muted } else { colors.text }),
-                    ),
-                    Span::styled(
-                        if is_starred { " \u{2605}" } else { "" },
-                        Style::default().fg(Color::Rgb(255, 215, 0)),
+                        Style::default().fg(if is_read || is_visited {
+                            colors.muted
+                        } else if is_alert {
+                            ALERT_COLOR
+                        } else {
+                            colors.text
+                        }),
                     ),
-                    Span::styled(format!("  {}", date_str), Style::default().fg(colors.muted)),
-                ]))
-                .style(Style::default().fg(colors.text).bg(if is_selected {
-                    colors.selected_bg
-                } else {
-                    colors.background
-                }))
+                    star,
+                    alert_flag(is_alert),
+                ]);
+                if let Some(meta) = meta {
+                    spans.push(Span::styled(
+                        format!("  {}", meta),
+                        Style::default().fg(colors.muted),
+                    ));
+                }
+                ListItem::new(Line::from(spans)).style(Style::default().fg(colors.text).bg(
+                    if is_selected {
+                        colors.selected_bg
+                    } else {
+                        colors.background
+                    },
+                ))
             } else {
+                let feed_style = Style::default()
+                    .fg(if is_selected {
+                        colors.secondary
+                    } else {
+                        colors.text_secondary
+                    })
+                    .add_modifier(Modifier::BOLD);
+                let title = Span::styled(
+                    &item.title,
+                    Style::default()
+                        .fg(if is_selected {
+                            colors.text
+                        } else if is_visited {
+                            colors.muted
+                        } else if is_read {
+                            colors.text_secondary
+                        } else if is_alert {
+                            ALERT_COLOR
+                        } else {
+                            colors.text
+                        })
+                        .add_modifier(if is_selected {
+                            Modifier::BOLD
+                        } else {
+                            Modifier::empty()
+                        }),
+                );
+
                 // Create clearer visual group with theme-specific hierarchy
-                ListItem::new(vec![
-                    // Feed source with theme-specific indicator
-                    Line::from(vec![
-                        Span::styled(
-                            if is_selected {
-                                format!("{} ", arrow)
-                            } else {
-                                "  ".to_string()
-                            },
-                            Style::default().fg(colors.highlight),
-                        ),
-                        Span::styled(
-                            feed.title.to_string(),
-                            Style::default()
-                                .fg(if is_selected {
-                                    colors.secondary
-                                } else {
-                                    colors.text_secondary
-                                })
-                                .add_modifier(Modifier::BOLD),
-                        ),
-                        Span::styled(
-                            if is_starred { " \u{2605}" } else { "" },
-                            Style::default().fg(Color::Rgb(255, 215, 0)),
-                        ),
-                        Span::styled(
-                            if is_read {
-                                format!(" {}", success_icon)
-                            } else {
-                                "".to_string()
-                            },
-                            Style::default().fg(colors.success),
-                        ),
-                    ]),
-                    // Item title - cleaner layout
-                    Line::from(vec![
-                        Span::styled("  ", Style::default()),
-                        Span::styled(
-                            &item.title,
-                            Style::default()
-                                .fg(if is_selected {
-                                    colors.text
-                                } else if is_read {
-                                    colors.text_secondary
-                                } else {
-                                    colors.text
-                                })
-                                .add_modifier(if is_selected {
-                                    Modifier::BOLD
-                                } else {
-                                    Modifier::empty()
-                                }),
-                        ),
-                    ]),
-                    // Publication date with subtle styling
-                    Line::from(vec![
+                let mut lines = if feed_name == FeedNamePosition::Above {
+                    vec![
+                        // Feed source with theme-specific indicator
+                        Line::from(vec![
+                            pointer,
+                            feed_badge(&feed.title, &feed.url, &app.config.ui),
+                            Span::raw(" "),
+                            Span::styled(feed.title.to_string(), feed_style),
+                            star,
+                            alert_flag(is_alert),
+                            read_icon,
+                        ]),
+                        // Item title - cleaner layout
+                        Line::from(vec![
+                            Span::styled("  ", Style::default()),
+                            read_marker(read_indicator, is_read, colors),
+                            title,
+                        ]),
+                    ]
+                } else {
+                    // Feed name beside the title, or just its badge
+                    let mut spans = vec![
+                        pointer,
+                        read_marker(read_indicator, is_read, colors),
+                        feed_badge(&feed.title, &feed.url, &app.config.ui),
+                        Span::raw(" "),
+                    ];
+                    if feed_name == FeedNamePosition::Inline {
+                        spans.push(Span::styled(format!("{} | ", feed.title), feed_style));
+                    }
+                    spans.extend([title, star, alert_flag(is_alert), read_icon]);
+                    vec![Line::from(spans)]
+                };
+                // Author and publication date with subtle styling
+                if let Some(meta) = meta {
+                    lines.push(Line::from(vec![
                         Span::styled("  ", Style::default()),
-                        Span::styled(date_str, Style::default().fg(colors.muted)),
-                    ]),
-                    // Spacing between items
-                    Line::from(""),
-                ])
-                .style(Style::default().fg(colors.text).bg(if is_selected {
+                        Span::styled(meta, Style::default().fg(colors.muted)),
+                    ]));
+                }
+                // Spacing between items
+                lines.push(Line::from(""));
+
+                ListItem::new(lines).style(Style::default().fg(colors.text).bg(if is_selected {
                     colors.selected_bg
                 } else {
                     colors.background
@@ -362,8 +444,8 @@ pub(super) fn render_dashboard<B: Backend>(
         })
         .collect();
 
-    // Split area for preview pane if active (disabled in compact mode)
-    let (list_area, preview_area) = if app.preview_pane && !app.compact {
+    // Split area for preview pane if active (disabled in compact mode and narrow terminals)
+    let (list_area, preview_area) = if app.preview_pane && !app.compact && !app.narrow {
         let chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
@@ -403,6 +485,150 @@ pub(super) fn render_dashboard<B: Backend>(
     }
 }
 
+fn render_failed_feeds_banner<B: Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    area: Rect,
+    colors: &ColorScheme,
+) {
+    let count = app.failed_feeds.len();
+    let text = format!(
+        " \u{26a0} {} feed{} failed to load \u{2014} press {} to retry or view errors ",
+        count,
+        if count == 1 { "" } else { "s" },
+        key_display(&KeyAction::ShowFailedFeeds, &app.keybindings)
+    );
+    let banner = Paragraph::new(Line::from(Span::styled(
+        text,
+        Style::default()
+            .fg(colors.error)
+            .add_modifier(Modifier::BOLD),
+    )))
+    .alignment(Alignment::Center)
+    .style(Style::default().bg(colors.surface));
+    f.render_widget(banner, area);
+}
+
+/// The dashboard as the configured sections, stacked as separate blocks. Only the
+/// focused block shows the cursor.
+fn render_dashboard_sections<B: Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    area: Rect,
+    colors: &ColorScheme,
+) {
+    let count = app.dashboard_sections.len() as u32;
+    let constraints: Vec<Constraint> = (0..count).map(|_| Constraint::Ratio(1, count)).collect();
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints(constraints)
+        .split(area);
+
+    let arrow = colors.get_arrow_right();
+    let read_indicator = app.config.ui.read_indicator;
+    for (section_idx, (section, chunk)) in
+        app.dashboard_sections.iter().zip(chunks.iter()).enumerate()
+    {
+        let is_focused = section_idx == app.focused_section;
+        let mut items: Vec<ListItem> = section
+            .items
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, &(feed_idx, item_idx))| {
+                let feed = app.feeds.get(feed_idx)?;
+                let item = feed.items.get(item_idx)?;
+                let is_selected = is_focused && app.selected_item == Some(idx);
+                let is_read = app.is_item_read(feed_idx, item_idx);
+                let is_alert = app.item_alert(feed_idx, item_idx).is_some();
+                let date_str = item.formatted_date.as_deref().unwrap_or("");
+                // Stale feeds are listed by name, with when they last posted
+                let (label, detail) = if section.kind == SectionKind::Stale {
+                    (feed.title.clone(), format!("  last post {}", date_str))
+                } else {
+                    (item.title.clone(), format!("  {}", date_str))
+                };
+                Some(
+                    ListItem::new(Line::from(vec![
+                        Span::styled(
+                            if is_selected {
+                                format!("{} ", arrow)
+                            } else {
+                                "  ".to_string()
+                            },
+                            Style::default().fg(colors.highlight),
+                        ),
+                        read_marker(read_indicator, is_read, colors),
+                        feed_badge(&feed.title, &feed.url, &app.config.ui),
+                        Span::raw(" "),
+                        Span::styled(
+                            label,
+                            Style::default()
+                                .fg(if is_read {
+                                    colors.muted
+                                } else if is_alert {
+                                    ALERT_COLOR
+                                } else {
+                                    colors.text
+                                })
+                                .add_modifier(if is_selected {
+                                    Modifier::BOLD
+                                } else {
+                                    Modifier::empty()
+                                }),
+                        ),
+                        Span::styled(
+                            if app.is_item_starred(feed_idx, item_idx) {
+                                " \u{2605}"
+                            } else {
+                                ""
+                            },
+                            Style::default().fg(Color::Rgb(255, 215, 0)),
+                        ),
+                        alert_flag(is_alert),
+                        Span::styled(detail, Style::default().fg(colors.muted)),
+                    ]))
+                    .style(Style::default().bg(if is_selected {
+                        colors.selected_bg
+                    } else {
+                        colors.surface
+                    })),
+                )
+            })
+            .collect();
+        if items.is_empty() {
+            items.push(ListItem::new(Span::styled(
+                "  Nothing here right now",
+                Style::default().fg(colors.muted),
+            )));
+        }
+
+        let list = List::new(items).block(
+            Block::default()
+                .title(format!(" {} ({}) ", section.title, section.items.len()))
+                .title_alignment(Alignment::Center)
+                .borders(Borders::ALL)
+                .border_type(if is_focused {
+                    colors.border_focus_type
+                } else {
+                    colors.border_normal
+                })
+                .border_style(Style::default().fg(if is_focused {
+                    colors.primary
+                } else {
+                    colors.border
+                }))
+                .style(Style::default().bg(colors.surface))
+                .padding(Padding::new(1, 1, 0, 0)),
+        );
+
+        let mut state = ratatui::widgets::ListState::default();
+        if is_focused {
+            state.select(app.selected_item);
+        }
+        f.render_stateful_widget(list, *chunk, &mut state);
+    }
+}
+
 fn render_preview_pane<B: Backend>(
     f: &mut Frame<B>,
     app: &mut App,
@@ -411,6 +637,10 @@ fn render_preview_pane<B: Backend>(
     colors: &ColorScheme,
 ) {
     let selected = app.selected_item.unwrap_or(0);
+    // Preview never triggers full-text fetches, but shows the page once the detail view has it
+    let content_html = items_to_display
+        .get(selected)
+        .and_then(|&(feed_idx, item_idx)| app.display_html(feed_idx, item_idx));
     let item_data = items_to_display
         .get(selected)
         .and_then(|&(feed_idx, item_idx)| {
@@ -491,7 +721,7 @@ fn render_preview_pane<B: Backend>(
     lines.push(Line::from(""));
 
     // Content
-    if let Some(desc) = &item.description {
+    if let Some(desc) = &content_html {
         let raw_text = from_read(desc.as_bytes(), area.width.saturating_sub(10) as usize);
         let formatted = format_content_for_reading(&raw_text);
         for line in formatted.lines() {
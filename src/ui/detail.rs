@@ -1,15 +1,22 @@
-use crate::app::App;
-use crate::ui::utils::{count_wrapped_lines, format_content_for_reading, truncate_url};
+use crate::app::{App, CopyMode, DetailSource, SelectionKind, ToolOutput};
+use crate::config::TranslateDisplay;
+use crate::image_art::ImageArt;
+use crate::ui::utils::{
+    article_text, comment_count_label, count_wrapped_lines, item_extras, truncate_url,
+};
 use crate::ui::ColorScheme;
-use html2text::from_read;
 use ratatui::{
     backend::Backend,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Modifier, Style},
+    style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, BorderType, Borders, Padding, Paragraph, Wrap},
     Frame,
 };
+use unicode_width::UnicodeWidthStr;
+
+/// Rows of article text kept under a comic and its hover text.
+const COMIC_MIN_TEXT_ROWS: u16 = 6;
 
 pub(super) fn render_item_detail<B: Backend>(
     f: &mut Frame<B>,
@@ -18,12 +25,16 @@ pub(super) fn render_item_detail<B: Backend>(
     colors: &ColorScheme,
 ) {
     if let Some(item) = app.current_item() {
+        // Zen mode drops the header and borders; the title leads the article text instead
+        let zen = app.zen_mode;
+        let zen_title = zen.then(|| item.title.clone());
+
         // Split the area into header and content with better proportions
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(9), // Header - increased for better spacing
-                Constraint::Min(0),    // Content
+                Constraint::Length(if zen { 0 } else { 9 }), // Header - increased for better spacing
+                Constraint::Min(0),                          // Content
             ])
             .split(area);
 
@@ -80,6 +91,68 @@ pub(super) fn render_item_detail<B: Backend>(
             ));
         }
 
+        // Episode, running time and location from feed extensions
+        for extra in item_extras(item, app.narrow) {
+            if !metadata_parts.is_empty() {
+                metadata_parts.push(Span::styled(" · ", Style::default().fg(colors.muted)));
+            }
+            metadata_parts.push(Span::styled(
+                extra,
+                Style::default().fg(colors.text_secondary),
+            ));
+        }
+
+        // Comment count, or just that there's a discussion to open
+        if item.comment_count.is_some() || item.comments_url.is_some() {
+            if !metadata_parts.is_empty() {
+                metadata_parts.push(Span::styled(" · ", Style::default().fg(colors.muted)));
+            }
+            let label = item
+                .comment_count
+                .map_or_else(|| "Comments".to_string(), comment_count_label);
+            metadata_parts.push(Span::styled(
+                label,
+                Style::default().fg(colors.text_secondary),
+            ));
+        }
+
+        // Event the article announces, which the export-event action saves
+        if let Some(event) = app
+            .selected_feed
+            .zip(app.selected_item)
+            .and_then(|(feed_idx, item_idx)| app.item_event_time(feed_idx, item_idx))
+        {
+            if !metadata_parts.is_empty() {
+                metadata_parts.push(Span::styled(" · ", Style::default().fg(colors.muted)));
+            }
+            metadata_parts.push(Span::styled(
+                format!("\u{1f4c5} {}", event.label()),
+                Style::default().fg(colors.accent),
+            ));
+        }
+
+        // Tags: confirmed ones in full colour, suggestions dimmed until confirmed
+        if let (Some(feed_idx), Some(item_idx)) = (app.selected_feed, app.selected_item) {
+            let (confirmed, suggested) = app.item_tag_lists(feed_idx, item_idx);
+            let styled_tags = confirmed
+                .iter()
+                .map(|tag| (tag, Style::default().fg(colors.accent)))
+                .chain(suggested.iter().map(|tag| {
+                    (
+                        tag,
+                        Style::default()
+                            .fg(colors.muted)
+                            .add_modifier(Modifier::DIM),
+                    )
+                }));
+            for (tag, style) in styled_tags {
+                if !metadata_parts.is_empty() {
+                    metadata_parts.push(Span::styled(" · ", Style::default().fg(colors.muted)));
+                }
+                metadata_parts.push(Span::styled(format!("#{}", tag), style));
+            }
+        }
+
         if !metadata_parts.is_empty() {
             header_lines.push(Line::from(metadata_parts));
         }
@@ -104,7 +177,10 @@ pub(super) fn render_item_detail<B: Backend>(
         let header = Paragraph::new(header_lines)
             .block(
                 Block::default()
-                    .title(format!(" {} Article ", article_icon))
+                    .title(match app.reading_session_status() {
+                        Some(status) => format!(" {} Article \u{00b7} {} ", article_icon, status),
+                        None => format!(" {} Article ", article_icon),
+                    })
                     .title_alignment(Alignment::Center)
                     .borders(Borders::ALL)
                     .border_type(colors.border_normal)
@@ -115,32 +191,229 @@ pub(super) fn render_item_detail<B: Backend>(
             .style(Style::default().fg(colors.text))
             .alignment(Alignment::Left);
 
-        f.render_widget(header, chunks[0]);
+        if !zen {
+            f.render_widget(header, chunks[0]);
+        }
 
-        // Process content with enhanced formatting
-        let description = if let Some(desc) = &item.description {
-            // Convert HTML to plain text with better width for readability
-            let raw_text = from_read(desc.as_bytes(), 100);
-            format_content_for_reading(&raw_text)
+        // Process content with enhanced formatting, after the feed's display rules
+        let html = app
+            .selected_feed
+            .zip(app.selected_item)
+            .and_then(|(feed_idx, item_idx)| app.display_html(feed_idx, item_idx));
+        let mut description = if let Some(html) = &html {
+            article_text(html)
         } else {
             "No description available".to_string()
         };
+        // The source toggle shows the markup behind the text instead, without art or translation
+        let source = match &app.detail_source {
+            DetailSource::Rendered => None,
+            DetailSource::Html => Some(html.clone().unwrap_or_default()),
+            DetailSource::Entry(entry) => Some(entry.clone()),
+        };
+        let rendered = source.is_none();
+
+        // Translation replaces the article or sits beside it, depending on tools.translate_display
+        let translation =
+            (rendered && app.show_translation && app.copy_mode.is_none()).then(|| {
+                match app.current_translation() {
+                    Some(ToolOutput::Done(text)) => text,
+                    Some(ToolOutput::Failed(e)) => format!("Translation failed: {}", e),
+                    _ => "Translating…".to_string(),
+                }
+            });
+        // The lead image's block art sits above the text, taking at most a third of the pane.
+        // A comic takes all but a few rows, with its hover text right under it.
+        let mut body = chunks[1];
+        let panel = app
+            .selected_feed
+            .zip(app.selected_item)
+            .filter(|&(feed_idx, _)| rendered && app.is_comic(feed_idx))
+            .and_then(|(feed_idx, item_idx)| app.comic_panel(feed_idx, item_idx, html.as_deref()));
+        let caption = panel
+            .as_ref()
+            .and_then(|panel| panel.caption())
+            .filter(|_| app.copy_mode.is_none());
+        let caption_height = caption.map_or(0, |caption| {
+            count_wrapped_lines(caption, body.width.saturating_sub(4) as usize).min(4) + 1
+        });
+        let art_limit = if panel.is_some() {
+            body.height
+                .saturating_sub(caption_height + COMIC_MIN_TEXT_ROWS)
+        } else {
+            body.height / 3
+        };
+        // Block art is nothing but color, so the mono theme leaves it out
+        let drawable = rendered && app.copy_mode.is_none() && !colors.monochrome;
+        let art = app
+            .selected_feed
+            .zip(app.selected_item)
+            .filter(|_| drawable)
+            .and_then(|(feed_idx, item_idx)| app.image_art(feed_idx, item_idx, html.as_deref()));
+        if let Some(art) = art {
+            let art_height = art.height().min(art_limit);
+            if art_height > 0 {
+                let rows = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Length(art_height), Constraint::Min(0)])
+                    .split(body);
+                f.render_widget(
+                    Paragraph::new(image_art_lines(&art))
+                        .style(Style::default().bg(colors.surface))
+                        .alignment(Alignment::Center),
+                    rows[0],
+                );
+                body = rows[1];
+            }
+        }
+        if let Some(caption) = caption {
+            let rows = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(caption_height), Constraint::Min(0)])
+                .split(body);
+            f.render_widget(
+                Paragraph::new(caption.to_string())
+                    .block(
+                        Block::default()
+                            .padding(Padding::new(2, 2, 0, 0))
+                            .style(Style::default().bg(colors.surface)),
+                    )
+                    .style(
+                        Style::default()
+                            .fg(colors.accent)
+                            .add_modifier(Modifier::ITALIC),
+                    )
+                    .alignment(Alignment::Center)
+                    .wrap(Wrap { trim: true }),
+                rows[0],
+            );
+            body = rows[1];
+        }
+
+        // Zen mode keeps one row under the article for a minimal scroll indicator
+        let (text_area, indicator_area) = if zen {
+            let rows = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(1)])
+                .split(body);
+            (rows[0], Some(rows[1]))
+        } else {
+            (body, None)
+        };
+        let side_by_side = app.config.tools.translate_display == TranslateDisplay::SideBySide;
+        let (content_area, translation_area) = match translation {
+            Some(_) if side_by_side => {
+                let halves = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                    .split(text_area);
+                (halves[0], Some(halves[1]))
+            }
+            _ => (text_area, None),
+        };
+        let translation = match translation {
+            Some(text) if !side_by_side => {
+                description = text;
+                None
+            }
+            other => other,
+        };
+        if let Some(source) = source {
+            description = source;
+        } else if let Some(title) = zen_title {
+            description = format!("{}\n\n{}", title, description);
+        }
+
+        let (borders, padding) = if zen {
+            (Borders::NONE, Padding::new(6, 6, 2, 1))
+        } else {
+            (Borders::ALL, Padding::new(4, 4, 2, 2)) // Generous padding for reading comfort
+        };
 
         // Calculate the viewport height (accounting for borders and padding)
-        let viewport_height = chunks[1]
+        let border_size = if zen { 0 } else { 2 };
+        let viewport_height = content_area
             .height
-            .saturating_sub(2) // borders (top and bottom)
-            .saturating_sub(4); // increased padding (top and bottom)
+            .saturating_sub(border_size) // borders (top and bottom)
+            .saturating_sub(padding.top + padding.bottom);
 
         // Calculate the content width (accounting for borders and padding)
-        let content_width = chunks[1]
+        let content_width = content_area
             .width
-            .saturating_sub(2) // borders (left and right)
-            .saturating_sub(8) // increased padding for better reading width
-            as usize;
+            .saturating_sub(border_size) // borders (left and right)
+            .saturating_sub(padding.left + padding.right) as usize;
+
+        // Copy mode shows unwrapped lines so the cursor maps to real text positions
+        if let Some(copy_mode) = &app.copy_mode {
+            let content_lines = copy_mode.lines.len() as u16;
+            let cursor_line = copy_mode.line as u16;
+            // Keep the cursor on screen
+            let mut scroll = app.detail_vertical_scroll;
+            if cursor_line < scroll {
+                scroll = cursor_line;
+            } else if cursor_line >= scroll + viewport_height {
+                scroll = cursor_line + 1 - viewport_height.max(1);
+            }
+            let horizontal_scroll = copy_mode
+                .col
+                .saturating_sub(content_width.saturating_sub(1));
 
-        // Calculate the number of lines the wrapped content will take
-        let content_lines = count_wrapped_lines(&description, content_width);
+            let title = match copy_mode.anchor {
+                Some((_, _, SelectionKind::Block)) => " Copy Mode · Block ",
+                Some(_) => " Copy Mode · Lines ",
+                None => " Copy Mode ",
+            };
+            let content = Paragraph::new(copy_mode_lines(copy_mode, colors))
+                .block(
+                    Block::default()
+                        .title(title)
+                        .title_alignment(Alignment::Center)
+                        .borders(Borders::ALL)
+                        .border_type(colors.border_focus_type)
+                        .border_style(Style::default().fg(colors.highlight))
+                        .style(Style::default().bg(colors.surface))
+                        .padding(Padding::new(4, 4, 2, 2)),
+                )
+                .style(Style::default().fg(colors.text))
+                .scroll((scroll, horizontal_scroll as u16));
+            f.render_widget(content, text_area);
+
+            app.update_detail_max_scroll(content_lines, viewport_height);
+            app.detail_vertical_scroll = scroll;
+            return;
+        }
+
+        // Calculate the number of lines the content will take, wrapped or not
+        let mut content_lines = if app.detail_no_wrap {
+            let widest = description.lines().map(|l| l.width()).max().unwrap_or(0);
+            app.detail_max_horizontal_scroll = widest.saturating_sub(content_width) as u16;
+            description.lines().count().max(1) as u16
+        } else {
+            app.detail_max_horizontal_scroll = 0;
+            count_wrapped_lines(&description, content_width)
+        };
+        // Both panes scroll together, so scroll as far as the longer one needs
+        if let Some(text) = &translation {
+            content_lines = content_lines.max(count_wrapped_lines(text, content_width));
+        }
+
+        // Map footnote jumps from logical lines to the rows they land on once wrapped
+        let row_starts: Vec<u16> = description
+            .lines()
+            .scan(0u16, |row, line| {
+                let start = *row;
+                *row = row.saturating_add(if app.detail_no_wrap {
+                    1
+                } else {
+                    count_wrapped_lines(line, content_width)
+                });
+                Some(start)
+            })
+            .collect();
+        app.detail_footnote_rows = crate::footnotes::jump_lines(&description)
+            .into_iter()
+            .map(|(ref_line, note_line)| (row_starts[ref_line], row_starts[note_line]))
+            .collect();
 
         // Update the max scroll value
         app.update_detail_max_scroll(content_lines, viewport_height);
@@ -153,7 +426,7 @@ pub(super) fn render_item_detail<B: Backend>(
             ("↓", "↑") // Light: simple arrows
         };
 
-        let scroll_indicator = if app.detail_max_scroll > 0 {
+        let mut scroll_indicator = if app.detail_max_scroll > 0 {
             let scroll_pct =
                 (app.detail_vertical_scroll as f32 / app.detail_max_scroll as f32 * 100.0) as u16;
             if app.detail_vertical_scroll == 0 {
@@ -169,24 +442,139 @@ pub(super) fn render_item_detail<B: Backend>(
         } else {
             format!(" {} Article Content ", article_icon)
         };
+        if app.show_translation && rendered && translation_area.is_none() {
+            scroll_indicator.push_str("· Translated ");
+        }
+        match app.detail_source {
+            DetailSource::Html => scroll_indicator.push_str("· HTML source "),
+            DetailSource::Entry(_) => scroll_indicator.push_str("· Feed entry "),
+            DetailSource::Rendered => {}
+        }
+        if app.detail_no_wrap {
+            scroll_indicator.push_str(&format!(
+                "· No wrap {}/{} ",
+                app.detail_horizontal_scroll, app.detail_max_horizontal_scroll
+            ));
+        }
+
+        let mut block = Block::default()
+            .borders(borders)
+            .border_type(colors.border_normal)
+            .border_style(Style::default().fg(colors.border))
+            .style(Style::default().bg(colors.surface))
+            .padding(padding);
+        if let Some(area) = indicator_area {
+            let position = if app.detail_max_scroll > 0 {
+                format!(
+                    "{}% ",
+                    app.detail_vertical_scroll as u32 * 100 / app.detail_max_scroll as u32
+                )
+            } else {
+                String::new()
+            };
+            let indicator = Paragraph::new(position)
+                .style(Style::default().fg(colors.muted).bg(colors.surface))
+                .alignment(Alignment::Right);
+            f.render_widget(indicator, area);
+        } else {
+            block = block
+                .title(scroll_indicator)
+                .title_alignment(Alignment::Center);
+        }
 
         // Create content paragraph with theme-specific styling
-        let content = Paragraph::new(description)
-            .block(
-                Block::default()
-                    .title(scroll_indicator)
-                    .title_alignment(Alignment::Center)
-                    .borders(Borders::ALL)
-                    .border_type(colors.border_normal)
-                    .border_style(Style::default().fg(colors.border))
-                    .style(Style::default().bg(colors.surface))
-                    .padding(Padding::new(4, 4, 2, 2)), // Generous padding for reading comfort
-            )
+        let mut content = Paragraph::new(description)
+            .block(block)
             .style(Style::default().fg(colors.text))
-            .scroll((app.detail_vertical_scroll, 0))
-            .wrap(Wrap { trim: true })
+            .scroll((app.detail_vertical_scroll, app.detail_horizontal_scroll))
             .alignment(Alignment::Left);
+        // No-wrap keeps code blocks and ASCII tables intact, scrolled with Left/Right
+        if !app.detail_no_wrap {
+            content = content.wrap(Wrap { trim: true });
+        }
 
-        f.render_widget(content, chunks[1]);
+        f.render_widget(content, content_area);
+
+        if let (Some(text), Some(area)) = (translation, translation_area) {
+            let translated = Paragraph::new(text)
+                .block(
+                    Block::default()
+                        .title(" Translation ")
+                        .title_alignment(Alignment::Center)
+                        .borders(Borders::ALL)
+                        .border_type(colors.border_normal)
+                        .border_style(Style::default().fg(colors.border))
+                        .style(Style::default().bg(colors.surface))
+                        .padding(Padding::new(4, 4, 2, 2)),
+                )
+                .style(Style::default().fg(colors.text))
+                .scroll((app.detail_vertical_scroll, 0))
+                .wrap(Wrap { trim: true });
+            f.render_widget(translated, area);
+        }
     }
 }
+
+/// Block art as lines of upper half blocks: top pixel in front, bottom pixel behind.
+fn image_art_lines(art: &ImageArt) -> Vec<Line<'static>> {
+    art.rows
+        .iter()
+        .map(|row| {
+            Line::from(
+                row.iter()
+                    .map(|&([tr, tg, tb], [br, bg, bb])| {
+                        Span::styled(
+                            "▀",
+                            Style::default()
+                                .fg(Color::Rgb(tr, tg, tb))
+                                .bg(Color::Rgb(br, bg, bb)),
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect()
+}
+
+/// Article lines with the copy-mode selection and cursor highlighted.
+fn copy_mode_lines(copy_mode: &CopyMode, colors: &ColorScheme) -> Vec<Line<'static>> {
+    let selected = Style::default().fg(colors.surface).bg(colors.secondary);
+    let cursor = Style::default()
+        .fg(colors.surface)
+        .bg(colors.highlight)
+        .add_modifier(Modifier::BOLD);
+
+    copy_mode
+        .lines
+        .iter()
+        .enumerate()
+        .map(|(idx, text)| {
+            let mut chars: Vec<char> = text.chars().collect();
+            let range = copy_mode.selected_cols(idx);
+            let cursor_col = (idx == copy_mode.line).then_some(copy_mode.col);
+            // Pad so block selections and the cursor stay visible past the end of the line
+            let needed = range
+                .map_or(0, |(_, end)| end)
+                .max(cursor_col.map_or(0, |c| c + 1));
+            if chars.len() < needed {
+                chars.resize(needed, ' ');
+            }
+
+            let spans: Vec<Span<'static>> = chars
+                .iter()
+                .enumerate()
+                .map(|(col, ch)| {
+                    let style = if Some(col) == cursor_col {
+                        cursor
+                    } else if range.is_some_and(|(start, end)| col >= start && col < end) {
+                        selected
+                    } else {
+                        Style::default()
+                    };
+                    Span::styled(ch.to_string(), style)
+                })
+                .collect();
+            Line::from(spans)
+        })
+        .collect()
+}
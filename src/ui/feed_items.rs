@@ -1,9 +1,13 @@
-use crate::app::App;
-use crate::ui::utils::truncate_str;
+use crate::app::{App, ItemSort};
+use crate::feed::{format_date, Feed};
+use crate::keybindings::{key_display, KeyAction};
+use crate::ui::utils::{
+    alert_flag, item_meta, item_snippet, read_marker, truncate_str, truncate_url, ALERT_COLOR,
+};
 use crate::ui::ColorScheme;
 use ratatui::{
     backend::Backend,
-    layout::{Alignment, Rect},
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span, Text},
     widgets::{Block, BorderType, Borders, List, ListItem, Padding, Paragraph},
@@ -18,7 +22,25 @@ pub(super) fn render_feed_items<B: Backend>(
 ) {
     if let Some(feed) = app.current_feed() {
         let feed_icon = colors.get_icon_feed();
-        let title = format!(" {} {} ", feed_icon, feed.title);
+        let mut title = format!(" {} {} ", feed_icon, feed.title);
+        if app.item_sort == ItemSort::OldestFirst {
+            title.push_str("· oldest first ");
+        }
+        if app.filter_options.is_active() {
+            title.push_str("· filtered ");
+        }
+
+        // Header strip with the feed's details, unless space is tight
+        let area = if app.compact || area.height < 16 {
+            area
+        } else {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(5), Constraint::Min(0)])
+                .split(area);
+            render_feed_header(f, app, feed, chunks[0], colors);
+            chunks[1]
+        };
 
         if feed.items.is_empty() {
             // Empty feed visualization
@@ -67,16 +89,48 @@ pub(super) fn render_feed_items<B: Backend>(
             return;
         }
 
+        let order = app
+            .selected_feed
+            .map(|feed_idx| app.feed_item_order(feed_idx))
+            .unwrap_or_default();
+        if order.is_empty() {
+            let kd = |action: &KeyAction| key_display(action, &app.keybindings);
+            let text = vec![
+                Line::from(""),
+                Line::from(Span::styled(
+                    "No items match the filters for this feed",
+                    Style::default()
+                        .fg(colors.text)
+                        .add_modifier(Modifier::BOLD),
+                )),
+                Line::from(""),
+                Line::from(Span::styled(
+                    format!("Press '{}' to change them", kd(&KeyAction::OpenFilter)),
+                    Style::default().fg(colors.highlight),
+                )),
+            ];
+            let paragraph = Paragraph::new(text).alignment(Alignment::Center).block(
+                Block::default()
+                    .title(title)
+                    .title_alignment(Alignment::Center)
+                    .borders(Borders::ALL)
+                    .border_type(colors.border_normal)
+                    .border_style(Style::default().fg(colors.border))
+                    .style(Style::default().bg(colors.surface))
+                    .padding(Padding::new(2, 2, 2, 2)),
+            );
+            f.render_widget(paragraph, area);
+            return;
+        }
+
         // Enhanced feed items with theme-specific styling
         let arrow = colors.get_arrow_right();
         let success_icon = colors.get_icon_success();
-        let items: Vec<ListItem> = feed
-            .items
+        let read_indicator = app.config.ui.read_indicator;
+        let items: Vec<ListItem> = order
             .iter()
-            .enumerate()
+            .map(|&idx| (idx, &feed.items[idx]))
             .map(|(idx, item)| {
-                let date_str = item.formatted_date.as_deref().unwrap_or("");
-                let author = item.author.as_deref().unwrap_or("");
                 let is_selected = app.selected_item == Some(idx);
                 let is_read = app
                     .selected_feed
@@ -84,19 +138,9 @@ pub(super) fn render_feed_items<B: Backend>(
                 let is_starred = app
                     .selected_feed
                     .is_some_and(|feed_idx| app.is_item_starred(feed_idx, idx));
-
-                // Use cached plain_text to avoid HTML parsing per frame
-                let snippet = if let Some(plain_text) = &item.plain_text {
-                    // Remove excess whitespace for cleaner display
-                    let clean_text = plain_text
-                        .replace('\n', " ")
-                        .replace("  ", " ")
-                        .trim()
-                        .to_string();
-                    truncate_str(&clean_text, 100)
-                } else {
-                    "".to_string()
-                };
+                let is_alert = app
+                    .selected_feed
+                    .is_some_and(|feed_idx| app.item_alert(feed_idx, idx).is_some());
 
                 // Create compact but readable item layout with theme-specific indicators
                 let mut lines = vec![
@@ -110,6 +154,7 @@ pub(super) fn render_feed_items<B: Backend>(
                             },
                             Style::default().fg(colors.highlight),
                         ),
+                        read_marker(read_indicator, is_read, colors),
                         Span::styled(
                             &item.title,
                             Style::default()
@@ -117,6 +162,8 @@ pub(super) fn render_feed_items<B: Backend>(
                                     colors.text
                                 } else if is_read {
                                     colors.text_secondary
+                                } else if is_alert {
+                                    ALERT_COLOR
                                 } else {
                                     colors.text
                                 })
@@ -130,6 +177,7 @@ pub(super) fn render_feed_items<B: Backend>(
                             if is_starred { " \u{2605}" } else { "" },
                             Style::default().fg(Color::Rgb(255, 215, 0)),
                         ),
+                        alert_flag(is_alert),
                         Span::styled(
                             if is_read {
                                 format!(" {}", success_icon)
@@ -142,7 +190,7 @@ pub(super) fn render_feed_items<B: Backend>(
                 ];
 
                 // Add content preview with subtle styling
-                if !snippet.is_empty() {
+                if let Some(snippet) = item_snippet(item, &app.config.ui, app.narrow) {
                     lines.push(Line::from(vec![
                         Span::styled("  ", Style::default()),
                         Span::styled(
@@ -157,22 +205,11 @@ pub(super) fn render_feed_items<B: Backend>(
                 }
 
                 // Add metadata on one line
-                let mut metadata_parts = Vec::new();
-                metadata_parts.push(Span::styled("  ", Style::default()));
-
-                if !author.is_empty() {
-                    metadata_parts.push(Span::styled(author, Style::default().fg(colors.muted)));
-                    if !date_str.is_empty() {
-                        metadata_parts.push(Span::styled(" · ", Style::default().fg(colors.muted)));
-                    }
-                }
-
-                if !date_str.is_empty() {
-                    metadata_parts.push(Span::styled(date_str, Style::default().fg(colors.muted)));
-                }
-
-                if !metadata_parts.is_empty() {
-                    lines.push(Line::from(metadata_parts));
+                if let Some(meta) = item_meta(item, &app.config.ui, app.narrow) {
+                    lines.push(Line::from(vec![
+                        Span::styled("  ", Style::default()),
+                        Span::styled(meta, Style::default().fg(colors.muted)),
+                    ]));
                 }
 
                 // Add spacing between items
@@ -206,8 +243,73 @@ pub(super) fn render_feed_items<B: Backend>(
             .highlight_symbol("");
 
         let mut state = ratatui::widgets::ListState::default();
-        state.select(app.selected_item);
+        state.select(
+            app.selected_item
+                .and_then(|selected| order.iter().position(|&idx| idx == selected)),
+        );
 
         f.render_stateful_widget(items_list, area, &mut state);
     }
 }
+
+/// Summary of the open feed above its items: description, counts, freshness and the
+/// keys for acting on the feed as a whole.
+fn render_feed_header<B: Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    feed: &Feed,
+    area: Rect,
+    colors: &ColorScheme,
+) {
+    let width = area.width.saturating_sub(4) as usize;
+    let description = match &feed.description {
+        Some(description) => truncate_str(&description.replace('\n', " "), width),
+        None => truncate_url(&feed.url, width),
+    };
+    let unread = app.selected_feed.map_or(0, |idx| app.unread_count(idx));
+    let updated = feed
+        .last_updated()
+        .map_or_else(|| "unknown".to_string(), format_date);
+
+    let muted = Style::default().fg(colors.muted);
+    let key = Style::default().fg(colors.highlight);
+    let kd = |action: &KeyAction| key_display(action, &app.keybindings);
+    let lines = vec![
+        Line::from(Span::styled(
+            description,
+            Style::default().fg(colors.text_secondary),
+        )),
+        Line::from(vec![
+            Span::styled(
+                format!("{} unread", unread),
+                Style::default()
+                    .fg(colors.highlight)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!(" · {} items · updated {}", feed.items.len(), updated),
+                muted,
+            ),
+        ]),
+        Line::from(vec![
+            Span::styled(kd(&KeyAction::RefreshFeed), key),
+            Span::styled(" refresh · ", muted),
+            Span::styled(kd(&KeyAction::MarkAllRead), key),
+            Span::styled(" mark all read · ", muted),
+            Span::styled(kd(&KeyAction::AssignCategory), key),
+            Span::styled(" category · ", muted),
+            Span::styled(kd(&KeyAction::FeedInfo), key),
+            Span::styled(" info", muted),
+        ]),
+    ];
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_type(colors.border_normal)
+            .border_style(Style::default().fg(colors.border))
+            .style(Style::default().bg(colors.surface))
+            .padding(Padding::new(1, 1, 0, 0)),
+    );
+    f.render_widget(paragraph, area);
+}
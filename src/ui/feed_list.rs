@@ -1,4 +1,5 @@
-use crate::app::{App, TreeItem};
+use crate::app::{App, FeedSortMode, TreeItem};
+use crate::ui::utils::feed_badge;
 use crate::ui::ColorScheme;
 use ratatui::{
     backend::Backend,
@@ -37,7 +38,7 @@ pub(super) fn render_feed_list<B: Backend>(
 
     f.render_widget(title_para, chunks[0]);
 
-    if app.feeds.is_empty() && app.categories.is_empty() {
+    if app.feeds.is_empty() && app.categories.is_empty() && app.feed_tree.is_empty() {
         // Theme-specific empty feed ASCII art
         let mut text = Text::default();
         let art_lines = colors.get_empty_feed_art();
@@ -174,10 +175,82 @@ pub(super) fn render_feed_list<B: Backend>(
                         ),
                     ]))
                 }
+                TreeItem::Archive => {
+                    let expand_icon = if app.archive_expanded {
+                        "\u{25be}" // ▾
+                    } else {
+                        "\u{25b8}" // ▸
+                    };
+                    let feed_count = app.archived_feeds.len();
+                    ListItem::new(Line::from(vec![
+                        Span::styled(
+                            format!("{} ", expand_icon),
+                            Style::default().fg(if is_selected {
+                                colors.highlight
+                            } else {
+                                colors.muted
+                            }),
+                        ),
+                        Span::styled(
+                            "Archived feeds",
+                            Style::default()
+                                .fg(if is_selected {
+                                    colors.highlight
+                                } else {
+                                    colors.muted
+                                })
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                        Span::styled(
+                            format!(
+                                " ({} feed{})",
+                                feed_count,
+                                if feed_count == 1 { "" } else { "s" }
+                            ),
+                            Style::default().fg(colors.muted),
+                        ),
+                    ]))
+                }
+                TreeItem::Pending(url, parent) => {
+                    let indent = if parent.is_some() { "    " } else { "  " };
+                    let frames = colors.get_loading_frames();
+                    let title = app.placeholder_title(url);
+                    ListItem::new(Line::from(vec![
+                        Span::styled(indent, Style::default()),
+                        Span::styled(
+                            format!("{} ", frames[app.loading_indicator % frames.len()]),
+                            Style::default().fg(colors.muted),
+                        ),
+                        feed_badge(&title, url, &app.config.ui),
+                        Span::raw(" "),
+                        Span::styled(
+                            title,
+                            Style::default()
+                                .fg(colors.muted)
+                                .add_modifier(Modifier::ITALIC),
+                        ),
+                        Span::styled(
+                            format!(" \u{00b7} {} \u{00b7} loading", extract_domain(url)),
+                            Style::default().fg(colors.muted),
+                        ),
+                    ]))
+                }
                 TreeItem::Feed(feed_idx, parent) => {
                     let feed = &app.feeds[*feed_idx];
                     let indent = if parent.is_some() { "    " } else { "  " };
-                    let item_count = feed.items.len();
+                    // Show unread rather than total when that's what the list is sorted by
+                    let count_label = match app.feed_sort {
+                        FeedSortMode::MostUnread => {
+                            format!(" ({} unread)", app.unread_count(*feed_idx))
+                        }
+                        FeedSortMode::LeastEngaged => {
+                            match app.feed_engagement(&feed.url).score() {
+                                Some(score) => format!(" ({}% opened)", score),
+                                None => " (no activity yet)".to_string(),
+                            }
+                        }
+                        _ => format!(" ({})", feed.items.len()),
+                    };
                     let domain = extract_domain(&feed.url);
 
                     let title_style = Style::default()
@@ -202,13 +275,34 @@ pub(super) fn render_feed_list<B: Backend>(
                                 colors.accent
                             }),
                         ),
+                        feed_badge(&feed.title, &feed.url, &app.config.ui),
+                        Span::raw(" "),
                         Span::styled(feed.title.clone(), title_style),
+                        Span::styled(count_label, Style::default().fg(colors.muted)),
                         Span::styled(
-                            format!(" ({})", item_count),
+                            format!(" \u{00b7} {}", domain),
                             Style::default().fg(colors.muted),
                         ),
                         Span::styled(
-                            format!(" \u{00b7} {}", domain),
+                            feed.dominant_language()
+                                .map(|code| format!(" \u{00b7} {}", code))
+                                .unwrap_or_default(),
+                            Style::default().fg(colors.muted),
+                        ),
+                        Span::styled(
+                            if app.muted_feeds.contains(&feed.url) {
+                                " \u{00b7} muted"
+                            } else {
+                                ""
+                            },
+                            Style::default().fg(colors.error),
+                        ),
+                        Span::styled(
+                            if app.dashboard_hidden_feeds.contains(&feed.url) {
+                                " \u{00b7} off dashboard"
+                            } else {
+                                ""
+                            },
                             Style::default().fg(colors.muted),
                         ),
                     ]))
@@ -225,7 +319,11 @@ pub(super) fn render_feed_list<B: Backend>(
             Block::default()
                 .borders(Borders::ALL)
                 .border_type(colors.border_normal)
-                .title(format!(" {} Feeds ", feed_icon))
+                .title(format!(
+                    " {} Feeds \u{00b7} sort: {} ",
+                    feed_icon,
+                    app.feed_sort.label()
+                ))
                 .title_alignment(Alignment::Center)
                 .border_style(Style::default().fg(colors.border))
                 .style(Style::default().bg(colors.surface))
@@ -0,0 +1,406 @@
+//! The hints in the bottom help bar, built from the active keymap. Each hint shows the key
+//! its action is bound to now, so remapped keys show up as remapped, and hints are left
+//! out when their action has no key or can't do anything here (an item without a link,
+//! a command that isn't configured, edits in read-only mode).
+
+use crate::app::{App, CategoryAction, TreeItem, View};
+use crate::globals;
+use crate::keybindings::{key_display, KeyAction};
+
+/// One entry of the help bar.
+pub(super) struct Hint {
+    pub keys: String,
+    pub label: &'static str,
+    /// Label in the compact bar of short terminals; hints without one are left out there
+    pub short: Option<&'static str>,
+}
+
+struct Hints<'a> {
+    app: &'a App,
+    list: Vec<Hint>,
+}
+
+impl Hints<'_> {
+    fn key(&self, action: KeyAction) -> Option<String> {
+        self.app
+            .keybindings
+            .get(&action)
+            .is_some_and(|bindings| !bindings.is_empty())
+            .then(|| key_display(&action, &self.app.keybindings))
+    }
+
+    // A hint for `action`, if it has a key
+    fn action(&mut self, action: KeyAction, label: &'static str, short: Option<&'static str>) {
+        if let Some(keys) = self.key(action) {
+            self.push(keys, label, short);
+        }
+    }
+
+    // A hint for `action` when `available`
+    fn action_if(
+        &mut self,
+        available: bool,
+        action: KeyAction,
+        label: &'static str,
+        short: Option<&'static str>,
+    ) {
+        if available {
+            self.action(action, label, short);
+        }
+    }
+
+    // One hint for two actions, e.g. "k/j: Navigate"
+    fn pair(&mut self, first: KeyAction, second: KeyAction, label: &'static str) {
+        let keys = match (self.key(first), self.key(second)) {
+            (Some(first), Some(second)) => format!("{}/{}", first, second),
+            (Some(key), None) | (None, Some(key)) => key,
+            (None, None) => return,
+        };
+        self.push(keys, label, None);
+    }
+
+    // A hint for keys that can't be remapped
+    fn fixed(&mut self, keys: &str, label: &'static str, short: Option<&'static str>) {
+        self.push(keys.to_string(), label, short);
+    }
+
+    fn push(&mut self, keys: String, label: &'static str, short: Option<&'static str>) {
+        self.list.push(Hint { keys, label, short });
+    }
+}
+
+/// The help bar hints for the current view and selection, most useful first.
+pub(super) fn hints(app: &App) -> Vec<Hint> {
+    let mut h = Hints {
+        app,
+        list: Vec::new(),
+    };
+    let writable = !globals::read_only();
+    let tools = &app.config.tools;
+    let item = app
+        .selected_item_indices()
+        .and_then(|(feed_idx, item_idx)| app.feeds.get(feed_idx)?.items.get(item_idx));
+    let has_item = item.is_some();
+    let has_link = item
+        .and_then(|item| item.link.as_deref())
+        .is_some_and(|link| !link.is_empty());
+    let has_comments = item.is_some_and(|item| item.comments_url.is_some());
+
+    match app.view {
+        View::Dashboard if app.feeds.is_empty() => {
+            h.action_if(writable, KeyAction::AddFeed, "Add feed", Some("add"));
+            h.action_if(
+                writable,
+                KeyAction::OpenCategoryManagement,
+                "Categories",
+                None,
+            );
+            h.action(KeyAction::ToggleTheme, "Theme", None);
+            h.action(KeyAction::Help, "Help", Some("help"));
+            h.action(KeyAction::Quit, "Quit", Some("quit"));
+        }
+        View::Dashboard => {
+            h.pair(KeyAction::MoveUp, KeyAction::MoveDown, "Navigate");
+            if !app.dashboard_sections.is_empty() && !app.is_searching {
+                h.pair(
+                    KeyAction::NextSection,
+                    KeyAction::PrevSection,
+                    "Next/prev section",
+                );
+            }
+            h.action_if(has_item, KeyAction::Select, "View", None);
+            h.action_if(has_item, KeyAction::ContextMenu, "Actions", Some("actions"));
+            h.action_if(has_comments, KeyAction::OpenComments, "Comments", None);
+            h.action_if(has_item, KeyAction::ToggleStar, "Star", None);
+            h.action_if(has_item, KeyAction::ToggleRead, "Toggle read", None);
+            h.action(KeyAction::MarkAllRead, "Mark all read", None);
+            h.action(KeyAction::TogglePreview, "Preview", Some("preview"));
+            h.action_if(writable, KeyAction::AddFeed, "Add", Some("add"));
+            h.action(KeyAction::Refresh, "Refresh", Some("refresh"));
+            h.action(KeyAction::OpenFilter, "Filter", Some("filter"));
+            h.action(KeyAction::OpenSearch, "Search", Some("search"));
+            h.action(KeyAction::Triage, "Triage", None);
+            h.action(KeyAction::OpenReadLater, "Read later", None);
+            h.action(KeyAction::OpenAlerts, "Alerts", None);
+            h.action(KeyAction::OpenScheduler, "Scheduler", None);
+            h.action(KeyAction::Help, "Help", Some("help"));
+            h.action(KeyAction::Quit, "Quit", Some("quit"));
+        }
+        View::FeedList => {
+            let selected = app
+                .selected_tree_item
+                .and_then(|sel| app.feed_tree.get(sel));
+            let on_feed = matches!(selected, Some(TreeItem::Feed(..)));
+            let on_group = matches!(selected, Some(TreeItem::Category(_) | TreeItem::Archive));
+            let on_category = matches!(selected, Some(TreeItem::Category(_)));
+            h.pair(KeyAction::MoveUp, KeyAction::MoveDown, "Navigate");
+            h.action_if(on_feed, KeyAction::Select, "Open", Some("open"));
+            h.action_if(
+                on_group,
+                KeyAction::ToggleExpand,
+                "Expand/collapse",
+                Some("expand"),
+            );
+            h.action_if(
+                writable && (on_feed || on_category),
+                KeyAction::DeleteFeed,
+                "Delete",
+                Some("del"),
+            );
+            h.action_if(
+                writable && on_feed,
+                KeyAction::AssignCategory,
+                "Category",
+                Some("category"),
+            );
+            h.action_if(on_feed, KeyAction::ArchiveFeed, "Archive", None);
+            h.action(KeyAction::MarkAllRead, "Mark read", Some("read"));
+            h.action(KeyAction::CycleFeedSort, "Sort", Some("sort"));
+            h.action_if(writable, KeyAction::AddFeed, "Add", Some("add"));
+            h.action_if(
+                writable && app.edit_history().can_undo(),
+                KeyAction::Undo,
+                "Undo",
+                Some("undo"),
+            );
+            h.action_if(
+                writable && app.edit_history().can_redo(),
+                KeyAction::Redo,
+                "Redo",
+                Some("redo"),
+            );
+            h.action_if(
+                writable,
+                KeyAction::OpenCategoryManagement,
+                "Categories",
+                None,
+            );
+            h.action(KeyAction::Help, "Help", None);
+            h.action(KeyAction::Quit, "Back", Some("back"));
+        }
+        View::CategoryManagement => {
+            let selected = app.selected_category.is_some();
+            let filing = matches!(
+                app.category_action,
+                Some(CategoryAction::AddFeedToCategory(_))
+            );
+            if filing && selected {
+                h.fixed("Enter", "Add feed to category", Some("add"));
+                h.fixed("r", "Remove feed from category", None);
+            }
+            h.fixed("n", "New category", Some("new"));
+            if selected {
+                h.fixed("e", "Rename", Some("edit"));
+                h.fixed("d", "Delete", Some("del"));
+                h.fixed("Space", "Toggle feeds", None);
+            }
+            h.action_if(app.edit_history().can_undo(), KeyAction::Undo, "Undo", None);
+            h.action_if(app.edit_history().can_redo(), KeyAction::Redo, "Redo", None);
+            h.fixed("t", "Theme", None);
+            h.fixed("Esc/q", "Back", Some("back"));
+        }
+        View::FeedItems => {
+            h.pair(KeyAction::MoveUp, KeyAction::MoveDown, "Navigate");
+            h.action_if(has_item, KeyAction::Select, "View", Some("view"));
+            h.action_if(has_item, KeyAction::ContextMenu, "Actions", Some("actions"));
+            h.action_if(has_link, KeyAction::OpenInBrowser, "Open", Some("open"));
+            h.action_if(has_comments, KeyAction::OpenComments, "Comments", None);
+            h.action_if(has_item, KeyAction::ToggleStar, "Star", Some("star"));
+            h.action_if(has_item, KeyAction::ToggleRead, "Toggle read", None);
+            h.action(KeyAction::MarkAllRead, "Mark all read", None);
+            h.action(KeyAction::OpenFilter, "Filter", None);
+            h.action(KeyAction::ToggleItemSort, "Oldest/newest", None);
+            h.action(KeyAction::RefreshFeed, "Refresh feed", None);
+            h.action(KeyAction::FeedInfo, "Feed info", None);
+            h.action(KeyAction::OpenSearch, "Search", Some("search"));
+            h.action(KeyAction::ToggleTheme, "Theme", None);
+            h.action(KeyAction::Back, "Back", Some("back"));
+            h.action(KeyAction::ForceQuit, "Quit", None);
+        }
+        View::FeedItemDetail if app.copy_mode.is_some() => {
+            h.fixed("h/j/k/l", "Move", None);
+            h.fixed("0/$", "Line start/end", None);
+            h.action(KeyAction::CopyMode, "Select lines", Some("select"));
+            h.fixed("Ctrl+v", "Select block", None);
+            h.fixed("y/Enter", "Copy", Some("copy"));
+            h.fixed("Esc", "Exit copy mode", Some("exit"));
+        }
+        View::FeedItemDetail => {
+            let has_image = item.is_some_and(|item| item.image.is_some());
+            h.pair(KeyAction::MoveUp, KeyAction::MoveDown, "Scroll");
+            h.pair(KeyAction::PageUp, KeyAction::PageDown, "Fast scroll");
+            h.action_if(has_link, KeyAction::OpenInBrowser, "Open", Some("open"));
+            h.action_if(has_comments, KeyAction::OpenComments, "Comments", None);
+            h.action_if(has_item, KeyAction::Share, "Share", None);
+            h.action_if(
+                has_link && !app.config.share.webhooks.is_empty(),
+                KeyAction::ShareWebhook,
+                "Webhook",
+                None,
+            );
+            h.action_if(has_item, KeyAction::ToggleStar, "Star", Some("star"));
+            h.action_if(has_item, KeyAction::ToggleRead, "Toggle read", Some("read"));
+            h.action_if(has_item, KeyAction::ContextMenu, "Actions", Some("actions"));
+            h.action(KeyAction::ExtractLinks, "Links", None);
+            h.action(KeyAction::ToggleWrap, "Wrap", None);
+            h.action(KeyAction::ZenMode, "Zen", None);
+            h.action_if(has_image, KeyAction::OpenImage, "Image", None);
+            h.action_if(
+                tools.translate_command.is_some(),
+                KeyAction::Translate,
+                "Translate",
+                None,
+            );
+            h.action(KeyAction::ViewSource, "Source", None);
+            h.action_if(
+                tools.summarize_command.is_some(),
+                KeyAction::Summarize,
+                "Summarize",
+                None,
+            );
+            h.action(KeyAction::ConfirmTags, "Confirm tags", None);
+            h.action(KeyAction::MuteMenu, "Mute", None);
+            h.action(KeyAction::ExportArticle, "Export", None);
+            h.action_if(
+                tools.task_command.is_some(),
+                KeyAction::MakeTask,
+                "Task",
+                None,
+            );
+            h.action(KeyAction::ExportEvent, "Event", None);
+            h.action(KeyAction::CopyMode, "Copy mode", None);
+            h.action(KeyAction::OpenSearch, "Search", None);
+            h.action(KeyAction::ToggleTheme, "Theme", None);
+            h.action(KeyAction::Back, "Back", Some("back"));
+            h.action(KeyAction::ForceQuit, "Quit", None);
+        }
+        View::Starred => {
+            h.pair(KeyAction::MoveUp, KeyAction::MoveDown, "Navigate");
+            h.action_if(has_item, KeyAction::Select, "View", Some("view"));
+            h.action_if(has_item, KeyAction::ContextMenu, "Actions", Some("actions"));
+            h.action_if(has_item, KeyAction::ToggleStar, "Unstar", Some("unstar"));
+            h.action_if(has_item, KeyAction::ToggleRead, "Toggle read", None);
+            h.action(KeyAction::MarkAllRead, "Mark all read", None);
+            h.action_if(has_link, KeyAction::OpenInBrowser, "Open", Some("open"));
+            h.action_if(has_comments, KeyAction::OpenComments, "Comments", None);
+            h.action(KeyAction::OpenSearch, "Search", None);
+            h.action(KeyAction::Quit, "Back", Some("back"));
+            h.action(KeyAction::ForceQuit, "Quit", None);
+        }
+        View::Alerts => {
+            h.pair(KeyAction::MoveUp, KeyAction::MoveDown, "Navigate");
+            h.action_if(has_item, KeyAction::Select, "View", Some("view"));
+            h.action_if(has_item, KeyAction::ContextMenu, "Actions", Some("actions"));
+            h.action_if(has_item, KeyAction::DeleteFeed, "Dismiss", Some("dismiss"));
+            h.action_if(has_item, KeyAction::ToggleStar, "Star", None);
+            h.action_if(has_item, KeyAction::ToggleRead, "Toggle read", None);
+            h.action(KeyAction::MarkAllRead, "Mark all read", None);
+            h.action_if(has_link, KeyAction::OpenInBrowser, "Open", Some("open"));
+            h.action_if(has_comments, KeyAction::OpenComments, "Comments", None);
+            h.action(KeyAction::Quit, "Back", Some("back"));
+            h.action(KeyAction::ForceQuit, "Quit", None);
+        }
+        View::ReadLater => {
+            h.pair(KeyAction::MoveUp, KeyAction::MoveDown, "Navigate");
+            h.action_if(has_item, KeyAction::Select, "View", Some("view"));
+            h.action_if(has_item, KeyAction::ContextMenu, "Actions", Some("actions"));
+            h.action_if(has_link, KeyAction::OpenInBrowser, "Open", None);
+            h.action_if(has_comments, KeyAction::OpenComments, "Comments", None);
+            h.action(KeyAction::DeleteFeed, "Remove", Some("remove"));
+            h.fixed("K/J", "Move up/down", Some("move"));
+            h.fixed("T/B", "Move to top/bottom", Some("top/bottom"));
+            h.action(KeyAction::Quit, "Back", Some("back"));
+        }
+        View::Scheduler => {
+            h.pair(KeyAction::MoveUp, KeyAction::MoveDown, "Navigate");
+            h.action(KeyAction::Select, "Refresh now", Some("refresh"));
+            h.fixed("p", "Pause/resume", Some("pause"));
+            h.action(KeyAction::Refresh, "Refresh all", Some("all"));
+            h.action(KeyAction::Quit, "Back", Some("back"));
+        }
+        View::Releases => {
+            h.pair(KeyAction::MoveUp, KeyAction::MoveDown, "Navigate");
+            h.action_if(has_item, KeyAction::Select, "Release notes", Some("notes"));
+            h.action_if(has_link, KeyAction::OpenInBrowser, "Diff", Some("diff"));
+            h.action(KeyAction::Quit, "Back", Some("back"));
+        }
+        View::Triage => {
+            h.fixed("l", "Read later", Some("later"));
+            h.action(KeyAction::ToggleStar, "Star", Some("star"));
+            h.action_if(has_link, KeyAction::OpenInBrowser, "Open", Some("open"));
+            h.action_if(has_comments, KeyAction::OpenComments, "Comments", None);
+            h.action(KeyAction::ToggleRead, "Mark read", None);
+            let skip = h
+                .key(KeyAction::MoveDown)
+                .map_or_else(|| "n".to_string(), |key| format!("n/{}", key));
+            h.push(skip, "Skip", Some("skip"));
+            h.fixed("x", "Mute source", Some("mute"));
+            let previous = h
+                .key(KeyAction::MoveUp)
+                .map_or_else(|| "←".to_string(), |key| format!("←/{}", key));
+            h.push(previous, "Previous", None);
+            h.fixed("Esc", "Done", None);
+        }
+        View::Summary => {
+            h.fixed("Any key", "Continue to Dashboard", Some("continue"));
+        }
+    }
+    h.list
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::feed::{Feed, FeedItem};
+    use crate::keybindings::KeyBinding;
+    use crossterm::event::KeyCode;
+
+    fn labels(app: &App) -> Vec<String> {
+        hints(app)
+            .iter()
+            .map(|hint| format!("{}: {}", hint.keys, hint.label))
+            .collect()
+    }
+
+    fn item(link: Option<&str>) -> FeedItem {
+        FeedItem {
+            link: link.map(str::to_string),
+            ..FeedItem::with_title("Post")
+        }
+    }
+
+    #[test]
+    fn test_hints_follow_remapped_and_unbound_keys() {
+        let mut app = App::new();
+        app.view = View::FeedItems;
+        app.keybindings.insert(
+            KeyAction::OpenSearch,
+            vec![KeyBinding::new(KeyCode::Char('f'))],
+        );
+        app.keybindings.remove(&KeyAction::FeedInfo);
+        let labels = labels(&app);
+        assert!(labels.contains(&"f: Search".to_string()));
+        assert!(!labels.iter().any(|label| label.ends_with(": Feed info")));
+    }
+
+    #[test]
+    fn test_open_is_hidden_for_items_without_a_link() {
+        let mut app = App::new();
+        app.feeds = vec![Feed {
+            url: "https://example.com/feed".to_string(),
+            title: "Feed".to_string(),
+            title_lower: "feed".to_string(),
+            description: None,
+            updated: None,
+            link: None,
+            items: vec![item(None), item(Some("https://example.com/post"))],
+        }];
+        app.selected_feed = Some(0);
+        app.view = View::FeedItems;
+
+        app.selected_item = Some(0);
+        assert!(!labels(&app).iter().any(|label| label.ends_with(": Open")));
+        app.selected_item = Some(1);
+        assert!(labels(&app).iter().any(|label| label.ends_with(": Open")));
+    }
+}
@@ -1,37 +1,55 @@
 use crate::app::{App, InputMode, View};
 use crate::config::Theme;
-use crate::keybindings::{key_display, KeyAction};
 use ratatui::{
     backend::Backend,
+    buffer::Buffer,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     symbols::{self},
     text::{Line, Span},
-    widgets::{Block, BorderType, Borders, Padding, Paragraph, Tabs},
+    widgets::{block::Title, Block, BorderType, Borders, Padding, Paragraph, Tabs},
     Frame,
 };
+use unicode_width::UnicodeWidthStr;
 
+mod alerts;
 mod categories;
 mod dashboard;
 mod detail;
 mod feed_items;
 mod feed_list;
+mod help_bar;
 mod modals;
+mod mono;
+mod read_later;
+mod releases;
+mod scheduler;
 mod starred;
 mod summary;
+mod triage;
 pub(crate) mod utils;
 
+use alerts::render_alerts;
 use categories::{render_category_input_modal, render_category_management};
 use dashboard::render_dashboard;
 use detail::render_item_detail;
 use feed_items::render_feed_items;
 use feed_list::render_feed_list;
 use modals::{
-    render_error_modal, render_feed_selection_modal, render_filter_modal, render_help_overlay,
-    render_input_modal, render_link_overlay, render_success_notification,
+    render_bulk_add_report_popup, render_context_menu_popup, render_error_modal,
+    render_failed_feeds_popup, render_feed_change_popup, render_feed_info_popup,
+    render_feed_preview_modal, render_feed_selection_modal, render_filter_modal,
+    render_full_title_popup, render_help_overlay, render_input_modal,
+    render_item_explanation_popup, render_link_overlay, render_mute_menu_popup,
+    render_quit_prompt_popup, render_refresh_diff_popup, render_success_notification,
+    render_summary_popup, render_tour, render_webhook_menu_popup,
 };
+use read_later::render_read_later;
+use releases::render_releases;
+use scheduler::render_scheduler;
 use starred::render_starred;
 use summary::render_summary;
+use triage::render_triage;
 
 // Re-export extract_domain so it remains accessible as crate::ui::extract_domain
 pub use feed_list::extract_domain;
@@ -57,6 +75,8 @@ pub struct ColorScheme {
     pub border_normal: BorderType,
     pub border_active: BorderType,
     pub border_focus_type: BorderType,
+    /// Colors are placeholders for bold, underline and reverse video (see `mono`)
+    pub monochrome: bool,
 }
 
 impl ColorScheme {
@@ -80,6 +100,7 @@ impl ColorScheme {
             border_normal: BorderType::Double,
             border_active: BorderType::Double,
             border_focus_type: BorderType::Thick,
+            monochrome: false,
         }
     }
 
@@ -103,14 +124,52 @@ impl ColorScheme {
             border_normal: BorderType::Rounded,
             border_active: BorderType::Rounded,
             border_focus_type: BorderType::Rounded,
+            monochrome: false,
         }
     }
 
-    /// Get the color scheme for the given theme
+    /// Mono theme - no colors at all, for limited terminals and `NO_COLOR`. Uses the dark
+    /// theme's symbols, which need no emoji support.
+    pub fn mono() -> Self {
+        Self {
+            primary: mono::PRIMARY,
+            secondary: mono::SECONDARY,
+            highlight: mono::HIGHLIGHT,
+            success: mono::SUCCESS,
+            background: mono::BACKGROUND,
+            surface: mono::SURFACE,
+            selected_bg: mono::SELECTED_BG,
+            text: mono::TEXT,
+            text_secondary: mono::TEXT_SECONDARY,
+            muted: mono::MUTED,
+            accent: mono::ACCENT,
+            error: mono::ERROR,
+            border: mono::BORDER,
+            border_focus: mono::BORDER_FOCUS,
+            border_normal: BorderType::Double,
+            border_active: BorderType::Double,
+            border_focus_type: BorderType::Thick,
+            monochrome: true,
+        }
+    }
+
+    /// Get the color scheme for the given theme, or the mono one when `NO_COLOR` is set
     pub fn from_theme(theme: &Theme) -> Self {
+        if mono::no_color() {
+            return Self::mono();
+        }
         match theme {
             Theme::Dark => Self::dark(),
             Theme::Light => Self::light(),
+            Theme::Mono => Self::mono(),
+        }
+    }
+
+    /// Last step of drawing a frame: the mono theme swaps its placeholder colors for text
+    /// attributes, other themes leave the frame as drawn.
+    pub fn finish(&self, buffer: &mut Buffer) {
+        if self.monochrome {
+            mono::apply(buffer);
         }
     }
 
@@ -264,6 +323,11 @@ impl ColorScheme {
 }
 
 pub fn render<B: Backend>(f: &mut Frame<B>, app: &mut App) {
+    render_frame(f, app);
+    app.color_scheme.finish(f.buffer_mut());
+}
+
+fn render_frame<B: Backend>(f: &mut Frame<B>, app: &mut App) {
     // Use the cached color scheme from app state
     let colors = app.color_scheme.clone();
 
@@ -271,6 +335,22 @@ pub fn render<B: Backend>(f: &mut Frame<B>, app: &mut App) {
     let bg_block = Block::default().style(Style::default().bg(colors.background));
     f.render_widget(bg_block, f.size());
 
+    // Zen mode: just the article, centered at the reading width
+    if app.zen_mode && app.view == View::FeedItemDetail {
+        let screen = f.size();
+        // Generous side padding on top of the text column
+        let width = (app.config.ui.reading_width + 12).min(screen.width);
+        let area = Rect::new(
+            screen.x + (screen.width - width) / 2,
+            screen.y,
+            width,
+            screen.height,
+        );
+        render_item_detail(f, app, area, &colors);
+        render_overlays(f, app, &colors);
+        return;
+    }
+
     // Main layout division — compact mode uses tighter spacing
     let chunks = if app.compact {
         Layout::default()
@@ -307,6 +387,11 @@ pub fn render<B: Backend>(f: &mut Frame<B>, app: &mut App) {
         View::FeedItemDetail => render_item_detail(f, app, chunks[1], &colors),
         View::CategoryManagement => render_category_management(f, app, chunks[1], &colors),
         View::Starred => render_starred(f, app, chunks[1], &colors),
+        View::Alerts => render_alerts(f, app, chunks[1], &colors),
+        View::Triage => render_triage(f, app, chunks[1], &colors),
+        View::ReadLater => render_read_later(f, app, chunks[1], &colors),
+        View::Scheduler => render_scheduler(f, app, chunks[1], &colors),
+        View::Releases => render_releases(f, app, chunks[1], &colors),
         View::Summary => render_summary(f, app, chunks[1], &colors),
     }
 
@@ -316,58 +401,196 @@ pub fn render<B: Backend>(f: &mut Frame<B>, app: &mut App) {
         render_help_bar(f, app, chunks[2], &colors);
     }
 
+    render_overlays(f, app, &colors);
+
+    if let Some(step) = app.tour_step {
+        render_tour(f, app, step, &chunks, &colors);
+    }
+}
+
+/// Popups, modals and notifications drawn over the current view.
+fn render_overlays<B: Backend>(f: &mut Frame<B>, app: &mut App, colors: &ColorScheme) {
     // Show error if present
     if let Some(error) = &app.error {
-        render_error_modal(f, error, &colors);
+        render_error_modal(f, error, colors);
     }
 
     // Show success notification if present
     if let Some(success) = &app.success_message {
-        render_success_notification(f, success, &colors);
+        render_success_notification(f, success, colors);
     }
 
     // Show input modal when in input modes
-    if matches!(app.input_mode, InputMode::InsertUrl | InputMode::SearchMode) {
-        render_input_modal(f, app, &colors);
+    if matches!(
+        app.input_mode,
+        InputMode::InsertUrl
+            | InputMode::SearchMode
+            | InputMode::ImportOpmlPath
+            | InputMode::ExportOpmlPath
+            | InputMode::ExportKeysPath
+    ) {
+        render_input_modal(f, app, colors);
     }
 
     // Show feed selection modal when picking from discovered feeds
     if app.input_mode == InputMode::SelectDiscoveredFeed {
-        render_feed_selection_modal(f, app, &colors);
+        render_feed_selection_modal(f, app, colors);
+    }
+
+    // Show the fetched feed before subscribing to it
+    if app.input_mode == InputMode::PreviewFeed {
+        render_feed_preview_modal(f, app, colors);
     }
 
     // Show filter modal when in filter mode
     if app.filter_mode {
-        render_filter_modal(f, app, &colors);
+        render_filter_modal(f, app, colors);
     }
 
     // Show category input modal when in category name input mode
     if app.input_mode == InputMode::CategoryNameInput {
-        render_category_input_modal(f, app, &colors);
+        render_category_input_modal(f, app, colors);
     }
 
     // Show link extraction overlay
     if app.show_link_overlay {
-        render_link_overlay(f, app, &colors);
+        render_link_overlay(f, app, colors);
+    }
+
+    // Show the article summary popup
+    if app.show_summary_popup {
+        render_summary_popup(f, app, colors);
+    }
+
+    // Show details about the open feed
+    if app.show_feed_info {
+        render_feed_info_popup(f, app, colors);
+    }
+
+    // Show why the selected item is listed
+    if app.show_item_explanation {
+        render_item_explanation_popup(f, app, colors);
+    }
+
+    // Show the selected item's title in full
+    if app.show_full_title {
+        render_full_title_popup(f, app, colors);
+    }
+
+    // Offer to mute the open item's author or domain
+    if let Some((feed_idx, item_idx)) = app.mute_menu {
+        render_mute_menu_popup(f, app, feed_idx, item_idx, colors);
+    }
+
+    // Everything that can be done with the selected item
+    if let Some(menu) = &app.context_menu {
+        render_context_menu_popup(f, app, menu, colors);
+    }
+
+    // Pick which webhook to post the item to
+    if let Some((feed_idx, item_idx)) = app.webhook_menu {
+        render_webhook_menu_popup(f, app, feed_idx, item_idx, colors);
+    }
+
+    // Show which feeds failed to load and why
+    if app.show_failed_feeds {
+        render_failed_feeds_popup(f, app, colors);
+    }
+
+    // Show how adding a pasted list of feeds went
+    if let Some(report) = &app.bulk_add_report {
+        render_bulk_add_report_popup(f, report, colors);
+    }
+
+    // Tell about a feed that changed its title, site or description
+    if let Some(change) = app.pending_feed_change() {
+        render_feed_change_popup(f, app, change, colors);
+    }
+
+    // Show what the last refresh brought in
+    if app.refresh_diff.is_some() {
+        render_refresh_diff_popup(f, app, colors);
+    }
+
+    // Ask before quitting with work still pending
+    if app.show_quit_prompt {
+        render_quit_prompt_popup(f, app, colors);
     }
 
     // Show help overlay on top of everything
     if app.show_help_overlay {
-        render_help_overlay(f, app, &colors);
+        render_help_overlay(f, app, colors);
+    }
+}
+
+// Longest status command output shown in the title bar
+const STATUS_STRIP_WIDTH: usize = 40;
+
+// Progress toward the daily reading goal: a five-step bar, or a check once it's met
+fn reading_goal_text(read: std::time::Duration, goal_minutes: u64) -> String {
+    let minutes = read.as_secs() / 60;
+    if minutes >= goal_minutes {
+        return format!(" \u{2713} {} min read today ", minutes);
+    }
+    let filled = (minutes * 5 / goal_minutes) as usize;
+    format!(
+        " {}{} {}/{} min ",
+        "\u{25b0}".repeat(filled),
+        "\u{25b1}".repeat(5 - filled),
+        minutes,
+        goal_minutes
+    )
+}
+
+// Tab labels with the symbols that stand in for them when the labels don't fit
+const TABS: [(&str, &str); 12] = [
+    ("Dashboard", "\u{2302}"),
+    ("Feeds", "\u{2261}"),
+    ("Items", "\u{2630}"),
+    ("Detail", "\u{00b6}"),
+    ("Categories", "#"),
+    ("Starred", "\u{2605}"),
+    ("What's New", "\u{2726}"),
+    ("Alerts", "!"),
+    ("Triage", "\u{21c5}"),
+    ("Read Later", "\u{25f7}"),
+    ("Scheduler", "\u{21bb}"),
+    ("Releases", "\u{2691}"),
+];
+
+// Tab titles that fit in `width` columns and which of them is selected: the labels, or
+// failing that the symbols with only the current tab's label spelled out, or on the
+// narrowest terminals just the current tab
+fn tab_titles(selected_tab: usize, width: u16) -> (Vec<String>, usize) {
+    // Each tab takes its title, a selection marker and a space either side, plus a divider;
+    // the title bar's borders and padding take another six columns
+    let fits = |titles: &[String]| {
+        titles.iter().map(|t| t.width() + 5).sum::<usize>() + 5 <= width as usize
+    };
+    let labels: Vec<String> = TABS.iter().map(|(label, _)| label.to_string()).collect();
+    if fits(&labels) {
+        return (labels, selected_tab);
+    }
+    let (label, icon) = TABS[selected_tab];
+    let icons: Vec<String> = TABS
+        .iter()
+        .enumerate()
+        .map(|(i, (_, icon))| {
+            if i == selected_tab {
+                format!("{} {}", icon, label)
+            } else {
+                icon.to_string()
+            }
+        })
+        .collect();
+    if fits(&icons) {
+        (icons, selected_tab)
+    } else {
+        (vec![format!("{} {}", icon, label)], 0)
     }
 }
 
 fn render_title_bar<B: Backend>(f: &mut Frame<B>, app: &App, area: Rect, colors: &ColorScheme) {
-    // Create tabs for navigation
-    let titles = [
-        "Dashboard",
-        "Feeds",
-        "Items",
-        "Detail",
-        "Categories",
-        "Starred",
-        "What's New",
-    ];
     let selected_tab = match app.view {
         View::Dashboard => 0,
         View::FeedList => 1,
@@ -376,6 +599,11 @@ fn render_title_bar<B: Backend>(f: &mut Frame<B>, app: &App, area: Rect, colors:
         View::CategoryManagement => 4,
         View::Starred => 5,
         View::Summary => 6,
+        View::Alerts => 7,
+        View::Triage => 8,
+        View::ReadLater => 9,
+        View::Scheduler => 10,
+        View::Releases => 11,
     };
 
     // Theme-specific loading animation
@@ -391,8 +619,65 @@ fn render_title_bar<B: Backend>(f: &mut Frame<B>, app: &App, area: Rect, colors:
         format!(" {} Feedr ", colors.get_icon_dashboard())
     };
 
+    let mut block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(if app.is_loading {
+            colors.border_active
+        } else {
+            colors.border_normal
+        })
+        .border_style(Style::default().fg(if app.is_loading {
+            colors.highlight
+        } else {
+            colors.border
+        }))
+        .title(title)
+        .title_alignment(Alignment::Center)
+        .padding(Padding::new(2, 2, 0, 0));
+    if app.config.ui.reading_goal > 0 {
+        block = block.title(
+            Title::from(Span::styled(
+                reading_goal_text(app.reading_time_today(), app.config.ui.reading_goal),
+                Style::default().fg(colors.muted),
+            ))
+            .alignment(Alignment::Left),
+        );
+    }
+    if app.macro_recording.is_some() {
+        block = block.title(
+            Title::from(Span::styled(
+                " \u{25cf} REC ",
+                Style::default()
+                    .fg(colors.error)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .alignment(Alignment::Left),
+        );
+    }
+    if crate::globals::read_only() {
+        block = block.title(
+            Title::from(Span::styled(
+                " READ-ONLY ",
+                Style::default()
+                    .fg(colors.accent)
+                    .add_modifier(Modifier::BOLD),
+            ))
+            .alignment(Alignment::Left),
+        );
+    }
+    if let Some(status) = app.status_strip_text() {
+        block = block.title(
+            Title::from(Span::styled(
+                format!(" {} ", utils::truncate_str(&status, STATUS_STRIP_WIDTH)),
+                Style::default().fg(colors.text_secondary),
+            ))
+            .alignment(Alignment::Right),
+        );
+    }
+
     // Create tab highlight effect with theme-specific indicators
     let selection_indicator = colors.get_selection_indicator();
+    let (titles, selected_tab) = tab_titles(selected_tab, area.width);
     let tabs = Tabs::new(
         titles
             .iter()
@@ -416,23 +701,7 @@ fn render_title_bar<B: Backend>(f: &mut Frame<B>, app: &App, area: Rect, colors:
             })
             .collect(),
     )
-    .block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_type(if app.is_loading {
-                colors.border_active
-            } else {
-                colors.border_normal
-            })
-            .border_style(Style::default().fg(if app.is_loading {
-                colors.highlight
-            } else {
-                colors.border
-            }))
-            .title(title)
-            .title_alignment(Alignment::Center)
-            .padding(Padding::new(2, 2, 0, 0)),
-    )
+    .block(block)
     .style(
         Style::default()
             .fg(colors.text_secondary)
@@ -458,6 +727,11 @@ fn render_compact_title_bar<B: Backend>(
         View::CategoryManagement => "Categories",
         View::Starred => "Starred",
         View::Summary => "What's New",
+        View::Alerts => "Alerts",
+        View::Triage => "Triage",
+        View::ReadLater => "Read Later",
+        View::Scheduler => "Scheduler",
+        View::Releases => "Releases",
     };
 
     let title = if app.is_loading {
@@ -480,174 +754,41 @@ fn render_compact_title_bar<B: Backend>(
     )]))
     .style(Style::default().bg(colors.surface));
     f.render_widget(bar, area);
+
+    if let Some(status) = app.status_strip_text() {
+        let status = Paragraph::new(Span::styled(
+            format!("{} ", utils::truncate_str(&status, STATUS_STRIP_WIDTH)),
+            Style::default().fg(colors.text_secondary),
+        ))
+        .alignment(Alignment::Right);
+        f.render_widget(status, area);
+    }
 }
 
 fn render_help_bar<B: Backend>(f: &mut Frame<B>, app: &App, area: Rect, colors: &ColorScheme) {
-    // Match on the input mode and view to determine the help text and style
-    let (help_text, _style) = match app.input_mode {
-        InputMode::Normal => {
-            let help_text = match app.view {
-                View::Dashboard => {
-                    if app.feeds.is_empty() {
-                        format!(
-                            "{}: Add feed | {}: Theme | {}: Quit | {}: Quit | CTRL+C: Manage categories",
-                            key_display(&KeyAction::AddFeed, &app.keybindings),
-                            key_display(&KeyAction::ToggleTheme, &app.keybindings),
-                            key_display(&KeyAction::Quit, &app.keybindings),
-                            key_display(&KeyAction::ForceQuit, &app.keybindings),
-                        )
-                    } else {
-                        format!(
-                            "{}/{}: Navigate | {}: View | {}: Star | {}: Toggle read | {}: Mark all read | {}: Preview | {}: Add | {}: Refresh | {}: Filter | {}: Search | {}: Help | {}: Quit",
-                            key_display(&KeyAction::MoveUp, &app.keybindings),
-                            key_display(&KeyAction::MoveDown, &app.keybindings),
-                            key_display(&KeyAction::Select, &app.keybindings),
-                            key_display(&KeyAction::ToggleStar, &app.keybindings),
-                            key_display(&KeyAction::ToggleRead, &app.keybindings),
-                            key_display(&KeyAction::MarkAllRead, &app.keybindings),
-                            key_display(&KeyAction::TogglePreview, &app.keybindings),
-                            key_display(&KeyAction::AddFeed, &app.keybindings),
-                            key_display(&KeyAction::Refresh, &app.keybindings),
-                            key_display(&KeyAction::OpenFilter, &app.keybindings),
-                            key_display(&KeyAction::OpenSearch, &app.keybindings),
-                            key_display(&KeyAction::Help, &app.keybindings),
-                            key_display(&KeyAction::Quit, &app.keybindings),
-                        )
-                    }
-                }
-                View::FeedList => {
-                    if app.feeds.is_empty() && app.categories.is_empty() {
-                        format!(
-                            "{}: Add feed | {}: Theme | {}: Back | {}: Quit | TAB: Dashboard | CTRL+C: Categories",
-                            key_display(&KeyAction::AddFeed, &app.keybindings),
-                            key_display(&KeyAction::ToggleTheme, &app.keybindings),
-                            key_display(&KeyAction::Quit, &app.keybindings),
-                            key_display(&KeyAction::ForceQuit, &app.keybindings),
-                        )
-                    } else {
-                        format!(
-                            "{}/{}: Navigate | {}: Open | Space: Expand/Collapse | d: Delete | c: Category | {}: Mark read | {}: Add | {}: Help | {}: Back",
-                            key_display(&KeyAction::MoveUp, &app.keybindings),
-                            key_display(&KeyAction::MoveDown, &app.keybindings),
-                            key_display(&KeyAction::Select, &app.keybindings),
-                            key_display(&KeyAction::MarkAllRead, &app.keybindings),
-                            key_display(&KeyAction::AddFeed, &app.keybindings),
-                            key_display(&KeyAction::Help, &app.keybindings),
-                            key_display(&KeyAction::Quit, &app.keybindings),
-                        )
-                    }
-                }
-                View::CategoryManagement => {
-                    format!(
-                        "n: New category | e: Edit | d: Delete | {}: Toggle feeds | c: Add selected feed | {}: Theme | ESC/{}: Back",
-                        key_display(&KeyAction::ToggleExpand, &app.keybindings),
-                        key_display(&KeyAction::ToggleTheme, &app.keybindings),
-                        key_display(&KeyAction::Quit, &app.keybindings),
-                    )
-                }
-                View::FeedItems => {
-                    format!(
-                        "{}/{}: Navigate | {}: View | {}: Star | {}: Toggle read | {}: Mark all read | {}: Open | {}: Search | {}: Theme | {}: Back | {}: Quit",
-                        key_display(&KeyAction::MoveUp, &app.keybindings),
-                        key_display(&KeyAction::MoveDown, &app.keybindings),
-                        key_display(&KeyAction::Select, &app.keybindings),
-                        key_display(&KeyAction::ToggleStar, &app.keybindings),
-                        key_display(&KeyAction::ToggleRead, &app.keybindings),
-                        key_display(&KeyAction::MarkAllRead, &app.keybindings),
-                        key_display(&KeyAction::OpenInBrowser, &app.keybindings),
-                        key_display(&KeyAction::OpenSearch, &app.keybindings),
-                        key_display(&KeyAction::ToggleTheme, &app.keybindings),
-                        key_display(&KeyAction::Back, &app.keybindings),
-                        key_display(&KeyAction::ForceQuit, &app.keybindings),
-                    )
-                }
-                View::FeedItemDetail => {
-                    format!(
-                        "{}/{}: Scroll | {}/{}: Fast scroll | {}: Open | {}: Star | {}: Toggle read | {}: Links | {}: Search | {}: Theme | {}: Back | {}: Quit",
-                        key_display(&KeyAction::MoveUp, &app.keybindings),
-                        key_display(&KeyAction::MoveDown, &app.keybindings),
-                        key_display(&KeyAction::PageUp, &app.keybindings),
-                        key_display(&KeyAction::PageDown, &app.keybindings),
-                        key_display(&KeyAction::OpenInBrowser, &app.keybindings),
-                        key_display(&KeyAction::ToggleStar, &app.keybindings),
-                        key_display(&KeyAction::ToggleRead, &app.keybindings),
-                        key_display(&KeyAction::ExtractLinks, &app.keybindings),
-                        key_display(&KeyAction::OpenSearch, &app.keybindings),
-                        key_display(&KeyAction::ToggleTheme, &app.keybindings),
-                        key_display(&KeyAction::Back, &app.keybindings),
-                        key_display(&KeyAction::ForceQuit, &app.keybindings),
-                    )
-                }
-                View::Starred => {
-                    format!(
-                        "{}/{}: Navigate | {}: View | {}: Unstar | {}: Toggle read | {}: Mark all read | {}: Open | {}: Search | {}: Back | {}: Quit",
-                        key_display(&KeyAction::MoveUp, &app.keybindings),
-                        key_display(&KeyAction::MoveDown, &app.keybindings),
-                        key_display(&KeyAction::Select, &app.keybindings),
-                        key_display(&KeyAction::ToggleStar, &app.keybindings),
-                        key_display(&KeyAction::ToggleRead, &app.keybindings),
-                        key_display(&KeyAction::MarkAllRead, &app.keybindings),
-                        key_display(&KeyAction::OpenInBrowser, &app.keybindings),
-                        key_display(&KeyAction::OpenSearch, &app.keybindings),
-                        key_display(&KeyAction::Quit, &app.keybindings),
-                        key_display(&KeyAction::ForceQuit, &app.keybindings),
-                    )
-                }
-                View::Summary => {
-                    format!(
-                        "Press any key to continue to Dashboard | {}: Back | {}: Quit",
-                        key_display(&KeyAction::Quit, &app.keybindings),
-                        key_display(&KeyAction::ForceQuit, &app.keybindings),
-                    )
-                }
-            };
-            (help_text, Style::default().fg(colors.text))
-        }
-        InputMode::InsertUrl => (
-            "Enter feed URL (e.g., https://news.ycombinator.com/rss)".to_string(),
-            Style::default().fg(colors.highlight),
-        ),
-        InputMode::SearchMode => (
-            "Type to search (results update live) | ENTER: keep results | ESC: cancel".to_string(),
-            Style::default().fg(colors.highlight),
-        ),
-        InputMode::FilterMode => ("".to_string(), Style::default().fg(colors.muted)),
-        InputMode::CategoryNameInput => ("".to_string(), Style::default().fg(colors.muted)),
-        InputMode::SelectDiscoveredFeed => (
-            "j/k: Navigate | Enter: Select feed | Esc: Cancel".to_string(),
-            Style::default().fg(colors.highlight),
-        ),
-    };
-
     // Only show help bar in normal mode
     if matches!(app.input_mode, InputMode::Normal) {
         // Create a stylized help bar with visually separated commands
-        let parts: Vec<&str> = help_text.split('|').collect();
+        let hints = help_bar::hints(app);
         let mut spans = Vec::new();
 
-        for (idx, part) in parts.iter().enumerate() {
-            let trimmed = part.trim();
-
-            // Extract the command key and description
-            if let Some(pos) = trimmed.find(':') {
-                let (key, desc) = trimmed.split_at(pos + 1);
-
-                // Add the key in highlight color
-                spans.push(Span::styled(
-                    key,
-                    Style::default()
-                        .fg(colors.highlight)
-                        .add_modifier(Modifier::BOLD),
-                ));
-
-                // Add the description in normal text color
-                spans.push(Span::styled(desc, Style::default().fg(colors.text)));
-            } else {
-                spans.push(Span::styled(trimmed, Style::default().fg(colors.text)));
-            }
+        for (idx, hint) in hints.iter().enumerate() {
+            // Add the key in highlight color
+            spans.push(Span::styled(
+                format!("{}:", hint.keys),
+                Style::default()
+                    .fg(colors.highlight)
+                    .add_modifier(Modifier::BOLD),
+            ));
+
+            // Add the description in normal text color
+            spans.push(Span::styled(
+                format!(" {}", hint.label),
+                Style::default().fg(colors.text),
+            ));
 
             // Add separator unless this is the last item
-            if idx < parts.len() - 1 {
+            if idx < hints.len() - 1 {
                 spans.push(Span::styled(" | ", Style::default().fg(colors.border)));
             }
         }
@@ -684,76 +825,23 @@ fn render_compact_help_bar<B: Backend>(
         return;
     }
 
-    let kd = |action: &KeyAction| key_display(action, &app.keybindings);
-    let help_text = match app.view {
-        View::Dashboard => format!(
-            "{}:quit {}:add {}:refresh {}:search {}:filter {}:preview {}:help",
-            kd(&KeyAction::Quit),
-            kd(&KeyAction::AddFeed),
-            kd(&KeyAction::Refresh),
-            kd(&KeyAction::OpenSearch),
-            kd(&KeyAction::OpenFilter),
-            kd(&KeyAction::TogglePreview),
-            kd(&KeyAction::Help),
-        ),
-        View::FeedList => format!(
-            "{}:back {}:add {}:open {}:expand d:del c:category {}:read",
-            kd(&KeyAction::Quit),
-            kd(&KeyAction::AddFeed),
-            kd(&KeyAction::Select),
-            kd(&KeyAction::ToggleExpand),
-            kd(&KeyAction::MarkAllRead),
-        ),
-        View::FeedItems => format!(
-            "{}:back {}:view {}:open {}:star {}:search",
-            kd(&KeyAction::Quit),
-            kd(&KeyAction::Select),
-            kd(&KeyAction::OpenInBrowser),
-            kd(&KeyAction::ToggleStar),
-            kd(&KeyAction::OpenSearch),
-        ),
-        View::FeedItemDetail => format!(
-            "{}:back {}:scroll {}:open {}:star {}:read",
-            kd(&KeyAction::Quit),
-            kd(&KeyAction::MoveDown),
-            kd(&KeyAction::OpenInBrowser),
-            kd(&KeyAction::ToggleStar),
-            kd(&KeyAction::ToggleRead),
-        ),
-        View::CategoryManagement => format!("{}:back n:new e:edit d:del", kd(&KeyAction::Quit),),
-        View::Starred => format!(
-            "{}:back {}:view {}:unstar {}:open",
-            kd(&KeyAction::Quit),
-            kd(&KeyAction::Select),
-            kd(&KeyAction::ToggleStar),
-            kd(&KeyAction::OpenInBrowser),
-        ),
-        View::Summary => "any key:continue".to_string(),
-    };
-
-    let spans: Vec<Span> = help_text
-        .split(' ')
-        .enumerate()
-        .flat_map(|(i, part)| {
-            let mut result = Vec::new();
-            if i > 0 {
-                result.push(Span::styled(" ", Style::default().fg(colors.border)));
-            }
-            if let Some(pos) = part.find(':') {
-                let (key, desc) = part.split_at(pos + 1);
-                result.push(Span::styled(
-                    key,
-                    Style::default()
-                        .fg(colors.highlight)
-                        .add_modifier(Modifier::BOLD),
-                ));
-                result.push(Span::styled(desc, Style::default().fg(colors.text)));
-            } else {
-                result.push(Span::styled(part, Style::default().fg(colors.text)));
-            }
-            result
-        })
+    let hints: Vec<_> = help_bar::hints(app)
+        .into_iter()
+        .filter_map(|hint| Some((hint.keys, hint.short?)))
         .collect();
+    let mut spans = Vec::new();
+    for (idx, (keys, short)) in hints.iter().enumerate() {
+        if idx > 0 {
+            spans.push(Span::styled(" ", Style::default().fg(colors.border)));
+        }
+        spans.push(Span::styled(
+            format!("{}:", keys),
+            Style::default()
+                .fg(colors.highlight)
+                .add_modifier(Modifier::BOLD),
+        ));
+        spans.push(Span::styled(*short, Style::default().fg(colors.text)));
+    }
 
     let help = Paragraph::new(Line::from(spans))
         .alignment(Alignment::Center)
@@ -1,6 +1,11 @@
-use crate::app::{App, InputMode, LinkType, TimeFilter, View};
+use crate::app::{
+    App, ContextMenu, FeedChange, ImportReport, InputMode, LinkType, MenuCommand, TimeFilter,
+    ToolOutput, View,
+};
+use crate::feed::{format_date, language_name};
 use crate::keybindings::{key_display, KeyAction};
-use crate::ui::utils::{centered_rect_with_min, truncate_str};
+use crate::tour::{step_text, TourRegion, STEPS};
+use crate::ui::utils::{centered_rect_with_min, count_wrapped_lines, item_date, truncate_str};
 use crate::ui::ColorScheme;
 use ratatui::{
     backend::Backend,
@@ -105,9 +110,20 @@ pub(super) fn render_input_modal<B: Backend>(f: &mut Frame<B>, app: &App, colors
         };
         (
             "Add Feed URL",
-            "Enter the RSS feed URL and press Enter".to_string(),
+            "Enter the RSS feed URL and press Enter, or paste several to add them all".to_string(),
             link_icon,
         )
+    } else if let Some(input) = &app.path_input {
+        let title = match app.input_mode {
+            InputMode::ImportOpmlPath => "Import OPML File",
+            InputMode::ExportOpmlPath => "Export Subscriptions as OPML",
+            _ => "Export Keybindings as Markdown",
+        };
+        let help = match input.validate() {
+            Err(reason) if !input.text.trim().is_empty() => reason,
+            _ => "Tab completes · \u{2191}/\u{2193} recent paths · ~ is your home".to_string(),
+        };
+        (title, help, "\u{2192}")
     } else {
         let result_count = app.filtered_items.len();
         let search_help = if app.input.is_empty() {
@@ -136,10 +152,18 @@ pub(super) fn render_input_modal<B: Backend>(f: &mut Frame<B>, app: &App, colors
     // Add separator
     lines.push(Line::from(""));
 
-    // Add help text
+    // Add help text, or what's wrong with the path typed so far
+    let path_invalid = app
+        .path_input
+        .as_ref()
+        .is_some_and(|input| !input.text.trim().is_empty() && input.validate().is_err());
     lines.push(Line::from(vec![Span::styled(
         help_text.clone(),
-        Style::default().fg(colors.text_secondary),
+        Style::default().fg(if path_invalid {
+            colors.error
+        } else {
+            colors.text_secondary
+        }),
     )]));
 
     // Add spacers
@@ -175,7 +199,16 @@ pub(super) fn render_input_modal<B: Backend>(f: &mut Frame<B>, app: &App, colors
     );
     f.render_widget(modal_paragraph, area);
 
-    let input_paragraph = Paragraph::new(app.input.as_str())
+    // Long paths show their end, where the typing happens
+    let input = app
+        .path_input
+        .as_ref()
+        .map_or(app.input.as_str(), |input| input.text.as_str());
+    let width = area.width.saturating_sub(9) as usize;
+    let skip = input.chars().count().saturating_sub(width);
+    let input: String = input.chars().skip(skip).collect();
+
+    let input_paragraph = Paragraph::new(input.as_str())
         .block(Block::default().borders(Borders::NONE))
         .style(
             Style::default()
@@ -188,7 +221,7 @@ pub(super) fn render_input_modal<B: Backend>(f: &mut Frame<B>, app: &App, colors
 
     f.render_widget(input_paragraph, input_rect);
 
-    let cursor_x = input_rect.x + app.input.chars().count() as u16;
+    let cursor_x = input_rect.x + input.chars().count() as u16;
     f.set_cursor(cursor_x.min(input_rect.x + input_rect.width), input_rect.y);
 }
 
@@ -333,6 +366,98 @@ pub(super) fn render_feed_selection_modal<B: Backend>(
     f.render_widget(modal, area);
 }
 
+/// A fetched feed shown before subscribing: its title, description and latest items,
+/// and the category it will be filed under.
+pub(super) fn render_feed_preview_modal<B: Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    colors: &ColorScheme,
+) {
+    let Some(preview) = &app.feed_preview else {
+        return;
+    };
+    let feed = &preview.feed;
+    let area = centered_rect_with_min(70, 60, 50, 20, f.size());
+    f.render_widget(Clear, area);
+
+    let width = area.width.saturating_sub(8) as usize;
+    let muted = Style::default().fg(colors.text_secondary);
+    let key = Style::default()
+        .fg(colors.primary)
+        .add_modifier(Modifier::BOLD);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            truncate_str(&feed.title, width),
+            Style::default()
+                .fg(colors.text)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(truncate_str(&preview.url, width), muted)),
+        Line::from(""),
+    ];
+    if let Some(description) = &feed.description {
+        lines.push(Line::from(Span::styled(
+            truncate_str(&description.replace('\n', " "), width),
+            Style::default().fg(colors.text),
+        )));
+        lines.push(Line::from(""));
+    }
+
+    lines.push(Line::from(Span::styled(
+        format!("Latest of {} items", feed.items.len()),
+        Style::default()
+            .fg(colors.highlight)
+            .add_modifier(Modifier::BOLD),
+    )));
+    for item in feed.items.iter().take(5) {
+        let date = item.formatted_date.as_deref().unwrap_or("");
+        let title_width = width.saturating_sub(date.chars().count() + 4);
+        lines.push(Line::from(vec![
+            Span::styled("  • ", muted),
+            Span::styled(
+                truncate_str(&item.title, title_width),
+                Style::default().fg(colors.text),
+            ),
+            Span::styled(format!("  {}", date), muted),
+        ]));
+    }
+    if feed.items.is_empty() {
+        lines.push(Line::from(Span::styled("  No items yet", muted)));
+    }
+
+    let category = preview
+        .category
+        .and_then(|idx| app.categories.get(idx))
+        .map_or("none", |c| c.name.as_str());
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("Category: ", muted),
+        Span::styled(category, Style::default().fg(colors.text)),
+    ]));
+    lines.push(Line::from(""));
+    lines.push(Line::from(vec![
+        Span::styled("Enter", key),
+        Span::styled(" subscribe · ", muted),
+        Span::styled("c", key),
+        Span::styled(" category · ", muted),
+        Span::styled("Esc", key),
+        Span::styled(" cancel", muted),
+    ]));
+
+    let modal = Paragraph::new(lines).block(
+        Block::default()
+            .title(" Preview Feed ")
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL)
+            .border_type(colors.border_focus_type)
+            .border_style(Style::default().fg(colors.border_focus))
+            .style(Style::default().bg(colors.surface))
+            .padding(Padding::new(3, 3, 1, 1)),
+    );
+    f.render_widget(modal, area);
+}
+
 pub(super) fn render_filter_modal<B: Backend>(f: &mut Frame<B>, app: &App, colors: &ColorScheme) {
     let area = centered_rect_with_min(70, 60, 50, 18, f.size());
 
@@ -493,6 +618,59 @@ pub(super) fn render_filter_modal<B: Backend>(f: &mut Frame<B>, app: &App, color
         ),
     ]));
 
+    // Language filter
+    let language_status = match app.filter_options.language {
+        Some(code) => format!("[{}]", language_name(code)),
+        None => "[Off]".to_string(),
+    };
+    let available_languages = app.get_available_languages();
+
+    text.push(Line::from(vec![
+        Span::styled("  g - Language: ", Style::default().fg(colors.text)),
+        Span::styled(
+            language_status,
+            Style::default().fg(if app.filter_options.language.is_some() {
+                colors.highlight
+            } else {
+                colors.muted
+            }),
+        ),
+        Span::styled(
+            if !available_languages.is_empty() {
+                format!(" ({})", available_languages.join(", "))
+            } else {
+                "".to_string()
+            },
+            Style::default().fg(colors.muted),
+        ),
+    ]));
+
+    // Tag filter
+    let available_tags = app.get_available_tags();
+    text.push(Line::from(vec![
+        Span::styled("  # - Tag: ", Style::default().fg(colors.text)),
+        Span::styled(
+            match &app.filter_options.tag {
+                Some(tag) => format!("[{}]", tag),
+                None => "[Off]".to_string(),
+            },
+            Style::default().fg(if app.filter_options.tag.is_some() {
+                colors.highlight
+            } else {
+                colors.muted
+            }),
+        ),
+        Span::styled(
+            if !available_tags.is_empty() {
+                let shown: Vec<&str> = available_tags.iter().take(5).map(String::as_str).collect();
+                format!(" ({})", shown.join(", "))
+            } else {
+                "".to_string()
+            },
+            Style::default().fg(colors.muted),
+        ),
+    ]));
+
     // Clear filters option
     text.push(Line::from(""));
     text.push(Line::from(vec![
@@ -508,7 +686,7 @@ pub(super) fn render_filter_modal<B: Backend>(f: &mut Frame<B>, app: &App, color
 
     text.push(Line::from(vec![Span::styled(
         format!(
-            "  Active Filters: {}/6  |  Showing: {}/{} items",
+            "  Active Filters: {}/8  |  Showing: {}/{} items",
             active_count, filtered_count, total_count
         ),
         Style::default().fg(colors.muted),
@@ -586,6 +764,16 @@ pub(super) fn render_help_overlay<B: Backend>(f: &mut Frame<B>, app: &App, color
     lines.push(Line::from(""));
     add_key(&kd(&KeyAction::ForceQuit), "Quit from any view", &mut lines);
     add_key(&kd(&KeyAction::Help), "Show this help", &mut lines);
+    add_key(
+        &kd(&KeyAction::Tour),
+        "Replay the onboarding tour",
+        &mut lines,
+    );
+    add_key(
+        &kd(&KeyAction::ExportKeys),
+        "Export all keybindings as Markdown",
+        &mut lines,
+    );
     add_key(
         &kd(&KeyAction::ToggleTheme),
         "Toggle theme (dark/light)",
@@ -594,6 +782,50 @@ pub(super) fn render_help_overlay<B: Backend>(f: &mut Frame<B>, app: &App, color
     add_key(&kd(&KeyAction::Refresh), "Refresh all feeds", &mut lines);
     add_key(&kd(&KeyAction::NextTab), "Next view", &mut lines);
     add_key(&kd(&KeyAction::PrevTab), "Previous view", &mut lines);
+    add_key(
+        &kd(&KeyAction::HistoryBack),
+        "Back to the previous place",
+        &mut lines,
+    );
+    add_key(
+        &kd(&KeyAction::HistoryForward),
+        "Forward again after going back",
+        &mut lines,
+    );
+    add_key(
+        &kd(&KeyAction::ExplainItem),
+        "Why is the selected item here?",
+        &mut lines,
+    );
+    add_key(
+        &kd(&KeyAction::OpenComments),
+        "Open the selected item's comments",
+        &mut lines,
+    );
+    add_key(
+        &kd(&KeyAction::FullTitle),
+        "Full title and snippet of the selected item",
+        &mut lines,
+    );
+    add_key(
+        &kd(&KeyAction::ContextMenu),
+        "Everything you can do with the selected item",
+        &mut lines,
+    );
+    add_key(
+        &kd(&KeyAction::RecordMacro),
+        if app.macro_recording.is_some() {
+            "Stop recording the macro"
+        } else {
+            "Record a macro"
+        },
+        &mut lines,
+    );
+    add_key(
+        &kd(&KeyAction::PlayMacro),
+        "Replay the recorded macro",
+        &mut lines,
+    );
     lines.push(Line::from(""));
     lines.push(separator.clone());
     lines.push(Line::from(""));
@@ -611,11 +843,77 @@ pub(super) fn render_help_overlay<B: Backend>(f: &mut Frame<B>, app: &App, color
                 &mut lines,
             );
             add_key(&kd(&KeyAction::Select), "View article detail", &mut lines);
+            add_key(
+                &kd(&KeyAction::ShowFailedFeeds),
+                "Feeds that failed to load (retry / dismiss)",
+                &mut lines,
+            );
+            if !app.dashboard_sections.is_empty() {
+                add_key(
+                    &format!(
+                        "{}/{}",
+                        kd(&KeyAction::NextSection),
+                        kd(&KeyAction::PrevSection)
+                    ),
+                    "Focus next/previous section",
+                    &mut lines,
+                );
+            }
+            add_key(
+                &kd(&KeyAction::Triage),
+                "Triage unread items one at a time",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::CatchUp),
+                "Open a random unread article",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::ReadingSession),
+                "Start a time-boxed reading session",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::OpenReadLater),
+                "Show the read-later queue",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::OpenAlerts),
+                "Show keyword alerts",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::OpenScheduler),
+                "Show the refresh schedule",
+                &mut lines,
+            );
             add_key(
                 &kd(&KeyAction::OpenInBrowser),
                 "Open in browser",
                 &mut lines,
             );
+            add_key(
+                &kd(&KeyAction::Share),
+                "Share (copy or run command)",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::ShareWebhook),
+                "Post to a chat webhook",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::MakeTask),
+                "Make a task (tools.task_command)",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::ExportEvent),
+                "Save announced event as .ics",
+                &mut lines,
+            );
             add_key(
                 &kd(&KeyAction::ToggleRead),
                 "Toggle read/unread",
@@ -633,6 +931,11 @@ pub(super) fn render_help_overlay<B: Backend>(f: &mut Frame<B>, app: &App, color
             );
             add_key(&kd(&KeyAction::AddFeed), "Add new feed", &mut lines);
             add_key(&kd(&KeyAction::OpenFilter), "Open filter menu", &mut lines);
+            add_key(
+                &kd(&KeyAction::ToggleItemSort),
+                "Toggle newest/oldest first",
+                &mut lines,
+            );
             add_key(
                 &kd(&KeyAction::MarkAllRead),
                 "Mark all visible as read",
@@ -681,6 +984,21 @@ pub(super) fn render_help_overlay<B: Backend>(f: &mut Frame<B>, app: &App, color
                 "Mark feed/category as read",
                 &mut lines,
             );
+            add_key(
+                &kd(&KeyAction::CycleFeedSort),
+                "Cycle sort (added, A-Z, unread, recent)",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::ToggleMute),
+                "Mute/unmute feed (hide from dashboard)",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::ArchiveFeed),
+                "Archive feed (stop fetching, keep items) / resubscribe",
+                &mut lines,
+            );
             add_key(
                 &kd(&KeyAction::AssignCategory),
                 "Assign feed to category",
@@ -691,6 +1009,18 @@ pub(super) fn render_help_overlay<B: Backend>(f: &mut Frame<B>, app: &App, color
                 "Manage categories",
                 &mut lines,
             );
+            add_key(
+                &kd(&KeyAction::ImportOpml),
+                "Import feeds from OPML",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::ExportOpml),
+                "Export subscriptions as OPML",
+                &mut lines,
+            );
+            add_key(&kd(&KeyAction::Undo), "Undo category/feed edit", &mut lines);
+            add_key(&kd(&KeyAction::Redo), "Redo", &mut lines);
             add_key(&kd(&KeyAction::OpenSearch), "Search", &mut lines);
             add_key(&kd(&KeyAction::Quit), "Back to Dashboard", &mut lines);
         }
@@ -705,6 +1035,26 @@ pub(super) fn render_help_overlay<B: Backend>(f: &mut Frame<B>, app: &App, color
                 "Open in browser",
                 &mut lines,
             );
+            add_key(
+                &kd(&KeyAction::Share),
+                "Share (copy or run command)",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::ShareWebhook),
+                "Post to a chat webhook",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::MakeTask),
+                "Make a task (tools.task_command)",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::ExportEvent),
+                "Save announced event as .ics",
+                &mut lines,
+            );
             add_key(
                 &kd(&KeyAction::ToggleRead),
                 "Toggle read/unread",
@@ -712,6 +1062,33 @@ pub(super) fn render_help_overlay<B: Backend>(f: &mut Frame<B>, app: &App, color
             );
             add_key(&kd(&KeyAction::ToggleStar), "Star/unstar", &mut lines);
             add_key(&kd(&KeyAction::MarkAllRead), "Mark all as read", &mut lines);
+            add_key(&kd(&KeyAction::OpenFilter), "Filter this feed", &mut lines);
+            add_key(
+                &kd(&KeyAction::ToggleItemSort),
+                "Toggle newest/oldest first",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::RefreshFeed),
+                "Refresh this feed",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::AssignCategory),
+                "Assign feed to category",
+                &mut lines,
+            );
+            add_key(&kd(&KeyAction::FeedInfo), "Feed info", &mut lines);
+            add_key(
+                &kd(&KeyAction::ReleaseTimeline),
+                "Release timeline (release feeds)",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::ArchiveFeed),
+                "Archive feed / resubscribe",
+                &mut lines,
+            );
             add_key(&kd(&KeyAction::OpenSearch), "Search", &mut lines);
             add_key(&kd(&KeyAction::Back), "Back to Feeds", &mut lines);
             add_key(&kd(&KeyAction::Home), "Back to Dashboard", &mut lines);
@@ -739,6 +1116,43 @@ pub(super) fn render_help_overlay<B: Backend>(f: &mut Frame<B>, app: &App, color
                 "Open in browser",
                 &mut lines,
             );
+            add_key(
+                &kd(&KeyAction::Share),
+                "Share (copy or run command)",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::ShareWebhook),
+                "Post to a chat webhook",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::MakeTask),
+                "Make a task (tools.task_command)",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::ExportEvent),
+                "Save announced event as .ics",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::OpenImage),
+                "Open image in viewer",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::CatchUp),
+                "Next random unread article",
+                &mut lines,
+            );
+            if app.reading_session.is_some() {
+                add_key(
+                    &kd(&KeyAction::Select),
+                    "Next article in the session",
+                    &mut lines,
+                );
+            }
             add_key(
                 &kd(&KeyAction::ToggleRead),
                 "Toggle read/unread",
@@ -750,6 +1164,56 @@ pub(super) fn render_help_overlay<B: Backend>(f: &mut Frame<B>, app: &App, color
                 "Extract links/images",
                 &mut lines,
             );
+            add_key(
+                &kd(&KeyAction::JumpFootnote),
+                "Jump between footnote reference and note",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::Summarize),
+                "Summarize article (tools.summarize_command)",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::Translate),
+                "Toggle translation (tools.translate_command)",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::ViewSource),
+                "Cycle text / HTML source / feed entry",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::ConfirmTags),
+                "Confirm suggested tags / undo",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::MuteMenu),
+                "Mute/unmute author or domain",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::ExportArticle),
+                "Export as HTML/PDF (tools.export_dir)",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::ToggleWrap),
+                "Toggle wrap (Left/Right scroll when off)",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::ZenMode),
+                "Zen mode (article only, ui.reading_width)",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::CopyMode),
+                "Copy mode (v/Ctrl+v select, y yank)",
+                &mut lines,
+            );
             add_key(
                 &kd(&KeyAction::OpenSearch),
                 "Search across all feeds",
@@ -770,6 +1234,26 @@ pub(super) fn render_help_overlay<B: Backend>(f: &mut Frame<B>, app: &App, color
                 "Open in browser",
                 &mut lines,
             );
+            add_key(
+                &kd(&KeyAction::Share),
+                "Share (copy or run command)",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::ShareWebhook),
+                "Post to a chat webhook",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::MakeTask),
+                "Make a task (tools.task_command)",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::ExportEvent),
+                "Save announced event as .ics",
+                &mut lines,
+            );
             add_key(
                 &kd(&KeyAction::ToggleRead),
                 "Toggle read/unread",
@@ -792,11 +1276,109 @@ pub(super) fn render_help_overlay<B: Backend>(f: &mut Frame<B>, app: &App, color
             add_key("n", "Create new category", &mut lines);
             add_key("e", "Rename category", &mut lines);
             add_key("d", "Delete category", &mut lines);
+            add_key(&kd(&KeyAction::Undo), "Undo category/feed edit", &mut lines);
+            add_key(&kd(&KeyAction::Redo), "Redo", &mut lines);
             add_key("Space", "Expand/collapse", &mut lines);
             add_key("Enter", "Assign feed (when adding)", &mut lines);
             add_key("q/Esc", "Back to Feeds", &mut lines);
         }
-        View::Summary => {
+        View::ReadLater => {
+            lines.push(Line::from(Span::styled("  Read Later", section_style)));
+            lines.push(Line::from(""));
+            add_key(&kd(&KeyAction::MoveUp), "Navigate up", &mut lines);
+            add_key(&kd(&KeyAction::MoveDown), "Navigate down", &mut lines);
+            add_key(&kd(&KeyAction::Select), "Read article", &mut lines);
+            add_key(
+                &kd(&KeyAction::OpenInBrowser),
+                "Open in browser",
+                &mut lines,
+            );
+            add_key(&kd(&KeyAction::DeleteFeed), "Remove from queue", &mut lines);
+            add_key("K / J", "Move entry up / down", &mut lines);
+            add_key("T / B", "Move entry to top / bottom", &mut lines);
+            add_key(&kd(&KeyAction::Quit), "Back to Dashboard", &mut lines);
+        }
+        View::Scheduler => {
+            lines.push(Line::from(Span::styled("  Scheduler", section_style)));
+            lines.push(Line::from(""));
+            add_key(&kd(&KeyAction::MoveUp), "Navigate up", &mut lines);
+            add_key(&kd(&KeyAction::MoveDown), "Navigate down", &mut lines);
+            add_key(&kd(&KeyAction::Select), "Refresh feed now", &mut lines);
+            add_key("p", "Pause/resume scheduled refreshes", &mut lines);
+            add_key(&kd(&KeyAction::Refresh), "Refresh all feeds", &mut lines);
+            add_key(&kd(&KeyAction::Quit), "Back to Dashboard", &mut lines);
+        }
+        View::Releases => {
+            lines.push(Line::from(Span::styled("  Releases", section_style)));
+            lines.push(Line::from(""));
+            add_key(&kd(&KeyAction::MoveUp), "Navigate up", &mut lines);
+            add_key(&kd(&KeyAction::MoveDown), "Navigate down", &mut lines);
+            add_key(
+                &kd(&KeyAction::Select),
+                "Read the release notes",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::OpenInBrowser),
+                "Open the diff since the previous release",
+                &mut lines,
+            );
+            add_key(&kd(&KeyAction::Quit), "Back to the feed", &mut lines);
+        }
+        View::Triage => {
+            lines.push(Line::from(Span::styled("  Triage", section_style)));
+            lines.push(Line::from(""));
+            add_key("l", "Save for later", &mut lines);
+            add_key(
+                &kd(&KeyAction::ToggleStar),
+                "Star and mark read",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::OpenInBrowser),
+                "Open in browser and mark read",
+                &mut lines,
+            );
+            add_key(&kd(&KeyAction::ToggleRead), "Mark read", &mut lines);
+            add_key("n/→", "Skip (leave unread)", &mut lines);
+            add_key("x", "Mute this item's feed", &mut lines);
+            add_key("←", "Previous item", &mut lines);
+            add_key("Esc", "Back to Dashboard", &mut lines);
+        }
+        View::Alerts => {
+            lines.push(Line::from(Span::styled("  Alerts", section_style)));
+            lines.push(Line::from(""));
+            add_key(&kd(&KeyAction::MoveUp), "Navigate up", &mut lines);
+            add_key(&kd(&KeyAction::MoveDown), "Navigate down", &mut lines);
+            add_key(&kd(&KeyAction::Select), "View article detail", &mut lines);
+            add_key(
+                &kd(&KeyAction::OpenInBrowser),
+                "Open in browser",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::DeleteFeed),
+                "Dismiss alert (marks it read)",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::ToggleRead),
+                "Toggle read/unread",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::ToggleStar),
+                "Star/unstar article",
+                &mut lines,
+            );
+            add_key(
+                &kd(&KeyAction::MarkAllRead),
+                "Mark all alerts read",
+                &mut lines,
+            );
+            add_key(&kd(&KeyAction::Quit), "Back to Dashboard", &mut lines);
+        }
+        View::Summary => {
             lines.push(Line::from(Span::styled("  What's New", section_style)));
             lines.push(Line::from(""));
             add_key("Any key", "Dismiss and go to Dashboard", &mut lines);
@@ -956,3 +1538,855 @@ pub(super) fn render_link_overlay<B: Backend>(f: &mut Frame<B>, app: &App, color
 
     f.render_widget(paragraph, area);
 }
+
+pub(super) fn render_summary_popup<B: Backend>(f: &mut Frame<B>, app: &App, colors: &ColorScheme) {
+    let area = centered_rect_with_min(70, 60, 50, 14, f.size());
+    f.render_widget(Clear, area);
+
+    let (text, style) = match app.current_article_summary() {
+        Some(ToolOutput::Done(summary)) => (summary, Style::default().fg(colors.text)),
+        Some(ToolOutput::Failed(e)) => (
+            format!("Summarize command failed: {}", e),
+            Style::default().fg(colors.error),
+        ),
+        _ => (
+            "Summarizing…".to_string(),
+            Style::default().fg(colors.muted),
+        ),
+    };
+
+    let paragraph = Paragraph::new(text)
+        .style(style)
+        .scroll((app.summary_popup_scroll, 0))
+        .wrap(Wrap { trim: false })
+        .block(
+            Block::default()
+                .title(format!(
+                    " Summary · {}/{}: Scroll · any other key closes ",
+                    key_display(&KeyAction::MoveDown, &app.keybindings),
+                    key_display(&KeyAction::MoveUp, &app.keybindings),
+                ))
+                .title_alignment(Alignment::Center)
+                .borders(Borders::ALL)
+                .border_type(colors.border_focus_type)
+                .border_style(Style::default().fg(colors.primary))
+                .style(Style::default().bg(colors.surface))
+                .padding(Padding::new(2, 2, 1, 1)),
+        );
+
+    f.render_widget(paragraph, area);
+}
+
+/// What a feed changed about itself, old value above new, with the choice to adopt
+/// a new title.
+pub(super) fn render_feed_change_popup<B: Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    change: &FeedChange,
+    colors: &ColorScheme,
+) {
+    let area = centered_rect_with_min(60, 50, 50, 14, f.size());
+    f.render_widget(Clear, area);
+
+    let shown = app
+        .feed_titles
+        .get(&change.url)
+        .unwrap_or(&change.old.title);
+    let mut lines = vec![
+        Line::from(Span::styled(
+            shown.clone(),
+            Style::default()
+                .fg(colors.text)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            change.url.clone(),
+            Style::default().fg(colors.muted),
+        )),
+    ];
+    let mut field = |label: &str, old: Option<&str>, new: Option<&str>| {
+        if old == new {
+            return;
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            label.to_string(),
+            Style::default().fg(colors.secondary),
+        )));
+        lines.push(Line::from(Span::styled(
+            format!("  - {}", old.unwrap_or("(none)")),
+            Style::default().fg(colors.text_secondary),
+        )));
+        lines.push(Line::from(Span::styled(
+            format!("  + {}", new.unwrap_or("(none)")),
+            Style::default().fg(colors.text),
+        )));
+    };
+    field(
+        "Title",
+        Some(change.old.title.as_str()),
+        Some(change.new.title.as_str()),
+    );
+    field(
+        "Site",
+        change.old.link.as_deref(),
+        change.new.link.as_deref(),
+    );
+    field(
+        "Description",
+        change.old.description.as_deref(),
+        change.new.description.as_deref(),
+    );
+
+    lines.push(Line::from(""));
+    if change.old.title != change.new.title {
+        lines.push(Line::from(vec![
+            Span::styled("  a - ", Style::default().fg(colors.highlight)),
+            Span::styled(
+                format!("Use the new title \"{}\"", change.new.title),
+                Style::default().fg(colors.text),
+            ),
+        ]));
+        lines.push(Line::from(vec![
+            Span::styled("  any other key - ", Style::default().fg(colors.highlight)),
+            Span::styled(
+                format!("Keep \"{}\"", shown),
+                Style::default().fg(colors.text),
+            ),
+        ]));
+    } else {
+        lines.push(Line::from(Span::styled(
+            "Press any key to continue",
+            Style::default().fg(colors.muted),
+        )));
+    }
+
+    let more = app.feed_changes.len().saturating_sub(1);
+    let title = if more > 0 {
+        format!(" Feed Details Changed · {} more ", more)
+    } else {
+        " Feed Details Changed ".to_string()
+    };
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .title(title)
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL)
+            .border_type(colors.border_focus_type)
+            .border_style(Style::default().fg(colors.primary))
+            .style(Style::default().bg(colors.surface))
+            .padding(Padding::new(2, 2, 1, 1)),
+    );
+
+    f.render_widget(paragraph, area);
+}
+
+pub(super) fn render_refresh_diff_popup<B: Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    colors: &ColorScheme,
+) {
+    let Some(diff) = &app.refresh_diff else {
+        return;
+    };
+    // Borders, padding and the header line take six rows
+    let height = (diff.entries.len() as u16).saturating_add(6);
+    let area = centered_rect_with_min(50, 0, 40, height, f.size());
+    f.render_widget(Clear, area);
+
+    let total = diff.total();
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!("{} new item{}", total, if total == 1 { "" } else { "s" }),
+            Style::default()
+                .fg(colors.highlight)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+
+    let inner_height = area.height.saturating_sub(6) as usize;
+    let scroll_offset = (diff.selected + 1).saturating_sub(inner_height);
+    let max_title_len = area.width.saturating_sub(16) as usize;
+    for (idx, entry) in diff
+        .entries
+        .iter()
+        .enumerate()
+        .skip(scroll_offset)
+        .take(inner_height)
+    {
+        let is_selected = idx == diff.selected;
+        let prefix = if is_selected { "\u{25B8} " } else { "  " };
+        lines.push(Line::from(vec![
+            Span::styled(prefix, Style::default().fg(colors.highlight)),
+            Span::styled(
+                format!("{:>4}  ", entry.new_items),
+                Style::default().fg(colors.secondary),
+            ),
+            Span::styled(
+                truncate_str(&entry.feed_title, max_title_len),
+                Style::default()
+                    .fg(if is_selected {
+                        colors.text
+                    } else {
+                        colors.text_secondary
+                    })
+                    .add_modifier(if is_selected {
+                        Modifier::BOLD
+                    } else {
+                        Modifier::empty()
+                    }),
+            ),
+        ]));
+    }
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .title(format!(
+                " Refreshed · {}: Open feed · any other key closes ",
+                key_display(&KeyAction::Select, &app.keybindings),
+            ))
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL)
+            .border_type(colors.border_focus_type)
+            .border_style(Style::default().fg(colors.primary))
+            .style(Style::default().bg(colors.surface))
+            .padding(Padding::new(2, 2, 1, 1)),
+    );
+
+    f.render_widget(paragraph, area);
+}
+
+pub(super) fn render_feed_info_popup<B: Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    colors: &ColorScheme,
+) {
+    let (Some(feed), Some(feed_idx)) = (app.current_feed(), app.selected_feed) else {
+        return;
+    };
+    let area = centered_rect_with_min(60, 50, 50, 16, f.size());
+    f.render_widget(Clear, area);
+
+    let label = |name: &'static str| {
+        Span::styled(format!("{:<12}", name), Style::default().fg(colors.muted))
+    };
+    let value = |text: String| Span::styled(text, Style::default().fg(colors.text));
+    let category = app
+        .get_category_for_feed(&feed.url)
+        .and_then(|idx| app.categories.get(idx))
+        .map_or_else(|| "none".to_string(), |c| c.name.clone());
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            feed.title.clone(),
+            Style::default()
+                .fg(colors.highlight)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(vec![label("URL"), value(feed.url.clone())]),
+        Line::from(vec![
+            label("Items"),
+            value(format!(
+                "{} ({} unread)",
+                feed.items.len(),
+                app.unread_count(feed_idx)
+            )),
+        ]),
+        Line::from(vec![
+            label("Updated"),
+            value(
+                feed.last_updated()
+                    .map_or_else(|| "unknown".to_string(), format_date),
+            ),
+        ]),
+        Line::from(vec![label("Category"), value(category)]),
+        Line::from(vec![
+            label("Language"),
+            value(
+                feed.dominant_language()
+                    .map_or("unknown", language_name)
+                    .to_string(),
+            ),
+        ]),
+        Line::from(vec![
+            label("Muted"),
+            value(
+                if app.muted_feeds.contains(&feed.url) {
+                    "yes"
+                } else {
+                    "no"
+                }
+                .to_string(),
+            ),
+        ]),
+        Line::from(vec![
+            label("Engagement"),
+            value(engagement_text(app, &feed.url)),
+        ]),
+        Line::from(vec![
+            label("Last fetch"),
+            value(
+                app.fetch_summaries
+                    .get(&feed.url)
+                    .cloned()
+                    .unwrap_or_else(|| "not fetched this session".to_string()),
+            ),
+        ]),
+    ];
+    if let Some(description) = &feed.description {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            description.clone(),
+            Style::default().fg(colors.text_secondary),
+        )));
+    }
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .title(" Feed Info · any key closes ")
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL)
+            .border_type(colors.border_focus_type)
+            .border_style(Style::default().fg(colors.primary))
+            .style(Style::default().bg(colors.surface))
+            .padding(Padding::new(2, 2, 1, 1)),
+    );
+
+    f.render_widget(paragraph, area);
+}
+
+// "40% opened (4 opened, 6 dismissed, 3 clicks)"
+fn engagement_text(app: &App, url: &str) -> String {
+    let engagement = app.feed_engagement(url);
+    let counts = format!(
+        "{} opened, {} dismissed, {} clicks",
+        engagement.opened, engagement.dismissed, engagement.clicks
+    );
+    match engagement.score() {
+        Some(score) => format!("{}% opened ({})", score, counts),
+        None => "no activity yet".to_string(),
+    }
+}
+
+pub(super) fn render_item_explanation_popup<B: Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    colors: &ColorScheme,
+) {
+    let Some((feed_idx, item_idx)) = app.selected_item_indices() else {
+        return;
+    };
+    let area = centered_rect_with_min(60, 50, 50, 14, f.size());
+    f.render_widget(Clear, area);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            app.feeds[feed_idx].items[item_idx].title.clone(),
+            Style::default()
+                .fg(colors.highlight)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+    lines.extend(
+        app.explain_item(feed_idx, item_idx)
+            .into_iter()
+            .map(|line| Line::from(Span::styled(line, Style::default().fg(colors.text)))),
+    );
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .title(" Why Is This Here · any key closes ")
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL)
+            .border_type(colors.border_focus_type)
+            .border_style(Style::default().fg(colors.primary))
+            .style(Style::default().bg(colors.surface))
+            .padding(Padding::new(2, 2, 1, 1)),
+    );
+
+    f.render_widget(paragraph, area);
+}
+
+// How much of the article text the full title popup shows
+const FULL_TITLE_SNIPPET_CHARS: usize = 600;
+
+pub(super) fn render_full_title_popup<B: Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    colors: &ColorScheme,
+) {
+    let Some((feed_idx, item_idx)) = app.selected_item_indices() else {
+        return;
+    };
+    let feed = &app.feeds[feed_idx];
+    let item = &feed.items[item_idx];
+    let area = centered_rect_with_min(60, 50, 50, 12, f.size());
+    f.render_widget(Clear, area);
+
+    let meta: Vec<String> = std::iter::once(feed.title.clone())
+        .chain(item.author.clone())
+        .chain(item_date(item, app.config.ui.date_style, app.narrow))
+        .collect();
+    let mut lines = vec![
+        Line::from(Span::styled(
+            item.title.clone(),
+            Style::default()
+                .fg(colors.highlight)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            meta.join(" \u{00b7} "),
+            Style::default().fg(colors.muted),
+        )),
+    ];
+    let snippet = item
+        .plain_text
+        .as_deref()
+        .unwrap_or_default()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    if !snippet.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            truncate_str(&snippet, FULL_TITLE_SNIPPET_CHARS),
+            Style::default().fg(colors.text),
+        )));
+    }
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .title(" Full Title · any key closes ")
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL)
+            .border_type(colors.border_focus_type)
+            .border_style(Style::default().fg(colors.primary))
+            .style(Style::default().bg(colors.surface))
+            .padding(Padding::new(2, 2, 1, 1)),
+    );
+
+    f.render_widget(paragraph, area);
+}
+
+pub(super) fn render_mute_menu_popup<B: Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    feed_idx: usize,
+    item_idx: usize,
+    colors: &ColorScheme,
+) {
+    let item = &app.feeds[feed_idx].items[item_idx];
+    let area = centered_rect_with_min(50, 30, 44, 9, f.size());
+    f.render_widget(Clear, area);
+
+    let option = |key: &'static str, text: String, available: bool| {
+        Line::from(vec![
+            Span::styled(
+                format!("  {} - ", key),
+                Style::default().fg(if available {
+                    colors.highlight
+                } else {
+                    colors.muted
+                }),
+            ),
+            Span::styled(
+                text,
+                Style::default().fg(if available { colors.text } else { colors.muted }),
+            ),
+        ])
+    };
+    let verb = |muted: bool| if muted { "Unmute" } else { "Mute" };
+
+    let author = item
+        .author
+        .as_deref()
+        .map(str::trim)
+        .filter(|a| !a.is_empty());
+    let author_line = match author {
+        Some(author) => option(
+            "a",
+            format!(
+                "{} author {}",
+                verb(app.muted_authors.contains(&author.to_lowercase())),
+                author
+            ),
+            true,
+        ),
+        None => option("a", "No author to mute".to_string(), false),
+    };
+    let domain_line = match crate::app::item_domain(item) {
+        Some(domain) => option(
+            "d",
+            format!("{} {}", verb(app.muted_domains.contains(&domain)), domain),
+            true,
+        ),
+        None => option("d", "No link to mute".to_string(), false),
+    };
+
+    let lines = vec![
+        author_line,
+        domain_line,
+        Line::from(""),
+        Line::from(Span::styled(
+            "Muted items leave the dashboard and triage; their feed still lists them",
+            Style::default().fg(colors.muted),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .title(" Mute · any other key closes ")
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL)
+            .border_type(colors.border_focus_type)
+            .border_style(Style::default().fg(colors.primary))
+            .style(Style::default().bg(colors.surface))
+            .padding(Padding::new(2, 2, 1, 1)),
+    );
+
+    f.render_widget(paragraph, area);
+}
+
+/// Numbered list of the configured webhooks to post an article to.
+pub(super) fn render_webhook_menu_popup<B: Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    feed_idx: usize,
+    item_idx: usize,
+    colors: &ColorScheme,
+) {
+    let item = &app.feeds[feed_idx].items[item_idx];
+    let webhooks = &app.config.share.webhooks;
+    let height = (webhooks.len().min(9) as u16) + 7;
+    let area = centered_rect_with_min(50, 30, 44, height, f.size());
+    f.render_widget(Clear, area);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            item.title.clone(),
+            Style::default()
+                .fg(colors.text)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+    for (i, webhook) in webhooks.iter().take(9).enumerate() {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {} - ", i + 1),
+                Style::default().fg(colors.highlight),
+            ),
+            Span::styled(webhook.name.clone(), Style::default().fg(colors.text)),
+        ]));
+    }
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .title(" Post to Webhook · any other key closes ")
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL)
+            .border_type(colors.border_focus_type)
+            .border_style(Style::default().fg(colors.primary))
+            .style(Style::default().bg(colors.surface))
+            .padding(Padding::new(2, 2, 1, 1)),
+    );
+
+    f.render_widget(paragraph, area);
+}
+
+/// The selected item's context menu, each entry with the key that does the same.
+pub(super) fn render_context_menu_popup<B: Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    menu: &ContextMenu,
+    colors: &ColorScheme,
+) {
+    let item = &app.feeds[menu.feed_idx].items[menu.item_idx];
+    let height = menu.entries.len() as u16 + 6;
+    let area = centered_rect_with_min(40, 50, 44, height, f.size());
+    f.render_widget(Clear, area);
+
+    let width = area.width.saturating_sub(6) as usize;
+    let mut lines = vec![
+        Line::from(Span::styled(
+            truncate_str(&item.title, width),
+            Style::default()
+                .fg(colors.text)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+    for (idx, (command, label)) in menu.entries.iter().enumerate() {
+        let key = match command {
+            MenuCommand::Key(action) => key_display(action, &app.keybindings),
+            _ => String::new(),
+        };
+        let selected = idx == menu.selected;
+        let style = if selected {
+            Style::default()
+                .fg(colors.highlight)
+                .bg(colors.selected_bg)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(colors.text)
+        };
+        let marker = if selected { "\u{25B6} " } else { "  " };
+        // Keys line up on the right
+        let used = 2 + label.chars().count() + key.chars().count();
+        lines.push(Line::from(vec![
+            Span::styled(format!("{}{}", marker, label), style),
+            Span::styled(" ".repeat(width.saturating_sub(used)), style),
+            Span::styled(key, Style::default().fg(colors.muted)),
+        ]));
+    }
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .title(" Actions · Enter runs · any other key closes ")
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL)
+            .border_type(colors.border_focus_type)
+            .border_style(Style::default().fg(colors.primary))
+            .style(Style::default().bg(colors.surface))
+            .padding(Padding::new(2, 2, 1, 1)),
+    );
+
+    f.render_widget(paragraph, area);
+}
+
+/// Bookmarks that failed to load on the last refresh, with the reason for each.
+pub(super) fn render_failed_feeds_popup<B: Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    colors: &ColorScheme,
+) {
+    let area = centered_rect_with_min(70, 60, 50, 12, f.size());
+    f.render_widget(Clear, area);
+
+    let mut lines = Vec::new();
+    for (url, reason) in &app.failed_feeds {
+        let title = app.feed_titles.get(url).unwrap_or(url);
+        lines.push(Line::from(Span::styled(
+            format!("\u{2717} {}", title),
+            Style::default()
+                .fg(colors.error)
+                .add_modifier(Modifier::BOLD),
+        )));
+        if title != url {
+            lines.push(Line::from(Span::styled(
+                format!("  {}", url),
+                Style::default().fg(colors.text_secondary),
+            )));
+        }
+        lines.push(Line::from(Span::styled(
+            format!("  {}", reason),
+            Style::default().fg(colors.text),
+        )));
+        lines.push(Line::from(""));
+    }
+    lines.push(Line::from(Span::styled(
+        format!(
+            "{}: Retry these feeds  ·  d: Dismiss banner  ·  any other key closes",
+            key_display(&KeyAction::Refresh, &app.keybindings)
+        ),
+        Style::default().fg(colors.muted),
+    )));
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .title(format!(" Failed Feeds ({}) ", app.failed_feeds.len()))
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL)
+            .border_type(colors.border_focus_type)
+            .border_style(Style::default().fg(colors.error))
+            .style(Style::default().bg(colors.surface))
+            .padding(Padding::new(2, 2, 1, 1)),
+    );
+    f.render_widget(paragraph, area);
+}
+
+/// What's still pending when the user quits, with the choice to quit anyway.
+pub(super) fn render_quit_prompt_popup<B: Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    colors: &ColorScheme,
+) {
+    let area = centered_rect_with_min(50, 30, 44, 9, f.size());
+    f.render_widget(Clear, area);
+
+    let mut lines: Vec<Line> = app
+        .quit_summary()
+        .into_iter()
+        .map(|pending| {
+            Line::from(Span::styled(
+                format!("\u{2022} {}", pending),
+                Style::default().fg(colors.text),
+            ))
+        })
+        .collect();
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "y/Enter: Save & quit  ·  any other key cancels",
+        Style::default().fg(colors.muted),
+    )));
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .title(" Quit Feedr? ")
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL)
+            .border_type(colors.border_focus_type)
+            .border_style(Style::default().fg(colors.accent))
+            .style(Style::default().bg(colors.surface))
+            .padding(Padding::new(2, 2, 1, 1)),
+    );
+    f.render_widget(paragraph, area);
+}
+
+/// Per-URL results of adding a pasted list of feeds.
+pub(super) fn render_bulk_add_report_popup<B: Backend>(
+    f: &mut Frame<B>,
+    report: &ImportReport,
+    colors: &ColorScheme,
+) {
+    let area = centered_rect_with_min(70, 60, 50, 14, f.size());
+    f.render_widget(Clear, area);
+
+    let heading = |text: String, color| {
+        Line::from(Span::styled(
+            text,
+            Style::default().fg(color).add_modifier(Modifier::BOLD),
+        ))
+    };
+    let text = Style::default().fg(colors.text);
+    let muted = Style::default().fg(colors.text_secondary);
+
+    let mut lines = vec![heading(
+        format!("\u{2713} Added {} feeds", report.new.len()),
+        colors.success,
+    )];
+    for (url, title) in &report.new {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {} ", title), text),
+            Span::styled(url.clone(), muted),
+        ]));
+    }
+    if !report.duplicates.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(heading(
+            format!(
+                "= Already subscribed or listed twice ({})",
+                report.duplicates.len()
+            ),
+            colors.muted,
+        ));
+        for url in &report.duplicates {
+            lines.push(Line::from(Span::styled(format!("  {}", url), muted)));
+        }
+    }
+    if !report.failed.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(heading(
+            format!("\u{2717} Failed ({})", report.failed.len()),
+            colors.error,
+        ));
+        for (url, reason) in &report.failed {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {} ", url), text),
+                Span::styled(reason.clone(), muted),
+            ]));
+        }
+    }
+
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .title(" Add Feeds · any key closes ")
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL)
+            .border_type(colors.border_focus_type)
+            .border_style(Style::default().fg(colors.primary))
+            .style(Style::default().bg(colors.surface))
+            .padding(Padding::new(2, 2, 1, 1)),
+    );
+    f.render_widget(paragraph, area);
+}
+
+/// The tour's current step: a frame around the part of the screen it's about, with a
+/// callout beside it. `regions` are the title bar, content and help bar areas.
+pub(super) fn render_tour<B: Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    step: usize,
+    regions: &[Rect],
+    colors: &ColorScheme,
+) {
+    let Some(tour_step) = STEPS.get(step) else {
+        return;
+    };
+    let screen = f.size();
+    let target = match tour_step.region {
+        TourRegion::Screen => None,
+        TourRegion::TitleBar => regions.first(),
+        TourRegion::Content => regions.get(1),
+        TourRegion::HelpBar => regions.get(2),
+    };
+    // A title or help bar one row high has no room for a frame; the callout still points at it
+    if let Some(&target) = target.filter(|target| target.height >= 3) {
+        f.render_widget(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Thick)
+                .border_style(
+                    Style::default()
+                        .fg(colors.highlight)
+                        .add_modifier(Modifier::BOLD),
+                ),
+            target,
+        );
+    }
+
+    let text = step_text(tour_step, &app.keybindings);
+    let footer = format!(
+        "{}/{} \u{00b7} any key: next \u{00b7} {}: back \u{00b7} Esc: leave",
+        step + 1,
+        STEPS.len(),
+        key_display(&KeyAction::HistoryBack, &app.keybindings)
+    );
+    let width = screen.width.saturating_sub(4).min(64);
+    // Borders and padding take six columns and four rows; a spare row covers word wrapping
+    let text_width = width.saturating_sub(6) as usize;
+    let height =
+        (count_wrapped_lines(&text, text_width) + count_wrapped_lines(&footer, text_width) + 6)
+            .min(screen.height);
+    let x = screen.x + (screen.width - width) / 2;
+    // Beside the region it's about: under the title bar, over the help bar
+    let y = match target {
+        Some(target) if tour_step.region == TourRegion::TitleBar => target.bottom(),
+        Some(target) if tour_step.region == TourRegion::HelpBar => target.y.saturating_sub(height),
+        Some(target) => target.y + target.height.saturating_sub(height) / 2,
+        None => screen.y + (screen.height - height) / 2,
+    }
+    .min(screen.bottom().saturating_sub(height));
+    let area = Rect::new(x, y, width, height);
+    f.render_widget(Clear, area);
+
+    let lines = vec![
+        Line::from(Span::styled(text, Style::default().fg(colors.text))),
+        Line::from(""),
+        Line::from(Span::styled(footer, Style::default().fg(colors.muted))),
+    ];
+    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+        Block::default()
+            .title(format!(" {} ", tour_step.title))
+            .title_alignment(Alignment::Center)
+            .borders(Borders::ALL)
+            .border_type(colors.border_focus_type)
+            .border_style(Style::default().fg(colors.highlight))
+            .style(Style::default().bg(colors.surface))
+            .padding(Padding::new(2, 2, 1, 1)),
+    );
+    f.render_widget(paragraph, area);
+}
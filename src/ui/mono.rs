@@ -0,0 +1,85 @@
+//! Monochrome rendering, for `theme = "mono"` and terminals with `NO_COLOR` set. The mono
+//! color scheme paints with placeholder colors that stand for what the color means; once
+//! a frame is drawn, `apply` turns each of them into bold, underline or reverse video and
+//! clears every color, so nothing on screen depends on color to be told apart.
+
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Modifier};
+
+// Placeholder colors of the mono scheme, one per role
+pub(super) const PRIMARY: Color = Color::Indexed(1);
+pub(super) const SECONDARY: Color = Color::Indexed(2);
+pub(super) const HIGHLIGHT: Color = Color::Indexed(3);
+pub(super) const SUCCESS: Color = Color::Indexed(4);
+pub(super) const BACKGROUND: Color = Color::Indexed(5);
+pub(super) const SURFACE: Color = Color::Indexed(6);
+pub(super) const SELECTED_BG: Color = Color::Indexed(7);
+pub(super) const TEXT: Color = Color::Indexed(8);
+pub(super) const TEXT_SECONDARY: Color = Color::Indexed(9);
+pub(super) const MUTED: Color = Color::Indexed(10);
+pub(super) const ACCENT: Color = Color::Indexed(11);
+pub(super) const ERROR: Color = Color::Indexed(12);
+pub(super) const BORDER: Color = Color::Indexed(13);
+pub(super) const BORDER_FOCUS: Color = Color::Indexed(14);
+
+// Roles shown in bold
+const EMPHASIS: [Color; 6] = [PRIMARY, SECONDARY, HIGHLIGHT, SUCCESS, ACCENT, BORDER_FOCUS];
+
+/// Whether the `NO_COLOR` convention (<https://no-color.org>) asks for no colors: the
+/// variable is set to anything but an empty string.
+pub(super) fn no_color() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+
+/// Swap the colors in a drawn frame for text attributes: selections in reverse video,
+/// headings, keys and other emphasis in bold, errors in bold and underlined. Colors
+/// from anywhere else (images, alert flags) are dropped.
+pub(super) fn apply(buffer: &mut Buffer) {
+    for cell in buffer.content.iter_mut() {
+        let mut modifier = if cell.fg == ERROR {
+            Modifier::BOLD | Modifier::UNDERLINED
+        } else if EMPHASIS.contains(&cell.fg) {
+            Modifier::BOLD
+        } else {
+            Modifier::empty()
+        };
+        if cell.bg == SELECTED_BG {
+            modifier |= Modifier::REVERSED;
+        }
+        cell.modifier |= modifier;
+        cell.fg = Color::Reset;
+        cell.bg = Color::Reset;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+    use ratatui::style::Style;
+
+    #[test]
+    fn test_apply_turns_roles_into_attributes() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        buffer.set_string(0, 0, "a", Style::default().fg(HIGHLIGHT));
+        buffer.set_string(1, 0, "b", Style::default().fg(TEXT).bg(SELECTED_BG));
+        buffer.set_string(2, 0, "c", Style::default().fg(ERROR));
+        buffer.set_string(3, 0, "d", Style::default().fg(Color::Rgb(255, 120, 40)));
+        apply(&mut buffer);
+
+        let modifiers: Vec<Modifier> = buffer.content.iter().map(|c| c.modifier).collect();
+        assert_eq!(
+            modifiers,
+            [
+                Modifier::BOLD,
+                Modifier::REVERSED,
+                Modifier::BOLD | Modifier::UNDERLINED,
+                Modifier::empty(),
+            ]
+        );
+        assert!(buffer
+            .content
+            .iter()
+            .all(|c| c.fg == Color::Reset && c.bg == Color::Reset));
+    }
+}
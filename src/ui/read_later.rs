@@ -0,0 +1,160 @@
+use crate::app::App;
+use crate::ui::utils::{feed_badge, read_marker};
+use crate::ui::ColorScheme;
+use ratatui::{
+    backend::Backend,
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, List, ListItem, ListState, Padding, Paragraph},
+    Frame,
+};
+
+pub(super) fn render_read_later<B: Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    area: Rect,
+    colors: &ColorScheme,
+) {
+    let entries = app.read_later_entries();
+    let title = format!(
+        " Read Later ({}) \u{00b7} ~{} min ",
+        entries.len(),
+        app.read_later_minutes()
+    );
+
+    if entries.is_empty() {
+        let mut text = Text::default();
+        text.lines.push(Line::from(""));
+        text.lines.push(Line::from(Span::styled(
+            "Your reading queue is empty",
+            Style::default()
+                .fg(colors.text)
+                .add_modifier(Modifier::BOLD),
+        )));
+        text.lines.push(Line::from(""));
+        text.lines.push(Line::from(Span::styled(
+            "Press l in triage mode to save articles for later",
+            Style::default().fg(colors.highlight),
+        )));
+
+        let paragraph = Paragraph::new(text).alignment(Alignment::Center).block(
+            Block::default()
+                .title(title)
+                .title_alignment(Alignment::Center)
+                .borders(Borders::ALL)
+                .border_type(colors.border_normal)
+                .border_style(Style::default().fg(colors.border))
+                .style(Style::default().bg(colors.surface))
+                .padding(Padding::new(2, 2, 2, 2)),
+        );
+
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let arrow = colors.get_arrow_right();
+    let read_indicator = app.config.ui.read_indicator;
+    let items: Vec<ListItem> = entries
+        .iter()
+        .enumerate()
+        .map(|(idx, entry)| {
+            let is_selected = app.selected_item == Some(idx);
+            let pointer = Span::styled(
+                if is_selected {
+                    format!("{} ", arrow)
+                } else {
+                    "  ".to_string()
+                },
+                Style::default().fg(colors.highlight),
+            );
+            let position = Span::styled(
+                format!("{:>2}. ", idx + 1),
+                Style::default().fg(colors.muted),
+            );
+
+            let lines = match *entry {
+                Some((feed_idx, item_idx)) => {
+                    let feed = &app.feeds[feed_idx];
+                    let item = &feed.items[item_idx];
+                    let is_read = app.is_item_read(feed_idx, item_idx);
+                    vec![
+                        Line::from(vec![
+                            pointer,
+                            position,
+                            read_marker(read_indicator, is_read, colors),
+                            Span::styled(
+                                item.title.clone(),
+                                Style::default()
+                                    .fg(colors.text)
+                                    .add_modifier(if is_selected {
+                                        Modifier::BOLD
+                                    } else {
+                                        Modifier::empty()
+                                    }),
+                            ),
+                        ]),
+                        Line::from(vec![
+                            Span::raw("      "),
+                            feed_badge(&feed.title, &feed.url, &app.config.ui),
+                            Span::raw(" "),
+                            Span::styled(
+                                feed.title.clone(),
+                                Style::default().fg(colors.text_secondary),
+                            ),
+                            Span::styled(
+                                format!(" \u{00b7} {} min read", item.reading_minutes()),
+                                Style::default().fg(colors.muted),
+                            ),
+                        ]),
+                        Line::from(""),
+                    ]
+                }
+                None => vec![
+                    Line::from(vec![
+                        pointer,
+                        position,
+                        Span::styled(
+                            app.read_later[idx].clone(),
+                            Style::default().fg(colors.muted),
+                        ),
+                    ]),
+                    Line::from(Span::styled(
+                        "      (feed not loaded)",
+                        Style::default().fg(colors.muted),
+                    )),
+                    Line::from(""),
+                ],
+            };
+            ListItem::new(lines).style(Style::default().fg(colors.text).bg(if is_selected {
+                colors.selected_bg
+            } else {
+                colors.background
+            }))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(title)
+                .title_alignment(Alignment::Center)
+                .borders(Borders::ALL)
+                .border_type(colors.border_normal)
+                .border_style(Style::default().fg(colors.border))
+                .style(Style::default().bg(colors.surface))
+                .padding(Padding::new(2, 1, 1, 1)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(colors.selected_bg)
+                .fg(colors.highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("");
+
+    let mut state = ListState::default();
+    state.select(app.selected_item);
+
+    f.render_stateful_widget(list, area, &mut state);
+}
@@ -0,0 +1,147 @@
+use crate::app::App;
+use crate::ui::ColorScheme;
+use ratatui::{
+    backend::Backend,
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, List, ListItem, ListState, Padding, Paragraph},
+    Frame,
+};
+
+pub(super) fn render_releases<B: Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    area: Rect,
+    colors: &ColorScheme,
+) {
+    let releases = app.release_timeline();
+    let feed_title = app
+        .current_feed()
+        .map(|feed| feed.title.as_str())
+        .unwrap_or("Releases");
+    let title = format!(" {} \u{00b7} {} releases ", feed_title, releases.len());
+
+    if releases.is_empty() {
+        let mut text = Text::default();
+        text.lines.push(Line::from(""));
+        text.lines.push(Line::from(Span::styled(
+            "No versioned releases",
+            Style::default()
+                .fg(colors.text)
+                .add_modifier(Modifier::BOLD),
+        )));
+        text.lines.push(Line::from(""));
+        text.lines.push(Line::from(Span::styled(
+            "None of this feed's item titles carry a version number",
+            Style::default().fg(colors.highlight),
+        )));
+
+        let paragraph = Paragraph::new(text).alignment(Alignment::Center).block(
+            Block::default()
+                .title(title)
+                .title_alignment(Alignment::Center)
+                .borders(Borders::ALL)
+                .border_type(colors.border_normal)
+                .border_style(Style::default().fg(colors.border))
+                .style(Style::default().bg(colors.surface))
+                .padding(Padding::new(2, 2, 2, 2)),
+        );
+
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let arrow = colors.get_arrow_right();
+    let feed_idx = app.selected_feed.unwrap_or(0);
+    let items: Vec<ListItem> = releases
+        .iter()
+        .enumerate()
+        .map(|(idx, release)| {
+            let is_selected = app.selected_item == Some(idx);
+            let prerelease = release.version.is_prerelease();
+            let read = app.is_item_read(feed_idx, release.item_idx);
+            let date = release
+                .date
+                .map(|date| date.format("%Y-%m-%d").to_string())
+                .unwrap_or_else(|| "          ".to_string());
+
+            let mut spans = vec![
+                Span::styled(
+                    if is_selected {
+                        format!("{} ", arrow)
+                    } else {
+                        "  ".to_string()
+                    },
+                    Style::default().fg(colors.highlight),
+                ),
+                Span::styled(date, Style::default().fg(colors.muted)),
+                // Pre-releases hang off the release they lead up to
+                Span::styled(
+                    if prerelease { "   \u{2514} " } else { "  " },
+                    Style::default().fg(colors.border),
+                ),
+                Span::styled(
+                    release.version.text.clone(),
+                    Style::default()
+                        .fg(if prerelease {
+                            colors.text_secondary
+                        } else if read {
+                            colors.text
+                        } else {
+                            colors.highlight
+                        })
+                        .add_modifier(if prerelease {
+                            Modifier::empty()
+                        } else {
+                            Modifier::BOLD
+                        }),
+                ),
+            ];
+            if prerelease {
+                spans.push(Span::styled(
+                    " pre-release",
+                    Style::default().fg(colors.accent),
+                ));
+            }
+            if release.diff_url.is_some() {
+                spans.push(Span::styled(
+                    " \u{00b7} diff",
+                    Style::default().fg(colors.muted),
+                ));
+            }
+
+            ListItem::new(Line::from(spans)).style(Style::default().fg(colors.text).bg(
+                if is_selected {
+                    colors.selected_bg
+                } else {
+                    colors.background
+                },
+            ))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(title)
+                .title_alignment(Alignment::Center)
+                .borders(Borders::ALL)
+                .border_type(colors.border_normal)
+                .border_style(Style::default().fg(colors.border))
+                .style(Style::default().bg(colors.surface))
+                .padding(Padding::new(2, 1, 1, 1)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(colors.selected_bg)
+                .fg(colors.highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("");
+
+    let mut state = ListState::default();
+    state.select(app.selected_item);
+
+    f.render_stateful_widget(list, area, &mut state);
+}
@@ -0,0 +1,195 @@
+use crate::app::{App, FeedSchedule};
+use crate::ui::utils::{feed_badge, truncate_url};
+use crate::ui::ColorScheme;
+use ratatui::{
+    backend::Backend,
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, List, ListItem, ListState, Padding, Paragraph},
+    Frame,
+};
+use std::time::Duration;
+
+pub(super) fn render_scheduler<B: Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    area: Rect,
+    colors: &ColorScheme,
+) {
+    let schedules = app.feed_schedules();
+    let mode = if app.config.general.adaptive_refresh {
+        "adaptive"
+    } else {
+        "fixed"
+    };
+    let title = format!(" Scheduler ({}) \u{00b7} {} ", schedules.len(), mode);
+
+    if schedules.is_empty() {
+        let mut text = Text::default();
+        text.lines.push(Line::from(""));
+        text.lines.push(Line::from(Span::styled(
+            "Nothing to schedule",
+            Style::default()
+                .fg(colors.text)
+                .add_modifier(Modifier::BOLD),
+        )));
+        text.lines.push(Line::from(""));
+        text.lines.push(Line::from(Span::styled(
+            "Add a feed and its refresh schedule will show up here",
+            Style::default().fg(colors.highlight),
+        )));
+
+        let paragraph = Paragraph::new(text).alignment(Alignment::Center).block(
+            Block::default()
+                .title(title)
+                .title_alignment(Alignment::Center)
+                .borders(Borders::ALL)
+                .border_type(colors.border_normal)
+                .border_style(Style::default().fg(colors.border))
+                .style(Style::default().bg(colors.surface))
+                .padding(Padding::new(2, 2, 2, 2)),
+        );
+
+        f.render_widget(paragraph, area);
+        return;
+    }
+
+    let arrow = colors.get_arrow_right();
+    let items: Vec<ListItem> = schedules
+        .iter()
+        .enumerate()
+        .map(|(idx, schedule)| {
+            let is_selected = app.selected_item == Some(idx);
+            let title = app
+                .feed_titles
+                .get(&schedule.url)
+                .or_else(|| {
+                    app.feeds
+                        .iter()
+                        .find(|feed| feed.url == schedule.url)
+                        .map(|feed| &feed.title)
+                })
+                .cloned()
+                .unwrap_or_else(|| truncate_url(&schedule.url, 60));
+
+            let lines = vec![
+                Line::from(vec![
+                    Span::styled(
+                        if is_selected {
+                            format!("{} ", arrow)
+                        } else {
+                            "  ".to_string()
+                        },
+                        Style::default().fg(colors.highlight),
+                    ),
+                    feed_badge(&title, &schedule.url, &app.config.ui),
+                    Span::raw(" "),
+                    Span::styled(
+                        title.clone(),
+                        Style::default()
+                            .fg(if schedule.paused {
+                                colors.muted
+                            } else {
+                                colors.text
+                            })
+                            .add_modifier(if is_selected {
+                                Modifier::BOLD
+                            } else {
+                                Modifier::empty()
+                            }),
+                    ),
+                ]),
+                Line::from(vec![
+                    Span::raw("    "),
+                    next_refresh_span(schedule, colors),
+                    Span::styled(
+                        format!(" \u{00b7} {}", interval_text(schedule)),
+                        Style::default().fg(colors.text_secondary),
+                    ),
+                    Span::styled(
+                        match schedule.last_duration {
+                            Some(took) => format!(" \u{00b7} last fetch {} ms", took.as_millis()),
+                            None => " \u{00b7} not fetched yet".to_string(),
+                        },
+                        Style::default().fg(colors.muted),
+                    ),
+                ]),
+                Line::from(""),
+            ];
+            ListItem::new(lines).style(Style::default().fg(colors.text).bg(if is_selected {
+                colors.selected_bg
+            } else {
+                colors.background
+            }))
+        })
+        .collect();
+
+    let list = List::new(items)
+        .block(
+            Block::default()
+                .title(title)
+                .title_alignment(Alignment::Center)
+                .borders(Borders::ALL)
+                .border_type(colors.border_normal)
+                .border_style(Style::default().fg(colors.border))
+                .style(Style::default().bg(colors.surface))
+                .padding(Padding::new(2, 1, 1, 1)),
+        )
+        .highlight_style(
+            Style::default()
+                .bg(colors.selected_bg)
+                .fg(colors.highlight)
+                .add_modifier(Modifier::BOLD),
+        )
+        .highlight_symbol("");
+
+    let mut state = ListState::default();
+    state.select(app.selected_item);
+
+    f.render_stateful_widget(list, area, &mut state);
+}
+
+fn next_refresh_span(schedule: &FeedSchedule, colors: &ColorScheme) -> Span<'static> {
+    if schedule.paused {
+        return Span::styled("paused", Style::default().fg(colors.accent));
+    }
+    match schedule.due_in {
+        Some(due_in) if due_in.is_zero() => {
+            Span::styled("due now", Style::default().fg(colors.success))
+        }
+        Some(due_in) => Span::styled(
+            format!("next in {}", format_span(due_in)),
+            Style::default().fg(colors.highlight),
+        ),
+        None => Span::styled("manual only", Style::default().fg(colors.muted)),
+    }
+}
+
+fn interval_text(schedule: &FeedSchedule) -> String {
+    match schedule.interval {
+        Some((secs, source)) => format!(
+            "every {} ({})",
+            format_span(Duration::from_secs(secs)),
+            source.label()
+        ),
+        None => "no interval".to_string(),
+    }
+}
+
+// Coarse human duration: "45s", "12m", "3h 20m", "2d 4h"
+fn format_span(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    match secs {
+        0..=59 => format!("{}s", secs),
+        60..=3599 => format!("{}m", secs / 60),
+        3600..=86399 => match (secs % 3600) / 60 {
+            0 => format!("{}h", secs / 3600),
+            mins => format!("{}h {}m", secs / 3600, mins),
+        },
+        _ => match (secs % 86400) / 3600 {
+            0 => format!("{}d", secs / 86400),
+            hours => format!("{}d {}h", secs / 86400, hours),
+        },
+    }
+}
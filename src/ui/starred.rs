@@ -1,4 +1,5 @@
 use crate::app::App;
+use crate::ui::utils::{feed_badge, item_meta, read_marker};
 use crate::ui::ColorScheme;
 use ratatui::{
     backend::Backend,
@@ -61,13 +62,14 @@ pub(super) fn render_starred<B: Backend>(
 
     let arrow = colors.get_arrow_right();
     let success_icon = colors.get_icon_success();
+    let read_indicator = app.config.ui.read_indicator;
     let items: Vec<ListItem> = starred_items
         .iter()
         .enumerate()
         .map(|(idx, &(feed_idx, item_idx))| {
             let feed = &app.feeds[feed_idx];
             let item = &feed.items[item_idx];
-            let date_str = item.formatted_date.as_deref().unwrap_or("Unknown date");
+            let meta = item_meta(item, &app.config.ui, app.narrow).unwrap_or_default();
             let is_selected = app.selected_item == Some(idx);
             let is_read = app.is_item_read(feed_idx, item_idx);
 
@@ -81,6 +83,8 @@ pub(super) fn render_starred<B: Backend>(
                         },
                         Style::default().fg(colors.highlight),
                     ),
+                    feed_badge(&feed.title, &feed.url, &app.config.ui),
+                    Span::raw(" "),
                     Span::styled(
                         feed.title.to_string(),
                         Style::default()
@@ -103,6 +107,7 @@ pub(super) fn render_starred<B: Backend>(
                 ]),
                 Line::from(vec![
                     Span::styled("  ", Style::default()),
+                    read_marker(read_indicator, is_read, colors),
                     Span::styled(
                         &item.title,
                         Style::default()
@@ -122,7 +127,7 @@ pub(super) fn render_starred<B: Backend>(
                 ]),
                 Line::from(vec![
                     Span::styled("  ", Style::default()),
-                    Span::styled(date_str, Style::default().fg(colors.muted)),
+                    Span::styled(meta, Style::default().fg(colors.muted)),
                 ]),
                 Line::from(""),
             ])
@@ -1,4 +1,5 @@
 use crate::app::App;
+use crate::ui::utils::format_reading_time;
 use crate::ui::ColorScheme;
 use ratatui::{
     backend::Backend,
@@ -42,6 +43,20 @@ pub(super) fn render_summary<B: Backend>(
             .fg(colors.primary)
             .add_modifier(Modifier::BOLD),
     )]));
+    lines.push(Line::from(vec![Span::styled(
+        match app.config.ui.reading_goal {
+            0 => format!(
+                "  {} read today",
+                format_reading_time(app.reading_time_today())
+            ),
+            goal => format!(
+                "  {} read today, daily goal {} min",
+                format_reading_time(app.reading_time_today()),
+                goal
+            ),
+        },
+        Style::default().fg(colors.text_secondary),
+    )]));
     lines.push(Line::from(""));
 
     // Per-feed breakdown
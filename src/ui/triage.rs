@@ -0,0 +1,152 @@
+use crate::app::App;
+use crate::keybindings::{key_display, KeyAction};
+use crate::ui::utils::{feed_badge, truncate_str};
+use crate::ui::ColorScheme;
+use ratatui::{
+    backend::Backend,
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Padding, Paragraph, Wrap},
+    Frame,
+};
+
+pub(super) fn render_triage<B: Backend>(
+    f: &mut Frame<B>,
+    app: &App,
+    area: Rect,
+    colors: &ColorScheme,
+) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(5), Constraint::Length(3)])
+        .split(area);
+
+    let total = app.triage_queue.len();
+    let title = format!(
+        " Triage \u{00b7} {}/{} ",
+        (app.triage_pos + 1).min(total),
+        total
+    );
+
+    let mut lines: Vec<Line> = Vec::new();
+    if let Some((feed_idx, item_idx)) = app.current_triage_item() {
+        let feed = &app.feeds[feed_idx];
+        let item = &feed.items[item_idx];
+        lines.push(Line::from(vec![
+            feed_badge(&feed.title, &feed.url, &app.config.ui),
+            Span::raw(" "),
+            Span::styled(
+                feed.title.clone(),
+                Style::default()
+                    .fg(colors.text_secondary)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!(
+                    "  {}",
+                    item.formatted_date.as_deref().unwrap_or("Unknown date")
+                ),
+                Style::default().fg(colors.muted),
+            ),
+        ]));
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            item.title.clone(),
+            Style::default()
+                .fg(colors.text)
+                .add_modifier(Modifier::BOLD),
+        )));
+        if let Some(author) = &item.author {
+            lines.push(Line::from(Span::styled(
+                format!("by {}", author),
+                Style::default().fg(colors.muted),
+            )));
+        }
+        if app.is_item_starred(feed_idx, item_idx) || app.is_item_read(feed_idx, item_idx) {
+            // Reached again with the previous key after a disposition
+            lines.push(Line::from(Span::styled(
+                "already handled",
+                Style::default().fg(colors.success),
+            )));
+        }
+        lines.push(Line::from(""));
+        if let Some(text) = &item.plain_text {
+            let snippet = text.split_whitespace().collect::<Vec<_>>().join(" ");
+            lines.push(Line::from(Span::styled(
+                truncate_str(&snippet, 800),
+                Style::default().fg(colors.text),
+            )));
+        }
+    } else {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "Inbox zero",
+            Style::default()
+                .fg(colors.success)
+                .add_modifier(Modifier::BOLD),
+        )));
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!(
+                "{} items triaged \u{00b7} {} waiting in read later",
+                total,
+                app.read_later.len()
+            ),
+            Style::default().fg(colors.text_secondary),
+        )));
+    }
+
+    let card = Paragraph::new(lines)
+        .alignment(if app.current_triage_item().is_some() {
+            Alignment::Left
+        } else {
+            Alignment::Center
+        })
+        .wrap(Wrap { trim: true })
+        .block(
+            Block::default()
+                .title(title)
+                .title_alignment(Alignment::Center)
+                .borders(Borders::ALL)
+                .border_type(colors.border_focus_type)
+                .border_style(Style::default().fg(colors.primary))
+                .style(Style::default().bg(colors.surface))
+                .padding(Padding::new(4, 4, 2, 1)),
+        );
+    f.render_widget(card, chunks[0]);
+
+    let kd = |action: &KeyAction| key_display(action, &app.keybindings);
+    let key_style = Style::default()
+        .fg(colors.highlight)
+        .add_modifier(Modifier::BOLD);
+    let desc_style = Style::default().fg(colors.text);
+    let legend: Vec<Span> = [
+        ("l".to_string(), "read later"),
+        (kd(&KeyAction::ToggleStar), "star"),
+        (kd(&KeyAction::OpenInBrowser), "open"),
+        (kd(&KeyAction::ToggleRead), "mark read"),
+        ("n".to_string(), "skip"),
+        ("x".to_string(), "mute source"),
+        ("\u{2190}".to_string(), "previous"),
+        ("Esc".to_string(), "done"),
+    ]
+    .into_iter()
+    .flat_map(|(key, desc)| {
+        [
+            Span::styled(key, key_style),
+            Span::styled(format!(": {}  ", desc), desc_style),
+        ]
+    })
+    .collect();
+    let legend = Paragraph::new(Line::from(legend))
+        .alignment(Alignment::Center)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_type(colors.border_normal)
+                .border_style(Style::default().fg(colors.border))
+                .style(Style::default().bg(colors.surface)),
+        );
+    f.render_widget(legend, chunks[1]);
+}
@@ -1,6 +1,92 @@
+use crate::config::{DateStyle, ReadIndicator, UiConfig};
+use crate::feed::{meta, FeedItem};
+use crate::ui::ColorScheme;
+use chrono::{DateTime, Datelike, Utc};
 use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Span;
+use std::time::Duration;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
+// Read-state marker for item lists, so read status never depends on color alone
+pub(crate) fn read_marker(
+    indicator: ReadIndicator,
+    is_read: bool,
+    colors: &ColorScheme,
+) -> Span<'static> {
+    // Without colors, "none" would leave read and unread looking the same
+    let indicator = match indicator {
+        ReadIndicator::None if colors.monochrome => ReadIndicator::Dot,
+        indicator => indicator,
+    };
+    let symbol = match (indicator, is_read) {
+        (ReadIndicator::Dot, false) => "● ",
+        (ReadIndicator::Dot, true) => "○ ",
+        (ReadIndicator::Bracket, false) => "[N] ",
+        (ReadIndicator::Bracket, true) => "[ ] ",
+        (ReadIndicator::None, _) => "",
+    };
+    let color = if is_read {
+        colors.muted
+    } else {
+        colors.primary
+    };
+    Span::styled(symbol, Style::default().fg(color))
+}
+
+// Color for items flagged by an alert rule, distinct from the star's gold
+pub(crate) const ALERT_COLOR: Color = Color::Rgb(255, 120, 40);
+
+// Flag shown after items that matched an alert rule
+pub(crate) fn alert_flag(is_alert: bool) -> Span<'static> {
+    Span::styled(
+        if is_alert { " \u{2691}" } else { "" },
+        Style::default().fg(ALERT_COLOR),
+    )
+}
+
+// Badge colors for feed glyphs; all readable with black text on both themes
+const BADGE_COLORS: [Color; 8] = [
+    Color::Rgb(239, 83, 80),
+    Color::Rgb(255, 167, 38),
+    Color::Rgb(255, 238, 88),
+    Color::Rgb(102, 187, 106),
+    Color::Rgb(38, 198, 218),
+    Color::Rgb(66, 165, 245),
+    Color::Rgb(171, 71, 188),
+    Color::Rgb(236, 64, 122),
+];
+
+// Colored initial badge for a feed, with the color derived from its domain so
+// every feed from the same site gets the same badge across runs. A symbol set for
+// the domain in `[ui.icons]` takes the initial's place.
+pub(crate) fn feed_badge(title: &str, url: &str, ui: &UiConfig) -> Span<'static> {
+    let domain = crate::ui::feed_list::extract_domain(url);
+    // FNV-1a: stable across Rust versions, unlike DefaultHasher
+    let hash = domain.bytes().fold(0xcbf2_9ce4_8422_2325u64, |h, b| {
+        (h ^ u64::from(b)).wrapping_mul(0x0100_0000_01b3)
+    });
+    let color = BADGE_COLORS[(hash % BADGE_COLORS.len() as u64) as usize];
+    let initial = title
+        .chars()
+        .find(|c| c.is_alphanumeric())
+        .map_or('•', |c| c.to_uppercase().next().unwrap_or(c));
+    // Badges stay three columns wide, so wide emoji lose the trailing space
+    let badge = match ui.icon_for(&domain) {
+        Some(icon) if icon.width() >= 2 => format!(" {}", icon),
+        Some(icon) => format!(" {} ", icon),
+        None => format!(" {} ", initial),
+    };
+
+    Span::styled(
+        badge,
+        Style::default()
+            .fg(Color::Black)
+            .bg(color)
+            .add_modifier(Modifier::BOLD),
+    )
+}
+
 // Helper function to create a centered rect with minimum dimensions
 pub(crate) fn centered_rect_with_min(
     percent_x: u16,
@@ -50,10 +136,143 @@ pub(crate) fn truncate_str(s: &str, max_chars: usize) -> String {
         if truncate_idx > 0 {
             format!("{}...", &s[..truncate_idx])
         } else {
-            // Fallback if we couldn't properly calculate (shouldn't happen often)
-            format!("{}...", &s[..max_chars.saturating_sub(3)])
+            // Not even the first character fits before the ellipsis
+            "...".to_string()
+        }
+    }
+}
+
+// Time read as "23 min", "1 h 5 min" or "under a minute"
+pub(crate) fn format_reading_time(time: Duration) -> String {
+    match time.as_secs() / 60 {
+        0 => "under a minute".to_string(),
+        minutes @ 1..=59 => format!("{} min", minutes),
+        minutes => format!("{} h {} min", minutes / 60, minutes % 60),
+    }
+}
+
+// Plain-text preview for an item row, cut to the configured length. Narrow terminals
+// leave it out, since it would only wrap into the next row.
+pub(crate) fn item_snippet(item: &FeedItem, ui: &UiConfig, narrow: bool) -> Option<String> {
+    if !ui.show_snippet || narrow {
+        return None;
+    }
+    // Use cached plain_text to avoid HTML parsing per frame
+    let clean_text = item
+        .plain_text
+        .as_deref()?
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!clean_text.is_empty()).then(|| truncate_str(&clean_text, ui.snippet_length))
+}
+
+// Publication date for an item row in the configured style, abbreviated
+// ("3h", "May 17") on narrow terminals
+pub(crate) fn item_date(item: &FeedItem, style: DateStyle, narrow: bool) -> Option<String> {
+    if narrow && style != DateStyle::Hidden {
+        if let Some(dt) = item.parsed_date {
+            return Some(short_date(dt, style));
+        }
+    }
+    match style {
+        DateStyle::Auto => item.formatted_date.clone(),
+        DateStyle::Relative => item
+            .parsed_date
+            .map(|dt| crate::feed::relative_date(dt, chrono::Utc::now()))
+            .or_else(|| item.formatted_date.clone()),
+        DateStyle::Absolute => item
+            .parsed_date
+            .map(|dt| dt.format("%Y-%m-%d").to_string())
+            .or_else(|| item.formatted_date.clone()),
+        DateStyle::Hidden => None,
+    }
+}
+
+pub(crate) fn comment_count_label(count: u32) -> String {
+    if count == 1 {
+        "1 comment".to_string()
+    } else {
+        format!("{} comments", count)
+    }
+}
+
+/// Labels for the extension metadata worth showing next to an item: the episode
+/// ("S2 E42" or "episode 42"), running time ("38 min") and, unless `narrow`, where
+/// it's geotagged ("45.26°N 71.92°W").
+pub(crate) fn item_extras(item: &FeedItem, narrow: bool) -> Vec<String> {
+    let mut extras = Vec::new();
+    if let Some(episode) = item.metadata_number(meta::EPISODE) {
+        extras.push(match item.metadata_number(meta::SEASON) {
+            Some(season) => format!("S{} E{}", season, episode),
+            None => format!("episode {}", episode),
+        });
+    }
+    if let Some(seconds) = item.metadata_number(meta::DURATION) {
+        let minutes = seconds.div_ceil(60);
+        extras.push(if minutes < 60 {
+            format!("{} min", minutes)
+        } else {
+            format!("{} h {} min", minutes / 60, minutes % 60)
+        });
+    }
+    if let Some((lat, lon)) = item.geo_point().filter(|_| !narrow) {
+        let ns = if lat < 0.0 { 'S' } else { 'N' };
+        let ew = if lon < 0.0 { 'W' } else { 'E' };
+        extras.push(format!("{:.2}°{} {:.2}°{}", lat.abs(), ns, lon.abs(), ew));
+    }
+    extras
+}
+
+fn short_date(dt: DateTime<Utc>, style: DateStyle) -> String {
+    let now = Utc::now();
+    let recent = now.signed_duration_since(dt) < chrono::Duration::days(7);
+    match style {
+        DateStyle::Absolute => dt.format("%y-%m-%d").to_string(),
+        DateStyle::Auto if !recent => {
+            if dt.year() == now.year() {
+                dt.format("%b %-d").to_string()
+            } else {
+                dt.format("%b %Y").to_string()
+            }
+        }
+        _ => crate::feed::short_relative_date(dt, now),
+    }
+}
+
+// "author · date · comments" metadata for an item row, or None when there's none to show
+pub(crate) fn item_meta(item: &FeedItem, ui: &UiConfig, narrow: bool) -> Option<String> {
+    let author = item
+        .author
+        .as_deref()
+        .filter(|author| ui.show_author && !author.is_empty());
+    let parts: Vec<String> = author
+        .map(str::to_string)
+        .into_iter()
+        .chain(item_date(item, ui.date_style, narrow))
+        .chain(item_extras(item, narrow))
+        .chain(item.comment_count.map(comment_count_label))
+        .collect();
+    (!parts.is_empty()).then(|| parts.join(" \u{00b7} "))
+}
+
+// Article HTML rendered as plain text the way the detail view shows it,
+// with footnotes collected into a numbered section at the end
+pub(crate) fn article_text(html: &str) -> String {
+    let (body, notes) = crate::footnotes::extract(html);
+    let mut text = format_content_for_reading(&html2text::from_read(body.as_bytes(), 100));
+    if !notes.is_empty() {
+        text.push_str("\n\n");
+        text.push_str(crate::footnotes::HEADING);
+        for (idx, note) in notes.iter().enumerate() {
+            text.push_str(&format!(
+                "\n\n{} {}",
+                crate::footnotes::marker(idx + 1),
+                note
+            ));
         }
     }
+    text
 }
 
 // Helper function to format content for better reading experience
@@ -0,0 +1,221 @@
+// Rendering tests: each view is drawn from the `--demo` fixture feeds into a
+// ratatui TestBackend and compared against a text snapshot in tests/snapshots/.
+//
+// Snapshots are committed, and a missing one fails the test. Record new ones, or
+// re-record them after an intended UI change, with
+// `FEEDR_UPDATE_SNAPSHOTS=1 cargo test --test ui_snapshots` and review the diff.
+
+use feedr::app::{App, InputMode, View};
+use feedr::config::Theme;
+use feedr::globals::{self, GlobalOptions};
+use feedr::ui::{self, ColorScheme};
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::Terminal;
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::sync::{Mutex, Once};
+
+static INIT: Once = Once::new();
+// The demo app is saved to one scratch directory, so build them one at a time
+static DEMO_DATA: Mutex<()> = Mutex::new(());
+
+fn demo_app() -> App {
+    INIT.call_once(|| {
+        globals::init(GlobalOptions {
+            demo: true,
+            ..GlobalOptions::default()
+        })
+        .unwrap()
+    });
+    let _guard = DEMO_DATA.lock().unwrap_or_else(|e| e.into_inner());
+    let mut app = feedr::demo::app().unwrap();
+    feedr::demo::load_feeds(&mut app);
+    app
+}
+
+fn render(app: &mut App, width: u16, height: u16) -> Buffer {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal
+        .draw(|f| {
+            app.update_compact_mode(f.size().height);
+            ui::render(f, app);
+        })
+        .unwrap();
+    terminal.backend().buffer().clone()
+}
+
+fn text(buffer: &Buffer) -> String {
+    let mut out = String::new();
+    for y in 0..buffer.area.height {
+        let line: String = (0..buffer.area.width)
+            .map(|x| buffer.get(x, y).symbol.as_str())
+            .collect();
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+// The text, then each row's colours as runs of "fg/bg x count", so theme changes show up
+fn text_and_colors(buffer: &Buffer) -> String {
+    let mut out = text(buffer);
+    out.push_str("--- colors ---\n");
+    for y in 0..buffer.area.height {
+        let mut runs: Vec<(String, usize)> = Vec::new();
+        for x in 0..buffer.area.width {
+            let cell = buffer.get(x, y);
+            let style = format!("{:?}/{:?}", cell.fg, cell.bg);
+            match runs.last_mut() {
+                Some((last, count)) if *last == style => *count += 1,
+                _ => runs.push((style, 1)),
+            }
+        }
+        let row: Vec<String> = runs
+            .iter()
+            .map(|(style, count)| format!("{}x{}", style, count))
+            .collect();
+        let _ = writeln!(out, "{:02}: {}", y, row.join(" "));
+    }
+    out
+}
+
+fn assert_snapshot(name: &str, actual: &str) {
+    let path: PathBuf = [env!("CARGO_MANIFEST_DIR"), "tests", "snapshots"]
+        .iter()
+        .collect::<PathBuf>()
+        .join(format!("{}.snap", name));
+    let update = std::env::var_os("FEEDR_UPDATE_SNAPSHOTS").is_some();
+    match std::fs::read_to_string(&path) {
+        Ok(expected) if !update => assert!(
+            expected == actual,
+            "{} doesn't match the rendered screen:\n{}\n\
+             If the change is intended, re-run with FEEDR_UPDATE_SNAPSHOTS=1",
+            path.display(),
+            actual
+        ),
+        Err(e) if !update => panic!(
+            "No snapshot at {} ({}). Record it with FEEDR_UPDATE_SNAPSHOTS=1 and commit it",
+            path.display(),
+            e
+        ),
+        _ => {
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, actual).unwrap();
+            eprintln!("Recorded snapshot {}", path.display());
+        }
+    }
+}
+
+fn use_theme(app: &mut App, theme: Theme) {
+    app.config.ui.theme = theme;
+    app.color_scheme = ColorScheme::from_theme(&app.config.ui.theme);
+}
+
+fn open_item(app: &mut App, feed_idx: usize, item_idx: usize) {
+    app.selected_feed = Some(feed_idx);
+    app.selected_item = Some(item_idx);
+    app.view = View::FeedItemDetail;
+}
+
+#[test]
+fn test_dashboard_in_both_themes() {
+    let mut app = demo_app();
+    use_theme(&mut app, Theme::Dark);
+    assert_snapshot(
+        "dashboard_dark",
+        &text_and_colors(&render(&mut app, 100, 30)),
+    );
+    use_theme(&mut app, Theme::Light);
+    assert_snapshot(
+        "dashboard_light",
+        &text_and_colors(&render(&mut app, 100, 30)),
+    );
+}
+
+#[test]
+fn test_list_views() {
+    let mut app = demo_app();
+    let views = [
+        ("feed_list", View::FeedList),
+        ("starred", View::Starred),
+        ("read_later", View::ReadLater),
+        ("categories", View::CategoryManagement),
+    ];
+    for (name, view) in views {
+        app.view = view;
+        app.selected_item = Some(0);
+        assert_snapshot(name, &text(&render(&mut app, 100, 30)));
+    }
+
+    app.view = View::FeedItems;
+    app.selected_feed = Some(0);
+    app.selected_item = Some(0);
+    assert_snapshot("feed_items", &text(&render(&mut app, 100, 30)));
+}
+
+#[test]
+fn test_item_detail() {
+    let mut app = demo_app();
+    // The first Rustacean Weekly post has a code block
+    open_item(&mut app, 0, 0);
+    assert_snapshot("item_detail", &text(&render(&mut app, 100, 30)));
+    // A French post, to cover accented text
+    open_item(&mut app, 3, 0);
+    assert_snapshot("item_detail_accents", &text(&render(&mut app, 100, 30)));
+}
+
+#[test]
+fn test_modals() {
+    let mut app = demo_app();
+    app.show_help_overlay = true;
+    assert_snapshot("help_overlay", &text(&render(&mut app, 100, 40)));
+    app.show_help_overlay = false;
+
+    app.input_mode = InputMode::SearchMode;
+    app.input = "rust".to_string();
+    assert_snapshot("search_input", &text(&render(&mut app, 100, 30)));
+    app.input_mode = InputMode::Normal;
+
+    app.error = Some("Failed to fetch https://demo.feedr.invalid/broken.xml".to_string());
+    assert_snapshot("error_modal", &text(&render(&mut app, 100, 30)));
+}
+
+#[test]
+fn test_small_terminals_truncate_instead_of_overflowing() {
+    let mut app = demo_app();
+    assert_snapshot("dashboard_narrow", &text(&render(&mut app, 60, 20)));
+    open_item(&mut app, 1, 0);
+    assert_snapshot("item_detail_narrow", &text(&render(&mut app, 60, 20)));
+}
+
+#[test]
+fn test_every_view_renders_at_any_size() {
+    let views = [
+        View::Dashboard,
+        View::FeedList,
+        View::FeedItems,
+        View::FeedItemDetail,
+        View::CategoryManagement,
+        View::Starred,
+        View::ReadLater,
+        View::Alerts,
+        View::Summary,
+    ];
+    let mut app = demo_app();
+    for view in views {
+        for (width, height) in [(20, 6), (40, 12), (80, 24), (200, 60)] {
+            app.view = view.clone();
+            app.selected_feed = Some(0);
+            app.selected_item = Some(0);
+            let screen = text(&render(&mut app, width, height));
+            assert_eq!(screen.lines().count(), height as usize);
+        }
+    }
+
+    // Fixture feeds show up where they should
+    app.view = View::FeedList;
+    let screen = text(&render(&mut app, 100, 30));
+    assert!(screen.contains("Rustacean Weekly"));
+    assert!(screen.contains("Carnet de cuisine"));
+}